@@ -0,0 +1,184 @@
+use std::io::IsTerminal;
+
+use futures::{SinkExt, StreamExt};
+use serde_json::json;
+use tokio_tungstenite::tungstenite;
+
+use crate::client::ZeniiClient;
+
+use super::chat::prompt_approval;
+use super::{encode_path_segment, truncate};
+
+pub struct AskArgs<'a> {
+    pub prompt: &'a str,
+    /// Existing session to continue; a new one is created when absent.
+    pub resume: Option<&'a str>,
+    pub model: Option<&'a str>,
+    /// Approve tool calls without prompting.
+    pub auto: bool,
+    /// Print `{response, usage, session_id}` instead of the bare answer.
+    pub json: bool,
+}
+
+/// One-shot prompt for scripts: streams a single turn over `/ws/chat`, prints
+/// only the final answer to stdout and sends tool traces to stderr. Agent
+/// errors are returned so the process exits non-zero.
+pub async fn run(client: &ZeniiClient, args: AskArgs<'_>) -> Result<(), String> {
+    let session_id = match args.resume {
+        Some(sid) => sid.to_string(),
+        None => {
+            let mut body = json!({ "title": truncate(args.prompt, 50) });
+            if let Some(m) = args.model {
+                body["model"] = json!(m);
+            }
+            let session: serde_json::Value = client.post("/sessions", &body).await?;
+            session
+                .get("id")
+                .and_then(|v| v.as_str())
+                .ok_or("daemon returned a session without an id")?
+                .to_string()
+        }
+    };
+
+    // The WS handler only persists the assistant reply; store the prompt first.
+    let _: serde_json::Value = client
+        .post(
+            &format!("/sessions/{}/messages", encode_path_segment(&session_id)),
+            &json!({ "role": "user", "content": args.prompt }),
+        )
+        .await?;
+
+    let url = client.ws_url("/ws/chat");
+    let mut request = tungstenite::client::IntoClientRequest::into_client_request(url.as_str())
+        .map_err(|e| format!("invalid WS URL: {e}"))?;
+    if let Some(auth) = client.auth_header_value() {
+        request.headers_mut().insert(
+            "authorization",
+            auth.parse()
+                .map_err(|e| format!("invalid auth header: {e}"))?,
+        );
+    }
+
+    let (ws, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .map_err(|e| format!("failed to connect to daemon: {e}"))?;
+    let (mut write, mut read) = ws.split();
+
+    let mut msg = json!({ "prompt": args.prompt, "session_id": session_id });
+    if let Some(m) = args.model {
+        msg["model"] = json!(m);
+    }
+    write
+        .send(tungstenite::Message::Text(msg.to_string().into()))
+        .await
+        .map_err(|e| format!("send error: {e}"))?;
+
+    let mut response: Option<String> = None;
+    let mut usage = serde_json::Value::Null;
+
+    while let Some(msg_result) = read.next().await {
+        let msg = msg_result.map_err(|e| format!("ws read error: {e}"))?;
+        let text = match msg {
+            tungstenite::Message::Text(text) => text,
+            tungstenite::Message::Close(_) => break,
+            _ => continue,
+        };
+        let chunk: serde_json::Value = serde_json::from_str(&text).unwrap_or_default();
+
+        match chunk.get("type").and_then(|v| v.as_str()).unwrap_or("") {
+            "text" => {
+                response = chunk
+                    .get("content")
+                    .and_then(|v| v.as_str())
+                    .map(String::from);
+            }
+            "tool_call" => {
+                let name = chunk
+                    .get("tool_name")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("?");
+                eprintln!("\u{26A1} {name}...");
+            }
+            "tool_result" => {
+                let name = chunk
+                    .get("tool_name")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("?");
+                let ok = chunk
+                    .get("success")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let ms = chunk
+                    .get("duration_ms")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(0);
+                let icon = if ok { "\u{2713}" } else { "\u{2717}" };
+                eprintln!("{icon} {name} ({ms}ms)");
+            }
+            "approval_request" => {
+                let approval_id = chunk
+                    .get("approval_id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("");
+                let decision = if args.auto {
+                    "approve"
+                } else if std::io::stdin().is_terminal() {
+                    prompt_approval(&chunk).await
+                } else {
+                    // Piped stdin belongs to the caller; never read approvals from it.
+                    eprintln!("Tool approval required; denied (pass --auto to approve)");
+                    "deny"
+                };
+                let reply = json!({
+                    "type": "approval_response",
+                    "approval_id": approval_id,
+                    "decision": decision,
+                });
+                write
+                    .send(tungstenite::Message::Text(reply.to_string().into()))
+                    .await
+                    .map_err(|e| format!("send error: {e}"))?;
+            }
+            "warning" => {
+                if let Some(warning) = chunk.get("warning").and_then(|v| v.as_str()) {
+                    eprintln!("Warning: {warning}");
+                }
+            }
+            "done" => {
+                usage = chunk.get("usage").cloned().unwrap_or_default();
+                break;
+            }
+            "error" => {
+                let err = chunk
+                    .get("error")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("agent error");
+                let mut message = match chunk.get("error_code").and_then(|v| v.as_str()) {
+                    Some(code) => format!("[{code}] {err}"),
+                    None => err.to_string(),
+                };
+                if let Some(hint) = chunk.get("hint").and_then(|v| v.as_str()) {
+                    message.push_str(&format!("\n  Hint: {hint}"));
+                }
+                return Err(message);
+            }
+            _ => {}
+        }
+    }
+
+    let _ = write.send(tungstenite::Message::Close(None)).await;
+
+    let response = response.ok_or("connection closed before the agent replied")?;
+    if args.json {
+        let out = json!({
+            "response": response,
+            "usage": usage,
+            "session_id": session_id,
+        });
+        println!("{}", serde_json::to_string_pretty(&out).unwrap_or_default());
+    } else {
+        println!("{response}");
+    }
+
+    Ok(())
+}
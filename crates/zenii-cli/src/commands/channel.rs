@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::client::ZeniiClient;
 use crate::commands::{encode_path_segment, encode_query_value, truncate};
@@ -19,6 +19,13 @@ struct ChannelMessage {
     created_at: String,
 }
 
+#[derive(Deserialize, Serialize, Default)]
+struct ChannelSettings {
+    model: Option<String>,
+    autonomy_level: Option<String>,
+    system_prompt: Option<String>,
+}
+
 pub async fn list(client: &ZeniiClient, source: Option<&str>) -> Result<(), String> {
     let mut path = "/channels/sessions?limit=50".to_string();
     if let Some(src) = source {
@@ -86,3 +93,101 @@ pub async fn messages(
 
     Ok(())
 }
+
+pub async fn settings(
+    client: &ZeniiClient,
+    name: &str,
+    model: Option<String>,
+    autonomy: Option<String>,
+    system_prompt: Option<String>,
+) -> Result<(), String> {
+    let path = format!("/channels/{}/settings", encode_path_segment(name));
+    let mut current: ChannelSettings = client.get(&path).await?;
+
+    if model.is_some() || autonomy.is_some() || system_prompt.is_some() {
+        // Only the flags given change; the server treats "" as "unset"
+        if model.is_some() {
+            current.model = model;
+        }
+        if autonomy.is_some() {
+            current.autonomy_level = autonomy;
+        }
+        if system_prompt.is_some() {
+            current.system_prompt = system_prompt;
+        }
+        current = client.put(&path, &current).await?;
+        println!("Settings for {name} updated.");
+    }
+
+    let show = |v: &Option<String>| v.clone().unwrap_or_else(|| "(default)".into());
+    println!("Model:         {}", show(&current.model));
+    println!("Autonomy:      {}", show(&current.autonomy_level));
+    println!(
+        "System prompt: {}",
+        current
+            .system_prompt
+            .as_deref()
+            .map(|p| truncate(p, 60))
+            .unwrap_or_else(|| "(default)".into())
+    );
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct Allowlist {
+    list: String,
+    entries: Vec<String>,
+}
+
+fn print_allowlist(allowlist: &Allowlist) {
+    if allowlist.entries.is_empty() {
+        println!(
+            "The {} allowlist is empty (no restriction).",
+            allowlist.list
+        );
+        return;
+    }
+    for entry in &allowlist.entries {
+        println!("{entry}");
+    }
+    println!("\n{} id(s)", allowlist.entries.len());
+}
+
+pub async fn allow_list(client: &ZeniiClient, name: &str) -> Result<(), String> {
+    let path = format!("/channels/{}/allowlist", encode_path_segment(name));
+    let allowlist: Allowlist = client.get(&path).await?;
+    print_allowlist(&allowlist);
+    Ok(())
+}
+
+pub async fn allow_add(client: &ZeniiClient, name: &str, entry: &str) -> Result<(), String> {
+    let path = format!("/channels/{}/allowlist", encode_path_segment(name));
+    let allowlist: Allowlist = client
+        .post(&path, &serde_json::json!({ "entry": entry }))
+        .await?;
+    println!("Allowed {entry} on {}.", allowlist.list);
+    print_allowlist(&allowlist);
+    Ok(())
+}
+
+pub async fn allow_remove(
+    client: &ZeniiClient,
+    name: &str,
+    entry: &str,
+    allow_all: bool,
+) -> Result<(), String> {
+    let mut path = format!(
+        "/channels/{}/allowlist/{}",
+        encode_path_segment(name),
+        encode_path_segment(entry)
+    );
+    if allow_all {
+        path.push_str("?allow_all=true");
+    }
+    client.delete(&path).await?;
+    println!("Removed {entry} from the {name} allowlist.");
+    if allow_all {
+        allow_list(client, name).await?;
+    }
+    Ok(())
+}
@@ -86,6 +86,7 @@ pub async fn run(
             .map_err(|e| format!("send error: {e}"))?;
 
         // Read response chunks until "done" or "error"
+        let mut streamed = false;
         while let Some(msg_result) = read.next().await {
             let msg = msg_result.map_err(|e| format!("ws read error: {e}"))?;
             match msg {
@@ -94,8 +95,20 @@ pub async fn run(
                     let chunk_type = chunk.get("type").and_then(|v| v.as_str()).unwrap_or("");
 
                     match chunk_type {
+                        "token" => {
+                            if let Some(delta) = chunk.get("delta").and_then(|v| v.as_str()) {
+                                print!("{delta}");
+                                let _ = io::stdout().flush();
+                                streamed = true;
+                            }
+                        }
                         "text" => {
-                            if let Some(content) = chunk.get("content").and_then(|v| v.as_str()) {
+                            // Already shown token by token; just end the line
+                            if streamed {
+                                println!();
+                            } else if let Some(content) =
+                                chunk.get("content").and_then(|v| v.as_str())
+                            {
                                 println!("{content}");
                             }
                         }
@@ -212,65 +225,11 @@ pub async fn run(
                             );
                         }
                         "approval_request" => {
-                            let tool_name = chunk
-                                .get("tool_name")
-                                .and_then(|v| v.as_str())
-                                .unwrap_or("?");
-                            let args_summary = chunk
-                                .get("args_summary")
-                                .and_then(|v| v.as_str())
-                                .unwrap_or("");
-                            let risk = chunk
-                                .get("risk_level")
-                                .and_then(|v| v.as_str())
-                                .unwrap_or("medium");
-                            let reason = chunk.get("reason").and_then(|v| v.as_str()).unwrap_or("");
                             let approval_id = chunk
                                 .get("approval_id")
                                 .and_then(|v| v.as_str())
                                 .unwrap_or("");
-                            let timeout = chunk
-                                .get("timeout_secs")
-                                .and_then(|v| v.as_u64())
-                                .unwrap_or(120);
-
-                            eprintln!(
-                                "\n\x1b[33m\u{26A0}\u{FE0F}  Tool \"{tool_name}\" needs approval\x1b[0m"
-                            );
-                            eprintln!("    Command: {args_summary}");
-                            eprintln!("    Risk: {risk}");
-                            if !reason.is_empty() {
-                                eprintln!("    Reason: {reason}");
-                            }
-                            eprint!(
-                                "    [a]pprove / [A]lways allow / [d]eny (auto-deny in {timeout}s): "
-                            );
-                            io::stderr().flush().unwrap_or(());
-
-                            let decision_result = tokio::time::timeout(
-                                Duration::from_secs(timeout),
-                                tokio::task::spawn_blocking(|| -> &'static str {
-                                    let mut input = String::new();
-                                    if io::stdin().lock().read_line(&mut input).is_ok() {
-                                        match input.trim() {
-                                            "a" | "approve" | "y" | "yes" => "approve",
-                                            "A" | "always" => "approve_always",
-                                            _ => "deny",
-                                        }
-                                    } else {
-                                        "deny"
-                                    }
-                                }),
-                            )
-                            .await;
-
-                            let decision = match decision_result {
-                                Ok(Ok(d)) => d,
-                                _ => {
-                                    eprintln!("\n    (auto-denied: timeout)");
-                                    "deny"
-                                }
-                            };
+                            let decision = prompt_approval(&chunk).await;
 
                             let response = json!({
                                 "type": "approval_response",
@@ -315,3 +274,59 @@ pub async fn run(
 
     Ok(())
 }
+
+/// Describe a pending tool approval on stderr and read the decision from stdin.
+/// Returns `"deny"` when the prompt times out or stdin is closed.
+pub(crate) async fn prompt_approval(chunk: &serde_json::Value) -> &'static str {
+    let tool_name = chunk
+        .get("tool_name")
+        .and_then(|v| v.as_str())
+        .unwrap_or("?");
+    let args_summary = chunk
+        .get("args_summary")
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+    let risk = chunk
+        .get("risk_level")
+        .and_then(|v| v.as_str())
+        .unwrap_or("medium");
+    let reason = chunk.get("reason").and_then(|v| v.as_str()).unwrap_or("");
+    let timeout = chunk
+        .get("timeout_secs")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(120);
+
+    eprintln!("\n\x1b[33m\u{26A0}\u{FE0F}  Tool \"{tool_name}\" needs approval\x1b[0m");
+    eprintln!("    Command: {args_summary}");
+    eprintln!("    Risk: {risk}");
+    if !reason.is_empty() {
+        eprintln!("    Reason: {reason}");
+    }
+    eprint!("    [a]pprove / [A]lways allow / [d]eny (auto-deny in {timeout}s): ");
+    io::stderr().flush().unwrap_or(());
+
+    let decision_result = tokio::time::timeout(
+        Duration::from_secs(timeout),
+        tokio::task::spawn_blocking(|| -> &'static str {
+            let mut input = String::new();
+            if io::stdin().lock().read_line(&mut input).is_ok() {
+                match input.trim() {
+                    "a" | "approve" | "y" | "yes" => "approve",
+                    "A" | "always" => "approve_always",
+                    _ => "deny",
+                }
+            } else {
+                "deny"
+            }
+        }),
+    )
+    .await;
+
+    match decision_result {
+        Ok(Ok(d)) => d,
+        _ => {
+            eprintln!("\n    (auto-denied: timeout)");
+            "deny"
+        }
+    }
+}
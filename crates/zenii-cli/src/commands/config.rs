@@ -11,6 +11,15 @@ pub async fn show(client: &ZeniiClient) -> Result<(), String> {
     Ok(())
 }
 
+pub async fn runtime(client: &ZeniiClient) -> Result<(), String> {
+    let runtime: serde_json::Value = client.get("/runtime").await?;
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&runtime).unwrap_or_default()
+    );
+    Ok(())
+}
+
 pub async fn set(client: &ZeniiClient, key: &str, value: &str) -> Result<(), String> {
     // Try to parse value as JSON (number, bool, etc.), fall back to string
     let json_value: serde_json::Value = serde_json::from_str(value).unwrap_or(json!(value));
@@ -0,0 +1,158 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+
+use crate::client::ZeniiClient;
+
+/// The daemon only reads and writes snapshots in `{data_dir}/backups/`. A bare
+/// file name is sent as is; for any other path the CLI moves the file into or
+/// out of that folder, which assumes the daemon runs on this machine.
+fn external_path(path: &str) -> Option<PathBuf> {
+    let p = Path::new(path);
+    let is_name = p.file_name().is_some_and(|name| name == p.as_os_str());
+    (!is_name).then(|| p.to_path_buf())
+}
+
+fn snapshot_name(path: &Path) -> Result<String, String> {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| format!("Invalid snapshot path '{}'", path.display()))
+}
+
+fn manifest_path(snapshot: &Path) -> PathBuf {
+    let mut path = snapshot.as_os_str().to_owned();
+    path.push(".manifest.json");
+    PathBuf::from(path)
+}
+
+/// Rename, falling back to copy-and-delete across filesystems.
+fn move_file(from: &Path, to: &Path) -> Result<(), String> {
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    std::fs::copy(from, to)
+        .and_then(|_| std::fs::remove_file(from))
+        .map_err(|e| format!("Failed to move {} to {}: {e}", from.display(), to.display()))
+}
+
+pub async fn backup(client: &ZeniiClient, path: &str) -> Result<(), String> {
+    let external = external_path(path);
+    let name = match &external {
+        Some(dest) => {
+            if dest.exists() {
+                return Err(format!("Backup destination already exists: {path}"));
+            }
+            snapshot_name(dest)?
+        }
+        None => path.to_string(),
+    };
+
+    let resp: serde_json::Value = client
+        .post("/system/db/backup", &json!({ "name": name }))
+        .await?;
+
+    let mut path = resp["path"].as_str().unwrap_or("unknown").to_string();
+    let mut manifest = resp["manifest_path"]
+        .as_str()
+        .unwrap_or("unknown")
+        .to_string();
+    if let Some(dest) = external {
+        if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {e}", parent.display()))?;
+        }
+        move_file(Path::new(&path), &dest)?;
+        move_file(Path::new(&manifest), &manifest_path(&dest))?;
+        path = dest.display().to_string();
+        manifest = manifest_path(&dest).display().to_string();
+    }
+
+    let version = resp["manifest"]["schema_version"].as_u64().unwrap_or(0);
+    println!("Database backed up to {path} (schema version {version})");
+    println!("Manifest: {manifest}");
+    println!("Note: API keys and tokens in the OS keyring are not included.");
+    Ok(())
+}
+
+pub async fn restore(client: &ZeniiClient, path: &str, yes: bool) -> Result<(), String> {
+    if !yes {
+        let confirmed = dialoguer::Confirm::new()
+            .with_prompt("Replace the current database with this snapshot?")
+            .default(false)
+            .interact()
+            .map_err(|e| e.to_string())?;
+        if !confirmed {
+            println!("Restore cancelled.");
+            return Ok(());
+        }
+    }
+
+    let name = match external_path(path) {
+        Some(src) => stage_snapshot(client, &src).await?,
+        None => path.to_string(),
+    };
+
+    let resp: serde_json::Value = client
+        .post("/system/db/restore", &json!({ "name": name }))
+        .await?;
+
+    let path = resp["path"].as_str().unwrap_or("unknown");
+    let taken = resp["manifest"]["created_at"].as_str().unwrap_or("unknown");
+    println!("Database restored from {path} (taken {taken})");
+    if resp["restart_required"].as_bool().unwrap_or(false) {
+        println!("Restart the daemon to reload scheduled jobs and channels.");
+    }
+    Ok(())
+}
+
+/// Copy a snapshot and its manifest into the daemon's backups folder and
+/// return the name to restore it by.
+async fn stage_snapshot(client: &ZeniiClient, src: &Path) -> Result<String, String> {
+    let name = snapshot_name(src)?;
+    let config: serde_json::Value = client.get("/config").await?;
+    let data_dir = config["data_dir"]
+        .as_str()
+        .ok_or("Daemon did not report its data_dir")?;
+    let dir = Path::new(data_dir).join("backups");
+    let staged = dir.join(&name);
+    if staged.exists() {
+        return Err(format!(
+            "A snapshot named '{name}' already exists in {}; restore it by name or rename the file",
+            dir.display()
+        ));
+    }
+
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+    std::fs::copy(src, &staged).map_err(|e| format!("Failed to copy {}: {e}", src.display()))?;
+    if let Err(e) = std::fs::copy(manifest_path(src), manifest_path(&staged)) {
+        let _ = std::fs::remove_file(&staged);
+        return Err(format!(
+            "Failed to copy {}: {e} (was it created with `zenii db backup`?)",
+            manifest_path(src).display()
+        ));
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare_names_stay_on_the_daemon() {
+        assert_eq!(external_path("nightly.db"), None);
+        assert_eq!(
+            external_path("/tmp/nightly.db"),
+            Some(PathBuf::from("/tmp/nightly.db"))
+        );
+        assert_eq!(
+            external_path("./nightly.db"),
+            Some(PathBuf::from("./nightly.db"))
+        );
+        assert_eq!(
+            manifest_path(Path::new("/tmp/nightly.db")),
+            PathBuf::from("/tmp/nightly.db.manifest.json")
+        );
+    }
+}
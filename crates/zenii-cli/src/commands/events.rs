@@ -0,0 +1,30 @@
+use serde_json::json;
+
+use crate::client::ZeniiClient;
+
+pub async fn record(client: &ZeniiClient, name: Option<String>) -> Result<(), String> {
+    let resp: serde_json::Value = client
+        .post("/system/events/recording/start", &json!({ "name": name }))
+        .await?;
+
+    let path = resp["path"].as_str().unwrap_or("unknown");
+    let max_bytes = resp["max_bytes"].as_u64().unwrap_or(0);
+    println!("Recording events to {path} (cap: {max_bytes} bytes)");
+    println!("Run `zenii events stop` to finish.");
+    Ok(())
+}
+
+pub async fn stop(client: &ZeniiClient) -> Result<(), String> {
+    let resp: serde_json::Value = client
+        .post("/system/events/recording/stop", &json!({}))
+        .await?;
+
+    let path = resp["path"].as_str().unwrap_or("unknown");
+    let events = resp["events"].as_u64().unwrap_or(0);
+    let bytes = resp["bytes"].as_u64().unwrap_or(0);
+    println!("Recorded {events} events ({bytes} bytes) to {path}");
+    if resp["truncated"].as_bool().unwrap_or(false) {
+        println!("Recording stopped early: size cap reached.");
+    }
+    Ok(())
+}
@@ -129,6 +129,19 @@ pub async fn list(client: &ZeniiClient) -> Result<(), String> {
     Ok(())
 }
 
+pub async fn oauth(client: &ZeniiClient, provider: &str) -> Result<(), String> {
+    let path = format!("/oauth/{}/start", encode_path_segment(provider));
+    let resp: serde_json::Value = client.post(&path, &json!({})).await?;
+
+    let url = resp["authorize_url"].as_str().unwrap_or_default();
+    println!("Open this URL in a browser to connect {provider}:\n\n  {url}\n");
+    println!(
+        "Authorization completes automatically on {}",
+        resp["redirect_uri"].as_str().unwrap_or("the redirect URI")
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use httpmock::prelude::*;
@@ -200,4 +213,22 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(mock.hits(), 1);
     }
+
+    // oauth posts to the provider's start endpoint
+    #[tokio::test]
+    async fn oauth_starts_flow() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/oauth/slack/start");
+            then.status(200).json_body(json!({
+                "authorize_url": "https://slack.com/oauth/v2/authorize?state=s1",
+                "state": "s1",
+                "redirect_uri": "http://127.0.0.1:18981/oauth/callback"
+            }));
+        });
+
+        let result = oauth(&test_client(server.port()), "slack").await;
+        assert!(result.is_ok());
+        assert_eq!(mock.hits(), 1);
+    }
 }
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use tokio::process::Command;
+
+const BINARY: &str = "zenii-mcp-server";
+
+/// Run the `zenii-mcp-server` binary in the foreground over stdio.
+///
+/// stdin/stdout are inherited so an MCP host (e.g. Claude Desktop) can speak
+/// newline-delimited JSON-RPC to it directly; nothing else may write to stdout.
+pub async fn run(config: Option<PathBuf>) -> Result<(), String> {
+    let mut cmd = Command::new(binary_path());
+    cmd.stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit());
+    if let Some(path) = config {
+        cmd.arg("--config").arg(path);
+    }
+
+    let status = cmd.status().await.map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            format!(
+                "{BINARY} not found next to this executable or on PATH; install it alongside zenii"
+            )
+        } else {
+            format!("failed to start {BINARY}: {e}")
+        }
+    })?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{BINARY} exited with {status}"))
+    }
+}
+
+/// The server installed next to this executable, else the name to look up on `PATH`.
+fn binary_path() -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| {
+            let sibling = exe.with_file_name(format!("{BINARY}{}", std::env::consts::EXE_SUFFIX));
+            sibling.is_file().then_some(sibling)
+        })
+        .unwrap_or_else(|| PathBuf::from(BINARY))
+}
@@ -1,10 +1,14 @@
+pub mod ask;
 #[cfg(feature = "channels")]
 pub mod channel;
 pub mod chat;
 pub mod config;
 pub mod daemon;
+pub mod db;
 pub mod embedding;
+pub mod events;
 pub mod key;
+pub mod mcp_server;
 pub mod memory;
 pub mod onboard;
 pub mod plugin;
@@ -12,6 +16,8 @@ pub mod provider;
 pub mod run;
 #[cfg(feature = "scheduler")]
 pub mod schedule;
+pub mod token;
+pub mod watch;
 pub mod wiki;
 pub mod workflow;
 
@@ -48,6 +48,11 @@ const CHANNELS: &[ChannelDef] = &[
                 "Allowed Channel IDs (comma-separated, empty = all)",
                 false,
             ),
+            (
+                "signing_secret",
+                "Signing Secret (only for approval buttons via a Request URL)",
+                false,
+            ),
         ],
     },
     ChannelDef {
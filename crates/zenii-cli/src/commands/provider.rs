@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde_json::json;
 
 use crate::client::ZeniiClient;
@@ -68,6 +70,62 @@ pub async fn add(client: &ZeniiClient, id: &str, name: &str, base_url: &str) ->
     Ok(())
 }
 
+/// Options for `zenii provider update`; header and query lists replace what
+/// the provider has when non-empty.
+pub struct ProviderUpdate {
+    pub base_url: Option<String>,
+    pub headers: Vec<String>,
+    pub query: Vec<String>,
+    pub secret_headers: Vec<String>,
+    pub clear_extras: bool,
+}
+
+pub async fn update(client: &ZeniiClient, id: &str, update: ProviderUpdate) -> Result<(), String> {
+    let mut body = serde_json::Map::new();
+    if let Some(base_url) = update.base_url {
+        body.insert("base_url".into(), json!(base_url));
+    }
+    if update.clear_extras {
+        for field in ["extra_headers", "extra_query", "secret_headers"] {
+            body.insert(field.into(), json!({}));
+        }
+    }
+    for (field, pairs) in [
+        ("extra_headers", &update.headers),
+        ("extra_query", &update.query),
+        ("secret_headers", &update.secret_headers),
+    ] {
+        if !pairs.is_empty() {
+            body.insert(field.into(), json!(parse_pairs(pairs)?));
+        }
+    }
+    if body.is_empty() {
+        return Err("nothing to update; pass --base-url, --header, --query, --secret-header or --clear-extras".into());
+    }
+
+    let _resp: serde_json::Value = client
+        .put(
+            &format!("/providers/{}", encode_path_segment(id)),
+            &serde_json::Value::Object(body),
+        )
+        .await?;
+    println!("Provider '{id}' updated.");
+    Ok(())
+}
+
+/// Parse `NAME=VALUE` arguments; the value may itself contain `=`.
+fn parse_pairs(pairs: &[String]) -> Result<HashMap<String, String>, String> {
+    pairs
+        .iter()
+        .map(|pair| {
+            pair.split_once('=')
+                .map(|(k, v)| (k.trim().to_string(), v.to_string()))
+                .filter(|(k, _)| !k.is_empty())
+                .ok_or_else(|| format!("expected NAME=VALUE, got '{pair}'"))
+        })
+        .collect()
+}
+
 pub async fn remove(client: &ZeniiClient, id: &str) -> Result<(), String> {
     client
         .delete(&format!("/providers/{}", encode_path_segment(id)))
@@ -89,3 +147,30 @@ pub async fn set_default(
     println!("Default model set to {provider_id}/{model_id}");
     Ok(())
 }
+
+pub async fn reload(client: &ZeniiClient) -> Result<(), String> {
+    let result: serde_json::Value = client.post("/providers/reload", &json!({})).await?;
+
+    let model = result["active_model"].as_str().unwrap_or("none");
+    if result["ready"].as_bool().unwrap_or(false) {
+        println!("Providers reloaded. Active model: {model}");
+    } else {
+        let error = result["error"].as_str().unwrap_or("unknown");
+        println!("Providers reloaded, but no provider is usable ({model}): {error}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pairs_splits_on_first_equals() {
+        let pairs = parse_pairs(&["api-version=2024-10-21".into(), "X-Sig=a=b".into()]).unwrap();
+        assert_eq!(pairs["api-version"], "2024-10-21");
+        assert_eq!(pairs["X-Sig"], "a=b");
+        assert!(parse_pairs(&["novalue".into()]).is_err());
+        assert!(parse_pairs(&["=x".into()]).is_err());
+    }
+}
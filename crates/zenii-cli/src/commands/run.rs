@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use serde_json::json;
 
 use crate::client::ZeniiClient;
@@ -7,6 +9,7 @@ pub async fn run(
     prompt: &str,
     session_id: Option<&str>,
     model: Option<&str>,
+    response_format: Option<&Path>,
 ) -> Result<(), String> {
     let mut body = json!({ "prompt": prompt });
     if let Some(sid) = session_id {
@@ -15,6 +18,13 @@ pub async fn run(
     if let Some(m) = model {
         body["model"] = json!(m);
     }
+    if let Some(path) = response_format {
+        let raw = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        let schema: serde_json::Value = serde_json::from_str(&raw)
+            .map_err(|e| format!("invalid JSON Schema in {}: {e}", path.display()))?;
+        body["response_format"] = schema;
+    }
 
     let resp: serde_json::Value = client.post("/chat", &body).await?;
 
@@ -25,7 +25,14 @@ pub async fn list(client: &ZeniiClient) -> Result<(), String> {
                 }
                 Some("cron") => {
                     let expr = sched.get("expr").and_then(|e| e.as_str()).unwrap_or("?");
-                    format!("cron: {expr}")
+                    match sched.get("tz").and_then(|t| t.as_str()) {
+                        Some(tz) => format!("cron: {expr} ({tz})"),
+                        None => format!("cron: {expr}"),
+                    }
+                }
+                Some("once") => {
+                    let at = sched.get("at").and_then(|a| a.as_str()).unwrap_or("?");
+                    format!("once at {at}")
                 }
                 _ => "unknown".to_string(),
             }
@@ -50,31 +57,30 @@ pub struct CreateJobArgs<'a> {
     pub schedule_type: &'a str,
     pub interval_secs: Option<u64>,
     pub cron_expr: Option<&'a str>,
+    pub tz: Option<&'a str>,
+    /// RFC 3339 instant; implies the `once` schedule type.
+    pub at: Option<&'a str>,
     pub payload_type: &'a str,
     pub message: Option<&'a str>,
     pub prompt: Option<&'a str>,
+    /// Channel for a `channel_notify` payload; implies that payload type.
+    pub notify_channel: Option<&'a str>,
+    /// Recipient on `notify_channel`: a chat or channel ID.
+    pub notify_to: Option<&'a str>,
     pub one_shot: bool,
 }
 
-pub async fn create(client: &ZeniiClient, args: CreateJobArgs<'_>) -> Result<(), String> {
-    let schedule = match args.schedule_type {
-        "interval" => {
-            let secs = args
-                .interval_secs
-                .ok_or("--interval-secs required for interval schedule")?;
-            json!({ "type": "interval", "secs": secs })
-        }
-        "cron" => {
-            let expr = args
-                .cron_expr
-                .ok_or("--cron-expr required for cron schedule")?;
-            json!({ "type": "cron", "expr": expr })
-        }
-        _ => return Err(format!("Unknown schedule type: {}", args.schedule_type)),
+/// Job payload JSON for the CLI flags. `--notify-channel` implies the
+/// `channel_notify` payload type.
+fn build_payload(args: &CreateJobArgs<'_>) -> Result<serde_json::Value, String> {
+    let payload_type = if args.notify_channel.is_some() {
+        "channel_notify"
+    } else {
+        args.payload_type
     };
-
-    let payload = match args.payload_type {
+    let payload = match payload_type {
         "heartbeat" => json!({ "type": "heartbeat" }),
+        "daily_summary" => json!({ "type": "daily_summary" }),
         "notify" => {
             let msg = args
                 .message
@@ -87,9 +93,56 @@ pub async fn create(client: &ZeniiClient, args: CreateJobArgs<'_>) -> Result<(),
                 .ok_or("--prompt required for agent_turn payload")?;
             json!({ "type": "agent_turn", "prompt": p })
         }
-        _ => return Err(format!("Unknown payload type: {}", args.payload_type)),
+        "channel_notify" => {
+            let channel = args
+                .notify_channel
+                .ok_or("--notify-channel required for channel_notify payload")?;
+            let recipient = args
+                .notify_to
+                .ok_or("--notify-to required for channel_notify payload")?;
+            let msg = args
+                .message
+                .ok_or("--message required for channel_notify payload")?;
+            json!({
+                "type": "channel_notify",
+                "channel": channel,
+                "recipient": recipient,
+                "message": msg,
+            })
+        }
+        _ => return Err(format!("Unknown payload type: {payload_type}")),
+    };
+    Ok(payload)
+}
+
+pub async fn create(client: &ZeniiClient, args: CreateJobArgs<'_>) -> Result<(), String> {
+    let schedule_type = if args.at.is_some() {
+        "once"
+    } else {
+        args.schedule_type
+    };
+    let schedule = match schedule_type {
+        "interval" => {
+            let secs = args
+                .interval_secs
+                .ok_or("--interval-secs required for interval schedule")?;
+            json!({ "type": "interval", "secs": secs })
+        }
+        "cron" => {
+            let expr = args
+                .cron_expr
+                .ok_or("--cron-expr required for cron schedule")?;
+            json!({ "type": "cron", "expr": expr, "tz": args.tz })
+        }
+        "once" => {
+            let at = args.at.ok_or("--at required for once schedule")?;
+            json!({ "type": "once", "at": at })
+        }
+        _ => return Err(format!("Unknown schedule type: {schedule_type}")),
     };
 
+    let payload = build_payload(&args)?;
+
     let body = json!({
         "id": "",
         "name": args.name,
@@ -107,7 +160,12 @@ pub async fn create(client: &ZeniiClient, args: CreateJobArgs<'_>) -> Result<(),
 }
 
 pub async fn update(client: &ZeniiClient, id: &str, args: CreateJobArgs<'_>) -> Result<(), String> {
-    let schedule = match args.schedule_type {
+    let schedule_type = if args.at.is_some() {
+        "once"
+    } else {
+        args.schedule_type
+    };
+    let schedule = match schedule_type {
         "interval" => {
             let secs = args
                 .interval_secs
@@ -118,28 +176,17 @@ pub async fn update(client: &ZeniiClient, id: &str, args: CreateJobArgs<'_>) ->
             let expr = args
                 .cron_expr
                 .ok_or("--cron-expr required for cron schedule")?;
-            json!({ "type": "cron", "expr": expr })
+            json!({ "type": "cron", "expr": expr, "tz": args.tz })
         }
-        _ => return Err(format!("Unknown schedule type: {}", args.schedule_type)),
-    };
-
-    let payload = match args.payload_type {
-        "heartbeat" => json!({ "type": "heartbeat" }),
-        "notify" => {
-            let msg = args
-                .message
-                .ok_or("--message required for notify payload")?;
-            json!({ "type": "notify", "message": msg })
-        }
-        "agent_turn" => {
-            let p = args
-                .prompt
-                .ok_or("--prompt required for agent_turn payload")?;
-            json!({ "type": "agent_turn", "prompt": p })
+        "once" => {
+            let at = args.at.ok_or("--at required for once schedule")?;
+            json!({ "type": "once", "at": at })
         }
-        _ => return Err(format!("Unknown payload type: {}", args.payload_type)),
+        _ => return Err(format!("Unknown schedule type: {schedule_type}")),
     };
 
+    let payload = build_payload(&args)?;
+
     let body = json!({
         "id": id,
         "name": args.name,
@@ -165,6 +212,18 @@ pub async fn toggle(client: &ZeniiClient, id: &str) -> Result<(), String> {
     Ok(())
 }
 
+pub async fn cancel(client: &ZeniiClient, id: &str) -> Result<(), String> {
+    let result: serde_json::Value = client
+        .post(&format!("/scheduler/jobs/{id}/cancel"), &json!({}))
+        .await?;
+    if result["cancelled"].as_bool().unwrap_or(false) {
+        println!("Job {id}: cancelled");
+    } else {
+        println!("Job {id} is not running");
+    }
+    Ok(())
+}
+
 pub async fn delete(client: &ZeniiClient, id: &str) -> Result<(), String> {
     client.delete(&format!("/scheduler/jobs/{id}")).await?;
     println!("Job {id} deleted.");
@@ -180,6 +239,58 @@ pub async fn status(client: &ZeniiClient) -> Result<(), String> {
     Ok(())
 }
 
+pub async fn export(client: &ZeniiClient, output: Option<&str>) -> Result<(), String> {
+    let export: serde_json::Value = client.get("/scheduler/export").await?;
+    let json = serde_json::to_string_pretty(&export).map_err(|e| e.to_string())?;
+    match output {
+        Some(path) => {
+            std::fs::write(path, format!("{json}\n")).map_err(|e| format!("{path}: {e}"))?;
+            let count = export["jobs"].as_array().map_or(0, Vec::len);
+            eprintln!("Exported {count} jobs to {path}");
+        }
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+pub async fn import(client: &ZeniiClient, file: &str, overwrite: bool) -> Result<(), String> {
+    let content = if file == "-" {
+        std::io::read_to_string(std::io::stdin()).map_err(|e| e.to_string())?
+    } else {
+        std::fs::read_to_string(file).map_err(|e| format!("{file}: {e}"))?
+    };
+    let export: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| format!("{file}: invalid JSON: {e}"))?;
+    let on_conflict = if overwrite { "overwrite" } else { "skip" };
+
+    let report: serde_json::Value = client
+        .post(
+            &format!("/scheduler/import?on_conflict={on_conflict}"),
+            &export,
+        )
+        .await?;
+    let mut printed = false;
+    for (key, label) in [
+        ("created", "Created"),
+        ("updated", "Updated"),
+        ("skipped", "Skipped (already exists)"),
+        ("unchanged", "Unchanged"),
+    ] {
+        let names: Vec<&str> = report[key]
+            .as_array()
+            .map(|names| names.iter().filter_map(|n| n.as_str()).collect())
+            .unwrap_or_default();
+        if !names.is_empty() {
+            println!("{label}: {}", names.join(", "));
+            printed = true;
+        }
+    }
+    if !printed {
+        println!("No jobs in {file}.");
+    }
+    Ok(())
+}
+
 pub async fn history(client: &ZeniiClient, id: &str) -> Result<(), String> {
     let entries: Vec<serde_json::Value> =
         client.get(&format!("/scheduler/jobs/{id}/history")).await?;
@@ -0,0 +1,45 @@
+use serde_json::json;
+
+use crate::client::ZeniiClient;
+use crate::commands::encode_path_segment;
+
+pub async fn list(client: &ZeniiClient) -> Result<(), String> {
+    let tokens: Vec<serde_json::Value> = client.get("/auth/tokens").await?;
+    if tokens.is_empty() {
+        println!("No scoped tokens.");
+        return Ok(());
+    }
+    for token in &tokens {
+        println!(
+            "{}  {:<6} {}  (created {})",
+            token["id"].as_str().unwrap_or("?"),
+            token["scope"].as_str().unwrap_or("?"),
+            token["name"].as_str().unwrap_or("?"),
+            token["created_at"].as_str().unwrap_or("?"),
+        );
+    }
+    Ok(())
+}
+
+pub async fn mint(client: &ZeniiClient, name: &str, scope: &str) -> Result<(), String> {
+    let resp: serde_json::Value = client
+        .post("/auth/tokens", &json!({ "name": name, "scope": scope }))
+        .await?;
+    println!(
+        "Created {} token '{}' ({})",
+        resp["scope"].as_str().unwrap_or(scope),
+        resp["name"].as_str().unwrap_or(name),
+        resp["id"].as_str().unwrap_or("?"),
+    );
+    println!("{}", resp["token"].as_str().unwrap_or_default());
+    println!("Store this token now; it cannot be shown again.");
+    Ok(())
+}
+
+pub async fn revoke(client: &ZeniiClient, id: &str) -> Result<(), String> {
+    client
+        .delete(&format!("/auth/tokens/{}", encode_path_segment(id)))
+        .await?;
+    println!("Token revoked: {id}");
+    Ok(())
+}
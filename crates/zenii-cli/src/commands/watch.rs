@@ -0,0 +1,359 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::client::ZeniiClient;
+
+/// How often the watched tree is rescanned for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Directories that are never descended into.
+const SKIPPED_DIRS: &[&str] = &[".git", "target", "node_modules"];
+
+pub struct WatchArgs<'a> {
+    pub path: &'a Path,
+    /// Prompt template; `{files}` is replaced by the batch, `{file}` runs once per file.
+    pub prompt: &'a str,
+    /// Quiet period after the last change before the batch fires.
+    pub debounce_ms: u64,
+    pub model: Option<&'a str>,
+    /// Approve tool calls without prompting.
+    pub auto: bool,
+}
+
+/// Watch `path` and run the agent on changed files. Changes are collected
+/// until nothing has changed for `debounce_ms`, then handed to a single agent
+/// run, so a checkout touching hundreds of files starts one run, not hundreds.
+pub async fn run(client: &ZeniiClient, args: WatchArgs<'_>) -> Result<(), String> {
+    if !args.path.exists() {
+        return Err(format!("path not found: {}", args.path.display()));
+    }
+    let debounce = Duration::from_millis(args.debounce_ms);
+    eprintln!(
+        "Watching {} ({} files, debounce {}ms). Press Ctrl+C to stop.",
+        args.path.display(),
+        scan(args.path).len(),
+        args.debounce_ms
+    );
+
+    watch_loop(args.path, debounce, POLL_INTERVAL, |files| async move {
+        for prompt in render_prompts(args.prompt, &files) {
+            eprintln!("Running agent for {} changed file(s)", files.len());
+            let result = super::ask::run(
+                client,
+                super::ask::AskArgs {
+                    prompt: &prompt,
+                    resume: None,
+                    model: args.model,
+                    auto: args.auto,
+                    json: false,
+                },
+            )
+            .await;
+            if let Err(e) = result {
+                eprintln!("Error: {e}");
+            }
+        }
+    })
+    .await;
+    Ok(())
+}
+
+/// Poll `root` every `interval` and pass each debounced batch to `on_batch`.
+/// Scanning continues while a batch runs, so files saved during a run are
+/// queued for the next one instead of being lost.
+async fn watch_loop<F, Fut>(root: &Path, debounce: Duration, interval: Duration, mut on_batch: F)
+where
+    F: FnMut(Vec<PathBuf>) -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    let mut snapshot = scan(root);
+    let mut batch = WatchBatch::default();
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let poll = |snapshot: &mut Snapshot, batch: &mut WatchBatch| {
+        let current = scan(root);
+        let changed = changed_paths(snapshot, &current);
+        *snapshot = current;
+        batch.add(changed, Instant::now());
+    };
+    loop {
+        ticker.tick().await;
+        poll(&mut snapshot, &mut batch);
+
+        let Some(files) = batch.take_ready(Instant::now(), debounce) else {
+            continue;
+        };
+        let run = on_batch(files);
+        tokio::pin!(run);
+        loop {
+            tokio::select! {
+                () = &mut run => break,
+                _ = ticker.tick() => poll(&mut snapshot, &mut batch),
+            }
+        }
+    }
+}
+
+/// Changed paths collected during the debounce window. Repeated edits to the
+/// same file collapse into one entry.
+#[derive(Default)]
+struct WatchBatch {
+    pending: BTreeSet<PathBuf>,
+    last_change: Option<Instant>,
+}
+
+impl WatchBatch {
+    fn add(&mut self, paths: impl IntoIterator<Item = PathBuf>, now: Instant) {
+        let mut touched = false;
+        for path in paths.into_iter().filter(|p| !is_ignored(p)) {
+            touched = true;
+            self.pending.insert(path);
+        }
+        if touched {
+            self.last_change = Some(now);
+        }
+    }
+
+    /// The batch, once nothing has changed for `debounce`.
+    fn take_ready(&mut self, now: Instant, debounce: Duration) -> Option<Vec<PathBuf>> {
+        let last = self.last_change?;
+        if self.pending.is_empty() || now.duration_since(last) < debounce {
+            return None;
+        }
+        self.last_change = None;
+        Some(std::mem::take(&mut self.pending).into_iter().collect())
+    }
+}
+
+/// Editor swap, backup and temp files that should never trigger a run.
+fn is_ignored(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return true;
+    };
+    name.ends_with('~')
+        || name.starts_with(".#")
+        || (name.starts_with('#') && name.ends_with('#'))
+        || name == "4913"
+        || name == ".DS_Store"
+        || [".swp", ".swo", ".swx", ".tmp", ".part", ".crdownload"]
+            .iter()
+            .any(|ext| name.ends_with(ext))
+}
+
+/// Prompts for one batch. `{files}` yields a single run with every path as a
+/// list; `{file}` yields one run per path; otherwise the list is appended.
+fn render_prompts(template: &str, files: &[PathBuf]) -> Vec<String> {
+    let list = files
+        .iter()
+        .map(|f| format!("- {}", f.display()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if template.contains("{files}") {
+        vec![template.replace("{files}", &list)]
+    } else if template.contains("{file}") {
+        files
+            .iter()
+            .map(|f| template.replace("{file}", &f.display().to_string()))
+            .collect()
+    } else {
+        vec![format!("{template}\n\nChanged files:\n{list}")]
+    }
+}
+
+type Snapshot = HashMap<PathBuf, (Option<SystemTime>, u64)>;
+
+/// Modification time and size of every file under `root`.
+fn scan(root: &Path) -> Snapshot {
+    let mut files = Snapshot::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(path) = stack.pop() {
+        let Ok(meta) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if meta.is_file() {
+            files.insert(path, (meta.modified().ok(), meta.len()));
+            continue;
+        }
+        let Ok(entries) = std::fs::read_dir(&path) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let child = entry.path();
+            let skipped = entry.file_type().is_ok_and(|t| t.is_dir())
+                && child
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| SKIPPED_DIRS.contains(&n));
+            if !skipped {
+                stack.push(child);
+            }
+        }
+    }
+    files
+}
+
+/// Files created, modified or removed between two scans.
+fn changed_paths(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, state)| before.get(*path) != Some(state))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .cloned(),
+    );
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_waits_for_quiet_period_and_coalesces() {
+        let start = Instant::now();
+        let debounce = Duration::from_millis(500);
+        let mut batch = WatchBatch::default();
+
+        batch.add(
+            [PathBuf::from("src/a.rs"), PathBuf::from("src/b.rs")],
+            start,
+        );
+        batch.add(
+            [PathBuf::from("src/a.rs")],
+            start + Duration::from_millis(300),
+        );
+        // Still inside the window that the second edit restarted
+        assert!(
+            batch
+                .take_ready(start + Duration::from_millis(600), debounce)
+                .is_none()
+        );
+
+        let files = batch
+            .take_ready(start + Duration::from_millis(800), debounce)
+            .unwrap();
+        assert_eq!(
+            files,
+            vec![PathBuf::from("src/a.rs"), PathBuf::from("src/b.rs")]
+        );
+        assert!(
+            batch
+                .take_ready(start + Duration::from_secs(5), debounce)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn swap_and_temp_files_are_ignored() {
+        for name in [
+            ".main.rs.swp",
+            "main.rs~",
+            ".#main.rs",
+            "#main.rs#",
+            "4913",
+            "download.part",
+        ] {
+            assert!(is_ignored(Path::new(name)), "{name}");
+        }
+        assert!(!is_ignored(Path::new("src/main.rs")));
+
+        let mut batch = WatchBatch::default();
+        let now = Instant::now();
+        batch.add([PathBuf::from(".notes.md.swp")], now);
+        assert!(
+            batch
+                .take_ready(now + Duration::from_secs(1), Duration::ZERO)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn prompts_follow_placeholders() {
+        let files = vec![PathBuf::from("a.rs"), PathBuf::from("b.rs")];
+
+        let batched = render_prompts("Review:\n{files}", &files);
+        assert_eq!(batched, vec!["Review:\n- a.rs\n- b.rs"]);
+
+        let single = render_prompts("Lint {file}", &files);
+        assert_eq!(single, vec!["Lint a.rs", "Lint b.rs"]);
+
+        let plain = render_prompts("Summarize the changes", &files);
+        assert_eq!(
+            plain,
+            vec!["Summarize the changes\n\nChanged files:\n- a.rs\n- b.rs"]
+        );
+    }
+
+    #[tokio::test]
+    async fn files_saved_during_a_run_are_batched_next() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let first = dir.path().join("first.rs");
+        let during = dir.path().join("during.rs");
+        let batches = std::sync::Mutex::new(Vec::new());
+
+        let watch = watch_loop(
+            dir.path(),
+            Duration::from_millis(50),
+            Duration::from_millis(10),
+            |files| {
+                batches.lock().unwrap().push(files);
+                let during = during.clone();
+                async move {
+                    // The user saves another file while the agent is still running
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    std::fs::write(&during, "edited mid-run").unwrap();
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+            },
+        );
+        let edits = async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            std::fs::write(&first, "fn main() {}").unwrap();
+            while batches.lock().unwrap().len() < 2 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(5), async {
+            tokio::select! {
+                () = watch => unreachable!("watch_loop never returns"),
+                () = edits => {}
+            }
+        })
+        .await
+        .expect("the mid-run edit was never batched");
+
+        let batches = batches.lock().unwrap();
+        assert_eq!(batches[0], vec![first.clone()]);
+        assert_eq!(batches[1], vec![during.clone()]);
+    }
+
+    #[test]
+    fn scan_detects_created_modified_and_removed_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let kept = dir.path().join("kept.txt");
+        let removed = dir.path().join("removed.txt");
+        std::fs::write(&kept, "one").unwrap();
+        std::fs::write(&removed, "x").unwrap();
+        std::fs::create_dir(dir.path().join(".git")).unwrap();
+        std::fs::write(dir.path().join(".git/HEAD"), "ref").unwrap();
+
+        let before = scan(dir.path());
+        assert_eq!(before.len(), 2);
+
+        std::fs::write(&kept, "one two").unwrap();
+        std::fs::remove_file(&removed).unwrap();
+        let added = dir.path().join("added.txt");
+        std::fs::write(&added, "new").unwrap();
+
+        let mut changed = changed_paths(&before, &scan(dir.path()));
+        changed.sort();
+        let mut expected = vec![added, kept, removed];
+        expected.sort();
+        assert_eq!(changed, expected);
+    }
+}
@@ -26,6 +26,10 @@ struct Cli {
     #[arg(long, global = true)]
     no_setup: bool,
 
+    /// Emit machine-readable JSON output (supported by `ask`)
+    #[arg(long, global = true)]
+    json: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -59,6 +63,40 @@ enum Commands {
         /// Model override
         #[arg(long)]
         model: Option<String>,
+        /// JSON Schema file the reply must conform to
+        #[arg(long, value_name = "FILE")]
+        response_format: Option<std::path::PathBuf>,
+    },
+    /// Ask a one-shot question for scripts: answer on stdout, tool traces on stderr
+    Ask {
+        /// The prompt to send
+        prompt: String,
+        /// Continue an existing session instead of creating a new one
+        #[arg(long, value_name = "SESSION_ID")]
+        resume: Option<String>,
+        /// Model override
+        #[arg(long)]
+        model: Option<String>,
+        /// Approve tool calls without prompting
+        #[arg(long)]
+        auto: bool,
+    },
+    /// Watch a file or directory and run the agent on changed files
+    Watch {
+        /// File or directory to watch
+        path: std::path::PathBuf,
+        /// Prompt template: `{files}` lists the whole batch, `{file}` runs once per file
+        #[arg(long)]
+        prompt: String,
+        /// Quiet period after the last change before the batch is sent
+        #[arg(long, default_value_t = 500)]
+        debounce_ms: u64,
+        /// Model override
+        #[arg(long)]
+        model: Option<String>,
+        /// Approve tool calls without prompting
+        #[arg(long)]
+        auto: bool,
     },
     /// Manage memory entries
     Memory {
@@ -112,6 +150,27 @@ enum Commands {
         #[command(subcommand)]
         action: commands::wiki::WikiAction,
     },
+    /// Record the daemon event stream to a file for debugging
+    Events {
+        #[command(subcommand)]
+        action: EventsAction,
+    },
+    /// Back up or restore the daemon database
+    Db {
+        #[command(subcommand)]
+        action: DbAction,
+    },
+    /// Mint, list or revoke scoped gateway tokens (needs an admin token)
+    Token {
+        #[command(subcommand)]
+        action: TokenAction,
+    },
+    /// Serve Zenii tools to MCP hosts over stdio (runs zenii-mcp-server)
+    McpServer {
+        /// Path to config.toml
+        #[arg(short, long)]
+        config: Option<std::path::PathBuf>,
+    },
     /// Interactive onboarding wizard
     Onboard,
     /// Generate shell completions (hidden from --help)
@@ -170,6 +229,8 @@ enum ConfigAction {
         /// Config value
         value: String,
     },
+    /// Dump the effective agent runtime (tools, skills, models, channels) for bug reports
+    Runtime,
 }
 
 #[derive(Subcommand)]
@@ -219,6 +280,11 @@ enum KeyAction {
     },
     /// List all stored credential keys (grouped by type)
     List,
+    /// Connect an account via OAuth (e.g. slack); prints the URL to open
+    Oauth {
+        /// OAuth provider
+        provider: String,
+    },
 }
 
 #[cfg(feature = "scheduler")]
@@ -230,7 +296,7 @@ enum ScheduleAction {
     Create {
         /// Job name
         name: String,
-        /// Schedule type: interval or cron
+        /// Schedule type: interval, cron, or once
         #[arg(long, default_value = "interval")]
         schedule_type: String,
         /// Interval in seconds (for interval schedule)
@@ -239,15 +305,27 @@ enum ScheduleAction {
         /// Cron expression (for cron schedule)
         #[arg(long)]
         cron_expr: Option<String>,
-        /// Payload type: heartbeat, notify, or agent_turn
+        /// IANA timezone for the cron expression, e.g. America/New_York (default: local time)
+        #[arg(long)]
+        tz: Option<String>,
+        /// Run once at this RFC 3339 instant, e.g. 2025-06-01T09:00:00Z (implies --schedule-type once)
+        #[arg(long, conflicts_with_all = ["interval_secs", "cron_expr"])]
+        at: Option<String>,
+        /// Payload type: heartbeat, notify, agent_turn, channel_notify, or daily_summary
         #[arg(long, default_value = "heartbeat")]
         payload: String,
-        /// Message for notify payload
+        /// Message for notify and channel_notify payloads
         #[arg(long)]
         message: Option<String>,
         /// Prompt for agent_turn payload
         #[arg(long)]
         prompt: Option<String>,
+        /// Send --message to this channel without the LLM (implies --payload channel_notify)
+        #[arg(long, requires = "notify_to")]
+        notify_channel: Option<String>,
+        /// Chat or channel ID to send to on --notify-channel
+        #[arg(long, requires = "notify_channel")]
+        notify_to: Option<String>,
         /// Delete after first run (one-shot)
         #[arg(long)]
         one_shot: bool,
@@ -259,7 +337,7 @@ enum ScheduleAction {
         /// New job name
         #[arg(long)]
         name: String,
-        /// Schedule type: interval or cron
+        /// Schedule type: interval, cron, or once
         #[arg(long, default_value = "interval")]
         schedule_type: String,
         /// Interval in seconds (for interval schedule)
@@ -268,15 +346,27 @@ enum ScheduleAction {
         /// Cron expression (for cron schedule)
         #[arg(long)]
         cron_expr: Option<String>,
-        /// Payload type: heartbeat, notify, or agent_turn
+        /// IANA timezone for the cron expression, e.g. America/New_York (default: local time)
+        #[arg(long)]
+        tz: Option<String>,
+        /// Run once at this RFC 3339 instant, e.g. 2025-06-01T09:00:00Z (implies --schedule-type once)
+        #[arg(long, conflicts_with_all = ["interval_secs", "cron_expr"])]
+        at: Option<String>,
+        /// Payload type: heartbeat, notify, agent_turn, channel_notify, or daily_summary
         #[arg(long, default_value = "heartbeat")]
         payload: String,
-        /// Message for notify payload
+        /// Message for notify and channel_notify payloads
         #[arg(long)]
         message: Option<String>,
         /// Prompt for agent_turn payload
         #[arg(long)]
         prompt: Option<String>,
+        /// Send --message to this channel without the LLM (implies --payload channel_notify)
+        #[arg(long, requires = "notify_to")]
+        notify_channel: Option<String>,
+        /// Chat or channel ID to send to on --notify-channel
+        #[arg(long, requires = "notify_channel")]
+        notify_to: Option<String>,
         /// Delete after first run (one-shot)
         #[arg(long)]
         one_shot: bool,
@@ -286,6 +376,11 @@ enum ScheduleAction {
         /// Job ID
         id: String,
     },
+    /// Stop a running job (no-op if it is not running)
+    Cancel {
+        /// Job ID
+        id: String,
+    },
     /// Delete a scheduled job
     Delete {
         /// Job ID
@@ -298,6 +393,20 @@ enum ScheduleAction {
     },
     /// Show scheduler status
     Status,
+    /// Write all jobs as a portable JSON file (stdout if no path is given)
+    Export {
+        /// Output file
+        #[arg(long, short)]
+        output: Option<String>,
+    },
+    /// Create jobs from an exported JSON file ("-" reads stdin)
+    Import {
+        /// Export file
+        file: String,
+        /// Replace jobs whose name already exists instead of skipping them
+        #[arg(long)]
+        overwrite: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -363,6 +472,42 @@ enum ChannelAction {
         #[arg(long)]
         before: Option<String>,
     },
+    /// Show or update per-channel agent settings (pass "" to clear a field)
+    Settings {
+        /// Channel name (telegram, slack, discord)
+        name: String,
+        /// Model override as provider_id:model_id
+        #[arg(long)]
+        model: Option<String>,
+        /// Autonomy level: readonly, supervised or full
+        #[arg(long)]
+        autonomy: Option<String>,
+        /// System prompt that replaces the default identity
+        #[arg(long)]
+        system_prompt: Option<String>,
+    },
+    /// Show a channel allowlist (empty = no restriction)
+    AllowList {
+        /// Allowlist: telegram, discord (guilds), discord-channels or slack
+        name: String,
+    },
+    /// Allow a chat, guild or channel id; applies without a restart
+    AllowAdd {
+        /// Allowlist: telegram, discord (guilds), discord-channels or slack
+        name: String,
+        /// Id to allow (numeric for Telegram and Discord)
+        entry: String,
+    },
+    /// Remove an id from a channel allowlist
+    AllowRemove {
+        /// Allowlist: telegram, discord (guilds), discord-channels or slack
+        name: String,
+        /// Id to remove
+        entry: String,
+        /// Allow removing the last id, which opens the channel to everyone
+        #[arg(long)]
+        allow_all: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -434,6 +579,55 @@ enum EmbeddingAction {
     Reindex,
 }
 
+#[derive(Subcommand)]
+enum EventsAction {
+    /// Start appending every event to a JSON Lines file
+    Record {
+        /// File name inside {data_dir}/recordings/ (default: events-<timestamp>.jsonl)
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Stop the running recording and print its summary
+    Stop,
+}
+
+#[derive(Subcommand)]
+enum TokenAction {
+    /// List scoped tokens
+    List,
+    /// Create a token; the secret is printed once
+    Mint {
+        /// Token name, e.g. monitoring
+        name: String,
+        /// Access scope: read, agent or admin
+        #[arg(long, default_value = "read", value_parser = ["read", "agent", "admin"])]
+        scope: String,
+    },
+    /// Revoke a token by id or name
+    Revoke {
+        /// Token id or name
+        id: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum DbAction {
+    /// Snapshot the database while the daemon runs (keyring secrets excluded)
+    Backup {
+        /// Snapshot file to create; a <path>.manifest.json is written beside it.
+        /// A bare file name stays in {data_dir}/backups/
+        path: String,
+    },
+    /// Replace the database with a snapshot taken by `zenii db backup`
+    Restore {
+        /// Snapshot file, or a bare name in {data_dir}/backups/
+        path: String,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
 #[derive(Subcommand)]
 enum ProviderAction {
     /// List all providers with key status
@@ -454,6 +648,26 @@ enum ProviderAction {
         #[arg(long)]
         base_url: String,
     },
+    /// Update a provider's base URL or extra request headers and query params
+    Update {
+        /// Provider ID
+        provider_id: String,
+        /// New base URL for the API
+        #[arg(long)]
+        base_url: Option<String>,
+        /// Header sent with every request, as NAME=VALUE (replaces existing)
+        #[arg(long = "header", value_name = "NAME=VALUE")]
+        headers: Vec<String>,
+        /// Query parameter added to every request, as NAME=VALUE (replaces existing)
+        #[arg(long = "query", value_name = "NAME=VALUE")]
+        query: Vec<String>,
+        /// Header whose value is kept in the keyring, as NAME=VALUE (replaces existing)
+        #[arg(long = "secret-header", value_name = "NAME=VALUE")]
+        secret_headers: Vec<String>,
+        /// Remove all extra headers, query params and secret headers
+        #[arg(long, conflicts_with_all = ["headers", "query", "secret_headers"])]
+        clear_extras: bool,
+    },
     /// Remove a user-defined provider
     Remove {
         /// Provider ID
@@ -466,6 +680,8 @@ enum ProviderAction {
         /// Model ID
         model_id: String,
     },
+    /// Re-read provider config and API keys without restarting the daemon
+    Reload,
 }
 
 fn init_file_tracing() {
@@ -490,6 +706,7 @@ async fn main() {
 
     let cli = Cli::parse();
     let client = ZeniiClient::new(&cli.host, cli.port, cli.token);
+    let json = cli.json;
 
     // Auto-trigger onboarding for interactive commands if setup is incomplete
     if !cli.no_setup
@@ -517,7 +734,54 @@ async fn main() {
             prompt,
             session,
             model,
-        } => commands::run::run(&client, &prompt, session.as_deref(), model.as_deref()).await,
+            response_format,
+        } => {
+            commands::run::run(
+                &client,
+                &prompt,
+                session.as_deref(),
+                model.as_deref(),
+                response_format.as_deref(),
+            )
+            .await
+        }
+        Commands::Ask {
+            prompt,
+            resume,
+            model,
+            auto,
+        } => {
+            commands::ask::run(
+                &client,
+                commands::ask::AskArgs {
+                    prompt: &prompt,
+                    resume: resume.as_deref(),
+                    model: model.as_deref(),
+                    auto,
+                    json,
+                },
+            )
+            .await
+        }
+        Commands::Watch {
+            path,
+            prompt,
+            debounce_ms,
+            model,
+            auto,
+        } => {
+            commands::watch::run(
+                &client,
+                commands::watch::WatchArgs {
+                    path: &path,
+                    prompt: &prompt,
+                    debounce_ms,
+                    model: model.as_deref(),
+                    auto,
+                },
+            )
+            .await
+        }
         Commands::Memory { action } => match action {
             MemoryAction::Search {
                 query,
@@ -532,6 +796,7 @@ async fn main() {
         Commands::Config { action } => match action {
             ConfigAction::Show => commands::config::show(&client).await,
             ConfigAction::Set { key, value } => commands::config::set(&client, &key, &value).await,
+            ConfigAction::Runtime => commands::config::runtime(&client).await,
         },
         Commands::Key { action } => match action {
             KeyAction::Set { provider, key } => commands::key::set(&client, &provider, &key).await,
@@ -547,6 +812,7 @@ async fn main() {
             KeyAction::SetRaw { key, value } => commands::key::set_raw(&client, &key, &value).await,
             KeyAction::RemoveRaw { key } => commands::key::remove_raw(&client, &key).await,
             KeyAction::List => commands::key::list(&client).await,
+            KeyAction::Oauth { provider } => commands::key::oauth(&client, &provider).await,
         },
         #[cfg(feature = "scheduler")]
         Commands::Schedule { action } => match action {
@@ -556,9 +822,13 @@ async fn main() {
                 schedule_type,
                 interval_secs,
                 cron_expr,
+                tz,
+                at,
                 payload,
                 message,
                 prompt,
+                notify_channel,
+                notify_to,
                 one_shot,
             } => {
                 commands::schedule::create(
@@ -568,9 +838,13 @@ async fn main() {
                         schedule_type: &schedule_type,
                         interval_secs,
                         cron_expr: cron_expr.as_deref(),
+                        tz: tz.as_deref(),
+                        at: at.as_deref(),
                         payload_type: &payload,
                         message: message.as_deref(),
                         prompt: prompt.as_deref(),
+                        notify_channel: notify_channel.as_deref(),
+                        notify_to: notify_to.as_deref(),
                         one_shot,
                     },
                 )
@@ -582,9 +856,13 @@ async fn main() {
                 schedule_type,
                 interval_secs,
                 cron_expr,
+                tz,
+                at,
                 payload,
                 message,
                 prompt,
+                notify_channel,
+                notify_to,
                 one_shot,
             } => {
                 commands::schedule::update(
@@ -595,18 +873,29 @@ async fn main() {
                         schedule_type: &schedule_type,
                         interval_secs,
                         cron_expr: cron_expr.as_deref(),
+                        tz: tz.as_deref(),
+                        at: at.as_deref(),
                         payload_type: &payload,
                         message: message.as_deref(),
                         prompt: prompt.as_deref(),
+                        notify_channel: notify_channel.as_deref(),
+                        notify_to: notify_to.as_deref(),
                         one_shot,
                     },
                 )
                 .await
             }
             ScheduleAction::Toggle { id } => commands::schedule::toggle(&client, &id).await,
+            ScheduleAction::Cancel { id } => commands::schedule::cancel(&client, &id).await,
             ScheduleAction::Delete { id } => commands::schedule::delete(&client, &id).await,
             ScheduleAction::History { id } => commands::schedule::history(&client, &id).await,
             ScheduleAction::Status => commands::schedule::status(&client).await,
+            ScheduleAction::Export { output } => {
+                commands::schedule::export(&client, output.as_deref()).await
+            }
+            ScheduleAction::Import { file, overwrite } => {
+                commands::schedule::import(&client, &file, overwrite).await
+            }
         },
         Commands::Embedding { action } => match action {
             EmbeddingAction::Status => commands::embedding::status(&client).await,
@@ -639,6 +928,23 @@ async fn main() {
                 limit,
                 before,
             } => commands::channel::messages(&client, &session_id, limit, before.as_deref()).await,
+            ChannelAction::Settings {
+                name,
+                model,
+                autonomy,
+                system_prompt,
+            } => commands::channel::settings(&client, &name, model, autonomy, system_prompt).await,
+            ChannelAction::AllowList { name } => {
+                commands::channel::allow_list(&client, &name).await
+            }
+            ChannelAction::AllowAdd { name, entry } => {
+                commands::channel::allow_add(&client, &name, &entry).await
+            }
+            ChannelAction::AllowRemove {
+                name,
+                entry,
+                allow_all,
+            } => commands::channel::allow_remove(&client, &name, &entry, allow_all).await,
         },
         Commands::Workflow { action } => match action {
             WorkflowAction::List => commands::workflow::list(&client).await,
@@ -682,6 +988,22 @@ async fn main() {
                 commands::wiki::prompt(&client, &action).await
             }
         },
+        Commands::Events { action } => match action {
+            EventsAction::Record { name } => commands::events::record(&client, name).await,
+            EventsAction::Stop => commands::events::stop(&client).await,
+        },
+        Commands::Db { action } => match action {
+            DbAction::Backup { path } => commands::db::backup(&client, &path).await,
+            DbAction::Restore { path, yes } => commands::db::restore(&client, &path, yes).await,
+        },
+        Commands::Token { action } => match action {
+            TokenAction::List => commands::token::list(&client).await,
+            TokenAction::Mint { name, scope } => {
+                commands::token::mint(&client, &name, &scope).await
+            }
+            TokenAction::Revoke { id } => commands::token::revoke(&client, &id).await,
+        },
+        Commands::McpServer { config } => commands::mcp_server::run(config).await,
         Commands::Onboard => commands::onboard::run(&client).await,
         Commands::Completions { shell } => {
             clap_complete::generate(shell, &mut Cli::command(), "zenii", &mut std::io::stdout());
@@ -696,6 +1018,27 @@ async fn main() {
                 let display_name = name.as_deref().unwrap_or(&id);
                 commands::provider::add(&client, &id, display_name, &base_url).await
             }
+            ProviderAction::Update {
+                provider_id,
+                base_url,
+                headers,
+                query,
+                secret_headers,
+                clear_extras,
+            } => {
+                commands::provider::update(
+                    &client,
+                    &provider_id,
+                    commands::provider::ProviderUpdate {
+                        base_url,
+                        headers,
+                        query,
+                        secret_headers,
+                        clear_extras,
+                    },
+                )
+                .await
+            }
             ProviderAction::Remove { provider_id } => {
                 commands::provider::remove(&client, &provider_id).await
             }
@@ -703,12 +1046,33 @@ async fn main() {
                 provider_id,
                 model_id,
             } => commands::provider::set_default(&client, &provider_id, &model_id).await,
+            ProviderAction::Reload => commands::provider::reload(&client).await,
         },
     };
 
     if let Err(e) = result {
         eprintln!("Error: {e}");
-        process::exit(1);
+        process::exit(exit_code_for(&e));
+    }
+}
+
+/// Exit status for a failed command, so scripts can tell failures apart.
+/// Daemon errors carry their `[ZENII_*]` code at the start of the message.
+fn exit_code_for(err: &str) -> i32 {
+    let code = err
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .map(|(code, _)| code);
+    match code {
+        Some("ZENII_AGENT_AUTH" | "ZENII_AUTH_REQUIRED") => 3,
+        Some("ZENII_BUDGET_EXCEEDED") => 4,
+        Some("ZENII_AGENT_MAX_TURNS") => 5,
+        Some("ZENII_AGENT_CONTEXT_LENGTH") => 6,
+        Some("ZENII_AGENT_TOOL_ERROR") => 7,
+        Some("ZENII_AGENT_CANCELLED") => 130,
+        Some(_) => 1,
+        None if err.contains("error sending request") || err.contains("Connection refused") => 2,
+        None => 1,
     }
 }
 
@@ -803,15 +1167,64 @@ mod tests {
                 prompt,
                 session,
                 model,
+                response_format,
             } => {
                 assert_eq!(prompt, "hello world");
                 assert!(session.is_none());
                 assert!(model.is_none());
+                assert!(response_format.is_none());
             }
             _ => panic!("expected Run"),
         }
     }
 
+    #[test]
+    fn parse_ask_defaults() {
+        let cli = parse(&["zenii", "ask", "summarize foo.txt"]);
+        assert!(!cli.json);
+        match cli.command {
+            Commands::Ask {
+                prompt,
+                resume,
+                model,
+                auto,
+            } => {
+                assert_eq!(prompt, "summarize foo.txt");
+                assert!(resume.is_none());
+                assert!(model.is_none());
+                assert!(!auto);
+            }
+            _ => panic!("expected Ask"),
+        }
+    }
+
+    #[test]
+    fn parse_ask_with_json_resume_auto() {
+        let cli = parse(&["zenii", "ask", "hi", "--resume", "abc", "--auto", "--json"]);
+        assert!(cli.json);
+        match cli.command {
+            Commands::Ask { resume, auto, .. } => {
+                assert_eq!(resume.as_deref(), Some("abc"));
+                assert!(auto);
+            }
+            _ => panic!("expected Ask"),
+        }
+    }
+
+    #[test]
+    fn parse_run_with_response_format() {
+        let cli = parse(&["zenii", "run", "hi", "--response-format", "schema.json"]);
+        match cli.command {
+            Commands::Run {
+                response_format, ..
+            } => assert_eq!(
+                response_format,
+                Some(std::path::PathBuf::from("schema.json"))
+            ),
+            _ => panic!("expected Run"),
+        }
+    }
+
     #[test]
     fn parse_memory_search() {
         let cli = parse(&["zenii", "memory", "search", "rust", "--limit", "5"]);
@@ -979,6 +1392,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_mcp_server_with_config() {
+        let cli = parse(&["zenii", "mcp-server", "--config", "/tmp/zenii.toml"]);
+        match cli.command {
+            Commands::McpServer { config } => {
+                assert_eq!(config, Some(std::path::PathBuf::from("/tmp/zenii.toml")));
+            }
+            _ => panic!("expected McpServer"),
+        }
+    }
+
+    #[test]
+    fn parse_events_record_with_name() {
+        let cli = parse(&["zenii", "events", "record", "--name", "trace.jsonl"]);
+        match cli.command {
+            Commands::Events {
+                action: EventsAction::Record { name },
+            } => assert_eq!(name.as_deref(), Some("trace.jsonl")),
+            _ => panic!("expected Events Record"),
+        }
+    }
+
+    #[test]
+    fn parse_db_restore_with_yes() {
+        let cli = parse(&["zenii", "db", "restore", "/tmp/zenii.db", "--yes"]);
+        match cli.command {
+            Commands::Db {
+                action: DbAction::Restore { path, yes },
+            } => {
+                assert_eq!(path, "/tmp/zenii.db");
+                assert!(yes);
+            }
+            _ => panic!("expected Db Restore"),
+        }
+    }
+
+    #[test]
+    fn parse_token_mint_with_scope() {
+        let cli = parse(&["zenii", "token", "mint", "monitoring", "--scope", "read"]);
+        match cli.command {
+            Commands::Token {
+                action: TokenAction::Mint { name, scope },
+            } => {
+                assert_eq!(name, "monitoring");
+                assert_eq!(scope, "read");
+            }
+            _ => panic!("expected Token Mint"),
+        }
+        assert!(Cli::try_parse_from(["zenii", "token", "mint", "x", "--scope", "root"]).is_err());
+    }
+
+    #[test]
+    fn parse_provider_reload() {
+        let cli = parse(&["zenii", "provider", "reload"]);
+        assert!(matches!(
+            cli.command,
+            Commands::Provider {
+                action: ProviderAction::Reload
+            }
+        ));
+    }
+
     #[test]
     fn parse_completions_bash() {
         let cli = parse(&["zenii", "completions", "bash"]);
@@ -997,6 +1472,21 @@ mod tests {
         ));
     }
 
+    #[cfg(feature = "scheduler")]
+    #[test]
+    fn parse_schedule_import_overwrite() {
+        let cli = parse(&["zenii", "schedule", "import", "jobs.json", "--overwrite"]);
+        match cli.command {
+            Commands::Schedule {
+                action: ScheduleAction::Import { file, overwrite },
+            } => {
+                assert_eq!(file, "jobs.json");
+                assert!(overwrite);
+            }
+            _ => panic!("expected Schedule Import"),
+        }
+    }
+
     #[cfg(feature = "scheduler")]
     #[test]
     fn parse_schedule_status() {
@@ -1046,6 +1536,108 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "scheduler")]
+    #[test]
+    fn parse_schedule_create_channel_notify() {
+        let cli = parse(&[
+            "zenii",
+            "schedule",
+            "create",
+            "standup",
+            "--cron-expr",
+            "0 9 * * 1-5",
+            "--schedule-type",
+            "cron",
+            "--notify-channel",
+            "telegram",
+            "--notify-to",
+            "123",
+            "--message",
+            "standup!",
+        ]);
+        match cli.command {
+            Commands::Schedule {
+                action:
+                    ScheduleAction::Create {
+                        notify_channel,
+                        notify_to,
+                        message,
+                        ..
+                    },
+            } => {
+                assert_eq!(notify_channel.as_deref(), Some("telegram"));
+                assert_eq!(notify_to.as_deref(), Some("123"));
+                assert_eq!(message.as_deref(), Some("standup!"));
+            }
+            _ => panic!("expected Schedule Create"),
+        }
+
+        // A channel without a recipient is rejected
+        assert!(
+            Cli::try_parse_from([
+                "zenii",
+                "schedule",
+                "create",
+                "standup",
+                "--notify-channel",
+                "telegram",
+            ])
+            .is_err()
+        );
+    }
+
+    #[cfg(feature = "scheduler")]
+    #[test]
+    fn parse_schedule_create_cron_with_tz() {
+        let cli = parse(&[
+            "zenii",
+            "schedule",
+            "create",
+            "standup",
+            "--schedule-type",
+            "cron",
+            "--cron-expr",
+            "0 9 * * 1-5",
+            "--tz",
+            "America/New_York",
+        ]);
+        match cli.command {
+            Commands::Schedule {
+                action:
+                    ScheduleAction::Create {
+                        schedule_type,
+                        cron_expr,
+                        tz,
+                        ..
+                    },
+            } => {
+                assert_eq!(schedule_type, "cron");
+                assert_eq!(cron_expr.as_deref(), Some("0 9 * * 1-5"));
+                assert_eq!(tz.as_deref(), Some("America/New_York"));
+            }
+            _ => panic!("expected Schedule Create"),
+        }
+    }
+
+    #[cfg(feature = "scheduler")]
+    #[test]
+    fn parse_schedule_create_once_at() {
+        let cli = parse(&[
+            "zenii",
+            "schedule",
+            "create",
+            "launch",
+            "--at",
+            "2025-06-01T09:00:00Z",
+        ]);
+        match cli.command {
+            Commands::Schedule {
+                action: ScheduleAction::Create { at, .. },
+            } => assert_eq!(at.as_deref(), Some("2025-06-01T09:00:00Z")),
+            _ => panic!("expected Schedule Create"),
+        }
+    }
+
     #[cfg(feature = "scheduler")]
     #[test]
     fn parse_schedule_toggle() {
@@ -1060,6 +1652,18 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "scheduler")]
+    #[test]
+    fn parse_schedule_cancel() {
+        let cli = parse(&["zenii", "schedule", "cancel", "job-123"]);
+        match cli.command {
+            Commands::Schedule {
+                action: ScheduleAction::Cancel { id },
+            } => assert_eq!(id, "job-123"),
+            _ => panic!("expected Schedule Cancel"),
+        }
+    }
+
     #[cfg(feature = "scheduler")]
     #[test]
     fn parse_schedule_delete() {
@@ -1162,6 +1766,55 @@ mod tests {
         }
     }
 
+    // IN.22 — parse channel settings update
+    #[cfg(feature = "channels")]
+    #[test]
+    fn parse_channel_settings() {
+        let cli = parse(&[
+            "zenii",
+            "channel",
+            "settings",
+            "telegram",
+            "--model",
+            "openai:gpt-4o-mini",
+            "--autonomy",
+            "readonly",
+        ]);
+        match cli.command {
+            Commands::Channel {
+                action:
+                    ChannelAction::Settings {
+                        name,
+                        model,
+                        autonomy,
+                        system_prompt,
+                    },
+            } => {
+                assert_eq!(name, "telegram");
+                assert_eq!(model.as_deref(), Some("openai:gpt-4o-mini"));
+                assert_eq!(autonomy.as_deref(), Some("readonly"));
+                assert!(system_prompt.is_none());
+            }
+            _ => panic!("expected Channel Settings"),
+        }
+    }
+
+    // parse channel allow-add
+    #[cfg(feature = "channels")]
+    #[test]
+    fn parse_channel_allow_add() {
+        let cli = parse(&["zenii", "channel", "allow-add", "telegram", "12345"]);
+        match cli.command {
+            Commands::Channel {
+                action: ChannelAction::AllowAdd { name, entry },
+            } => {
+                assert_eq!(name, "telegram");
+                assert_eq!(entry, "12345");
+            }
+            _ => panic!("expected Channel AllowAdd"),
+        }
+    }
+
     // 9.0 — parse plugin list
     #[test]
     fn parse_plugin_list() {
@@ -1423,4 +2076,45 @@ mod tests {
             _ => panic!("expected Workflow Cancel"),
         }
     }
+
+    #[test]
+    fn exit_codes_follow_error_code() {
+        assert_eq!(exit_code_for("[ZENII_AGENT_AUTH] bad key"), 3);
+        assert_eq!(exit_code_for("[ZENII_BUDGET_EXCEEDED] spent"), 4);
+        assert_eq!(exit_code_for("[ZENII_AGENT_CANCELLED] stopped"), 130);
+        assert_eq!(exit_code_for("[ZENII_NOT_FOUND] no such session"), 1);
+        assert_eq!(
+            exit_code_for("error sending request for url (http://127.0.0.1:18981/health)"),
+            2
+        );
+        assert_eq!(exit_code_for("no prompt given"), 1);
+    }
+
+    #[test]
+    fn parse_watch() {
+        let cli = parse(&[
+            "zenii",
+            "watch",
+            "src",
+            "--prompt",
+            "Review {files}",
+            "--debounce-ms",
+            "1000",
+        ]);
+        match cli.command {
+            Commands::Watch {
+                path,
+                prompt,
+                debounce_ms,
+                auto,
+                ..
+            } => {
+                assert_eq!(path, std::path::PathBuf::from("src"));
+                assert_eq!(prompt, "Review {files}");
+                assert_eq!(debounce_ms, 1000);
+                assert!(!auto);
+            }
+            _ => panic!("expected Watch"),
+        }
+    }
 }
@@ -10,10 +10,13 @@ use rig::completion::ToolDefinition;
 use rig::tool::{ToolDyn, ToolError};
 use rig::wasm_compat::WasmBoxedFuture;
 use serde::{Deserialize, Serialize};
-use tokio::sync::broadcast;
+use tokio::sync::{Semaphore, SemaphorePermit, broadcast};
 
 use crate::ai::compression::ToolOutputCompressor;
-use crate::tools::Tool;
+use crate::ai::reflection::ToolFailureTracker;
+use crate::ai::tool_parser::parse_arguments;
+use crate::ai::tool_results::{ToolResultStore, shared_prefix_len, truncation_note};
+use crate::tools::{Tool, ToolResult};
 
 /// Cached result from a tool call.
 #[derive(Debug, Clone)]
@@ -41,6 +44,8 @@ pub struct ToolCallCache {
     per_tool_last: DashMap<String, CachedResult>,
     /// Per-tool call caps. Only tools listed here are limited.
     tool_call_limits: HashMap<String, usize>,
+    /// Consecutive-failure tracking for the reflection step.
+    failures: ToolFailureTracker,
 }
 
 impl Default for ToolCallCache {
@@ -51,6 +56,7 @@ impl Default for ToolCallCache {
             per_tool_counts: DashMap::new(),
             per_tool_last: DashMap::new(),
             tool_call_limits: HashMap::new(),
+            failures: ToolFailureTracker::default(),
         }
     }
 }
@@ -68,6 +74,16 @@ impl ToolCallCache {
         }
     }
 
+    /// Track repeated tool failures and prompt the agent to reflect on them.
+    pub fn with_failure_tracker(mut self, failures: ToolFailureTracker) -> Self {
+        self.failures = failures;
+        self
+    }
+
+    pub fn failures(&self) -> &ToolFailureTracker {
+        &self.failures
+    }
+
     /// Compute cache key from tool name and args JSON string.
     /// Canonicalizes JSON (sorted keys, normalized whitespace) before hashing.
     pub fn cache_key(tool_name: &str, args: &str) -> u64 {
@@ -145,6 +161,41 @@ pub struct ToolCallEvent {
     pub phase: ToolCallPhase,
 }
 
+impl ToolCallEvent {
+    /// Session-scoped bus event for this tool event. Approval phases map to
+    /// `None`; approvals are published on the bus by the adapter itself.
+    pub fn to_app_event(&self, session_id: &str) -> Option<crate::event_bus::AppEvent> {
+        use crate::event_bus::AppEvent;
+
+        let (output, success, duration_ms) = match &self.phase {
+            ToolCallPhase::Started { args } => {
+                return Some(AppEvent::AgentToolStarted {
+                    session_id: session_id.to_string(),
+                    call_id: self.call_id.clone(),
+                    tool_name: self.tool_name.clone(),
+                    args: args.clone(),
+                });
+            }
+            ToolCallPhase::Completed {
+                output,
+                success,
+                duration_ms,
+                ..
+            } => (output, *success, *duration_ms),
+            ToolCallPhase::Cached { output, success } => (output, *success, 0),
+            _ => return None,
+        };
+        Some(AppEvent::AgentToolCompleted {
+            session_id: session_id.to_string(),
+            call_id: self.call_id.clone(),
+            tool_name: self.tool_name.clone(),
+            output: output.clone(),
+            success,
+            duration_ms,
+        })
+    }
+}
+
 /// Phase of a tool call lifecycle.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "phase")]
@@ -156,6 +207,9 @@ pub enum ToolCallPhase {
         output: String,
         success: bool,
         duration_ms: u64,
+        /// Id of the untruncated output in the `ToolResultStore`, when `output` was compressed.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        full_output_id: Option<String>,
     },
     #[serde(rename = "cached")]
     Cached { output: String, success: bool },
@@ -165,14 +219,45 @@ pub enum ToolCallPhase {
         reason: String,
         risk_level: String,
         timeout_secs: u64,
+        /// The command or arguments being approved.
+        #[serde(default)]
+        args_summary: String,
     },
     #[serde(rename = "approval_resolved")]
     ApprovalResolved {
         approval_id: String,
         decision: String,
+        /// Surface that decided (`ws`, `http`, `slack:<user>`), or `timeout`.
+        #[serde(default)]
+        resolved_by: String,
     },
 }
 
+/// Limits how tool calls from one model response overlap, shared by all
+/// adapters of an agent. Read-only calls take one permit each, so up to
+/// `capacity` run at once; any other call takes every permit and runs alone.
+pub struct ToolExecGate {
+    permits: Semaphore,
+    capacity: u32,
+}
+
+impl ToolExecGate {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.clamp(1, 1024) as u32;
+        Self {
+            permits: Semaphore::new(capacity as usize),
+            capacity,
+        }
+    }
+
+    /// Wait for a slot; `exclusive` waits until no other call is running.
+    /// The semaphore is never closed, so this only yields `None` in theory.
+    pub async fn acquire(&self, exclusive: bool) -> Option<SemaphorePermit<'_>> {
+        let n = if exclusive { self.capacity } else { 1 };
+        self.permits.acquire_many(n).await.ok()
+    }
+}
+
 /// Bridges a Zenii `Tool` trait object to rig-core's `ToolDyn` trait,
 /// allowing Zenii tools to be used with rig agents.
 pub struct RigToolAdapter {
@@ -183,8 +268,14 @@ pub struct RigToolAdapter {
     event_bus: Option<Arc<dyn crate::event_bus::EventBus>>,
     surface: String,
     approval_timeout_secs: u64,
+    unattended_decision: crate::security::approval::UnattendedDecision,
     permission_state: crate::security::permissions::PermissionState,
     compressor: ToolOutputCompressor,
+    result_store: Option<Arc<ToolResultStore>>,
+    gate: Arc<ToolExecGate>,
+    audit: Option<crate::security::audit::AuditSink>,
+    /// Execution limit; the call is abandoned and reported as failed when exceeded.
+    timeout: Option<std::time::Duration>,
 }
 
 impl RigToolAdapter {
@@ -196,9 +287,14 @@ impl RigToolAdapter {
             approval_broker: None,
             event_bus: None,
             surface: "desktop".into(),
-            approval_timeout_secs: 120,
+            approval_timeout_secs: 300,
+            unattended_decision: crate::security::approval::UnattendedDecision::Deny,
             permission_state: crate::security::permissions::PermissionState::Allowed,
             compressor: ToolOutputCompressor::default(),
+            result_store: None,
+            gate: Arc::new(ToolExecGate::new(1)),
+            audit: None,
+            timeout: None,
         }
     }
 
@@ -211,9 +307,14 @@ impl RigToolAdapter {
             approval_broker: None,
             event_bus: None,
             surface: "desktop".into(),
-            approval_timeout_secs: 120,
+            approval_timeout_secs: 300,
+            unattended_decision: crate::security::approval::UnattendedDecision::Deny,
             permission_state: crate::security::permissions::PermissionState::Allowed,
             compressor: ToolOutputCompressor::default(),
+            result_store: None,
+            gate: Arc::new(ToolExecGate::new(1)),
+            audit: None,
+            timeout: None,
         }
     }
 
@@ -223,6 +324,17 @@ impl RigToolAdapter {
         self
     }
 
+    /// Apply the configured execution limit (builder pattern). Tools that
+    /// enforce their own limit are left unwrapped.
+    pub fn with_timeout(mut self, config: &crate::config::AppConfig) -> Self {
+        self.timeout = if self.tool.enforces_timeout() {
+            None
+        } else {
+            crate::security::policy::tool_timeout(config, self.tool.name())
+        };
+        self
+    }
+
     /// Attach an approval broker for interactive tool approval.
     pub fn with_approval(
         mut self,
@@ -238,12 +350,67 @@ impl RigToolAdapter {
         self
     }
 
+    /// Decide what a timed-out approval means on unattended surfaces (builder pattern).
+    pub fn with_unattended_decision(
+        mut self,
+        decision: crate::security::approval::UnattendedDecision,
+    ) -> Self {
+        self.unattended_decision = decision;
+        self
+    }
+
     /// Set the permission state for this adapter (builder pattern).
     pub fn with_permission(mut self, state: crate::security::permissions::PermissionState) -> Self {
         self.permission_state = state;
         self
     }
 
+    /// Keep untruncated outputs of compressed results in `store` (builder pattern).
+    pub fn with_result_store(mut self, store: Arc<ToolResultStore>) -> Self {
+        self.result_store = Some(store);
+        self
+    }
+
+    /// Share an execution gate with the other adapters of an agent (builder pattern).
+    pub fn with_gate(mut self, gate: Arc<ToolExecGate>) -> Self {
+        self.gate = gate;
+        self
+    }
+
+    /// Write an audit record for every call this adapter runs (builder pattern).
+    pub fn with_audit(mut self, sink: Option<crate::security::audit::AuditSink>) -> Self {
+        self.audit = sink;
+        self
+    }
+
+    /// Record a call outcome for the reflection step; returns the prompt to
+    /// append to the failure once the same error keeps repeating.
+    fn reflect(&self, tool_name: &str, success: bool, error: &str) -> Option<String> {
+        self.cache
+            .as_ref()
+            .and_then(|cache| cache.failures().record(tool_name, success, error))
+    }
+
+    /// Send a tool call event to listeners and write its audit record.
+    /// Auditing does not depend on a listener being attached.
+    fn emit(&self, event: ToolCallEvent) {
+        if let Some(sink) = &self.audit {
+            match &event.phase {
+                ToolCallPhase::Started { args } => {
+                    sink.tool_started(&event.call_id, &event.tool_name, args)
+                }
+                ToolCallPhase::Completed { success, .. }
+                | ToolCallPhase::Cached { success, .. } => {
+                    sink.tool_finished(&event.call_id, &event.tool_name, *success)
+                }
+                _ => {}
+            }
+        }
+        if let Some(ref tx) = self.event_tx {
+            let _ = tx.send(event);
+        }
+    }
+
     /// Attach a dedup cache to this adapter (builder pattern).
     pub fn with_cache(mut self, cache: Arc<ToolCallCache>) -> Self {
         self.cache = Some(cache);
@@ -253,11 +420,21 @@ impl RigToolAdapter {
     /// Convert a list of Zenii tools into boxed rig ToolDyn objects.
     pub fn from_tools(
         tools: &[Arc<dyn Tool>],
+        audit: Option<crate::security::audit::AuditSink>,
         config: &crate::config::AppConfig,
     ) -> Vec<Box<dyn ToolDyn>> {
+        let gate = Arc::new(ToolExecGate::new(config.agent_tool_concurrency));
         tools
             .iter()
-            .map(|t| Box::new(Self::new(Arc::clone(t)).with_compressor(config)) as Box<dyn ToolDyn>)
+            .map(|t| {
+                Box::new(
+                    Self::new(Arc::clone(t))
+                        .with_compressor(config)
+                        .with_timeout(config)
+                        .with_gate(Arc::clone(&gate))
+                        .with_audit(audit.clone()),
+                ) as Box<dyn ToolDyn>
+            })
             .collect()
     }
 
@@ -267,11 +444,16 @@ impl RigToolAdapter {
         tx: broadcast::Sender<ToolCallEvent>,
         config: &crate::config::AppConfig,
     ) -> Vec<Box<dyn ToolDyn>> {
+        let gate = Arc::new(ToolExecGate::new(config.agent_tool_concurrency));
         tools
             .iter()
             .map(|t| {
-                Box::new(Self::new_with_events(Arc::clone(t), tx.clone()).with_compressor(config))
-                    as Box<dyn ToolDyn>
+                Box::new(
+                    Self::new_with_events(Arc::clone(t), tx.clone())
+                        .with_compressor(config)
+                        .with_timeout(config)
+                        .with_gate(Arc::clone(&gate)),
+                ) as Box<dyn ToolDyn>
             })
             .collect()
     }
@@ -283,12 +465,15 @@ impl RigToolAdapter {
         cache: Arc<ToolCallCache>,
         config: &crate::config::AppConfig,
     ) -> Vec<Box<dyn ToolDyn>> {
+        let gate = Arc::new(ToolExecGate::new(config.agent_tool_concurrency));
         tools
             .iter()
             .map(|t| {
                 Box::new(
                     Self::new_with_events(Arc::clone(t), tx.clone())
                         .with_compressor(config)
+                        .with_timeout(config)
+                        .with_gate(Arc::clone(&gate))
                         .with_cache(Arc::clone(&cache)),
                 ) as Box<dyn ToolDyn>
             })
@@ -299,26 +484,34 @@ impl RigToolAdapter {
     pub fn from_tools_with_cache(
         tools: &[Arc<dyn Tool>],
         cache: Arc<ToolCallCache>,
+        audit: Option<crate::security::audit::AuditSink>,
         config: &crate::config::AppConfig,
     ) -> Vec<Box<dyn ToolDyn>> {
+        let gate = Arc::new(ToolExecGate::new(config.agent_tool_concurrency));
         tools
             .iter()
             .map(|t| {
                 Box::new(
                     Self::new(Arc::clone(t))
                         .with_compressor(config)
-                        .with_cache(Arc::clone(&cache)),
+                        .with_timeout(config)
+                        .with_gate(Arc::clone(&gate))
+                        .with_cache(Arc::clone(&cache))
+                        .with_audit(audit.clone()),
                 ) as Box<dyn ToolDyn>
             })
             .collect()
     }
 
-    /// Convert tools with full configuration: events, cache, approval, and per-tool permissions.
+    /// Convert tools with full configuration: events, cache, approval, auditing
+    /// and per-tool permissions.
     #[allow(clippy::too_many_arguments)]
     pub fn from_tools_full(
         tools: &[Arc<dyn Tool>],
         tx: broadcast::Sender<ToolCallEvent>,
         cache: Option<Arc<ToolCallCache>>,
+        result_store: Option<Arc<ToolResultStore>>,
+        audit: Option<crate::security::audit::AuditSink>,
         broker: Option<Arc<crate::security::approval::ApprovalBroker>>,
         event_bus: Option<Arc<dyn crate::event_bus::EventBus>>,
         surface: &str,
@@ -326,6 +519,7 @@ impl RigToolAdapter {
         permissions: &crate::security::permissions::ToolPermissions,
         config: &crate::config::AppConfig,
     ) -> Vec<Box<dyn ToolDyn>> {
+        let gate = Arc::new(ToolExecGate::new(config.agent_tool_concurrency));
         tools
             .iter()
             .map(|t| {
@@ -337,19 +531,27 @@ impl RigToolAdapter {
                 );
                 let mut adapter = Self::new_with_events(Arc::clone(t), tx.clone())
                     .with_compressor(config)
-                    .with_permission(perm);
+                    .with_timeout(config)
+                    .with_permission(perm)
+                    .with_gate(Arc::clone(&gate))
+                    .with_audit(audit.clone());
                 if let Some(ref cache) = cache {
                     adapter = adapter.with_cache(Arc::clone(cache));
                 }
+                if let Some(ref store) = result_store {
+                    adapter = adapter.with_result_store(Arc::clone(store));
+                }
                 if let Some(ref broker) = broker
                     && let Some(ref bus) = event_bus
                 {
-                    adapter = adapter.with_approval(
-                        Arc::clone(broker),
-                        Arc::clone(bus),
-                        surface,
-                        timeout_secs,
-                    );
+                    adapter = adapter
+                        .with_approval(Arc::clone(broker), Arc::clone(bus), surface, timeout_secs)
+                        .with_unattended_decision(
+                            crate::security::approval::UnattendedDecision::parse(
+                                &config.approval_unattended_decision,
+                            )
+                            .unwrap_or_default(),
+                        );
                 }
                 Box::new(adapter) as Box<dyn ToolDyn>
             })
@@ -374,53 +576,83 @@ impl ToolDyn for RigToolAdapter {
 
     fn call<'a>(&'a self, args: String) -> WasmBoxedFuture<'a, Result<String, ToolError>> {
         Box::pin(async move {
-            let args_value: serde_json::Value =
-                serde_json::from_str(&args).map_err(ToolError::JsonError)?;
-
             let call_id = uuid::Uuid::new_v4().to_string();
             let tool_name = self.tool.name().to_string();
 
+            // Malformed arguments go back to the model as a tool error it can
+            // correct, rather than failing the call silently.
+            let args_value = match parse_arguments("native", &tool_name, &args) {
+                Ok(value) => value,
+                Err(e) => {
+                    tracing::warn!("Rejected tool call: {e}");
+                    let message = e.to_tool_message();
+                    let note = self.reflect(&tool_name, false, &message);
+                    let output = serde_json::to_string(&ToolResult::err(message))
+                        .map_err(ToolError::JsonError)?;
+                    self.emit(ToolCallEvent {
+                        call_id: call_id.clone(),
+                        tool_name: tool_name.clone(),
+                        phase: ToolCallPhase::Started {
+                            args: serde_json::Value::String(args.clone()),
+                        },
+                    });
+                    self.emit(ToolCallEvent {
+                        call_id,
+                        tool_name,
+                        phase: ToolCallPhase::Completed {
+                            output: output.clone(),
+                            success: false,
+                            duration_ms: 0,
+                            full_output_id: None,
+                        },
+                    });
+                    return Ok(append_note(output, note));
+                }
+            };
+
             // Check cache first (before emitting Started)
             if let Some(ref cache) = self.cache {
                 let key = ToolCallCache::cache_key(&tool_name, &args);
                 // 1. Exact-match dedup (same tool + same canonical args)
                 if let Some(cached) = cache.get(key) {
-                    if let Some(ref tx) = self.event_tx {
-                        let _ = tx.send(ToolCallEvent {
-                            call_id,
-                            tool_name,
-                            phase: ToolCallPhase::Cached {
-                                output: cached.output.clone(),
-                                success: cached.success,
-                            },
-                        });
-                    }
+                    self.emit(ToolCallEvent {
+                        call_id,
+                        tool_name: tool_name.clone(),
+                        phase: ToolCallPhase::Cached {
+                            output: cached.output.clone(),
+                            success: cached.success,
+                        },
+                    });
+                    let note = cache
+                        .failures()
+                        .record(&tool_name, cached.success, &cached.output);
                     return if cached.is_ok {
-                        Ok(cached.output)
+                        Ok(append_note(cached.output, note))
                     } else {
                         Err(ToolError::ToolCallError(Box::new(std::io::Error::other(
-                            cached.output,
+                            append_note(cached.output, note),
                         ))))
                     };
                 }
 
                 // 2. Per-tool limit check (different args but same tool over limit)
                 if let Some(last) = cache.check_per_tool_limit(&tool_name) {
-                    if let Some(ref tx) = self.event_tx {
-                        let _ = tx.send(ToolCallEvent {
-                            call_id,
-                            tool_name,
-                            phase: ToolCallPhase::Cached {
-                                output: last.output.clone(),
-                                success: last.success,
-                            },
-                        });
-                    }
+                    self.emit(ToolCallEvent {
+                        call_id,
+                        tool_name: tool_name.clone(),
+                        phase: ToolCallPhase::Cached {
+                            output: last.output.clone(),
+                            success: last.success,
+                        },
+                    });
+                    let note = cache
+                        .failures()
+                        .record(&tool_name, last.success, &last.output);
                     return if last.is_ok {
-                        Ok(last.output)
+                        Ok(append_note(last.output, note))
                     } else {
                         Err(ToolError::ToolCallError(Box::new(std::io::Error::other(
-                            last.output,
+                            append_note(last.output, note),
                         ))))
                     };
                 }
@@ -438,6 +670,8 @@ impl ToolDyn for RigToolAdapter {
                 )),
                 _ => self.tool.needs_approval(&args_value),
             };
+            // Side-effecting or approval-gated calls never overlap another tool
+            let exclusive = !self.tool.is_read_only() || approval_reason.is_some();
 
             if let Some(ref broker) = self.approval_broker
                 && let Some(reason) = approval_reason
@@ -478,18 +712,17 @@ impl ToolDyn for RigToolAdapter {
                         let approval_id = uuid::Uuid::new_v4().to_string();
 
                         // Emit approval requested event via tool events
-                        if let Some(ref tx) = self.event_tx {
-                            let _ = tx.send(ToolCallEvent {
-                                call_id: call_id.clone(),
-                                tool_name: tool_name.clone(),
-                                phase: ToolCallPhase::ApprovalRequested {
-                                    approval_id: approval_id.clone(),
-                                    reason: reason.clone(),
-                                    risk_level: risk_level.clone(),
-                                    timeout_secs: self.approval_timeout_secs,
-                                },
-                            });
-                        }
+                        self.emit(ToolCallEvent {
+                            call_id: call_id.clone(),
+                            tool_name: tool_name.clone(),
+                            phase: ToolCallPhase::ApprovalRequested {
+                                approval_id: approval_id.clone(),
+                                reason: reason.clone(),
+                                risk_level: risk_level.clone(),
+                                timeout_secs: self.approval_timeout_secs,
+                                args_summary: args_summary.clone(),
+                            },
+                        });
 
                         // Also publish to event bus for notifications WS
                         if let Some(ref bus) = self.event_bus {
@@ -501,34 +734,71 @@ impl ToolDyn for RigToolAdapter {
                                 risk_level: risk_level.clone(),
                                 reason: reason.clone(),
                                 timeout_secs: self.approval_timeout_secs,
+                                session_id: crate::security::audit::current_session(),
                             });
                         }
 
                         // Wait for decision with timeout
                         let rx = broker.register(&approval_id);
                         let timeout = std::time::Duration::from_secs(self.approval_timeout_secs);
-                        let decision = tokio::select! {
-                            result = rx => {
-                                result.unwrap_or(crate::security::approval::ApprovalDecision::Deny)
-                            }
+                        let (decision, resolved_by) = tokio::select! {
+                            result = rx => match result {
+                                Ok(r) => (r.decision, r.resolved_by),
+                                Err(_) => (
+                                    crate::security::approval::ApprovalDecision::Deny,
+                                    "cancelled".to_string(),
+                                ),
+                            },
                             _ = tokio::time::sleep(timeout) => {
                                 broker.cancel(&approval_id);
-                                crate::security::approval::ApprovalDecision::Deny
+                                (
+                                    crate::security::approval::ApprovalDecision::Deny,
+                                    "timeout".to_string(),
+                                )
                             }
                         };
 
+                        // Nobody is around to answer on scheduler/channel surfaces, so
+                        // a timeout there may skip the tool instead of failing it.
+                        let skip = resolved_by == "timeout"
+                            && self.unattended_decision
+                                == crate::security::approval::UnattendedDecision::Skip
+                            && !crate::security::approval::is_interactive_surface(&self.surface);
+                        let decision_label = if skip {
+                            crate::security::approval::UnattendedDecision::Skip.as_str()
+                        } else {
+                            decision.as_str()
+                        };
+
                         // Emit resolution event
-                        if let Some(ref tx) = self.event_tx {
-                            let _ = tx.send(ToolCallEvent {
+                        self.emit(ToolCallEvent {
+                            call_id: call_id.clone(),
+                            tool_name: tool_name.clone(),
+                            phase: ToolCallPhase::ApprovalResolved {
+                                approval_id: approval_id.clone(),
+                                decision: decision_label.to_string(),
+                                resolved_by: resolved_by.clone(),
+                            },
+                        });
+                        if let Some(ref bus) = self.event_bus {
+                            let _ = bus.publish(crate::event_bus::AppEvent::ApprovalResolved {
+                                approval_id: approval_id.clone(),
                                 call_id: call_id.clone(),
                                 tool_name: tool_name.clone(),
-                                phase: ToolCallPhase::ApprovalResolved {
-                                    approval_id: approval_id.clone(),
-                                    decision: decision.as_str().to_string(),
-                                },
+                                decision: decision_label.to_string(),
+                                resolved_by,
                             });
                         }
 
+                        if skip {
+                            let skipped = ToolResult::err(format!(
+                                "Tool '{tool_name}' skipped: no approval received within {}s. \
+                                 Continue without it.",
+                                self.approval_timeout_secs
+                            ));
+                            return serde_json::to_string(&skipped).map_err(ToolError::JsonError);
+                        }
+
                         match decision {
                             crate::security::approval::ApprovalDecision::Approve => {
                                 broker.cache_session(&tool_name, decision);
@@ -556,25 +826,45 @@ impl ToolDyn for RigToolAdapter {
                 }
             }
 
+            let _permit = self.gate.acquire(exclusive).await;
+
             // Emit Started event (cache miss)
-            if let Some(ref tx) = self.event_tx {
-                let _ = tx.send(ToolCallEvent {
-                    call_id: call_id.clone(),
-                    tool_name: tool_name.clone(),
-                    phase: ToolCallPhase::Started {
-                        args: args_value.clone(),
-                    },
-                });
-            }
+            self.emit(ToolCallEvent {
+                call_id: call_id.clone(),
+                tool_name: tool_name.clone(),
+                phase: ToolCallPhase::Started {
+                    args: args_value.clone(),
+                },
+            });
 
             let start = Instant::now();
-            let exec_result = self.tool.execute(args_value).await;
+            let exec_result = match self.timeout {
+                Some(limit) => tokio::time::timeout(limit, self.tool.execute(args_value))
+                    .await
+                    .unwrap_or_else(|_| {
+                        Ok(ToolResult::err(format!(
+                            "tool '{tool_name}' timed out after {}s",
+                            limit.as_secs()
+                        )))
+                    }),
+                None => self.tool.execute(args_value).await,
+            };
             let duration_ms = start.elapsed().as_millis() as u64;
 
             match exec_result {
                 Ok(result) => {
                     let raw = serde_json::to_string(&result).map_err(ToolError::JsonError)?;
                     let output = self.compressor.compress(&tool_name, &raw, result.success);
+                    let (output, full_output_id) = match self.result_store {
+                        Some(ref store) if output != raw => {
+                            let offset = shared_prefix_len(&output, &raw);
+                            let total = raw.len();
+                            let id = store.put(&tool_name, raw);
+                            let note = truncation_note(&id, total, offset);
+                            (format!("{output}\n{note}"), Some(id))
+                        }
+                        _ => (output, None),
+                    };
 
                     // Store in cache and record execution
                     if let Some(ref cache) = self.cache {
@@ -590,19 +880,19 @@ impl ToolDyn for RigToolAdapter {
                     }
 
                     // Emit Completed event
-                    if let Some(ref tx) = self.event_tx {
-                        let _ = tx.send(ToolCallEvent {
-                            call_id,
-                            tool_name,
-                            phase: ToolCallPhase::Completed {
-                                output: output.clone(),
-                                success: result.success,
-                                duration_ms,
-                            },
-                        });
-                    }
+                    self.emit(ToolCallEvent {
+                        call_id,
+                        tool_name: tool_name.clone(),
+                        phase: ToolCallPhase::Completed {
+                            output: output.clone(),
+                            success: result.success,
+                            duration_ms,
+                            full_output_id,
+                        },
+                    });
 
-                    Ok(output)
+                    let note = self.reflect(&tool_name, result.success, &result.output);
+                    Ok(append_note(output, note))
                 }
                 Err(e) => {
                     // Store error in cache and record execution
@@ -619,25 +909,37 @@ impl ToolDyn for RigToolAdapter {
                     }
 
                     // Emit Completed with failure
-                    if let Some(ref tx) = self.event_tx {
-                        let _ = tx.send(ToolCallEvent {
-                            call_id,
-                            tool_name,
-                            phase: ToolCallPhase::Completed {
-                                output: e.to_string(),
-                                success: false,
-                                duration_ms,
-                            },
-                        });
+                    self.emit(ToolCallEvent {
+                        call_id,
+                        tool_name: tool_name.clone(),
+                        phase: ToolCallPhase::Completed {
+                            output: e.to_string(),
+                            success: false,
+                            duration_ms,
+                            full_output_id: None,
+                        },
+                    });
+
+                    match self.reflect(&tool_name, false, &e.to_string()) {
+                        Some(note) => Err(ToolError::ToolCallError(Box::new(
+                            std::io::Error::other(format!("{e}\n\n{note}")),
+                        ))),
+                        None => Err(ToolError::ToolCallError(Box::new(e))),
                     }
-
-                    Err(ToolError::ToolCallError(Box::new(e)))
                 }
             }
         })
     }
 }
 
+/// Append a reflection prompt to a tool's output for the model.
+fn append_note(output: String, note: Option<String>) -> String {
+    match note {
+        Some(note) => format!("{output}\n\n{note}"),
+        None => output,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -742,7 +1044,7 @@ mod tests {
             Arc::new(MockTool { name: "tool_a" }),
             Arc::new(MockTool { name: "tool_b" }),
         ];
-        let rig_tools = RigToolAdapter::from_tools(&tools, &config);
+        let rig_tools = RigToolAdapter::from_tools(&tools, None, &config);
 
         assert_eq!(rig_tools.len(), 2);
         assert_eq!(rig_tools[0].name(), "tool_a");
@@ -797,6 +1099,37 @@ mod tests {
         ));
     }
 
+    // TV.3b — An adapter with an audit sink records its calls without a listener
+    #[tokio::test]
+    async fn adapter_writes_to_its_audit_sink() {
+        use crate::security::audit::{AuditQuery, AuditSink, query, session_scope};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let pool = crate::db::init_pool(&dir.path().join("test.db")).unwrap();
+        crate::db::with_db(&pool, |conn| crate::db::run_migrations(conn))
+            .await
+            .unwrap();
+        let tool: Arc<dyn Tool> = Arc::new(MockTool { name: "test" });
+        let adapter = RigToolAdapter::new(tool).with_audit(Some(AuditSink::spawn(pool.clone())));
+
+        session_scope(Some("s1".into()), async {
+            let _ = adapter.call(json!({"input": "hi"}).to_string()).await;
+        })
+        .await;
+
+        let mut rows = Vec::new();
+        for _ in 0..50 {
+            rows = query(&pool, &AuditQuery::default()).await.unwrap();
+            if rows.len() == 2 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        let kinds: Vec<_> = rows.iter().map(|r| r.kind.as_str()).collect();
+        assert_eq!(kinds, ["tool_result", "tool_start"]);
+        assert!(rows.iter().all(|r| r.session_id.as_deref() == Some("s1")));
+    }
+
     // TV.4 — RigToolAdapter with event sender emits Completed with success=false on error
     #[tokio::test]
     async fn adapter_emits_completed_on_error() {
@@ -872,6 +1205,51 @@ mod tests {
         }
     }
 
+    // TV.8 — Truncated output is kept in the result store and referenced by id
+    #[tokio::test]
+    async fn truncated_output_stored_with_id() {
+        let config = crate::config::AppConfig {
+            compression_max_output_chars: 200,
+            ..Default::default()
+        };
+        let store = Arc::new(ToolResultStore::new(std::time::Duration::from_secs(60)));
+        let (tx, mut rx) = broadcast::channel::<ToolCallEvent>(8);
+        let tool: Arc<dyn Tool> = Arc::new(MockTool { name: "test" });
+        let adapter = RigToolAdapter::new_with_events(tool, tx)
+            .with_compressor(&config)
+            .with_result_store(Arc::clone(&store));
+
+        let long_input = "x".repeat(500);
+        let shown = adapter
+            .call(json!({"input": long_input}).to_string())
+            .await
+            .unwrap();
+        let _ = adapter.call(json!({"input": "hi"}).to_string()).await;
+
+        let _started = rx.recv().await.unwrap();
+        let ToolCallPhase::Completed { full_output_id, .. } = rx.recv().await.unwrap().phase else {
+            panic!("expected Completed phase");
+        };
+        let id = full_output_id.unwrap();
+        let stored = store.get(&id).unwrap();
+        let full: ToolResult = serde_json::from_str(&stored.output).unwrap();
+        assert_eq!(full.output, format!("processed: {long_input}"));
+
+        // The model is told where the rest of the output is
+        assert!(
+            shown.ends_with(&format!("handle=\"{id}\" and offset=200 to read more]")),
+            "{shown}"
+        );
+
+        // Output that fits is not stored
+        let _started = rx.recv().await.unwrap();
+        let ToolCallPhase::Completed { full_output_id, .. } = rx.recv().await.unwrap().phase else {
+            panic!("expected Completed phase");
+        };
+        assert!(full_output_id.is_none());
+        assert_eq!(store.len(), 1);
+    }
+
     // TC-D1 — Cache hit returns cached result
     #[tokio::test]
     async fn tc_d1_cache_hit_returns_cached() {
@@ -910,6 +1288,27 @@ mod tests {
         assert_eq!(cache.executions(), 1);
     }
 
+    // TC-D3b — Repeated identical failures get a reflection prompt appended
+    #[tokio::test]
+    async fn tc_d3b_repeated_failures_trigger_reflection() {
+        let cache = Arc::new(
+            ToolCallCache::new().with_failure_tracker(ToolFailureTracker::new(2, false, None)),
+        );
+        let tool: Arc<dyn Tool> = Arc::new(FailingTool);
+        let adapter = RigToolAdapter::new(tool).with_cache(Arc::clone(&cache));
+
+        let first = adapter.call(r#"{"a":1}"#.to_string()).await.unwrap_err();
+        assert!(!first.to_string().contains("Reflection"));
+        let second = adapter.call(r#"{"a":2}"#.to_string()).await.unwrap_err();
+        assert!(
+            second.to_string().contains("last 2 calls to 'failing'"),
+            "{second}"
+        );
+        // The reflection prompt is not cached with the result
+        let key = ToolCallCache::cache_key("failing", r#"{"a":2}"#);
+        assert!(!cache.get(key).unwrap().output.contains("Reflection"));
+    }
+
     // TC-D4 — Cache disabled (no cache attached)
     #[tokio::test]
     async fn tc_d4_no_cache_no_dedup() {
@@ -1180,4 +1579,202 @@ mod tests {
         let k2 = ToolCallCache::cache_key("web_search", r#"{ "query" : "rust" }"#);
         assert_eq!(k1, k2);
     }
+
+    struct GatedTool;
+
+    #[async_trait]
+    impl Tool for GatedTool {
+        fn name(&self) -> &str {
+            "gated"
+        }
+        fn description(&self) -> &str {
+            "A tool that always asks for approval"
+        }
+        fn parameters_schema(&self) -> serde_json::Value {
+            json!({"type": "object"})
+        }
+        fn needs_approval(&self, _args: &serde_json::Value) -> Option<String> {
+            Some("test approval".into())
+        }
+        async fn execute(&self, _args: serde_json::Value) -> crate::Result<ToolResult> {
+            Ok(ToolResult::ok("ran"))
+        }
+    }
+
+    async fn gated_adapter(
+        surface: &str,
+        decision: crate::security::approval::UnattendedDecision,
+    ) -> (tempfile::TempDir, RigToolAdapter) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let pool = crate::db::init_pool(&dir.path().join("test.db")).unwrap();
+        crate::db::with_db(&pool, |conn| crate::db::run_migrations(conn))
+            .await
+            .unwrap();
+        let broker = Arc::new(crate::security::approval::ApprovalBroker::new(pool));
+        let bus: Arc<dyn crate::event_bus::EventBus> =
+            Arc::new(crate::event_bus::TokioBroadcastBus::new(16));
+        let adapter = RigToolAdapter::new(Arc::new(GatedTool))
+            .with_approval(broker, bus, surface, 1)
+            .with_unattended_decision(decision);
+        (dir, adapter)
+    }
+
+    // Timed-out approval on an unattended surface skips the tool when configured
+    #[tokio::test]
+    async fn approval_timeout_skips_on_unattended_surface() {
+        let (_dir, adapter) = gated_adapter(
+            "scheduler",
+            crate::security::approval::UnattendedDecision::Skip,
+        )
+        .await;
+        let output = adapter.call("{}".into()).await.unwrap();
+        assert!(output.contains("skipped"), "got: {output}");
+        assert!(!output.contains("ran"));
+    }
+
+    // Timed-out approval denies by default, and always on interactive surfaces
+    #[tokio::test]
+    async fn approval_timeout_denies() {
+        let (_dir, adapter) = gated_adapter(
+            "scheduler",
+            crate::security::approval::UnattendedDecision::Deny,
+        )
+        .await;
+        assert!(adapter.call("{}".into()).await.is_err());
+
+        let (_dir, adapter) = gated_adapter(
+            "desktop",
+            crate::security::approval::UnattendedDecision::Skip,
+        )
+        .await;
+        assert!(adapter.call("{}".into()).await.is_err());
+    }
+
+    /// Sleeps briefly and records the peak number of overlapping executions.
+    struct ConcurrencyProbe {
+        name: &'static str,
+        read_only: bool,
+        running: Arc<AtomicU32>,
+        peak: Arc<AtomicU32>,
+    }
+
+    #[async_trait]
+    impl Tool for ConcurrencyProbe {
+        fn name(&self) -> &str {
+            self.name
+        }
+        fn description(&self) -> &str {
+            "Concurrency probe"
+        }
+        fn parameters_schema(&self) -> serde_json::Value {
+            json!({})
+        }
+        fn is_read_only(&self) -> bool {
+            self.read_only
+        }
+        async fn execute(&self, _args: serde_json::Value) -> crate::Result<ToolResult> {
+            let now = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(ToolResult::ok("done"))
+        }
+    }
+
+    fn probes(read_only: &[bool]) -> (Vec<Arc<dyn Tool>>, Arc<AtomicU32>) {
+        const NAMES: [&str; 4] = ["p0", "p1", "p2", "p3"];
+        let running = Arc::new(AtomicU32::new(0));
+        let peak = Arc::new(AtomicU32::new(0));
+        let tools = read_only
+            .iter()
+            .zip(NAMES)
+            .map(|(&read_only, name)| {
+                Arc::new(ConcurrencyProbe {
+                    name,
+                    read_only,
+                    running: running.clone(),
+                    peak: peak.clone(),
+                }) as Arc<dyn Tool>
+            })
+            .collect();
+        (tools, peak)
+    }
+
+    // PT.1 — Read-only calls overlap up to agent_tool_concurrency
+    #[tokio::test]
+    async fn read_only_calls_run_concurrently_within_cap() {
+        let config = crate::config::AppConfig {
+            agent_tool_concurrency: 3,
+            ..Default::default()
+        };
+        let (tools, peak) = probes(&[true, true, true, true]);
+        let adapters = RigToolAdapter::from_tools(&tools, None, &config);
+        futures::future::join_all(adapters.iter().map(|a| a.call("{}".into()))).await;
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+    }
+
+    // PT.2 — A side-effecting call never overlaps another tool
+    #[tokio::test]
+    async fn side_effecting_calls_run_alone() {
+        let config = crate::config::AppConfig {
+            agent_tool_concurrency: 4,
+            ..Default::default()
+        };
+        let (tools, peak) = probes(&[false, false, true]);
+        let adapters = RigToolAdapter::from_tools(&tools, None, &config);
+        futures::future::join_all(adapters.iter().map(|a| a.call("{}".into()))).await;
+        assert_eq!(peak.load(Ordering::SeqCst), 1);
+    }
+
+    struct HangingTool;
+
+    #[async_trait]
+    impl Tool for HangingTool {
+        fn name(&self) -> &str {
+            "hanging"
+        }
+        fn description(&self) -> &str {
+            "Never returns in time"
+        }
+        fn parameters_schema(&self) -> serde_json::Value {
+            json!({})
+        }
+        async fn execute(&self, _args: serde_json::Value) -> crate::Result<ToolResult> {
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+            Ok(ToolResult::ok("too late"))
+        }
+    }
+
+    // TV.1 — A hung tool yields a timeout result instead of blocking the turn
+    #[tokio::test]
+    async fn hung_tool_times_out_with_error_result() {
+        let mut config = crate::config::AppConfig::default();
+        config.security_tool_timeouts.insert("hanging".into(), 1);
+        let tool: Arc<dyn Tool> = Arc::new(HangingTool);
+        let adapter = RigToolAdapter::new(tool).with_timeout(&config);
+        let result = adapter.call("{}".to_string()).await.unwrap();
+
+        let parsed: ToolResult = serde_json::from_str(&result).unwrap();
+        assert!(!parsed.success);
+        assert!(parsed.output.contains("timed out after 1s"));
+    }
+
+    // TV.9 — Malformed arguments come back as a tool error and a failed event
+    #[tokio::test]
+    async fn malformed_arguments_return_tool_error() {
+        let (tx, mut rx) = broadcast::channel::<ToolCallEvent>(8);
+        let tool: Arc<dyn Tool> = Arc::new(MockTool { name: "test" });
+        let adapter = RigToolAdapter::new_with_events(tool, tx);
+
+        let result = adapter.call(r#"{"input": "hi""#.to_string()).await.unwrap();
+        let parsed: ToolResult = serde_json::from_str(&result).unwrap();
+        assert!(!parsed.success);
+        assert!(parsed.output.contains("could not parse tool 'test'"));
+
+        let _started = rx.recv().await.unwrap();
+        let ToolCallPhase::Completed { success, .. } = rx.recv().await.unwrap().phase else {
+            panic!("expected Completed phase");
+        };
+        assert!(!success);
+    }
 }
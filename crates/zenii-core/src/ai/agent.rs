@@ -1,19 +1,29 @@
+use std::collections::HashSet;
 use std::ops::Add;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
 
-use rig::agent::Agent;
-use rig::completion::Prompt;
+use futures::StreamExt;
+use rig::agent::{Agent, HookAction, MultiTurnStreamItem, PromptHook, ToolCallHookAction};
+use rig::completion::{CompletionModel, GetTokenUsage, Prompt};
 use rig::message::Message;
 use rig::prelude::CompletionClient;
-use rig::providers::{anthropic, openai};
+use rig::providers::{anthropic, gemini, openai};
+use rig::streaming::{StreamedAssistantContent, StreamingPrompt};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use tokio::sync::broadcast;
 
+use crate::ai::context_window::ContextWindow;
+use crate::ai::provider_registry::{ModelPricing, ProviderExtras};
+use crate::ai::retry::{RequestExtras, RetryConfig, RetryingHttpClient};
 use crate::config::AppConfig;
 use crate::credential::CredentialStore;
+use crate::event_bus::{AppEvent, EventBus};
+use crate::security::audit::AuditSink;
 use crate::tools::Tool;
-use crate::{Result, ZeniiError};
+use crate::{AgentError, Result, ZeniiError};
 
 /// Token usage from a single AI request.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -34,6 +44,24 @@ impl TokenUsage {
             cached_input_tokens: usage.cached_input_tokens,
         }
     }
+
+    /// Total tokens, or `None` when the provider returned no usage block.
+    pub fn reported_total(&self) -> Option<u64> {
+        let total = self
+            .total_tokens
+            .max(self.input_tokens + self.output_tokens);
+        (total > 0).then_some(total)
+    }
+
+    /// Estimated cost in USD. `None` when usage was not reported.
+    pub fn estimate_cost(&self, pricing: &ModelPricing) -> Option<f64> {
+        self.reported_total()?;
+        Some(
+            (self.input_tokens as f64 * pricing.input_per_mtok
+                + self.output_tokens as f64 * pricing.output_per_mtok)
+                / 1_000_000.0,
+        )
+    }
 }
 
 impl Add for TokenUsage {
@@ -58,6 +86,163 @@ impl std::ops::AddAssign for TokenUsage {
     }
 }
 
+/// Per-turn limits on cumulative token usage and wall-clock time.
+/// `None` means unbounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TurnBudget {
+    pub max_total_tokens: Option<u64>,
+    pub max_wall_time: Option<Duration>,
+}
+
+impl TurnBudget {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            max_total_tokens: config.agent_max_total_tokens,
+            max_wall_time: config.agent_max_wall_time_secs.map(Duration::from_secs),
+        }
+    }
+
+    /// Budget left after spending `tokens` over `elapsed`.
+    /// Fails with [`AgentError::BudgetExceeded`] once either limit is used up.
+    pub fn remaining(&self, tokens: u64, elapsed: Duration) -> Result<Self> {
+        let max_total_tokens = match self.max_total_tokens {
+            Some(max) if tokens >= max => {
+                return Err(token_budget_exceeded(max, tokens));
+            }
+            max => max.map(|m| m - tokens),
+        };
+        let max_wall_time = match self.max_wall_time {
+            Some(max) if elapsed >= max => {
+                return Err(wall_time_exceeded(max));
+            }
+            max => max.map(|m| m - elapsed),
+        };
+        Ok(Self {
+            max_total_tokens,
+            max_wall_time,
+        })
+    }
+}
+
+fn token_budget_exceeded(max: u64, used: u64) -> ZeniiError {
+    AgentError::BudgetExceeded(format!(
+        "token budget of {max} exceeded ({used} tokens used)"
+    ))
+    .into()
+}
+
+fn wall_time_exceeded(max: Duration) -> ZeniiError {
+    AgentError::BudgetExceeded(format!("wall time limit of {max:?} reached")).into()
+}
+
+fn repeated_tool_failures(reason: &str) -> ZeniiError {
+    AgentError::Tool(format!("agent {reason}")).into()
+}
+
+/// Prompt hook for one rig multi-turn run: meters token usage across its
+/// completions and terminates the loop once the budget is spent or a tool
+/// keeps failing after the reflection prompt, and answers calls to tools
+/// outside the agent's toolset with a clear error.
+#[derive(Clone)]
+struct TurnHook {
+    max_total_tokens: Option<u64>,
+    used: Arc<AtomicU64>,
+    tool_names: Arc<HashSet<String>>,
+    cache: Option<Arc<ToolCallCache>>,
+    called_tools: Arc<AtomicBool>,
+}
+
+impl TurnHook {
+    fn new(
+        max_total_tokens: Option<u64>,
+        tool_names: Arc<HashSet<String>>,
+        cache: Option<Arc<ToolCallCache>>,
+    ) -> Self {
+        Self {
+            max_total_tokens,
+            used: Arc::new(AtomicU64::new(0)),
+            tool_names,
+            cache,
+            called_tools: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Whether the model asked for any tool during this turn.
+    fn called_tools(&self) -> bool {
+        self.called_tools.load(Ordering::Relaxed)
+    }
+
+    /// Why the run was stopped for repeated tool failures, if it was.
+    fn abort_reason(&self) -> Option<String> {
+        self.cache
+            .as_ref()
+            .and_then(|cache| cache.failures().abort_reason().map(str::to_string))
+    }
+
+    /// Record a completion's usage; returns true once the budget is exceeded.
+    fn record(&self, usage: &TokenUsage) -> bool {
+        let spent = usage.reported_total().unwrap_or(0);
+        let used = self.used.fetch_add(spent, Ordering::Relaxed) + spent;
+        self.max_total_tokens.is_some_and(|max| used >= max)
+    }
+
+    fn used(&self) -> u64 {
+        self.used.load(Ordering::Relaxed)
+    }
+}
+
+impl<M: rig::completion::CompletionModel> PromptHook<M> for TurnHook {
+    async fn on_completion_response(
+        &self,
+        _prompt: &Message,
+        response: &rig::completion::CompletionResponse<M::Response>,
+    ) -> HookAction {
+        // A final answer is kept even if it crosses the budget; only a response
+        // that would continue the tool loop is cut off.
+        let wants_tools = response
+            .choice
+            .iter()
+            .any(|c| matches!(c, rig::message::AssistantContent::ToolCall(_)));
+        if self.record(&TokenUsage::from_rig(response.usage)) && wants_tools {
+            HookAction::terminate("token budget exceeded")
+        } else {
+            HookAction::cont()
+        }
+    }
+
+    async fn on_stream_completion_response_finish(
+        &self,
+        _prompt: &Message,
+        response: &M::StreamingResponse,
+    ) -> HookAction {
+        // Streamed responses only expose usage once finished; whether the model
+        // asked for tools is checked in `on_tool_call` below.
+        if let Some(usage) = response.token_usage() {
+            self.record(&TokenUsage::from_rig(usage));
+        }
+        HookAction::cont()
+    }
+
+    async fn on_tool_call(
+        &self,
+        tool_name: &str,
+        _tool_call_id: Option<String>,
+        _internal_call_id: &str,
+        _args: &str,
+    ) -> ToolCallHookAction {
+        self.called_tools.store(true, Ordering::Relaxed);
+        if self.max_total_tokens.is_some_and(|max| self.used() >= max) {
+            ToolCallHookAction::terminate("token budget exceeded")
+        } else if self.abort_reason().is_some() {
+            ToolCallHookAction::terminate("repeated tool failures")
+        } else if !self.tool_names.contains(tool_name) {
+            ToolCallHookAction::skip(crate::tools::profiles::not_available(tool_name))
+        } else {
+            ToolCallHookAction::cont()
+        }
+    }
+}
+
 /// Response from an AI prompt/chat call, including token usage.
 #[derive(Debug, Clone)]
 pub struct AgentResponse {
@@ -70,23 +255,62 @@ pub struct AgentResponse {
 use crate::gateway::state::AppState;
 
 use super::adapter::{RigToolAdapter, ToolCallCache, ToolCallEvent};
+use super::provider_registry::ModelModality;
 use super::providers;
-use super::routing::ModelRouter;
+use super::reflection::ToolFailureTracker;
+use super::routing::{ModelRouter, is_failover_error, is_transport_error};
+use super::tool_results::ToolResultStore;
 
-type OpenAIAgent = Agent<openai::completion::CompletionModel>;
-type AnthropicAgent = Agent<anthropic::completion::CompletionModel>;
+type OpenAIAgent = Agent<openai::completion::CompletionModel<RetryingHttpClient>>;
+type AnthropicAgent = Agent<anthropic::completion::CompletionModel<RetryingHttpClient>>;
+type GeminiAgent = Agent<gemini::completion::CompletionModel<RetryingHttpClient>>;
 
 enum AgentInner {
     OpenAI(OpenAIAgent),
     Anthropic(AnthropicAgent),
+    Gemini(GeminiAgent),
 }
 
 /// ZeniiAgent wraps a rig-core Agent with provider abstraction.
-/// Supports OpenAI-compatible and Anthropic providers.
+/// Supports OpenAI-compatible, Anthropic and native Gemini providers.
 // Debug can't be derived (Agent<M> doesn't impl Debug), use manual impl for test ergonomics.
 pub struct ZeniiAgent {
     inner: AgentInner,
     cache: Option<Arc<ToolCallCache>>,
+    pricing: Option<ModelPricing>,
+    failover: Option<Failover>,
+    budget: TurnBudget,
+    /// Max tool calls from one model response executed at once.
+    tool_concurrency: usize,
+    /// Names of the tools this agent was built with; calls to others are refused.
+    tool_names: Arc<HashSet<String>>,
+    /// History is trimmed to fit this window before every chat request.
+    context: ContextWindow,
+    /// Where forced history truncation is reported.
+    context_events: Option<Arc<dyn EventBus>>,
+}
+
+/// Fallback agents tried in order when a completion fails with a provider-side
+/// error (rate limit, 5xx, timeout); see [`is_failover_error`].
+struct Failover {
+    /// "provider_id:model_id" of the agent owning the chain.
+    primary: String,
+    chain: Vec<(String, ZeniiAgent)>,
+    event_bus: Option<Arc<dyn EventBus>>,
+}
+
+impl Failover {
+    /// Log and publish the switch from `failed` to `next`.
+    fn notify(&self, failed: &str, next: &str, err: &ZeniiError) {
+        tracing::warn!("Model {failed} failed ({err}), falling back to {next}");
+        if let Some(bus) = &self.event_bus {
+            let _ = bus.publish(AppEvent::ModelFailover {
+                failed_model: failed.to_string(),
+                next_model: next.to_string(),
+                error: err.to_string(),
+            });
+        }
+    }
 }
 
 impl std::fmt::Debug for ZeniiAgent {
@@ -94,6 +318,7 @@ impl std::fmt::Debug for ZeniiAgent {
         let variant = match &self.inner {
             AgentInner::OpenAI(_) => "OpenAI",
             AgentInner::Anthropic(_) => "Anthropic",
+            AgentInner::Gemini(_) => "Gemini",
         };
         f.debug_struct("ZeniiAgent")
             .field("provider", &variant)
@@ -101,12 +326,47 @@ impl std::fmt::Debug for ZeniiAgent {
     }
 }
 
-/// Convert rig-core prompt errors into ZeniiError::Agent.
+/// Map a one-shot prompt error, reporting a repeated-failure stop as such.
+fn prompt_error(hook: &TurnHook, e: rig::completion::request::PromptError) -> ZeniiError {
+    match (&e, hook.abort_reason()) {
+        (rig::completion::PromptError::PromptCancelled { .. }, Some(reason)) => {
+            repeated_tool_failures(&reason)
+        }
+        _ => enrich_agent_error("prompt")(e),
+    }
+}
+
+/// Convert rig-core prompt errors into a typed [`AgentError`].
 /// Hint enrichment is handled centrally by `crate::error::enrich_error()`.
 fn enrich_agent_error(
     context: &'static str,
 ) -> impl Fn(rig::completion::request::PromptError) -> ZeniiError {
-    move |e| ZeniiError::Agent(format!("{context} failed: {e}"))
+    move |e| match e {
+        rig::completion::PromptError::PromptCancelled { .. } => {
+            AgentError::Cancelled(format!("{context} cancelled: {e}")).into()
+        }
+        e => AgentError::from_message(format!("{context} failed: {e}")).into(),
+    }
+}
+
+/// Wrap the assembled system prompt with the configured `agent_prompt_prefix` and
+/// `agent_prompt_suffix`. Applied at agent construction so chat, channel, scheduler
+/// and delegated sessions all receive the same standing instructions.
+pub fn build_system_prompt(config: &AppConfig, preamble: &str) -> String {
+    let prefix = config
+        .agent_prompt_prefix
+        .as_deref()
+        .filter(|s| !s.trim().is_empty());
+    let suffix = config
+        .agent_prompt_suffix
+        .as_deref()
+        .filter(|s| !s.trim().is_empty());
+
+    [prefix, Some(preamble), suffix]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("\n\n")
 }
 
 impl ZeniiAgent {
@@ -115,22 +375,30 @@ impl ZeniiAgent {
         config: &AppConfig,
         credentials: &dyn CredentialStore,
         tools: &[Arc<dyn Tool>],
+        audit: Option<AuditSink>,
     ) -> Result<Self> {
         let api_key = providers::resolve_api_key(config, credentials).await?;
-        let rig_tools = RigToolAdapter::from_tools(tools, config);
+        let rig_tools = RigToolAdapter::from_tools(tools, audit, config);
 
-        let preamble = config
-            .agent_system_prompt
-            .as_deref()
-            .unwrap_or("You are Zenii, a helpful AI assistant.");
+        let preamble = build_system_prompt(
+            config,
+            config
+                .agent_system_prompt
+                .as_deref()
+                .unwrap_or("You are Zenii, a helpful AI assistant."),
+        );
 
         let inner = match config.provider_type.as_str() {
             "openai" | "custom" => {
-                let client =
-                    providers::build_openai_client(&api_key, config.provider_base_url.as_deref())?;
+                let client = providers::build_openai_client(
+                    &api_key,
+                    config.provider_base_url.as_deref(),
+                    RetryConfig::from_config(config),
+                    RequestExtras::default(),
+                )?;
                 let agent = client
                     .agent(&config.provider_model_id)
-                    .preamble(preamble)
+                    .preamble(&preamble)
                     .additional_params(json!({"max_completion_tokens": config.agent_max_tokens}))
                     .default_max_turns(config.agent_max_turns)
                     .tools(rig_tools)
@@ -138,24 +406,106 @@ impl ZeniiAgent {
                 AgentInner::OpenAI(agent)
             }
             "anthropic" => {
-                let client = providers::build_anthropic_client(&api_key)?;
+                let client = providers::build_anthropic_client(
+                    &api_key,
+                    RetryConfig::from_config(config),
+                    config.provider_prompt_caching,
+                    RequestExtras::default(),
+                )?;
                 let agent = client
                     .agent(&config.provider_model_id)
-                    .preamble(preamble)
+                    .preamble(&preamble)
                     .max_tokens(config.agent_max_tokens as u64)
                     .default_max_turns(config.agent_max_turns)
                     .tools(rig_tools)
                     .build();
                 AgentInner::Anthropic(agent)
             }
+            "gemini" => {
+                let client = providers::build_gemini_client(
+                    &api_key,
+                    config.provider_base_url.as_deref(),
+                    RetryConfig::from_config(config),
+                    RequestExtras::default(),
+                )?;
+                let agent = client
+                    .agent(&config.provider_model_id)
+                    .preamble(&preamble)
+                    .max_tokens(config.agent_max_tokens as u64)
+                    .default_max_turns(config.agent_max_turns)
+                    .tools(rig_tools)
+                    .build();
+                AgentInner::Gemini(agent)
+            }
             other => {
                 return Err(ZeniiError::Agent(format!(
-                    "unsupported provider type: '{other}'. Supported: openai, anthropic, custom"
+                    "unsupported provider type: '{other}'. Supported: openai, anthropic, gemini, custom"
                 )));
             }
         };
 
-        Ok(Self { inner, cache: None })
+        Ok(Self {
+            inner,
+            cache: None,
+            pricing: None,
+            failover: None,
+            budget: TurnBudget::from_config(config),
+            tool_concurrency: config.agent_tool_concurrency.max(1),
+            tool_names: Arc::new(tools.iter().map(|t| t.name().to_string()).collect()),
+            context: ContextWindow::new(config, config.provider_model_id.clone(), &preamble, tools),
+            context_events: None,
+        })
+    }
+
+    /// Attach the model's pricing so callers can estimate the cost of a run.
+    pub fn with_pricing(mut self, pricing: Option<ModelPricing>) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    /// Apply the model's context limit (from the provider registry) and report
+    /// forced history truncation on `event_bus`. An unknown limit keeps
+    /// `agent_default_context_limit`.
+    pub fn with_context_limit(
+        mut self,
+        limit: Option<i64>,
+        event_bus: Option<Arc<dyn EventBus>>,
+    ) -> Self {
+        self.context = self.context.with_limit(limit);
+        self.context_events = event_bus;
+        self
+    }
+
+    /// Attach fallback agents tried, in order, when this agent's model fails with a
+    /// retryable provider error. An empty chain disables failover.
+    pub fn with_failover(
+        mut self,
+        primary: String,
+        chain: Vec<(String, ZeniiAgent)>,
+        event_bus: Option<Arc<dyn EventBus>>,
+    ) -> Self {
+        self.failover = (!chain.is_empty()).then_some(Failover {
+            primary,
+            chain,
+            event_bus,
+        });
+        self
+    }
+
+    /// Override the per-turn token / wall-time budget (defaults to config).
+    pub fn with_budget(mut self, budget: TurnBudget) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// The per-turn budget applied by [`Self::chat`].
+    pub fn budget(&self) -> TurnBudget {
+        self.budget
+    }
+
+    /// Estimated cost in USD of `usage` on this agent's model, if its pricing is known.
+    pub fn estimate_cost(&self, usage: &TokenUsage) -> Option<f64> {
+        usage.estimate_cost(self.pricing.as_ref()?)
     }
 
     /// Number of actual (non-cached) tool executions for this agent's request.
@@ -166,8 +516,9 @@ impl ZeniiAgent {
 
     /// Build a new ZeniiAgent from provider details (for dynamic per-request agent building).
     ///
-    /// Provider type is inferred at runtime: `provider_id == "anthropic"` uses the native
-    /// Anthropic client, everything else uses the OpenAI-compatible client with `base_url`.
+    /// Provider type is inferred at runtime: `provider_id == "anthropic"` and
+    /// `provider_id == "gemini"` use the native clients, everything else uses the
+    /// OpenAI-compatible client with `base_url`.
     ///
     /// If `preamble_override` is provided, it replaces the default system prompt.
     #[allow(clippy::too_many_arguments)]
@@ -176,43 +527,75 @@ impl ZeniiAgent {
         base_url: &str,
         model_id: &str,
         requires_api_key: bool,
+        extras: &ProviderExtras,
         credentials: &dyn CredentialStore,
         tools: &[Arc<dyn Tool>],
         config: &AppConfig,
         preamble_override: Option<&str>,
         dedup_cache: Option<Arc<ToolCallCache>>,
+        audit: Option<AuditSink>,
     ) -> Result<Self> {
         let api_key =
             providers::resolve_api_key_for_provider(provider_id, requires_api_key, credentials)
                 .await?;
+        let request_extras =
+            providers::resolve_request_extras(provider_id, extras, credentials).await?;
         let rig_tools = if let Some(ref cache) = dedup_cache {
-            RigToolAdapter::from_tools_with_cache(tools, Arc::clone(cache), config)
+            RigToolAdapter::from_tools_with_cache(tools, Arc::clone(cache), audit, config)
         } else {
-            RigToolAdapter::from_tools(tools, config)
+            RigToolAdapter::from_tools(tools, audit, config)
         };
 
-        let preamble = preamble_override.unwrap_or_else(|| {
-            config
-                .agent_system_prompt
-                .as_deref()
-                .unwrap_or("You are Zenii, a helpful AI assistant.")
-        });
+        let preamble = build_system_prompt(
+            config,
+            preamble_override.unwrap_or_else(|| {
+                config
+                    .agent_system_prompt
+                    .as_deref()
+                    .unwrap_or("You are Zenii, a helpful AI assistant.")
+            }),
+        );
 
         let inner = if provider_id == "anthropic" {
-            let client = providers::build_anthropic_client(&api_key)?;
+            let client = providers::build_anthropic_client(
+                &api_key,
+                RetryConfig::from_config(config),
+                config.provider_prompt_caching,
+                request_extras,
+            )?;
             let agent = client
                 .agent(model_id)
-                .preamble(preamble)
+                .preamble(&preamble)
                 .max_tokens(config.agent_max_tokens as u64)
                 .default_max_turns(config.agent_max_turns)
                 .tools(rig_tools)
                 .build();
             AgentInner::Anthropic(agent)
+        } else if provider_id == "gemini" {
+            let client = providers::build_gemini_client(
+                &api_key,
+                Some(base_url),
+                RetryConfig::from_config(config),
+                request_extras,
+            )?;
+            let agent = client
+                .agent(model_id)
+                .preamble(&preamble)
+                .max_tokens(config.agent_max_tokens as u64)
+                .default_max_turns(config.agent_max_turns)
+                .tools(rig_tools)
+                .build();
+            AgentInner::Gemini(agent)
         } else {
-            let client = providers::build_openai_client(&api_key, Some(base_url))?;
+            let client = providers::build_openai_client(
+                &api_key,
+                Some(base_url),
+                RetryConfig::from_config(config),
+                request_extras,
+            )?;
             let agent = client
                 .agent(model_id)
-                .preamble(preamble)
+                .preamble(&preamble)
                 .additional_params(json!({"max_completion_tokens": config.agent_max_tokens}))
                 .default_max_turns(config.agent_max_turns)
                 .tools(rig_tools)
@@ -223,6 +606,18 @@ impl ZeniiAgent {
         Ok(Self {
             inner,
             cache: dedup_cache,
+            pricing: None,
+            failover: None,
+            budget: TurnBudget::from_config(config),
+            tool_concurrency: config.agent_tool_concurrency.max(1),
+            tool_names: Arc::new(tools.iter().map(|t| t.name().to_string()).collect()),
+            context: ContextWindow::new(
+                config,
+                format!("{provider_id}:{model_id}"),
+                &preamble,
+                tools,
+            ),
+            context_events: None,
         })
     }
 
@@ -237,12 +632,15 @@ impl ZeniiAgent {
         base_url: &str,
         model_id: &str,
         requires_api_key: bool,
+        extras: &ProviderExtras,
         credentials: &dyn CredentialStore,
         tools: &[Arc<dyn Tool>],
         config: &AppConfig,
         tool_event_tx: broadcast::Sender<ToolCallEvent>,
         preamble_override: Option<&str>,
         dedup_cache: Option<Arc<ToolCallCache>>,
+        result_store: Option<Arc<ToolResultStore>>,
+        audit: Option<AuditSink>,
         approval_broker: Option<Arc<crate::security::approval::ApprovalBroker>>,
         event_bus: Option<Arc<dyn crate::event_bus::EventBus>>,
         surface: &str,
@@ -250,10 +648,14 @@ impl ZeniiAgent {
         let api_key =
             providers::resolve_api_key_for_provider(provider_id, requires_api_key, credentials)
                 .await?;
+        let request_extras =
+            providers::resolve_request_extras(provider_id, extras, credentials).await?;
         let rig_tools = RigToolAdapter::from_tools_full(
             tools,
             tool_event_tx,
             dedup_cache.clone(),
+            result_store,
+            audit,
             approval_broker,
             event_bus,
             surface,
@@ -262,28 +664,56 @@ impl ZeniiAgent {
             config,
         );
 
-        let preamble = preamble_override.unwrap_or_else(|| {
-            config
-                .agent_system_prompt
-                .as_deref()
-                .unwrap_or("You are Zenii, a helpful AI assistant.")
-        });
+        let preamble = build_system_prompt(
+            config,
+            preamble_override.unwrap_or_else(|| {
+                config
+                    .agent_system_prompt
+                    .as_deref()
+                    .unwrap_or("You are Zenii, a helpful AI assistant.")
+            }),
+        );
 
         let inner = if provider_id == "anthropic" {
-            let client = providers::build_anthropic_client(&api_key)?;
+            let client = providers::build_anthropic_client(
+                &api_key,
+                RetryConfig::from_config(config),
+                config.provider_prompt_caching,
+                request_extras,
+            )?;
             let agent = client
                 .agent(model_id)
-                .preamble(preamble)
+                .preamble(&preamble)
                 .max_tokens(config.agent_max_tokens as u64)
                 .default_max_turns(config.agent_max_turns)
                 .tools(rig_tools)
                 .build();
             AgentInner::Anthropic(agent)
+        } else if provider_id == "gemini" {
+            let client = providers::build_gemini_client(
+                &api_key,
+                Some(base_url),
+                RetryConfig::from_config(config),
+                request_extras,
+            )?;
+            let agent = client
+                .agent(model_id)
+                .preamble(&preamble)
+                .max_tokens(config.agent_max_tokens as u64)
+                .default_max_turns(config.agent_max_turns)
+                .tools(rig_tools)
+                .build();
+            AgentInner::Gemini(agent)
         } else {
-            let client = providers::build_openai_client(&api_key, Some(base_url))?;
+            let client = providers::build_openai_client(
+                &api_key,
+                Some(base_url),
+                RetryConfig::from_config(config),
+                request_extras,
+            )?;
             let agent = client
                 .agent(model_id)
-                .preamble(preamble)
+                .preamble(&preamble)
                 .additional_params(json!({"max_completion_tokens": config.agent_max_tokens}))
                 .default_max_turns(config.agent_max_turns)
                 .tools(rig_tools)
@@ -294,22 +724,62 @@ impl ZeniiAgent {
         Ok(Self {
             inner,
             cache: dedup_cache,
+            pricing: None,
+            failover: None,
+            budget: TurnBudget::from_config(config),
+            tool_concurrency: config.agent_tool_concurrency.max(1),
+            tool_names: Arc::new(tools.iter().map(|t| t.name().to_string()).collect()),
+            context: ContextWindow::new(
+                config,
+                format!("{provider_id}:{model_id}"),
+                &preamble,
+                tools,
+            ),
+            context_events: None,
         })
     }
 
     /// Send a simple prompt and get a response with token usage.
     pub async fn prompt(&self, input: &str) -> Result<AgentResponse> {
+        let mut result = self.prompt_once(input).await;
+        if let Some(failover) = &self.failover {
+            let mut failed = failover.primary.as_str();
+            for (spec, agent) in &failover.chain {
+                match &result {
+                    Err(e) if is_failover_error(e) => failover.notify(failed, spec, e),
+                    _ => break,
+                }
+                result = agent.prompt_once(input).await;
+                failed = spec;
+            }
+        }
+        result
+    }
+
+    async fn prompt_once(&self, input: &str) -> Result<AgentResponse> {
+        let hook = TurnHook::new(None, self.tool_names.clone(), self.cache.clone());
         let resp = match &self.inner {
             AgentInner::OpenAI(agent) => agent
                 .prompt(input)
+                .with_hook(hook.clone())
+                .with_tool_concurrency(self.tool_concurrency)
                 .extended_details()
                 .await
-                .map_err(enrich_agent_error("prompt"))?,
+                .map_err(|e| prompt_error(&hook, e))?,
             AgentInner::Anthropic(agent) => agent
                 .prompt(input)
+                .with_hook(hook.clone())
+                .with_tool_concurrency(self.tool_concurrency)
                 .extended_details()
                 .await
-                .map_err(enrich_agent_error("prompt"))?,
+                .map_err(|e| prompt_error(&hook, e))?,
+            AgentInner::Gemini(agent) => agent
+                .prompt(input)
+                .with_hook(hook.clone())
+                .with_tool_concurrency(self.tool_concurrency)
+                .extended_details()
+                .await
+                .map_err(|e| prompt_error(&hook, e))?,
         };
         Ok(AgentResponse {
             output: resp.output,
@@ -318,26 +788,254 @@ impl ZeniiAgent {
     }
 
     /// Send a prompt with chat history and get a response with token usage.
+    ///
+    /// The turn is bounded by this agent's [`TurnBudget`]; exceeding it aborts
+    /// the tool loop with [`AgentError::BudgetExceeded`].
     pub async fn chat(&self, input: &str, history: Vec<Message>) -> Result<AgentResponse> {
-        let resp = match &self.inner {
-            AgentInner::OpenAI(agent) => agent
-                .prompt(input)
-                .with_history(history)
-                .extended_details()
-                .await
-                .map_err(enrich_agent_error("chat"))?,
-            AgentInner::Anthropic(agent) => agent
-                .prompt(input)
-                .with_history(history)
-                .extended_details()
+        self.chat_with_budget(input, history, self.budget).await
+    }
+
+    /// Like [`Self::chat`], but bounded by an explicit budget (e.g. what is left
+    /// of a turn after earlier calls).
+    pub async fn chat_with_budget(
+        &self,
+        input: &str,
+        history: Vec<Message>,
+        budget: TurnBudget,
+    ) -> Result<AgentResponse> {
+        let Some(failover) = &self.failover else {
+            return self.chat_once(input, history, budget).await;
+        };
+        let mut result = self.chat_once(input, history.clone(), budget).await;
+        let mut failed = failover.primary.as_str();
+        for (spec, agent) in &failover.chain {
+            match &result {
+                Err(e) if is_failover_error(e) => failover.notify(failed, spec, e),
+                _ => break,
+            }
+            result = agent.chat_once(input, history.clone(), budget).await;
+            failed = spec;
+        }
+        result
+    }
+
+    async fn chat_once(
+        &self,
+        input: &str,
+        history: Vec<Message>,
+        budget: TurnBudget,
+    ) -> Result<AgentResponse> {
+        let history = self
+            .context
+            .enforce(input, history, self.context_events.as_ref());
+        let hook = TurnHook::new(
+            budget.max_total_tokens,
+            self.tool_names.clone(),
+            self.cache.clone(),
+        );
+        let run = async {
+            match &self.inner {
+                AgentInner::OpenAI(agent) => {
+                    agent
+                        .prompt(input)
+                        .with_history(history)
+                        .with_hook(hook.clone())
+                        .with_tool_concurrency(self.tool_concurrency)
+                        .extended_details()
+                        .await
+                }
+                AgentInner::Anthropic(agent) => {
+                    agent
+                        .prompt(input)
+                        .with_history(history)
+                        .with_hook(hook.clone())
+                        .with_tool_concurrency(self.tool_concurrency)
+                        .extended_details()
+                        .await
+                }
+                AgentInner::Gemini(agent) => {
+                    agent
+                        .prompt(input)
+                        .with_history(history)
+                        .with_hook(hook.clone())
+                        .with_tool_concurrency(self.tool_concurrency)
+                        .extended_details()
+                        .await
+                }
+            }
+        };
+        let resp = match budget.max_wall_time {
+            Some(limit) => tokio::time::timeout(limit, run)
                 .await
-                .map_err(enrich_agent_error("chat"))?,
+                .map_err(|_| wall_time_exceeded(limit))?,
+            None => run.await,
         };
+        let resp = resp.map_err(|e| match (e, budget.max_total_tokens) {
+            (rig::completion::PromptError::PromptCancelled { .. }, _)
+                if hook.abort_reason().is_some() =>
+            {
+                repeated_tool_failures(&hook.abort_reason().unwrap_or_default())
+            }
+            (rig::completion::PromptError::PromptCancelled { .. }, Some(max)) => {
+                token_budget_exceeded(max, hook.used())
+            }
+            (e, _) => enrich_agent_error("chat")(e),
+        })?;
         Ok(AgentResponse {
             output: resp.output,
             usage: TokenUsage::from_rig(resp.usage),
         })
     }
+
+    /// Like [`Self::chat_with_budget`], but streams the assistant's text to
+    /// `on_token` as the model produces it. The returned response still carries
+    /// the complete final answer.
+    ///
+    /// Failover only moves to the next model while nothing has been streamed
+    /// yet, so a caller never sees deltas from two different models.
+    pub async fn chat_stream(
+        &self,
+        input: &str,
+        history: Vec<Message>,
+        budget: TurnBudget,
+        on_token: &TokenSink<'_>,
+    ) -> Result<AgentResponse> {
+        let emitted = AtomicBool::new(false);
+        let sink = |delta: &str| {
+            emitted.store(true, Ordering::Relaxed);
+            on_token(delta);
+        };
+        let mut result = self
+            .stream_once(input, history.clone(), budget, &sink)
+            .await;
+        if let Some(failover) = &self.failover {
+            let mut failed = failover.primary.as_str();
+            for (spec, agent) in &failover.chain {
+                match &result {
+                    Err(e) if is_failover_error(e) && !emitted.load(Ordering::Relaxed) => {
+                        failover.notify(failed, spec, e)
+                    }
+                    _ => break,
+                }
+                result = agent
+                    .stream_once(input, history.clone(), budget, &sink)
+                    .await;
+                failed = spec;
+            }
+        }
+        result
+    }
+
+    /// One streamed chat against this agent's model. If the stream fails in
+    /// transport before producing any text or tool call, the turn is retried
+    /// without streaming and the whole answer is delivered as a single delta.
+    /// HTTP errors (4xx included) are returned as-is.
+    async fn stream_once(
+        &self,
+        input: &str,
+        history: Vec<Message>,
+        budget: TurnBudget,
+        on_token: &TokenSink<'_>,
+    ) -> Result<AgentResponse> {
+        let history = self
+            .context
+            .enforce(input, history, self.context_events.as_ref());
+        let hook = TurnHook::new(
+            budget.max_total_tokens,
+            self.tool_names.clone(),
+            self.cache.clone(),
+        );
+        let emitted = AtomicBool::new(false);
+        let sink = |delta: &str| {
+            emitted.store(true, Ordering::Relaxed);
+            on_token(delta);
+        };
+        let run = async {
+            match &self.inner {
+                AgentInner::OpenAI(agent) => {
+                    stream_agent(agent, input, history.clone(), hook.clone(), &sink).await
+                }
+                AgentInner::Anthropic(agent) => {
+                    stream_agent(agent, input, history.clone(), hook.clone(), &sink).await
+                }
+                AgentInner::Gemini(agent) => {
+                    stream_agent(agent, input, history.clone(), hook.clone(), &sink).await
+                }
+            }
+        };
+        let streamed = match budget.max_wall_time {
+            Some(limit) => tokio::time::timeout(limit, run)
+                .await
+                .map_err(|_| wall_time_exceeded(limit))?,
+            None => run.await,
+        };
+        let err = match streamed {
+            Ok(resp) => return Ok(resp),
+            Err(rig::agent::StreamingError::Prompt(e)) => match (*e, budget.max_total_tokens) {
+                (rig::completion::PromptError::PromptCancelled { .. }, _)
+                    if hook.abort_reason().is_some() =>
+                {
+                    return Err(repeated_tool_failures(
+                        &hook.abort_reason().unwrap_or_default(),
+                    ));
+                }
+                (rig::completion::PromptError::PromptCancelled { .. }, Some(max)) => {
+                    return Err(token_budget_exceeded(max, hook.used()));
+                }
+                (e, _) => enrich_agent_error("stream")(e),
+            },
+            Err(e) => AgentError::from_message(format!("stream failed: {e}")).into(),
+        };
+        if emitted.load(Ordering::Relaxed) || hook.called_tools() || !is_transport_error(&err) {
+            return Err(err);
+        }
+        tracing::debug!("Streaming unavailable ({err}), falling back to a single response");
+        let resp = self.chat_once(input, history, budget).await?;
+        on_token(&resp.output);
+        Ok(resp)
+    }
+}
+
+/// Callback receiving the assistant's text deltas during a streamed chat.
+pub type TokenSink<'a> = dyn Fn(&str) + Send + Sync + 'a;
+
+/// Drive a rig streaming multi-turn run, forwarding text deltas to `on_token`.
+async fn stream_agent<M>(
+    agent: &Agent<M>,
+    input: &str,
+    history: Vec<Message>,
+    hook: TurnHook,
+    on_token: &TokenSink<'_>,
+) -> std::result::Result<AgentResponse, rig::agent::StreamingError>
+where
+    M: CompletionModel + 'static,
+    M::StreamingResponse: GetTokenUsage,
+{
+    let mut stream = agent
+        .stream_prompt(input)
+        .with_history(history)
+        .with_hook(hook)
+        .await;
+    let mut output = String::new();
+    while let Some(item) = stream.next().await {
+        match item? {
+            MultiTurnStreamItem::StreamAssistantItem(StreamedAssistantContent::Text(text)) => {
+                on_token(&text.text);
+                output.push_str(&text.text);
+            }
+            MultiTurnStreamItem::FinalResponse(resp) => {
+                return Ok(AgentResponse {
+                    output: resp.response().to_string(),
+                    usage: TokenUsage::from_rig(resp.usage()),
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(AgentResponse {
+        output,
+        usage: TokenUsage::default(),
+    })
 }
 
 /// Resolve the agent to use for a chat request.
@@ -391,7 +1089,25 @@ pub async fn resolve_agent_with_tools(
 ) -> Result<Arc<ZeniiAgent>> {
     // Translate hint prefixes before any resolution
     let config_guard = state.config.load();
-    let routed: Option<String> = ModelRouter::new(&config_guard).route(requested_model);
+    let router = ModelRouter::new(&config_guard);
+    let fallback_specs = router.fallbacks(requested_model).to_vec();
+    let required = ModelRouter::required_modalities(requested_model);
+    let routed: Option<String> = match router.route(requested_model) {
+        // No model configured for the hint: pick one that can take the input
+        None if !required.is_empty() => {
+            let candidates: Vec<_> = state
+                .provider_registry
+                .list_providers_with_key_status(state.credentials.as_ref())
+                .await?
+                .into_iter()
+                .filter(|p| p.provider.is_active && p.has_api_key)
+                .flat_map(|p| p.models)
+                .collect();
+            let model = router.select(&candidates, required)?;
+            Some(format!("{}:{}", model.provider_id, model.model_id))
+        }
+        routed => routed,
+    };
     if routed.is_some() && requested_model.is_none() {
         tracing::warn!("routing hint resolved without explicit model request");
     }
@@ -416,28 +1132,6 @@ pub async fn resolve_agent_with_tools(
     };
 
     if let Some(spec) = model_spec {
-        let (provider_id, model_id) = spec.split_once(':').ok_or_else(|| {
-            ZeniiError::Agent(format!(
-                "invalid model format '{spec}': expected 'provider_id:model_id'"
-            ))
-        })?;
-
-        let provider = state.provider_registry.get_provider(provider_id).await?;
-
-        // Check model capability before building agent.
-        // If model not found in registry, proceed (backwards-compatible).
-        if let Some(model_info) = state
-            .provider_registry
-            .get_model_info(provider_id, model_id)
-            .await?
-            && !model_info.supports_tools
-        {
-            return Err(ZeniiError::ModelCapability(format!(
-                "The model '{}' ({}) does not support tool usage. Please select a model that supports tools.",
-                model_info.display_name, spec
-            )));
-        }
-
         let tools = tool_override.unwrap_or_else(|| {
             crate::security::permissions::PermissionResolver::executable_tools(
                 &config_guard.tool_permissions,
@@ -446,67 +1140,166 @@ pub async fn resolve_agent_with_tools(
             )
         });
 
-        // Create per-request dedup cache if enabled
+        // Create per-request dedup cache if enabled; shared with fallbacks so a
+        // retried turn does not re-run tools that already executed. It also
+        // tracks repeated tool failures for the reflection step.
         let dedup_cache = if config_guard.tool_dedup_enabled {
-            Some(Arc::new(ToolCallCache::with_limits(
-                config_guard.tool_call_limits.clone(),
-            )))
+            Some(Arc::new(
+                ToolCallCache::with_limits(config_guard.tool_call_limits.clone())
+                    .with_failure_tracker(ToolFailureTracker::from_config(
+                        &config_guard,
+                        Some(state.event_bus.clone()),
+                    )),
+            ))
         } else {
             None
         };
 
-        let agent = if let Some(tx) = tool_event_tx {
-            ZeniiAgent::from_provider_with_events(
-                provider_id,
-                &provider.provider.base_url,
-                model_id,
-                provider.provider.requires_api_key,
-                state.credentials.as_ref(),
-                &tools,
+        let build = |spec: &str| {
+            build_agent(
+                state,
                 &config_guard,
-                tx,
-                preamble_override,
-                dedup_cache,
-                if skip_approval {
-                    None
-                } else {
-                    state.approval_broker.clone()
-                },
-                if skip_approval {
-                    None
-                } else {
-                    Some(state.event_bus.clone())
-                },
-                surface,
-            )
-            .await?
-        } else {
-            ZeniiAgent::from_provider(
-                provider_id,
-                &provider.provider.base_url,
-                model_id,
-                provider.provider.requires_api_key,
-                state.credentials.as_ref(),
+                spec.to_string(),
                 &tools,
-                &config_guard,
+                tool_event_tx.clone(),
                 preamble_override,
-                dedup_cache,
+                dedup_cache.clone(),
+                surface,
+                skip_approval,
+                required,
             )
-            .await?
         };
+        let agent = build(&spec).await?;
+
+        let mut chain = Vec::new();
+        for fallback in fallback_specs.iter().filter(|f| **f != spec) {
+            if chain.len() >= config_guard.routing_failover_max_attempts {
+                break;
+            }
+            match build(fallback).await {
+                Ok(fallback_agent) => chain.push((fallback.clone(), fallback_agent)),
+                Err(e) => tracing::warn!("Skipping fallback model {fallback}: {e}"),
+            }
+        }
 
         if let Some(spec) = persist_model {
             let mut last = state.last_used_model.write().await;
             *last = Some(spec);
         }
-        return Ok(Arc::new(agent));
+        return Ok(Arc::new(agent.with_failover(
+            spec,
+            chain,
+            Some(state.event_bus.clone()),
+        )));
     }
-
     // Fallback to boot-time agent
-    state
-        .agent
-        .clone()
-        .ok_or_else(|| ZeniiError::Agent("no agent configured".into()))
+    state.agent.clone().ok_or_else(|| {
+        ZeniiError::ProviderNotConfigured(
+            "no default model is set and no boot-time agent is available".into(),
+        )
+    })
+}
+
+/// Build the agent for one "provider_id:model_id" spec with the request's tools,
+/// event wiring and pricing. Shared by the primary model and its fallbacks.
+#[cfg(feature = "ai")]
+#[allow(clippy::too_many_arguments)]
+async fn build_agent(
+    state: &AppState,
+    config: &AppConfig,
+    spec: String,
+    tools: &[Arc<dyn Tool>],
+    tool_event_tx: Option<broadcast::Sender<ToolCallEvent>>,
+    preamble_override: Option<&str>,
+    dedup_cache: Option<Arc<ToolCallCache>>,
+    surface: &str,
+    skip_approval: bool,
+    required_modalities: &[ModelModality],
+) -> Result<ZeniiAgent> {
+    let (provider_id, model_id) = spec.split_once(':').ok_or_else(|| {
+        ZeniiError::Agent(format!(
+            "invalid model format '{spec}': expected 'provider_id:model_id'"
+        ))
+    })?;
+
+    let provider = state.provider_registry.get_provider(provider_id).await?;
+
+    // Check model capability before building agent.
+    // If model not found in registry, proceed (backwards-compatible).
+    let model_info = state
+        .provider_registry
+        .get_model_info(provider_id, model_id)
+        .await?;
+    if let Some(ref model_info) = model_info
+        && !model_info.supports_tools
+    {
+        return Err(ZeniiError::ModelCapability(format!(
+            "The model '{}' ({}) does not support tool usage. Please select a model that supports tools.",
+            model_info.display_name, spec
+        )));
+    }
+    if let Some(ref model_info) = model_info
+        && let Some(missing) = required_modalities
+            .iter()
+            .find(|m| !model_info.input_modalities.contains(m))
+    {
+        return Err(ZeniiError::ModelCapability(format!(
+            "The model '{}' ({}) does not accept {} input. Please select a model that does.",
+            model_info.display_name,
+            spec,
+            missing.as_str()
+        )));
+    }
+    let context_limit = model_info.as_ref().and_then(|m| m.context_limit);
+    let pricing = model_info.and_then(|m| m.pricing());
+
+    let agent = if let Some(tx) = tool_event_tx {
+        ZeniiAgent::from_provider_with_events(
+            provider_id,
+            &provider.provider.base_url,
+            model_id,
+            provider.provider.requires_api_key,
+            &provider.provider.extras,
+            state.credentials.as_ref(),
+            tools,
+            config,
+            tx,
+            preamble_override,
+            dedup_cache,
+            Some(state.tool_results.clone()),
+            state.audit.clone(),
+            if skip_approval {
+                None
+            } else {
+                state.approval_broker.clone()
+            },
+            if skip_approval {
+                None
+            } else {
+                Some(state.event_bus.clone())
+            },
+            surface,
+        )
+        .await?
+    } else {
+        ZeniiAgent::from_provider(
+            provider_id,
+            &provider.provider.base_url,
+            model_id,
+            provider.provider.requires_api_key,
+            &provider.provider.extras,
+            state.credentials.as_ref(),
+            tools,
+            config,
+            preamble_override,
+            dedup_cache,
+            state.audit.clone(),
+        )
+        .await?
+    };
+    Ok(agent
+        .with_pricing(pricing)
+        .with_context_limit(context_limit, Some(state.event_bus.clone())))
 }
 
 // Compile-time assertion: ZeniiAgent must be Send + Sync for use in AppState
@@ -522,8 +1315,31 @@ const _: () = {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ai::test_support::{MockReply, completion, openai_mock};
     use crate::credential::InMemoryCredentialStore;
 
+    // 1.4.0 — prefix and suffix wrap the assembled prompt in order
+    #[test]
+    fn system_prompt_prefix_and_suffix_order() {
+        let config = AppConfig {
+            agent_prompt_prefix: Some("PREFIX: be careful.".into()),
+            agent_prompt_suffix: Some("SUFFIX: always cite sources.".into()),
+            ..Default::default()
+        };
+        let prompt = build_system_prompt(&config, "## Agent Identity\n- Name: Zenii");
+        assert_eq!(
+            prompt,
+            "PREFIX: be careful.\n\n## Agent Identity\n- Name: Zenii\n\nSUFFIX: always cite sources."
+        );
+
+        // Unset or blank wrappers leave the prompt untouched
+        let config = AppConfig {
+            agent_prompt_suffix: Some("  ".into()),
+            ..Default::default()
+        };
+        assert_eq!(build_system_prompt(&config, "base"), "base");
+    }
+
     // 1.4.1 — agent new with mock (uses real client, no LLM call)
     #[tokio::test]
     async fn agent_new_with_openai_config() {
@@ -537,7 +1353,7 @@ mod tests {
         creds.set("api_key:openai", "sk-test-key").await.unwrap();
 
         let tools: Vec<Arc<dyn Tool>> = vec![];
-        let agent = ZeniiAgent::new(&config, &creds, &tools).await;
+        let agent = ZeniiAgent::new(&config, &creds, &tools, None).await;
         assert!(agent.is_ok());
     }
 
@@ -554,7 +1370,7 @@ mod tests {
         creds.set("api_key:anthropic", "sk-ant-test").await.unwrap();
 
         let tools: Vec<Arc<dyn Tool>> = vec![];
-        let agent = ZeniiAgent::new(&config, &creds, &tools).await;
+        let agent = ZeniiAgent::new(&config, &creds, &tools, None).await;
         assert!(agent.is_ok());
     }
 
@@ -570,7 +1386,7 @@ mod tests {
         creds.set("api_key:bad", "sk-test").await.unwrap();
 
         let tools: Vec<Arc<dyn Tool>> = vec![];
-        let result = ZeniiAgent::new(&config, &creds, &tools).await;
+        let result = ZeniiAgent::new(&config, &creds, &tools, None).await;
         assert!(result.is_err());
         let err = result.err().unwrap();
         assert!(matches!(err, ZeniiError::Agent(_)));
@@ -589,11 +1405,13 @@ mod tests {
             "https://api.openai.com/v1",
             "gpt-4o",
             true,
+            &Default::default(),
             &creds,
             &tools,
             &config,
             None,
             None,
+            None,
         )
         .await;
         assert!(agent.is_ok());
@@ -612,11 +1430,13 @@ mod tests {
             "https://api.anthropic.com",
             "claude-sonnet-4-20250514",
             true,
+            &Default::default(),
             &creds,
             &tools,
             &config,
             None,
             None,
+            None,
         )
         .await;
         assert!(agent.is_ok());
@@ -635,11 +1455,13 @@ mod tests {
             "https://openrouter.ai/api/v1",
             "anthropic/claude-sonnet-4-20250514",
             true,
+            &Default::default(),
             &creds,
             &tools,
             &config,
             None,
             None,
+            None,
         )
         .await;
         assert!(agent.is_ok());
@@ -657,11 +1479,13 @@ mod tests {
             "http://localhost:11434/v1",
             "llama3",
             false,
+            &Default::default(),
             &creds,
             &tools,
             &config,
             None,
             None,
+            None,
         )
         .await;
         assert!(agent.is_ok());
@@ -679,11 +1503,13 @@ mod tests {
             "https://api.openai.com/v1",
             "gpt-4o",
             true,
+            &Default::default(),
             &creds,
             &tools,
             &config,
             None,
             None,
+            None,
         )
         .await;
         assert!(result.is_err());
@@ -767,7 +1593,7 @@ mod tests {
             Arc::new(CounterTool { name: "step2" }),
         ];
         let config = AppConfig::default();
-        let adapters = RigToolAdapter::from_tools(&tools, &config);
+        let adapters = RigToolAdapter::from_tools(&tools, None, &config);
 
         let r1 = adapters[0].call("{}".into()).await.unwrap();
         let p1: ToolResult = serde_json::from_str(&r1).unwrap();
@@ -794,7 +1620,7 @@ mod tests {
         let creds = InMemoryCredentialStore::new();
         creds.set("api_key:openai", "sk-test").await.unwrap();
         let tools: Vec<Arc<dyn crate::tools::Tool>> = vec![];
-        let agent = ZeniiAgent::new(&config, &creds, &tools).await;
+        let agent = ZeniiAgent::new(&config, &creds, &tools, None).await;
         assert!(agent.is_ok(), "Agent should build with max_turns=1");
 
         // A second agent with max_turns=0 should also build (rig handles it)
@@ -804,7 +1630,7 @@ mod tests {
             agent_max_turns: 0,
             ..Default::default()
         };
-        let agent2 = ZeniiAgent::new(&config2, &creds, &tools).await;
+        let agent2 = ZeniiAgent::new(&config2, &creds, &tools, None).await;
         assert!(agent2.is_ok());
     }
 
@@ -908,7 +1734,7 @@ mod tests {
         creds.set("api_key:openai", "sk-test").await.unwrap();
 
         let tools: Vec<Arc<dyn Tool>> = vec![];
-        let agent = ZeniiAgent::new(&config, &creds, &tools).await;
+        let agent = ZeniiAgent::new(&config, &creds, &tools, None).await;
         assert!(agent.is_ok());
     }
 
@@ -962,6 +1788,28 @@ mod tests {
         assert_eq!(sum.cached_input_tokens, 7);
     }
 
+    // 8.14.3b — Cost is estimated from pricing; missing usage yields None
+    #[test]
+    fn token_usage_estimate_cost() {
+        let pricing = ModelPricing {
+            input_per_mtok: 3.0,
+            output_per_mtok: 15.0,
+        };
+        let usage = TokenUsage {
+            input_tokens: 1_000,
+            output_tokens: 200,
+            total_tokens: 1_200,
+            cached_input_tokens: 0,
+        };
+        assert_eq!(usage.reported_total(), Some(1_200));
+        let cost = usage.estimate_cost(&pricing).unwrap();
+        assert!((cost - 0.006).abs() < 1e-9);
+
+        let missing = TokenUsage::default();
+        assert_eq!(missing.reported_total(), None);
+        assert_eq!(missing.estimate_cost(&pricing), None);
+    }
+
     // 8.14.4 — AgentResponse contains output and usage
     #[test]
     fn agent_response_fields() {
@@ -977,4 +1825,363 @@ mod tests {
         assert_eq!(resp.output, "hello");
         assert_eq!(resp.usage.total_tokens, 15);
     }
+
+    // 4.13 — a rate-limited model fails over to the next fallback; a bad request does not
+    #[tokio::test]
+    async fn failover_on_rate_limit_but_not_bad_request() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+
+        let hits = Arc::new(parking_lot::Mutex::new(Vec::<String>::new()));
+        let server_hits = hits.clone();
+        let url = openai_mock(move |_, request| {
+            let model = request
+                .split("\"model\":\"")
+                .nth(1)
+                .and_then(|rest| rest.split('"').next())
+                .unwrap_or_default()
+                .to_string();
+            server_hits.lock().push(model.clone());
+            match model.as_str() {
+                "limited" => MockReply::json(
+                    "429 Too Many Requests",
+                    json!({"error": {"message": "Rate limit exceeded", "code": 429}}),
+                ),
+                "broken" => MockReply::json(
+                    "400 Bad Request",
+                    json!({"error": {"message": "Bad request", "code": 400}}),
+                ),
+                _ => MockReply::text("from fallback"),
+            }
+        })
+        .await;
+        state
+            .provider_registry
+            .add_user_provider(
+                "mock",
+                "Mock",
+                &url,
+                false,
+                &[
+                    ("limited".into(), "Limited".into(), true),
+                    ("broken".into(), "Broken".into(), true),
+                    ("good".into(), "Good".into(), true),
+                ],
+            )
+            .await
+            .unwrap();
+        let mut config = (*state.config.load_full()).clone();
+        config.routing_fallbacks =
+            std::collections::HashMap::from([("default".into(), vec!["mock:good".into()])]);
+        config.provider_retry_initial_backoff_ms = 1;
+        state.config.store(Arc::new(config));
+
+        let mut rx = state.event_bus.subscribe();
+        let agent = resolve_agent(Some("mock:limited"), &state, None, None, "desktop")
+            .await
+            .unwrap();
+        let resp = agent.chat("hello", vec![]).await.unwrap();
+        assert_eq!(resp.output, "from fallback");
+        // 429 is retried on the same model before failing over
+        assert_eq!(*hits.lock(), ["limited", "limited", "limited", "good"]);
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            AppEvent::ModelFailover { failed_model, next_model, .. }
+                if failed_model == "mock:limited" && next_model == "mock:good"
+        ));
+
+        hits.lock().clear();
+        let agent = resolve_agent(Some("mock:broken"), &state, None, None, "desktop")
+            .await
+            .unwrap();
+        assert!(agent.chat("hello", vec![]).await.is_err());
+        assert_eq!(*hits.lock(), ["broken"]);
+    }
+
+    // Turn budget: remaining() subtracts spend and fails once a limit is used up
+    #[test]
+    fn turn_budget_remaining() {
+        let unbounded = TurnBudget::default();
+        assert_eq!(
+            unbounded
+                .remaining(1_000_000, Duration::from_secs(3600))
+                .unwrap(),
+            unbounded
+        );
+
+        let budget = TurnBudget {
+            max_total_tokens: Some(100),
+            max_wall_time: Some(Duration::from_secs(10)),
+        };
+        let left = budget.remaining(40, Duration::from_secs(4)).unwrap();
+        assert_eq!(left.max_total_tokens, Some(60));
+        assert_eq!(left.max_wall_time, Some(Duration::from_secs(6)));
+
+        let err = budget.remaining(100, Duration::ZERO).unwrap_err();
+        assert!(
+            matches!(err, ZeniiError::AgentTurn(AgentError::BudgetExceeded(ref m)) if m.contains("token budget of 100"))
+        );
+        let err = budget.remaining(0, Duration::from_secs(10)).unwrap_err();
+        assert!(
+            matches!(err, ZeniiError::AgentTurn(AgentError::BudgetExceeded(ref m)) if m.contains("wall time"))
+        );
+    }
+
+    #[test]
+    fn turn_budget_from_config() {
+        let mut config = AppConfig::default();
+        assert_eq!(TurnBudget::from_config(&config), TurnBudget::default());
+        config.agent_max_total_tokens = Some(5000);
+        config.agent_max_wall_time_secs = Some(60);
+        let budget = TurnBudget::from_config(&config);
+        assert_eq!(budget.max_total_tokens, Some(5000));
+        assert_eq!(budget.max_wall_time, Some(Duration::from_secs(60)));
+    }
+
+    /// OpenAI-compatible mock that always answers with an `echo` tool call
+    /// costing 100 tokens, after `delay`. Returns the base URL and hit counter.
+    async fn tool_loop_server(delay: Duration) -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        let hits = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let server_hits = hits.clone();
+        let url = openai_mock(move |_, _| {
+            let n = server_hits.fetch_add(1, Ordering::SeqCst);
+            let body = json!({
+                "id": format!("chatcmpl-{n}"),
+                "object": "chat.completion",
+                "created": 0,
+                "model": "loop",
+                "choices": [{
+                    "index": 0,
+                    "message": {
+                        "role": "assistant",
+                        "content": null,
+                        "tool_calls": [{
+                            "id": format!("call_{n}"),
+                            "type": "function",
+                            "function": {"name": "echo", "arguments": "{\"text\":\"again\"}"}
+                        }]
+                    },
+                    "finish_reason": "tool_calls"
+                }],
+                "usage": {"prompt_tokens": 90, "completion_tokens": 10, "total_tokens": 100}
+            });
+            MockReply::json("200 OK", body).after(delay)
+        })
+        .await;
+        (url, hits)
+    }
+
+    async fn looping_agent(base_url: &str, config: &AppConfig) -> ZeniiAgent {
+        struct EchoTool;
+        #[async_trait::async_trait]
+        impl crate::tools::Tool for EchoTool {
+            fn name(&self) -> &str {
+                "echo"
+            }
+            fn description(&self) -> &str {
+                "Echoes input"
+            }
+            fn parameters_schema(&self) -> serde_json::Value {
+                json!({"type": "object", "properties": {"text": {"type": "string"}}})
+            }
+            async fn execute(&self, _args: serde_json::Value) -> Result<crate::tools::ToolResult> {
+                Ok(crate::tools::ToolResult::ok("echoed"))
+            }
+        }
+
+        let tools: Vec<Arc<dyn Tool>> = vec![Arc::new(EchoTool)];
+        ZeniiAgent::from_provider(
+            "mock",
+            base_url,
+            "loop",
+            false,
+            &Default::default(),
+            &InMemoryCredentialStore::new(),
+            &tools,
+            config,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+    }
+
+    // A runaway tool loop stops once the token budget is spent
+    #[tokio::test]
+    async fn chat_stops_when_token_budget_exceeded() {
+        let (base_url, hits) = tool_loop_server(Duration::ZERO).await;
+        let config = AppConfig {
+            agent_max_turns: 20,
+            agent_max_total_tokens: Some(250),
+            ..Default::default()
+        };
+        let agent = looping_agent(&base_url, &config).await;
+
+        let err = agent.chat("loop forever", vec![]).await.unwrap_err();
+        assert!(
+            matches!(err, ZeniiError::AgentTurn(AgentError::BudgetExceeded(ref m)) if m.contains("300 tokens used")),
+            "{err}"
+        );
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        assert!(!err.is_recoverable());
+    }
+
+    // A turn stalled on a slow model or tool ends at the wall-time limit
+    #[tokio::test]
+    async fn chat_stops_at_wall_time_limit() {
+        let (base_url, _hits) = tool_loop_server(Duration::from_secs(5)).await;
+        let agent = looping_agent(&base_url, &AppConfig::default())
+            .await
+            .with_budget(TurnBudget {
+                max_total_tokens: None,
+                max_wall_time: Some(Duration::from_millis(100)),
+            });
+
+        let started = std::time::Instant::now();
+        let err = agent.chat("hello", vec![]).await.unwrap_err();
+        assert!(
+            matches!(err, ZeniiError::AgentTurn(AgentError::BudgetExceeded(ref m)) if m.contains("100ms"))
+        );
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    /// How [`text_server`] answers streaming requests.
+    #[derive(Clone, Copy, PartialEq)]
+    enum StreamMode {
+        Sse,
+        Reject,
+        Drop,
+    }
+
+    /// OpenAI-compatible mock answering "Hello there". Streaming requests get
+    /// SSE deltas, a 400, or a closed connection depending on `mode`.
+    async fn text_server(mode: StreamMode) -> String {
+        openai_mock(move |_, request| {
+            if !request.contains("\"stream\":true") {
+                let mut body = completion("Hello there");
+                body["usage"] =
+                    json!({"prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7});
+                return MockReply::json("200 OK", body);
+            }
+            match mode {
+                StreamMode::Sse => {
+                    let chunk = |delta: serde_json::Value, finish: serde_json::Value| {
+                        json!({
+                            "id": "chatcmpl-s",
+                            "object": "chat.completion.chunk",
+                            "created": 0,
+                            "model": "mock",
+                            "choices": [{"index": 0, "delta": delta, "finish_reason": finish}]
+                        })
+                    };
+                    let mut last = chunk(json!({}), json!("stop"));
+                    last["usage"] =
+                        json!({"prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7});
+                    MockReply::sse(&[
+                        chunk(
+                            json!({"role": "assistant", "content": "Hello"}),
+                            json!(null),
+                        ),
+                        chunk(json!({"content": " there"}), json!(null)),
+                        last,
+                    ])
+                }
+                StreamMode::Reject => {
+                    MockReply::error("400 Bad Request", "streaming is not supported")
+                }
+                StreamMode::Drop => MockReply::Close,
+            }
+        })
+        .await
+    }
+
+    async fn collect_stream(base_url: &str) -> (Result<AgentResponse>, Vec<String>) {
+        let agent = looping_agent(base_url, &AppConfig::default()).await;
+        let deltas = std::sync::Mutex::new(Vec::new());
+        let resp = agent
+            .chat_stream("hi", vec![], agent.budget(), &|delta: &str| {
+                deltas.lock().unwrap().push(delta.to_string())
+            })
+            .await;
+        (resp, deltas.into_inner().unwrap())
+    }
+
+    // Streamed chat forwards text deltas and still returns the full answer
+    #[tokio::test]
+    async fn chat_stream_emits_text_deltas() {
+        let (resp, deltas) = collect_stream(&text_server(StreamMode::Sse).await).await;
+        let resp = resp.unwrap();
+        assert_eq!(deltas, ["Hello", " there"]);
+        assert_eq!(resp.output, "Hello there");
+        assert_eq!(resp.usage.total_tokens, 7);
+    }
+
+    // A stream lost in transport degrades to one final delta
+    #[tokio::test]
+    async fn chat_stream_falls_back_on_transport_error() {
+        let (resp, deltas) = collect_stream(&text_server(StreamMode::Drop).await).await;
+        let resp = resp.unwrap();
+        assert_eq!(deltas, ["Hello there"]);
+        assert_eq!(resp.output, "Hello there");
+    }
+
+    // An HTTP error from the streaming request is returned, not retried unstreamed
+    #[tokio::test]
+    async fn chat_stream_returns_http_errors() {
+        let (resp, deltas) = collect_stream(&text_server(StreamMode::Reject).await).await;
+        let err = resp.unwrap_err();
+        assert!(err.to_string().contains("400"), "{err}");
+        assert!(deltas.is_empty());
+    }
+
+    /// Mock provider answering the first `failures` requests with `status`.
+    async fn flaky_server(
+        failures: u32,
+        status: &'static str,
+    ) -> (String, Arc<std::sync::atomic::AtomicU32>) {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let hits = Arc::new(AtomicU32::new(0));
+        let counter = hits.clone();
+        let url = openai_mock(move |_, _| {
+            if counter.fetch_add(1, Ordering::SeqCst) < failures {
+                MockReply::error(status, "try later")
+            } else {
+                MockReply::text("recovered")
+            }
+        })
+        .await;
+        (url, hits)
+    }
+
+    fn fast_retry_config() -> AppConfig {
+        AppConfig {
+            provider_retry_initial_backoff_ms: 1,
+            ..Default::default()
+        }
+    }
+
+    // Transient 5xx/429 responses are retried transparently
+    #[tokio::test]
+    async fn provider_request_retries_transient_failures() {
+        let (url, hits) = flaky_server(2, "503 Service Unavailable").await;
+        let agent = looping_agent(&url, &fast_retry_config()).await;
+        let resp = agent.chat("hi", vec![]).await.unwrap();
+        assert_eq!(resp.output, "recovered");
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        let (url, hits) = flaky_server(1, "429 Too Many Requests").await;
+        let agent = looping_agent(&url, &fast_retry_config()).await;
+        assert_eq!(agent.chat("hi", vec![]).await.unwrap().output, "recovered");
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    // Other client errors fail on the first attempt
+    #[tokio::test]
+    async fn provider_request_does_not_retry_client_errors() {
+        let (url, hits) = flaky_server(1, "401 Unauthorized").await;
+        let agent = looping_agent(&url, &fast_retry_config()).await;
+        assert!(agent.chat("hi", vec![]).await.is_err());
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
 }
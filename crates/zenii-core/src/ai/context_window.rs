@@ -0,0 +1,262 @@
+use std::sync::Arc;
+
+use rig::message::{Message, UserContent};
+use tracing::warn;
+
+use crate::ai::prompt::TokenBudget;
+use crate::config::AppConfig;
+use crate::event_bus::{AppEvent, EventBus};
+use crate::tools::traits::Tool;
+
+/// Framing tokens per message (role markers, separators).
+const MESSAGE_OVERHEAD_TOKENS: usize = 4;
+
+/// Rough token count in the spirit of tiktoken's ~4 characters per token.
+/// Prose with many short words falls back to the per-word estimate so it is
+/// never undercounted.
+pub fn estimate_tokens(text: &str) -> usize {
+    let by_chars = text.chars().count().div_ceil(4);
+    by_chars.max(TokenBudget::estimate_tokens(text))
+}
+
+/// Estimate for one history message, measured on its serialized form so tool
+/// calls and results count too.
+fn message_tokens(message: &Message) -> usize {
+    let text = serde_json::to_string(message).unwrap_or_default();
+    MESSAGE_OVERHEAD_TOKENS + estimate_tokens(&text)
+}
+
+/// Whether `message` opens an exchange: a user turn, not a tool result fed
+/// back to the model.
+fn starts_exchange(message: &Message) -> bool {
+    matches!(message, Message::User { content }
+        if content.iter().any(|c| !matches!(c, UserContent::ToolResult(_))))
+}
+
+/// A model's context window and what every request to it spends up front.
+#[derive(Debug, Clone)]
+pub struct ContextWindow {
+    /// Model name used in logs and the truncation event.
+    pub model: String,
+    /// Context limit in tokens; `agent_default_context_limit` when the
+    /// model's own limit is unknown.
+    pub limit: usize,
+    /// Tokens kept free for the response (`agent_max_tokens`), capped at half
+    /// the limit so a small window still holds a prompt.
+    pub reserve: usize,
+    /// Estimated tokens of the system prompt and tool definitions.
+    pub fixed_tokens: usize,
+}
+
+/// History after fitting it into a context window.
+#[derive(Debug)]
+pub struct FittedHistory {
+    pub history: Vec<Message>,
+    /// Oldest messages dropped to make room.
+    pub dropped: usize,
+    /// Estimated prompt tokens of the request that will be sent.
+    pub estimated_tokens: usize,
+}
+
+impl ContextWindow {
+    pub fn new(config: &AppConfig, model: String, preamble: &str, tools: &[Arc<dyn Tool>]) -> Self {
+        let tool_tokens: usize = tools
+            .iter()
+            .map(|t| {
+                estimate_tokens(t.name())
+                    + estimate_tokens(t.description())
+                    + estimate_tokens(&t.parameters_schema().to_string())
+            })
+            .sum();
+        Self {
+            model,
+            limit: config.agent_default_context_limit,
+            reserve: config.agent_max_tokens,
+            fixed_tokens: estimate_tokens(preamble) + tool_tokens,
+        }
+    }
+
+    /// Use the model's known limit. Missing or non-positive values keep the
+    /// default.
+    pub fn with_limit(mut self, limit: Option<i64>) -> Self {
+        if let Some(limit) = limit
+            .and_then(|l| usize::try_from(l).ok())
+            .filter(|l| *l > 0)
+        {
+            self.limit = limit;
+        }
+        self
+    }
+
+    /// Tokens available for the prompt once the response reserve is set aside.
+    pub fn available(&self) -> usize {
+        self.limit - self.reserve.min(self.limit / 2)
+    }
+
+    /// Drop the oldest history messages until system prompt, tools, history
+    /// and `input` fit in [`Self::available`]. Whole exchanges go together, so
+    /// trimmed history starts with a user message.
+    pub fn fit(&self, input: &str, mut history: Vec<Message>) -> FittedHistory {
+        let sizes: Vec<usize> = history.iter().map(message_tokens).collect();
+        let mut total = self.fixed_tokens
+            + MESSAGE_OVERHEAD_TOKENS
+            + estimate_tokens(input)
+            + sizes.iter().sum::<usize>();
+        let mut dropped = 0;
+        while dropped < sizes.len()
+            && (total > self.available() || (dropped > 0 && !starts_exchange(&history[dropped])))
+        {
+            total -= sizes[dropped];
+            dropped += 1;
+        }
+        history.drain(..dropped);
+        FittedHistory {
+            history,
+            dropped,
+            estimated_tokens: total,
+        }
+    }
+
+    /// [`Self::fit`], logging and publishing `ContextTruncated` when history
+    /// had to be dropped.
+    pub fn enforce(
+        &self,
+        input: &str,
+        history: Vec<Message>,
+        event_bus: Option<&Arc<dyn EventBus>>,
+    ) -> Vec<Message> {
+        let fitted = self.fit(input, history);
+        if fitted.estimated_tokens > self.available() {
+            warn!(
+                "Request to {} is estimated at {} tokens, over its {} token budget even without history",
+                self.model,
+                fitted.estimated_tokens,
+                self.available()
+            );
+        }
+        if fitted.dropped > 0 {
+            warn!(
+                "Dropped {} oldest history messages to fit {}'s context window ({} of {} tokens)",
+                fitted.dropped, self.model, fitted.estimated_tokens, self.limit
+            );
+            if let Some(bus) = event_bus {
+                let _ = bus.publish(AppEvent::ContextTruncated {
+                    model: self.model.clone(),
+                    dropped_messages: fitted.dropped,
+                    estimated_tokens: fitted.estimated_tokens,
+                    context_limit: self.limit,
+                });
+            }
+        }
+        fitted.history
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(limit: usize, reserve: usize) -> ContextWindow {
+        ContextWindow {
+            model: "test:model".into(),
+            limit,
+            reserve,
+            fixed_tokens: 0,
+        }
+    }
+
+    fn history(count: usize, words: usize) -> Vec<Message> {
+        (0..count)
+            .map(|i| Message::user(format!("m{i} {}", "word ".repeat(words))))
+            .collect()
+    }
+
+    // CTX.1 — Estimates never fall below ~4 chars per token or the word count
+    #[test]
+    fn estimate_tokens_uses_larger_heuristic() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens(&"x".repeat(40)), 10);
+        assert_eq!(estimate_tokens("a b c d e f g h i j"), 13);
+    }
+
+    // CTX.2 — History that fits is sent unchanged
+    #[test]
+    fn fit_keeps_history_within_limit() {
+        let fitted = window(100_000, 4_096).fit("hello", history(10, 10));
+        assert_eq!(fitted.dropped, 0);
+        assert_eq!(fitted.history.len(), 10);
+        // The response reserve never takes more than half the window
+        assert_eq!(window(8_000, 6_000).available(), 4_000);
+    }
+
+    // CTX.3 — Oldest messages go first once the reserve would be eaten into
+    #[test]
+    fn fit_drops_oldest_messages() {
+        let history = history(10, 100);
+        let per_message = message_tokens(&history[0]);
+        let window = window(per_message * 4 + 100, 100);
+
+        let fitted = window.fit("hi", history);
+        assert!(fitted.dropped >= 7, "dropped {}", fitted.dropped);
+        assert!(fitted.estimated_tokens <= window.available());
+        let first = serde_json::to_string(&fitted.history[0]).unwrap();
+        assert!(first.contains(&format!("m{} ", fitted.dropped)), "{first}");
+    }
+
+    // CTX.6 — Trimming drops the assistant reply with its user message
+    #[test]
+    fn fit_drops_whole_exchanges() {
+        let history: Vec<Message> = (0..10)
+            .map(|i| {
+                let text = format!("m{i} {}", "word ".repeat(100));
+                if i % 2 == 0 {
+                    Message::user(text)
+                } else {
+                    Message::assistant(text)
+                }
+            })
+            .collect();
+        let per_message = message_tokens(&history[0]);
+        // Room for three messages: the oldest kept exchange must be complete
+        let window = window(per_message * 3 + 100, 100);
+
+        let fitted = window.fit("hi", history);
+        assert_eq!(fitted.dropped % 2, 0, "dropped {}", fitted.dropped);
+        assert!(starts_exchange(&fitted.history[0]));
+        assert!(fitted.estimated_tokens <= window.available());
+    }
+
+    // CTX.4 — Unknown limits keep the configured default
+    #[test]
+    fn with_limit_ignores_missing_values() {
+        let config = AppConfig::default();
+        let base = ContextWindow::new(&config, "m".into(), "You are Zenii.", &[]);
+        assert_eq!(base.limit, config.agent_default_context_limit);
+        assert_eq!(base.clone().with_limit(None).limit, base.limit);
+        assert_eq!(base.clone().with_limit(Some(0)).limit, base.limit);
+        assert_eq!(base.with_limit(Some(200_000)).limit, 200_000);
+    }
+
+    // CTX.5 — Forced truncation is published on the event bus
+    #[tokio::test]
+    async fn enforce_publishes_truncation_event() {
+        let bus: Arc<dyn EventBus> = Arc::new(crate::event_bus::TokioBroadcastBus::new(16));
+        let mut rx = bus.subscribe();
+
+        let kept = window(600, 100).enforce("hi", history(20, 50), Some(&bus));
+        assert!(kept.len() < 20);
+        match rx.try_recv().unwrap() {
+            AppEvent::ContextTruncated {
+                model,
+                dropped_messages,
+                context_limit,
+                ..
+            } => {
+                assert_eq!(model, "test:model");
+                assert_eq!(dropped_messages, 20 - kept.len());
+                assert_eq!(context_limit, 600);
+            }
+            other => panic!("unexpected event {other:?}"),
+        }
+    }
+}
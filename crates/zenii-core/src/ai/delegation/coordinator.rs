@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use dashmap::DashMap;
 use tracing::{debug, info, warn};
@@ -48,7 +48,9 @@ impl Coordinator {
         }
     }
 
-    /// Validate that tasks respect config constraints.
+    /// Validate that tasks respect config constraints and form a dependency
+    /// graph without cycles. `tool_names` lists every name an allowlist may
+    /// reference, tool profiles included.
     pub fn validate_tasks(&self, tasks: &[DelegationTask], tool_names: &[String]) -> Result<()> {
         if tasks.len() > self.config.max_sub_agents {
             return Err(ZeniiError::Validation(format!(
@@ -63,7 +65,7 @@ impl Coordinator {
                 for tool in allowlist {
                     if !tool_names.contains(tool) {
                         return Err(ZeniiError::Validation(format!(
-                            "unknown tool '{}' in task '{}' allowlist",
+                            "unknown tool or profile '{}' in task '{}' allowlist",
                             tool, task.id
                         )));
                     }
@@ -71,7 +73,7 @@ impl Coordinator {
             }
         }
 
-        Ok(())
+        validate_graph(tasks)
     }
 
     /// Decompose a prompt into sub-tasks using the LLM.
@@ -81,22 +83,27 @@ impl Coordinator {
         prompt: &str,
         agent: &crate::ai::agent::ZeniiAgent,
         available_tools: &[String],
+        tool_profiles: &[String],
     ) -> Result<Vec<DelegationTask>> {
         let tools_list = available_tools.join(", ");
+        let profiles_list = tool_profiles.join(", ");
         let decompose_prompt = format!(
             "You are a task decomposition agent. Break the following task into {} or fewer \
              sub-tasks that can be executed IN PARALLEL by separate AI agents.\n\n\
-             Available tools: [{tools_list}]\n\n\
+             Available tools: [{tools_list}]\n\
+             Tool profiles (each grants a group of tools): [{profiles_list}]\n\n\
              Return a JSON array of tasks. Each task object must have:\n\
              - \"id\": a unique string like \"t1\", \"t2\"\n\
              - \"description\": what the sub-agent should accomplish (include full context needed)\n\
-             - \"tool_allowlist\": optional array of tool names from the available tools list above, or null for all tools\n\
+             - \"tool_allowlist\": optional array of tool or profile names from the lists above, or null for all tools\n\
              - \"depends_on\": array of task IDs this task depends on\n\n\
              CRITICAL: Set depends_on to [] (empty) for ALL tasks UNLESS one task strictly \
              requires the output of another. Maximize parallelism — independent research, \
              analysis, and data gathering tasks should ALWAYS run in parallel with empty \
-             depends_on arrays. Each task description must be self-contained with all context \
-             needed, since agents cannot see each other's work.\n\n\
+             depends_on arrays. A task starts only after the tasks in its depends_on finish \
+             and receives their outputs; it cannot see any other task's work, so each \
+             description must otherwise be self-contained. Dependencies must not form a \
+             cycle.\n\n\
              Task: {}\n\n\
              Return ONLY a valid JSON array, no markdown formatting or explanation.",
             self.config.max_sub_agents, prompt
@@ -115,28 +122,13 @@ impl Coordinator {
             task.timeout_secs = self.config.per_agent_timeout_secs;
         }
 
-        // Validate structural integrity of decomposed tasks
-        {
-            let mut seen_ids = std::collections::HashSet::new();
-            for task in &tasks {
-                if !seen_ids.insert(&task.id) {
-                    return Err(ZeniiError::Validation(format!(
-                        "duplicate task id '{}'",
-                        task.id
-                    )));
-                }
-                if task.depends_on.contains(&task.id) {
-                    return Err(ZeniiError::Validation(format!(
-                        "task '{}' depends on itself",
-                        task.id
-                    )));
-                }
-                if task.description.len() > 2000 {
-                    return Err(ZeniiError::Validation(format!(
-                        "task '{}' description exceeds 2000 chars",
-                        task.id
-                    )));
-                }
+        // Ids and dependencies are checked by validate_tasks
+        for task in &tasks {
+            if task.description.len() > 2000 {
+                return Err(ZeniiError::Validation(format!(
+                    "task '{}' description exceeds 2000 chars",
+                    task.id
+                )));
             }
         }
 
@@ -182,12 +174,17 @@ impl Coordinator {
         let agent =
             crate::ai::resolve_agent(decomp_model, state, None, Some(&preamble), surface).await?;
 
-        let tasks = self.decompose(prompt, &agent, &tool_names).await?;
+        let profile_names = crate::tools::profiles::profile_names(&cfg.tool_profiles);
+        let tasks = self
+            .decompose(prompt, &agent, &tool_names, &profile_names)
+            .await?;
         if tasks.is_empty() {
             return Err(ZeniiError::Agent("decomposition produced no tasks".into()));
         }
 
-        self.validate_tasks(&tasks, &tool_names)?;
+        let mut allowlist_names = tool_names;
+        allowlist_names.extend(profile_names);
+        self.validate_tasks(&tasks, &allowlist_names)?;
 
         info!(
             delegation_id = %delegation_id,
@@ -209,149 +206,161 @@ impl Coordinator {
                     .collect(),
             });
 
-        // Execute tasks in dependency waves
-        let mut completed: HashMap<String, TaskResult> = HashMap::new();
-        let mut remaining: Vec<DelegationTask> = tasks;
-
-        while !remaining.is_empty() {
-            let (ready, not_ready): (Vec<_>, Vec<_>) = remaining
-                .into_iter()
-                .partition(|t| t.depends_on.iter().all(|dep| completed.contains_key(dep)));
-
-            if ready.is_empty() {
-                warn!(
-                    "Delegation {}: {} tasks stuck with unresolved dependencies",
-                    delegation_id,
-                    not_ready.len()
-                );
-                for task in not_ready {
-                    completed.insert(
-                        task.id.clone(),
-                        TaskResult {
-                            task_id: task.id,
-                            status: TaskStatus::Failed,
-                            output: String::new(),
-                            usage: TokenUsage::default(),
-                            duration_ms: 0,
-                            error: Some("unresolved dependencies".into()),
-                            session_id: String::new(),
-                            tool_uses: 0,
-                            description: task.description.clone(),
-                            hint: None,
-                        },
-                    );
+        // Start each task as soon as everything it depends on has finished, so
+        // independent branches of the graph run concurrently
+        let order: Vec<String> = tasks.iter().map(|t| t.id.clone()).collect();
+        let mut finished: HashMap<String, TaskResult> = HashMap::new();
+        let mut pending: Vec<DelegationTask> = tasks;
+        let mut running: HashMap<tokio::task::Id, (String, String)> = HashMap::new();
+        let mut join_set = tokio::task::JoinSet::new();
+
+        loop {
+            // Skipping a task can unblock its own dependents, so repeat until
+            // no more tasks become ready
+            loop {
+                let (ready, blocked): (Vec<_>, Vec<_>) = pending
+                    .into_iter()
+                    .partition(|t| t.depends_on.iter().all(|dep| finished.contains_key(dep)));
+                pending = blocked;
+                if ready.is_empty() {
+                    break;
                 }
-                break;
-            }
 
-            remaining = not_ready;
-
-            let mut join_set = tokio::task::JoinSet::new();
-            let mut wave_task_ids: Vec<String> = Vec::new();
-            let mut wave_handles: Vec<tokio::task::AbortHandle> = Vec::new();
-            for task in ready {
-                let task_id = task.id.clone();
-                let task_desc = task.description.clone();
-                let _ = state
-                    .event_bus
-                    .publish(crate::event_bus::AppEvent::SubAgentSpawned {
-                        delegation_id: delegation_id.clone(),
-                        agent_id: task_id.clone(),
-                        task: task_desc.clone(),
-                    });
-
-                match SubAgent::new(task, state, surface, delegation_id.clone()).await {
-                    Ok(sub) => {
-                        wave_task_ids.push(task_id.clone());
-                        let abort_handle = join_set.spawn(async move {
-                            let result = sub.execute().await;
-                            (task_id, result)
-                        });
-                        wave_handles.push(abort_handle);
-                    }
-                    Err(e) => {
-                        warn!("Failed to create sub-agent for {}: {e}", task_id);
-                        completed.insert(
-                            task_id.clone(),
-                            TaskResult {
-                                task_id,
-                                status: TaskStatus::Failed,
-                                output: String::new(),
-                                usage: TokenUsage::default(),
-                                duration_ms: 0,
-                                error: Some(e.to_string()),
-                                session_id: String::new(),
-                                tool_uses: 0,
-                                description: task_desc,
-                                hint: None,
-                            },
-                        );
+                let mut handles: Vec<tokio::task::AbortHandle> = Vec::new();
+                for task in ready {
+                    let task_id = task.id.clone();
+                    let task_desc = task.description.clone();
+
+                    if let Some(dep) = failed_dependency(&task, &finished) {
+                        let result = skipped_result(&task, dep);
+                        let _ =
+                            state
+                                .event_bus
+                                .publish(crate::event_bus::AppEvent::SubAgentFailed {
+                                    delegation_id: delegation_id.clone(),
+                                    agent_id: task_id.clone(),
+                                    error: result.error.clone().unwrap_or_default(),
+                                    tool_uses: 0,
+                                    duration_ms: 0,
+                                });
+                        finished.insert(task_id, result);
+                        continue;
                     }
-                }
-            }
 
-            self.active
-                .entry(delegation_id.clone())
-                .or_default()
-                .extend(wave_handles.iter().cloned());
-
-            while let Some(result) = join_set.join_next().await {
-                match result {
-                    Ok((task_id, task_result)) => {
-                        let event = if task_result.status == TaskStatus::Completed {
-                            crate::event_bus::AppEvent::SubAgentCompleted {
-                                delegation_id: delegation_id.clone(),
-                                agent_id: task_id.clone(),
-                                status: "completed".into(),
-                                duration_ms: task_result.duration_ms,
-                                tool_uses: task_result.tool_uses,
-                                tokens_used: task_result.usage.total_tokens,
-                            }
-                        } else {
-                            crate::event_bus::AppEvent::SubAgentFailed {
-                                delegation_id: delegation_id.clone(),
-                                agent_id: task_id.clone(),
-                                error: task_result.error.clone().unwrap_or_default(),
-                                tool_uses: task_result.tool_uses,
-                                duration_ms: task_result.duration_ms,
-                            }
-                        };
-                        let _ = state.event_bus.publish(event);
-                        completed.insert(task_id, task_result);
-                    }
-                    Err(e) => {
-                        warn!("Sub-agent task panicked: {e}");
+                    let _ = state
+                        .event_bus
+                        .publish(crate::event_bus::AppEvent::SubAgentSpawned {
+                            delegation_id: delegation_id.clone(),
+                            agent_id: task_id.clone(),
+                            task: task_desc.clone(),
+                        });
+
+                    let task = with_upstream_context(task, &finished);
+                    match SubAgent::new(task, state, surface, delegation_id.clone()).await {
+                        Ok(sub) => {
+                            let description = task_desc.clone();
+                            let abort_handle = join_set.spawn(async move {
+                                let mut result = sub.execute().await;
+                                // Report the task as decomposed, without upstream context
+                                result.description = description;
+                                result
+                            });
+                            running.insert(abort_handle.id(), (task_id, task_desc));
+                            handles.push(abort_handle);
+                        }
+                        Err(e) => {
+                            warn!("Failed to create sub-agent for {}: {e}", task_id);
+                            finished.insert(
+                                task_id.clone(),
+                                TaskResult {
+                                    task_id,
+                                    status: TaskStatus::Failed,
+                                    output: String::new(),
+                                    usage: TokenUsage::default(),
+                                    duration_ms: 0,
+                                    error: Some(e.to_string()),
+                                    session_id: String::new(),
+                                    tool_uses: 0,
+                                    description: task_desc,
+                                    hint: None,
+                                    workspace_diff: None,
+                                },
+                            );
+                        }
                     }
                 }
+
+                self.active
+                    .entry(delegation_id.clone())
+                    .or_default()
+                    .extend(handles);
             }
 
-            // Insert failed results for any spawned tasks that panicked
-            for wave_id in &wave_task_ids {
-                if !completed.contains_key(wave_id) {
-                    warn!(task_id = %wave_id, "sub-agent task panicked without producing a result");
-                    completed.insert(
-                        wave_id.clone(),
-                        TaskResult {
-                            task_id: wave_id.clone(),
-                            status: TaskStatus::Failed,
-                            output: String::new(),
-                            usage: TokenUsage::default(),
-                            duration_ms: start.elapsed().as_millis() as u64,
-                            error: Some("task panicked".into()),
-                            session_id: String::new(),
-                            tool_uses: 0,
-                            description: String::new(),
-                            hint: None,
-                        },
+            let Some(joined) = join_set.join_next_with_id().await else {
+                // validate_tasks rejects cycles and unknown dependencies, so
+                // with nothing running every task has finished
+                if !pending.is_empty() {
+                    warn!(
+                        "Delegation {}: {} tasks stuck with unresolved dependencies",
+                        delegation_id,
+                        pending.len()
                     );
                 }
-            }
+                break;
+            };
+
+            let task_result = match joined {
+                Ok((id, task_result)) => {
+                    running.remove(&id);
+                    task_result
+                }
+                Err(e) => {
+                    warn!("Sub-agent task panicked: {e}");
+                    let Some((task_id, description)) = running.remove(&e.id()) else {
+                        continue;
+                    };
+                    TaskResult {
+                        task_id,
+                        status: TaskStatus::Failed,
+                        output: String::new(),
+                        usage: TokenUsage::default(),
+                        duration_ms: start.elapsed().as_millis() as u64,
+                        error: Some("task panicked".into()),
+                        session_id: String::new(),
+                        tool_uses: 0,
+                        description,
+                        hint: None,
+                        workspace_diff: None,
+                    }
+                }
+            };
+
+            let event = if task_result.status == TaskStatus::Completed {
+                crate::event_bus::AppEvent::SubAgentCompleted {
+                    delegation_id: delegation_id.clone(),
+                    agent_id: task_result.task_id.clone(),
+                    status: "completed".into(),
+                    duration_ms: task_result.duration_ms,
+                    tool_uses: task_result.tool_uses,
+                    tokens_used: task_result.usage.total_tokens,
+                }
+            } else {
+                crate::event_bus::AppEvent::SubAgentFailed {
+                    delegation_id: delegation_id.clone(),
+                    agent_id: task_result.task_id.clone(),
+                    error: task_result.error.clone().unwrap_or_default(),
+                    tool_uses: task_result.tool_uses,
+                    duration_ms: task_result.duration_ms,
+                }
+            };
+            let _ = state.event_bus.publish(event);
+            finished.insert(task_result.task_id.clone(), task_result);
         }
 
         self.active.remove(&delegation_id);
 
         let mut total_usage = TokenUsage::default();
-        for r in completed.values() {
+        for r in finished.values() {
             total_usage += r.usage.clone();
         }
 
@@ -366,7 +375,7 @@ impl Coordinator {
                 total_tokens: total_usage.total_tokens,
             });
 
-        let results: Vec<TaskResult> = completed.into_values().collect();
+        let results: Vec<TaskResult> = order.iter().filter_map(|id| finished.remove(id)).collect();
         let aggregated = self.aggregate(prompt, &results, &agent).await?;
 
         Ok(DelegationResult {
@@ -429,6 +438,142 @@ fn extract_json(text: &str) -> &str {
     trimmed
 }
 
+/// Most characters of one upstream output handed to a dependent task.
+const MAX_UPSTREAM_OUTPUT_CHARS: usize = 4_000;
+
+/// Reject duplicate ids, dependencies on unknown tasks and dependency cycles.
+fn validate_graph(tasks: &[DelegationTask]) -> Result<()> {
+    let mut ids = HashSet::new();
+    for task in tasks {
+        if !ids.insert(task.id.as_str()) {
+            return Err(ZeniiError::Validation(format!(
+                "duplicate task id '{}'",
+                task.id
+            )));
+        }
+    }
+    for task in tasks {
+        if let Some(dep) = task.depends_on.iter().find(|d| !ids.contains(d.as_str())) {
+            return Err(ZeniiError::Validation(format!(
+                "task '{}' depends on unknown task '{dep}'",
+                task.id
+            )));
+        }
+    }
+
+    // Peel off tasks whose dependencies are all ordered; whatever is left
+    // sits on or behind a cycle
+    let mut ordered: HashSet<&str> = HashSet::new();
+    let mut remaining: Vec<&DelegationTask> = tasks.iter().collect();
+    while !remaining.is_empty() {
+        let before = remaining.len();
+        remaining.retain(|t| {
+            let ready = t.depends_on.iter().all(|d| ordered.contains(d.as_str()));
+            if ready {
+                ordered.insert(t.id.as_str());
+            }
+            !ready
+        });
+        if remaining.len() == before {
+            return Err(ZeniiError::Validation(format!(
+                "task dependencies form a cycle: {}",
+                cycle_path(&remaining)
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Follow dependencies among `remaining` (each has at least one) until an id
+/// repeats, e.g. `t1 -> t2 -> t1`.
+fn cycle_path(remaining: &[&DelegationTask]) -> String {
+    let by_id: HashMap<&str, &DelegationTask> =
+        remaining.iter().map(|t| (t.id.as_str(), *t)).collect();
+    let mut path: Vec<&str> = vec![remaining[0].id.as_str()];
+    while let Some(next) = path
+        .last()
+        .and_then(|id| by_id.get(id))
+        .and_then(|t| t.depends_on.iter().find(|d| by_id.contains_key(d.as_str())))
+    {
+        let next = next.as_str();
+        if let Some(pos) = path.iter().position(|id| *id == next) {
+            path.drain(..pos);
+            path.push(next);
+            break;
+        }
+        path.push(next);
+    }
+    path.join(" -> ")
+}
+
+/// First dependency of `task` that finished without completing.
+fn failed_dependency<'a>(
+    task: &'a DelegationTask,
+    finished: &HashMap<String, TaskResult>,
+) -> Option<&'a str> {
+    task.depends_on
+        .iter()
+        .find(|dep| {
+            finished
+                .get(*dep)
+                .is_some_and(|r| r.status != TaskStatus::Completed)
+        })
+        .map(String::as_str)
+}
+
+fn skipped_result(task: &DelegationTask, failed_dep: &str) -> TaskResult {
+    TaskResult {
+        task_id: task.id.clone(),
+        status: TaskStatus::Skipped,
+        output: String::new(),
+        usage: TokenUsage::default(),
+        duration_ms: 0,
+        error: Some(format!(
+            "skipped: upstream task '{failed_dep}' did not complete"
+        )),
+        session_id: String::new(),
+        tool_uses: 0,
+        description: task.description.clone(),
+        hint: None,
+        workspace_diff: None,
+    }
+}
+
+/// Append the outputs of the tasks `task` depends on to its description.
+fn with_upstream_context(
+    mut task: DelegationTask,
+    finished: &HashMap<String, TaskResult>,
+) -> DelegationTask {
+    let upstream: Vec<&TaskResult> = task
+        .depends_on
+        .iter()
+        .filter_map(|dep| finished.get(dep))
+        .collect();
+    if upstream.is_empty() {
+        return task;
+    }
+
+    let mut context = String::from("\n\nResults of the tasks this one builds on:\n");
+    for result in upstream {
+        let output: String = result
+            .output
+            .chars()
+            .take(MAX_UPSTREAM_OUTPUT_CHARS)
+            .collect();
+        let ellipsis = if output.len() < result.output.len() {
+            " …"
+        } else {
+            ""
+        };
+        context.push_str(&format!(
+            "\n## {} ({})\n{output}{ellipsis}\n",
+            result.task_id, result.description
+        ));
+    }
+    task.description.push_str(&context);
+    task
+}
+
 impl std::fmt::Debug for Coordinator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Coordinator")
@@ -556,6 +701,114 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    fn result(id: &str, status: TaskStatus, output: &str) -> TaskResult {
+        TaskResult {
+            task_id: id.into(),
+            status,
+            output: output.into(),
+            usage: TokenUsage::default(),
+            duration_ms: 0,
+            error: None,
+            session_id: String::new(),
+            tool_uses: 0,
+            description: format!("task {id}"),
+            hint: None,
+            workspace_diff: None,
+        }
+    }
+
+    // 7.29
+    #[test]
+    fn coordinator_rejects_dependency_cycle() {
+        let coord = Coordinator::new(DelegationConfig::default());
+        let tasks = vec![
+            make_task("t1", vec![]),
+            make_task("t2", vec!["t1", "t3"]),
+            make_task("t3", vec!["t2"]),
+        ];
+
+        let err = coord.validate_tasks(&tasks, &[]).unwrap_err().to_string();
+        assert!(err.contains("cycle: t2 -> t3 -> t2"), "{err}");
+
+        let tasks = vec![make_task("t1", vec!["t1"])];
+        let err = coord.validate_tasks(&tasks, &[]).unwrap_err().to_string();
+        assert!(err.contains("cycle: t1 -> t1"), "{err}");
+    }
+
+    // 7.30
+    #[test]
+    fn coordinator_rejects_unknown_dependency_and_duplicate_ids() {
+        let coord = Coordinator::new(DelegationConfig::default());
+
+        let tasks = vec![make_task("t1", vec!["t9"])];
+        let err = coord.validate_tasks(&tasks, &[]).unwrap_err().to_string();
+        assert!(err.contains("unknown task 't9'"), "{err}");
+
+        let tasks = vec![make_task("t1", vec![]), make_task("t1", vec![])];
+        let err = coord.validate_tasks(&tasks, &[]).unwrap_err().to_string();
+        assert!(err.contains("duplicate task id 't1'"), "{err}");
+    }
+
+    // 7.31
+    #[test]
+    fn coordinator_accepts_diamond_dependencies() {
+        let coord = Coordinator::new(DelegationConfig::default());
+        let tasks = vec![
+            make_task("review", vec!["draft", "facts"]),
+            make_task("draft", vec!["research"]),
+            make_task("facts", vec!["research"]),
+            make_task("research", vec![]),
+        ];
+        assert!(coord.validate_tasks(&tasks, &[]).is_ok());
+    }
+
+    // 7.32
+    #[test]
+    fn failed_upstream_skips_dependent() {
+        let task = make_task("t3", vec!["t1", "t2"]);
+        let mut finished = HashMap::new();
+        finished.insert("t1".into(), result("t1", TaskStatus::Completed, "ok"));
+        assert_eq!(failed_dependency(&task, &finished), None);
+
+        finished.insert("t2".into(), result("t2", TaskStatus::TimedOut, ""));
+        assert_eq!(failed_dependency(&task, &finished), Some("t2"));
+
+        let skipped = skipped_result(&task, "t2");
+        assert_eq!(skipped.status, TaskStatus::Skipped);
+        assert_eq!(
+            skipped.error.as_deref(),
+            Some("skipped: upstream task 't2' did not complete")
+        );
+    }
+
+    // 7.33
+    #[test]
+    fn dependent_receives_upstream_outputs() {
+        let mut finished = HashMap::new();
+        finished.insert(
+            "t1".into(),
+            result("t1", TaskStatus::Completed, "Rust has async/await"),
+        );
+        finished.insert(
+            "t2".into(),
+            result("t2", TaskStatus::Completed, &"x".repeat(5_000)),
+        );
+
+        let task = with_upstream_context(make_task("t3", vec!["t1", "t2"]), &finished);
+        assert!(task.description.starts_with("task t3\n\n"));
+        assert!(
+            task.description
+                .contains("## t1 (task t1)\nRust has async/await\n")
+        );
+        assert!(task.description.contains(&format!(
+            "## t2 (task t2)\n{} …",
+            "x".repeat(MAX_UPSTREAM_OUTPUT_CHARS)
+        )));
+
+        let independent = with_upstream_context(make_task("t4", vec![]), &finished);
+        assert_eq!(independent.description, "task t4");
+    }
+
     #[test]
     fn extract_json_plain() {
         assert_eq!(extract_json("[{\"id\":\"t1\"}]"), "[{\"id\":\"t1\"}]");
@@ -1,6 +1,7 @@
 pub mod coordinator;
 pub mod sub_agent;
 pub mod task;
+pub mod workspace_diff;
 
 pub use coordinator::Coordinator;
 pub use task::{DelegationResult, DelegationTask, TaskResult, TaskStatus};
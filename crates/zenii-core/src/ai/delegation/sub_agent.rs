@@ -6,6 +6,7 @@ use tracing::warn;
 use crate::ai::adapter::{ToolCallEvent, ToolCallPhase};
 use crate::ai::agent::{TokenUsage, ZeniiAgent};
 use crate::ai::delegation::task::{DelegationTask, TaskResult, TaskStatus};
+use crate::ai::delegation::workspace_diff::WorkspaceSnapshot;
 use crate::ai::prompt::AssemblyRequest;
 use crate::event_bus::EventBus;
 
@@ -16,6 +17,37 @@ pub struct SubAgent {
     delegation_id: String,
     event_bus: Arc<dyn EventBus>,
     tool_rx: broadcast::Receiver<ToolCallEvent>,
+    /// Per-task workspace, snapshotted before and after the run.
+    workspace_root: Option<std::path::PathBuf>,
+}
+
+/// Create and canonicalize `<dir>/<delegation_id>/<task_id>`. IDs are reduced to
+/// `[A-Za-z0-9_-]` so a decomposed task cannot name a path outside `dir`.
+fn agent_workspace_root(
+    dir: &str,
+    delegation_id: &str,
+    task_id: &str,
+) -> crate::Result<std::path::PathBuf> {
+    let sanitize = |id: &str| -> String {
+        id.chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    };
+    let raw = std::path::Path::new(&crate::tools::path::resolve_path(dir))
+        .join(sanitize(delegation_id))
+        .join(sanitize(task_id));
+    crate::tools::workspace::prepare_root(&raw).map_err(|e| {
+        crate::ZeniiError::Agent(format!(
+            "failed to create sub-agent workspace {}: {e}",
+            raw.display()
+        ))
+    })
 }
 
 impl SubAgent {
@@ -33,24 +65,39 @@ impl SubAgent {
             .create_session_with_source(&format!("delegation: {desc_preview}"), "delegation")
             .await?;
 
-        let tools = if let Some(ref allowlist) = task.tool_allowlist {
-            state
-                .tools
-                .to_vec()
-                .into_iter()
-                .filter(|t| allowlist.contains(&t.name().to_string()))
-                .collect()
-        } else {
-            let cfg = state.config.load();
-            crate::security::permissions::PermissionResolver::executable_tools(
-                &cfg.tool_permissions,
-                surface,
-                &state.tools,
-            )
+        let cfg = state.config.load_full();
+        let executable = crate::security::permissions::PermissionResolver::executable_tools(
+            &cfg.tool_permissions,
+            surface,
+            &state.tools,
+        );
+        // The allowlist narrows the surface's executable tools; it never widens them.
+        let tools = match task.tool_allowlist {
+            Some(ref allowlist) => {
+                let allowed = crate::tools::profiles::resolve_allowlist(
+                    allowlist,
+                    &cfg.tool_profiles,
+                    &state.tools.to_vec(),
+                )?;
+                executable
+                    .into_iter()
+                    .filter(|t| allowed.contains(t.name()))
+                    .collect()
+            }
+            None => executable,
+        };
+
+        let workspace_root = cfg
+            .delegation_workspace_dir
+            .as_deref()
+            .map(|dir| agent_workspace_root(dir, &delegation_id, &task.id))
+            .transpose()?;
+        let tools = match workspace_root {
+            Some(ref root) => crate::tools::workspace::WorkspaceScopedTool::wrap_all(tools, root),
+            None => tools,
         };
 
         let skill_count = state.skill_registry.list().await.len();
-        let cfg = state.config.load_full();
         let assembly_request = AssemblyRequest {
             boot_context: state.boot_context.clone(),
             model_display: "delegation".into(),
@@ -87,6 +134,7 @@ impl SubAgent {
             delegation_id,
             event_bus: state.event_bus.clone(),
             tool_rx,
+            workspace_root,
         })
     }
 
@@ -148,6 +196,16 @@ impl SubAgent {
             }
         });
 
+        let snapshot = match self.workspace_root.clone() {
+            Some(root) => tokio::task::spawn_blocking(move || {
+                let before = WorkspaceSnapshot::capture(&root);
+                (root, before)
+            })
+            .await
+            .ok(),
+            None => None,
+        };
+
         let result = tokio::time::timeout(timeout, self.agent.prompt(&self.task.description)).await;
 
         // Stop the monitor
@@ -155,6 +213,17 @@ impl SubAgent {
 
         let final_tool_uses = tool_uses.load(std::sync::atomic::Ordering::Relaxed);
 
+        // Diff even failed and timed-out runs: partial changes still need review.
+        let workspace_diff = match snapshot {
+            Some((root, before)) => {
+                tokio::task::spawn_blocking(move || before.diff(&WorkspaceSnapshot::capture(&root)))
+                    .await
+                    .inspect_err(|e| warn!(task_id = %self.task.id, "workspace diff failed: {e}"))
+                    .ok()
+            }
+            None => None,
+        };
+
         match result {
             Ok(Ok(response)) => {
                 // A.4: Post-hoc token budget warning
@@ -177,6 +246,7 @@ impl SubAgent {
                     tool_uses: final_tool_uses,
                     description: self.task.description.clone(),
                     hint: None,
+                    workspace_diff,
                 }
             }
             Ok(Err(e)) => {
@@ -194,6 +264,7 @@ impl SubAgent {
                     tool_uses: final_tool_uses,
                     description: self.task.description.clone(),
                     hint,
+                    workspace_diff,
                 }
             }
             Err(_) => TaskResult {
@@ -207,6 +278,7 @@ impl SubAgent {
                 tool_uses: final_tool_uses,
                 description: self.task.description.clone(),
                 hint: None,
+                workspace_diff,
             },
         }
     }
@@ -303,6 +375,38 @@ mod tests {
         assert!(sub.is_ok(), "SubAgent with tool allowlist should succeed");
     }
 
+    // 7.9b
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn sub_agent_resolves_profiles_and_rejects_unknown_entries() {
+        let (_dir, state) = setup_state_with_agent().await;
+
+        let task = |allowlist: &str| DelegationTask {
+            id: "t5".into(),
+            description: "profile task".into(),
+            tool_allowlist: Some(vec![allowlist.into()]),
+            token_budget: 4000,
+            timeout_secs: 120,
+            depends_on: vec![],
+        };
+
+        let sub = SubAgent::new(
+            task(crate::tools::profiles::READ_ONLY_PROFILE),
+            &state,
+            "desktop",
+            "d-test".into(),
+        )
+        .await;
+        assert!(sub.is_ok(), "read_only profile should resolve");
+
+        let Err(err) =
+            SubAgent::new(task("no_such_tool"), &state, "desktop", "d-test".into()).await
+        else {
+            panic!("unknown tool should be rejected");
+        };
+        assert!(err.to_string().contains("unknown tool or profile"));
+    }
+
     // 7.10
     #[cfg(feature = "ai")]
     #[tokio::test]
@@ -322,6 +426,19 @@ mod tests {
         assert!(sub.is_ok(), "SubAgent with no allowlist should succeed");
     }
 
+    #[test]
+    fn agent_workspace_root_is_per_task_and_sanitized() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().to_string_lossy().into_owned();
+        let a = agent_workspace_root(&base, "d1", "t1").unwrap();
+        let b = agent_workspace_root(&base, "d1", "../t2").unwrap();
+        assert!(a.is_dir() && b.is_dir());
+        assert_ne!(a, b);
+        let canon = std::fs::canonicalize(dir.path()).unwrap();
+        assert!(b.starts_with(canon.join("d1")));
+        assert!(b.ends_with("___t2"));
+    }
+
     // 7.11 — Structural test: timeout_secs is plumbed correctly
     #[test]
     fn sub_agent_execute_timeout_plumbing() {
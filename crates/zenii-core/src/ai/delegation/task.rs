@@ -6,6 +6,8 @@ use crate::ai::agent::TokenUsage;
 pub struct DelegationTask {
     pub id: String,
     pub description: String,
+    /// Tool or profile names (see `tools::profiles`) the sub-agent may use;
+    /// `None` grants every tool executable on the surface.
     #[serde(default)]
     pub tool_allowlist: Option<Vec<String>>,
     #[serde(default = "default_token_budget")]
@@ -33,6 +35,8 @@ pub enum TaskStatus {
     Failed,
     Cancelled,
     TimedOut,
+    /// Not run because a task it depends on did not complete.
+    Skipped,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +55,10 @@ pub struct TaskResult {
     /// Actionable hint computed from `enrich_error()` when the task fails.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hint: Option<String>,
+    /// Files the sub-agent changed in its workspace. Only set when
+    /// `delegation_workspace_dir` is configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_diff: Option<super::workspace_diff::WorkspaceDiff>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -113,6 +121,7 @@ mod tests {
             (TaskStatus::Failed, "\"Failed\""),
             (TaskStatus::Cancelled, "\"Cancelled\""),
             (TaskStatus::TimedOut, "\"TimedOut\""),
+            (TaskStatus::Skipped, "\"Skipped\""),
         ];
         for (status, expected) in variants {
             let json = serde_json::to_string(&status).unwrap();
@@ -134,6 +143,7 @@ mod tests {
             tool_uses: 0,
             description: "test task".into(),
             hint: None,
+            workspace_diff: None,
         };
         let json = serde_json::to_string(&result).unwrap();
         let back: TaskResult = serde_json::from_str(&json).unwrap();
@@ -160,6 +170,7 @@ mod tests {
             tool_uses: 3,
             description: "test task".into(),
             hint: None,
+            workspace_diff: None,
         };
         let r2 = TaskResult {
             task_id: "t2".into(),
@@ -177,6 +188,7 @@ mod tests {
             tool_uses: 5,
             description: "test task".into(),
             hint: None,
+            workspace_diff: None,
         };
         let total = r1.usage.clone() + r2.usage.clone();
         let result = DelegationResult {
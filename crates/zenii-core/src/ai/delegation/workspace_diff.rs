@@ -0,0 +1,335 @@
+// Before/after comparison of a sub-agent's workspace.
+// The snapshot keeps a hash and size for every file, plus the text of small
+// text files so a unified diff can be produced once the run ends.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Files larger than this are compared by hash and size only.
+const MAX_TEXT_BYTES: u64 = 1024 * 1024;
+
+/// Files past this count are not snapshotted; the diff is marked truncated.
+const MAX_FILES: usize = 10_000;
+
+/// Longest unified diff kept per file.
+const MAX_PATCH_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone)]
+struct FileState {
+    hash: String,
+    size: u64,
+    /// Content of text files up to `MAX_TEXT_BYTES`; `None` for binary or large files.
+    text: Option<String>,
+    binary: bool,
+}
+
+/// Hashes of every file under a workspace root at one point in time.
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceSnapshot {
+    files: BTreeMap<String, FileState>,
+    truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FileChangeKind {
+    Added,
+    Modified,
+    Deleted,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct FileChange {
+    /// Path relative to the workspace root, `/`-separated.
+    pub path: String,
+    pub kind: FileChangeKind,
+    pub binary: bool,
+    pub old_size: Option<u64>,
+    pub new_size: Option<u64>,
+    /// Unified diff for text files; `None` for binary and oversized files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub diff_truncated: bool,
+}
+
+/// Files a sub-agent added, modified or deleted in its workspace.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct WorkspaceDiff {
+    pub files: Vec<FileChange>,
+    /// The workspace held more than 10 000 files; changes past that are missing.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
+/// Per-kind change counts, stored with each delegation task.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct WorkspaceDiffSummary {
+    pub added: u32,
+    pub modified: u32,
+    pub deleted: u32,
+}
+
+impl WorkspaceSnapshot {
+    /// Walk `root` and record every regular file. Unreadable files are skipped.
+    pub fn capture(root: &Path) -> Self {
+        let mut snapshot = Self::default();
+        let walker = ignore::WalkBuilder::new(root)
+            .standard_filters(false)
+            .filter_entry(|e| e.file_name() != ".git")
+            .build();
+
+        for entry in walker.flatten() {
+            let path = entry.path();
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                continue;
+            }
+            if snapshot.files.len() >= MAX_FILES {
+                snapshot.truncated = true;
+                break;
+            }
+            let Ok(rel) = path.strip_prefix(root) else {
+                continue;
+            };
+            let Ok(bytes) = std::fs::read(path) else {
+                continue;
+            };
+            let rel = rel
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            snapshot.files.insert(rel, FileState::from_bytes(bytes));
+        }
+        snapshot
+    }
+
+    /// Compare this (earlier) snapshot with `after`.
+    pub fn diff(&self, after: &WorkspaceSnapshot) -> WorkspaceDiff {
+        let mut files = Vec::new();
+
+        for (path, old) in &self.files {
+            match after.files.get(path) {
+                None => files.push(FileChange::new(
+                    path,
+                    FileChangeKind::Deleted,
+                    Some(old),
+                    None,
+                )),
+                Some(new) if new.hash != old.hash => files.push(FileChange::new(
+                    path,
+                    FileChangeKind::Modified,
+                    Some(old),
+                    Some(new),
+                )),
+                Some(_) => {}
+            }
+        }
+        for (path, new) in &after.files {
+            if !self.files.contains_key(path) {
+                files.push(FileChange::new(
+                    path,
+                    FileChangeKind::Added,
+                    None,
+                    Some(new),
+                ));
+            }
+        }
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        WorkspaceDiff {
+            files,
+            truncated: self.truncated || after.truncated,
+        }
+    }
+}
+
+impl FileState {
+    fn from_bytes(bytes: Vec<u8>) -> Self {
+        let hash = Sha256::digest(&bytes)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        let size = bytes.len() as u64;
+        let binary = bytes.contains(&0) || std::str::from_utf8(&bytes).is_err();
+        let text = if binary || size > MAX_TEXT_BYTES {
+            None
+        } else {
+            String::from_utf8(bytes).ok()
+        };
+        Self {
+            hash,
+            size,
+            text,
+            binary,
+        }
+    }
+}
+
+impl FileChange {
+    fn new(
+        path: &str,
+        kind: FileChangeKind,
+        old: Option<&FileState>,
+        new: Option<&FileState>,
+    ) -> Self {
+        let binary = old.is_some_and(|f| f.binary) || new.is_some_and(|f| f.binary);
+        // Both sides must have text (or be absent) for a text diff.
+        let old_text = old.map(|f| f.text.as_deref());
+        let new_text = new.map(|f| f.text.as_deref());
+        let (diff, diff_truncated) = match (old_text, new_text) {
+            (Some(None), _) | (_, Some(None)) => (None, false),
+            (old_text, new_text) => {
+                let patch = unified_diff(
+                    path,
+                    old_text.flatten().unwrap_or(""),
+                    new_text.flatten().unwrap_or(""),
+                );
+                let truncated = patch.len() > MAX_PATCH_BYTES;
+                (Some(truncate_patch(patch)), truncated)
+            }
+        };
+        Self {
+            path: path.to_string(),
+            kind,
+            binary,
+            old_size: old.map(|f| f.size),
+            new_size: new.map(|f| f.size),
+            diff,
+            diff_truncated,
+        }
+    }
+}
+
+impl WorkspaceDiff {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    pub fn summary(&self) -> WorkspaceDiffSummary {
+        let mut summary = WorkspaceDiffSummary::default();
+        for file in &self.files {
+            match file.kind {
+                FileChangeKind::Added => summary.added += 1,
+                FileChangeKind::Modified => summary.modified += 1,
+                FileChangeKind::Deleted => summary.deleted += 1,
+            }
+        }
+        summary
+    }
+}
+
+/// Unified diff with `a/` and `b/` headers naming the file.
+fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    let patch = diffy::create_patch(old, new).to_string();
+    // diffy names the sides "original" and "modified"; keep only the hunks.
+    let hunks = patch.find("@@").map_or("", |i| &patch[i..]);
+    format!("--- a/{path}\n+++ b/{path}\n{hunks}")
+}
+
+fn truncate_patch(mut patch: String) -> String {
+    if patch.len() > MAX_PATCH_BYTES {
+        let mut end = MAX_PATCH_BYTES;
+        while !patch.is_char_boundary(end) {
+            end -= 1;
+        }
+        patch.truncate(end);
+    }
+    patch
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 7.34 — Added, modified and deleted files are reported with text diffs
+    #[test]
+    fn diff_reports_text_changes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("keep.txt"), "same\n").unwrap();
+        std::fs::write(dir.path().join("edit.txt"), "one\ntwo\n").unwrap();
+        std::fs::write(dir.path().join("gone.txt"), "bye\n").unwrap();
+        let before = WorkspaceSnapshot::capture(dir.path());
+
+        std::fs::write(dir.path().join("edit.txt"), "one\nthree\n").unwrap();
+        std::fs::remove_file(dir.path().join("gone.txt")).unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/new.rs"), "fn main() {}\n").unwrap();
+        let diff = before.diff(&WorkspaceSnapshot::capture(dir.path()));
+
+        let kinds: Vec<_> = diff
+            .files
+            .iter()
+            .map(|f| (f.path.as_str(), f.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("edit.txt", FileChangeKind::Modified),
+                ("gone.txt", FileChangeKind::Deleted),
+                ("src/new.rs", FileChangeKind::Added),
+            ]
+        );
+        let edit = diff.files[0].diff.as_deref().unwrap();
+        assert!(
+            edit.starts_with("--- a/edit.txt\n+++ b/edit.txt\n@@"),
+            "{edit}"
+        );
+        assert!(
+            edit.contains("-two\n") && edit.contains("+three\n"),
+            "{edit}"
+        );
+        assert!(
+            diff.files[2]
+                .diff
+                .as_deref()
+                .unwrap()
+                .contains("+fn main() {}")
+        );
+        assert_eq!(
+            diff.summary(),
+            WorkspaceDiffSummary {
+                added: 1,
+                modified: 1,
+                deleted: 1
+            }
+        );
+    }
+
+    // 7.35 — Binary files report sizes only
+    #[test]
+    fn diff_binary_reports_sizes_only() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("img.bin"), [0u8, 1, 2]).unwrap();
+        let before = WorkspaceSnapshot::capture(dir.path());
+
+        std::fs::write(dir.path().join("img.bin"), [0u8, 1, 2, 3, 4]).unwrap();
+        let diff = before.diff(&WorkspaceSnapshot::capture(dir.path()));
+
+        assert_eq!(diff.files.len(), 1);
+        let file = &diff.files[0];
+        assert!(file.binary);
+        assert!(file.diff.is_none());
+        assert_eq!((file.old_size, file.new_size), (Some(3), Some(5)));
+    }
+
+    // 7.36 — An untouched workspace produces an empty diff
+    #[test]
+    fn diff_unchanged_workspace_is_empty() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.txt"), "a").unwrap();
+        let before = WorkspaceSnapshot::capture(dir.path());
+        assert!(
+            before
+                .diff(&WorkspaceSnapshot::capture(dir.path()))
+                .is_empty()
+        );
+    }
+}
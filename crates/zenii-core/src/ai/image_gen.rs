@@ -0,0 +1,231 @@
+use async_trait::async_trait;
+use base64::Engine;
+
+use crate::{Result, ZeniiError};
+
+/// A single image returned by an image generation provider.
+#[derive(Debug, Clone)]
+pub struct GeneratedImage {
+    /// Raw image bytes.
+    pub bytes: Vec<u8>,
+    /// MIME type reported by the provider, e.g. `image/png`.
+    pub mime_type: String,
+    /// Prompt as rewritten by the provider, when it reports one.
+    pub revised_prompt: Option<String>,
+}
+
+impl GeneratedImage {
+    /// File extension matching the MIME type; `png` when it is not recognised.
+    pub fn extension(&self) -> &'static str {
+        match self.mime_type.as_str() {
+            "image/jpeg" | "image/jpg" => "jpg",
+            "image/webp" => "webp",
+            "image/gif" => "gif",
+            _ => "png",
+        }
+    }
+}
+
+/// Provider abstraction for text-to-image generation.
+#[async_trait]
+pub trait ImageGenProvider: Send + Sync {
+    /// Model identifier used for generation (for logging and tool output).
+    fn model(&self) -> &str;
+
+    /// Generate `count` images for `prompt` at the given `size` (e.g. "1024x1024").
+    async fn generate(&self, prompt: &str, size: &str, count: u32) -> Result<Vec<GeneratedImage>>;
+}
+
+/// OpenAI-compatible image provider using the /v1/images/generations endpoint.
+/// Works with DALL·E, gpt-image and SDXL servers exposing the same API shape.
+pub struct OpenAiImageGenProvider {
+    client: reqwest::Client,
+    api_key: String,
+    model: String,
+    base_url: String,
+}
+
+impl OpenAiImageGenProvider {
+    pub fn new(api_key: String, model: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            api_key,
+            model,
+            base_url: "https://api.openai.com/v1".into(),
+        }
+    }
+
+    /// Override the API base URL (must include the version segment, e.g. `.../v1`).
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+}
+
+#[derive(serde::Serialize)]
+struct ImageRequest<'a> {
+    model: &'a str,
+    prompt: &'a str,
+    n: u32,
+    size: &'a str,
+    response_format: &'a str,
+}
+
+#[derive(serde::Deserialize)]
+struct ImageResponse {
+    data: Vec<ImageData>,
+    /// `png`, `jpeg` or `webp`; reported by gpt-image models. DALL·E omits it
+    /// and always returns PNG.
+    output_format: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct ImageData {
+    b64_json: Option<String>,
+    url: Option<String>,
+    revised_prompt: Option<String>,
+}
+
+#[async_trait]
+impl ImageGenProvider for OpenAiImageGenProvider {
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn generate(&self, prompt: &str, size: &str, count: u32) -> Result<Vec<GeneratedImage>> {
+        if prompt.trim().is_empty() {
+            return Err(ZeniiError::Tool("image prompt cannot be empty".into()));
+        }
+
+        let url = format!("{}/images/generations", self.base_url);
+        let body = ImageRequest {
+            model: &self.model,
+            prompt,
+            n: count,
+            size,
+            response_format: "b64_json",
+        };
+
+        let resp = self
+            .client
+            .post(&url)
+            .bearer_auth(&self.api_key)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| ZeniiError::Tool(format!("image request failed: {e}")))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_else(|_| "unknown error".into());
+            return Err(ZeniiError::Tool(format!(
+                "image API error {status}: {body}"
+            )));
+        }
+
+        let result: ImageResponse = resp
+            .json()
+            .await
+            .map_err(|e| ZeniiError::Tool(format!("image response parse failed: {e}")))?;
+
+        let response_mime = result
+            .output_format
+            .as_deref()
+            .map_or_else(|| "image/png".to_string(), |f| format!("image/{f}"));
+        let mut images = Vec::with_capacity(result.data.len());
+        for item in result.data {
+            let (bytes, mime_type) = if let Some(b64) = item.b64_json {
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(b64.as_bytes())
+                    .map_err(|e| ZeniiError::Tool(format!("invalid base64 image data: {e}")))?;
+                (bytes, response_mime.clone())
+            } else if let Some(image_url) = item.url {
+                // Some compatible servers ignore response_format and return URLs
+                let resp = self
+                    .client
+                    .get(&image_url)
+                    .send()
+                    .await
+                    .and_then(|r| r.error_for_status())
+                    .map_err(|e| ZeniiError::Tool(format!("image download failed: {e}")))?;
+                let mime_type = resp
+                    .headers()
+                    .get(reqwest::header::CONTENT_TYPE)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.split(';').next())
+                    .map(|v| v.trim().to_ascii_lowercase())
+                    .filter(|v| v.starts_with("image/"))
+                    .unwrap_or_else(|| response_mime.clone());
+                let bytes = resp
+                    .bytes()
+                    .await
+                    .map_err(|e| ZeniiError::Tool(format!("image download failed: {e}")))?
+                    .to_vec();
+                (bytes, mime_type)
+            } else {
+                continue;
+            };
+            images.push(GeneratedImage {
+                bytes,
+                mime_type,
+                revised_prompt: item.revised_prompt,
+            });
+        }
+
+        if images.is_empty() {
+            return Err(ZeniiError::Tool("image API returned no images".into()));
+        }
+        Ok(images)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // IMG.1 — Provider constructs with default base URL
+    #[test]
+    fn openai_image_provider_new() {
+        let provider = OpenAiImageGenProvider::new("sk-test".into(), "dall-e-3".into());
+        assert_eq!(provider.model(), "dall-e-3");
+        assert_eq!(provider.base_url, "https://api.openai.com/v1");
+    }
+
+    // IMG.2 — with_base_url strips trailing slash
+    #[test]
+    fn openai_image_provider_base_url_trimmed() {
+        let provider = OpenAiImageGenProvider::new("sk-test".into(), "sdxl".into())
+            .with_base_url("http://localhost:8080/v1/".into());
+        assert_eq!(provider.base_url, "http://localhost:8080/v1");
+    }
+
+    // IMG.3 — Empty prompt returns error without a network call
+    #[tokio::test]
+    async fn openai_image_empty_prompt() {
+        let provider = OpenAiImageGenProvider::new("sk-test".into(), "dall-e-3".into())
+            .with_base_url("http://127.0.0.1:1".into());
+        assert!(provider.generate("  ", "1024x1024", 1).await.is_err());
+    }
+
+    // IMG.3b — Extension follows the MIME type
+    #[test]
+    fn extension_follows_mime_type() {
+        let image = |mime: &str| GeneratedImage {
+            bytes: Vec::new(),
+            mime_type: mime.into(),
+            revised_prompt: None,
+        };
+        assert_eq!(image("image/png").extension(), "png");
+        assert_eq!(image("image/jpeg").extension(), "jpg");
+        assert_eq!(image("image/webp").extension(), "webp");
+        assert_eq!(image("application/octet-stream").extension(), "png");
+    }
+
+    // IMG.4 — Unreachable server returns error
+    #[tokio::test]
+    async fn openai_image_unreachable() {
+        let provider = OpenAiImageGenProvider::new("sk-test".into(), "dall-e-3".into())
+            .with_base_url("http://127.0.0.1:1".into());
+        assert!(provider.generate("a cat", "1024x1024", 1).await.is_err());
+    }
+}
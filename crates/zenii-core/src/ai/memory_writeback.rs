@@ -0,0 +1,258 @@
+use std::sync::Arc;
+
+use tracing::{debug, info, warn};
+
+use crate::event_bus::AppEvent;
+use crate::gateway::state::AppState;
+use crate::memory::traits::MemoryCategory;
+use crate::{Result, ZeniiError};
+
+use super::agent::ZeniiAgent;
+
+/// Memory category for facts learned automatically after an agent run.
+pub const LEARNED_CATEGORY: &str = "learned";
+
+const EXTRACTION_PREAMBLE: &str = "You extract durable facts from conversations. \
+     Output only the requested format, nothing else.";
+
+/// A fact extracted from a finished exchange.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LearnedFact {
+    pub key: String,
+    pub content: String,
+}
+
+/// Parse `key|fact` lines from the extraction model. `NONE` or malformed
+/// lines yield nothing; at most `max_facts` facts are returned.
+pub fn parse_learned_facts(output: &str, max_facts: usize) -> Vec<LearnedFact> {
+    let trimmed = output.trim();
+    if trimmed.eq_ignore_ascii_case("NONE") {
+        return Vec::new();
+    }
+
+    trimmed
+        .lines()
+        .filter_map(|line| {
+            let (key, content) = line.trim().split_once('|')?;
+            let key: String = key
+                .trim()
+                .to_lowercase()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            let key = key.trim_matches('_').to_string();
+            let content = content.trim().to_string();
+            (!key.is_empty() && !content.is_empty()).then_some(LearnedFact { key, content })
+        })
+        .take(max_facts)
+        .collect()
+}
+
+/// Post-run hook: extract durable facts from the final exchange and store them
+/// as `learned` memories. Facts whose content is already stored are skipped.
+///
+/// Returns the number of memories written. No-op unless
+/// `memory_writeback_enabled` is set.
+pub async fn write_back(
+    state: &AppState,
+    session_id: Option<&str>,
+    prompt: &str,
+    response: &str,
+) -> Result<usize> {
+    let config = state.config.load_full();
+    if !config.memory_writeback_enabled {
+        return Ok(0);
+    }
+
+    let spec = config.memory_writeback_model.clone().unwrap_or_else(|| {
+        format!(
+            "{}:{}",
+            config.context_summary_provider_id, config.context_summary_model_id
+        )
+    });
+    let (provider_id, model_id) = spec.split_once(':').ok_or_else(|| {
+        ZeniiError::Config(format!(
+            "invalid memory_writeback_model '{spec}': expected 'provider_id:model_id'"
+        ))
+    })?;
+    let provider = state.provider_registry.get_provider(provider_id).await?;
+
+    let agent = ZeniiAgent::from_provider(
+        provider_id,
+        &provider.provider.base_url,
+        model_id,
+        provider.provider.requires_api_key,
+        &provider.provider.extras,
+        state.credentials.as_ref(),
+        &[],
+        &config,
+        Some(EXTRACTION_PREAMBLE),
+        None,
+        None,
+    )
+    .await?;
+
+    let extraction_prompt = format!(
+        "Extract durable facts worth remembering from this exchange: stable facts \
+         about the user, their projects, environment or decisions.\n\n\
+         User: {prompt}\n\
+         Assistant: {response}\n\n\
+         Output each fact on a separate line in the format:\n\
+         key|fact\n\n\
+         Rules:\n\
+         - Keys are short identifiers (e.g. \"primary_language\", \"deploy_target\")\n\
+         - Facts are single self-contained sentences\n\
+         - Skip greetings, one-off requests and anything only true right now\n\
+         - If nothing is worth remembering, output exactly: NONE"
+    );
+    let output = agent.prompt(&extraction_prompt).await?.output;
+
+    let mut stored = 0usize;
+    for fact in parse_learned_facts(&output, config.memory_writeback_max_facts) {
+        let existing = state.memory.recall(&fact.content, 5, 0).await?;
+        if existing
+            .iter()
+            .any(|e| e.content.trim().eq_ignore_ascii_case(&fact.content))
+        {
+            debug!("Skipping learned fact already in memory: {}", fact.key);
+            continue;
+        }
+
+        let key = format!("{LEARNED_CATEGORY}:{}", fact.key);
+        match state
+            .memory
+            .store(
+                &key,
+                &fact.content,
+                MemoryCategory::Custom(LEARNED_CATEGORY.into()),
+            )
+            .await
+        {
+            Ok(()) => {
+                stored += 1;
+                let _ = state.event_bus.publish(AppEvent::MemoryStored {
+                    key,
+                    category: LEARNED_CATEGORY.into(),
+                    session_id: session_id.map(String::from),
+                });
+            }
+            Err(ZeniiError::MemoryDuplicate(_)) => {
+                debug!("Skipping learned fact with duplicate content: {key}");
+            }
+            Err(e) => debug!("Failed to store learned fact '{key}': {e}"),
+        }
+    }
+
+    if stored > 0 {
+        info!("Memory write-back stored {stored} learned facts");
+    }
+    Ok(stored)
+}
+
+/// Run [`write_back`] in the background so the reply is not delayed.
+pub fn spawn_write_back(state: &Arc<AppState>, session_id: &str, prompt: &str, response: &str) {
+    if !state.config.load().memory_writeback_enabled {
+        return;
+    }
+    let state = Arc::clone(state);
+    let (session_id, prompt, response) = (
+        session_id.to_string(),
+        prompt.to_string(),
+        response.to_string(),
+    );
+    tokio::spawn(async move {
+        if let Err(e) = write_back(&state, Some(&session_id), &prompt, &response).await {
+            warn!("Memory write-back failed for session {session_id}: {e}");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // MW.1 — Parsing normalizes keys, drops malformed lines and honors the cap
+    #[test]
+    fn parse_learned_facts_normalizes_and_caps() {
+        let output = "Primary Language|The user writes mostly Rust.\n\
+                      no separator here\n\
+                      |missing key\n\
+                      deploy-target|Deploys to Fly.io\n\
+                      editor|Uses Helix";
+        let facts = parse_learned_facts(output, 2);
+        assert_eq!(
+            facts,
+            vec![
+                LearnedFact {
+                    key: "primary_language".into(),
+                    content: "The user writes mostly Rust.".into(),
+                },
+                LearnedFact {
+                    key: "deploy_target".into(),
+                    content: "Deploys to Fly.io".into(),
+                },
+            ]
+        );
+        assert!(parse_learned_facts(" none ", 5).is_empty());
+    }
+
+    // MW.2 — A completed exchange with a clear fact is stored once as a learned memory
+    #[tokio::test]
+    async fn write_back_stores_learned_fact_once() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+
+        let url = crate::ai::test_support::openai_text_mock(
+            "favorite_editor|The user's favorite editor is Helix.",
+        )
+        .await;
+        state
+            .provider_registry
+            .add_user_provider(
+                "mock",
+                "Mock",
+                &url,
+                false,
+                &[("mock-model".into(), "Mock".into(), true)],
+            )
+            .await
+            .unwrap();
+
+        // Disabled by default: nothing is extracted
+        assert_eq!(write_back(&state, None, "hi", "hello").await.unwrap(), 0);
+
+        let mut config = (*state.config.load_full()).clone();
+        config.memory_writeback_enabled = true;
+        config.memory_writeback_model = Some("mock:mock-model".into());
+        state.config.store(Arc::new(config));
+
+        let mut rx = state.event_bus.subscribe();
+        let stored = write_back(
+            &state,
+            Some("s1"),
+            "I always use Helix for editing",
+            "Got it, Helix it is.",
+        )
+        .await
+        .unwrap();
+        assert_eq!(stored, 1);
+
+        let entries = state.memory.recall("Helix", 10, 0).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key, "learned:favorite_editor");
+        assert_eq!(entries[0].category.to_string(), LEARNED_CATEGORY);
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            AppEvent::MemoryStored { key, session_id, .. }
+                if key == "learned:favorite_editor" && session_id.as_deref() == Some("s1")
+        ));
+
+        // The same fact again is deduplicated
+        assert_eq!(
+            write_back(&state, Some("s1"), "Helix again", "Sure.")
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(state.memory.recall("Helix", 10, 0).await.unwrap().len(), 1);
+    }
+}
@@ -2,13 +2,28 @@ pub mod adapter;
 pub mod agent;
 pub mod compression;
 pub mod context;
+pub mod context_window;
 pub mod delegation;
+pub mod image_gen;
+pub mod memory_writeback;
 pub mod prompt;
+pub mod prompt_cache;
+pub mod provider_health;
 pub mod provider_registry;
 pub mod providers;
 pub mod reasoning;
+pub mod reflection;
+pub mod retry;
 pub mod routing;
 pub mod session;
+pub mod stream_cancel;
+pub mod structured;
+pub mod summarize;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod tool_parser;
+pub mod tool_results;
+pub mod transcription;
 pub mod wiki_context_plugin;
 
 pub use adapter::{ToolCallCache, ToolCallEvent, ToolCallPhase};
@@ -375,7 +375,7 @@ impl LegacyStrategy {
 
 #[async_trait]
 impl PromptStrategy for LegacyStrategy {
-    async fn assemble(&self, _request: &AssemblyRequest) -> Result<String> {
+    async fn assemble(&self, request: &AssemblyRequest) -> Result<String> {
         // Delegate to existing PromptComposer for backward compat
         let identity = self.soul_loader.get().await;
         let observations = self.user_learner.build_context().await.unwrap_or_default();
@@ -391,11 +391,14 @@ impl PromptStrategy for LegacyStrategy {
             }
         }
 
-        let preamble = crate::identity::PromptComposer::compose(
+        let vars =
+            crate::identity::PromptVars::from_config(&self.config, request.channel_hint.as_deref());
+        let preamble = crate::identity::PromptComposer::compose_with(
             &identity,
             &active_skills,
             &observations,
             &self.config,
+            &vars,
         );
 
         Ok(preamble)
@@ -406,14 +409,30 @@ impl PromptStrategy for LegacyStrategy {
 // Built-in Plugins
 // ============================================================================
 
-/// Contributes cross-session memories recalled by keyword matching.
+/// Contributes cross-session memories recalled against the user's message,
+/// as a delimited background block.
 pub struct MemoryPlugin {
     memory: Arc<dyn Memory>,
+    limit: usize,
+    min_relevance: f32,
 }
 
 impl MemoryPlugin {
     pub fn new(memory: Arc<dyn Memory>) -> Self {
-        Self { memory }
+        Self {
+            memory,
+            limit: 5,
+            min_relevance: 0.0,
+        }
+    }
+
+    /// Use `context_max_memory_results` and `context_memory_min_relevance`.
+    pub fn from_config(memory: Arc<dyn Memory>, config: &AppConfig) -> Self {
+        Self {
+            memory,
+            limit: config.context_max_memory_results,
+            min_relevance: config.context_memory_min_relevance,
+        }
     }
 }
 
@@ -433,19 +452,21 @@ impl PromptPlugin for MemoryPlugin {
             return Ok(vec![]);
         }
 
-        let memories = self.memory.recall(prompt, 5, 0).await.unwrap_or_default();
+        let memories = crate::ai::context::recall_relevant_memories(
+            self.memory.as_ref(),
+            prompt,
+            self.limit,
+            self.min_relevance,
+        )
+        .await;
         if memories.is_empty() {
             return Ok(vec![]);
         }
 
-        let mut lines = vec!["[Relevant Memories]".to_string()];
-        for mem in &memories {
-            lines.push(format!("- {}", mem.content));
-        }
-
+        let contents: Vec<&str> = memories.iter().map(|m| m.content.as_str()).collect();
         Ok(vec![PromptFragment {
             section: PromptSection::DynamicContext,
-            content: lines.join("\n"),
+            content: crate::ai::context::format_memory_block(&contents),
             priority: 3,
         }])
     }
@@ -727,13 +748,19 @@ impl PromptPlugin for SchedulerContextPlugin {
         for job in &jobs {
             if job.enabled {
                 let schedule_str = match &job.schedule {
-                    crate::scheduler::traits::Schedule::Cron { expr } => format!("cron: {expr}"),
+                    crate::scheduler::traits::Schedule::Cron { expr, tz } => match tz {
+                        Some(tz) => format!("cron: {expr} ({tz})"),
+                        None => format!("cron: {expr}"),
+                    },
                     crate::scheduler::traits::Schedule::Interval { secs } => {
                         format!("every {secs}s")
                     }
                     crate::scheduler::traits::Schedule::Human { datetime } => {
                         format!("once at {datetime}")
                     }
+                    crate::scheduler::traits::Schedule::Once { at } => {
+                        format!("once at {}", at.format("%Y-%m-%d %H:%M UTC"))
+                    }
                 };
                 lines.push(format!("- {} ({schedule_str})", job.name));
             }
@@ -1018,6 +1045,18 @@ mod tests {
         let fragments = plugin.contribute(&request).await.unwrap();
         assert!(!fragments.is_empty());
         assert!(fragments[0].content.contains("dark mode"));
+        assert!(fragments[0].content.contains("<memories>"));
+    }
+
+    // 8.13.8a — MemoryPlugin is a no-op when nothing is stored
+    #[tokio::test]
+    async fn memory_plugin_empty_store() {
+        let memory = Arc::new(crate::memory::in_memory_store::InMemoryStore::new());
+        let plugin = MemoryPlugin::from_config(memory, &AppConfig::default());
+        let mut request = test_request();
+        request.user_message = Some("dark mode".into());
+
+        assert!(plugin.contribute(&request).await.unwrap().is_empty());
     }
 
     // 8.13.9 — MemoryPlugin returns empty for empty prompt
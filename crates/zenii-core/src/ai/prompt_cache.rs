@@ -0,0 +1,155 @@
+//! Prompt caching for the stable prefix of provider requests.
+//!
+//! The system prompt and tool schemas are identical on every turn of a
+//! session, so providers that support prompt caching can bill them at the
+//! cached rate. OpenAI and Gemini cache long prefixes automatically; Anthropic
+//! only caches up to explicit `cache_control` breakpoints, which
+//! [`mark_cacheable_prefix`] adds to the request body just before it is sent.
+//! Other providers ignore it.
+
+use bytes::Bytes;
+use serde_json::{Value, json};
+
+/// How a provider caches a repeated request prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PromptCacheStyle {
+    /// No caching, or the provider caches on its own (OpenAI, Gemini).
+    #[default]
+    Automatic,
+    /// Breakpoints marked with `cache_control` (Anthropic Messages API).
+    CacheControl,
+}
+
+impl PromptCacheStyle {
+    /// Style for a provider type (`openai`, `anthropic`, `gemini`, `custom`).
+    pub fn for_provider_type(provider_type: &str) -> Self {
+        match provider_type {
+            "anthropic" => Self::CacheControl,
+            _ => Self::Automatic,
+        }
+    }
+}
+
+/// Mark the end of the tool definitions and of the system prompt as cache
+/// breakpoints in an Anthropic Messages request. Anthropic caches everything
+/// before a breakpoint in tools -> system -> messages order, so the two marks
+/// cover the whole stable prefix and leave the history uncached.
+///
+/// Bodies that are not a Messages request are returned untouched, as are
+/// bodies that already carry their own breakpoints.
+pub fn mark_cacheable_prefix(body: Bytes) -> Bytes {
+    let Ok(Value::Object(mut request)) = serde_json::from_slice::<Value>(&body) else {
+        return body;
+    };
+    if !request.contains_key("messages") || body_has_cache_control(&request) {
+        return body;
+    }
+
+    let mut marked = false;
+    if let Some(Value::Array(tools)) = request.get_mut("tools")
+        && let Some(Value::Object(last)) = tools.last_mut()
+    {
+        last.insert("cache_control".into(), ephemeral());
+        marked = true;
+    }
+    match request.get_mut("system") {
+        Some(system) if system.as_str().is_some_and(|text| !text.is_empty()) => {
+            let text = system.take();
+            *system = json!([{"type": "text", "text": text, "cache_control": ephemeral()}]);
+            marked = true;
+        }
+        Some(Value::Array(blocks)) => {
+            if let Some(Value::Object(last)) = blocks.last_mut() {
+                last.insert("cache_control".into(), ephemeral());
+                marked = true;
+            }
+        }
+        _ => {}
+    }
+
+    if !marked {
+        return body;
+    }
+    serde_json::to_vec(&request)
+        .map(Bytes::from)
+        .unwrap_or(body)
+}
+
+fn ephemeral() -> Value {
+    json!({"type": "ephemeral"})
+}
+
+fn body_has_cache_control(request: &serde_json::Map<String, Value>) -> bool {
+    ["tools", "system"].iter().any(|key| {
+        request
+            .get(*key)
+            .and_then(Value::as_array)
+            .is_some_and(|items| items.iter().any(|i| i.get("cache_control").is_some()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mark(body: Value) -> Value {
+        let out = mark_cacheable_prefix(Bytes::from(serde_json::to_vec(&body).unwrap()));
+        serde_json::from_slice(&out).unwrap()
+    }
+
+    // PC.1 — The system prompt and last tool become cache breakpoints
+    #[test]
+    fn marks_system_and_last_tool() {
+        let out = mark(json!({
+            "model": "claude-sonnet-4-6",
+            "system": "You are Zenii.",
+            "tools": [{"name": "a"}, {"name": "b"}],
+            "messages": [{"role": "user", "content": "hi"}],
+        }));
+        assert_eq!(
+            out["system"],
+            json!([{"type": "text", "text": "You are Zenii.", "cache_control": {"type": "ephemeral"}}])
+        );
+        assert!(out["tools"][0].get("cache_control").is_none());
+        assert_eq!(
+            out["tools"][1]["cache_control"],
+            json!({"type": "ephemeral"})
+        );
+        assert!(out["messages"][0].get("cache_control").is_none());
+    }
+
+    // PC.2 — Non-Messages bodies and already-marked requests pass through
+    #[test]
+    fn leaves_other_bodies_alone() {
+        let raw = Bytes::from_static(b"not json");
+        assert_eq!(mark_cacheable_prefix(raw.clone()), raw);
+
+        let no_messages = json!({"system": "x"});
+        assert_eq!(mark(no_messages.clone()), no_messages);
+
+        let marked = json!({
+            "system": [{"type": "text", "text": "x", "cache_control": {"type": "ephemeral"}}],
+            "tools": [{"name": "a"}],
+            "messages": [],
+        });
+        assert_eq!(mark(marked.clone()), marked);
+
+        let bare = json!({"system": "", "messages": []});
+        assert_eq!(mark(bare.clone()), bare);
+    }
+
+    // PC.3 — Only Anthropic needs explicit breakpoints
+    #[test]
+    fn style_by_provider_type() {
+        assert_eq!(
+            PromptCacheStyle::for_provider_type("anthropic"),
+            PromptCacheStyle::CacheControl
+        );
+        for other in ["openai", "gemini", "custom"] {
+            assert_eq!(
+                PromptCacheStyle::for_provider_type(other),
+                PromptCacheStyle::Automatic
+            );
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::Serialize;
+
+/// Result of one live connection test against a provider.
+#[derive(Debug, Clone)]
+pub struct ProbeOutcome {
+    pub success: bool,
+    pub message: String,
+    pub latency_ms: u64,
+}
+
+/// Last known health of a provider, as returned by `POST /providers/{id}/test`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct ProviderHealth {
+    pub success: bool,
+    pub message: String,
+    pub latency_ms: u64,
+    pub checked_at: DateTime<Utc>,
+    /// Message of the most recent failed check, kept after later successes.
+    pub last_error: Option<String>,
+    pub last_error_at: Option<DateTime<Utc>>,
+    /// Served from the cache rather than a new request.
+    pub cached: bool,
+}
+
+struct Cached {
+    health: ProviderHealth,
+    at: Instant,
+}
+
+type Slot = Arc<tokio::sync::Mutex<Option<Cached>>>;
+
+/// Memoizes provider connection tests so dashboard refreshes do not spend
+/// rate limit on every render.
+///
+/// Each provider has its own async lock held for the duration of a check, so
+/// concurrent callers queue behind the in-flight request and then share its
+/// result instead of each making their own.
+#[derive(Default)]
+pub struct ProviderHealthCache {
+    slots: Mutex<HashMap<String, Slot>>,
+}
+
+impl ProviderHealthCache {
+    /// Return the cached health of `provider_id` if it is younger than `ttl`,
+    /// otherwise run `probe` and cache its outcome. `force` skips the cache,
+    /// but still shares a check that completed while this caller was waiting.
+    pub async fn check<F, Fut>(
+        &self,
+        provider_id: &str,
+        ttl: Duration,
+        force: bool,
+        probe: F,
+    ) -> ProviderHealth
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ProbeOutcome>,
+    {
+        let requested = Instant::now();
+        let slot = self.slot(provider_id);
+        let mut cached = slot.lock().await;
+
+        if let Some(c) = cached.as_ref()
+            && (c.at > requested || (!force && c.at.elapsed() < ttl))
+        {
+            return ProviderHealth {
+                cached: true,
+                ..c.health.clone()
+            };
+        }
+
+        let outcome = probe().await;
+        let now = Utc::now();
+        let (last_error, last_error_at) = if outcome.success {
+            cached
+                .as_ref()
+                .map(|c| (c.health.last_error.clone(), c.health.last_error_at))
+                .unwrap_or_default()
+        } else {
+            (Some(outcome.message.clone()), Some(now))
+        };
+        let health = ProviderHealth {
+            success: outcome.success,
+            message: outcome.message,
+            latency_ms: outcome.latency_ms,
+            checked_at: now,
+            last_error,
+            last_error_at,
+            cached: false,
+        };
+        *cached = Some(Cached {
+            health: health.clone(),
+            at: Instant::now(),
+        });
+        health
+    }
+
+    /// Drop the cached result for one provider, e.g. after its settings change.
+    pub fn invalidate(&self, provider_id: &str) {
+        self.slots.lock().remove(provider_id);
+    }
+
+    pub fn clear(&self) {
+        self.slots.lock().clear();
+    }
+
+    fn slot(&self, provider_id: &str) -> Slot {
+        self.slots
+            .lock()
+            .entry(provider_id.to_string())
+            .or_default()
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    fn outcome(success: bool, message: &str) -> ProbeOutcome {
+        ProbeOutcome {
+            success,
+            message: message.into(),
+            latency_ms: 5,
+        }
+    }
+
+    // PH.1 — Results are reused within the TTL and refreshed by force
+    #[tokio::test]
+    async fn caches_until_forced() {
+        let cache = ProviderHealthCache::default();
+        let calls = &AtomicU32::new(0);
+        let probe = || async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            outcome(true, "ok")
+        };
+        let ttl = Duration::from_secs(30);
+
+        assert!(!cache.check("openai", ttl, false, probe).await.cached);
+        assert!(cache.check("openai", ttl, false, probe).await.cached);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert!(!cache.check("openai", ttl, true, probe).await.cached);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        cache.invalidate("openai");
+        assert!(!cache.check("openai", ttl, false, probe).await.cached);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    // PH.2 — Concurrent checks for one provider make a single request
+    #[tokio::test]
+    async fn concurrent_checks_coalesce() {
+        let cache = Arc::new(ProviderHealthCache::default());
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let handles: Vec<_> = (0..5)
+            .map(|_| {
+                let cache = cache.clone();
+                let calls = calls.clone();
+                tokio::spawn(async move {
+                    cache
+                        .check("anthropic", Duration::from_secs(30), true, || async move {
+                            calls.fetch_add(1, Ordering::SeqCst);
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                            outcome(true, "ok")
+                        })
+                        .await
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    // PH.3 — The last error survives a later successful check
+    #[tokio::test]
+    async fn keeps_last_error() {
+        let cache = ProviderHealthCache::default();
+        let ttl = Duration::ZERO;
+
+        let failed = cache
+            .check("groq", ttl, false, || async { outcome(false, "HTTP 401") })
+            .await;
+        assert_eq!(failed.last_error.as_deref(), Some("HTTP 401"));
+
+        let ok = cache
+            .check("groq", ttl, false, || async { outcome(true, "ok") })
+            .await;
+        assert!(ok.success);
+        assert_eq!(ok.last_error.as_deref(), Some("HTTP 401"));
+        assert_eq!(ok.last_error_at, failed.last_error_at);
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::credential::CredentialStore;
@@ -13,9 +15,88 @@ pub struct ProviderInfo {
     pub name: String,
     pub base_url: String,
     pub requires_api_key: bool,
+    /// Whether the provider serves an OpenAI-compatible `/embeddings` endpoint.
+    pub supports_embeddings: bool,
     pub is_active: bool,
     pub is_user_defined: bool,
     pub created_at: String,
+    #[serde(flatten, default)]
+    pub extras: ProviderExtras,
+}
+
+/// Extra headers and query parameters sent with every request to a provider,
+/// e.g. an `api-version` for Azure OpenAI or an `X-Org-Id` for a proxy.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct ProviderExtras {
+    #[serde(default)]
+    pub extra_headers: HashMap<String, String>,
+    #[serde(default)]
+    pub extra_query: HashMap<String, String>,
+    /// Names of headers whose values are kept in the credential store under
+    /// [`secret_header_key`] instead of the database.
+    #[serde(default)]
+    pub secret_headers: Vec<String>,
+}
+
+impl ProviderExtras {
+    pub fn is_empty(&self) -> bool {
+        self.extra_headers.is_empty()
+            && self.extra_query.is_empty()
+            && self.secret_headers.is_empty()
+    }
+
+    /// Reject header names and values that could not be sent, empty query
+    /// keys, and headers listed both as plain and secret.
+    pub fn validate(&self) -> Result<()> {
+        for (name, value) in &self.extra_headers {
+            validate_header_name(name)?;
+            if reqwest::header::HeaderValue::from_str(value).is_err() {
+                return Err(ZeniiError::Validation(format!(
+                    "invalid value for header '{name}'"
+                )));
+            }
+        }
+        for name in &self.secret_headers {
+            validate_header_name(name)?;
+            if self
+                .extra_headers
+                .keys()
+                .any(|plain| plain.eq_ignore_ascii_case(name))
+            {
+                return Err(ZeniiError::Validation(format!(
+                    "header '{name}' is both a plain and a secret header"
+                )));
+            }
+        }
+        if self.extra_query.keys().any(|key| key.trim().is_empty()) {
+            return Err(ZeniiError::Validation(
+                "query parameter names must not be empty".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn validate_header_name(name: &str) -> Result<()> {
+    reqwest::header::HeaderName::from_bytes(name.as_bytes())
+        .map(|_| ())
+        .map_err(|_| ZeniiError::Validation(format!("invalid header name '{name}'")))
+}
+
+/// Credential store key holding the value of secret header `name` of `provider_id`.
+pub fn secret_header_key(provider_id: &str, name: &str) -> String {
+    format!(
+        "provider_header:{provider_id}:{}",
+        name.to_ascii_lowercase()
+    )
+}
+
+fn extras_from_column(column: &str) -> ProviderExtras {
+    serde_json::from_str(column).unwrap_or_else(|e| {
+        tracing::warn!("Ignoring unreadable provider extras: {e}");
+        ProviderExtras::default()
+    })
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +109,78 @@ pub struct ModelInfo {
     pub supports_tools: bool,
     pub is_custom: bool,
     pub is_active: bool,
+    /// USD per million input tokens, when known.
+    pub input_cost_per_mtok: Option<f64>,
+    /// USD per million output tokens, when known.
+    pub output_cost_per_mtok: Option<f64>,
+    /// Kinds of input the model accepts. Always includes text.
+    pub input_modalities: Vec<ModelModality>,
+}
+
+impl ModelInfo {
+    /// Whether the model accepts every modality in `required`.
+    pub fn accepts(&self, required: &[ModelModality]) -> bool {
+        required.iter().all(|m| self.input_modalities.contains(m))
+    }
+
+    /// Pricing for cost estimates; `None` unless both rates are known.
+    pub fn pricing(&self) -> Option<ModelPricing> {
+        Some(ModelPricing {
+            input_per_mtok: self.input_cost_per_mtok?,
+            output_per_mtok: self.output_cost_per_mtok?,
+        })
+    }
+}
+
+/// Kind of input a model can take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum ModelModality {
+    Text,
+    Image,
+    Audio,
+}
+
+impl ModelModality {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Image => "image",
+            Self::Audio => "audio",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "text" => Some(Self::Text),
+            "image" => Some(Self::Image),
+            "audio" => Some(Self::Audio),
+            _ => None,
+        }
+    }
+}
+
+/// Comma-separated form stored in `ai_models.input_modalities`. Text is always included.
+fn modalities_to_column(modalities: &[ModelModality]) -> String {
+    let mut names = vec![ModelModality::Text.as_str()];
+    for m in modalities {
+        if !names.contains(&m.as_str()) {
+            names.push(m.as_str());
+        }
+    }
+    names.join(",")
+}
+
+fn modalities_from_column(column: &str) -> Vec<ModelModality> {
+    column.split(',').filter_map(ModelModality::parse).collect()
+}
+
+/// Token pricing of a model, in USD per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPricing {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,6 +218,8 @@ struct BuiltinProvider {
     name: String,
     base_url: String,
     requires_api_key: bool,
+    #[serde(default)]
+    supports_embeddings: bool,
     models: Vec<BuiltinModel>,
 }
 
@@ -75,6 +230,12 @@ struct BuiltinModel {
     context_limit: Option<i64>,
     #[serde(default = "default_supports_tools")]
     supports_tools: bool,
+    #[serde(default)]
+    input_cost_per_mtok: Option<f64>,
+    #[serde(default)]
+    output_cost_per_mtok: Option<f64>,
+    #[serde(default)]
+    input_modalities: Vec<ModelModality>,
 }
 
 fn default_supports_tools() -> bool {
@@ -104,6 +265,11 @@ impl ProviderRegistry {
                      VALUES (?1, ?2, ?3, ?4, 0)",
                     rusqlite::params![p.id, p.name, p.base_url, p.requires_api_key as i32],
                 )?;
+                // Capability flags track the embedded definitions
+                conn.execute(
+                    "UPDATE ai_providers SET supports_embeddings = ?2 WHERE id = ?1 AND is_user_defined = 0",
+                    rusqlite::params![p.id, p.supports_embeddings as i32],
+                )?;
 
                 for m in &p.models {
                     let composite_id = format!("{}:{}", p.id, m.model_id);
@@ -112,6 +278,16 @@ impl ProviderRegistry {
                          VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0)",
                         rusqlite::params![composite_id, p.id, m.model_id, m.display_name, m.context_limit, m.supports_tools as i32],
                     )?;
+                    // Backfill pricing on rows seeded before it was known
+                    conn.execute(
+                        "UPDATE ai_models SET input_cost_per_mtok = ?2, output_cost_per_mtok = ?3
+                         WHERE id = ?1 AND is_custom = 0 AND input_cost_per_mtok IS NULL AND output_cost_per_mtok IS NULL",
+                        rusqlite::params![composite_id, m.input_cost_per_mtok, m.output_cost_per_mtok],
+                    )?;
+                    conn.execute(
+                        "UPDATE ai_models SET input_modalities = ?2 WHERE id = ?1 AND is_custom = 0",
+                        rusqlite::params![composite_id, modalities_to_column(&m.input_modalities)],
+                    )?;
                 }
             }
 
@@ -138,7 +314,7 @@ impl ProviderRegistry {
     pub async fn list_providers(&self) -> Result<Vec<ProviderWithModels>> {
         db::with_db(&self.db, |conn| {
             let mut stmt = conn.prepare(
-                "SELECT id, name, base_url, requires_api_key, is_active, is_user_defined, created_at
+                "SELECT id, name, base_url, requires_api_key, is_active, is_user_defined, created_at, supports_embeddings, extras
                  FROM ai_providers ORDER BY name",
             )?;
 
@@ -152,6 +328,8 @@ impl ProviderRegistry {
                         is_active: row.get::<_, i32>(4)? != 0,
                         is_user_defined: row.get::<_, i32>(5)? != 0,
                         created_at: row.get(6)?,
+                        supports_embeddings: row.get::<_, i32>(7)? != 0,
+                        extras: extras_from_column(&row.get::<_, String>(8)?),
                     })
                 })?
                 .filter_map(|r| {
@@ -201,7 +379,7 @@ impl ProviderRegistry {
         db::with_db(&self.db, move |conn| {
             let provider = conn
                 .query_row(
-                    "SELECT id, name, base_url, requires_api_key, is_active, is_user_defined, created_at
+                    "SELECT id, name, base_url, requires_api_key, is_active, is_user_defined, created_at, supports_embeddings, extras
                      FROM ai_providers WHERE id = ?1",
                     [&id],
                     |row| {
@@ -213,6 +391,8 @@ impl ProviderRegistry {
                             is_active: row.get::<_, i32>(4)? != 0,
                             is_user_defined: row.get::<_, i32>(5)? != 0,
                             created_at: row.get(6)?,
+                            supports_embeddings: row.get::<_, i32>(7)? != 0,
+                            extras: extras_from_column(&row.get::<_, String>(8)?),
                         })
                     },
                 )
@@ -239,7 +419,8 @@ impl ProviderRegistry {
         let composite_id = format!("{provider_id}:{model_id}");
         db::with_db(&self.db, move |conn| {
             match conn.query_row(
-                "SELECT id, provider_id, model_id, display_name, context_limit, supports_tools, is_custom, is_active
+                "SELECT id, provider_id, model_id, display_name, context_limit, supports_tools, is_custom, is_active,
+                        input_cost_per_mtok, output_cost_per_mtok, input_modalities
                  FROM ai_models WHERE id = ?1",
                 [&composite_id],
                 |row| {
@@ -252,6 +433,9 @@ impl ProviderRegistry {
                         supports_tools: row.get::<_, i32>(5)? != 0,
                         is_custom: row.get::<_, i32>(6)? != 0,
                         is_active: row.get::<_, i32>(7)? != 0,
+                        input_cost_per_mtok: row.get(8)?,
+                        output_cost_per_mtok: row.get(9)?,
+                        input_modalities: modalities_from_column(&row.get::<_, String>(10)?),
                     })
                 },
             ) {
@@ -263,6 +447,56 @@ impl ProviderRegistry {
         .await
     }
 
+    /// Validate a caller-supplied model override against configured providers.
+    ///
+    /// `model` may be `provider_id:model_id`, or a bare model id when `provider`
+    /// is given. Returns the combined spec, or `None` when neither is set.
+    pub async fn validate_model_override(
+        &self,
+        model: Option<&str>,
+        provider: Option<&str>,
+    ) -> Result<Option<String>> {
+        let (provider_id, model_id) = match (model, provider) {
+            (None, None) => return Ok(None),
+            (None, Some(_)) => {
+                return Err(ZeniiError::Validation(
+                    "model is required when provider is set".into(),
+                ));
+            }
+            (Some(model), provider) => match (model.split_once(':'), provider) {
+                (Some((pid, mid)), None) => (pid, mid),
+                (Some((pid, mid)), Some(p)) if pid == p => (pid, mid),
+                (Some((pid, _)), Some(p)) => {
+                    return Err(ZeniiError::Validation(format!(
+                        "model '{model}' does not belong to provider '{p}' (got '{pid}')"
+                    )));
+                }
+                (None, Some(p)) => (p, model),
+                (None, None) => {
+                    return Err(ZeniiError::Validation(format!(
+                        "invalid model '{model}': expected 'provider_id:model_id' or a provider"
+                    )));
+                }
+            },
+        };
+
+        match self.get_provider(provider_id).await {
+            Ok(_) => {}
+            Err(ZeniiError::NotFound(_)) => {
+                return Err(ZeniiError::Validation(format!(
+                    "unknown provider '{provider_id}'"
+                )));
+            }
+            Err(e) => return Err(e),
+        }
+        if self.get_model_info(provider_id, model_id).await?.is_none() {
+            return Err(ZeniiError::Validation(format!(
+                "model '{model_id}' is not configured for provider '{provider_id}'"
+            )));
+        }
+        Ok(Some(format!("{provider_id}:{model_id}")))
+    }
+
     /// Add a user-defined provider.
     pub async fn add_user_provider(
         &self,
@@ -332,6 +566,75 @@ impl ProviderRegistry {
         .await
     }
 
+    /// Replace a provider's extra headers and query parameters. Values of
+    /// secret headers are not stored here; see [`secret_header_key`].
+    pub async fn set_extras(&self, id: &str, extras: &ProviderExtras) -> Result<()> {
+        extras.validate()?;
+        let id = id.to_string();
+        let column = serde_json::to_string(extras)?;
+
+        db::with_db(&self.db, move |conn| {
+            let rows = conn.execute(
+                "UPDATE ai_providers SET extras = ?1 WHERE id = ?2",
+                rusqlite::params![column, id],
+            )?;
+            if rows == 0 {
+                return Err(ZeniiError::NotFound(format!("provider not found: {id}")));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Mark whether a provider serves embeddings. Built-in flags are reset from
+    /// `providers.json` on every seed, so this is meant for user-defined providers.
+    pub async fn set_supports_embeddings(&self, id: &str, supported: bool) -> Result<()> {
+        let id = id.to_string();
+
+        db::with_db(&self.db, move |conn| {
+            let rows = conn.execute(
+                "UPDATE ai_providers SET supports_embeddings = ?1 WHERE id = ?2",
+                rusqlite::params![supported as i32, id],
+            )?;
+            if rows == 0 {
+                return Err(ZeniiError::NotFound(format!("provider not found: {id}")));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Build an embedding provider that reuses `provider_id`'s base URL and
+    /// stored API key. Fails when the provider does not support embeddings.
+    pub async fn embedding_provider(
+        &self,
+        provider_id: &str,
+        model_id: &str,
+        dimensions: usize,
+        credentials: &dyn CredentialStore,
+    ) -> Result<crate::memory::openai_embeddings::OpenAiEmbeddingProvider> {
+        let provider = self.get_provider(provider_id).await?.provider;
+        if !provider.supports_embeddings {
+            return Err(ZeniiError::Embedding(format!(
+                "provider '{provider_id}' does not support embeddings"
+            )));
+        }
+        let api_key = crate::ai::providers::resolve_api_key_for_provider(
+            provider_id,
+            provider.requires_api_key,
+            credentials,
+        )
+        .await?;
+        Ok(
+            crate::memory::openai_embeddings::OpenAiEmbeddingProvider::new(
+                api_key,
+                model_id.to_string(),
+                dimensions,
+            )
+            .with_base_url(provider.base_url),
+        )
+    }
+
     /// Delete a user-defined provider. Built-in providers cannot be deleted.
     pub async fn delete_user_provider(&self, id: &str) -> Result<()> {
         let id = id.to_string();
@@ -366,13 +669,14 @@ impl ProviderRegistry {
         .await
     }
 
-    /// Add a custom model to an existing provider.
+    /// Add a custom model to an existing provider, optionally with its pricing.
     pub async fn add_custom_model(
         &self,
         provider_id: &str,
         model_id: &str,
         display_name: &str,
         supports_tools: bool,
+        pricing: Option<ModelPricing>,
     ) -> Result<()> {
         let provider_id = provider_id.to_string();
         let model_id = model_id.to_string();
@@ -394,9 +698,17 @@ impl ProviderRegistry {
 
             let composite_id = format!("{provider_id}:{model_id}");
             conn.execute(
-                "INSERT INTO ai_models (id, provider_id, model_id, display_name, supports_tools, is_custom)
-                 VALUES (?1, ?2, ?3, ?4, ?5, 1)",
-                rusqlite::params![composite_id, provider_id, model_id, display_name, supports_tools as i32],
+                "INSERT INTO ai_models (id, provider_id, model_id, display_name, supports_tools, is_custom, input_cost_per_mtok, output_cost_per_mtok)
+                 VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?7)",
+                rusqlite::params![
+                    composite_id,
+                    provider_id,
+                    model_id,
+                    display_name,
+                    supports_tools as i32,
+                    pricing.map(|p| p.input_per_mtok),
+                    pricing.map(|p| p.output_per_mtok),
+                ],
             )
             .map_err(|e| match e {
                 rusqlite::Error::SqliteFailure(ref err, _)
@@ -412,6 +724,30 @@ impl ProviderRegistry {
         .await
     }
 
+    /// Set the input modalities of a custom model. Built-in models take theirs
+    /// from the embedded definitions.
+    pub async fn set_input_modalities(
+        &self,
+        composite_id: &str,
+        modalities: &[ModelModality],
+    ) -> Result<()> {
+        let composite_id = composite_id.to_string();
+        let column = modalities_to_column(modalities);
+        db::with_db(&self.db, move |conn| {
+            let updated = conn.execute(
+                "UPDATE ai_models SET input_modalities = ?2 WHERE id = ?1 AND is_custom = 1",
+                rusqlite::params![composite_id, column],
+            )?;
+            if updated == 0 {
+                return Err(ZeniiError::NotFound(format!(
+                    "custom model not found: {composite_id}"
+                )));
+            }
+            Ok(())
+        })
+        .await
+    }
+
     /// Delete a custom model. Built-in models cannot be deleted.
     pub async fn delete_custom_model(&self, composite_id: &str) -> Result<()> {
         let composite_id = composite_id.to_string();
@@ -503,7 +839,8 @@ fn load_models_for_provider(
     provider_id: &str,
 ) -> Result<Vec<ModelInfo>> {
     let mut stmt = conn.prepare(
-        "SELECT id, provider_id, model_id, display_name, context_limit, supports_tools, is_custom, is_active
+        "SELECT id, provider_id, model_id, display_name, context_limit, supports_tools, is_custom, is_active,
+                        input_cost_per_mtok, output_cost_per_mtok, input_modalities
          FROM ai_models WHERE provider_id = ?1 AND id != '_default_model' ORDER BY display_name",
     )?;
 
@@ -518,6 +855,9 @@ fn load_models_for_provider(
                 supports_tools: row.get::<_, i32>(5)? != 0,
                 is_custom: row.get::<_, i32>(6)? != 0,
                 is_active: row.get::<_, i32>(7)? != 0,
+                input_cost_per_mtok: row.get(8)?,
+                output_cost_per_mtok: row.get(9)?,
+                input_modalities: modalities_from_column(&row.get::<_, String>(10)?),
             })
         })?
         .filter_map(|r| {
@@ -546,6 +886,52 @@ mod tests {
         (dir, registry)
     }
 
+    // PR.1 — Model overrides are validated against configured providers
+    #[tokio::test]
+    async fn validate_model_override_checks_registry() {
+        let (_dir, registry) = test_registry().await;
+        registry
+            .add_user_provider(
+                "local",
+                "Local",
+                "http://localhost:1234/v1",
+                false,
+                &[("llama3".into(), "Llama 3".into(), true)],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            registry.validate_model_override(None, None).await.unwrap(),
+            None
+        );
+        for (model, provider) in [
+            (Some("llama3"), Some("local")),
+            (Some("local:llama3"), None),
+        ] {
+            assert_eq!(
+                registry
+                    .validate_model_override(model, provider)
+                    .await
+                    .unwrap()
+                    .as_deref(),
+                Some("local:llama3")
+            );
+        }
+        for (model, provider) in [
+            (None, Some("local")),
+            (Some("llama3"), None),
+            (Some("local:llama3"), Some("other")),
+            (Some("missing"), Some("local")),
+            (Some("llama3"), Some("nope")),
+        ] {
+            assert!(matches!(
+                registry.validate_model_override(model, provider).await,
+                Err(ZeniiError::Validation(_))
+            ));
+        }
+    }
+
     #[tokio::test]
     async fn seed_builtin_idempotent() {
         let (_dir, registry) = test_registry().await;
@@ -671,7 +1057,13 @@ mod tests {
         registry.seed_builtin_providers().await.unwrap();
 
         registry
-            .add_custom_model("openai", "ft:gpt-4o:my-org", "My Fine-tuned GPT-4o", true)
+            .add_custom_model(
+                "openai",
+                "ft:gpt-4o:my-org",
+                "My Fine-tuned GPT-4o",
+                true,
+                None,
+            )
             .await
             .unwrap();
 
@@ -681,13 +1073,91 @@ mod tests {
         assert!(custom.unwrap().is_custom);
     }
 
+    // PR.2 — Seeded and custom models carry their pricing
+    #[tokio::test]
+    async fn model_pricing_seeded_and_custom() {
+        let (_dir, registry) = test_registry().await;
+        registry.seed_builtin_providers().await.unwrap();
+
+        let seeded = registry
+            .get_model_info("openai", "gpt-4o")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(seeded.pricing().is_some());
+
+        let pricing = ModelPricing {
+            input_per_mtok: 1.5,
+            output_per_mtok: 6.0,
+        };
+        registry
+            .add_custom_model("openai", "priced", "Priced", true, Some(pricing))
+            .await
+            .unwrap();
+        registry
+            .add_custom_model("openai", "unpriced", "Unpriced", true, None)
+            .await
+            .unwrap();
+
+        let priced = registry.get_model_info("openai", "priced").await.unwrap();
+        assert_eq!(priced.unwrap().pricing(), Some(pricing));
+        let unpriced = registry.get_model_info("openai", "unpriced").await.unwrap();
+        assert_eq!(unpriced.unwrap().pricing(), None);
+    }
+
+    // PR.5 — Seeded models carry their input modalities; custom ones can be set
+    #[tokio::test]
+    async fn model_input_modalities() {
+        let (_dir, registry) = test_registry().await;
+        registry.seed_builtin_providers().await.unwrap();
+
+        let vision = registry
+            .get_model_info("openai", "gpt-4o")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(vision.accepts(&[ModelModality::Text, ModelModality::Image]));
+        let text_only = registry
+            .get_model_info("openai", "o3-mini")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(text_only.input_modalities, vec![ModelModality::Text]);
+
+        registry
+            .add_custom_model("openai", "custom", "Custom", true, None)
+            .await
+            .unwrap();
+        registry
+            .set_input_modalities("openai:custom", &[ModelModality::Image])
+            .await
+            .unwrap();
+        let custom = registry
+            .get_model_info("openai", "custom")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            custom.input_modalities,
+            vec![ModelModality::Text, ModelModality::Image]
+        );
+
+        // Built-in models keep the embedded definition
+        assert!(
+            registry
+                .set_input_modalities("openai:o3-mini", &[ModelModality::Image])
+                .await
+                .is_err()
+        );
+    }
+
     #[tokio::test]
     async fn delete_custom_model() {
         let (_dir, registry) = test_registry().await;
         registry.seed_builtin_providers().await.unwrap();
 
         registry
-            .add_custom_model("openai", "custom-model", "Custom", true)
+            .add_custom_model("openai", "custom-model", "Custom", true, None)
             .await
             .unwrap();
 
@@ -775,4 +1245,56 @@ mod tests {
         assert!(ollama.has_api_key); // Ollama doesn't require a key, so has_api_key is true
         assert!(!ollama.provider.requires_api_key);
     }
+
+    // PR.3 — Embeddings capability is seeded and settable on user providers
+    #[tokio::test]
+    async fn embeddings_capability_flag() {
+        let (_dir, registry) = test_registry().await;
+        registry.seed_builtin_providers().await.unwrap();
+
+        let openai = registry.get_provider("openai").await.unwrap().provider;
+        assert!(openai.supports_embeddings);
+        let anthropic = registry.get_provider("anthropic").await.unwrap().provider;
+        assert!(!anthropic.supports_embeddings);
+
+        registry
+            .add_user_provider(
+                "lmstudio",
+                "LM Studio",
+                "http://localhost:1234/v1",
+                false,
+                &[],
+            )
+            .await
+            .unwrap();
+        registry
+            .set_supports_embeddings("lmstudio", true)
+            .await
+            .unwrap();
+        let custom = registry.get_provider("lmstudio").await.unwrap().provider;
+        assert!(custom.supports_embeddings);
+    }
+
+    // PR.4 — Embedding providers reuse the provider's key and refuse unsupported ones
+    #[tokio::test]
+    async fn embedding_provider_requires_capability() {
+        let (_dir, registry) = test_registry().await;
+        registry.seed_builtin_providers().await.unwrap();
+        let creds = InMemoryCredentialStore::new();
+        creds.set("api_key:openrouter", "sk-or").await.unwrap();
+        creds.set("api_key:anthropic", "sk-ant").await.unwrap();
+
+        assert!(
+            registry
+                .embedding_provider("openrouter", "openai/text-embedding-3-small", 384, &creds)
+                .await
+                .is_ok()
+        );
+        let err = registry
+            .embedding_provider("anthropic", "any", 384, &creds)
+            .await
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("does not support embeddings"));
+    }
 }
@@ -1,5 +1,8 @@
-use rig::providers::{anthropic, openai};
+use rig::providers::{anthropic, gemini, openai};
 
+use super::prompt_cache::PromptCacheStyle;
+use super::provider_registry::{ProviderExtras, secret_header_key};
+use super::retry::{RequestExtras, RetryConfig, RetryingHttpClient};
 use crate::config::AppConfig;
 use crate::credential::CredentialStore;
 use crate::{Result, ZeniiError};
@@ -38,11 +41,17 @@ pub async fn resolve_api_key(
 
 /// Build an OpenAI-compatible client from config.
 /// Works for OpenAI, custom OpenAI-compatible endpoints (Ollama, etc.).
+/// Transient HTTP failures are retried according to `retry`; `extras` are
+/// added to every request.
 pub fn build_openai_client(
     api_key: &str,
     base_url: Option<&str>,
-) -> Result<openai::CompletionsClient> {
-    let mut builder = openai::CompletionsClient::builder().api_key(api_key);
+    retry: RetryConfig,
+    extras: RequestExtras,
+) -> Result<openai::CompletionsClient<RetryingHttpClient>> {
+    let mut builder = openai::CompletionsClient::builder()
+        .api_key(api_key)
+        .http_client(RetryingHttpClient::new(retry).with_extras(extras));
     if let Some(url) = base_url {
         builder = builder.base_url(url);
     }
@@ -52,13 +61,63 @@ pub fn build_openai_client(
 }
 
 /// Build an Anthropic client from config.
-pub fn build_anthropic_client(api_key: &str) -> Result<anthropic::Client> {
+/// Transient HTTP failures are retried according to `retry`. With
+/// `prompt_caching`, the system prompt and tool schemas are sent as cache
+/// breakpoints so repeat turns bill them at the cached rate.
+pub fn build_anthropic_client(
+    api_key: &str,
+    retry: RetryConfig,
+    prompt_caching: bool,
+    extras: RequestExtras,
+) -> Result<anthropic::Client<RetryingHttpClient>> {
+    let style = if prompt_caching {
+        PromptCacheStyle::for_provider_type("anthropic")
+    } else {
+        PromptCacheStyle::Automatic
+    };
     anthropic::Client::builder()
         .api_key(api_key)
+        .http_client(
+            RetryingHttpClient::new(retry)
+                .with_prompt_cache(style)
+                .with_extras(extras),
+        )
         .build()
         .map_err(|e| ZeniiError::Agent(format!("failed to build Anthropic client: {e}")))
 }
 
+/// Build a native Gemini client that talks to `models/{model}:generateContent`,
+/// keeping `systemInstruction`, inline image parts and function calls intact
+/// instead of going through the OpenAI-compatible shim.
+/// Transient HTTP failures are retried according to `retry`.
+pub fn build_gemini_client(
+    api_key: &str,
+    base_url: Option<&str>,
+    retry: RetryConfig,
+    extras: RequestExtras,
+) -> Result<gemini::Client<RetryingHttpClient>> {
+    let mut builder = gemini::Client::builder()
+        .api_key(api_key)
+        .http_client(RetryingHttpClient::new(retry).with_extras(extras));
+    if let Some(url) = base_url {
+        builder = builder.base_url(gemini_api_root(url));
+    }
+    builder
+        .build()
+        .map_err(|e| ZeniiError::Agent(format!("failed to build Gemini client: {e}")))
+}
+
+/// Strip the API version (and the OpenAI-compatible `/openai` suffix the
+/// provider registry stores) from a Gemini base URL, leaving the host root the
+/// native client appends `/v1beta/models/...` to.
+pub fn gemini_api_root(base_url: &str) -> &str {
+    let url = base_url.trim_end_matches('/');
+    let url = url.strip_suffix("/openai").unwrap_or(url);
+    url.strip_suffix("/v1beta")
+        .or_else(|| url.strip_suffix("/v1"))
+        .unwrap_or(url)
+}
+
 /// Resolve the API key for a specific provider by ID.
 ///
 /// Looks up `api_key:{provider_id}` in the credential store.
@@ -84,6 +143,32 @@ pub async fn resolve_api_key_for_provider(
     )))
 }
 
+/// Headers and query parameters for requests to `provider_id`, with the
+/// values of its secret headers read from the credential store.
+pub async fn resolve_request_extras(
+    provider_id: &str,
+    extras: &ProviderExtras,
+    credentials: &dyn CredentialStore,
+) -> Result<RequestExtras> {
+    if extras.is_empty() {
+        return Ok(RequestExtras::default());
+    }
+    let mut headers = extras.extra_headers.clone();
+    for name in &extras.secret_headers {
+        let key = secret_header_key(provider_id, name);
+        let value = credentials.get(&key).await?.ok_or_else(|| {
+            ZeniiError::Credential(format!(
+                "secret header '{name}' of provider '{provider_id}' is not set (key: {key})"
+            ))
+        })?;
+        headers.insert(name.clone(), value);
+    }
+    RequestExtras::new(&headers, &extras.extra_query).map_err(|e| match e {
+        ZeniiError::Config(msg) => ZeniiError::Config(format!("provider '{provider_id}': {msg}")),
+        other => other,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,17 +209,64 @@ mod tests {
     // 1.3.1 — create openai provider
     #[test]
     fn create_openai_provider() {
-        let client = build_openai_client("sk-test", None);
+        let client = build_openai_client(
+            "sk-test",
+            None,
+            RetryConfig::default(),
+            RequestExtras::default(),
+        );
         assert!(client.is_ok());
     }
 
     // 1.3.2 — create anthropic provider
     #[test]
     fn create_anthropic_provider() {
-        let client = build_anthropic_client("sk-ant-test");
+        let client = build_anthropic_client(
+            "sk-ant-test",
+            RetryConfig::default(),
+            true,
+            RequestExtras::default(),
+        );
         assert!(client.is_ok());
     }
 
+    // Native Gemini client builds, with and without the registry base URL
+    #[test]
+    fn create_gemini_provider() {
+        assert!(
+            build_gemini_client(
+                "AIza-test",
+                None,
+                RetryConfig::default(),
+                RequestExtras::default()
+            )
+            .is_ok()
+        );
+        assert!(
+            build_gemini_client(
+                "AIza-test",
+                Some("https://generativelanguage.googleapis.com/v1beta/openai"),
+                RetryConfig::default(),
+                RequestExtras::default(),
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn gemini_api_root_strips_version_and_shim() {
+        let root = "https://generativelanguage.googleapis.com";
+        assert_eq!(
+            gemini_api_root("https://generativelanguage.googleapis.com/v1beta/openai/"),
+            root
+        );
+        assert_eq!(
+            gemini_api_root("https://generativelanguage.googleapis.com/v1beta"),
+            root
+        );
+        assert_eq!(gemini_api_root(root), root);
+    }
+
     // 1.3.3 — unknown provider type errors
     #[tokio::test]
     async fn unknown_provider_type_errors() {
@@ -150,7 +282,12 @@ mod tests {
     // 1.3.4 — custom base url applied
     #[test]
     fn custom_base_url_applied() {
-        let client = build_openai_client("sk-test", Some("http://localhost:11434/v1"));
+        let client = build_openai_client(
+            "sk-test",
+            Some("http://localhost:11434/v1"),
+            RetryConfig::default(),
+            RequestExtras::default(),
+        );
         assert!(client.is_ok());
     }
 
@@ -205,4 +342,35 @@ mod tests {
         assert_eq!(key, "sk-from-store");
         unsafe { std::env::remove_var("TEST_ZENII_CRED_PRIO") };
     }
+
+    // Secret header values come from the credential store
+    #[tokio::test]
+    async fn request_extras_read_secret_headers() {
+        let creds = InMemoryCredentialStore::new();
+        let extras = ProviderExtras {
+            extra_headers: [("X-Org-Id".to_string(), "org-1".to_string())].into(),
+            extra_query: [("api-version".to_string(), "2024-10-21".to_string())].into(),
+            secret_headers: vec!["api-key".into()],
+        };
+
+        let err = resolve_request_extras("azure", &extras, &creds)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ZeniiError::Credential(_)));
+
+        creds
+            .set(&secret_header_key("azure", "Api-Key"), "secret")
+            .await
+            .unwrap();
+        let resolved = resolve_request_extras("azure", &extras, &creds)
+            .await
+            .unwrap();
+        assert!(!resolved.is_empty());
+        assert!(
+            resolve_request_extras("azure", &ProviderExtras::default(), &creds)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
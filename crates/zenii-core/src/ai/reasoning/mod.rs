@@ -1,11 +1,16 @@
+use std::sync::Arc;
+use std::time::Instant;
+
 use async_trait::async_trait;
 use rig::message::Message;
 use tracing::{debug, info};
 
 use crate::Result;
-use crate::ai::agent::{TokenUsage, ZeniiAgent};
+use crate::ai::agent::{TokenSink, TokenUsage, ZeniiAgent};
+use crate::security::budget::BudgetTracker;
 
 pub mod continuation;
+pub mod text_tool_calls;
 
 /// Context passed to strategies for evaluation.
 #[derive(Debug, Clone)]
@@ -49,6 +54,7 @@ pub trait ReasoningStrategy: Send + Sync {
 pub struct ReasoningEngine {
     strategies: Vec<Box<dyn ReasoningStrategy>>,
     global_max_interventions: u32,
+    spend_guard: Option<Arc<BudgetTracker>>,
 }
 
 impl ReasoningEngine {
@@ -56,6 +62,7 @@ impl ReasoningEngine {
         Self {
             strategies: vec![],
             global_max_interventions: global_max,
+            spend_guard: None,
         }
     }
 
@@ -63,6 +70,12 @@ impl ReasoningEngine {
         self.strategies.push(Box::new(s));
     }
 
+    /// Stop continuations once a daily/monthly spend cap is reached, counting
+    /// the estimated cost of the turn so far.
+    pub fn set_spend_guard(&mut self, guard: Arc<BudgetTracker>) {
+        self.spend_guard = Some(guard);
+    }
+
     /// Drop-in replacement for `agent.chat()`. Applies all strategies post-response.
     ///
     /// The engine:
@@ -70,14 +83,49 @@ impl ReasoningEngine {
     /// 2. Builds `StrategyContext`
     /// 3. Iterates strategies in order; first one returning `Some(nudge)` triggers continuation
     /// 4. Extends history, calls `agent.chat(&nudge, extended_history)`
-    /// 5. Repeats until no strategy intervenes or global max reached
+    /// 5. Repeats until no strategy intervenes, global max reached, the
+    ///    agent's turn budget is spent, or a spend cap is reached
+    ///
+    /// The agent's [`TurnBudget`](crate::ai::agent::TurnBudget) covers the whole
+    /// turn: each continuation only gets what earlier calls left over.
     pub async fn chat(
         &self,
         agent: &ZeniiAgent,
         prompt: &str,
         history: Vec<Message>,
     ) -> Result<ChatResult> {
-        let agent_resp = agent.chat(prompt, history.clone()).await?;
+        self.run(agent, prompt, history, None).await
+    }
+
+    /// Like [`Self::chat`], but streams the assistant's text to `on_token` as it
+    /// is produced, including the text of any continuation.
+    pub async fn chat_streaming(
+        &self,
+        agent: &ZeniiAgent,
+        prompt: &str,
+        history: Vec<Message>,
+        on_token: &TokenSink<'_>,
+    ) -> Result<ChatResult> {
+        self.run(agent, prompt, history, Some(on_token)).await
+    }
+
+    async fn run(
+        &self,
+        agent: &ZeniiAgent,
+        prompt: &str,
+        history: Vec<Message>,
+        on_token: Option<&TokenSink<'_>>,
+    ) -> Result<ChatResult> {
+        let started = Instant::now();
+        let budget = agent.budget();
+        let agent_resp = match on_token {
+            Some(sink) => {
+                agent
+                    .chat_stream(prompt, history.clone(), budget, sink)
+                    .await?
+            }
+            None => agent.chat(prompt, history.clone()).await?,
+        };
         let mut current_response = agent_resp.output;
         let mut total_usage = agent_resp.usage;
         let mut interventions_used = 0u32;
@@ -122,6 +170,25 @@ impl ReasoningEngine {
                 break;
             };
 
+            // A complete answer is already in hand, so a spent budget just ends
+            // the continuations instead of failing the turn.
+            let spent = total_usage.reported_total().unwrap_or(0);
+            let remaining = match budget.remaining(spent, started.elapsed()) {
+                Ok(remaining) => remaining,
+                Err(e) => {
+                    info!("ReasoningEngine: skipping '{name}' continuation, {e}");
+                    break;
+                }
+            };
+
+            if let Some(guard) = &self.spend_guard {
+                let pending = agent.estimate_cost(&total_usage).unwrap_or(0.0);
+                if let Err(e) = guard.check_with_pending(None, pending).await {
+                    info!("ReasoningEngine: skipping '{name}' continuation, {e}");
+                    break;
+                }
+            }
+
             // Extend history with the incomplete exchange and nudge
             current_history.push(Message::user(prompt));
             current_history.push(Message::assistant(current_response));
@@ -129,7 +196,18 @@ impl ReasoningEngine {
             interventions_used += 1;
             strategy_used = Some(name);
 
-            let nudge_resp = agent.chat(&nudge_prompt, current_history.clone()).await?;
+            let nudge_resp = match on_token {
+                Some(sink) => {
+                    agent
+                        .chat_stream(&nudge_prompt, current_history.clone(), remaining, sink)
+                        .await?
+                }
+                None => {
+                    agent
+                        .chat_with_budget(&nudge_prompt, current_history.clone(), remaining)
+                        .await?
+                }
+            };
             current_response = nudge_resp.output;
             total_usage += nudge_resp.usage;
         }
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+
+use super::{ReasoningStrategy, StrategyContext};
+use crate::ai::tool_parser::ToolCallParser;
+
+/// Catches tool calls the model wrote into its reply text instead of making
+/// them through the tool-calling interface.
+///
+/// Such calls are never executed. Rather than letting the turn end on them,
+/// this strategy answers with a tool-error message — naming each call, and
+/// the parse error for any it could not decode — so the model can resend it.
+pub struct TextToolCallStrategy {
+    parser: ToolCallParser,
+    max_interventions: u32,
+}
+
+impl TextToolCallStrategy {
+    pub fn new(max: u32) -> Self {
+        Self {
+            parser: ToolCallParser::default(),
+            max_interventions: max,
+        }
+    }
+
+    /// Tool-error message for the calls found in `response`, if any.
+    pub fn build_nudge(&self, response: &str) -> Option<String> {
+        let (format, outcomes) = self.parser.parse(response)?;
+        let lines: Vec<String> = outcomes
+            .iter()
+            .map(|outcome| match outcome {
+                Ok(call) => format!(
+                    "- '{}' was not executed: call it through the tool-calling interface.",
+                    call.name
+                ),
+                Err(e) => format!("- {}", e.to_tool_message()),
+            })
+            .collect();
+        Some(format!(
+            "Tool call error: your reply contained {} tool call(s) written as text ({format}), \
+             which are not run.\n{}\nResend each call through the tool-calling interface with \
+             its arguments as a JSON object, or give your final answer without them.",
+            outcomes.len(),
+            lines.join("\n")
+        ))
+    }
+}
+
+#[async_trait]
+impl ReasoningStrategy for TextToolCallStrategy {
+    fn name(&self) -> &str {
+        "text_tool_calls"
+    }
+
+    async fn evaluate(&self, context: &StrategyContext) -> Option<String> {
+        self.build_nudge(&context.response)
+    }
+
+    fn max_interventions(&self) -> u32 {
+        self.max_interventions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(response: &str) -> StrategyContext {
+        StrategyContext {
+            original_prompt: "list files".into(),
+            response: response.into(),
+            intervention_count: 0,
+            tool_calls_made: 0,
+        }
+    }
+
+    // 8.12.1 — A textual call is reported back instead of ending the turn
+    #[tokio::test]
+    async fn textual_call_produces_tool_error() {
+        let strategy = TextToolCallStrategy::new(2);
+        let nudge = strategy
+            .evaluate(&context(
+                r#"<tool>{"name":"file_list","arguments":{"path":"."}}</tool>"#,
+            ))
+            .await
+            .unwrap();
+        assert!(nudge.starts_with("Tool call error:"));
+        assert!(nudge.contains("'file_list' was not executed"));
+    }
+
+    // 8.12.2 — Parse errors are included so the model can fix the JSON
+    #[tokio::test]
+    async fn malformed_call_includes_parse_error() {
+        let strategy = TextToolCallStrategy::new(2);
+        let nudge = strategy
+            .evaluate(&context(r#"<tool>{"name": "shell", </tool>"#))
+            .await
+            .unwrap();
+        assert!(nudge.contains("invalid JSON"), "{nudge}");
+    }
+
+    // 8.12.3 — Ordinary answers pass through
+    #[tokio::test]
+    async fn plain_answer_is_not_nudged() {
+        let strategy = TextToolCallStrategy::new(2);
+        assert!(
+            strategy
+                .evaluate(&context("There are 3 files."))
+                .await
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Reflection step for tools that keep failing the same way.
+//!
+//! The tool loop itself runs inside rig, so the step works on tool results:
+//! once a tool fails with the same error `threshold` times in a row, a
+//! reflection prompt is appended to that failure so the model sees it before
+//! its next iteration. If the tool keeps failing the same way for another
+//! `threshold` calls and aborting is enabled, the turn is stopped at the next
+//! tool call. Both steps publish [`AppEvent::ToolFailureLoop`].
+
+use std::sync::{Arc, OnceLock};
+
+use dashmap::DashMap;
+
+use crate::config::AppConfig;
+use crate::event_bus::{AppEvent, EventBus};
+
+/// Longest error excerpt quoted in the reflection prompt and event.
+const MAX_ERROR_CHARS: usize = 300;
+
+#[derive(Default)]
+struct Streak {
+    error: String,
+    count: u32,
+}
+
+/// Consecutive identical failures per tool for one request.
+#[derive(Default)]
+pub struct ToolFailureTracker {
+    /// Failures in a row before reflecting; 0 disables tracking.
+    threshold: u32,
+    abort: bool,
+    streaks: DashMap<String, Streak>,
+    abort_reason: OnceLock<String>,
+    event_bus: Option<Arc<dyn EventBus>>,
+}
+
+impl ToolFailureTracker {
+    pub fn new(threshold: u32, abort: bool, event_bus: Option<Arc<dyn EventBus>>) -> Self {
+        Self {
+            threshold,
+            abort,
+            event_bus,
+            ..Self::default()
+        }
+    }
+
+    pub fn from_config(config: &AppConfig, event_bus: Option<Arc<dyn EventBus>>) -> Self {
+        Self::new(
+            config.agent_tool_failure_threshold,
+            config.agent_abort_on_repeated_failures,
+            event_bus,
+        )
+    }
+
+    /// Record one call's outcome. Returns the reflection prompt to append to
+    /// the failure when the streak reaches the threshold.
+    pub fn record(&self, tool_name: &str, success: bool, error: &str) -> Option<String> {
+        if self.threshold == 0 {
+            return None;
+        }
+        if success {
+            self.streaks.remove(tool_name);
+            return None;
+        }
+        let error = excerpt(error);
+        let count = {
+            let mut streak = self.streaks.entry(tool_name.to_string()).or_default();
+            if streak.error != error {
+                streak.error = error.clone();
+                streak.count = 0;
+            }
+            streak.count += 1;
+            streak.count
+        };
+
+        if count == self.threshold {
+            tracing::info!(
+                "Tool '{tool_name}' failed {count} times in a row, asking the agent to reflect"
+            );
+            self.publish(tool_name, count, &error, false);
+            return Some(format!(
+                "Reflection: the last {count} calls to '{tool_name}' failed with the same error: \
+                 {error}. Repeating the call will not help. Reconsider your approach: change the \
+                 arguments, use a different tool, or ask the user for help."
+            ));
+        }
+        if self.abort && count >= self.threshold * 2 && self.abort_reason.get().is_none() {
+            let reason = format!(
+                "stopped after {count} consecutive failures of tool '{tool_name}': {error}"
+            );
+            tracing::warn!("Agent {reason}");
+            if self.abort_reason.set(reason).is_ok() {
+                self.publish(tool_name, count, &error, true);
+            }
+        }
+        None
+    }
+
+    /// Set once aborting is enabled and reflection did not break the streak.
+    pub fn abort_reason(&self) -> Option<&str> {
+        self.abort_reason.get().map(String::as_str)
+    }
+
+    fn publish(&self, tool_name: &str, failures: u32, error: &str, aborted: bool) {
+        if let Some(bus) = &self.event_bus {
+            let _ = bus.publish(AppEvent::ToolFailureLoop {
+                tool_name: tool_name.to_string(),
+                failures,
+                error: error.to_string(),
+                aborted,
+            });
+        }
+    }
+}
+
+fn excerpt(error: &str) -> String {
+    let error = error.trim();
+    match error.char_indices().nth(MAX_ERROR_CHARS) {
+        Some((i, _)) => format!("{}…", &error[..i]),
+        None => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RF.1 — The threshold-th identical failure yields a reflection prompt
+    #[test]
+    fn reflects_after_threshold() {
+        let tracker = ToolFailureTracker::new(3, false, None);
+        assert!(
+            tracker
+                .record("shell", false, "permission denied")
+                .is_none()
+        );
+        assert!(
+            tracker
+                .record("shell", false, "permission denied")
+                .is_none()
+        );
+        let prompt = tracker.record("shell", false, "permission denied").unwrap();
+        assert!(prompt.contains("last 3 calls to 'shell'"));
+        assert!(prompt.contains("permission denied"));
+        assert!(
+            tracker
+                .record("shell", false, "permission denied")
+                .is_none()
+        );
+    }
+
+    // RF.2 — A success or a different error restarts the streak
+    #[test]
+    fn success_or_new_error_resets() {
+        let tracker = ToolFailureTracker::new(2, false, None);
+        tracker.record("web_search", false, "timeout");
+        tracker.record("web_search", true, "");
+        assert!(tracker.record("web_search", false, "timeout").is_none());
+        assert!(
+            tracker
+                .record("web_search", false, "rate limited")
+                .is_none()
+        );
+        assert!(
+            tracker
+                .record("web_search", false, "rate limited")
+                .is_some()
+        );
+        // Other tools keep their own streaks
+        assert!(tracker.record("file_read", false, "rate limited").is_none());
+    }
+
+    // RF.3 — Aborting after reflection is opt-in and publishes an event
+    #[test]
+    fn aborts_when_reflection_does_not_help() {
+        let bus: Arc<dyn EventBus> = Arc::new(crate::event_bus::TokioBroadcastBus::new(16));
+        let mut rx = bus.subscribe();
+        let tracker = ToolFailureTracker::new(2, true, Some(bus));
+        for _ in 0..3 {
+            tracker.record("shell", false, "exit 1");
+        }
+        assert!(tracker.abort_reason().is_none());
+        tracker.record("shell", false, "exit 1");
+        assert!(
+            tracker
+                .abort_reason()
+                .unwrap()
+                .contains("4 consecutive failures")
+        );
+
+        let events: Vec<bool> = std::iter::from_fn(|| rx.try_recv().ok())
+            .map(|e| match e {
+                AppEvent::ToolFailureLoop { aborted, .. } => aborted,
+                other => panic!("unexpected event {other:?}"),
+            })
+            .collect();
+        assert_eq!(events, vec![false, true]);
+
+        let passive = ToolFailureTracker::new(2, false, None);
+        for _ in 0..10 {
+            passive.record("shell", false, "exit 1");
+        }
+        assert!(passive.abort_reason().is_none());
+    }
+}
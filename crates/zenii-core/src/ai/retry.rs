@@ -0,0 +1,454 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use rig::http_client::{
+    self, HeaderMap, HttpClientExt, LazyBody, Method, MultipartForm, Request, Response,
+    StreamingResponse, Uri,
+};
+use rig::wasm_compat::{WasmCompatSend, WasmCompatSync};
+use tracing::{debug, warn};
+
+use super::prompt_cache::{PromptCacheStyle, mark_cacheable_prefix};
+use crate::ZeniiError;
+use crate::config::AppConfig;
+
+/// Backoff schedule for provider HTTP requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Total attempts, including the first request. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for each further retry.
+    pub initial_backoff: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+        }
+    }
+}
+
+impl RetryConfig {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            max_attempts: config.provider_retry_max_attempts,
+            initial_backoff: Duration::from_millis(config.provider_retry_initial_backoff_ms),
+        }
+    }
+
+    /// Delay before retry number `retry` (1-based): exponential, plus up to 25%
+    /// jitter so concurrent requests don't retry in lockstep.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let base = self
+            .initial_backoff
+            .saturating_mul(1u32 << retry.saturating_sub(1).min(16));
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0);
+        base + base.mul_f64(f64::from(nanos % 1000) / 4000.0)
+    }
+}
+
+/// Whether a provider request that failed with `error` may be retried: connect
+/// errors, timeouts, 429 and 5xx. Other 4xx responses are never retried.
+pub fn is_retryable(error: &http_client::Error) -> bool {
+    match error {
+        http_client::Error::InvalidStatusCode(status)
+        | http_client::Error::InvalidStatusCodeWithMessage(status, _) => {
+            status.as_u16() == 429 || status.is_server_error()
+        }
+        http_client::Error::Instance(e) => e
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| e.is_connect() || e.is_timeout()),
+        _ => false,
+    }
+}
+
+/// Run `attempt` until it succeeds, fails with a non-retryable error, or
+/// `retry.max_attempts` is reached, sleeping [`RetryConfig::backoff`] between tries.
+pub async fn retry_request<T, F, Fut>(retry: RetryConfig, mut attempt: F) -> http_client::Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = http_client::Result<T>>,
+{
+    let mut tries = 1;
+    loop {
+        match attempt().await {
+            Err(e) if tries < retry.max_attempts && is_retryable(&e) => {
+                let delay = retry.backoff(tries);
+                debug!("Provider request failed ({e}), retry {tries} in {delay:?}");
+                tokio::time::sleep(delay).await;
+                tries += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Headers and query parameters added to every request a provider client sends.
+#[derive(Clone, Default)]
+pub struct RequestExtras {
+    headers: HeaderMap,
+    query: Vec<(String, String)>,
+}
+
+impl RequestExtras {
+    /// Fails when a header name or value is not valid HTTP.
+    pub fn new(
+        headers: &HashMap<String, String>,
+        query: &HashMap<String, String>,
+    ) -> crate::Result<Self> {
+        let headers = HeaderMap::try_from(headers)
+            .map_err(|e| ZeniiError::Config(format!("invalid header: {e}")))?;
+        let mut query: Vec<_> = query.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        query.sort();
+        Ok(Self { headers, query })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty() && self.query.is_empty()
+    }
+
+    /// Add the extras to a request made outside a provider client, such as
+    /// the connection test.
+    pub fn apply_to_request(
+        &self,
+        mut request: reqwest::RequestBuilder,
+    ) -> reqwest::RequestBuilder {
+        for (name, value) in &self.headers {
+            request = request.header(name.as_str(), value.as_bytes());
+        }
+        if !self.query.is_empty() {
+            request = request.query(&self.query);
+        }
+        request
+    }
+
+    fn apply(&self, template: &mut RequestTemplate) {
+        for (name, value) in &self.headers {
+            template.headers.insert(name.clone(), value.clone());
+        }
+        if self.query.is_empty() {
+            return;
+        }
+        let pairs = self
+            .query
+            .iter()
+            .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
+            .collect::<Vec<_>>()
+            .join("&");
+        let separator = if template.uri.query().is_some() {
+            '&'
+        } else {
+            '?'
+        };
+        match format!("{}{separator}{pairs}", template.uri).parse() {
+            Ok(uri) => template.uri = uri,
+            Err(e) => warn!(
+                "Not adding provider query parameters to {}: {e}",
+                template.uri
+            ),
+        }
+    }
+}
+
+// Header values may be credentials, so only the names are printed
+impl std::fmt::Debug for RequestExtras {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestExtras")
+            .field("headers", &self.headers.keys().collect::<Vec<_>>())
+            .field(
+                "query",
+                &self.query.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// The parts of a request needed to send it again.
+#[derive(Clone)]
+struct RequestTemplate {
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl RequestTemplate {
+    fn new<T: Into<Bytes>>(req: Request<T>) -> Self {
+        let (parts, body) = req.into_parts();
+        Self {
+            method: parts.method,
+            uri: parts.uri,
+            headers: parts.headers,
+            body: body.into(),
+        }
+    }
+
+    fn build(&self) -> http_client::Result<Request<Bytes>> {
+        let mut req = Request::builder()
+            .method(self.method.clone())
+            .uri(self.uri.clone())
+            .body(self.body.clone())?;
+        *req.headers_mut() = self.headers.clone();
+        Ok(req)
+    }
+}
+
+/// HTTP backend for provider clients that retries transient failures with
+/// [`retry_request`]. Streaming requests are only retried until the response
+/// starts; multipart uploads are sent once.
+#[derive(Debug, Clone, Default)]
+pub struct RetryingHttpClient {
+    inner: reqwest::Client,
+    retry: RetryConfig,
+    prompt_cache: PromptCacheStyle,
+    extras: Arc<RequestExtras>,
+}
+
+impl RetryingHttpClient {
+    pub fn new(retry: RetryConfig) -> Self {
+        Self {
+            inner: reqwest::Client::default(),
+            retry,
+            prompt_cache: PromptCacheStyle::default(),
+            extras: Arc::default(),
+        }
+    }
+
+    /// Add the provider's extra headers and query parameters to every request.
+    pub fn with_extras(mut self, extras: RequestExtras) -> Self {
+        self.extras = Arc::new(extras);
+        self
+    }
+
+    /// Mark the stable request prefix cacheable in the provider's format
+    /// before sending; see [`mark_cacheable_prefix`].
+    pub fn with_prompt_cache(mut self, style: PromptCacheStyle) -> Self {
+        self.prompt_cache = style;
+        self
+    }
+
+    fn template<T: Into<Bytes>>(&self, req: Request<T>) -> RequestTemplate {
+        let mut template = RequestTemplate::new(req);
+        if self.prompt_cache == PromptCacheStyle::CacheControl {
+            template.body = mark_cacheable_prefix(template.body);
+        }
+        self.extras.apply(&mut template);
+        template
+    }
+}
+
+impl HttpClientExt for RetryingHttpClient {
+    fn send<T, U>(
+        &self,
+        req: Request<T>,
+    ) -> impl Future<Output = http_client::Result<Response<LazyBody<U>>>> + WasmCompatSend + 'static
+    where
+        T: Into<Bytes> + WasmCompatSend,
+        U: From<Bytes> + WasmCompatSend + 'static,
+    {
+        let (inner, retry) = (self.inner.clone(), self.retry);
+        let template = self.template(req);
+        async move {
+            retry_request(retry, || {
+                let inner = inner.clone();
+                let req = template.build();
+                async move { inner.send(req?).await }
+            })
+            .await
+        }
+    }
+
+    fn send_multipart<U>(
+        &self,
+        req: Request<MultipartForm>,
+    ) -> impl Future<Output = http_client::Result<Response<LazyBody<U>>>> + WasmCompatSend + 'static
+    where
+        U: From<Bytes> + WasmCompatSend + 'static,
+    {
+        self.inner.send_multipart(req)
+    }
+
+    fn send_streaming<T>(
+        &self,
+        req: Request<T>,
+    ) -> impl Future<Output = http_client::Result<StreamingResponse>> + WasmCompatSend
+    where
+        T: Into<Bytes> + WasmCompatSend,
+    {
+        let (inner, retry) = (self.inner.clone(), self.retry);
+        let template = self.template(req);
+        async move {
+            retry_request(retry, || {
+                let inner = inner.clone();
+                let req = template.build();
+                async move { inner.send_streaming(req?).await }
+            })
+            .await
+        }
+    }
+}
+
+// `RetryingHttpClient` must satisfy the bounds rig's completion models put on `H`.
+const _: fn() = || {
+    fn assert_bounds<H: HttpClientExt + Clone + Default + std::fmt::Debug + WasmCompatSync>() {}
+    assert_bounds::<RetryingHttpClient>();
+};
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use reqwest::StatusCode;
+
+    use super::*;
+
+    fn fast() -> RetryConfig {
+        RetryConfig {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+        }
+    }
+
+    fn status(code: u16) -> http_client::Error {
+        http_client::Error::InvalidStatusCodeWithMessage(
+            StatusCode::from_u16(code).unwrap(),
+            String::new(),
+        )
+    }
+
+    // RETRY.1 — Only 429, 5xx and connection failures are retryable
+    #[test]
+    fn retryable_errors() {
+        assert!(is_retryable(&status(429)));
+        assert!(is_retryable(&status(500)));
+        assert!(is_retryable(&status(503)));
+        assert!(!is_retryable(&status(400)));
+        assert!(!is_retryable(&status(401)));
+        assert!(!is_retryable(&status(404)));
+        assert!(!is_retryable(&http_client::Error::StreamEnded));
+    }
+
+    // RETRY.2 — Backoff doubles per retry with at most 25% jitter
+    #[test]
+    fn backoff_is_exponential_with_bounded_jitter() {
+        let retry = RetryConfig::default();
+        for (n, base) in [(1, 200), (2, 400), (3, 800)] {
+            let delay = retry.backoff(n);
+            assert!(delay >= Duration::from_millis(base));
+            assert!(delay <= Duration::from_millis(base * 5 / 4));
+        }
+    }
+
+    // RETRY.3 — Transient failures are retried up to max_attempts
+    #[tokio::test]
+    async fn retry_request_retries_transient_errors() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let c = calls.clone();
+        let result = retry_request(fast(), || {
+            let n = c.fetch_add(1, Ordering::SeqCst);
+            async move { if n < 2 { Err(status(503)) } else { Ok(n) } }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        calls.store(0, Ordering::SeqCst);
+        let c = calls.clone();
+        let result: http_client::Result<()> = retry_request(fast(), || {
+            c.fetch_add(1, Ordering::SeqCst);
+            async { Err(status(429)) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    // RETRY.4 — Client errors other than 429 fail immediately
+    #[tokio::test]
+    async fn retry_request_does_not_retry_client_errors() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let c = calls.clone();
+        let result: http_client::Result<()> = retry_request(fast(), || {
+            c.fetch_add(1, Ordering::SeqCst);
+            async { Err(status(401)) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    // RETRY.5 — Extra headers and query parameters are added to each request
+    #[test]
+    fn extras_added_to_request() {
+        let headers = HashMap::from([("X-Org-Id".to_string(), "org-1".to_string())]);
+        let query = HashMap::from([("api-version".to_string(), "2024-10-21".to_string())]);
+        let client = RetryingHttpClient::new(fast())
+            .with_extras(RequestExtras::new(&headers, &query).unwrap());
+
+        let req = Request::builder()
+            .uri("https://example.openai.azure.com/openai/deployments/d/chat/completions")
+            .body(Bytes::from_static(b"{}"))
+            .unwrap();
+        let template = client.template(req);
+        assert_eq!(
+            template.uri.to_string(),
+            "https://example.openai.azure.com/openai/deployments/d/chat/completions?api-version=2024-10-21"
+        );
+        assert_eq!(template.headers["x-org-id"], "org-1");
+
+        let req = Request::builder()
+            .uri("https://proxy.local/v1/models?limit=5")
+            .body(Bytes::new())
+            .unwrap();
+        assert_eq!(
+            client.template(req).uri.query(),
+            Some("limit=5&api-version=2024-10-21")
+        );
+
+        let bad = HashMap::from([("bad header".to_string(), "x".to_string())]);
+        assert!(RequestExtras::new(&bad, &HashMap::new()).is_err());
+        assert!(!format!("{:?}", client.extras).contains("org-1"));
+    }
+
+    // RETRY.6 — A request sent with the extras carries them on the wire
+    #[tokio::test]
+    async fn extras_sent_with_request() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_lowercase()
+        });
+
+        let headers = HashMap::from([("X-Org-Id".to_string(), "org-1".to_string())]);
+        let query = HashMap::from([("api-version".to_string(), "2024-10-21".to_string())]);
+        let extras = RequestExtras::new(&headers, &query).unwrap();
+        let request = reqwest::Client::new().get(format!("http://{addr}/v1/models?limit=5"));
+        let response = extras.apply_to_request(request).send().await.unwrap();
+        assert!(response.status().is_success());
+
+        let received = server.await.unwrap();
+        assert!(
+            received.starts_with("get /v1/models?limit=5&api-version=2024-10-21 "),
+            "{received}"
+        );
+        assert!(received.contains("x-org-id: org-1"), "{received}");
+    }
+}
@@ -1,4 +1,84 @@
+use crate::ai::provider_registry::{ModelInfo, ModelModality};
 use crate::config::AppConfig;
+use crate::{AgentError, ZeniiError};
+
+/// `routing_fallbacks` key used for requests without a hint prefix.
+pub const DEFAULT_FALLBACK_KEY: &str = "default";
+
+/// Markers of provider failures another model may not hit, for errors that
+/// carry no status code: rate limits, overloads, server errors and timeouts.
+const RETRYABLE_MARKERS: &[&str] = &[
+    "too many requests",
+    "rate limit",
+    "rate_limit",
+    "overloaded",
+    "server_error",
+    "server error",
+    "api_error",
+    "bad gateway",
+    "service unavailable",
+    "timed out",
+    "timeout",
+    "connection",
+];
+
+/// Markers of failures every model would repeat: malformed requests and auth.
+const FATAL_MARKERS: &[&str] = &[
+    "invalid_request",
+    "bad request",
+    "authentication",
+    "unauthorized",
+    "invalid api key",
+    "incorrect api key",
+    "invalid_api_key",
+    "permission",
+];
+
+/// First HTTP status in an error message, from rig's `status code NNN` or a
+/// provider body's `"code":NNN`.
+fn status_code(message: &str) -> Option<u16> {
+    ["status code ", "\"code\":"].iter().find_map(|prefix| {
+        let (_, rest) = message.split_once(prefix)?;
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        digits.parse().ok().filter(|code| (100..600).contains(code))
+    })
+}
+
+/// Kind of work a model is routed for. Each has a `hint:` prefix and a
+/// `routing_hint_*` config target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskType {
+    Reasoning,
+    Fast,
+    Vision,
+    Summarize,
+    ImageGeneration,
+}
+
+impl TaskType {
+    pub const ALL: [TaskType; 5] = [
+        TaskType::Reasoning,
+        TaskType::Fast,
+        TaskType::Vision,
+        TaskType::Summarize,
+        TaskType::ImageGeneration,
+    ];
+
+    /// The `hint:` prefix requesting this task type.
+    pub fn hint(self) -> &'static str {
+        match self {
+            TaskType::Reasoning => "hint:reasoning",
+            TaskType::Fast => "hint:fast",
+            TaskType::Vision => "hint:vision",
+            TaskType::Summarize => "hint:summarize",
+            TaskType::ImageGeneration => "hint:image",
+        }
+    }
+
+    pub fn from_hint(hint: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.hint() == hint)
+    }
+}
 
 /// Translates hint prefix strings into concrete `provider_id:model_id` pairs.
 ///
@@ -21,18 +101,120 @@ impl<'a> ModelRouter<'a> {
     /// (caller falls through to normal resolution chain).
     pub fn route(&self, requested: Option<&str>) -> Option<String> {
         let req = requested?;
-        match req {
-            "hint:reasoning" => self.config.routing_hint_reasoning.clone(),
-            "hint:fast" => self.config.routing_hint_fast.clone(),
-            "hint:vision" => self.config.routing_hint_vision.clone(),
-            "hint:summarize" => self.config.routing_hint_summarize.clone(),
-            other => Some(other.to_string()),
+        match TaskType::from_hint(req) {
+            Some(task) => self.route_task(task),
+            None => Some(req.to_string()),
         }
     }
+
+    /// The configured "provider_id:model_id" target for a task type, if any.
+    pub fn route_task(&self, task: TaskType) -> Option<String> {
+        match task {
+            TaskType::Reasoning => self.config.routing_hint_reasoning.clone(),
+            TaskType::Fast => self.config.routing_hint_fast.clone(),
+            TaskType::Vision => self.config.routing_hint_vision.clone(),
+            TaskType::Summarize => self.config.routing_hint_summarize.clone(),
+            TaskType::ImageGeneration => self.config.routing_hint_image.clone(),
+        }
+    }
+
+    /// Input modalities a request needs beyond text: `hint:vision` needs image input.
+    pub fn required_modalities(requested: Option<&str>) -> &'static [ModelModality] {
+        match requested {
+            Some("hint:vision") => &[ModelModality::Image],
+            _ => &[],
+        }
+    }
+
+    /// Pick the cheapest active model that accepts every `required` modality.
+    ///
+    /// Models without pricing rank after priced ones; ties keep candidate order.
+    /// Incapable models are never chosen: when none qualify the error names the
+    /// missing capability instead of falling back to a text-only model.
+    pub fn select<'m>(
+        &self,
+        candidates: &'m [ModelInfo],
+        required: &[ModelModality],
+    ) -> Result<&'m ModelInfo, ZeniiError> {
+        candidates
+            .iter()
+            .filter(|m| m.is_active && m.accepts(required))
+            .min_by(|a, b| match (a.input_cost_per_mtok, b.input_cost_per_mtok) {
+                (Some(x), Some(y)) => x.total_cmp(&y),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            })
+            .ok_or_else(|| {
+                let needs: Vec<&str> = required.iter().map(|m| m.as_str()).collect();
+                ZeniiError::ModelCapability(format!(
+                    "no configured model accepts {} input; add a model that supports it or set a routing hint",
+                    needs.join(" and ")
+                ))
+            })
+    }
+
+    /// Ordered fallback models for a request: the chain configured for its hint
+    /// (`hint:fast` → `"fast"`), or the `"default"` chain for everything else.
+    pub fn fallbacks(&self, requested: Option<&str>) -> &'a [String] {
+        let key = requested
+            .and_then(|r| r.strip_prefix("hint:"))
+            .filter(|hint| self.config.routing_fallbacks.contains_key(*hint))
+            .unwrap_or(DEFAULT_FALLBACK_KEY);
+        self.config
+            .routing_fallbacks
+            .get(key)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// Whether a failed completion should be retried on the next fallback model.
+///
+/// Only provider-side failures qualify (rate limits, 5xx, overloads, timeouts).
+/// Bad requests, auth failures, tool errors and turn limits short-circuit, since
+/// the provider's status code is not preserved and the message is all there is.
+pub fn is_failover_error(err: &ZeniiError) -> bool {
+    let message = match err {
+        ZeniiError::Http(_) => return true,
+        ZeniiError::Agent(message) | ZeniiError::AgentTurn(AgentError::Provider(message)) => {
+            message.to_lowercase()
+        }
+        _ => return false,
+    };
+    if !message.contains("completionerror") {
+        return false;
+    }
+    if let Some(status) = status_code(&message) {
+        return status == 429 || status >= 500;
+    }
+    if FATAL_MARKERS.iter().any(|m| message.contains(m)) {
+        return false;
+    }
+    RETRYABLE_MARKERS.iter().any(|m| message.contains(m))
+}
+
+/// Whether a failed streamed completion died in transport (dropped connection,
+/// truncated or undecodable stream) rather than being answered by the provider
+/// with an error. Only these are worth retrying without streaming.
+pub fn is_transport_error(err: &ZeniiError) -> bool {
+    let message = match err {
+        ZeniiError::Http(_) => return true,
+        ZeniiError::Agent(message) | ZeniiError::AgentTurn(AgentError::Provider(message)) => {
+            message.to_lowercase()
+        }
+        _ => return false,
+    };
+    // rig reports a failed send as `ProviderError: Http client error: ...`
+    (message.contains("http client error") || !message.contains("providererror"))
+        && status_code(&message).is_none()
+        && !FATAL_MARKERS.iter().any(|m| message.contains(m))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use super::*;
 
     fn make_config_with_routing() -> AppConfig {
@@ -92,7 +274,25 @@ mod tests {
         assert_eq!(router.route(None), None);
     }
 
-    // 6. unknown_prefix_passes_through_unchanged
+    // 6. image_generation_task_resolves_to_configured_model
+    #[test]
+    fn image_generation_task_resolves_to_configured_model() {
+        let mut config = make_config_with_routing();
+        let task = TaskType::ImageGeneration;
+        assert_eq!(ModelRouter::new(&config).route_task(task), None);
+        config.routing_hint_image = Some("openai:gpt-image-1".to_string());
+        assert_eq!(
+            ModelRouter::new(&config).route_task(task),
+            Some("openai:gpt-image-1".to_string())
+        );
+        assert_eq!(TaskType::from_hint("hint:image"), Some(task));
+        assert_eq!(
+            ModelRouter::new(&config).route(Some(task.hint())),
+            Some("openai:gpt-image-1".to_string())
+        );
+    }
+
+    // 7. unknown_prefix_passes_through_unchanged
     #[test]
     fn unknown_prefix_passes_through_unchanged() {
         let config = make_config_with_routing();
@@ -102,4 +302,167 @@ mod tests {
             Some("custom:my-model".to_string())
         );
     }
+
+    // 8. fallbacks_follow_hint_or_default_chain
+    #[test]
+    fn fallbacks_follow_hint_or_default_chain() {
+        let mut config = make_config_with_routing();
+        assert!(ModelRouter::new(&config).fallbacks(None).is_empty());
+
+        config.routing_fallbacks = HashMap::from([
+            (DEFAULT_FALLBACK_KEY.into(), vec!["openai:gpt-4o".into()]),
+            (
+                "fast".into(),
+                vec![
+                    "gemini:gemini-2.5-flash".into(),
+                    "openai:gpt-4o-mini".into(),
+                ],
+            ),
+        ]);
+        let router = ModelRouter::new(&config);
+        assert_eq!(router.fallbacks(Some("hint:fast")).len(), 2);
+        assert_eq!(router.fallbacks(Some("hint:reasoning")), ["openai:gpt-4o"]);
+        assert_eq!(
+            router.fallbacks(Some("anthropic:claude")),
+            ["openai:gpt-4o"]
+        );
+        assert_eq!(router.fallbacks(None), ["openai:gpt-4o"]);
+    }
+
+    // 9. only_provider_side_failures_fail_over
+    #[test]
+    fn only_provider_side_failures_fail_over() {
+        let agent = |m: &str| {
+            ZeniiError::from(AgentError::from_message(format!(
+                "chat failed: CompletionError: {m}"
+            )))
+        };
+        assert!(is_failover_error(&agent(
+            r#"ProviderError: {"error":{"message":"Rate limit exceeded","code":429}}"#
+        )));
+        assert!(is_failover_error(&agent(
+            r#"ProviderError: {"type":"error","error":{"type":"overloaded_error"}}"#
+        )));
+        assert!(is_failover_error(&agent(
+            "HttpError: Http client error: operation timed out"
+        )));
+        assert!(is_failover_error(&agent(
+            "HttpError: Invalid status code 503 Service Unavailable with message: upstream down"
+        )));
+        assert!(!is_failover_error(&agent(
+            "HttpError: Invalid status code 400 Bad Request with message: {}"
+        )));
+        assert!(is_failover_error(&agent(
+            r#"ProviderError: {"error":{"message":"Provider returned error","code":502}}"#
+        )));
+
+        assert!(!is_failover_error(&agent(
+            r#"ProviderError: {"error":{"message":"Incorrect API key provided","code":401}}"#
+        )));
+        assert!(!is_failover_error(&agent(
+            r#"ProviderError: {"error":{"type":"invalid_request_error","message":"max_tokens too large"}}"#
+        )));
+        assert!(!is_failover_error(&ZeniiError::from(
+            AgentError::from_message("chat failed: ToolCallError: shell timed out".into())
+        )));
+        // Untyped agent errors are still classified by message
+        assert!(is_failover_error(&ZeniiError::Agent(
+            "chat failed: CompletionError: ProviderError: overloaded".into()
+        )));
+        assert!(!is_failover_error(&ZeniiError::Credential("no key".into())));
+    }
+
+    fn model(id: &str, cost: Option<f64>, modalities: &[ModelModality]) -> ModelInfo {
+        let (provider_id, model_id) = id.split_once(':').unwrap();
+        ModelInfo {
+            id: id.into(),
+            provider_id: provider_id.into(),
+            model_id: model_id.into(),
+            display_name: model_id.into(),
+            context_limit: None,
+            supports_tools: true,
+            is_custom: false,
+            is_active: true,
+            input_cost_per_mtok: cost,
+            output_cost_per_mtok: cost,
+            input_modalities: modalities.to_vec(),
+        }
+    }
+
+    // 10. select_excludes_models_missing_a_modality
+    #[test]
+    fn select_excludes_models_missing_a_modality() {
+        let config = AppConfig::default();
+        let router = ModelRouter::new(&config);
+        let text = [ModelModality::Text];
+        let vision = [ModelModality::Text, ModelModality::Image];
+        let candidates = vec![
+            model("a:cheap-text", Some(0.1), &text),
+            model("a:unpriced-vision", None, &vision),
+            model("a:vision", Some(2.5), &vision),
+            model("a:cheaper-vision", Some(1.0), &vision),
+        ];
+
+        let picked = router.select(&candidates, &[ModelModality::Image]).unwrap();
+        assert_eq!(picked.id, "a:cheaper-vision");
+        assert_eq!(router.select(&candidates, &[]).unwrap().id, "a:cheap-text");
+    }
+
+    // 10b. only_transport_failures_retry_unstreamed
+    #[test]
+    fn only_transport_failures_retry_unstreamed() {
+        let agent = |m: &str| {
+            ZeniiError::from(AgentError::from_message(format!(
+                "stream failed: CompletionError: {m}"
+            )))
+        };
+        assert!(is_transport_error(&agent(
+            "HttpError: Http client error: connection closed before message completed"
+        )));
+        assert!(is_transport_error(&agent(
+            "HttpError: Http client error: operation timed out"
+        )));
+        assert!(is_transport_error(&agent(
+            "ProviderError: Http client error: error sending request for url (http://127.0.0.1/v1)"
+        )));
+        assert!(!is_transport_error(&agent(
+            "HttpError: Invalid status code 400 Bad Request with message: {}"
+        )));
+        assert!(!is_transport_error(&agent(
+            "HttpError: Invalid status code 429 Too Many Requests with message: {}"
+        )));
+        assert!(!is_transport_error(&agent(
+            r#"ProviderError: {"type":"error","error":{"type":"overloaded_error"}}"#
+        )));
+        assert!(!is_transport_error(&ZeniiError::from(
+            AgentError::from_message("stream failed: Unauthorized".into())
+        )));
+    }
+
+    // 11. select_errors_when_no_model_qualifies
+    #[test]
+    fn select_errors_when_no_model_qualifies() {
+        let config = AppConfig::default();
+        let router = ModelRouter::new(&config);
+        let mut inactive = model("a:vision", Some(1.0), &[ModelModality::Image]);
+        inactive.is_active = false;
+        let candidates = vec![model("a:text", Some(0.1), &[ModelModality::Text]), inactive];
+
+        let err = router
+            .select(&candidates, &[ModelModality::Image])
+            .unwrap_err();
+        assert!(matches!(err, ZeniiError::ModelCapability(_)));
+        assert!(err.to_string().contains("image input"), "{err}");
+    }
+
+    // 12. vision_hint_requires_image_input
+    #[test]
+    fn vision_hint_requires_image_input() {
+        assert_eq!(
+            ModelRouter::required_modalities(Some("hint:vision")),
+            &[ModelModality::Image]
+        );
+        assert!(ModelRouter::required_modalities(Some("hint:fast")).is_empty());
+        assert!(ModelRouter::required_modalities(None).is_empty());
+    }
 }
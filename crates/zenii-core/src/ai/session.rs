@@ -15,12 +15,22 @@ pub struct Session {
     pub source: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_key: Option<String>,
+    /// Model override ("provider_id:model_id") used for this session's completions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// JSON Schema that chat replies in this session must conform to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<serde_json::Value>,
 }
 
 fn default_source() -> String {
     "web".to_string()
 }
 
+fn parse_response_format(raw: Option<String>) -> Option<serde_json::Value> {
+    raw.and_then(|s| serde_json::from_str(&s).ok())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct SessionSummary {
@@ -35,6 +45,14 @@ pub struct SessionSummary {
     pub channel_key: Option<String>,
 }
 
+/// One page of sessions, newest first. `next_cursor` is `None` on the last page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct SessionPage {
+    pub sessions: Vec<SessionSummary>,
+    pub next_cursor: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct Message {
@@ -78,6 +96,10 @@ pub struct DelegationAgentRecord {
     pub tokens_used: u64,
     pub duration_ms: u64,
     pub error: Option<String>,
+    /// Change counts for the sub-agent's workspace; the full diff is served by
+    /// `GET /delegations/{id}/tasks/{task_id}/diff`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_changes: Option<crate::ai::delegation::workspace_diff::WorkspaceDiffSummary>,
 }
 
 pub struct SessionManager {
@@ -120,6 +142,8 @@ impl SessionManager {
             updated_at: now,
             source,
             channel_key: None,
+            model: None,
+            response_format: None,
         })
     }
 
@@ -157,6 +181,8 @@ impl SessionManager {
             updated_at: now,
             source,
             channel_key: Some(channel_key),
+            model: None,
+            response_format: None,
         })
     }
 
@@ -165,7 +191,7 @@ impl SessionManager {
 
         db::with_db(&self.db, move |conn| {
             let result = conn.query_row(
-                "SELECT id, title, created_at, updated_at, source, channel_key FROM sessions WHERE channel_key = ?1",
+                "SELECT id, title, created_at, updated_at, source, channel_key, model, response_format FROM sessions WHERE channel_key = ?1",
                 rusqlite::params![channel_key],
                 |row| {
                     Ok(Session {
@@ -175,6 +201,8 @@ impl SessionManager {
                         updated_at: row.get(3)?,
                         source: row.get(4)?,
                         channel_key: row.get(5)?,
+                        model: row.get(6)?,
+                        response_format: parse_response_format(row.get(7)?),
                     })
                 },
             );
@@ -187,6 +215,21 @@ impl SessionManager {
         .await
     }
 
+    /// Detach `channel_key` from its session so the next message on that
+    /// channel thread starts a new one. The old session and its history stay.
+    pub async fn release_channel_key(&self, channel_key: &str) -> Result<()> {
+        let channel_key = channel_key.to_string();
+
+        db::with_db(&self.db, move |conn| {
+            conn.execute(
+                "UPDATE sessions SET channel_key = NULL WHERE channel_key = ?1",
+                rusqlite::params![channel_key],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
     pub async fn list_channel_sessions(
         &self,
         source: Option<&str>,
@@ -320,7 +363,7 @@ impl SessionManager {
 
         db::with_db(&self.db, move |conn| {
             conn.query_row(
-                "SELECT id, title, created_at, updated_at, source, channel_key FROM sessions WHERE id = ?1",
+                "SELECT id, title, created_at, updated_at, source, channel_key, model, response_format FROM sessions WHERE id = ?1",
                 rusqlite::params![id],
                 |row| {
                     Ok(Session {
@@ -330,6 +373,8 @@ impl SessionManager {
                         updated_at: row.get(3)?,
                         source: row.get(4)?,
                         channel_key: row.get(5)?,
+                        model: row.get(6)?,
+                        response_format: parse_response_format(row.get(7)?),
                     })
                 },
             )
@@ -389,6 +434,71 @@ impl SessionManager {
         .await
     }
 
+    /// List one page of sessions ordered by `updated_at` then `id`, newest first.
+    ///
+    /// `cursor` is the `next_cursor` of the previous page (`"{updated_at}|{id}"` of
+    /// its last session); the `id` tiebreaker keeps pages stable when sessions
+    /// share a timestamp.
+    pub async fn list_sessions_page(
+        &self,
+        include_internal: bool,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<SessionPage> {
+        let (after_ts, after_id) = match cursor {
+            Some(c) => {
+                let (ts, id) = c
+                    .split_once('|')
+                    .ok_or_else(|| ZeniiError::Validation(format!("invalid cursor: {c}")))?;
+                (Some(ts.to_string()), Some(id.to_string()))
+            }
+            None => (None, None),
+        };
+
+        db::with_db(&self.db, move |conn| {
+            let mut stmt = conn.prepare(
+                "SELECT s.id, s.title, s.created_at, s.updated_at, COUNT(m.id) as message_count, s.source, s.channel_key
+                 FROM sessions s
+                 LEFT JOIN messages m ON m.session_id = s.id
+                 WHERE (?1 OR s.source != 'delegation')
+                   AND (?2 IS NULL OR s.updated_at < ?2 OR (s.updated_at = ?2 AND s.id < ?3))
+                 GROUP BY s.id
+                 ORDER BY s.updated_at DESC, s.id DESC
+                 LIMIT ?4",
+            )?;
+
+            // Fetch one extra row to learn whether another page follows
+            let mut sessions = stmt
+                .query_map(
+                    rusqlite::params![include_internal, after_ts, after_id, limit as i64 + 1],
+                    |row| {
+                        Ok(SessionSummary {
+                            id: row.get(0)?,
+                            title: row.get(1)?,
+                            created_at: row.get(2)?,
+                            updated_at: row.get(3)?,
+                            message_count: row.get(4)?,
+                            source: row.get(5)?,
+                            channel_key: row.get(6)?,
+                        })
+                    },
+                )?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+
+            let next_cursor = if sessions.len() > limit {
+                sessions.truncate(limit);
+                sessions.last().map(|s| format!("{}|{}", s.updated_at, s.id))
+            } else {
+                None
+            };
+            Ok(SessionPage {
+                sessions,
+                next_cursor,
+            })
+        })
+        .await
+    }
+
     /// Delete sessions older than `max_age_days` and their associated messages/tool_calls.
     pub async fn cleanup_old_sessions(&self, max_age_days: u32) -> Result<usize> {
         let cutoff = chrono::Utc::now() - chrono::Duration::days(i64::from(max_age_days));
@@ -443,7 +553,7 @@ impl SessionManager {
             }
 
             conn.query_row(
-                "SELECT id, title, created_at, updated_at, source, channel_key FROM sessions WHERE id = ?1",
+                "SELECT id, title, created_at, updated_at, source, channel_key, model, response_format FROM sessions WHERE id = ?1",
                 rusqlite::params![update_id],
                 |row| {
                     Ok(Session {
@@ -453,6 +563,8 @@ impl SessionManager {
                         updated_at: row.get(3)?,
                         source: row.get(4)?,
                         channel_key: row.get(5)?,
+                        model: row.get(6)?,
+                        response_format: parse_response_format(row.get(7)?),
                     })
                 },
             )
@@ -461,6 +573,44 @@ impl SessionManager {
         .await
     }
 
+    /// Record (or clear, with `None`) the model override for a session.
+    pub async fn set_session_model(&self, id: &str, model: Option<&str>) -> Result<()> {
+        let id = id.to_string();
+        let model = model.map(String::from);
+        db::with_db(&self.db, move |conn| {
+            let rows_affected = conn.execute(
+                "UPDATE sessions SET model = ?1 WHERE id = ?2",
+                rusqlite::params![model, id],
+            )?;
+            if rows_affected == 0 {
+                return Err(ZeniiError::NotFound(format!("session not found: {id}")));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Record (or clear, with `None`) the JSON Schema replies must conform to.
+    pub async fn set_session_response_format(
+        &self,
+        id: &str,
+        response_format: Option<&serde_json::Value>,
+    ) -> Result<()> {
+        let id = id.to_string();
+        let response_format = response_format.map(|v| v.to_string());
+        db::with_db(&self.db, move |conn| {
+            let rows_affected = conn.execute(
+                "UPDATE sessions SET response_format = ?1 WHERE id = ?2",
+                rusqlite::params![response_format, id],
+            )?;
+            if rows_affected == 0 {
+                return Err(ZeniiError::NotFound(format!("session not found: {id}")));
+            }
+            Ok(())
+        })
+        .await
+    }
+
     pub async fn delete_session(&self, id: &str) -> Result<()> {
         let id = id.to_string();
 
@@ -549,6 +699,7 @@ impl SessionManager {
                     output,
                     success,
                     duration_ms,
+                    ..
                 } => {
                     let args = started
                         .remove(&evt.call_id)
@@ -672,7 +823,7 @@ impl SessionManager {
             .task_results
             .iter()
             .map(|t| {
-                (
+                Ok((
                     uuid::Uuid::new_v4().to_string(),
                     t.task_id.clone(),
                     t.description.clone(),
@@ -681,17 +832,35 @@ impl SessionManager {
                     t.usage.total_tokens as i64,
                     t.duration_ms as i64,
                     t.error.clone(),
-                )
+                    t.workspace_diff
+                        .as_ref()
+                        .map(|d| serde_json::to_string(&d.summary()))
+                        .transpose()?,
+                    t.workspace_diff
+                        .as_ref()
+                        .map(serde_json::to_string)
+                        .transpose()?,
+                ))
             })
-            .collect();
+            .collect::<std::result::Result<Vec<_>, serde_json::Error>>()?;
 
         db::with_db(&db, move |conn| {
-            for (id, agent_id, description, status, tool_uses, tokens_used, duration_ms, error) in
-                &tasks
+            for (
+                id,
+                agent_id,
+                description,
+                status,
+                tool_uses,
+                tokens_used,
+                duration_ms,
+                error,
+                workspace_changes,
+                workspace_diff,
+            ) in &tasks
             {
                 conn.execute(
-                    "INSERT INTO delegation_tasks (id, message_id, session_id, delegation_id, agent_id, description, status, tool_uses, tokens_used, duration_ms, error, total_duration_ms, total_tokens)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+                    "INSERT INTO delegation_tasks (id, message_id, session_id, delegation_id, agent_id, description, status, tool_uses, tokens_used, duration_ms, error, total_duration_ms, total_tokens, workspace_changes, workspace_diff)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
                     rusqlite::params![
                         id,
                         message_id,
@@ -705,7 +874,9 @@ impl SessionManager {
                         duration_ms,
                         error,
                         total_duration_ms,
-                        total_tokens
+                        total_tokens,
+                        workspace_changes,
+                        workspace_diff
                     ],
                 )?;
             }
@@ -721,7 +892,7 @@ impl SessionManager {
 
         db::with_db(&db, move |conn| {
             let mut stmt = conn.prepare(
-                "SELECT delegation_id, agent_id, description, status, tool_uses, tokens_used, duration_ms, error, total_duration_ms, total_tokens
+                "SELECT delegation_id, agent_id, description, status, tool_uses, tokens_used, duration_ms, error, total_duration_ms, total_tokens, workspace_changes
                  FROM delegation_tasks WHERE message_id = ?1 ORDER BY created_at ASC",
             )?;
             let rows: Vec<_> = stmt
@@ -737,6 +908,7 @@ impl SessionManager {
                         row.get::<_, Option<String>>(7)?,
                         row.get::<_, i64>(8)?,
                         row.get::<_, i64>(9)?,
+                        row.get::<_, Option<String>>(10)?,
                     ))
                 })?
                 .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -759,6 +931,7 @@ impl SessionManager {
                     tokens_used: r.5 as u64,
                     duration_ms: r.6 as u64,
                     error: r.7,
+                    workspace_changes: r.10.and_then(|j| serde_json::from_str(&j).ok()),
                 })
                 .collect();
 
@@ -772,6 +945,30 @@ impl SessionManager {
         .await
     }
 
+    /// Full workspace diff of one delegated task, if one was recorded.
+    pub async fn get_workspace_diff(
+        &self,
+        delegation_id: &str,
+        task_id: &str,
+    ) -> Result<Option<crate::ai::delegation::workspace_diff::WorkspaceDiff>> {
+        let delegation_id = delegation_id.to_string();
+        let task_id = task_id.to_string();
+        db::with_db(&self.db, move |conn| {
+            let result = conn.query_row(
+                "SELECT workspace_diff FROM delegation_tasks
+                 WHERE delegation_id = ?1 AND agent_id = ?2",
+                rusqlite::params![delegation_id, task_id],
+                |row| row.get::<_, Option<String>>(0),
+            );
+            match result {
+                Ok(Some(json)) => Ok(Some(serde_json::from_str(&json)?)),
+                Ok(None) | Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+                Err(e) => Err(ZeniiError::Sqlite(e)),
+            }
+        })
+        .await
+    }
+
     /// Get the conversation summary for a session.
     pub async fn get_summary(&self, session_id: &str) -> Result<Option<String>> {
         let session_id = session_id.to_string();
@@ -814,6 +1011,54 @@ impl SessionManager {
         .await
     }
 
+    /// Number of oldest messages already folded into the session summary.
+    pub async fn get_summary_through(&self, session_id: &str) -> Result<usize> {
+        let session_id = session_id.to_string();
+        db::with_db(&self.db, move |conn| {
+            let through: i64 = conn
+                .query_row(
+                    "SELECT summary_through FROM sessions WHERE id = ?1",
+                    rusqlite::params![session_id],
+                    |row| row.get(0),
+                )
+                .map_err(|e| match e {
+                    rusqlite::Error::QueryReturnedNoRows => {
+                        ZeniiError::NotFound(format!("session not found: {session_id}"))
+                    }
+                    other => ZeniiError::Sqlite(other),
+                })?;
+            Ok(through.max(0) as usize)
+        })
+        .await
+    }
+
+    /// Replace the session summary and record that it covers the oldest
+    /// `through` messages, so they are neither re-summarized nor replayed.
+    pub async fn set_summary_through(
+        &self,
+        session_id: &str,
+        summary: &str,
+        through: usize,
+    ) -> Result<()> {
+        let session_id = session_id.to_string();
+        let summary = summary.to_string();
+        db::with_db(&self.db, move |conn| {
+            let rows = conn
+                .execute(
+                    "UPDATE sessions SET summary = ?1, summary_through = ?2 WHERE id = ?3",
+                    rusqlite::params![summary, through as i64, session_id],
+                )
+                .map_err(ZeniiError::from)?;
+            if rows == 0 {
+                return Err(ZeniiError::NotFound(format!(
+                    "session not found: {session_id}"
+                )));
+            }
+            Ok(())
+        })
+        .await
+    }
+
     /// Get context-relevant info for a session: message count, last message time, summary.
     pub async fn get_context_info(
         &self,
@@ -991,6 +1236,49 @@ mod tests {
         assert_eq!(sessions[0].message_count, 0);
     }
 
+    // 1.2.5b — sessions page through a cursor without gaps or repeats
+    #[tokio::test]
+    async fn list_sessions_page_cursor() {
+        let (_dir, mgr) = setup().await;
+        let mut created = Vec::new();
+        for i in 0..5 {
+            created.push(mgr.create_session(&format!("S{i}")).await.unwrap().id);
+        }
+        // Two sessions sharing a timestamp are ordered by id
+        db::with_db(&mgr.db, |conn| {
+            conn.execute(
+                "UPDATE sessions SET updated_at = '2026-01-01T00:00:00+00:00'",
+                [],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = mgr
+                .list_sessions_page(true, 2, cursor.as_deref())
+                .await
+                .unwrap();
+            assert!(page.sessions.len() <= 2);
+            seen.extend(page.sessions.into_iter().map(|s| s.id));
+            match page.next_cursor {
+                Some(c) => cursor = Some(c),
+                None => break,
+            }
+        }
+        created.sort();
+        created.reverse();
+        assert_eq!(seen, created);
+
+        assert!(matches!(
+            mgr.list_sessions_page(true, 2, Some("bogus")).await,
+            Err(ZeniiError::Validation(_))
+        ));
+    }
+
     // 1.2.6 — update session
     #[tokio::test]
     async fn update_session() {
@@ -1003,6 +1291,45 @@ mod tests {
         assert_eq!(updated.created_at, created.created_at);
     }
 
+    // 1.2.6b — session model override round-trips
+    #[tokio::test]
+    async fn set_session_model() {
+        let (_dir, mgr) = setup().await;
+        let created = mgr.create_session("Routed").await.unwrap();
+        assert!(created.model.is_none());
+
+        mgr.set_session_model(&created.id, Some("openai:gpt-4o"))
+            .await
+            .unwrap();
+        let session = mgr.get_session(&created.id).await.unwrap();
+        assert_eq!(session.model.as_deref(), Some("openai:gpt-4o"));
+
+        mgr.set_session_model(&created.id, None).await.unwrap();
+        assert!(mgr.get_session(&created.id).await.unwrap().model.is_none());
+        assert!(mgr.set_session_model("missing", None).await.is_err());
+    }
+
+    // 1.2.6c — session response_format round-trips
+    #[tokio::test]
+    async fn set_session_response_format() {
+        let (_dir, mgr) = setup().await;
+        let created = mgr.create_session("Structured").await.unwrap();
+        assert!(created.response_format.is_none());
+
+        let schema = serde_json::json!({"type": "object", "required": ["answer"]});
+        mgr.set_session_response_format(&created.id, Some(&schema))
+            .await
+            .unwrap();
+        let session = mgr.get_session(&created.id).await.unwrap();
+        assert_eq!(session.response_format, Some(schema));
+
+        mgr.set_session_response_format(&created.id, None)
+            .await
+            .unwrap();
+        let session = mgr.get_session(&created.id).await.unwrap();
+        assert!(session.response_format.is_none());
+    }
+
     // 1.2.7 — delete session
     #[tokio::test]
     async fn delete_session() {
@@ -1108,6 +1435,7 @@ mod tests {
                     output: "results found".into(),
                     success: true,
                     duration_ms: 150,
+                    full_output_id: None,
                 },
             },
         ];
@@ -1148,6 +1476,7 @@ mod tests {
                     output: "file contents".into(),
                     success: true,
                     duration_ms: 5,
+                    full_output_id: None,
                 },
             },
             ToolCallEvent {
@@ -1164,6 +1493,7 @@ mod tests {
                     output: "error".into(),
                     success: false,
                     duration_ms: 10,
+                    full_output_id: None,
                 },
             },
         ];
@@ -1218,6 +1548,7 @@ mod tests {
                     output: "ok".into(),
                     success: true,
                     duration_ms: 1,
+                    full_output_id: None,
                 },
             },
         ];
@@ -1602,6 +1933,7 @@ mod tests {
                     output: "ok".into(),
                     success: true,
                     duration_ms: 5,
+                    full_output_id: None,
                 },
             },
         ];
@@ -1651,6 +1983,7 @@ mod tests {
                     tool_uses: 3,
                     description: "Research topic A".into(),
                     hint: None,
+                    workspace_diff: None,
                 },
                 crate::ai::delegation::task::TaskResult {
                     task_id: "agent-2".into(),
@@ -1668,6 +2001,7 @@ mod tests {
                     tool_uses: 5,
                     description: "Research topic B".into(),
                     hint: None,
+                    workspace_diff: None,
                 },
             ],
             aggregated_response: "combined".into(),
@@ -1736,6 +2070,7 @@ mod tests {
                     tool_uses: 1,
                     description: "Successful task".into(),
                     hint: None,
+                    workspace_diff: None,
                 },
                 crate::ai::delegation::task::TaskResult {
                     task_id: "agent-fail".into(),
@@ -1748,6 +2083,7 @@ mod tests {
                     tool_uses: 0,
                     description: "Failed task".into(),
                     hint: None,
+                    workspace_diff: None,
                 },
             ],
             aggregated_response: "partial".into(),
@@ -1769,4 +2105,74 @@ mod tests {
             Some("connection refused")
         );
     }
+
+    // DEL.4 — workspace diffs are stored and served per task
+    #[tokio::test]
+    async fn store_delegation_workspace_diff() {
+        use crate::ai::delegation::workspace_diff::{
+            FileChange, FileChangeKind, WorkspaceDiff, WorkspaceDiffSummary,
+        };
+
+        let (_dir, mgr) = setup().await;
+        let session = mgr.create_session("Chat").await.unwrap();
+        let msg = mgr
+            .append_message(&session.id, "assistant", "done")
+            .await
+            .unwrap();
+
+        let diff = WorkspaceDiff {
+            files: vec![FileChange {
+                path: "notes.md".into(),
+                kind: FileChangeKind::Added,
+                binary: false,
+                old_size: None,
+                new_size: Some(3),
+                diff: Some("--- a/notes.md\n+++ b/notes.md\n@@ -0,0 +1 @@\n+hi\n".into()),
+                diff_truncated: false,
+            }],
+            truncated: false,
+        };
+        let delegation_result = crate::ai::delegation::task::DelegationResult {
+            id: "del-3".into(),
+            task_results: vec![crate::ai::delegation::task::TaskResult {
+                task_id: "writer".into(),
+                status: crate::ai::delegation::task::TaskStatus::Completed,
+                output: "wrote notes".into(),
+                usage: crate::ai::agent::TokenUsage::default(),
+                duration_ms: 100,
+                error: None,
+                session_id: session.id.clone(),
+                tool_uses: 1,
+                description: "Write notes".into(),
+                hint: None,
+                workspace_diff: Some(diff.clone()),
+            }],
+            aggregated_response: "done".into(),
+            total_usage: crate::ai::agent::TokenUsage::default(),
+            total_duration_ms: 100,
+        };
+        mgr.store_delegation(&msg.id, &session.id, &delegation_result)
+            .await
+            .unwrap();
+
+        let record = mgr.get_delegation(&msg.id).await.unwrap().unwrap();
+        assert_eq!(
+            record.agents[0].workspace_changes,
+            Some(WorkspaceDiffSummary {
+                added: 1,
+                modified: 0,
+                deleted: 0
+            })
+        );
+        assert_eq!(
+            mgr.get_workspace_diff("del-3", "writer").await.unwrap(),
+            Some(diff)
+        );
+        assert!(
+            mgr.get_workspace_diff("del-3", "other")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
 }
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+
+use crate::{Result, ZeniiError};
+
+/// Cancellation flags for in-flight streaming chats, keyed by the
+/// client-supplied `stream_id`.
+///
+/// The WebSocket chat loop registers its stream and waits on the returned
+/// guard alongside the agent; `cancel` wakes it from any connection.
+#[derive(Default)]
+pub struct StreamCancelMap {
+    streams: Mutex<HashMap<String, Arc<Notify>>>,
+}
+
+/// Registration of one stream. Dropping it unregisters the stream.
+pub struct StreamGuard {
+    map: Arc<StreamCancelMap>,
+    id: String,
+    notify: Arc<Notify>,
+}
+
+impl StreamCancelMap {
+    /// Register `id`. Fails if a stream with the same id is still running.
+    pub fn register(self: &Arc<Self>, id: &str) -> Result<StreamGuard> {
+        let mut streams = self.streams.lock();
+        if streams.contains_key(id) {
+            return Err(ZeniiError::Validation(format!(
+                "stream '{id}' is already active"
+            )));
+        }
+        let notify = Arc::new(Notify::new());
+        streams.insert(id.to_string(), notify.clone());
+        Ok(StreamGuard {
+            map: self.clone(),
+            id: id.to_string(),
+            notify,
+        })
+    }
+
+    /// Request cancellation of `id`. Returns `false` if no such stream is running.
+    pub fn cancel(&self, id: &str) -> bool {
+        match self.streams.lock().get(id) {
+            Some(notify) => {
+                // notify_one keeps a permit, so a cancel that lands between
+                // two waits is not lost.
+                notify.notify_one();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_active(&self, id: &str) -> bool {
+        self.streams.lock().contains_key(id)
+    }
+}
+
+impl StreamGuard {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Resolves once the stream has been cancelled.
+    pub async fn cancelled(&self) {
+        self.notify.notified().await;
+    }
+}
+
+impl Drop for StreamGuard {
+    fn drop(&mut self) {
+        self.map.streams.lock().remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    // SC.1 — Cancelling a registered stream wakes its guard, even if the cancel comes first
+    #[tokio::test]
+    async fn cancel_wakes_guard() {
+        let map = Arc::new(StreamCancelMap::default());
+        let guard = map.register("s1").unwrap();
+
+        assert!(map.cancel("s1"));
+        tokio::time::timeout(Duration::from_secs(1), guard.cancelled())
+            .await
+            .expect("guard should be woken");
+    }
+
+    // SC.2 — Ids are unique while active and released on drop
+    #[tokio::test]
+    async fn guard_releases_id_on_drop() {
+        let map = Arc::new(StreamCancelMap::default());
+        let guard = map.register("s1").unwrap();
+        assert!(map.register("s1").is_err());
+
+        drop(guard);
+        assert!(!map.is_active("s1"));
+        assert!(!map.cancel("s1"));
+        assert!(map.register("s1").is_ok());
+    }
+}
@@ -0,0 +1,399 @@
+use rig::message::Message;
+use serde_json::Value;
+use tracing::info;
+
+use crate::{Result, ZeniiError};
+
+use super::agent::ZeniiAgent;
+use super::reasoning::{ChatResult, ReasoningEngine};
+
+/// Check that `schema` can be used as a `response_format`: a JSON object whose
+/// `type` (if any) is a known JSON Schema type.
+pub fn check_schema(schema: &Value) -> Result<()> {
+    let Some(obj) = schema.as_object() else {
+        return Err(ZeniiError::Validation(
+            "response_format must be a JSON Schema object".into(),
+        ));
+    };
+    let known = |t: &Value| {
+        matches!(
+            t.as_str(),
+            Some("object" | "array" | "string" | "number" | "integer" | "boolean" | "null")
+        )
+    };
+    match obj.get("type") {
+        None => Ok(()),
+        Some(Value::Array(types)) if types.iter().all(known) => Ok(()),
+        Some(t) if known(t) => Ok(()),
+        Some(t) => Err(ZeniiError::Validation(format!(
+            "response_format has unsupported type {t}"
+        ))),
+    }
+}
+
+/// Validate `value` against `schema`.
+///
+/// Supports the keywords models are usually asked for: `type`, `enum`, `const`,
+/// `properties`, `required`, `additionalProperties: false`, `items`,
+/// `minItems`/`maxItems`, `minLength`/`maxLength` and `minimum`/`maximum`.
+/// Other keywords are ignored. The error names the offending JSON pointer.
+pub fn validate(schema: &Value, value: &Value) -> Result<()> {
+    validate_at(schema, value, "")
+}
+
+fn validate_at(schema: &Value, value: &Value, path: &str) -> Result<()> {
+    let Some(schema) = schema.as_object() else {
+        return Ok(());
+    };
+    let at = if path.is_empty() { "/" } else { path };
+
+    if let Some(expected) = schema.get("type") {
+        let matches = |t: &Value| match t.as_str() {
+            Some("object") => value.is_object(),
+            Some("array") => value.is_array(),
+            Some("string") => value.is_string(),
+            Some("number") => value.is_number(),
+            Some("integer") => value.is_i64() || value.is_u64(),
+            Some("boolean") => value.is_boolean(),
+            Some("null") => value.is_null(),
+            _ => true,
+        };
+        let ok = match expected {
+            Value::Array(types) => types.iter().any(matches),
+            t => matches(t),
+        };
+        if !ok {
+            return Err(ZeniiError::Validation(format!(
+                "{at}: expected type {expected}, got {value}"
+            )));
+        }
+    }
+    if let Some(Value::Array(options)) = schema.get("enum")
+        && !options.contains(value)
+    {
+        return Err(ZeniiError::Validation(format!(
+            "{at}: {value} is not one of {}",
+            Value::from(options.clone())
+        )));
+    }
+    if let Some(expected) = schema.get("const")
+        && expected != value
+    {
+        return Err(ZeniiError::Validation(format!(
+            "{at}: expected {expected}, got {value}"
+        )));
+    }
+
+    match value {
+        Value::Object(obj) => {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if !obj.contains_key(key) {
+                        return Err(ZeniiError::Validation(format!(
+                            "{at}: missing required property \"{key}\""
+                        )));
+                    }
+                }
+            }
+            for (key, item) in obj {
+                let child = format!("{path}/{key}");
+                match properties.and_then(|p| p.get(key)) {
+                    Some(sub) => validate_at(sub, item, &child)?,
+                    None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                        return Err(ZeniiError::Validation(format!(
+                            "{at}: unexpected property \"{key}\""
+                        )));
+                    }
+                    None => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            let len = items.len() as u64;
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+                && len < min
+            {
+                return Err(ZeniiError::Validation(format!(
+                    "{at}: expected at least {min} items, got {len}"
+                )));
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+                && len > max
+            {
+                return Err(ZeniiError::Validation(format!(
+                    "{at}: expected at most {max} items, got {len}"
+                )));
+            }
+            if let Some(sub) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    validate_at(sub, item, &format!("{path}/{i}"))?;
+                }
+            }
+        }
+        Value::String(s) => {
+            let len = s.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+                && len < min
+            {
+                return Err(ZeniiError::Validation(format!(
+                    "{at}: string shorter than {min} characters"
+                )));
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+                && len > max
+            {
+                return Err(ZeniiError::Validation(format!(
+                    "{at}: string longer than {max} characters"
+                )));
+            }
+        }
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or_default();
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+                && n < min
+            {
+                return Err(ZeniiError::Validation(format!(
+                    "{at}: {n} is less than the minimum {min}"
+                )));
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+                && n > max
+            {
+                return Err(ZeniiError::Validation(format!(
+                    "{at}: {n} is greater than the maximum {max}"
+                )));
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Parse the JSON value in a model reply, tolerating a Markdown code fence or
+/// prose around it.
+pub fn extract_json(text: &str) -> Result<Value> {
+    let trimmed = text.trim();
+    if let Ok(value) = serde_json::from_str(trimmed) {
+        return Ok(value);
+    }
+    let unfenced = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .and_then(|s| s.trim_end().strip_suffix("```"))
+        .map(str::trim);
+    if let Some(inner) = unfenced
+        && let Ok(value) = serde_json::from_str(inner)
+    {
+        return Ok(value);
+    }
+    let start = trimmed.find(['{', '[']);
+    let end = trimmed.rfind(['}', ']']);
+    match (start, end) {
+        (Some(start), Some(end)) if start < end => serde_json::from_str(&trimmed[start..=end])
+            .map_err(|e| ZeniiError::Validation(format!("invalid JSON: {e}"))),
+        _ => Err(ZeniiError::Validation(
+            "the reply contains no JSON value".into(),
+        )),
+    }
+}
+
+/// Instruction appended to the prompt so the model replies with JSON only.
+pub fn schema_instruction(schema: &Value) -> String {
+    format!(
+        "\n\nRespond with a single JSON value that conforms to this JSON Schema, \
+         and nothing else (no prose, no code fence):\n{schema}"
+    )
+}
+
+fn parse_and_validate(text: &str, schema: &Value) -> Result<Value> {
+    let value = extract_json(text)?;
+    validate(schema, &value)?;
+    Ok(value)
+}
+
+/// Run a chat turn whose final answer must be JSON matching `schema`.
+///
+/// The schema is added to the prompt and the reply is validated. If it does not
+/// match, the validation error is fed back to the model once; a second mismatch
+/// fails with [`ZeniiError::Agent`]. On success `response` is the compact JSON.
+pub async fn chat_structured(
+    engine: &ReasoningEngine,
+    agent: &ZeniiAgent,
+    prompt: &str,
+    history: Vec<Message>,
+    schema: &Value,
+) -> Result<ChatResult> {
+    let instructed = format!("{prompt}{}", schema_instruction(schema));
+    let mut result = engine.chat(agent, &instructed, history.clone()).await?;
+    let error = match parse_and_validate(&result.response, schema) {
+        Ok(value) => {
+            result.response = value.to_string();
+            return Ok(result);
+        }
+        Err(e) => e,
+    };
+
+    info!("Structured reply did not match response_format ({error}), asking for a repair");
+    let mut repair_history = history;
+    repair_history.push(Message::user(instructed));
+    repair_history.push(Message::assistant(&result.response));
+    let repair_prompt = format!(
+        "Your reply did not match the JSON Schema: {error}\n\
+         Reply again with only the corrected JSON value."
+    );
+    let repaired = agent.chat(&repair_prompt, repair_history).await?;
+    result.usage += repaired.usage;
+    let value = parse_and_validate(&repaired.output, schema).map_err(|e| {
+        ZeniiError::Agent(format!(
+            "structured output did not match response_format after a repair attempt: {e}"
+        ))
+    })?;
+    result.response = value.to_string();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::ai::test_support::{MockReply, completion, openai_mock};
+    use crate::config::AppConfig;
+    use crate::credential::InMemoryCredentialStore;
+
+    fn person() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "minLength": 1},
+                "age": {"type": "integer", "minimum": 0},
+                "tags": {"type": "array", "items": {"enum": ["a", "b"]}, "maxItems": 2}
+            },
+            "required": ["name", "age"],
+            "additionalProperties": false
+        })
+    }
+
+    // STRUCT.1 — Conforming values pass; each violated keyword is reported with its path
+    #[test]
+    fn validate_reports_first_violation() {
+        let schema = person();
+        assert!(validate(&schema, &json!({"name": "Ada", "age": 36, "tags": ["a"]})).is_ok());
+
+        let cases = [
+            (json!([]), "/: expected type"),
+            (json!({"name": "Ada"}), "missing required property \"age\""),
+            (json!({"name": "Ada", "age": 1.5}), "/age: expected type"),
+            (json!({"name": "", "age": 1}), "/name: string shorter"),
+            (json!({"name": "A", "age": -1}), "/age: -1 is less than"),
+            (json!({"name": "A", "age": 1, "tags": ["c"]}), "/tags/0:"),
+            (
+                json!({"name": "A", "age": 1, "tags": ["a", "a", "b"]}),
+                "at most 2 items",
+            ),
+            (
+                json!({"name": "A", "age": 1, "x": 1}),
+                "unexpected property \"x\"",
+            ),
+        ];
+        for (value, expected) in cases {
+            let err = validate(&schema, &value).unwrap_err();
+            assert!(err.to_string().contains(expected), "{value}: {err}");
+        }
+    }
+
+    // STRUCT.2 — JSON is found inside fences and surrounding prose
+    #[test]
+    fn extract_json_tolerates_fences_and_prose() {
+        assert_eq!(extract_json(" {\"a\":1} ").unwrap(), json!({"a": 1}));
+        assert_eq!(
+            extract_json("```json\n{\"a\":1}\n```").unwrap(),
+            json!({"a": 1})
+        );
+        assert_eq!(
+            extract_json("Here you go: [1, 2] done").unwrap(),
+            json!([1, 2])
+        );
+        assert!(extract_json("no json here").is_err());
+    }
+
+    // STRUCT.3 — Only JSON Schema objects are accepted as response_format
+    #[test]
+    fn check_schema_rejects_non_objects() {
+        assert!(check_schema(&person()).is_ok());
+        assert!(check_schema(&json!({"type": ["string", "null"]})).is_ok());
+        assert!(check_schema(&json!("object")).is_err());
+        assert!(check_schema(&json!({"type": "map"})).is_err());
+    }
+
+    /// OpenAI-compatible mock returning `replies` in order (the last repeats),
+    /// recording each request body.
+    async fn scripted_server(
+        replies: &'static [&'static str],
+    ) -> (String, Arc<std::sync::Mutex<Vec<String>>>) {
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        let url = openai_mock(move |index, request| {
+            seen.lock().unwrap().push(request.to_string());
+            let mut body = completion(replies[index.min(replies.len() - 1)]);
+            body["usage"] = json!({"prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7});
+            MockReply::json("200 OK", body)
+        })
+        .await;
+        (url, requests)
+    }
+
+    async fn structured(base_url: &str) -> Result<ChatResult> {
+        let agent = ZeniiAgent::from_provider(
+            "mock",
+            base_url,
+            "json",
+            false,
+            &Default::default(),
+            &InMemoryCredentialStore::new(),
+            &[],
+            &AppConfig::default(),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        let schema = json!({
+            "type": "object",
+            "properties": {"answer": {"type": "integer"}},
+            "required": ["answer"]
+        });
+        chat_structured(&ReasoningEngine::new(3), &agent, "6 x 7?", vec![], &schema).await
+    }
+
+    // STRUCT.4 — An invalid reply gets one repair turn carrying the schema error
+    #[tokio::test]
+    async fn chat_structured_repairs_invalid_reply() {
+        let (url, requests) =
+            scripted_server(&["The answer is 42.", "```json\n{\"answer\": 42}\n```"]).await;
+        let result = structured(&url).await.unwrap();
+        assert_eq!(result.response, r#"{"answer":42}"#);
+        assert_eq!(result.usage.total_tokens, 14);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains("JSON Schema"));
+        assert!(requests[1].contains("did not match the JSON Schema"));
+    }
+
+    // STRUCT.5 — A reply that is still invalid after the repair is an error
+    #[tokio::test]
+    async fn chat_structured_fails_after_one_repair() {
+        let (url, requests) = scripted_server(&["{\"answer\": \"forty-two\"}"]).await;
+        let err = structured(&url).await.unwrap_err();
+        assert!(
+            matches!(err, ZeniiError::Agent(ref m) if m.contains("/answer: expected type")),
+            "{err}"
+        );
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+}
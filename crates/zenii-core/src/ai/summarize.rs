@@ -0,0 +1,273 @@
+use std::ops::Range;
+
+use tracing::{debug, info};
+
+use crate::gateway::state::AppState;
+use crate::{Result, ZeniiError};
+
+use super::agent::ZeniiAgent;
+use super::session::Message;
+
+const SUMMARY_PREAMBLE: &str = "You maintain a running summary of a chat conversation. \
+     Output only the updated summary, nothing else.";
+
+/// Per-message character cap in the summarization transcript.
+const MAX_MESSAGE_CHARS: usize = 2_000;
+
+/// Decide which messages to fold into the summary.
+///
+/// `through` is the number of oldest messages already summarized. Once more than
+/// `window` messages remain unsummarized, everything except the newest
+/// `keep_recent` is folded. Returns `None` while the history still fits.
+pub fn fold_range(
+    total: usize,
+    through: usize,
+    window: usize,
+    keep_recent: usize,
+) -> Option<Range<usize>> {
+    let through = through.min(total);
+    if total - through <= window {
+        return None;
+    }
+    let end = total.saturating_sub(keep_recent);
+    (end > through).then_some(through..end)
+}
+
+/// Build the summarization prompt from the previous summary (if any) and the
+/// messages being folded in. Long messages are truncated.
+pub fn build_summary_prompt(previous: Option<&str>, messages: &[Message]) -> String {
+    let mut transcript = String::new();
+    for msg in messages {
+        let content: String = msg.content.chars().take(MAX_MESSAGE_CHARS).collect();
+        let ellipsis = if content.len() < msg.content.len() {
+            " …"
+        } else {
+            ""
+        };
+        transcript.push_str(&format!("{}: {content}{ellipsis}\n", msg.role));
+    }
+
+    let previous = match previous.map(str::trim).filter(|s| !s.is_empty()) {
+        Some(summary) => format!("Existing summary:\n{summary}\n\n"),
+        None => String::new(),
+    };
+
+    format!(
+        "{previous}Update the summary with these older messages from the conversation:\n\n\
+         {transcript}\n\
+         Rules:\n\
+         - Keep names, decisions, open questions and anything the user asked to remember\n\
+         - Drop greetings and small talk\n\
+         - Write at most a few short paragraphs in the third person"
+    )
+}
+
+/// Fold the oldest unsummarized messages of a session into its summary so the
+/// verbatim history stays within `context_max_history_messages`.
+///
+/// The newest `channel_summary_keep_recent` messages are never summarized, and
+/// `summary_through` records which messages the summary already covers so they
+/// are not summarized twice. Returns `true` if the summary was updated. On error
+/// the session is left unchanged and callers fall back to plain windowing.
+pub async fn summarize_and_compact(state: &AppState, session_id: &str) -> Result<bool> {
+    let config = state.config.load_full();
+    if !config.channel_summarize_enabled {
+        return Ok(false);
+    }
+
+    let messages = state.session_manager.get_messages(session_id).await?;
+    let (previous, through) = (
+        state.session_manager.get_summary(session_id).await?,
+        state
+            .session_manager
+            .get_summary_through(session_id)
+            .await?,
+    );
+    let Some(range) = fold_range(
+        messages.len(),
+        through,
+        config.context_max_history_messages,
+        config.channel_summary_keep_recent,
+    ) else {
+        return Ok(false);
+    };
+
+    let provider_id = &config.context_summary_provider_id;
+    let model_id = &config.context_summary_model_id;
+    let provider = state.provider_registry.get_provider(provider_id).await?;
+    let agent = ZeniiAgent::from_provider(
+        provider_id,
+        &provider.provider.base_url,
+        model_id,
+        provider.provider.requires_api_key,
+        &provider.provider.extras,
+        state.credentials.as_ref(),
+        &[],
+        &config,
+        Some(SUMMARY_PREAMBLE),
+        None,
+        None,
+    )
+    .await?;
+
+    debug!(
+        "Summarizing messages {}..{} of session {session_id}",
+        range.start, range.end
+    );
+    let prompt = build_summary_prompt(previous.as_deref(), &messages[range.clone()]);
+    let summary = agent.prompt(&prompt).await?.output;
+    let summary = summary.trim();
+    if summary.is_empty() {
+        return Err(ZeniiError::Context(
+            "summary model returned an empty summary".into(),
+        ));
+    }
+
+    state
+        .session_manager
+        .set_summary_through(session_id, summary, range.end)
+        .await?;
+    info!(
+        "Folded {} messages into summary of session {session_id}",
+        range.len()
+    );
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    fn msg(role: &str, content: &str) -> Message {
+        Message {
+            id: String::new(),
+            session_id: "s1".into(),
+            role: role.into(),
+            content: content.into(),
+            created_at: String::new(),
+        }
+    }
+
+    // SUM.1 — Nothing is folded until the unsummarized tail outgrows the window
+    #[test]
+    fn fold_range_respects_window_and_tail() {
+        assert_eq!(fold_range(20, 0, 20, 10), None);
+        assert_eq!(fold_range(21, 0, 20, 10), Some(0..11));
+        assert_eq!(fold_range(25, 11, 20, 10), None);
+        assert_eq!(fold_range(32, 11, 20, 10), Some(11..22));
+        // A stale `through` beyond the history never panics
+        assert_eq!(fold_range(5, 40, 4, 2), None);
+    }
+
+    // SUM.2 — Prompt carries the previous summary and truncates long messages
+    #[test]
+    fn build_summary_prompt_includes_previous_and_truncates() {
+        let long = "x".repeat(MAX_MESSAGE_CHARS + 50);
+        let prompt = build_summary_prompt(
+            Some("User is planning a trip to Lisbon."),
+            &[msg("user", "Book the hotel"), msg("assistant", &long)],
+        );
+        assert!(prompt.starts_with("Existing summary:\nUser is planning a trip to Lisbon."));
+        assert!(prompt.contains("user: Book the hotel\n"));
+        assert!(prompt.contains(&format!("assistant: {} …", "x".repeat(MAX_MESSAGE_CHARS))));
+        assert!(!build_summary_prompt(None, &[]).contains("Existing summary"));
+    }
+
+    async fn seed_session(state: &AppState, count: usize) -> String {
+        let session = state.session_manager.create_session("chat").await.unwrap();
+        for i in 0..count {
+            let role = if i % 2 == 0 { "user" } else { "assistant" };
+            state
+                .session_manager
+                .append_message(&session.id, role, &format!("message {i}"))
+                .await
+                .unwrap();
+        }
+        session.id
+    }
+
+    fn use_summary_model(state: &AppState, provider_id: &str) {
+        let mut config = (*state.config.load_full()).clone();
+        config.context_summary_provider_id = provider_id.into();
+        config.context_summary_model_id = "mock-model".into();
+        config.context_max_history_messages = 6;
+        config.channel_summary_keep_recent = 2;
+        state.config.store(Arc::new(config));
+    }
+
+    // SUM.3 — Older messages are folded into the summary; only the tail is replayed
+    #[tokio::test]
+    async fn summarize_and_compact_folds_old_messages() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+
+        let url = crate::ai::test_support::openai_text_mock("The user counted to five.").await;
+        state
+            .provider_registry
+            .add_user_provider(
+                "mock",
+                "Mock",
+                &url,
+                false,
+                &[("mock-model".into(), "Mock".into(), true)],
+            )
+            .await
+            .unwrap();
+        use_summary_model(&state, "mock");
+
+        // Within the window: nothing to do
+        let short = seed_session(&state, 6).await;
+        assert!(!summarize_and_compact(&state, &short).await.unwrap());
+
+        let sid = seed_session(&state, 8).await;
+        assert!(summarize_and_compact(&state, &sid).await.unwrap());
+        assert_eq!(
+            state
+                .session_manager
+                .get_summary(&sid)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("The user counted to five.")
+        );
+        assert_eq!(
+            state
+                .session_manager
+                .get_summary_through(&sid)
+                .await
+                .unwrap(),
+            6
+        );
+
+        // The summarized messages are no longer replayed as history
+        let (history, _, _) = state
+            .context_builder
+            .build_parts(Some(&sid), "next")
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 2);
+
+        // Already-summarized messages are not folded again
+        assert!(!summarize_and_compact(&state, &sid).await.unwrap());
+    }
+
+    // SUM.4 — A failing summary model leaves the session untouched
+    #[tokio::test]
+    async fn summarize_and_compact_failure_leaves_session_unchanged() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        use_summary_model(&state, "missing-provider");
+
+        let sid = seed_session(&state, 8).await;
+        assert!(summarize_and_compact(&state, &sid).await.is_err());
+        assert_eq!(state.session_manager.get_summary(&sid).await.unwrap(), None);
+        assert_eq!(
+            state
+                .session_manager
+                .get_summary_through(&sid)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+}
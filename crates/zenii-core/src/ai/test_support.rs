@@ -0,0 +1,163 @@
+//! OpenAI-compatible provider mock shared by tests that drive a real agent.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::{Value, json};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// How the mock answers one request.
+pub(crate) enum MockReply {
+    /// Respond with this status line, content type and body after `delay`.
+    Http {
+        status: &'static str,
+        content_type: &'static str,
+        body: String,
+        delay: Duration,
+    },
+    /// Keep the connection open without answering.
+    Hang,
+    /// Close the connection without answering.
+    Close,
+}
+
+impl MockReply {
+    /// `200 OK` with a `chat.completion` whose message is `content`.
+    pub(crate) fn text(content: &str) -> Self {
+        Self::json("200 OK", completion(content))
+    }
+
+    /// A JSON response with the given status line.
+    pub(crate) fn json(status: &'static str, body: Value) -> Self {
+        Self::Http {
+            status,
+            content_type: "application/json",
+            body: body.to_string(),
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// An error response carrying `{"error": {"message": ...}}`.
+    pub(crate) fn error(status: &'static str, message: &str) -> Self {
+        Self::json(status, json!({"error": {"message": message}}))
+    }
+
+    /// `200 OK` server-sent events, one `data:` frame per chunk, then `[DONE]`.
+    pub(crate) fn sse(chunks: &[Value]) -> Self {
+        let body = chunks
+            .iter()
+            .map(|c| format!("data: {c}\n\n"))
+            .collect::<String>()
+            + "data: [DONE]\n\n";
+        Self::Http {
+            status: "200 OK",
+            content_type: "text/event-stream",
+            body,
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Wait `delay` before responding.
+    pub(crate) fn after(self, delay: Duration) -> Self {
+        match self {
+            Self::Http {
+                status,
+                content_type,
+                body,
+                ..
+            } => Self::Http {
+                status,
+                content_type,
+                body,
+                delay,
+            },
+            other => other,
+        }
+    }
+}
+
+/// A `chat.completion` body answering `content`, using 1 prompt and 1 completion token.
+pub(crate) fn completion(content: &str) -> Value {
+    json!({
+        "id": "chatcmpl-1",
+        "object": "chat.completion",
+        "created": 0,
+        "model": "mock-model",
+        "choices": [{
+            "index": 0,
+            "message": {"role": "assistant", "content": content},
+            "finish_reason": "stop"
+        }],
+        "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}
+    })
+}
+
+/// Serve OpenAI-compatible responses on a local port. `reply` is called with
+/// the zero-based request number and the raw request (headers and body).
+/// Returns the base URL, ending in `/v1`.
+pub(crate) async fn openai_mock<F>(reply: F) -> String
+where
+    F: Fn(usize, &str) -> MockReply + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let reply = Arc::new(reply);
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        let mut index = 0;
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let request = read_request(&mut stream).await;
+            match reply(index, &request) {
+                MockReply::Http {
+                    status,
+                    content_type,
+                    body,
+                    delay,
+                } => {
+                    tokio::time::sleep(delay).await;
+                    let response = format!(
+                        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                }
+                MockReply::Hang => held.push(stream),
+                MockReply::Close => {}
+            }
+            index += 1;
+        }
+    });
+    format!("http://{addr}/v1")
+}
+
+/// Mock answering every request with `content`.
+pub(crate) async fn openai_text_mock(content: &'static str) -> String {
+    openai_mock(move |_, _| MockReply::text(content)).await
+}
+
+/// Read one request: headers, then as much body as `Content-Length` announces.
+async fn read_request(stream: &mut TcpStream) -> String {
+    let mut data = Vec::new();
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = match stream.read(&mut buf).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => n,
+        };
+        data.extend_from_slice(&buf[..n]);
+        let text = String::from_utf8_lossy(&data);
+        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+            let length = head
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            if body.len() >= length {
+                break;
+            }
+        }
+    }
+    String::from_utf8_lossy(&data).into_owned()
+}
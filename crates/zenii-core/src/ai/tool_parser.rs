@@ -0,0 +1,444 @@
+//! Deterministic parsing of tool calls in model output.
+//!
+//! Native tool calls are decoded by the provider client, but some models
+//! write calls into their text instead: a raw OpenAI `tool_calls` payload,
+//! Anthropic `tool_use` content blocks, or a `<tool>{json}</tool>` tag. The
+//! [`ToolCallParser`] tries each registered [`ToolCallFormat`] in order and
+//! reports every call it finds — including the ones it could not decode — so
+//! the caller can hand a [`ToolParseError`] back to the model instead of
+//! dropping the call.
+
+use serde_json::Value;
+
+/// A tool call decoded from model output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedToolCall {
+    /// Provider call id, when the format carries one.
+    pub id: Option<String>,
+    pub name: String,
+    /// Always a JSON object.
+    pub arguments: Value,
+}
+
+/// A tool call that was recognised but could not be decoded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolParseError {
+    /// Name of the format that recognised the call.
+    pub format: &'static str,
+    /// Tool name, if it could be read before decoding failed.
+    pub tool_name: Option<String>,
+    pub message: String,
+}
+
+impl ToolParseError {
+    fn new(format: &'static str, tool_name: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            format,
+            tool_name: tool_name.map(str::to_string),
+            message: message.into(),
+        }
+    }
+
+    /// Tool-error text to return to the model so it can resend the call.
+    pub fn to_tool_message(&self) -> String {
+        let target = match &self.tool_name {
+            Some(name) => format!("tool '{name}'"),
+            None => "a tool call".to_string(),
+        };
+        format!(
+            "Tool call error: could not parse {target}: {}. \
+             Resend the call with its arguments as a single valid JSON object.",
+            self.message
+        )
+    }
+}
+
+impl std::fmt::Display for ToolParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.tool_name {
+            Some(name) => write!(f, "{} call to '{name}': {}", self.format, self.message),
+            None => write!(f, "{} call: {}", self.format, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ToolParseError {}
+
+pub type ParseOutcome = Result<ParsedToolCall, ToolParseError>;
+
+/// One way a model can write tool calls into its text.
+pub trait ToolCallFormat: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// `None` when `text` is not in this format; otherwise one outcome per
+    /// call found, in order.
+    fn parse(&self, text: &str) -> Option<Vec<ParseOutcome>>;
+}
+
+/// Decode a tool's raw arguments string into a JSON object.
+///
+/// Empty input means no arguments, and a JSON string holding an object
+/// (double-encoded arguments) is unwrapped.
+pub fn parse_arguments(
+    format: &'static str,
+    tool_name: &str,
+    raw: &str,
+) -> Result<Value, ToolParseError> {
+    if raw.trim().is_empty() {
+        return Ok(Value::Object(Default::default()));
+    }
+    let value: Value = serde_json::from_str(raw).map_err(|e| {
+        ToolParseError::new(
+            format,
+            Some(tool_name),
+            format!("invalid arguments JSON ({e})"),
+        )
+    })?;
+    arguments_object(format, tool_name, value)
+}
+
+fn arguments_object(
+    format: &'static str,
+    tool_name: &str,
+    value: Value,
+) -> Result<Value, ToolParseError> {
+    match value {
+        Value::Object(_) => Ok(value),
+        Value::Null => Ok(Value::Object(Default::default())),
+        Value::String(s) => parse_arguments(format, tool_name, &s),
+        other => Err(ToolParseError::new(
+            format,
+            Some(tool_name),
+            format!("arguments must be a JSON object, got {}", json_kind(&other)),
+        )),
+    }
+}
+
+fn json_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+/// Strip a surrounding Markdown code fence, if any.
+fn unfence(text: &str) -> &str {
+    let t = text.trim();
+    let Some(rest) = t.strip_prefix("```") else {
+        return t;
+    };
+    let body = rest.split_once('\n').map_or("", |(_, b)| b);
+    body.trim_end().strip_suffix("```").unwrap_or(body).trim()
+}
+
+/// OpenAI chat-completions style: `{"tool_calls": [{"id", "function":
+/// {"name", "arguments": "<json string>"}}]}`.
+pub struct OpenAiToolCalls;
+
+impl ToolCallFormat for OpenAiToolCalls {
+    fn name(&self) -> &'static str {
+        "openai_tool_calls"
+    }
+
+    fn parse(&self, text: &str) -> Option<Vec<ParseOutcome>> {
+        let body = unfence(text);
+        if !body.starts_with('{') || !body.contains("\"tool_calls\"") {
+            return None;
+        }
+        let format = self.name();
+        let value: Value = match serde_json::from_str(body) {
+            Ok(v) => v,
+            Err(e) => {
+                return Some(vec![Err(ToolParseError::new(
+                    format,
+                    None,
+                    format!("invalid JSON ({e})"),
+                ))]);
+            }
+        };
+        let Some(calls) = value["tool_calls"].as_array() else {
+            return Some(vec![Err(ToolParseError::new(
+                format,
+                None,
+                "'tool_calls' must be an array",
+            ))]);
+        };
+        Some(
+            calls
+                .iter()
+                .map(|call| {
+                    let function = &call["function"];
+                    let name = function["name"].as_str().ok_or_else(|| {
+                        ToolParseError::new(format, None, "missing function name")
+                    })?;
+                    let arguments = arguments_object(format, name, function["arguments"].clone())?;
+                    Ok(ParsedToolCall {
+                        id: call["id"].as_str().map(str::to_string),
+                        name: name.to_string(),
+                        arguments,
+                    })
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Anthropic messages style: `{"type": "tool_use", "id", "name", "input"}`
+/// blocks, bare or inside a `content` array.
+pub struct AnthropicContentBlocks;
+
+impl ToolCallFormat for AnthropicContentBlocks {
+    fn name(&self) -> &'static str {
+        "anthropic_tool_use"
+    }
+
+    fn parse(&self, text: &str) -> Option<Vec<ParseOutcome>> {
+        let body = unfence(text);
+        if !(body.starts_with('{') || body.starts_with('[')) || !body.contains("\"tool_use\"") {
+            return None;
+        }
+        let format = self.name();
+        let value: Value = match serde_json::from_str(body) {
+            Ok(v) => v,
+            Err(e) => {
+                return Some(vec![Err(ToolParseError::new(
+                    format,
+                    None,
+                    format!("invalid JSON ({e})"),
+                ))]);
+            }
+        };
+        let blocks = match &value {
+            Value::Array(blocks) => blocks.as_slice(),
+            Value::Object(_) => match value["content"].as_array() {
+                Some(blocks) => blocks.as_slice(),
+                None => std::slice::from_ref(&value),
+            },
+            _ => return None,
+        };
+        let outcomes: Vec<ParseOutcome> = blocks
+            .iter()
+            .filter(|b| b["type"] == "tool_use")
+            .map(|block| {
+                let name = block["name"]
+                    .as_str()
+                    .ok_or_else(|| ToolParseError::new(format, None, "missing tool name"))?;
+                let arguments = arguments_object(format, name, block["input"].clone())?;
+                Ok(ParsedToolCall {
+                    id: block["id"].as_str().map(str::to_string),
+                    name: name.to_string(),
+                    arguments,
+                })
+            })
+            .collect();
+        (!outcomes.is_empty()).then_some(outcomes)
+    }
+}
+
+/// Textual fallback: `<tool>{"name": ..., "arguments": {...}}</tool>`, also
+/// accepting `<tool_call>`, `<tool_use>` and `<function_call>` tags and an
+/// `input` or `parameters` key for the arguments.
+pub struct TaggedToolCalls;
+
+const CALL_TAGS: &[&str] = &["tool", "tool_call", "tool_use", "function_call"];
+
+impl TaggedToolCalls {
+    /// Earliest opening tag at or after `from`: (tag, start, body start).
+    fn next_open(text: &str, from: usize) -> Option<(&'static str, usize, usize)> {
+        CALL_TAGS
+            .iter()
+            .filter_map(|tag| {
+                let open = format!("<{tag}>");
+                text[from..]
+                    .find(&open)
+                    .map(|i| (*tag, from + i, from + i + open.len()))
+            })
+            .min_by_key(|(_, start, _)| *start)
+    }
+
+    fn parse_body(format: &'static str, body: &str) -> ParseOutcome {
+        let value: Value = serde_json::from_str(unfence(body))
+            .map_err(|e| ToolParseError::new(format, None, format!("invalid JSON ({e})")))?;
+        let name = value["name"]
+            .as_str()
+            .ok_or_else(|| ToolParseError::new(format, None, "missing tool name"))?;
+        let raw_args = ["arguments", "input", "parameters"]
+            .iter()
+            .map(|k| &value[*k])
+            .find(|v| !v.is_null())
+            .cloned()
+            .unwrap_or(Value::Null);
+        Ok(ParsedToolCall {
+            id: value["id"].as_str().map(str::to_string),
+            name: name.to_string(),
+            arguments: arguments_object(format, name, raw_args)?,
+        })
+    }
+}
+
+impl ToolCallFormat for TaggedToolCalls {
+    fn name(&self) -> &'static str {
+        "tagged"
+    }
+
+    fn parse(&self, text: &str) -> Option<Vec<ParseOutcome>> {
+        let format = self.name();
+        let mut outcomes = Vec::new();
+        let mut pos = 0;
+        while let Some((tag, start, body_start)) = Self::next_open(text, pos) {
+            // Only a tag followed by a JSON payload is a call; prose that
+            // merely mentions `<tool>` is left alone.
+            let rest = text[body_start..].trim_start();
+            if !(rest.starts_with('{') || rest.starts_with("```")) {
+                pos = start + 1;
+                continue;
+            }
+            let close = format!("</{tag}>");
+            let Some(len) = text[body_start..].find(&close) else {
+                outcomes.push(Err(ToolParseError::new(
+                    format,
+                    None,
+                    format!("missing closing </{tag}> tag"),
+                )));
+                break;
+            };
+            outcomes.push(Self::parse_body(
+                format,
+                &text[body_start..body_start + len],
+            ));
+            pos = body_start + len + close.len();
+        }
+        (!outcomes.is_empty()).then_some(outcomes)
+    }
+}
+
+/// Tries each [`ToolCallFormat`] in registration order; the first format
+/// that recognises the text decides the result.
+pub struct ToolCallParser {
+    formats: Vec<Box<dyn ToolCallFormat>>,
+}
+
+impl ToolCallParser {
+    /// A parser with no formats registered.
+    pub fn empty() -> Self {
+        Self { formats: vec![] }
+    }
+
+    pub fn with_format(mut self, format: impl ToolCallFormat + 'static) -> Self {
+        self.formats.push(Box::new(format));
+        self
+    }
+
+    /// Parse `text`, returning the matching format's name and its outcomes,
+    /// or `None` when no format recognises any tool call.
+    pub fn parse(&self, text: &str) -> Option<(&'static str, Vec<ParseOutcome>)> {
+        self.formats
+            .iter()
+            .find_map(|f| f.parse(text).map(|outcomes| (f.name(), outcomes)))
+    }
+}
+
+impl Default for ToolCallParser {
+    fn default() -> Self {
+        Self::empty()
+            .with_format(OpenAiToolCalls)
+            .with_format(AnthropicContentBlocks)
+            .with_format(TaggedToolCalls)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn single(text: &str) -> (&'static str, ParseOutcome) {
+        let (format, mut outcomes) = ToolCallParser::default().parse(text).unwrap();
+        assert_eq!(outcomes.len(), 1);
+        (format, outcomes.remove(0))
+    }
+
+    // TP.1 — OpenAI tool_calls with string-encoded arguments
+    #[test]
+    fn parses_openai_tool_calls() {
+        let text = r#"{"tool_calls":[{"id":"call_1","type":"function","function":{"name":"web_search","arguments":"{\"query\":\"rust\"}"}}]}"#;
+        let (format, call) = single(text);
+        assert_eq!(format, "openai_tool_calls");
+        let call = call.unwrap();
+        assert_eq!(call.id.as_deref(), Some("call_1"));
+        assert_eq!(call.name, "web_search");
+        assert_eq!(call.arguments, json!({"query": "rust"}));
+    }
+
+    // TP.2 — Anthropic tool_use blocks, skipping text blocks
+    #[test]
+    fn parses_anthropic_content_blocks() {
+        let text = r#"```json
+[{"type":"text","text":"Searching."},{"type":"tool_use","id":"toolu_1","name":"file_read","input":{"path":"a.txt"}}]
+```"#;
+        let (format, call) = single(text);
+        assert_eq!(format, "anthropic_tool_use");
+        let call = call.unwrap();
+        assert_eq!(call.name, "file_read");
+        assert_eq!(call.arguments, json!({"path": "a.txt"}));
+    }
+
+    // TP.3 — Textual <tool> tags, several per reply and with surrounding prose
+    #[test]
+    fn parses_tagged_calls() {
+        let text = r#"Let me check.
+<tool>{"name":"shell","arguments":{"command":"ls"}}</tool>
+then <tool_use>{"name":"system_info"}</tool_use>"#;
+        let (format, outcomes) = ToolCallParser::default().parse(text).unwrap();
+        assert_eq!(format, "tagged");
+        let calls: Vec<_> = outcomes.into_iter().map(Result::unwrap).collect();
+        assert_eq!(calls[0].name, "shell");
+        assert_eq!(calls[0].arguments, json!({"command": "ls"}));
+        assert_eq!(calls[1].name, "system_info");
+        assert_eq!(calls[1].arguments, json!({}));
+    }
+
+    // TP.4 — Malformed JSON is reported, not dropped, with a tool-error message
+    #[test]
+    fn malformed_json_is_reported() {
+        let (_, outcome) = single(r#"<tool>{"name":"shell","arguments":{"command":"ls"}</tool>"#);
+        let err = outcome.unwrap_err();
+        assert_eq!(err.format, "tagged");
+        assert!(err.message.contains("invalid JSON"));
+        assert!(err.to_tool_message().starts_with("Tool call error:"));
+
+        let text =
+            r#"{"tool_calls":[{"function":{"name":"web_search","arguments":"{\"query\": "}}]}"#;
+        let err = single(text).1.unwrap_err();
+        assert_eq!(err.tool_name.as_deref(), Some("web_search"));
+        assert!(err.message.contains("invalid arguments JSON"));
+
+        let err = single("<tool>{\"name\":\"shell\"}").1.unwrap_err();
+        assert!(err.message.contains("</tool>"));
+    }
+
+    // TP.5 — Plain prose is not a tool call; argument decoding edge cases
+    #[test]
+    fn plain_text_and_arguments() {
+        assert!(
+            ToolCallParser::default()
+                .parse("The answer is 42.")
+                .is_none()
+        );
+        assert!(
+            ToolCallParser::default()
+                .parse("Wrap calls in <tool> tags.")
+                .is_none()
+        );
+        assert_eq!(parse_arguments("native", "t", "  ").unwrap(), json!({}));
+        assert_eq!(
+            parse_arguments("native", "t", r#""{\"a\":1}""#).unwrap(),
+            json!({"a": 1})
+        );
+        assert!(parse_arguments("native", "t", "[1]").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use serde::Serialize;
+
+/// Upper bound on retained outputs so a long tool loop cannot grow memory unbounded.
+const MAX_STORED_RESULTS: usize = 256;
+
+/// Full output of a tool call whose result was truncated for the model.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct StoredToolResult {
+    pub id: String,
+    pub tool_name: String,
+    pub output: String,
+}
+
+struct Entry {
+    result: StoredToolResult,
+    stored_at: Instant,
+    /// Insertion order, for evicting the oldest entry at capacity.
+    seq: u64,
+}
+
+/// Short-lived store of untruncated tool outputs, keyed by a random id.
+///
+/// The adapter only hands the model a compressed result; the id of the full
+/// version travels with the `tool_result` event so the UI can fetch it later,
+/// and is named in the truncation note so the model can page through it with
+/// `read_tool_output`. Entries expire after `ttl`.
+pub struct ToolResultStore {
+    entries: Mutex<HashMap<String, Entry>>,
+    next_seq: AtomicU64,
+    ttl: Duration,
+}
+
+impl ToolResultStore {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            next_seq: AtomicU64::new(0),
+            ttl,
+        }
+    }
+
+    /// Store `output` and return the id it can be fetched by.
+    pub fn put(&self, tool_name: &str, output: String) -> String {
+        let id = uuid::Uuid::new_v4().to_string();
+        let mut entries = self.entries.lock();
+        entries.retain(|_, e| e.stored_at.elapsed() < self.ttl);
+        if entries.len() >= MAX_STORED_RESULTS
+            && let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, e)| e.seq)
+                .map(|(k, _)| k.clone())
+        {
+            entries.remove(&oldest);
+        }
+        entries.insert(
+            id.clone(),
+            Entry {
+                result: StoredToolResult {
+                    id: id.clone(),
+                    tool_name: tool_name.to_string(),
+                    output,
+                },
+                stored_at: Instant::now(),
+                seq: self.next_seq.fetch_add(1, Ordering::Relaxed),
+            },
+        );
+        id
+    }
+
+    /// Fetch a stored output. Expired entries are dropped and return `None`.
+    pub fn get(&self, id: &str) -> Option<StoredToolResult> {
+        let mut entries = self.entries.lock();
+        match entries.get(id) {
+            Some(e) if e.stored_at.elapsed() < self.ttl => Some(e.result.clone()),
+            Some(_) => {
+                entries.remove(id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Note appended to a truncated result so the model knows how to page through
+/// the rest with the `read_tool_output` tool.
+pub fn truncation_note(handle: &str, total_bytes: usize, offset: usize) -> String {
+    format!(
+        "[truncated, {} total; use read_tool_output with handle=\"{handle}\" and offset={offset} to read more]",
+        format_size(total_bytes)
+    )
+}
+
+/// Byte length of the prefix `shown` shares with `full`, on a char boundary of
+/// `full`. This is where reading the stored output should resume.
+pub fn shared_prefix_len(shown: &str, full: &str) -> usize {
+    let mut len = shown
+        .bytes()
+        .zip(full.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !full.is_char_boundary(len) {
+        len -= 1;
+    }
+    len
+}
+
+fn format_size(bytes: usize) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    let b = bytes as f64;
+    if b >= MB {
+        format!("{:.1} MB", b / MB)
+    } else if b >= KB {
+        format!("{:.1} KB", b / KB)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // TRS.1 — Stored output is retrievable within the TTL and gone after it
+    #[test]
+    fn stored_output_expires_after_ttl() {
+        let store = ToolResultStore::new(Duration::from_millis(50));
+        let id = store.put("shell", "full output".into());
+
+        let result = store.get(&id).unwrap();
+        assert_eq!(result.tool_name, "shell");
+        assert_eq!(result.output, "full output");
+
+        std::thread::sleep(Duration::from_millis(80));
+        assert!(store.get(&id).is_none());
+        assert!(store.is_empty());
+    }
+
+    // TRS.2 — The store is capped; the oldest entry is evicted first
+    #[test]
+    fn store_evicts_oldest_at_capacity() {
+        let store = ToolResultStore::new(Duration::from_secs(60));
+        let first = store.put("shell", "0".into());
+        for i in 1..=MAX_STORED_RESULTS {
+            store.put("shell", i.to_string());
+        }
+        assert_eq!(store.len(), MAX_STORED_RESULTS);
+        assert!(store.get(&first).is_none());
+    }
+
+    // TRS.3 — The note reports a readable size and where to resume
+    #[test]
+    fn truncation_note_reports_size_and_offset() {
+        let note = truncation_note("abc", 1_258_291, 8192);
+        assert_eq!(
+            note,
+            "[truncated, 1.2 MB total; use read_tool_output with handle=\"abc\" and offset=8192 to read more]"
+        );
+        assert!(truncation_note("abc", 512, 0).contains("512 B total"));
+    }
+
+    // TRS.4 — The resume offset never splits a multi-byte character
+    #[test]
+    fn shared_prefix_stops_on_char_boundary() {
+        assert_eq!(shared_prefix_len("hello...[truncated]", "hello world"), 5);
+        // "é" is two bytes; the first byte matches but the second does not.
+        assert_eq!(shared_prefix_len("a\u{e9}", "a\u{e8}"), 1);
+        assert_eq!(shared_prefix_len("", "abc"), 0);
+    }
+}
@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+
+use crate::{Result, ZeniiError};
+
+/// Provider abstraction for speech-to-text transcription.
+#[async_trait]
+pub trait TranscriptionProvider: Send + Sync {
+    /// Transcribe `audio` into plain text. `file_name` carries the extension the
+    /// provider uses to detect the container format (e.g. `voice.ogg`).
+    async fn transcribe(
+        &self,
+        audio: Vec<u8>,
+        file_name: &str,
+        mime_type: Option<&str>,
+    ) -> Result<String>;
+}
+
+/// OpenAI-compatible transcription provider using the /v1/audio/transcriptions endpoint.
+/// Works with Whisper and self-hosted servers exposing the same API shape.
+pub struct OpenAiTranscriptionProvider {
+    client: reqwest::Client,
+    api_key: String,
+    model: String,
+    base_url: String,
+}
+
+impl OpenAiTranscriptionProvider {
+    pub fn new(api_key: String, model: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            api_key,
+            model,
+            base_url: "https://api.openai.com/v1".into(),
+        }
+    }
+
+    /// Override the API base URL (must include the version segment, e.g. `.../v1`).
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct TranscriptionResponse {
+    text: String,
+}
+
+#[async_trait]
+impl TranscriptionProvider for OpenAiTranscriptionProvider {
+    async fn transcribe(
+        &self,
+        audio: Vec<u8>,
+        file_name: &str,
+        mime_type: Option<&str>,
+    ) -> Result<String> {
+        if audio.is_empty() {
+            return Err(ZeniiError::Channel("audio data is empty".into()));
+        }
+
+        let mut part = reqwest::multipart::Part::bytes(audio).file_name(file_name.to_string());
+        if let Some(mime) = mime_type {
+            part = part
+                .mime_str(mime)
+                .map_err(|e| ZeniiError::Channel(format!("invalid audio MIME type: {e}")))?;
+        }
+        let form = reqwest::multipart::Form::new()
+            .text("model", self.model.clone())
+            .part("file", part);
+
+        let url = format!("{}/audio/transcriptions", self.base_url);
+        let resp = self
+            .client
+            .post(&url)
+            .bearer_auth(&self.api_key)
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| ZeniiError::Channel(format!("transcription request failed: {e}")))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_else(|_| "unknown error".into());
+            return Err(ZeniiError::Channel(format!(
+                "transcription API error {status}: {body}"
+            )));
+        }
+
+        let result: TranscriptionResponse = resp.json().await.map_err(|e| {
+            ZeniiError::Channel(format!("transcription response parse failed: {e}"))
+        })?;
+        Ok(result.text.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // TR.1 — Provider constructs with default base URL
+    #[test]
+    fn openai_transcription_provider_new() {
+        let provider = OpenAiTranscriptionProvider::new("sk-test".into(), "whisper-1".into());
+        assert_eq!(provider.model, "whisper-1");
+        assert_eq!(provider.base_url, "https://api.openai.com/v1");
+    }
+
+    // TR.2 — Empty audio returns error without a network call
+    #[tokio::test]
+    async fn openai_transcription_empty_audio() {
+        let provider = OpenAiTranscriptionProvider::new("sk-test".into(), "whisper-1".into())
+            .with_base_url("http://127.0.0.1:1".into());
+        assert!(
+            provider
+                .transcribe(vec![], "voice.ogg", None)
+                .await
+                .is_err()
+        );
+    }
+
+    // TR.3 — Transcription text parsed from a mock server
+    #[tokio::test]
+    async fn openai_transcription_parses_text() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mock_body = serde_json::json!({"text": " hello from a voice note "}).to_string();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 8192];
+            let _ = stream.readable().await;
+            let _ = stream.try_read(&mut buf);
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                mock_body.len(),
+                mock_body
+            );
+            let _ = stream.writable().await;
+            let _ = stream.try_write(response.as_bytes());
+        });
+
+        let provider = OpenAiTranscriptionProvider::new("sk-test".into(), "whisper-1".into())
+            .with_base_url(format!("http://{addr}/v1"));
+        let text = provider
+            .transcribe(b"OggS-fake".to_vec(), "voice.ogg", Some("audio/ogg"))
+            .await
+            .unwrap();
+        assert_eq!(text, "hello from a voice note");
+    }
+}
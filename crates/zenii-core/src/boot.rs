@@ -31,7 +31,9 @@ use crate::ai::{
     context::{BootContext, ContextBuilder},
     prompt::{self, PromptStrategy},
     provider_registry::ProviderRegistry,
-    reasoning::{ReasoningEngine, continuation::ContinuationStrategy},
+    reasoning::{
+        ReasoningEngine, continuation::ContinuationStrategy, text_tool_calls::TextToolCallStrategy,
+    },
     session::SessionManager,
 };
 
@@ -46,6 +48,14 @@ pub struct Services {
     pub config_path: PathBuf,
     pub db: DbPool,
     pub event_bus: Arc<TokioBroadcastBus>,
+    pub event_recorder: Arc<crate::event_bus::recorder::EventRecorder>,
+    pub oauth_states: Arc<crate::credential::oauth::OAuthStateStore>,
+    /// Scoped gateway tokens (`<data_dir>/gateway_tokens.json`).
+    #[cfg(feature = "gateway")]
+    pub api_tokens: Arc<crate::gateway::auth::TokenStore>,
+    pub tool_results: Arc<crate::ai::tool_results::ToolResultStore>,
+    pub session_streams: Arc<crate::event_bus::session_stream::SessionStreamLog>,
+    pub activity: Arc<crate::event_bus::activity::ActivityBuffer>,
     pub memory: Arc<dyn Memory>,
     pub credentials: Arc<dyn CredentialStore>,
     pub security: Arc<SecurityPolicy>,
@@ -66,6 +76,8 @@ pub struct Services {
     pub reasoning_engine: Arc<ReasoningEngine>,
     #[cfg(feature = "ai")]
     pub prompt_strategy: Arc<dyn PromptStrategy>,
+    #[cfg(feature = "ai")]
+    pub transcriber: Option<Arc<dyn crate::ai::transcription::TranscriptionProvider>>,
     pub context_injection_enabled: Arc<AtomicBool>,
     pub self_evolution_enabled: Arc<AtomicBool>,
     pub soul_loader: Arc<SoulLoader>,
@@ -91,6 +103,9 @@ pub struct Services {
     /// Whether the local embedding model is downloaded and ready.
     pub embedding_model_available: Arc<AtomicBool>,
     pub approval_broker: Option<Arc<crate::security::approval::ApprovalBroker>>,
+    /// Durable audit log writer for tool calls.
+    pub audit: Option<crate::security::audit::AuditSink>,
+    pub budget: Arc<crate::security::budget::BudgetTracker>,
     pub wiki: Arc<tokio::sync::Mutex<crate::wiki::WikiManager>>,
     pub converter: Arc<dyn crate::wiki::convert::DocumentConverter>,
 }
@@ -114,12 +129,19 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         std::fs::create_dir_all(parent)?;
     }
 
-    let pool = db::init_pool(&db_path)?;
+    let pool = db::init_pool_with_busy_timeout(&db_path, config.db_busy_timeout_ms)?;
     db::with_db(&pool, db::run_migrations).await?;
     info!("Database initialized at {}", db_path.display());
 
     // 2. Event bus (M8: configurable capacity)
-    let event_bus = Arc::new(TokioBroadcastBus::new(config.event_bus_capacity));
+    let event_bus = Arc::new(TokioBroadcastBus::with_replay(
+        config.event_bus_capacity,
+        config.event_bus_replay_capacity,
+    ));
+    let audit = crate::security::audit::AuditSink::spawn(pool.clone());
+    crate::security::audit::AuditLogger::spawn(event_bus.clone(), pool.clone());
+    crate::event_bus::lifecycle::LifecycleLogger::spawn(event_bus.clone(), pool.clone());
+    let approval_broker = Arc::new(crate::security::approval::ApprovalBroker::new(pool.clone()));
 
     // 3. Memory — always use SqliteMemoryStore (persistent)
     let memory_db_path = config
@@ -135,7 +157,8 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
     if let Some(parent) = memory_db_path.parent() {
         std::fs::create_dir_all(parent)?;
     }
-    let memory_pool = crate::db::init_pool(&memory_db_path)?;
+    let memory_pool =
+        crate::db::init_pool_with_busy_timeout(&memory_db_path, config.db_busy_timeout_ms)?;
     {
         let mp = memory_pool.clone();
         tokio::task::spawn_blocking(move || {
@@ -293,10 +316,21 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
     // 6. Tools
     let tool_registry = ToolRegistry::new();
     tool_registry.register(Arc::new(crate::tools::system_info::SystemInfoTool::new()))?;
-    tool_registry.register(Arc::new(crate::tools::web_search::WebSearchTool::new(
-        credentials.clone(),
-        config.web_search_timeout_secs,
-        config.web_search_max_results,
+    tool_registry.register(Arc::new(
+        crate::tools::web_search::WebSearchTool::new(
+            credentials.clone(),
+            config.web_search_timeout_secs,
+            config.web_search_max_results,
+        )
+        .with_provider(
+            &config.web_search_provider,
+            config.web_search_searxng_url.clone(),
+        ),
+    ))?;
+    tool_registry.register(Arc::new(crate::tools::http::HttpTool::new(
+        security.clone(),
+        config.tool_http_timeout_secs,
+        config.tool_http_max_response_bytes,
     )))?;
     tool_registry.register(Arc::new(crate::tools::file_ops::FileReadTool::new(
         security.clone(),
@@ -320,6 +354,11 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
             config.tool_file_search_max_depth,
         ),
     ))?;
+    tool_registry.register(Arc::new(crate::tools::grep::GrepTool::new(
+        security.clone(),
+        config.tool_content_search_max_file_size_kb * 1024,
+        config.tool_file_search_max_depth,
+    )))?;
     tool_registry.register(Arc::new(crate::tools::shell::ShellTool::new(
         security.clone(),
         config.tool_shell_timeout_secs,
@@ -329,6 +368,13 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         config.tool_process_list_limit,
     )))?;
     tool_registry.register(Arc::new(crate::tools::patch::PatchTool::new()))?;
+    #[cfg(feature = "git")]
+    if let Some(ref root) = config.tool_git_workspace_root {
+        tool_registry.register(Arc::new(crate::tools::git::GitTool::new(
+            security.clone(),
+            PathBuf::from(root),
+        )))?;
+    }
 
     // 10. User learner (needed before tools that reference it)
     let user_learner = Arc::new(UserLearner::new(pool.clone(), &config));
@@ -353,6 +399,9 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
     tool_registry.register(Arc::new(crate::tools::memory_tool::MemoryTool::new(
         memory.clone(),
     )))?;
+    tool_registry.register(Arc::new(
+        crate::tools::memory_query_tool::MemoryQueryTool::new(memory.clone()),
+    ))?;
 
     // Register AgentSelfTool (self-evolving prompt notes)
     tool_registry.register(Arc::new(crate::tools::agent_self_tool::AgentSelfTool::new(
@@ -363,6 +412,13 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
     // Create shared ArcSwap config for runtime hot-swapping
     let config_swap = Arc::new(arc_swap::ArcSwap::from(config.clone()));
 
+    // Spend ledger: records costed AgentUsage events, enforces budget_*_usd caps
+    let budget = Arc::new(crate::security::budget::BudgetTracker::new(
+        pool.clone(),
+        config_swap.clone(),
+    ));
+    budget.spawn_recorder(event_bus.clone());
+
     // Register ConfigTool
     tool_registry.register(Arc::new(crate::tools::config_tool::ConfigTool::new(
         config_swap.clone(),
@@ -393,6 +449,17 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         wiki.clone(),
     )))?;
 
+    let tool_results = Arc::new(crate::ai::tool_results::ToolResultStore::new(
+        std::time::Duration::from_secs(config.tool_result_retention_secs),
+    ));
+    // Half the compression ceiling, so a JSON-escaped chunk is not truncated again.
+    tool_registry.register(Arc::new(
+        crate::tools::read_tool_output::ReadToolOutputTool::new(
+            Arc::clone(&tool_results),
+            config.compression_max_output_chars / 2,
+        ),
+    ))?;
+
     let tools = Arc::new(tool_registry);
     info!("Registered {} tools", tools.len());
 
@@ -412,7 +479,10 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         .as_ref()
         .map(PathBuf::from)
         .unwrap_or_else(|| data_dir.join("identity"));
-    let soul_loader = Arc::new(SoulLoader::new(&identity_dir)?);
+    let soul_loader = Arc::new(SoulLoader::with_fallback(
+        &identity_dir,
+        config.identity_fallback_prompt.clone(),
+    )?);
     info!("Identity loaded from {}", identity_dir.display());
 
     // 9. Skills (SkillRegistry)
@@ -479,7 +549,9 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
     #[cfg(feature = "ai")]
     let reasoning_engine = {
         let mut engine = ReasoningEngine::new(config.agent_max_continuations);
+        engine.add_strategy(TextToolCallStrategy::new(config.agent_max_continuations));
         engine.add_strategy(ContinuationStrategy::new(config.agent_max_continuations));
+        engine.set_spend_guard(budget.clone());
         Arc::new(engine)
     };
     #[cfg(feature = "ai")]
@@ -509,10 +581,63 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
     #[cfg(feature = "ai")]
     info!("Provider registry initialized");
 
+    // Register ImageGenTool when a model is routed for image generation
+    #[cfg(feature = "ai")]
+    if let Some(target) = crate::ai::routing::ModelRouter::new(&config)
+        .route_task(crate::ai::routing::TaskType::ImageGeneration)
+    {
+        match build_image_provider(&target, &provider_registry, credentials.as_ref()).await {
+            Ok(provider) => {
+                let output_dir = config
+                    .image_gen_output_dir
+                    .as_ref()
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| data_dir.join("images"));
+                tools
+                    .register(Arc::new(crate::tools::image_gen::ImageGenTool::new(
+                        provider,
+                        output_dir,
+                        config.image_gen_default_size.clone(),
+                        config.image_gen_max_images,
+                    )))
+                    .unwrap_or_else(|e| tracing::warn!("Failed to register image tool: {e}"));
+                info!("Image generation enabled via '{target}'");
+            }
+            Err(e) => tracing::warn!("Image generation not available: {e}"),
+        }
+    }
+
+    // Transcription provider for inbound channel voice messages
+    #[cfg(feature = "ai")]
+    let transcriber = match config.transcription_model.as_deref() {
+        Some(target) => {
+            match build_transcription_provider(target, &provider_registry, credentials.as_ref())
+                .await
+            {
+                Ok(provider) => {
+                    info!("Audio transcription enabled via '{target}'");
+                    Some(provider)
+                }
+                Err(e) => {
+                    tracing::warn!("Audio transcription not available: {e}");
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+
     // 12. Agent (may fail if no API key configured — that's OK)
     #[cfg(feature = "ai")]
     let tool_vec = tools.to_vec();
-    let agent = match ZeniiAgent::new(&config, credentials.as_ref(), &tool_vec).await {
+    let agent = match ZeniiAgent::new(
+        &config,
+        credentials.as_ref(),
+        &tool_vec,
+        Some(audit.clone()),
+    )
+    .await
+    {
         Ok(a) => {
             info!(
                 "AI agent initialized with provider '{}'",
@@ -528,7 +653,12 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
 
     // 13. Channel registry and router
     #[cfg(feature = "channels")]
-    let channel_registry = Arc::new(ChannelRegistry::new());
+    let channel_registry = Arc::new(ChannelRegistry::with_config(config_swap.clone()));
+    #[cfg(feature = "channels")]
+    channel_registry
+        .allowlists()
+        .reload(&config, credentials.as_ref())
+        .await;
     #[cfg(feature = "channels")]
     let channel_router = {
         #[cfg(feature = "gateway")]
@@ -551,12 +681,7 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
     if matches!(credentials.get("channel:telegram:token").await, Ok(Some(_))) {
         let mut tg_config =
             crate::channels::telegram::config::TelegramConfig::from_app_config(&config);
-        if let Ok(Some(ids_str)) = credentials.get("channel:telegram:allowed_chat_ids").await {
-            tg_config.allowed_chat_ids = ids_str
-                .split(',')
-                .filter_map(|s| s.trim().parse::<i64>().ok())
-                .collect();
-        }
+        tg_config.allowed_chat_ids = channel_registry.allowlists().telegram_chats.clone();
         let tg: Arc<dyn crate::channels::traits::Channel> =
             Arc::new(crate::channels::telegram::TelegramChannel::new(
                 tg_config,
@@ -579,7 +704,10 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
     ) {
         let sl: Arc<dyn crate::channels::traits::Channel> = Arc::new(
             crate::channels::slack::SlackChannel::new(credentials.clone())
-                .with_allowed_channels(config.slack_allowed_channel_ids.clone()),
+                .with_allowed_channels(channel_registry.allowlists().slack_channels.clone())
+                .with_max_attachment_bytes(config.channel_attachment_max_mb * 1024 * 1024)
+                .with_approval_broker(approval_broker.clone())
+                .with_event_bus(event_bus.clone()),
         );
         if let Err(e) = channel_registry.register_or_replace(sl.clone()) {
             tracing::warn!("Failed to register slack: {e}");
@@ -592,7 +720,10 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
 
     #[cfg(feature = "channels-discord")]
     if matches!(credentials.get("channel:discord:token").await, Ok(Some(_))) {
-        let dc_config = crate::channels::discord::config::DiscordConfig::from_app_config(&config);
+        let mut dc_config =
+            crate::channels::discord::config::DiscordConfig::from_app_config(&config);
+        dc_config.allowed_guild_ids = channel_registry.allowlists().discord_guilds.clone();
+        dc_config.allowed_channel_ids = channel_registry.allowlists().discord_channels.clone();
         let dc: Arc<dyn crate::channels::traits::Channel> = Arc::new(
             crate::channels::discord::DiscordChannel::new(dc_config, credentials.clone()),
         );
@@ -605,6 +736,24 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         }
     }
 
+    #[cfg(feature = "channels-whatsapp")]
+    if matches!(
+        credentials.get("channel:whatsapp:access_token").await,
+        Ok(Some(_))
+    ) {
+        let wa: Arc<dyn crate::channels::traits::Channel> = Arc::new(
+            crate::channels::whatsapp::WhatsAppChannel::new(credentials.clone())
+                .with_allowed_numbers(config.whatsapp_allowed_numbers.clone()),
+        );
+        if let Err(e) = channel_registry.register_or_replace(wa.clone()) {
+            tracing::warn!("Failed to register whatsapp: {e}");
+        } else if let Err(e) = wa.connect().await {
+            tracing::warn!("Failed to connect whatsapp: {e}");
+        } else {
+            info!("WhatsApp auto-connected from stored credentials");
+        }
+    }
+
     // Register ChannelSendTool (post-Arc, DashMap allows it)
     #[cfg(feature = "channels")]
     {
@@ -612,13 +761,16 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
             session_manager.clone(),
         ));
         tools
-            .register(Arc::new(crate::tools::channel_tool::ChannelSendTool::new(
-                channel_registry.clone(),
-                pool.clone(),
-                tool_session_map,
-                session_manager.clone(),
-                event_bus.clone(),
-            )))
+            .register(Arc::new(
+                crate::tools::channel_tool::ChannelSendTool::new(
+                    channel_registry.clone(),
+                    pool.clone(),
+                    tool_session_map,
+                    session_manager.clone(),
+                    event_bus.clone(),
+                )
+                .with_policy(security.clone()),
+            ))
             .unwrap_or_else(|e| tracing::warn!("Failed to register channel_send tool: {e}"));
     }
 
@@ -632,6 +784,9 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         if let Err(e) = sched.load_from_db().await {
             tracing::warn!("Failed to load scheduler jobs from DB: {e}");
         }
+        if config.memory_daily_summary_enabled {
+            seed_daily_summary_job(&sched).await;
+        }
         sched.start().await;
         info!("Scheduler initialized and started");
         Some(sched)
@@ -676,6 +831,14 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         config.workflow_step_timeout_secs,
         config.workflow_step_max_retries,
     )));
+    #[cfg(feature = "workflows")]
+    if let Some(ref executor) = workflow_executor_init {
+        match executor.fail_interrupted_runs().await {
+            Ok(0) => {}
+            Ok(n) => tracing::warn!("Marked {n} interrupted workflow run(s) as failed"),
+            Err(e) => tracing::warn!("Failed to recover interrupted workflow runs: {e}"),
+        }
+    }
 
     #[cfg(all(feature = "workflows", feature = "ai"))]
     let workflow_generator_init = match (agent.as_ref(), tools.as_ref()) {
@@ -719,9 +882,14 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         let registry = prompt::PromptStrategyRegistry::new(base, config.clone());
 
         // Always-registered plugins
-        registry
-            .register_plugin(Arc::new(prompt::MemoryPlugin::new(memory.clone())))
-            .await;
+        if config.context_memory_injection {
+            registry
+                .register_plugin(Arc::new(prompt::MemoryPlugin::from_config(
+                    memory.clone(),
+                    &config,
+                )))
+                .await;
+        }
         registry
             .register_plugin(Arc::new(prompt::UserObservationsPlugin::new(
                 user_learner.clone(),
@@ -808,60 +976,32 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         .unwrap_or_else(|| data_dir.join("plugins"));
     let plugin_registry = Arc::new(PluginRegistry::new(plugins_dir)?);
 
-    // Register enabled plugin tools into ToolRegistry
+    let plugin_installer = Arc::new(
+        PluginInstaller::new(
+            plugin_registry.clone(),
+            tools.clone(),
+            skill_registry.clone(),
+            config.plugin_execute_timeout_secs,
+            config.plugin_max_restart_attempts,
+        )
+        .with_health_failure_threshold(config.plugin_health_failure_threshold)
+        .with_event_bus(event_bus.clone()),
+    );
+
+    // Register enabled plugin tools and skills; the installer tracks them so
+    // later reloads can swap them without a restart.
     for plugin in plugin_registry.list() {
         if !plugin.enabled {
             continue;
         }
-        for tool_def in &plugin.manifest.tools {
-            let binary = plugin.install_path.join(&tool_def.binary);
-
-            // Fetch real schema from the plugin's info() JSON-RPC method
-            let schema = crate::plugins::fetch_plugin_schema(
-                &binary,
-                &tool_def.name,
-                config.plugin_execute_timeout_secs,
-                config.plugin_max_restart_attempts,
-            )
-            .await;
-
-            // Create a fresh process for the adapter (the one used for schema fetch is consumed)
-            let process = crate::plugins::process::PluginProcess::new(
-                &tool_def.name,
-                binary,
-                config.plugin_execute_timeout_secs,
-                config.plugin_max_restart_attempts,
+        if let Err(e) = plugin_installer.activate(&plugin).await {
+            tracing::warn!(
+                "Failed to activate plugin '{}': {e}",
+                plugin.manifest.plugin.name
             );
-            let adapter = crate::plugins::adapter::PluginToolAdapter::new(
-                tool_def.name.clone(),
-                tool_def.description.clone(),
-                schema,
-                Arc::new(tokio::sync::Mutex::new(process)),
-            );
-            tools.register(Arc::new(adapter)).unwrap_or_else(|e| {
-                tracing::warn!("Failed to register plugin tool '{}': {e}", tool_def.name);
-            });
-        }
-        for skill_def in &plugin.manifest.skills {
-            let path = plugin.install_path.join(&skill_def.file);
-            if let Ok(content) = std::fs::read_to_string(&path)
-                && let Err(e) = skill_registry
-                    .register_external(&skill_def.name, content)
-                    .await
-            {
-                tracing::warn!("Failed to register plugin skill '{}': {e}", skill_def.name);
-            }
         }
     }
 
-    let plugin_installer = Arc::new(PluginInstaller::new(
-        plugin_registry.clone(),
-        tools.clone(),
-        skill_registry.clone(),
-        config.plugin_execute_timeout_secs,
-        config.plugin_max_restart_attempts,
-    ));
-
     info!(
         "Plugin system initialized: {} plugins",
         plugin_registry.list().len()
@@ -901,14 +1041,47 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
     ));
     info!("Delegation coordinator initialized");
 
+    let session_streams = crate::event_bus::session_stream::SessionStreamLog::spawn(
+        event_bus.clone(),
+        crate::event_bus::session_stream::DEFAULT_REPLAY_CAPACITY,
+    );
+    // A call still running well past the longest tool timeout has lost its result
+    let longest_tool_timeout = config
+        .security_tool_timeouts
+        .values()
+        .copied()
+        .fold(config.security_tool_timeout_secs, u64::max);
+    let activity = crate::event_bus::activity::ActivityBuffer::spawn(
+        event_bus.clone(),
+        crate::event_bus::activity::DEFAULT_ACTIVITY_CAPACITY,
+        std::time::Duration::from_secs(longest_tool_timeout.saturating_add(30)),
+    );
+
+    // 18. Scoped gateway tokens
+    #[cfg(feature = "gateway")]
+    let api_tokens = Arc::new(crate::gateway::auth::TokenStore::open(
+        &data_dir.join(crate::gateway::auth::TOKENS_FILE),
+    )?);
+
+    let oauth_states = Arc::new(crate::credential::oauth::OAuthStateStore::new(
+        std::time::Duration::from_secs(config.oauth_state_ttl_secs),
+    ));
+
     info!("All services initialized");
 
     Ok(Services {
         config,
         config_swap,
         config_path: crate::config::default_config_path(),
-        db: pool.clone(),
+        db: pool,
         event_bus,
+        event_recorder: Arc::new(crate::event_bus::recorder::EventRecorder::new()),
+        oauth_states,
+        #[cfg(feature = "gateway")]
+        api_tokens,
+        tool_results,
+        session_streams,
+        activity,
         memory,
         credentials,
         security,
@@ -929,6 +1102,8 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         reasoning_engine,
         #[cfg(feature = "ai")]
         prompt_strategy,
+        #[cfg(feature = "ai")]
+        transcriber,
         context_injection_enabled,
         self_evolution_enabled,
         soul_loader,
@@ -952,14 +1127,108 @@ pub async fn init_services(config: AppConfig) -> Result<Services> {
         workflow_generator: workflow_generator_init,
         usage_logger,
         embedding_model_available,
-        approval_broker: Some(Arc::new(crate::security::approval::ApprovalBroker::new(
-            pool,
-        ))),
+        approval_broker: Some(approval_broker),
+        audit: Some(audit),
+        budget,
         wiki,
         converter,
     })
 }
 
+/// Resolve a `"provider_id:model_id"` target into `(base_url, api_key, model_id)`.
+/// The provider's base URL and key requirement come from the provider registry;
+/// `setting` names the config field in error messages.
+#[cfg(feature = "ai")]
+async fn resolve_provider_target(
+    target: &str,
+    setting: &str,
+    registry: &ProviderRegistry,
+    credentials: &dyn CredentialStore,
+) -> Result<(String, String, String)> {
+    let (provider_id, model_id) = target.split_once(':').ok_or_else(|| {
+        crate::ZeniiError::Config(format!(
+            "{setting} must be 'provider_id:model_id', got '{target}'"
+        ))
+    })?;
+    let provider = registry.get_provider(provider_id).await?.provider;
+    let api_key = crate::ai::providers::resolve_api_key_for_provider(
+        provider_id,
+        provider.requires_api_key,
+        credentials,
+    )
+    .await?;
+    Ok((provider.base_url, api_key, model_id.to_string()))
+}
+
+/// Build an image generation provider from the `TaskType::ImageGeneration`
+/// routing target.
+#[cfg(feature = "ai")]
+async fn build_image_provider(
+    target: &str,
+    registry: &ProviderRegistry,
+    credentials: &dyn CredentialStore,
+) -> Result<Arc<dyn crate::ai::image_gen::ImageGenProvider>> {
+    let (base_url, api_key, model_id) =
+        resolve_provider_target(target, "routing_hint_image", registry, credentials).await?;
+    Ok(Arc::new(
+        crate::ai::image_gen::OpenAiImageGenProvider::new(api_key, model_id)
+            .with_base_url(base_url),
+    ))
+}
+
+/// Build a transcription provider from the `transcription_model` target.
+#[cfg(feature = "ai")]
+async fn build_transcription_provider(
+    target: &str,
+    registry: &ProviderRegistry,
+    credentials: &dyn CredentialStore,
+) -> Result<Arc<dyn crate::ai::transcription::TranscriptionProvider>> {
+    let (base_url, api_key, model_id) =
+        resolve_provider_target(target, "transcription_model", registry, credentials).await?;
+    Ok(Arc::new(
+        crate::ai::transcription::OpenAiTranscriptionProvider::new(api_key, model_id)
+            .with_base_url(base_url),
+    ))
+}
+
+/// Add the end-of-day memory summary job unless one already exists, so a user
+/// who edits its schedule keeps their version.
+#[cfg(feature = "scheduler")]
+async fn seed_daily_summary_job(scheduler: &TokioScheduler) {
+    use crate::scheduler::traits::{JobPayload, Schedule, ScheduledJob, SessionTarget};
+
+    if scheduler
+        .list_jobs()
+        .await
+        .iter()
+        .any(|job| job.payload == JobPayload::DailySummary)
+    {
+        return;
+    }
+    let job = ScheduledJob {
+        id: crate::memory::daily_summary::JOB_ID.into(),
+        name: "Daily memory summary".into(),
+        // Memory dates are UTC, so close the UTC day
+        schedule: Schedule::Cron {
+            expr: "55 23 * * *".into(),
+            tz: Some("UTC".into()),
+        },
+        session_target: SessionTarget::Isolated,
+        payload: JobPayload::DailySummary,
+        enabled: true,
+        error_count: 0,
+        next_run: None,
+        active_hours: Vec::new(),
+        delete_after_run: false,
+        timeout_secs: None,
+        priority: None,
+    };
+    match scheduler.add_job(job).await {
+        Ok(_) => info!("Seeded daily memory summary job"),
+        Err(e) => tracing::warn!("Failed to seed daily memory summary job: {e}"),
+    }
+}
+
 /// Convert Services into gateway AppState.
 /// After wrapping in Arc, call `state.wire_scheduler()` to enable payload execution.
 #[cfg(feature = "gateway")]
@@ -971,6 +1240,12 @@ impl From<Services> for AppState {
             config_write_lock: tokio::sync::Mutex::new(()),
             db: s.db,
             event_bus: s.event_bus,
+            event_recorder: s.event_recorder,
+            oauth_states: s.oauth_states,
+            api_tokens: s.api_tokens,
+            tool_results: s.tool_results,
+            session_streams: s.session_streams,
+            activity: s.activity,
             memory: s.memory,
             credentials: s.credentials,
             security: s.security,
@@ -982,6 +1257,10 @@ impl From<Services> for AppState {
             #[cfg(feature = "ai")]
             provider_registry: s.provider_registry,
             #[cfg(feature = "ai")]
+            provider_health: Arc::default(),
+            #[cfg(feature = "ai")]
+            stream_cancels: Arc::default(),
+            #[cfg(feature = "ai")]
             boot_context: s.boot_context,
             #[cfg(feature = "ai")]
             last_used_model: s.last_used_model,
@@ -991,6 +1270,8 @@ impl From<Services> for AppState {
             reasoning_engine: s.reasoning_engine,
             #[cfg(feature = "ai")]
             prompt_strategy: s.prompt_strategy,
+            #[cfg(feature = "ai")]
+            transcriber: s.transcriber,
             context_injection_enabled: s.context_injection_enabled,
             self_evolution_enabled: s.self_evolution_enabled,
             soul_loader: s.soul_loader,
@@ -1017,6 +1298,8 @@ impl From<Services> for AppState {
             usage_logger: s.usage_logger,
             embedding_model_available: s.embedding_model_available,
             approval_broker: s.approval_broker,
+            audit: s.audit,
+            budget: s.budget,
             wiki: s.wiki, // Already Arc<tokio::sync::Mutex<WikiManager>>
             converter: s.converter,
         }
@@ -1080,19 +1363,12 @@ mod tests {
         let dir = tempfile::TempDir::new().unwrap();
         let config = test_config(&dir);
         let services = init_services(config).await.unwrap();
-        let mut expected = 16; // base tools + memory + config + agent_notes + content_search + wiki
-        #[cfg(feature = "channels")]
-        {
-            expected += 1; // channel_send
-        }
-        #[cfg(feature = "scheduler")]
-        {
-            expected += 1; // scheduler
-        }
-        #[cfg(feature = "workflows")]
-        {
-            expected += 1; // workflows
-        }
+        // base tools + memory + memory_query + config + agent_notes + content_search + grep + wiki
+        // + read_tool_output, plus channel_send, scheduler and workflows when their features are on
+        let expected = 20
+            + usize::from(cfg!(feature = "channels"))
+            + usize::from(cfg!(feature = "scheduler"))
+            + usize::from(cfg!(feature = "workflows"));
         assert_eq!(services.tools.len(), expected);
     }
 
@@ -1106,6 +1382,21 @@ mod tests {
         assert!(ws.is_some(), "web_search tool must be registered");
     }
 
+    // IMG.8 — image_generate is only registered when hint:image is configured
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn image_tool_gated_on_routing_hint() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let services = init_services(test_config(&dir)).await.unwrap();
+        assert!(services.tools.get("image_generate").is_none());
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut config = test_config(&dir);
+        config.routing_hint_image = Some("ollama:sdxl".into());
+        let services = init_services(config).await.unwrap();
+        assert!(services.tools.get("image_generate").is_some());
+    }
+
     // 5.5 — agent is None when no API key is configured
     #[cfg(feature = "ai")]
     #[tokio::test]
@@ -1186,6 +1477,25 @@ mod tests {
         );
     }
 
+    // Boot seeds the daily memory summary job
+    #[cfg(feature = "scheduler")]
+    #[tokio::test]
+    async fn boot_seeds_daily_summary_job() {
+        use crate::scheduler::traits::JobPayload;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let services = init_services(test_config(&dir)).await.unwrap();
+        let sched = services.scheduler.unwrap();
+        let seeded: Vec<_> = sched
+            .list_jobs()
+            .await
+            .into_iter()
+            .filter(|job| job.payload == JobPayload::DailySummary)
+            .collect();
+        assert_eq!(seeded.len(), 1);
+        assert_eq!(seeded[0].id, crate::memory::daily_summary::JOB_ID);
+    }
+
     // 8.7.11 — With channels feature, boot creates a channel_router
     #[cfg(all(feature = "channels", feature = "gateway"))]
     #[tokio::test]
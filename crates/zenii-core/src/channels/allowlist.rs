@@ -0,0 +1,369 @@
+//! Runtime-editable channel allowlists.
+//!
+//! Each list is an [`Allowlist`] shared between the live channel and the
+//! [`ChannelRegistry`](super::registry::ChannelRegistry), so entries added or
+//! removed through the gateway take effect on the next inbound message,
+//! without reconnecting. Telegram chat ids persist in the credential store;
+//! Discord and Slack lists persist in the config file.
+
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::config::AppConfig;
+use crate::credential::CredentialStore;
+use crate::{Result, ZeniiError};
+
+/// Credential key holding Telegram's comma-separated allowed chat ids.
+pub const TELEGRAM_ALLOWLIST_KEY: &str = "channel:telegram:allowed_chat_ids";
+
+/// A shared, lock-protected list of allowed ids. Clones share the same list.
+#[derive(Debug)]
+pub struct Allowlist<T>(Arc<RwLock<Vec<T>>>);
+
+impl<T> Clone for Allowlist<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Default for Allowlist<T> {
+    fn default() -> Self {
+        Self(Arc::new(RwLock::new(Vec::new())))
+    }
+}
+
+impl<T: Clone + PartialEq> Allowlist<T> {
+    pub fn new(entries: Vec<T>) -> Self {
+        Self(Arc::new(RwLock::new(entries)))
+    }
+
+    pub fn contains(&self, entry: &T) -> bool {
+        self.0.read().contains(entry)
+    }
+
+    /// Empty list = allow all.
+    pub fn allows(&self, entry: &T) -> bool {
+        let entries = self.0.read();
+        entries.is_empty() || entries.contains(entry)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.read().is_empty()
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.0.read().clone()
+    }
+
+    /// Add an entry. Returns `false` if it was already present.
+    pub fn insert(&self, entry: T) -> bool {
+        let mut entries = self.0.write();
+        if entries.contains(&entry) {
+            return false;
+        }
+        entries.push(entry);
+        true
+    }
+
+    /// Remove an entry. Returns `false` if it was not present.
+    pub fn remove(&self, entry: &T) -> bool {
+        let mut entries = self.0.write();
+        let before = entries.len();
+        entries.retain(|e| e != entry);
+        entries.len() != before
+    }
+
+    pub fn set(&self, entries: Vec<T>) {
+        *self.0.write() = entries;
+    }
+}
+
+impl<T: Clone + PartialEq> From<Vec<T>> for Allowlist<T> {
+    fn from(entries: Vec<T>) -> Self {
+        Self::new(entries)
+    }
+}
+
+impl<T: Serialize> Serialize for Allowlist<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.0.read().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Clone + PartialEq> Deserialize<'de> for Allowlist<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Which allowlist an operation targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllowlistTarget {
+    TelegramChats,
+    DiscordGuilds,
+    DiscordChannels,
+    SlackChannels,
+}
+
+impl AllowlistTarget {
+    /// Parse a list name: `telegram`, `discord` (guilds), `discord-channels`
+    /// or `slack`.
+    pub fn parse(name: &str) -> Result<Self> {
+        match name {
+            "telegram" => Ok(Self::TelegramChats),
+            "discord" | "discord-guilds" => Ok(Self::DiscordGuilds),
+            "discord-channels" => Ok(Self::DiscordChannels),
+            "slack" => Ok(Self::SlackChannels),
+            other => Err(ZeniiError::NotFound(format!(
+                "no allowlist named '{other}' (expected telegram, discord, discord-channels or slack)"
+            ))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TelegramChats => "telegram",
+            Self::DiscordGuilds => "discord",
+            Self::DiscordChannels => "discord-channels",
+            Self::SlackChannels => "slack",
+        }
+    }
+}
+
+fn parse_id<T: std::str::FromStr>(target: AllowlistTarget, entry: &str) -> Result<T> {
+    entry.parse().map_err(|_| {
+        ZeniiError::Validation(format!(
+            "'{entry}' is not a valid {} id (expected a number)",
+            target.as_str()
+        ))
+    })
+}
+
+/// All runtime-editable channel allowlists.
+#[derive(Debug, Clone, Default)]
+pub struct ChannelAllowlists {
+    pub telegram_chats: Allowlist<i64>,
+    pub discord_guilds: Allowlist<u64>,
+    pub discord_channels: Allowlist<u64>,
+    pub slack_channels: Allowlist<String>,
+}
+
+impl ChannelAllowlists {
+    /// Load the persisted lists from the config and credential store.
+    pub async fn reload(&self, config: &AppConfig, credentials: &dyn CredentialStore) {
+        self.sync_from_config(config);
+        if let Ok(Some(ids)) = credentials.get(TELEGRAM_ALLOWLIST_KEY).await {
+            self.telegram_chats.set(
+                ids.split(',')
+                    .filter_map(|s| s.trim().parse::<i64>().ok())
+                    .collect(),
+            );
+        }
+    }
+
+    /// Refresh the config-backed lists after a config reload.
+    pub fn sync_from_config(&self, config: &AppConfig) {
+        self.discord_guilds
+            .set(config.discord_allowed_guild_ids.clone());
+        self.discord_channels
+            .set(config.discord_allowed_channel_ids.clone());
+        self.slack_channels
+            .set(config.slack_allowed_channel_ids.clone());
+    }
+
+    pub fn list(&self, target: AllowlistTarget) -> Vec<String> {
+        fn strings<T: ToString + Clone + PartialEq>(list: &Allowlist<T>) -> Vec<String> {
+            list.to_vec().iter().map(T::to_string).collect()
+        }
+        match target {
+            AllowlistTarget::TelegramChats => strings(&self.telegram_chats),
+            AllowlistTarget::DiscordGuilds => strings(&self.discord_guilds),
+            AllowlistTarget::DiscordChannels => strings(&self.discord_channels),
+            AllowlistTarget::SlackChannels => self.slack_channels.to_vec(),
+        }
+    }
+
+    /// Validate and add an entry to the live list. Returns `false` if it was
+    /// already present.
+    pub fn add(&self, target: AllowlistTarget, entry: &str) -> Result<bool> {
+        let entry = entry.trim();
+        Ok(match target {
+            AllowlistTarget::TelegramChats => self.telegram_chats.insert(parse_id(target, entry)?),
+            AllowlistTarget::DiscordGuilds => self.discord_guilds.insert(parse_id(target, entry)?),
+            AllowlistTarget::DiscordChannels => {
+                self.discord_channels.insert(parse_id(target, entry)?)
+            }
+            AllowlistTarget::SlackChannels => {
+                if entry.is_empty() || !entry.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    return Err(ZeniiError::Validation(format!(
+                        "'{entry}' is not a valid Slack channel id (e.g. C0123ABCD)"
+                    )));
+                }
+                self.slack_channels.insert(entry.to_ascii_uppercase())
+            }
+        })
+    }
+
+    /// Remove an entry from the live list. Returns `false` if it was not
+    /// present.
+    pub fn remove(&self, target: AllowlistTarget, entry: &str) -> Result<bool> {
+        let entry = entry.trim();
+        Ok(match target {
+            AllowlistTarget::TelegramChats => self.telegram_chats.remove(&parse_id(target, entry)?),
+            AllowlistTarget::DiscordGuilds => self.discord_guilds.remove(&parse_id(target, entry)?),
+            AllowlistTarget::DiscordChannels => {
+                self.discord_channels.remove(&parse_id(target, entry)?)
+            }
+            AllowlistTarget::SlackChannels => {
+                self.slack_channels.remove(&entry.to_ascii_uppercase())
+            }
+        })
+    }
+
+    /// Whether removing `entry` would leave the list empty, which allows
+    /// everyone.
+    pub fn would_empty(&self, target: AllowlistTarget, entry: &str) -> Result<bool> {
+        let entry = entry.trim();
+        Ok(match target {
+            AllowlistTarget::TelegramChats => {
+                self.telegram_chats.to_vec() == [parse_id::<i64>(target, entry)?]
+            }
+            AllowlistTarget::DiscordGuilds => {
+                self.discord_guilds.to_vec() == [parse_id::<u64>(target, entry)?]
+            }
+            AllowlistTarget::DiscordChannels => {
+                self.discord_channels.to_vec() == [parse_id::<u64>(target, entry)?]
+            }
+            AllowlistTarget::SlackChannels => {
+                self.slack_channels.to_vec() == [entry.to_ascii_uppercase()]
+            }
+        })
+    }
+
+    /// Whether the list is persisted in the config file (as opposed to the
+    /// credential store).
+    pub fn is_config_backed(target: AllowlistTarget) -> bool {
+        target != AllowlistTarget::TelegramChats
+    }
+
+    /// Copy a config-backed list into `config` for saving.
+    pub fn write_to_config(&self, target: AllowlistTarget, config: &mut AppConfig) {
+        match target {
+            AllowlistTarget::TelegramChats => {}
+            AllowlistTarget::DiscordGuilds => {
+                config.discord_allowed_guild_ids = self.discord_guilds.to_vec();
+            }
+            AllowlistTarget::DiscordChannels => {
+                config.discord_allowed_channel_ids = self.discord_channels.to_vec();
+            }
+            AllowlistTarget::SlackChannels => {
+                config.slack_allowed_channel_ids = self.slack_channels.to_vec();
+            }
+        }
+    }
+
+    /// Save the Telegram list to the credential store.
+    pub async fn persist_telegram(&self, credentials: &dyn CredentialStore) -> Result<()> {
+        let ids = self.list(AllowlistTarget::TelegramChats);
+        if ids.is_empty() {
+            credentials.delete(TELEGRAM_ALLOWLIST_KEY).await?;
+        } else {
+            credentials
+                .set(TELEGRAM_ALLOWLIST_KEY, &ids.join(","))
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::InMemoryCredentialStore;
+
+    // AL.1 — Clones share the live list; empty allows all
+    #[test]
+    fn allowlist_is_shared() {
+        let list = Allowlist::<u64>::default();
+        let live = list.clone();
+        assert!(live.allows(&7));
+        assert!(list.insert(5));
+        assert!(!list.insert(5));
+        assert!(!live.allows(&7));
+        assert!(live.allows(&5));
+        assert!(list.remove(&5));
+        assert!(live.is_empty());
+    }
+
+    // AL.2 — Numeric ids are validated for Telegram and Discord
+    #[test]
+    fn add_validates_ids() {
+        let lists = ChannelAllowlists::default();
+        assert!(
+            lists
+                .add(AllowlistTarget::TelegramChats, "-100123")
+                .unwrap()
+        );
+        assert!(lists.add(AllowlistTarget::TelegramChats, "abc").is_err());
+        assert!(lists.add(AllowlistTarget::DiscordGuilds, "-1").is_err());
+        assert!(lists.add(AllowlistTarget::SlackChannels, "c0123").unwrap());
+        assert!(
+            lists
+                .add(AllowlistTarget::SlackChannels, "#general")
+                .is_err()
+        );
+        assert_eq!(lists.list(AllowlistTarget::SlackChannels), vec!["C0123"]);
+        assert!(AllowlistTarget::parse("irc").is_err());
+    }
+
+    // AL.4 — Removing the sole entry is reported as emptying the list
+    #[test]
+    fn would_empty_detects_last_entry() {
+        let lists = ChannelAllowlists::default();
+        lists.add(AllowlistTarget::SlackChannels, "C01").unwrap();
+        assert!(
+            lists
+                .would_empty(AllowlistTarget::SlackChannels, "c01")
+                .unwrap()
+        );
+        assert!(
+            !lists
+                .would_empty(AllowlistTarget::SlackChannels, "C02")
+                .unwrap()
+        );
+        lists.add(AllowlistTarget::SlackChannels, "C02").unwrap();
+        assert!(
+            !lists
+                .would_empty(AllowlistTarget::SlackChannels, "C01")
+                .unwrap()
+        );
+        assert!(
+            !lists
+                .would_empty(AllowlistTarget::DiscordGuilds, "1")
+                .unwrap()
+        );
+    }
+
+    // AL.3 — Telegram ids round-trip through the credential store
+    #[tokio::test]
+    async fn telegram_persists_to_credentials() {
+        let credentials = InMemoryCredentialStore::new();
+        let lists = ChannelAllowlists::default();
+        lists.add(AllowlistTarget::TelegramChats, "12345").unwrap();
+        lists.add(AllowlistTarget::TelegramChats, "678").unwrap();
+        lists.persist_telegram(&credentials).await.unwrap();
+
+        let loaded = ChannelAllowlists::default();
+        loaded.reload(&AppConfig::default(), &credentials).await;
+        assert_eq!(loaded.telegram_chats.to_vec(), vec![12345, 678]);
+
+        lists
+            .remove(AllowlistTarget::TelegramChats, "12345")
+            .unwrap();
+        lists.remove(AllowlistTarget::TelegramChats, "678").unwrap();
+        lists.persist_telegram(&credentials).await.unwrap();
+        assert_eq!(credentials.get(TELEGRAM_ALLOWLIST_KEY).await.unwrap(), None);
+    }
+}
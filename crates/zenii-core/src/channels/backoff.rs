@@ -0,0 +1,81 @@
+//! Reconnect backoff for long-lived channel connections (Slack Socket Mode,
+//! gateway WebSockets). Unlike the supervisor's restart backoff, this runs
+//! inside a channel's `listen()` loop, so a dropped socket is re-opened
+//! without tearing the channel down.
+
+use std::time::Duration;
+
+/// Exponential backoff with jitter, capped at a ceiling.
+#[derive(Debug, Clone)]
+pub struct ReconnectBackoff {
+    initial: Duration,
+    max: Duration,
+    attempt: u32,
+}
+
+impl ReconnectBackoff {
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self {
+            initial,
+            max: max.max(initial),
+            attempt: 0,
+        }
+    }
+
+    /// Failed attempts since the last [`reset`](Self::reset).
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Forget past failures once a connection is up again.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    /// Record a failed attempt and return how long to wait before the next.
+    /// The delay doubles per attempt up to the ceiling, and up to 25% of it is
+    /// random so clients cut off by the same outage don't retry in lockstep.
+    pub fn next_delay(&mut self) -> Duration {
+        let base = self
+            .initial
+            .saturating_mul(1u32 << self.attempt.min(16))
+            .min(self.max);
+        self.attempt = self.attempt.saturating_add(1);
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0);
+        base - base.mul_f64(f64::from(nanos % 1000) / 4000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RB.1 — Delay doubles per attempt, stays under the ceiling, and resets
+    #[test]
+    fn backoff_doubles_to_ceiling() {
+        let mut backoff = ReconnectBackoff::new(Duration::from_secs(1), Duration::from_secs(60));
+        for base in [1, 2, 4, 8, 16, 32, 60, 60] {
+            let delay = backoff.next_delay();
+            let base = Duration::from_secs(base);
+            assert!(delay <= base, "{delay:?} > {base:?}");
+            assert!(delay >= base.mul_f64(0.75), "{delay:?} too short");
+        }
+        assert_eq!(backoff.attempt(), 8);
+
+        backoff.reset();
+        assert_eq!(backoff.attempt(), 0);
+        assert!(backoff.next_delay() <= Duration::from_secs(1));
+    }
+
+    // RB.2 — Many attempts never overflow
+    #[test]
+    fn backoff_overflow_safe() {
+        let mut backoff = ReconnectBackoff::new(Duration::from_secs(1), Duration::from_secs(60));
+        for _ in 0..100 {
+            assert!(backoff.next_delay() <= Duration::from_secs(60));
+        }
+    }
+}
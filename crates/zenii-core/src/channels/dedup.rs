@@ -0,0 +1,122 @@
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+
+use lru::LruCache;
+use parking_lot::Mutex;
+
+use super::message::ChannelMessage;
+
+/// Metadata key carrying the platform's id for an inbound message.
+pub const MESSAGE_ID_KEY: &str = "message_id";
+
+const DEFAULT_TTL: Duration = Duration::from_secs(60);
+const DEFAULT_CAPACITY: usize = 4096;
+
+/// (channel, sender, message_id)
+type DedupKey = (String, String, String);
+
+/// Drops inbound messages already seen within a short TTL, keyed on
+/// `(channel, sender, message_id)`. Retried webhook deliveries and
+/// double-published events carry the same platform id, while distinct
+/// messages with identical text do not. Messages without a `message_id`
+/// in their metadata are never treated as duplicates.
+pub struct MessageDeduplicator {
+    seen: Mutex<LruCache<DedupKey, Instant>>,
+    ttl: Duration,
+}
+
+impl MessageDeduplicator {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            seen: Mutex::new(LruCache::new(
+                NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN),
+            )),
+            ttl,
+        }
+    }
+
+    /// Returns true if `message` was already seen within the TTL; otherwise
+    /// records it and returns false.
+    pub fn is_duplicate(&self, message: &ChannelMessage) -> bool {
+        let Some(message_id) = message
+            .metadata
+            .get(MESSAGE_ID_KEY)
+            .filter(|id| !id.is_empty())
+        else {
+            return false;
+        };
+        let key = (
+            message.channel.clone(),
+            message.sender.clone().unwrap_or_default(),
+            message_id.clone(),
+        );
+
+        let now = Instant::now();
+        let mut seen = self.seen.lock();
+        if let Some(first_seen) = seen.get(&key)
+            && now.duration_since(*first_seen) < self.ttl
+        {
+            return true;
+        }
+        seen.put(key, now);
+        false
+    }
+}
+
+impl Default for MessageDeduplicator {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY, DEFAULT_TTL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn msg(channel: &str, sender: &str, id: Option<&str>, text: &str) -> ChannelMessage {
+        let mut metadata = HashMap::new();
+        if let Some(id) = id {
+            metadata.insert(MESSAGE_ID_KEY.to_string(), id.to_string());
+        }
+        ChannelMessage::new(channel, text)
+            .with_sender(sender)
+            .with_metadata(metadata)
+    }
+
+    #[test]
+    fn repeated_id_is_duplicate() {
+        let dedup = MessageDeduplicator::default();
+        assert!(!dedup.is_duplicate(&msg("telegram", "alice", Some("42"), "hi")));
+        assert!(dedup.is_duplicate(&msg("telegram", "alice", Some("42"), "hi")));
+    }
+
+    #[test]
+    fn identical_text_with_distinct_ids_passes() {
+        let dedup = MessageDeduplicator::default();
+        assert!(!dedup.is_duplicate(&msg("telegram", "alice", Some("1"), "ok")));
+        assert!(!dedup.is_duplicate(&msg("telegram", "alice", Some("2"), "ok")));
+    }
+
+    #[test]
+    fn key_includes_channel_and_sender() {
+        let dedup = MessageDeduplicator::default();
+        assert!(!dedup.is_duplicate(&msg("telegram", "alice", Some("7"), "x")));
+        assert!(!dedup.is_duplicate(&msg("telegram", "bob", Some("7"), "x")));
+        assert!(!dedup.is_duplicate(&msg("slack", "alice", Some("7"), "x")));
+    }
+
+    #[test]
+    fn messages_without_id_are_never_dropped() {
+        let dedup = MessageDeduplicator::default();
+        assert!(!dedup.is_duplicate(&msg("discord", "alice", None, "same")));
+        assert!(!dedup.is_duplicate(&msg("discord", "alice", None, "same")));
+    }
+
+    #[test]
+    fn expired_entries_are_forgotten() {
+        let dedup = MessageDeduplicator::new(16, Duration::ZERO);
+        assert!(!dedup.is_duplicate(&msg("telegram", "alice", Some("9"), "hi")));
+        assert!(!dedup.is_duplicate(&msg("telegram", "alice", Some("9"), "hi")));
+    }
+}
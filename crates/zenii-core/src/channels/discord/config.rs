@@ -1,27 +1,59 @@
 use serde::{Deserialize, Serialize};
 
+use crate::channels::allowlist::Allowlist;
+
+/// Where the bot's slash commands are registered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SlashCommandScope {
+    /// Don't register; commands still work as plain text.
+    Off,
+    /// Application-wide. Discord can take up to an hour to propagate changes.
+    #[default]
+    Global,
+    /// Per guild the bot is in (filtered by `allowed_guild_ids`). Available at once.
+    Guild,
+}
+
+impl SlashCommandScope {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "off" => Some(Self::Off),
+            "global" => Some(Self::Global),
+            "guild" => Some(Self::Guild),
+            _ => None,
+        }
+    }
+}
+
 /// Discord-specific configuration.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DiscordConfig {
-    pub allowed_guild_ids: Vec<u64>,
-    pub allowed_channel_ids: Vec<u64>,
+    pub allowed_guild_ids: Allowlist<u64>,
+    pub allowed_channel_ids: Allowlist<u64>,
+    /// Inbound attachments larger than this are not downloaded.
+    pub max_attachment_bytes: u64,
+    pub slash_commands: SlashCommandScope,
 }
 
 impl DiscordConfig {
     /// Check if a guild is allowed (empty list = allow all).
     pub fn is_guild_allowed(&self, guild_id: u64) -> bool {
-        self.allowed_guild_ids.is_empty() || self.allowed_guild_ids.contains(&guild_id)
+        self.allowed_guild_ids.allows(&guild_id)
     }
 
     /// Check if a channel is allowed (empty list = allow all).
     pub fn is_channel_allowed(&self, channel_id: u64) -> bool {
-        self.allowed_channel_ids.is_empty() || self.allowed_channel_ids.contains(&channel_id)
+        self.allowed_channel_ids.allows(&channel_id)
     }
 
     pub fn from_app_config(config: &crate::config::AppConfig) -> Self {
         Self {
-            allowed_guild_ids: config.discord_allowed_guild_ids.clone(),
-            allowed_channel_ids: config.discord_allowed_channel_ids.clone(),
+            allowed_guild_ids: config.discord_allowed_guild_ids.clone().into(),
+            allowed_channel_ids: config.discord_allowed_channel_ids.clone().into(),
+            max_attachment_bytes: config.channel_attachment_max_mb * 1024 * 1024,
+            slash_commands: SlashCommandScope::parse(&config.discord_slash_commands)
+                .unwrap_or_default(),
         }
     }
 }
@@ -36,5 +68,20 @@ mod tests {
         let dc = DiscordConfig::from_app_config(&config);
         assert!(dc.allowed_guild_ids.is_empty());
         assert!(dc.allowed_channel_ids.is_empty());
+        assert_eq!(dc.max_attachment_bytes, 20 * 1024 * 1024);
+        assert_eq!(dc.slash_commands, SlashCommandScope::Global);
+    }
+
+    #[test]
+    fn slash_command_scope_parse() {
+        assert_eq!(
+            SlashCommandScope::parse("off"),
+            Some(SlashCommandScope::Off)
+        );
+        assert_eq!(
+            SlashCommandScope::parse("guild"),
+            Some(SlashCommandScope::Guild)
+        );
+        assert_eq!(SlashCommandScope::parse("everywhere"), None);
     }
 }
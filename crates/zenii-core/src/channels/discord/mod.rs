@@ -5,18 +5,22 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU8, Ordering};
 
 use async_trait::async_trait;
-use serenity::all::{ChannelId, Context, EventHandler, GatewayIntents, Message, Ready};
+use serenity::all::{
+    ApplicationId, ChannelId, Command, CommandInteraction, Context, CreateAttachment,
+    CreateCommand, CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage,
+    EditInteractionResponse, EventHandler, GatewayIntents, Interaction, Message, Ready,
+};
 use tokio::sync::{mpsc, watch};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 use crate::Result;
 use crate::credential::CredentialStore;
 use crate::error::ZeniiError;
 
-use super::message::ChannelMessage;
+use super::message::{ChannelAttachment, ChannelMessage};
 use super::traits::{Channel, ChannelLifecycle, ChannelSender, ChannelStatus};
 
-use config::DiscordConfig;
+use config::{DiscordConfig, SlashCommandScope};
 
 // Status values
 const STATUS_DISCONNECTED: u8 = 0;
@@ -29,6 +33,18 @@ pub const REQUIRED_INTENTS: u64 = (1 << 9) | (1 << 12) | (1 << 15);
 // GatewayIntents::DIRECT_MESSAGES (1 << 12) = 4096
 // GatewayIntents::MESSAGE_CONTENT (1 << 15) = 32768
 
+/// Slash commands registered on `ready`: (name, description).
+pub const SLASH_COMMANDS: &[(&str, &str)] = &[
+    ("new", "Start a new conversation"),
+    ("status", "Check bot status"),
+    ("cancel", "Cancel current operation"),
+];
+
+/// Metadata keys that route a reply to a deferred interaction response
+/// instead of a new channel message.
+const INTERACTION_TOKEN_KEY: &str = "interaction_token";
+const APPLICATION_ID_KEY: &str = "application_id";
+
 /// Discord channel implementation using serenity.
 pub struct DiscordChannel {
     config: DiscordConfig,
@@ -103,7 +119,7 @@ impl ChannelSender for DiscordChannel {
             })?;
             vec![id]
         } else if !self.config.allowed_channel_ids.is_empty() {
-            self.config.allowed_channel_ids.clone()
+            self.config.allowed_channel_ids.to_vec()
         } else {
             return Err(ZeniiError::Channel(
                 "discord: no channel_id in metadata and no allowed_channel_ids configured".into(),
@@ -111,10 +127,7 @@ impl ChannelSender for DiscordChannel {
         };
 
         for &cid in &channel_ids {
-            ChannelId::new(cid)
-                .say(http.as_ref(), &message.content)
-                .await
-                .map_err(|e| ZeniiError::Channel(format!("discord send failed: {e}")))?;
+            send_to_channel(http, cid, &message).await?;
         }
 
         Ok(())
@@ -173,6 +186,127 @@ impl ChannelLifecycle for DiscordChannel {
     }
 }
 
+/// The (application id, token) of the deferred interaction a message answers.
+fn interaction_target(metadata: &HashMap<String, String>) -> Option<(u64, &str)> {
+    let token = metadata.get(INTERACTION_TOKEN_KEY)?;
+    let application_id = metadata.get(APPLICATION_ID_KEY)?.parse().ok()?;
+    Some((application_id, token.as_str()))
+}
+
+/// Send the message text (if any) and its attachments as one Discord message.
+/// A reply to a slash command fills in its deferred response instead.
+async fn send_to_channel(
+    http: &serenity::http::Http,
+    channel_id: u64,
+    message: &ChannelMessage,
+) -> Result<()> {
+    if let Some((application_id, token)) = interaction_target(&message.metadata) {
+        return send_interaction_reply(http, application_id, token, message).await;
+    }
+
+    let mut builder = CreateMessage::new();
+    if !message.content.is_empty() {
+        builder = builder.content(message.content.clone());
+    }
+    for attachment in &message.attachments {
+        let data = attachment.bytes().await.map_err(|e| {
+            ZeniiError::Channel(format!(
+                "discord: failed to read attachment {}: {e}",
+                attachment.file_name
+            ))
+        })?;
+        builder = builder.add_file(CreateAttachment::bytes(data, attachment.file_name.clone()));
+    }
+
+    ChannelId::new(channel_id)
+        .send_message(http, builder)
+        .await
+        .map_err(|e| ZeniiError::Channel(format!("discord send failed: {e}")))?;
+    Ok(())
+}
+
+async fn send_interaction_reply(
+    http: &serenity::http::Http,
+    application_id: u64,
+    token: &str,
+    message: &ChannelMessage,
+) -> Result<()> {
+    let mut builder = EditInteractionResponse::new();
+    if !message.content.is_empty() {
+        builder = builder.content(message.content.clone());
+    }
+    let mut files = Vec::with_capacity(message.attachments.len());
+    for attachment in &message.attachments {
+        let data = attachment.bytes().await.map_err(|e| {
+            ZeniiError::Channel(format!(
+                "discord: failed to read attachment {}: {e}",
+                attachment.file_name
+            ))
+        })?;
+        files.push(CreateAttachment::bytes(data, attachment.file_name.clone()));
+    }
+
+    http.set_application_id(ApplicationId::new(application_id));
+    http.edit_original_interaction_response(token, &builder, files)
+        .await
+        .map_err(|e| ZeniiError::Channel(format!("discord interaction reply failed: {e}")))?;
+    Ok(())
+}
+
+/// Register [`SLASH_COMMANDS`] in the configured scope and clear them from the
+/// other, so switching scopes doesn't leave duplicates behind.
+async fn register_slash_commands(
+    http: &serenity::http::Http,
+    config: &DiscordConfig,
+    ready: &Ready,
+) {
+    let commands = || -> Vec<CreateCommand> {
+        SLASH_COMMANDS
+            .iter()
+            .map(|(name, description)| CreateCommand::new(*name).description(*description))
+            .collect()
+    };
+    let (global, guild) = match config.slash_commands {
+        SlashCommandScope::Off => (vec![], vec![]),
+        SlashCommandScope::Global => (commands(), vec![]),
+        SlashCommandScope::Guild => (vec![], commands()),
+    };
+
+    if let Err(e) = Command::set_global_commands(http, global).await {
+        warn!("Discord: failed to register global slash commands: {e}");
+    }
+    for guild_id in ready
+        .guilds
+        .iter()
+        .map(|g| g.id)
+        .filter(|id| config.is_guild_allowed(id.get()))
+    {
+        if let Err(e) = guild_id.set_commands(http, guild.clone()).await {
+            warn!(
+                "Discord: failed to register slash commands in guild {}: {e}",
+                guild_id.get()
+            );
+        }
+    }
+    info!(
+        "Discord slash commands registered ({:?})",
+        config.slash_commands
+    );
+}
+
+/// Answer a slash command immediately with an ephemeral message.
+async fn reply_ephemeral(ctx: &Context, command: &CommandInteraction, text: &str) -> Result<()> {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(text)
+            .ephemeral(true),
+    );
+    command
+        .create_response(ctx, response)
+        .await
+        .map_err(|e| ZeniiError::Channel(format!("discord: interaction response failed: {e}")))
+}
+
 /// Internal event handler for serenity gateway.
 struct ZeniiHandler {
     tx: mpsc::Sender<ChannelMessage>,
@@ -207,28 +341,115 @@ impl EventHandler for ZeniiHandler {
         }
 
         let content = msg.content.clone();
-        if content.is_empty() {
+        if content.is_empty() && msg.attachments.is_empty() {
             return;
         }
 
         let sender_name = msg.author.name.clone();
         let mut metadata = HashMap::new();
         metadata.insert("channel_id".into(), channel_id.to_string());
+        metadata.insert("message_id".into(), msg.id.get().to_string());
         if let Some(guild_id) = msg.guild_id {
             metadata.insert("guild_id".into(), guild_id.get().to_string());
         }
 
-        let channel_msg = ChannelMessage::new("discord", &content)
+        let mut channel_msg = ChannelMessage::new("discord", &content)
             .with_sender(&sender_name)
             .with_metadata(metadata);
 
+        for attachment in &msg.attachments {
+            if u64::from(attachment.size) > self.config.max_attachment_bytes {
+                warn!(
+                    "Discord: skipping attachment {} of {} bytes (limit {})",
+                    attachment.filename, attachment.size, self.config.max_attachment_bytes
+                );
+                continue;
+            }
+            match attachment.download().await {
+                Ok(data) => {
+                    channel_msg = channel_msg.with_attachment(ChannelAttachment::from_bytes(
+                        &attachment.filename,
+                        attachment.content_type.clone(),
+                        data,
+                    ));
+                }
+                Err(e) => warn!(
+                    "Discord: failed to download attachment {}: {e}",
+                    attachment.filename
+                ),
+            }
+        }
+        if content.is_empty() && channel_msg.attachments.is_empty() {
+            return;
+        }
+
         if let Err(e) = self.tx.send(channel_msg).await {
             error!("Discord: failed to send to router: {e}");
         }
     }
 
-    async fn ready(&self, _ctx: Context, ready: Ready) {
+    async fn ready(&self, ctx: Context, ready: Ready) {
         info!("Discord bot ready: {}", ready.user.name);
+        register_slash_commands(&ctx.http, &self.config, &ready).await;
+    }
+
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        let Interaction::Command(command) = interaction else {
+            return;
+        };
+        let channel_id = command.channel_id.get();
+        let allowed = command
+            .guild_id
+            .is_none_or(|g| self.config.is_guild_allowed(g.get()))
+            && self.config.is_channel_allowed(channel_id);
+
+        // Discord drops interactions not answered within 3 seconds, so every
+        // branch responds (or defers) before doing anything slow
+        let result = match command.data.name.as_str() {
+            _ if !allowed => {
+                debug!("Discord: refusing slash command from disallowed channel {channel_id}");
+                reply_ephemeral(&ctx, &command, "Commands are not enabled in this channel.").await
+            }
+            "status" => {
+                reply_ephemeral(
+                    &ctx,
+                    &command,
+                    &format!("Status: {}", ChannelStatus::Connected),
+                )
+                .await
+            }
+            "cancel" => reply_ephemeral(&ctx, &command, "Operation cancelled.").await,
+            "new" => match command.defer(&ctx).await {
+                // The router's reply fills in the deferred response
+                Ok(()) => {
+                    let mut metadata = HashMap::new();
+                    metadata.insert("channel_id".into(), channel_id.to_string());
+                    if let Some(guild_id) = command.guild_id {
+                        metadata.insert("guild_id".into(), guild_id.get().to_string());
+                    }
+                    metadata.insert(INTERACTION_TOKEN_KEY.into(), command.token.clone());
+                    metadata.insert(
+                        APPLICATION_ID_KEY.into(),
+                        command.application_id.get().to_string(),
+                    );
+                    let msg = ChannelMessage::new("discord", super::router::NEW_SESSION_COMMAND)
+                        .with_sender(&command.user.name)
+                        .with_metadata(metadata);
+                    if let Err(e) = self.tx.send(msg).await {
+                        error!("Discord: failed to send to router: {e}");
+                    }
+                    Ok(())
+                }
+                Err(e) => Err(ZeniiError::Channel(format!("discord: defer failed: {e}"))),
+            },
+            other => {
+                debug!("Discord: ignoring unknown slash command /{other}");
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            warn!("Discord: failed to answer /{}: {e}", command.data.name);
+        }
     }
 }
 
@@ -294,15 +515,22 @@ impl Channel for DiscordChannel {
         }
     }
 
-    async fn on_agent_start(&self, recipient: Option<&str>) {
-        if let Some(http) = self.http.get()
-            && let Some(channel_id_str) = recipient
-            && let Ok(channel_id) = channel_id_str.parse::<u64>()
-        {
-            let _ = ChannelId::new(channel_id)
-                .broadcast_typing(http.as_ref())
-                .await;
+    async fn set_typing(&self, recipient: &str, active: bool) -> Result<()> {
+        // Typing stops automatically when the bot sends a message
+        if !active {
+            return Ok(());
         }
+        let http = self
+            .http
+            .get()
+            .ok_or_else(|| ZeniiError::Channel("discord: not connected".into()))?;
+        let channel_id = recipient
+            .parse::<u64>()
+            .map_err(|_| ZeniiError::Channel(format!("discord: invalid channel_id {recipient}")))?;
+        ChannelId::new(channel_id)
+            .broadcast_typing(http.as_ref())
+            .await
+            .map_err(|e| ZeniiError::Channel(format!("discord: trigger_typing failed: {e}")))
     }
 
     async fn on_tool_use(&self, _tool_name: &str, recipient: Option<&str>) {
@@ -348,12 +576,7 @@ impl ChannelSender for DiscordSender {
             ZeniiError::Channel(format!("discord: invalid channel_id: {channel_id_str}"))
         })?;
 
-        ChannelId::new(channel_id)
-            .say(http.as_ref(), &message.content)
-            .await
-            .map_err(|e| ZeniiError::Channel(format!("discord send failed: {e}")))?;
-
-        Ok(())
+        send_to_channel(http, channel_id, &message).await
     }
 }
 
@@ -369,8 +592,10 @@ mod tests {
 
     fn test_config() -> DiscordConfig {
         DiscordConfig {
-            allowed_guild_ids: vec![111, 222],
-            allowed_channel_ids: vec![333, 444],
+            allowed_guild_ids: vec![111, 222].into(),
+            allowed_channel_ids: vec![333, 444].into(),
+            max_attachment_bytes: 1024,
+            slash_commands: SlashCommandScope::Guild,
         }
     }
 
@@ -381,6 +606,14 @@ mod tests {
         ch.on_agent_start(Some("user1")).await;
     }
 
+    // Typing needs a connection; clearing it is always a no-op
+    #[tokio::test]
+    async fn discord_set_typing_requires_connection() {
+        let ch = DiscordChannel::new(test_config(), test_credentials());
+        assert!(ch.set_typing("333", true).await.is_err());
+        assert!(ch.set_typing("333", false).await.is_ok());
+    }
+
     // 8.8.9 — Discord on_agent_complete is no-op (typing auto-stops)
     #[tokio::test]
     async fn discord_on_agent_complete() {
@@ -470,4 +703,24 @@ mod tests {
         assert_eq!(ch.status(), ChannelStatus::Disconnected);
         assert!(*ch.shutdown_rx.borrow());
     }
+
+    #[test]
+    fn slash_commands_cover_channel_commands() {
+        let names: Vec<_> = SLASH_COMMANDS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["new", "status", "cancel"]);
+    }
+
+    // Replies carrying an interaction token go to the deferred response
+    #[test]
+    fn interaction_target_requires_token_and_application() {
+        let mut metadata = HashMap::new();
+        metadata.insert("channel_id".to_string(), "333".to_string());
+        assert_eq!(interaction_target(&metadata), None);
+
+        metadata.insert(INTERACTION_TOKEN_KEY.to_string(), "tok".to_string());
+        assert_eq!(interaction_target(&metadata), None);
+
+        metadata.insert(APPLICATION_ID_KEY.to_string(), "42".to_string());
+        assert_eq!(interaction_target(&metadata), Some((42, "tok")));
+    }
 }
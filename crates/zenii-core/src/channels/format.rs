@@ -8,6 +8,12 @@ pub trait ChannelFormatter: Send + Sync {
     fn max_length(&self) -> usize;
 }
 
+/// Per-channel maximum message lengths (bytes, conservative for UTF-8).
+pub const TELEGRAM_MAX_LENGTH: usize = 4096;
+pub const SLACK_MAX_LENGTH: usize = 3000;
+pub const DISCORD_MAX_LENGTH: usize = 2000;
+pub const WHATSAPP_MAX_LENGTH: usize = 4096;
+
 /// Telegram formatter: markdown → HTML conversion, 4096 char limit.
 #[cfg(feature = "channels-telegram")]
 pub struct TelegramFormatter;
@@ -20,7 +26,7 @@ impl ChannelFormatter for TelegramFormatter {
     }
 
     fn max_length(&self) -> usize {
-        4096
+        TELEGRAM_MAX_LENGTH
     }
 }
 
@@ -36,7 +42,7 @@ impl ChannelFormatter for SlackFormatter {
     }
 
     fn max_length(&self) -> usize {
-        3000
+        SLACK_MAX_LENGTH
     }
 }
 
@@ -51,7 +57,22 @@ impl ChannelFormatter for DiscordFormatter {
     }
 
     fn max_length(&self) -> usize {
-        2000
+        DISCORD_MAX_LENGTH
+    }
+}
+
+/// WhatsApp formatter: keeps markdown, 4096 char limit.
+#[cfg(feature = "channels-whatsapp")]
+pub struct WhatsAppFormatter;
+
+#[cfg(feature = "channels-whatsapp")]
+impl ChannelFormatter for WhatsAppFormatter {
+    fn format(&self, markdown: &str) -> Vec<String> {
+        split_message(markdown, self.max_length())
+    }
+
+    fn max_length(&self) -> usize {
+        WHATSAPP_MAX_LENGTH
     }
 }
 
@@ -77,12 +98,31 @@ pub fn formatter_for(channel_name: &str) -> Box<dyn ChannelFormatter> {
         "slack" => Box::new(SlackFormatter),
         #[cfg(feature = "channels-discord")]
         "discord" => Box::new(DiscordFormatter),
+        #[cfg(feature = "channels-whatsapp")]
+        "whatsapp" => Box::new(WhatsAppFormatter),
         _ => Box::new(DefaultFormatter),
     }
 }
 
+/// Maximum message length for a channel type, as enforced by `ChannelRegistry::send`.
+pub fn max_length_for(channel_type: &str) -> usize {
+    match channel_type {
+        "telegram" => TELEGRAM_MAX_LENGTH,
+        "slack" => SLACK_MAX_LENGTH,
+        "discord" => DISCORD_MAX_LENGTH,
+        "whatsapp" => WHATSAPP_MAX_LENGTH,
+        _ => usize::MAX,
+    }
+}
+
+/// Closing fence appended to a chunk that ends inside a code block.
+const FENCE_CLOSE: &str = "\n```";
+
 /// Split a message into chunks respecting a max byte length.
-/// Prefers paragraph boundaries > newlines > hard cut.
+/// Prefers paragraph boundaries > newlines > word boundaries > hard cut.
+/// Never leaves a code fence open across chunks: the split moves before the
+/// fence when possible, otherwise the fence is closed and reopened (same
+/// language tag) in the next chunk.
 /// Safe for multi-byte UTF-8: rounds down to the nearest char boundary.
 pub fn split_message(text: &str, max_length: usize) -> Vec<String> {
     if text.len() <= max_length {
@@ -91,27 +131,82 @@ pub fn split_message(text: &str, max_length: usize) -> Vec<String> {
 
     let mut parts = Vec::new();
     let mut remaining = text;
+    // Opener line (e.g. "```rust") to repeat at the start of the next chunk
+    let mut reopen: Option<&str> = None;
 
     while !remaining.is_empty() {
-        if remaining.len() <= max_length {
-            parts.push(remaining.to_string());
+        let prefix_len = reopen.map_or(0, |o| o.len() + 1);
+        // Fence handling needs room for the reopen prefix, the close marker and
+        // some content; fall back to plain splitting for tiny limits.
+        let fence_aware = max_length > prefix_len + FENCE_CLOSE.len() * 2;
+        if !fence_aware {
+            reopen = None;
+        }
+        let prefix = match reopen {
+            Some(opener) if fence_aware => format!("{opener}\n"),
+            _ => String::new(),
+        };
+        let budget = max_length - prefix.len();
+
+        if remaining.len() <= budget {
+            parts.push(format!("{prefix}{remaining}"));
             break;
         }
 
-        let end = remaining.len().min(max_length);
-        // Round down to the nearest char boundary to avoid splitting
-        // multi-byte UTF-8 sequences (emoji, CJK, etc.)
-        let end = floor_char_boundary(remaining, end);
-        let chunk = &remaining[..end];
-        let split_pos = find_split_point(chunk);
+        let end = floor_char_boundary(remaining, budget);
+        let mut split_pos = find_split_point(&remaining[..end]);
+        let mut close = false;
+
+        if fence_aware {
+            let (open, opener) = fence_state(&remaining[..split_pos], reopen);
+            if let Some((offset, line)) = opener.filter(|_| open) {
+                if offset > 0 {
+                    // Move the whole code block to the next chunk
+                    split_pos = offset;
+                } else {
+                    // Block starts this chunk and is too long: close and reopen it
+                    let end = floor_char_boundary(remaining, budget - FENCE_CLOSE.len());
+                    split_pos = find_split_point(&remaining[..end]);
+                    close = true;
+                    reopen = Some(line);
+                }
+            }
+        }
 
-        parts.push(remaining[..split_pos].to_string());
-        remaining = remaining[split_pos..].trim_start();
+        let chunk = remaining[..split_pos].trim_end();
+        if close {
+            parts.push(format!("{prefix}{chunk}{FENCE_CLOSE}"));
+            // Keep indentation inside code: only drop the newline we split on
+            remaining = remaining[split_pos..].trim_start_matches('\n');
+        } else {
+            parts.push(format!("{prefix}{chunk}"));
+            reopen = None;
+            remaining = remaining[split_pos..].trim_start();
+        }
     }
 
     parts
 }
 
+/// Track code fences through `chunk`. Returns whether a fence is still open at
+/// the end, plus the byte offset (0 when carried over from the previous chunk)
+/// and line of the fence that opened it.
+fn fence_state<'a>(chunk: &'a str, carried: Option<&'a str>) -> (bool, Option<(usize, &'a str)>) {
+    let mut opener = carried.map(|line| (0, line));
+    let mut offset = 0;
+    for line in chunk.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            opener = match opener {
+                Some(_) => None,
+                None => Some((offset, trimmed)),
+            };
+        }
+        offset += line.len();
+    }
+    (opener.is_some(), opener)
+}
+
 /// Find the largest byte index <= `index` that is a valid char boundary.
 /// Equivalent to `str::floor_char_boundary` (stabilized in Rust 1.82,
 /// but unavailable with edition 2024 as of Rust 1.89).
@@ -274,4 +369,57 @@ mod tests {
             assert!(chunk.len() <= 4096);
         }
     }
+
+    // Code fences are never split: the block moves to the next chunk
+    #[test]
+    fn split_moves_code_block_to_next_chunk() {
+        let prose = "intro ".repeat(20);
+        let code = format!("```rust\n{}```", "let x = 1;\n".repeat(10));
+        let text = format!("{prose}\n{code}\nafter");
+        let parts = split_message(&text, 150);
+        assert!(parts.len() >= 2);
+        for part in &parts {
+            assert!(part.len() <= 150);
+            assert_eq!(
+                part.matches("```").count() % 2,
+                0,
+                "unbalanced fence: {part}"
+            );
+        }
+        assert!(parts.iter().any(|p| p.starts_with("```rust")));
+    }
+
+    // A code block longer than the limit is closed and reopened with its language tag
+    #[test]
+    fn split_reopens_long_code_block() {
+        let text = format!("```python\n{}```", "print('hello world')\n".repeat(40));
+        let parts = split_message(&text, 200);
+        assert!(parts.len() >= 2);
+        for part in &parts {
+            assert!(part.len() <= 200);
+            assert!(part.starts_with("```python\n"));
+            assert!(part.ends_with("```"));
+        }
+        let lines: usize = parts.iter().map(|p| p.matches("print(").count()).sum();
+        assert_eq!(lines, 40);
+    }
+
+    // Prose splits on word boundaries and keeps every word in order
+    #[test]
+    fn split_on_word_boundaries_preserves_order() {
+        let words: Vec<String> = (0..300).map(|i| format!("w{i}")).collect();
+        let text = words.join(" ");
+        let parts = split_message(&text, 100);
+        let rejoined: Vec<&str> = parts.iter().flat_map(|p| p.split(' ')).collect();
+        assert_eq!(rejoined, words);
+    }
+
+    #[test]
+    fn max_length_per_channel() {
+        assert_eq!(max_length_for("telegram"), 4096);
+        assert_eq!(max_length_for("discord"), 2000);
+        assert_eq!(max_length_for("slack"), 3000);
+        assert_eq!(max_length_for("whatsapp"), 4096);
+        assert_eq!(max_length_for("custom"), usize::MAX);
+    }
 }
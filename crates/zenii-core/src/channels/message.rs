@@ -1,7 +1,105 @@
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+/// Kind of media attached to a channel message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AttachmentKind {
+    Audio,
+    Image,
+    Video,
+    File,
+}
+
+impl AttachmentKind {
+    /// Classify a MIME type; anything that is not audio, image or video is a `File`.
+    pub fn from_mime(mime_type: Option<&str>) -> Self {
+        match mime_type.and_then(|m| m.split('/').next()) {
+            Some("audio") => Self::Audio,
+            Some("image") => Self::Image,
+            Some("video") => Self::Video,
+            _ => Self::File,
+        }
+    }
+}
+
+/// Binary media received alongside (or instead of) message text, or sent
+/// with an outbound message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelAttachment {
+    pub kind: AttachmentKind,
+    pub file_name: String,
+    pub mime_type: Option<String>,
+    /// Raw bytes, downloaded by the channel. Not serialized.
+    #[serde(skip)]
+    pub data: Vec<u8>,
+    /// File on disk holding the content, used when `data` is empty.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+impl ChannelAttachment {
+    /// Attachment backed by in-memory bytes.
+    pub fn from_bytes(file_name: &str, mime_type: Option<String>, data: Vec<u8>) -> Self {
+        Self {
+            kind: AttachmentKind::from_mime(mime_type.as_deref()),
+            file_name: file_name.to_string(),
+            mime_type,
+            data,
+            path: None,
+        }
+    }
+
+    /// MIME type guessed from a file extension, for files the agent attaches.
+    pub fn mime_for_path(path: &std::path::Path) -> Option<String> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        let mime = match ext.as_str() {
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            "gif" => "image/gif",
+            "webp" => "image/webp",
+            "mp4" => "video/mp4",
+            "mov" => "video/quicktime",
+            "webm" => "video/webm",
+            "mp3" => "audio/mpeg",
+            "ogg" | "oga" => "audio/ogg",
+            "wav" => "audio/wav",
+            "pdf" => "application/pdf",
+            "json" => "application/json",
+            "csv" => "text/csv",
+            "txt" | "md" | "log" => "text/plain",
+            _ => return None,
+        };
+        Some(mime.to_string())
+    }
+
+    /// Attachment backed by a file on disk; the name is taken from the path.
+    pub fn from_path(path: PathBuf, mime_type: Option<String>) -> Self {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "attachment".into());
+        Self {
+            kind: AttachmentKind::from_mime(mime_type.as_deref()),
+            file_name,
+            mime_type,
+            data: Vec::new(),
+            path: Some(path),
+        }
+    }
+
+    /// The attachment content: `data` if present, else the contents of `path`.
+    pub async fn bytes(&self) -> std::io::Result<Vec<u8>> {
+        match &self.path {
+            Some(path) if self.data.is_empty() => tokio::fs::read(path).await,
+            _ => Ok(self.data.clone()),
+        }
+    }
+}
+
 /// A message flowing through a channel.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChannelMessage {
@@ -10,6 +108,8 @@ pub struct ChannelMessage {
     pub content: String,
     pub timestamp: String,
     pub metadata: HashMap<String, String>,
+    #[serde(default)]
+    pub attachments: Vec<ChannelAttachment>,
 }
 
 impl ChannelMessage {
@@ -20,6 +120,7 @@ impl ChannelMessage {
             content: content.to_string(),
             timestamp: chrono::Utc::now().to_rfc3339(),
             metadata: HashMap::new(),
+            attachments: Vec::new(),
         }
     }
 
@@ -32,12 +133,44 @@ impl ChannelMessage {
         self.metadata = metadata;
         self
     }
+
+    /// Address the message to one chat or channel. Telegram and WhatsApp
+    /// read the target from `chat_id`, Slack and Discord from `channel_id`.
+    pub fn with_recipient(mut self, recipient: &str) -> Self {
+        let key = match self.channel.as_str() {
+            "telegram" | "whatsapp" => "chat_id",
+            _ => "channel_id",
+        };
+        self.metadata.insert(key.into(), recipient.to_string());
+        self
+    }
+
+    pub fn with_attachment(mut self, attachment: ChannelAttachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    /// Whether the message carries any audio attachments.
+    pub fn has_audio(&self) -> bool {
+        self.attachments
+            .iter()
+            .any(|a| a.kind == AttachmentKind::Audio)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn with_recipient_uses_channel_key() {
+        let msg = ChannelMessage::new("telegram", "hi").with_recipient("123");
+        assert_eq!(msg.metadata.get("chat_id").unwrap(), "123");
+        let msg = ChannelMessage::new("slack", "hi").with_recipient("C456");
+        assert_eq!(msg.metadata.get("channel_id").unwrap(), "C456");
+        assert!(!msg.metadata.contains_key("chat_id"));
+    }
+
     #[test]
     fn message_serde() {
         let msg = ChannelMessage::new("telegram", "hello world");
@@ -64,4 +197,67 @@ mod tests {
         assert_eq!(msg.metadata.get("channel_id").unwrap(), "C456");
         assert_eq!(msg.metadata.len(), 2);
     }
+
+    #[test]
+    fn message_audio_attachment() {
+        let msg = ChannelMessage::new("telegram", "");
+        assert!(!msg.has_audio());
+        let msg = msg.with_attachment(ChannelAttachment {
+            kind: AttachmentKind::Audio,
+            file_name: "voice.ogg".into(),
+            mime_type: Some("audio/ogg".into()),
+            data: vec![1, 2, 3],
+            path: None,
+        });
+        assert!(msg.has_audio());
+
+        // Attachment bytes are not serialized; old payloads without attachments still parse
+        let json = serde_json::to_value(&msg).unwrap();
+        assert!(json["attachments"][0].get("data").is_none());
+        let parsed: ChannelMessage = serde_json::from_str(
+            r#"{"channel":"slack","sender":null,"content":"hi","timestamp":"t","metadata":{}}"#,
+        )
+        .unwrap();
+        assert!(parsed.attachments.is_empty());
+    }
+
+    #[test]
+    fn attachment_kind_from_mime() {
+        assert_eq!(
+            AttachmentKind::from_mime(Some("image/png")),
+            AttachmentKind::Image
+        );
+        assert_eq!(
+            AttachmentKind::from_mime(Some("video/mp4")),
+            AttachmentKind::Video
+        );
+        assert_eq!(
+            AttachmentKind::from_mime(Some("audio/ogg")),
+            AttachmentKind::Audio
+        );
+        assert_eq!(
+            AttachmentKind::from_mime(Some("application/pdf")),
+            AttachmentKind::File
+        );
+        assert_eq!(AttachmentKind::from_mime(None), AttachmentKind::File);
+    }
+
+    #[tokio::test]
+    async fn attachment_bytes_prefers_data_then_path() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("report.pdf");
+        std::fs::write(&path, b"from disk").unwrap();
+
+        let mime = ChannelAttachment::mime_for_path(&path);
+        assert_eq!(mime.as_deref(), Some("application/pdf"));
+        let on_disk = ChannelAttachment::from_path(path, mime);
+        assert_eq!(on_disk.file_name, "report.pdf");
+        assert_eq!(on_disk.kind, AttachmentKind::File);
+        assert_eq!(on_disk.bytes().await.unwrap(), b"from disk");
+
+        let in_memory =
+            ChannelAttachment::from_bytes("photo.jpg", Some("image/jpeg".into()), vec![7, 8]);
+        assert_eq!(in_memory.kind, AttachmentKind::Image);
+        assert_eq!(in_memory.bytes().await.unwrap(), vec![7, 8]);
+    }
 }
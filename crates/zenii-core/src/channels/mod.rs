@@ -1,11 +1,18 @@
+pub mod allowlist;
+pub mod backoff;
 pub mod contacts;
+pub mod dedup;
 pub mod format;
 pub mod message;
+mod pacing;
 pub mod policy;
 pub mod protocol;
 pub mod registry;
 pub mod router;
 pub mod session_map;
+pub mod settings;
+#[cfg(any(feature = "channels-slack", feature = "channels-whatsapp"))]
+pub mod signature;
 pub mod traits;
 
 #[cfg(feature = "channels-telegram")]
@@ -16,3 +23,6 @@ pub mod slack;
 
 #[cfg(feature = "channels-discord")]
 pub mod discord;
+
+#[cfg(feature = "channels-whatsapp")]
+pub mod whatsapp;
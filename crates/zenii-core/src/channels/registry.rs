@@ -1,12 +1,25 @@
 use std::sync::Arc;
+use std::time::Duration;
 
+use arc_swap::ArcSwap;
 use dashmap::DashMap;
 
+use super::allowlist::ChannelAllowlists;
+use super::dedup::MessageDeduplicator;
+use super::format::{max_length_for, split_message};
 use super::message::ChannelMessage;
+use super::pacing::SendPacer;
 use super::traits::{Channel, ChannelSender, ChannelStatus};
 use crate::Result;
+use crate::config::AppConfig;
 use crate::error::ZeniiError;
 
+/// Wait before retrying a rate-limited send when the platform gave no `Retry-After`.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Longest platform-requested wait honored before giving up on a send.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
 /// Combined entry holding both the full channel and its send-only handle.
 struct ChannelEntry {
     channel: Arc<dyn Channel>,
@@ -16,15 +29,41 @@ struct ChannelEntry {
 /// Concurrent channel registry (single DashMap-backed, atomic register/unregister).
 pub struct ChannelRegistry {
     entries: DashMap<String, ChannelEntry>,
+    dedup: MessageDeduplicator,
+    pacer: SendPacer,
+    allowlists: ChannelAllowlists,
 }
 
 impl ChannelRegistry {
+    /// Registry without outbound pacing.
     pub fn new() -> Self {
         Self {
             entries: DashMap::new(),
+            dedup: MessageDeduplicator::default(),
+            pacer: SendPacer::new(None),
+            allowlists: ChannelAllowlists::default(),
+        }
+    }
+
+    /// Registry that paces sends per `channel_send_rate_per_min` in the live config.
+    pub fn with_config(config: Arc<ArcSwap<AppConfig>>) -> Self {
+        Self {
+            pacer: SendPacer::new(Some(config)),
+            ..Self::new()
         }
     }
 
+    /// Allowlists shared with the channels, editable at runtime.
+    pub fn allowlists(&self) -> &ChannelAllowlists {
+        &self.allowlists
+    }
+
+    /// True if this inbound message was already seen recently (same channel,
+    /// sender and platform `message_id`). Records it otherwise.
+    pub fn is_duplicate_inbound(&self, message: &ChannelMessage) -> bool {
+        self.dedup.is_duplicate(message)
+    }
+
     /// Register a channel. Creates a sender handle automatically.
     /// Uses `entry()` API for atomic insert (no TOCTOU).
     pub fn register(&self, channel: Arc<dyn Channel>) -> Result<()> {
@@ -125,10 +164,56 @@ impl ChannelRegistry {
     /// Send a message through a named channel.
     /// Routes through the full Channel object (not the lightweight Sender)
     /// so that connected channels can use their active bot handles.
-    pub async fn send(&self, name: &str, message: ChannelMessage) -> Result<()> {
+    /// Each chunk waits for a slot from the channel's send pacer. Attachments
+    /// go out once, with the last chunk.
+    pub async fn send(&self, name: &str, mut message: ChannelMessage) -> Result<()> {
         let channel = self
             .get_channel(name)
             .ok_or_else(|| ZeniiError::Channel(format!("channel not found: {name}")))?;
+        let limit = max_length_for(channel.channel_type());
+        if message.content.len() <= limit {
+            return self.send_paced(name, channel.as_ref(), message).await;
+        }
+
+        // Send chunks in order; stop at the first failure so the remainder
+        // is never delivered out of order.
+        let mut attachments = std::mem::take(&mut message.attachments);
+        let parts = split_message(&message.content, limit);
+        let last = parts.len().saturating_sub(1);
+        for (i, part) in parts.into_iter().enumerate() {
+            let mut chunk = message.clone();
+            chunk.content = part;
+            if i == last {
+                chunk.attachments = std::mem::take(&mut attachments);
+            }
+            self.send_paced(name, channel.as_ref(), chunk).await?;
+        }
+        Ok(())
+    }
+
+    /// Send one message after pacing. A platform rate-limit rejection is
+    /// retried once after its `Retry-After`, when that wait is reasonable.
+    async fn send_paced(
+        &self,
+        name: &str,
+        channel: &dyn Channel,
+        message: ChannelMessage,
+    ) -> Result<()> {
+        self.pacer.acquire(name, channel.channel_type()).await;
+        let retry_after = match channel.send_message(message.clone()).await {
+            Err(ZeniiError::ChannelRateLimited {
+                retry_after_secs, ..
+            }) if retry_after_secs.is_none_or(|s| Duration::from_secs(s) <= MAX_RETRY_AFTER) => {
+                retry_after_secs.map_or(DEFAULT_RETRY_AFTER, Duration::from_secs)
+            }
+            result => return result,
+        };
+        tracing::warn!(
+            "{name}: rate limited, retrying in {}s",
+            retry_after.as_secs_f64()
+        );
+        tokio::time::sleep(retry_after).await;
+        self.pacer.acquire(name, channel.channel_type()).await;
         channel.send_message(message).await
     }
 
@@ -152,7 +237,7 @@ impl Default for ChannelRegistry {
 mod tests {
     use super::*;
     use async_trait::async_trait;
-    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use tokio::sync::mpsc;
 
     struct MockChannel {
@@ -320,4 +405,206 @@ mod tests {
         assert!(registry.get_channel("test").is_none());
         assert!(registry.get_sender("test").is_none());
     }
+
+    /// Discord-typed channel recording sent chunks (and how many attachments each
+    /// carried), failing on the `fail_at`-th send. The first `rate_limits`
+    /// attempts are rejected with a 2s `Retry-After`.
+    struct RecordingChannel {
+        sent: Arc<parking_lot::Mutex<Vec<String>>>,
+        attached: Arc<parking_lot::Mutex<Vec<usize>>>,
+        fail_at: Option<usize>,
+        rate_limits: Arc<AtomicUsize>,
+    }
+
+    impl RecordingChannel {
+        fn new(sent: &Arc<parking_lot::Mutex<Vec<String>>>, fail_at: Option<usize>) -> Self {
+            Self {
+                sent: sent.clone(),
+                attached: Arc::default(),
+                fail_at,
+                rate_limits: Arc::new(AtomicUsize::new(0)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ChannelSender for RecordingChannel {
+        fn channel_type(&self) -> &str {
+            "discord"
+        }
+        async fn send_message(&self, message: ChannelMessage) -> Result<()> {
+            if self
+                .rate_limits
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok()
+            {
+                return Err(ZeniiError::ChannelRateLimited {
+                    message: "slow down".into(),
+                    retry_after_secs: Some(2),
+                });
+            }
+            let mut sent = self.sent.lock();
+            if self.fail_at == Some(sent.len()) {
+                return Err(ZeniiError::Channel("boom".into()));
+            }
+            sent.push(message.content);
+            self.attached.lock().push(message.attachments.len());
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl super::super::traits::ChannelLifecycle for RecordingChannel {
+        fn display_name(&self) -> &str {
+            "rec"
+        }
+        async fn connect(&self) -> Result<()> {
+            Ok(())
+        }
+        async fn disconnect(&self) -> Result<()> {
+            Ok(())
+        }
+        fn status(&self) -> ChannelStatus {
+            ChannelStatus::Connected
+        }
+        fn create_sender(&self) -> Box<dyn ChannelSender> {
+            Box::new(RecordingChannel {
+                sent: self.sent.clone(),
+                attached: self.attached.clone(),
+                fail_at: self.fail_at,
+                rate_limits: self.rate_limits.clone(),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl Channel for RecordingChannel {
+        async fn listen(&self, _tx: mpsc::Sender<ChannelMessage>) -> Result<()> {
+            Ok(())
+        }
+        async fn health_check(&self) -> bool {
+            true
+        }
+    }
+
+    // Long messages are chunked to the channel limit and sent in order
+    #[tokio::test]
+    async fn send_chunks_long_message_in_order() {
+        let registry = ChannelRegistry::new();
+        let sent = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        registry
+            .register(Arc::new(RecordingChannel::new(&sent, None)))
+            .unwrap();
+
+        let text = (0..1000)
+            .map(|i| format!("w{i}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        registry
+            .send("rec", ChannelMessage::new("rec", &text))
+            .await
+            .unwrap();
+
+        let sent = sent.lock();
+        assert!(sent.len() >= 3);
+        assert!(sent.iter().all(|c| c.len() <= 2000));
+        assert_eq!(sent.join(" "), text);
+    }
+
+    // Attachments are sent once, with the last chunk
+    #[tokio::test]
+    async fn send_attaches_files_to_last_chunk() {
+        let registry = ChannelRegistry::new();
+        let sent = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let channel = RecordingChannel::new(&sent, None);
+        let attached = channel.attached.clone();
+        registry.register(Arc::new(channel)).unwrap();
+
+        let message = ChannelMessage::new("rec", &"word ".repeat(1200)).with_attachment(
+            super::super::message::ChannelAttachment::from_bytes(
+                "report.pdf",
+                Some("application/pdf".into()),
+                vec![1, 2, 3],
+            ),
+        );
+        registry.send("rec", message).await.unwrap();
+
+        let attached = attached.lock();
+        assert!(attached.len() >= 3);
+        assert_eq!(attached.last(), Some(&1));
+        assert_eq!(attached.iter().sum::<usize>(), 1);
+    }
+
+    // A failed chunk stops delivery of the remainder
+    #[tokio::test]
+    async fn send_stops_after_failed_chunk() {
+        let registry = ChannelRegistry::new();
+        let sent = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        registry
+            .register(Arc::new(RecordingChannel::new(&sent, Some(1))))
+            .unwrap();
+
+        let text = "word ".repeat(1200);
+        let result = registry
+            .send("rec", ChannelMessage::new("rec", &text))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(sent.lock().len(), 1);
+    }
+
+    // CP.4 — chunks are paced to the channel type's configured rate
+    #[tokio::test(start_paused = true)]
+    async fn send_paces_chunks_to_configured_rate() {
+        let config = AppConfig {
+            channel_send_rate_per_min: [("discord".to_string(), 60)].into(),
+            ..Default::default()
+        };
+        let registry = ChannelRegistry::with_config(Arc::new(ArcSwap::from_pointee(config)));
+        let sent = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        registry
+            .register(Arc::new(RecordingChannel::new(&sent, None)))
+            .unwrap();
+
+        let start = tokio::time::Instant::now();
+        let text = "word ".repeat(1200);
+        registry
+            .send("rec", ChannelMessage::new("rec", &text))
+            .await
+            .unwrap();
+        let chunks = sent.lock().len() as u64;
+        assert!(chunks >= 3);
+        assert_eq!(start.elapsed().as_secs(), chunks - 1);
+    }
+
+    // CP.5 — a rate-limited send is retried once after Retry-After
+    #[tokio::test(start_paused = true)]
+    async fn send_retries_once_after_retry_after() {
+        let registry = ChannelRegistry::new();
+        let sent = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let channel = RecordingChannel::new(&sent, None);
+        channel.rate_limits.store(1, Ordering::SeqCst);
+        registry.register(Arc::new(channel)).unwrap();
+
+        let start = tokio::time::Instant::now();
+        registry
+            .send("rec", ChannelMessage::new("rec", "hi"))
+            .await
+            .unwrap();
+        assert_eq!(start.elapsed().as_secs(), 2);
+        assert_eq!(*sent.lock(), vec!["hi".to_string()]);
+    }
+
+    // CP.6 — a second rate-limit rejection is returned to the caller
+    #[tokio::test(start_paused = true)]
+    async fn send_gives_up_after_second_rate_limit() {
+        let registry = ChannelRegistry::new();
+        let sent = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let channel = RecordingChannel::new(&sent, None);
+        channel.rate_limits.store(2, Ordering::SeqCst);
+        registry.register(Arc::new(channel)).unwrap();
+
+        let result = registry.send("rec", ChannelMessage::new("rec", "hi")).await;
+        assert!(matches!(result, Err(ZeniiError::ChannelRateLimited { .. })));
+        assert!(sent.lock().is_empty());
+    }
 }
@@ -14,6 +14,8 @@ use crate::ai::adapter::{ToolCallEvent, ToolCallPhase};
 #[cfg(feature = "ai")]
 use crate::event_bus::AppEvent;
 #[cfg(feature = "ai")]
+use crate::security::approval::ApprovalRequest;
+#[cfg(feature = "ai")]
 use tokio::sync::broadcast;
 
 #[cfg(feature = "ai")]
@@ -23,6 +25,8 @@ use super::message::ChannelMessage;
 use super::policy::ChannelToolPolicy;
 #[cfg(all(feature = "channels", feature = "gateway", feature = "ai"))]
 use super::session_map::ChannelSessionMap;
+#[cfg(all(feature = "channels", feature = "gateway", feature = "ai"))]
+use super::settings::{ChannelSettings, get_channel_settings};
 
 /// Channel Router orchestrator: receives messages from all channels,
 /// resolves sessions, runs agent turns, formats responses, and sends replies.
@@ -145,15 +149,60 @@ impl ChannelRouter {
     /// Handle a single incoming channel message through the full pipeline.
     #[cfg(feature = "ai")]
     async fn handle_message(
-        message: ChannelMessage,
+        mut message: ChannelMessage,
         state: &Arc<AppState>,
         session_map: Option<&Arc<ChannelSessionMap>>,
     ) {
         let channel_name = message.channel.clone();
+
+        // Retried webhook deliveries and double-published messages share a platform id
+        if state.channel_registry.is_duplicate_inbound(&message) {
+            tracing::debug!(
+                "ChannelRouter: dropping duplicate message {:?} from {channel_name}",
+                message.metadata.get(super::dedup::MESSAGE_ID_KEY)
+            );
+            return;
+        }
+
         let reply_metadata = message.metadata.clone();
 
-        // Extract chat_id for lifecycle hooks (channels need chat_id, not username)
-        let recipient = reply_metadata.get("chat_id").cloned();
+        // Extract chat_id for lifecycle hooks (channels need chat_id, not username);
+        // Slack and Discord carry it as channel_id
+        let recipient = reply_metadata
+            .get("chat_id")
+            .or_else(|| reply_metadata.get("channel_id"))
+            .cloned();
+
+        // 0. Turn voice notes into text before anything sees the message
+        if message.has_audio()
+            && !transcribe_audio_attachments(&mut message, state.transcriber.as_deref()).await
+        {
+            let reply = ChannelMessage::new(&channel_name, AUDIO_UNSUPPORTED_REPLY)
+                .with_metadata(reply_metadata.clone());
+            if let Err(e) = state.channel_registry.send(&channel_name, reply).await {
+                warn!("ChannelRouter: failed to send audio fallback via {channel_name}: {e}");
+            }
+            return;
+        }
+
+        // 0b. Save remaining attachments to disk so the agent can reference them
+        if !message.attachments.is_empty() {
+            let config = state.config.load();
+            let dir = config
+                .data_dir
+                .as_ref()
+                .map(std::path::PathBuf::from)
+                .unwrap_or_else(crate::config::default_data_dir)
+                .join("attachments")
+                .join(&channel_name);
+            if config.channel_attachment_retention_hours > 0 {
+                let max_age = std::time::Duration::from_secs(
+                    config.channel_attachment_retention_hours * 3600,
+                );
+                sweep_attachments(&dir, max_age).await;
+            }
+            store_attachments(&mut message, &dir).await;
+        }
 
         // 1. Resolve or create session (uses shared map if available, else transient)
         let transient_map;
@@ -165,6 +214,24 @@ impl ChannelRouter {
             }
         };
         let channel_key = ChannelSessionMap::channel_key(&message);
+
+        // 1b. `/new` starts a fresh session on any channel (Discord also sends
+        // it from its slash command)
+        if message.content.trim() == NEW_SESSION_COMMAND {
+            let text = match sm.reset_session(&channel_key).await {
+                Ok(()) => NEW_SESSION_REPLY,
+                Err(e) => {
+                    warn!("ChannelRouter: failed to reset session for {channel_key}: {e}");
+                    NEW_SESSION_FAILED_REPLY
+                }
+            };
+            let reply = ChannelMessage::new(&channel_name, text).with_metadata(reply_metadata);
+            if let Err(e) = state.channel_registry.send(&channel_name, reply).await {
+                warn!("ChannelRouter: failed to send /new reply via {channel_name}: {e}");
+            }
+            return;
+        }
+
         let session_id = match sm.resolve_session(&channel_key, &channel_name).await {
             Ok(id) => id,
             Err(e) => {
@@ -194,10 +261,25 @@ impl ChannelRouter {
             role: "user".into(),
         });
 
-        // 3. Get allowed tools for this channel (enforced via resolve_agent_with_tools)
+        // 3. Load per-channel overrides, then the tools this channel may use
+        // (enforced via resolve_agent_with_tools, capped by the channel's autonomy level)
+        let settings = get_channel_settings(&state.db, &channel_name)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("ChannelRouter: failed to load settings for {channel_name}: {e}");
+                ChannelSettings::default()
+            });
         let tool_policy = ChannelToolPolicy::new(state.config.load_full());
-        let allowed_tool_names = tool_policy.allowed_tool_names(&channel_name, &state.tools);
-        let allowed_tools = tool_policy.allowed_tools(&channel_name, &state.tools);
+        let mut allowed_tool_names = tool_policy.allowed_tool_names(&channel_name, &state.tools);
+        let mut allowed_tools = tool_policy.allowed_tools(&channel_name, &state.tools);
+        allowed_tools.retain(|t| settings.allows_risk(t.risk_level()));
+        allowed_tool_names.retain(|name| allowed_tools.iter().any(|t| t.name() == name));
+
+        // 3b. Fold older history into the session summary; on failure the
+        // context builder still windows the history (plain truncation)
+        if let Err(e) = crate::ai::summarize::summarize_and_compact(state, &session_id).await {
+            warn!("ChannelRouter: summarization failed for {channel_name}, truncating: {e}");
+        }
 
         // 4. Build context parts + assemble preamble via PromptStrategy
         let (history_from_ctx, _memories, _user_obs) = state
@@ -219,7 +301,7 @@ impl ChannelRouter {
         let config = state.config.load_full();
         let assembly_request = crate::ai::prompt::AssemblyRequest {
             boot_context: state.boot_context.clone(),
-            model_display: "default".into(),
+            model_display: settings.model.as_deref().unwrap_or("default").into(),
             session_id: Some(session_id.clone()),
             user_message: Some(message.content.clone()),
             conversation_summary: summary,
@@ -228,11 +310,24 @@ impl ChannelRouter {
             skill_count: state.skill_registry.list().await.len(),
             version: config.identity_name.clone(),
         };
-        let preamble = state
-            .prompt_strategy
-            .assemble(&assembly_request)
-            .await
-            .unwrap_or_default();
+        // Per-channel system prompt wins, then a mapped persona, then the default assembly
+        let preamble = match (
+            &settings.system_prompt,
+            config.channel_personas.get(&channel_name),
+        ) {
+            (Some(prompt), _) => prompt.clone(),
+            (None, Some(persona)) => {
+                state
+                    .soul_loader
+                    .build_system_prompt_for(persona, &config, Some(&channel_name))
+                    .await
+            }
+            (None, None) => state
+                .prompt_strategy
+                .assemble(&assembly_request)
+                .await
+                .unwrap_or_default(),
+        };
 
         // 5. Merge: preamble + channel-specific formatting hint (with tool awareness)
         let channel_hint = channel_system_context(&channel_name, &allowed_tool_names);
@@ -245,6 +340,7 @@ impl ChannelRouter {
             sender: sender_name.clone(),
         });
         if let Some(channel) = state.channel_registry.get_channel(&channel_name) {
+            set_typing(channel.as_ref(), recipient.as_deref(), true).await;
             channel.on_agent_start(recipient.as_deref()).await;
         }
 
@@ -255,7 +351,7 @@ impl ChannelRouter {
         // Some(vec![]) = explicit "no tools"; None = use surface-permission defaults.
         let tool_override = Some(allowed_tools);
         let agent = match crate::ai::resolve_agent_with_tools(
-            None,
+            settings.model.as_deref(),
             state,
             Some(tool_event_tx),
             Some(&system_context),
@@ -268,13 +364,23 @@ impl ChannelRouter {
             Ok(a) => a,
             Err(e) => {
                 warn!("ChannelRouter: failed to resolve agent for {channel_name}: {e}");
+                let _ = state
+                    .event_bus
+                    .publish(AppEvent::agent_error(Some(&session_id), &e));
                 let _ = state.event_bus.publish(AppEvent::ChannelAgentCompleted {
                     channel: channel_name.clone(),
                     session_id: session_id.clone(),
                 });
                 if let Some(channel) = state.channel_registry.get_channel(&channel_name) {
+                    set_typing(channel.as_ref(), recipient.as_deref(), false).await;
                     channel.on_agent_complete(recipient.as_deref()).await;
                 }
+                // Tell the user why instead of silently dropping the message
+                let reply = ChannelMessage::new(&channel_name, &agent_unavailable_reply(&e))
+                    .with_metadata(reply_metadata.clone());
+                if let Err(e) = state.channel_registry.send(&channel_name, reply).await {
+                    warn!("ChannelRouter: failed to send error reply via {channel_name}: {e}");
+                }
                 return;
             }
         };
@@ -285,11 +391,42 @@ impl ChannelRouter {
         let tool_registry = state.channel_registry.clone();
         let tool_listener = tokio::spawn(async move {
             while let Ok(event) = tool_event_rx.recv().await {
-                if matches!(event.phase, ToolCallPhase::Started { .. })
-                    && let Some(ch) = tool_registry.get_channel(&tool_channel_name)
-                {
-                    ch.on_tool_use(&event.tool_name, tool_recipient.as_deref())
-                        .await;
+                let Some(ch) = tool_registry.get_channel(&tool_channel_name) else {
+                    continue;
+                };
+                match event.phase {
+                    ToolCallPhase::Started { .. } => {
+                        ch.on_tool_use(&event.tool_name, tool_recipient.as_deref())
+                            .await;
+                    }
+                    ToolCallPhase::ApprovalRequested {
+                        approval_id,
+                        reason,
+                        risk_level,
+                        timeout_secs,
+                        args_summary,
+                    } => {
+                        let request = ApprovalRequest {
+                            approval_id,
+                            call_id: event.call_id,
+                            tool_name: event.tool_name,
+                            args_summary,
+                            risk_level,
+                            reason,
+                            timeout_secs,
+                        };
+                        ch.on_approval_requested(&request, tool_recipient.as_deref())
+                            .await;
+                    }
+                    ToolCallPhase::ApprovalResolved {
+                        approval_id,
+                        decision,
+                        resolved_by,
+                    } => {
+                        ch.on_approval_resolved(&approval_id, &decision, &resolved_by)
+                            .await;
+                    }
+                    _ => {}
                 }
             }
         });
@@ -313,26 +450,28 @@ impl ChannelRouter {
         };
 
         // 11. Run agent chat with reasoning engine
-        let response = match state
-            .reasoning_engine
-            .chat(&agent, &message.content, history)
-            .await
+        let response = match crate::security::audit::session_scope(
+            Some(session_id.clone()),
+            state
+                .reasoning_engine
+                .chat(&agent, &message.content, history),
+        )
+        .await
         {
             Ok(r) => r.response,
             Err(e) => {
-                warn!("ChannelRouter: agent chat failed for {channel_name}: {e}");
                 tool_listener.abort();
+                if e.is_cancelled() {
+                    info!("ChannelRouter: agent turn cancelled for {channel_name}: {e}");
+                } else {
+                    warn!("ChannelRouter: agent chat failed for {channel_name}: {e}");
+                    let _ = state
+                        .event_bus
+                        .publish(AppEvent::agent_error(Some(&session_id), &e));
+                }
 
                 // Send a user-friendly error reply through the channel
-                let hint = crate::error::enrich_error(&e);
-                let error_msg = if let Some(h) = hint {
-                    format!(
-                        "Sorry, I encountered an error: {}\n\nHint: {}",
-                        h.summary, h.action
-                    )
-                } else {
-                    "Sorry, I encountered an internal error. Please try again.".into()
-                };
+                let error_msg = agent_failure_reply(&e);
                 let reply = ChannelMessage::new(&channel_name, &error_msg)
                     .with_metadata(reply_metadata.clone());
                 if let Err(send_err) = state.channel_registry.send(&channel_name, reply).await {
@@ -346,6 +485,7 @@ impl ChannelRouter {
                     session_id: session_id.clone(),
                 });
                 if let Some(channel) = state.channel_registry.get_channel(&channel_name) {
+                    set_typing(channel.as_ref(), recipient.as_deref(), false).await;
                     channel.on_agent_complete(recipient.as_deref()).await;
                 }
                 return;
@@ -361,6 +501,7 @@ impl ChannelRouter {
             session_id: session_id.clone(),
         });
         if let Some(channel) = state.channel_registry.get_channel(&channel_name) {
+            set_typing(channel.as_ref(), recipient.as_deref(), false).await;
             channel.on_agent_complete(recipient.as_deref()).await;
         }
 
@@ -384,12 +525,14 @@ impl ChannelRouter {
         let formatter = formatter_for(&channel_name);
         let parts = formatter.format(&response);
 
-        // 16. Send formatted response parts
+        // 16. Send formatted response parts in order; stop at the first
+        // failure rather than deliver the rest out of order
         for part in parts {
             let reply =
                 ChannelMessage::new(&channel_name, &part).with_metadata(reply_metadata.clone());
             if let Err(e) = state.channel_registry.send(&channel_name, reply).await {
                 warn!("ChannelRouter: failed to send reply via {channel_name}: {e}");
+                break;
             }
         }
 
@@ -400,6 +543,194 @@ impl ChannelRouter {
     }
 }
 
+/// Toggle the channel's typing indicator. Best-effort: failures are only logged.
+#[cfg(feature = "ai")]
+async fn set_typing(channel: &dyn super::traits::Channel, recipient: Option<&str>, active: bool) {
+    if let Some(recipient) = recipient
+        && let Err(e) = channel.set_typing(recipient, active).await
+    {
+        tracing::debug!(
+            "ChannelRouter: set_typing({active}) failed on {}: {e}",
+            channel.display_name()
+        );
+    }
+}
+
+/// User-facing reply when no agent could be resolved for a channel message.
+#[cfg(feature = "ai")]
+fn agent_unavailable_reply(err: &crate::ZeniiError) -> String {
+    match err {
+        crate::ZeniiError::ProviderNotConfigured(_) => {
+            "No AI provider is configured yet. Set one up in Settings > Providers, then send your message again.".into()
+        }
+        other => match crate::error::enrich_error(other) {
+            Some(hint) => format!("Sorry, I can't reply right now: {}. {}", hint.summary, hint.action),
+            None => "Sorry, I can't reach the AI provider right now. Please try again later.".into(),
+        },
+    }
+}
+
+/// User-facing reply when an agent turn for a channel message fails.
+#[cfg(feature = "ai")]
+fn agent_failure_reply(err: &crate::ZeniiError) -> String {
+    match err.agent_error() {
+        Some(crate::AgentError::Cancelled(_)) => "The request was cancelled.".into(),
+        Some(crate::AgentError::ProviderAuth(_)) => {
+            "Sorry, the AI provider rejected my credentials, so I can't reply right now. The bot owner needs to update the API key in Settings > Providers.".into()
+        }
+        _ => match crate::error::enrich_error(err) {
+            Some(h) => format!(
+                "Sorry, I encountered an error: {}\n\nHint: {}",
+                h.summary, h.action
+            ),
+            None => "Sorry, I encountered an internal error. Please try again.".into(),
+        },
+    }
+}
+
+/// Channel command that detaches the thread from its session.
+pub(crate) const NEW_SESSION_COMMAND: &str = "/new";
+
+#[cfg(feature = "ai")]
+const NEW_SESSION_REPLY: &str = "Started a new conversation.";
+
+#[cfg(feature = "ai")]
+const NEW_SESSION_FAILED_REPLY: &str =
+    "Sorry, I couldn't start a new conversation. Please try again.";
+
+/// Reply sent when a message carries audio that cannot be transcribed.
+#[cfg(feature = "ai")]
+const AUDIO_UNSUPPORTED_REPLY: &str =
+    "Sorry, I can't process audio messages right now. Please send your message as text.";
+
+/// Transcribe audio attachments into the message `content` and drop the audio bytes.
+/// Returns `false` if the message has audio but no transcriber is configured or
+/// transcription failed.
+#[cfg(feature = "ai")]
+pub(crate) async fn transcribe_audio_attachments(
+    message: &mut ChannelMessage,
+    transcriber: Option<&dyn crate::ai::transcription::TranscriptionProvider>,
+) -> bool {
+    use super::message::AttachmentKind;
+
+    let Some(transcriber) = transcriber else {
+        return false;
+    };
+
+    let (audio, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut message.attachments)
+        .into_iter()
+        .partition(|a| a.kind == AttachmentKind::Audio);
+    message.attachments = rest;
+
+    let mut transcripts = Vec::with_capacity(audio.len());
+    for attachment in audio {
+        match transcriber
+            .transcribe(
+                attachment.data,
+                &attachment.file_name,
+                attachment.mime_type.as_deref(),
+            )
+            .await
+        {
+            Ok(text) if !text.is_empty() => transcripts.push(text),
+            Ok(_) => {}
+            Err(e) => {
+                warn!(
+                    "ChannelRouter: transcription failed for {}: {e}",
+                    message.channel
+                );
+                return false;
+            }
+        }
+    }
+    if transcripts.is_empty() {
+        return false;
+    }
+
+    let transcript = transcripts.join("\n");
+    message.content = if message.content.trim().is_empty() {
+        transcript
+    } else {
+        format!("{}\n\n{transcript}", message.content)
+    };
+    true
+}
+
+/// Write attachment bytes under `dir` and append a reference line per
+/// attachment to the message `content`, so the agent sees what was sent and
+/// where to read it. Attachments that cannot be saved are listed without a path.
+#[cfg(feature = "ai")]
+pub(crate) async fn store_attachments(message: &mut ChannelMessage, dir: &std::path::Path) {
+    if let Err(e) = tokio::fs::create_dir_all(dir).await {
+        warn!("ChannelRouter: failed to create {}: {e}", dir.display());
+    }
+
+    let mut references = Vec::with_capacity(message.attachments.len());
+    for attachment in &mut message.attachments {
+        let mime = attachment.mime_type.as_deref().unwrap_or("unknown type");
+        if attachment.path.is_none() && !attachment.data.is_empty() {
+            // Keep only the final path component so a crafted name can't escape `dir`
+            let name = std::path::Path::new(&attachment.file_name)
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "attachment".into());
+            let path = dir.join(format!("{}-{name}", uuid::Uuid::new_v4().simple()));
+            match tokio::fs::write(&path, &attachment.data).await {
+                Ok(()) => {
+                    attachment.path = Some(path);
+                    attachment.data = Vec::new();
+                }
+                Err(e) => warn!("ChannelRouter: failed to save attachment {name}: {e}"),
+            }
+        }
+        references.push(match &attachment.path {
+            Some(path) => format!(
+                "[Attachment: {} ({mime}) saved at {}]",
+                attachment.file_name,
+                path.display()
+            ),
+            None => format!(
+                "[Attachment: {} ({mime}) could not be saved]",
+                attachment.file_name
+            ),
+        });
+    }
+
+    if references.is_empty() {
+        return;
+    }
+    let references = references.join("\n");
+    message.content = if message.content.trim().is_empty() {
+        references
+    } else {
+        format!("{}\n\n{references}", message.content)
+    };
+}
+
+/// Delete files in `dir` last modified more than `max_age` ago.
+#[cfg(feature = "ai")]
+pub(crate) async fn sweep_attachments(dir: &std::path::Path, max_age: std::time::Duration) {
+    let Ok(mut entries) = tokio::fs::read_dir(dir).await else {
+        return;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let expired = entry
+            .metadata()
+            .await
+            .ok()
+            .filter(|m| m.is_file())
+            .and_then(|m| m.modified().ok())
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > max_age);
+        if expired && let Err(e) = tokio::fs::remove_file(entry.path()).await {
+            warn!(
+                "ChannelRouter: failed to remove old attachment {}: {e}",
+                entry.path().display()
+            );
+        }
+    }
+}
+
 /// Supervisor loop for a channel: catches `listen()` exits, publishes lifecycle events,
 /// applies exponential backoff, and restarts the channel.
 #[cfg(all(feature = "channels", feature = "gateway"))]
@@ -532,6 +863,313 @@ pub fn channel_system_context(channel_name: &str, allowed_tool_names: &[String])
 mod tests {
     use super::*;
 
+    #[cfg(feature = "ai")]
+    struct FakeTranscriber;
+
+    #[cfg(feature = "ai")]
+    #[async_trait::async_trait]
+    impl crate::ai::transcription::TranscriptionProvider for FakeTranscriber {
+        async fn transcribe(
+            &self,
+            audio: Vec<u8>,
+            file_name: &str,
+            _mime_type: Option<&str>,
+        ) -> crate::Result<String> {
+            Ok(format!("transcribed {file_name} ({} bytes)", audio.len()))
+        }
+    }
+
+    #[cfg(feature = "ai")]
+    fn voice_message() -> ChannelMessage {
+        ChannelMessage::new("telegram", "").with_attachment(
+            super::super::message::ChannelAttachment {
+                kind: super::super::message::AttachmentKind::Audio,
+                file_name: "voice.ogg".into(),
+                mime_type: Some("audio/ogg".into()),
+                data: vec![0u8; 4],
+                path: None,
+            },
+        )
+    }
+
+    // Missing provider yields an actionable setup reply, not a generic failure
+    #[cfg(feature = "ai")]
+    #[test]
+    fn agent_unavailable_reply_for_missing_provider() {
+        let reply =
+            agent_unavailable_reply(&crate::ZeniiError::ProviderNotConfigured("none".into()));
+        assert!(reply.contains("No AI provider is configured"));
+
+        let reply = agent_unavailable_reply(&crate::ZeniiError::Credential("no key".into()));
+        assert!(reply.contains("API key not configured"));
+    }
+
+    // Auth failures and cancellations get their own replies
+    #[cfg(feature = "ai")]
+    #[test]
+    fn agent_failure_reply_by_kind() {
+        use crate::AgentError;
+
+        let reply = agent_failure_reply(&AgentError::Cancelled("stopped".into()).into());
+        assert_eq!(reply, "The request was cancelled.");
+
+        let reply = agent_failure_reply(&AgentError::ProviderAuth("401".into()).into());
+        assert!(reply.contains("rejected my credentials"));
+
+        let reply = agent_failure_reply(&AgentError::MaxIterations("limit".into()).into());
+        assert!(reply.contains("agent_max_turns"));
+
+        let reply = agent_failure_reply(&crate::ZeniiError::Other("boom".into()));
+        assert!(reply.contains("internal error"));
+    }
+
+    // TR.4 — audio attachment transcribed into content, audio dropped
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn audio_attachment_transcribed_into_content() {
+        let mut msg = voice_message();
+        assert!(transcribe_audio_attachments(&mut msg, Some(&FakeTranscriber)).await);
+        assert_eq!(msg.content, "transcribed voice.ogg (4 bytes)");
+        assert!(!msg.has_audio());
+    }
+
+    // TR.5 — caption text is kept ahead of the transcript
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn audio_transcript_appended_to_caption() {
+        let mut msg = voice_message();
+        msg.content = "see this".into();
+        assert!(transcribe_audio_attachments(&mut msg, Some(&FakeTranscriber)).await);
+        assert_eq!(msg.content, "see this\n\ntranscribed voice.ogg (4 bytes)");
+    }
+
+    // TR.8 — non-audio attachments are saved and referenced in the content
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn attachments_saved_and_referenced() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut msg = ChannelMessage::new("telegram", "what is this?").with_attachment(
+            super::super::message::ChannelAttachment::from_bytes(
+                "../photo.jpg",
+                Some("image/jpeg".into()),
+                vec![1, 2, 3],
+            ),
+        );
+        store_attachments(&mut msg, dir.path()).await;
+
+        let saved = msg.attachments[0].path.clone().unwrap();
+        assert!(saved.starts_with(dir.path()));
+        assert!(saved.to_string_lossy().ends_with("-photo.jpg"));
+        assert_eq!(std::fs::read(&saved).unwrap(), vec![1, 2, 3]);
+        assert!(msg.attachments[0].data.is_empty());
+        assert!(
+            msg.content
+                .starts_with("what is this?\n\n[Attachment: ../photo.jpg (image/jpeg) saved at ")
+        );
+    }
+
+    // TR.9 — attachments past the retention age are swept, newer ones kept
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn old_attachments_are_swept() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let old = dir.path().join("old.jpg");
+        let fresh = dir.path().join("fresh.jpg");
+        std::fs::write(&old, b"old").unwrap();
+        std::fs::write(&fresh, b"fresh").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&old)
+            .unwrap()
+            .set_modified(std::time::SystemTime::now() - std::time::Duration::from_secs(7200))
+            .unwrap();
+
+        sweep_attachments(dir.path(), std::time::Duration::from_secs(3600)).await;
+        assert!(!old.exists());
+        assert!(fresh.exists());
+    }
+
+    // TR.6 — no transcriber configured reports failure
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn audio_without_transcriber_fails() {
+        let mut msg = voice_message();
+        assert!(!transcribe_audio_attachments(&mut msg, None).await);
+    }
+
+    // TR.7 — voice note reaches the session (and thus the agent) as transcribed text
+    #[cfg(all(feature = "channels", feature = "gateway", feature = "ai"))]
+    #[tokio::test]
+    async fn voice_note_transcribed_before_agent() {
+        let (_dir, base_state) = crate::gateway::handlers::tests::test_state().await;
+        let mut state = Arc::try_unwrap(base_state)
+            .unwrap_or_else(|_| panic!("test_state must return unique Arc"));
+        state.transcriber = Some(Arc::new(FakeTranscriber));
+        let state = Arc::new(state);
+
+        let mut msg = voice_message();
+        msg.metadata.insert("chat_id".into(), "42".into());
+        ChannelRouter::handle_message_static(msg, &state).await;
+
+        let sessions = state.session_manager.list_sessions().await.unwrap();
+        assert_eq!(sessions.len(), 1);
+        let messages = state
+            .session_manager
+            .get_messages(&sessions[0].id)
+            .await
+            .unwrap();
+        assert_eq!(messages[0].role, "user");
+        assert_eq!(messages[0].content, "transcribed voice.ogg (4 bytes)");
+    }
+
+    // CS.4 — A channel's model override is used for its next message
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn channel_model_override_applied_on_next_message() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+
+        let url = crate::ai::test_support::openai_text_mock("reply from channel model").await;
+        state
+            .provider_registry
+            .add_user_provider(
+                "mock",
+                "Mock",
+                &url,
+                false,
+                &[("mock-model".into(), "Mock".into(), true)],
+            )
+            .await
+            .unwrap();
+
+        // The default model has no API key; only the channel override can answer
+        crate::channels::settings::set_channel_settings(
+            &state.db,
+            "telegram",
+            crate::channels::settings::ChannelSettings {
+                model: Some("mock:mock-model".into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let mut msg = ChannelMessage::new("telegram", "hello");
+        msg.metadata.insert("chat_id".into(), "42".into());
+        ChannelRouter::handle_message_static(msg, &state).await;
+
+        let sessions = state.session_manager.list_sessions().await.unwrap();
+        let messages = state
+            .session_manager
+            .get_messages(&sessions[0].id)
+            .await
+            .unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[1].role, "assistant");
+        assert_eq!(messages[1].content, "reply from channel model");
+    }
+
+    // `/new` detaches the thread from its session; the next message opens another
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn new_command_starts_fresh_session() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let message = |content: &str| {
+            let mut msg = ChannelMessage::new("discord", content);
+            msg.metadata.insert("channel_id".into(), "77".into());
+            msg
+        };
+
+        ChannelRouter::handle_message_static(message("hello"), &state).await;
+        ChannelRouter::handle_message_static(message("/new"), &state).await;
+        ChannelRouter::handle_message_static(message("hello again"), &state).await;
+
+        let sessions = state.session_manager.list_sessions().await.unwrap();
+        assert_eq!(sessions.len(), 2);
+        let current = state
+            .session_manager
+            .find_session_by_channel_key("discord:77")
+            .await
+            .unwrap()
+            .unwrap();
+        let messages = state
+            .session_manager
+            .get_messages(&current.id)
+            .await
+            .unwrap();
+        assert_eq!(messages[0].content, "hello again");
+    }
+
+    // Typing is switched on at agent start and off on completion (even on failure),
+    // addressed by channel_id when the message has no chat_id
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn typing_indicator_wraps_agent_turn() {
+        use crate::channels::traits::{Channel, ChannelLifecycle, ChannelSender, ChannelStatus};
+        use async_trait::async_trait;
+
+        struct TypingCh(Arc<parking_lot::Mutex<Vec<(String, bool)>>>);
+
+        #[async_trait]
+        impl ChannelSender for TypingCh {
+            fn channel_type(&self) -> &str {
+                "typing"
+            }
+            async fn send_message(&self, _msg: ChannelMessage) -> crate::Result<()> {
+                Ok(())
+            }
+        }
+
+        #[async_trait]
+        impl ChannelLifecycle for TypingCh {
+            fn display_name(&self) -> &str {
+                "typing"
+            }
+            async fn connect(&self) -> crate::Result<()> {
+                Ok(())
+            }
+            async fn disconnect(&self) -> crate::Result<()> {
+                Ok(())
+            }
+            fn status(&self) -> ChannelStatus {
+                ChannelStatus::Connected
+            }
+            fn create_sender(&self) -> Box<dyn ChannelSender> {
+                Box::new(TypingCh(self.0.clone()))
+            }
+        }
+
+        #[async_trait]
+        impl Channel for TypingCh {
+            async fn listen(&self, _tx: mpsc::Sender<ChannelMessage>) -> crate::Result<()> {
+                Ok(())
+            }
+            async fn health_check(&self) -> bool {
+                true
+            }
+            async fn set_typing(&self, recipient: &str, active: bool) -> crate::Result<()> {
+                self.0.lock().push((recipient.to_string(), active));
+                Err(crate::ZeniiError::Channel("best effort".into()))
+            }
+        }
+
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let calls = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        state
+            .channel_registry
+            .register(Arc::new(TypingCh(calls.clone())))
+            .unwrap();
+
+        // No provider is configured, so the turn fails after starting
+        let mut msg = ChannelMessage::new("typing", "hello");
+        msg.metadata.insert("channel_id".into(), "c1".into());
+        ChannelRouter::handle_message_static(msg, &state).await;
+
+        assert_eq!(
+            *calls.lock(),
+            vec![("c1".to_string(), true), ("c1".to_string(), false)]
+        );
+    }
+
     // SUP.1 — supervisor_backoff starts at min_ms
     #[cfg(all(feature = "channels", feature = "gateway"))]
     #[test]
@@ -124,6 +124,13 @@ impl ChannelSessionMap {
         }
     }
 
+    /// Forget the session for `channel_key`; the next message creates a new one.
+    #[cfg(feature = "ai")]
+    pub async fn reset_session(&self, channel_key: &str) -> Result<()> {
+        self.map.remove(channel_key);
+        self.session_manager.release_channel_key(channel_key).await
+    }
+
     /// List all active channel-to-session mappings.
     pub fn list_channel_sessions(&self) -> Vec<(String, String)> {
         self.map
@@ -266,4 +273,32 @@ mod tests {
         let sessions = map.list_channel_sessions();
         assert_eq!(sessions.len(), 2);
     }
+
+    // CR.8 — reset_session starts a new session and keeps the old one
+    #[cfg(feature = "ai")]
+    #[tokio::test]
+    async fn reset_starts_new_session() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("test.db");
+        let pool = crate::db::init_pool(&path).unwrap();
+        crate::db::with_db(&pool, |conn| crate::db::run_migrations(conn))
+            .await
+            .unwrap();
+        let mgr = Arc::new(crate::ai::session::SessionManager::new(pool));
+        let map = ChannelSessionMap::new(mgr.clone());
+
+        let old = map
+            .resolve_session("discord:987654", "discord")
+            .await
+            .unwrap();
+        map.reset_session("discord:987654").await.unwrap();
+        let new = map
+            .resolve_session("discord:987654", "discord")
+            .await
+            .unwrap();
+
+        assert_ne!(old, new);
+        let old_session = mgr.get_session(&old).await.unwrap();
+        assert_eq!(old_session.channel_key, None);
+    }
 }
@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::db::{self, DbPool};
+use crate::security::RiskLevel;
+use crate::security::policy::AutonomyLevel;
+
+/// Per-channel agent overrides stored in the `channel_settings` table.
+///
+/// Every field is optional; `None` falls back to the global configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct ChannelSettings {
+    /// "provider_id:model_id" used for this channel instead of the default model.
+    pub model: Option<String>,
+    /// "readonly", "supervised" or "full". Caps which tools the channel agent gets.
+    pub autonomy_level: Option<String>,
+    /// Replaces the assembled identity/system prompt for this channel.
+    pub system_prompt: Option<String>,
+}
+
+impl ChannelSettings {
+    pub fn is_empty(&self) -> bool {
+        self.model.is_none() && self.autonomy_level.is_none() && self.system_prompt.is_none()
+    }
+
+    /// Whether a tool of `risk` may run under this channel's autonomy level.
+    pub fn allows_risk(&self, risk: RiskLevel) -> bool {
+        match self
+            .autonomy_level
+            .as_deref()
+            .map(AutonomyLevel::from_str_lossy)
+        {
+            Some(AutonomyLevel::ReadOnly) => risk == RiskLevel::Low,
+            Some(AutonomyLevel::Supervised) => risk != RiskLevel::High,
+            _ => true,
+        }
+    }
+}
+
+fn normalize(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Load settings for `channel`. Returns defaults when none are stored.
+pub async fn get_channel_settings(pool: &DbPool, channel: &str) -> Result<ChannelSettings> {
+    let channel = channel.to_string();
+    db::with_db(pool, move |conn| {
+        let mut stmt = conn.prepare(
+            "SELECT model, autonomy_level, system_prompt FROM channel_settings WHERE channel = ?1",
+        )?;
+        let mut rows = stmt.query([&channel])?;
+        match rows.next()? {
+            Some(row) => Ok(ChannelSettings {
+                model: row.get(0)?,
+                autonomy_level: row.get(1)?,
+                system_prompt: row.get(2)?,
+            }),
+            None => Ok(ChannelSettings::default()),
+        }
+    })
+    .await
+}
+
+/// Replace the settings for `channel`. Blank values are stored as unset; an
+/// all-empty settings value removes the row.
+pub async fn set_channel_settings(
+    pool: &DbPool,
+    channel: &str,
+    settings: ChannelSettings,
+) -> Result<ChannelSettings> {
+    let settings = ChannelSettings {
+        model: normalize(settings.model),
+        autonomy_level: normalize(settings.autonomy_level),
+        system_prompt: normalize(settings.system_prompt),
+    };
+    if let Some(model) = &settings.model
+        && !model.contains(':')
+    {
+        return Err(crate::ZeniiError::Validation(format!(
+            "invalid model '{model}': expected 'provider_id:model_id'"
+        )));
+    }
+
+    if let Some(level) = &settings.autonomy_level
+        && !matches!(
+            level.to_lowercase().as_str(),
+            "readonly" | "read_only" | "read-only" | "supervised" | "full"
+        )
+    {
+        return Err(crate::ZeniiError::Validation(format!(
+            "invalid autonomy level '{level}': expected readonly, supervised or full"
+        )));
+    }
+
+    let channel = channel.to_string();
+    let stored = settings.clone();
+    db::with_db(pool, move |conn| {
+        if stored.is_empty() {
+            conn.execute("DELETE FROM channel_settings WHERE channel = ?1", [&channel])?;
+        } else {
+            conn.execute(
+                "INSERT INTO channel_settings (channel, model, autonomy_level, system_prompt, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, datetime('now'))
+                 ON CONFLICT(channel) DO UPDATE SET
+                    model = excluded.model,
+                    autonomy_level = excluded.autonomy_level,
+                    system_prompt = excluded.system_prompt,
+                    updated_at = excluded.updated_at",
+                rusqlite::params![
+                    channel,
+                    stored.model,
+                    stored.autonomy_level,
+                    stored.system_prompt
+                ],
+            )?;
+        }
+        Ok(())
+    })
+    .await?;
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool() -> (tempfile::TempDir, DbPool) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let pool = db::init_pool(&dir.path().join("test.db")).unwrap();
+        db::run_migrations(&pool.blocking_lock()).unwrap();
+        (dir, pool)
+    }
+
+    // CS.1 — Settings round-trip; blank values clear the row
+    #[tokio::test]
+    async fn settings_round_trip_and_clear() {
+        let (_dir, pool) = tokio::task::spawn_blocking(pool).await.unwrap();
+        assert!(
+            get_channel_settings(&pool, "telegram")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let saved = set_channel_settings(
+            &pool,
+            "telegram",
+            ChannelSettings {
+                model: Some(" openai:gpt-4o-mini ".into()),
+                autonomy_level: Some("readonly".into()),
+                system_prompt: Some(String::new()),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(saved.model.as_deref(), Some("openai:gpt-4o-mini"));
+        assert_eq!(saved.system_prompt, None);
+        assert_eq!(
+            get_channel_settings(&pool, "telegram").await.unwrap(),
+            saved
+        );
+
+        set_channel_settings(&pool, "telegram", ChannelSettings::default())
+            .await
+            .unwrap();
+        assert!(
+            get_channel_settings(&pool, "telegram")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    // CS.2 — Model must be provider-qualified and autonomy level known
+    #[tokio::test]
+    async fn invalid_model_rejected() {
+        let (_dir, pool) = tokio::task::spawn_blocking(pool).await.unwrap();
+        let settings = ChannelSettings {
+            model: Some("gpt-4o".into()),
+            ..Default::default()
+        };
+        assert!(
+            set_channel_settings(&pool, "slack", settings)
+                .await
+                .is_err()
+        );
+
+        let settings = ChannelSettings {
+            autonomy_level: Some("yolo".into()),
+            ..Default::default()
+        };
+        assert!(
+            set_channel_settings(&pool, "slack", settings)
+                .await
+                .is_err()
+        );
+    }
+
+    // CS.3 — Autonomy level caps tool risk
+    #[test]
+    fn autonomy_caps_tool_risk() {
+        let readonly = ChannelSettings {
+            autonomy_level: Some("readonly".into()),
+            ..Default::default()
+        };
+        assert!(readonly.allows_risk(RiskLevel::Low));
+        assert!(!readonly.allows_risk(RiskLevel::Medium));
+
+        let supervised = ChannelSettings {
+            autonomy_level: Some("supervised".into()),
+            ..Default::default()
+        };
+        assert!(supervised.allows_risk(RiskLevel::Medium));
+        assert!(!supervised.allows_risk(RiskLevel::High));
+        assert!(ChannelSettings::default().allows_risk(RiskLevel::High));
+    }
+}
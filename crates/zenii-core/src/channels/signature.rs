@@ -0,0 +1,29 @@
+//! HMAC-SHA256 checks for channels that receive signed webhooks.
+
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+
+/// Verify that `hex_sig` is the HMAC-SHA256 of `parts` (concatenated) keyed
+/// with `secret`. Comparison is constant-time.
+pub fn verify_hmac_sha256(secret: &str, parts: &[&[u8]], hex_sig: &str) -> bool {
+    let Some(expected) = decode_hex(hex_sig) else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    for part in parts {
+        mac.update(part);
+    }
+    mac.verify_slice(&expected).is_ok()
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
@@ -1,5 +1,20 @@
 use serde_json::{Value, json};
 
+use crate::channels::signature::verify_hmac_sha256;
+use crate::security::approval::{ApprovalDecision, ApprovalRequest};
+
+/// `action_id` of the Approve button on approval prompts.
+pub const APPROVE_ACTION_ID: &str = "zenii_approval_approve";
+
+/// `action_id` of the Deny button on approval prompts.
+pub const DENY_ACTION_ID: &str = "zenii_approval_deny";
+
+/// Requests signed longer ago than this are rejected as replays.
+const SIGNATURE_MAX_AGE_SECS: i64 = 300;
+
+/// Longest argument summary shown in an approval prompt.
+const MAX_ARGS_CHARS: usize = 500;
+
 /// Build JSON payload for Slack's chat.postMessage API.
 pub fn post_message_payload(channel: &str, text: &str) -> Value {
     json!({
@@ -19,6 +34,229 @@ pub fn update_message_payload(channel: &str, ts: &str, text: &str) -> Value {
     })
 }
 
+/// Build JSON payload for Slack's assistant.threads.setStatus API.
+/// An empty `status` clears the indicator.
+pub fn set_status_payload(channel: &str, thread_ts: &str, status: &str) -> Value {
+    json!({
+        "channel_id": channel,
+        "thread_ts": thread_ts,
+        "status": status
+    })
+}
+
+/// Build JSON payload for Slack's files.completeUploadExternal API, sharing
+/// the uploaded file into `channel` (and `thread_ts`, if given).
+pub fn complete_upload_payload(
+    file_id: &str,
+    title: &str,
+    channel: &str,
+    thread_ts: Option<&str>,
+) -> Value {
+    let mut payload = json!({
+        "files": [{ "id": file_id, "title": title }],
+        "channel_id": channel
+    });
+    if let Some(ts) = thread_ts {
+        payload["thread_ts"] = Value::String(ts.to_string());
+    }
+    payload
+}
+
+/// Seconds to wait from a `Retry-After` header, which Slack sends on HTTP 429.
+pub fn retry_after_secs(headers: &reqwest::header::HeaderMap) -> Option<u64> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Verify Slack's request signature: `X-Slack-Signature` must be
+/// `v0=<hex HMAC-SHA256 of "v0:{timestamp}:{body}">` keyed with the signing
+/// secret, and `X-Slack-Request-Timestamp` within five minutes of `now`.
+pub fn verify_signature(
+    signing_secret: &str,
+    timestamp: &str,
+    body: &[u8],
+    signature: &str,
+    now: i64,
+) -> bool {
+    let Ok(ts) = timestamp.parse::<i64>() else {
+        return false;
+    };
+    if (now - ts).abs() > SIGNATURE_MAX_AGE_SECS {
+        return false;
+    }
+    let prefix = format!("v0:{timestamp}:");
+    signature.strip_prefix("v0=").is_some_and(|hex_sig| {
+        verify_hmac_sha256(signing_secret, &[prefix.as_bytes(), body], hex_sig)
+    })
+}
+
+/// Decode the `payload` field of an interactivity request
+/// (`application/x-www-form-urlencoded`).
+pub fn interaction_payload(body: &[u8]) -> Option<Value> {
+    let body = std::str::from_utf8(body).ok()?;
+    let encoded = body
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("payload="))?;
+    let spaced = encoded.replace('+', " ");
+    let decoded = urlencoding::decode(&spaced).ok()?.into_owned();
+    serde_json::from_str(&decoded).ok()
+}
+
+/// A click on an approval prompt button.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApprovalAction {
+    pub approval_id: String,
+    pub decision: ApprovalDecision,
+    /// Slack user ID of whoever clicked.
+    pub user_id: String,
+    /// Channel the prompt was clicked in.
+    pub channel_id: String,
+    /// Webhook for replacing the prompt message, when Slack sent one.
+    pub response_url: Option<String>,
+}
+
+/// Extract an approval button click from a `block_actions` payload. Other
+/// interactions return `None`.
+pub fn parse_approval_action(payload: &Value) -> Option<ApprovalAction> {
+    if payload["type"].as_str() != Some("block_actions") {
+        return None;
+    }
+    let action = payload["actions"].as_array()?.first()?;
+    let decision = match action["action_id"].as_str()? {
+        APPROVE_ACTION_ID => ApprovalDecision::Approve,
+        DENY_ACTION_ID => ApprovalDecision::Deny,
+        _ => return None,
+    };
+    Some(ApprovalAction {
+        approval_id: action["value"].as_str()?.to_string(),
+        decision,
+        user_id: payload["user"]["id"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        channel_id: payload["channel"]["id"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        response_url: payload["response_url"].as_str().map(str::to_string),
+    })
+}
+
+fn approval_header(request_tool: &str, reason: &str, args_summary: &str) -> String {
+    let mut text = format!("*Approval needed:* `{request_tool}`\n{reason}");
+    if !args_summary.is_empty() {
+        let args: String = args_summary.chars().take(MAX_ARGS_CHARS).collect();
+        let ellipsis = if args.len() < args_summary.len() {
+            "…"
+        } else {
+            ""
+        };
+        text.push_str(&format!("\n```{args}{ellipsis}```"));
+    }
+    text
+}
+
+/// Build the chat.postMessage payload for an approval prompt with Block Kit
+/// Approve/Deny buttons. Both buttons carry the approval ID as their value.
+pub fn approval_prompt_payload(
+    channel: &str,
+    thread_ts: Option<&str>,
+    request: &ApprovalRequest,
+) -> Value {
+    let mut payload = json!({
+        "channel": channel,
+        "text": format!("Approval needed: {}", request.tool_name),
+        "blocks": [
+            {
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": approval_header(&request.tool_name, &request.reason, &request.args_summary)
+                }
+            },
+            {
+                "type": "context",
+                "elements": [{
+                    "type": "mrkdwn",
+                    "text": format!(
+                        "Risk: {} · expires in {}s",
+                        request.risk_level, request.timeout_secs
+                    )
+                }]
+            },
+            {
+                "type": "actions",
+                "elements": [
+                    {
+                        "type": "button",
+                        "text": { "type": "plain_text", "text": "Approve" },
+                        "style": "primary",
+                        "action_id": APPROVE_ACTION_ID,
+                        "value": request.approval_id
+                    },
+                    {
+                        "type": "button",
+                        "text": { "type": "plain_text", "text": "Deny" },
+                        "style": "danger",
+                        "action_id": DENY_ACTION_ID,
+                        "value": request.approval_id
+                    }
+                ]
+            }
+        ]
+    });
+    if let Some(ts) = thread_ts {
+        payload["thread_ts"] = Value::String(ts.to_string());
+    }
+    payload
+}
+
+/// Build the chat.update payload that replaces an approval prompt's buttons
+/// with its outcome.
+pub fn approval_resolved_payload(channel: &str, ts: &str, tool_name: &str, outcome: &str) -> Value {
+    json!({
+        "channel": channel,
+        "ts": ts,
+        "text": format!("{tool_name}: {outcome}"),
+        "blocks": [
+            {
+                "type": "section",
+                "text": { "type": "mrkdwn", "text": format!("*Approval:* `{tool_name}`") }
+            },
+            {
+                "type": "context",
+                "elements": [{ "type": "mrkdwn", "text": outcome }]
+            }
+        ]
+    })
+}
+
+/// Human-readable outcome of an approval for the updated prompt.
+pub fn approval_outcome(decision: &str, resolved_by: &str) -> String {
+    if resolved_by == "timeout" {
+        let result = if decision == "skip" {
+            "skipped"
+        } else {
+            "denied"
+        };
+        return format!(":hourglass: Expired without an answer, tool call {result}");
+    }
+    let verdict = match decision {
+        "approve" | "approve_always" => ":white_check_mark: Approved",
+        _ => ":x: Denied",
+    };
+    match resolved_by.strip_prefix("slack:") {
+        Some(user) if !user.is_empty() => format!("{verdict} by <@{user}>"),
+        _ if resolved_by.is_empty() => verdict.to_string(),
+        _ => format!("{verdict} via {resolved_by}"),
+    }
+}
+
 /// Build the envelope acknowledgment for Socket Mode.
 pub fn envelope_ack(envelope_id: &str) -> Value {
     json!({
@@ -38,6 +276,9 @@ pub fn contains_bot_mention(text: &str, bot_id: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use hmac::{Hmac, KeyInit, Mac};
+    use sha2::Sha256;
+
     use super::*;
 
     #[test]
@@ -55,4 +296,159 @@ mod tests {
         assert_eq!(payload["ts"], "1234567890.123456");
         assert_eq!(payload["text"], "updated text");
     }
+
+    #[test]
+    fn complete_upload_payload_test() {
+        let payload = complete_upload_payload("F123", "report.pdf", "C123", Some("1.2"));
+        assert_eq!(payload["files"][0]["id"], "F123");
+        assert_eq!(payload["files"][0]["title"], "report.pdf");
+        assert_eq!(payload["channel_id"], "C123");
+        assert_eq!(payload["thread_ts"], "1.2");
+
+        let payload = complete_upload_payload("F123", "report.pdf", "C123", None);
+        assert!(payload.get("thread_ts").is_none());
+    }
+
+    #[test]
+    fn retry_after_secs_parses_header() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(retry_after_secs(&headers), None);
+        headers.insert(reqwest::header::RETRY_AFTER, "30".parse().unwrap());
+        assert_eq!(retry_after_secs(&headers), Some(30));
+        headers.insert(reqwest::header::RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after_secs(&headers), None);
+    }
+
+    #[test]
+    fn set_status_payload_test() {
+        let payload = set_status_payload("C123", "1234567890.123456", "is typing...");
+        assert_eq!(payload["channel_id"], "C123");
+        assert_eq!(payload["thread_ts"], "1234567890.123456");
+        assert_eq!(payload["status"], "is typing...");
+    }
+
+    fn sign(secret: &str, ts: &str, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(format!("v0:{ts}:").as_bytes());
+        mac.update(body);
+        let hex: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        format!("v0={hex}")
+    }
+
+    #[test]
+    fn verify_signature_checks_hmac_and_age() {
+        let body = b"payload=%7B%7D";
+        let sig = sign("secret", "1700000000", body);
+        assert!(verify_signature(
+            "secret",
+            "1700000000",
+            body,
+            &sig,
+            1_700_000_010
+        ));
+        assert!(!verify_signature(
+            "other",
+            "1700000000",
+            body,
+            &sig,
+            1_700_000_010
+        ));
+        assert!(!verify_signature(
+            "secret",
+            "1700000000",
+            b"tampered",
+            &sig,
+            1_700_000_010
+        ));
+        // Replayed after the five-minute window
+        assert!(!verify_signature(
+            "secret",
+            "1700000000",
+            body,
+            &sig,
+            1_700_000_301
+        ));
+        assert!(!verify_signature(
+            "secret",
+            "soon",
+            body,
+            &sig,
+            1_700_000_010
+        ));
+    }
+
+    #[test]
+    fn parse_approval_action_from_form_body() {
+        let payload = json!({
+            "type": "block_actions",
+            "user": { "id": "U42" },
+            "channel": { "id": "C42" },
+            "response_url": "https://hooks.slack.com/actions/x",
+            "actions": [{ "action_id": APPROVE_ACTION_ID, "value": "appr-1" }]
+        });
+        let body = format!(
+            "payload={}",
+            urlencoding::encode(&payload.to_string()).replace("%20", "+")
+        );
+        let parsed = interaction_payload(body.as_bytes()).unwrap();
+        let action = parse_approval_action(&parsed).unwrap();
+        assert_eq!(action.approval_id, "appr-1");
+        assert_eq!(action.decision, ApprovalDecision::Approve);
+        assert_eq!(action.user_id, "U42");
+        assert_eq!(action.channel_id, "C42");
+        assert_eq!(
+            action.response_url.as_deref(),
+            Some("https://hooks.slack.com/actions/x")
+        );
+
+        let other = json!({ "type": "view_submission" });
+        assert!(parse_approval_action(&other).is_none());
+    }
+
+    #[test]
+    fn approval_prompt_has_both_buttons() {
+        let request = ApprovalRequest {
+            approval_id: "appr-1".into(),
+            call_id: "c1".into(),
+            tool_name: "shell".into(),
+            args_summary: "rm -rf build".into(),
+            risk_level: "high".into(),
+            reason: "Deletes files".into(),
+            timeout_secs: 120,
+        };
+        let payload = approval_prompt_payload("C123", Some("1.2"), &request);
+        assert_eq!(payload["thread_ts"], "1.2");
+        let buttons = payload["blocks"][2]["elements"].as_array().unwrap();
+        assert_eq!(buttons[0]["action_id"], APPROVE_ACTION_ID);
+        assert_eq!(buttons[1]["action_id"], DENY_ACTION_ID);
+        assert!(buttons.iter().all(|b| b["value"] == "appr-1"));
+        let header = payload["blocks"][0]["text"]["text"].as_str().unwrap();
+        assert!(header.contains("rm -rf build"), "{header}");
+
+        // The resolved message has no buttons left
+        let updated = approval_resolved_payload("C123", "9.9", "shell", "Denied");
+        assert!(
+            updated["blocks"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .all(|b| b["type"] != "actions")
+        );
+    }
+
+    #[test]
+    fn approval_outcome_labels() {
+        assert_eq!(
+            approval_outcome("approve", "slack:U42"),
+            ":white_check_mark: Approved by <@U42>"
+        );
+        assert_eq!(approval_outcome("deny", "ws"), ":x: Denied via ws");
+        assert!(approval_outcome("skip", "timeout").contains("skipped"));
+        assert!(approval_outcome("deny", "timeout").contains("Expired"));
+    }
 }
@@ -13,14 +13,33 @@ use tracing::{debug, error, info, warn};
 use crate::Result;
 use crate::credential::CredentialStore;
 use crate::error::ZeniiError;
+use crate::event_bus::{AppEvent, EventBus};
+use crate::security::approval::{ApprovalBroker, ApprovalRequest};
 
-use super::message::ChannelMessage;
+use super::allowlist::Allowlist;
+use super::backoff::ReconnectBackoff;
+use super::message::{ChannelAttachment, ChannelMessage};
 use super::traits::{Channel, ChannelLifecycle, ChannelSender, ChannelStatus};
 
 // Status values
 const STATUS_DISCONNECTED: u8 = 0;
 const STATUS_CONNECTING: u8 = 1;
 const STATUS_CONNECTED: u8 = 2;
+const STATUS_RECONNECTING: u8 = 3;
+
+/// First and longest wait between Socket Mode reconnect attempts.
+const RECONNECT_INITIAL: std::time::Duration = std::time::Duration::from_secs(1);
+const RECONNECT_MAX: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// An approval prompt posted to Slack whose buttons are still live.
+#[derive(Debug, Clone)]
+struct PendingApproval {
+    channel_id: String,
+    ts: String,
+    tool_name: String,
+    /// Slack user whose message started the run; the only one who may answer.
+    requester_id: Option<String>,
+}
 
 /// Slack channel using raw Socket Mode WebSocket (no slack-morphism).
 pub struct SlackChannel {
@@ -28,7 +47,11 @@ pub struct SlackChannel {
     /// Bot user ID for echo-loop prevention. Set at build time via `with_bot_id()`,
     /// or auto-resolved from `auth.test` during `connect()`.
     bot_id: Arc<tokio::sync::OnceCell<String>>,
-    allowed_channel_ids: Vec<String>,
+    allowed_channel_ids: Allowlist<String>,
+    /// Latest thread_ts per channel, used to target the typing status.
+    active_threads: parking_lot::Mutex<HashMap<String, String>>,
+    /// Latest sender per channel, recorded as the requester of approval prompts.
+    active_senders: parking_lot::Mutex<HashMap<String, String>>,
     status: AtomicU8,
     credentials: Arc<dyn CredentialStore>,
     http_client: reqwest::Client,
@@ -37,15 +60,26 @@ pub struct SlackChannel {
     shutdown_tx: watch::Sender<bool>,
     shutdown_rx: watch::Receiver<bool>,
     max_reconnect_attempts: u32,
+    /// Inbound files larger than this are not downloaded.
+    max_attachment_bytes: u64,
+    /// Resolves approval button clicks delivered over Socket Mode.
+    approval_broker: Option<Arc<ApprovalBroker>>,
+    /// Approval prompts by approval ID, so they can be updated once decided.
+    pending_approvals: parking_lot::Mutex<HashMap<String, PendingApproval>>,
+    /// Receives reconnecting/connected events when the socket drops and recovers.
+    event_bus: Option<Arc<dyn EventBus>>,
 }
 
 impl SlackChannel {
     pub fn new(credentials: Arc<dyn CredentialStore>) -> Self {
         let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let default_attachment_mb = crate::config::AppConfig::default().channel_attachment_max_mb;
         Self {
             display_name: "slack".to_string(),
             bot_id: Arc::new(tokio::sync::OnceCell::new()),
-            allowed_channel_ids: vec![],
+            allowed_channel_ids: Allowlist::default(),
+            active_threads: parking_lot::Mutex::new(HashMap::new()),
+            active_senders: parking_lot::Mutex::new(HashMap::new()),
             status: AtomicU8::new(STATUS_DISCONNECTED),
             credentials,
             http_client: reqwest::Client::new(),
@@ -54,6 +88,10 @@ impl SlackChannel {
             shutdown_tx,
             shutdown_rx,
             max_reconnect_attempts: 10,
+            max_attachment_bytes: default_attachment_mb * 1024 * 1024,
+            approval_broker: None,
+            pending_approvals: parking_lot::Mutex::new(HashMap::new()),
+            event_bus: None,
         }
     }
 
@@ -68,7 +106,7 @@ impl SlackChannel {
         self
     }
 
-    pub fn with_allowed_channels(mut self, channels: Vec<String>) -> Self {
+    pub fn with_allowed_channels(mut self, channels: Allowlist<String>) -> Self {
         self.allowed_channel_ids = channels;
         self
     }
@@ -78,20 +116,91 @@ impl SlackChannel {
         self
     }
 
+    pub fn with_max_attachment_bytes(mut self, n: u64) -> Self {
+        self.max_attachment_bytes = n;
+        self
+    }
+
+    pub fn with_approval_broker(mut self, broker: Arc<ApprovalBroker>) -> Self {
+        self.approval_broker = Some(broker);
+        self
+    }
+
+    pub fn with_event_bus(mut self, bus: Arc<dyn EventBus>) -> Self {
+        self.event_bus = Some(bus);
+        self
+    }
+
+    /// Remember `user_id` as the latest sender in `channel_id`.
+    pub fn record_sender(&self, channel_id: &str, user_id: &str) {
+        if !user_id.is_empty() {
+            self.active_senders
+                .lock()
+                .insert(channel_id.to_string(), user_id.to_string());
+        }
+    }
+
+    /// Remember a posted approval prompt so it can be updated once decided,
+    /// along with the user it was posted for.
+    pub fn track_approval_prompt(
+        &self,
+        approval_id: &str,
+        tool_name: &str,
+        channel_id: &str,
+        ts: &str,
+    ) {
+        let requester_id = self.active_senders.lock().get(channel_id).cloned();
+        self.pending_approvals.lock().insert(
+            approval_id.to_string(),
+            PendingApproval {
+                channel_id: channel_id.to_string(),
+                ts: ts.to_string(),
+                tool_name: tool_name.to_string(),
+                requester_id,
+            },
+        );
+    }
+
     /// Check if a channel ID is allowed (empty list = allow all).
     pub fn is_channel_allowed(&self, channel_id: &str) -> bool {
-        self.allowed_channel_ids.is_empty()
-            || self.allowed_channel_ids.iter().any(|c| c == channel_id)
+        self.allowed_channel_ids.allows(&channel_id.to_string())
     }
 
     fn status_from_u8(val: u8) -> ChannelStatus {
         match val {
             STATUS_CONNECTING => ChannelStatus::Connecting,
             STATUS_CONNECTED => ChannelStatus::Connected,
+            STATUS_RECONNECTING => ChannelStatus::Reconnecting,
             _ => ChannelStatus::Disconnected,
         }
     }
 
+    /// Count a failed connection and mark the channel reconnecting. Returns the
+    /// wait before the next attempt, or `None` once `max_reconnect_attempts`
+    /// consecutive attempts have failed.
+    fn begin_reconnect(&self, backoff: &mut ReconnectBackoff) -> Option<std::time::Duration> {
+        if backoff.attempt() >= self.max_reconnect_attempts {
+            error!("Slack: max reconnect attempts reached, giving up");
+            self.status.store(STATUS_DISCONNECTED, Ordering::SeqCst);
+            return None;
+        }
+        let delay = backoff.next_delay();
+        self.status.store(STATUS_RECONNECTING, Ordering::SeqCst);
+        if let Some(bus) = &self.event_bus {
+            let _ = bus.publish(AppEvent::ChannelReconnecting {
+                channel: self.display_name.clone(),
+                attempt: backoff.attempt(),
+            });
+        }
+        warn!(
+            "Slack: reconnecting in {}ms (attempt {}/{})",
+            delay.as_millis(),
+            backoff.attempt(),
+            self.max_reconnect_attempts
+        );
+        Some(delay)
+    }
+
     /// Get a WebSocket URL from Slack's apps.connections.open API.
     async fn get_ws_url(
         http_client: &reqwest::Client,
@@ -147,7 +256,7 @@ impl ChannelSender for SlackChannel {
         let channel_ids: Vec<String> = if let Some(cid) = message.metadata.get("channel_id") {
             vec![cid.clone()]
         } else if !self.allowed_channel_ids.is_empty() {
-            self.allowed_channel_ids.clone()
+            self.allowed_channel_ids.to_vec()
         } else {
             return Err(ZeniiError::Channel(
                 "slack: no channel_id in metadata and no allowed_channel_ids configured".into(),
@@ -156,31 +265,47 @@ impl ChannelSender for SlackChannel {
 
         let formatted = super::slack::fmt::to_mrkdwn(&message.content);
 
-        for channel_id in &channel_ids {
-            let mut payload = api::post_message_payload(channel_id, &formatted);
+        let thread_ts = message.metadata.get("thread_ts").map(String::as_str);
 
-            // Thread reply if thread_ts is present
-            if let Some(thread_ts) = message.metadata.get("thread_ts") {
-                payload["thread_ts"] = serde_json::Value::String(thread_ts.clone());
-            }
+        for channel_id in &channel_ids {
+            if !message.content.is_empty() {
+                let mut payload = api::post_message_payload(channel_id, &formatted);
 
-            let resp = self
-                .http_client
-                .post("https://slack.com/api/chat.postMessage")
-                .bearer_auth(bot_token)
-                .json(&payload)
-                .send()
-                .await
-                .map_err(|e| ZeniiError::Channel(format!("slack send failed: {e}")))?;
+                // Thread reply if thread_ts is present
+                if let Some(thread_ts) = thread_ts {
+                    payload["thread_ts"] = serde_json::Value::String(thread_ts.to_string());
+                }
 
-            let body: serde_json::Value = resp
-                .json()
-                .await
-                .map_err(|e| ZeniiError::Channel(format!("slack send parse failed: {e}")))?;
+                let resp = self
+                    .http_client
+                    .post("https://slack.com/api/chat.postMessage")
+                    .bearer_auth(bot_token)
+                    .json(&payload)
+                    .send()
+                    .await
+                    .map_err(|e| ZeniiError::Channel(format!("slack send failed: {e}")))?;
+                check_rate_limit(&resp)?;
+
+                let body: serde_json::Value = resp
+                    .json()
+                    .await
+                    .map_err(|e| ZeniiError::Channel(format!("slack send parse failed: {e}")))?;
+
+                if !body["ok"].as_bool().unwrap_or(false) {
+                    let err = body["error"].as_str().unwrap_or("unknown");
+                    return Err(ZeniiError::Channel(format!("slack send error: {err}")));
+                }
+            }
 
-            if !body["ok"].as_bool().unwrap_or(false) {
-                let err = body["error"].as_str().unwrap_or("unknown");
-                return Err(ZeniiError::Channel(format!("slack send error: {err}")));
+            for attachment in &message.attachments {
+                upload_file(
+                    &self.http_client,
+                    bot_token,
+                    channel_id,
+                    thread_ts,
+                    attachment,
+                )
+                .await?;
             }
         }
 
@@ -291,11 +416,11 @@ impl Channel for SlackChannel {
         let bot_id_ref = bot_id.get().cloned();
         let mut shutdown_rx = self.shutdown_rx.clone();
         let http_client = self.http_client.clone();
-        let max_attempts = self.max_reconnect_attempts;
 
         info!("Slack listen loop started (Socket Mode)");
 
-        let mut reconnect_attempts: u32 = 0;
+        let mut backoff = ReconnectBackoff::new(RECONNECT_INITIAL, RECONNECT_MAX);
+        let mut reconnecting = false;
 
         loop {
             // Check shutdown before attempting connection
@@ -303,44 +428,42 @@ impl Channel for SlackChannel {
                 break;
             }
 
-            // Get WebSocket URL
-            let ws_url = match Self::get_ws_url(&http_client, &app_token).await {
-                Ok(url) => {
-                    reconnect_attempts = 0;
-                    url
-                }
-                Err(e) => {
-                    reconnect_attempts += 1;
-                    if reconnect_attempts > max_attempts {
-                        error!("Slack: max reconnect attempts reached, giving up");
-                        self.status.store(STATUS_DISCONNECTED, Ordering::SeqCst);
-                        return Err(e);
-                    }
-                    warn!("Slack: failed to get WS URL (attempt {reconnect_attempts}): {e}");
-                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
-                    continue;
-                }
+            // Socket Mode URLs are single-use, so every attempt fetches a fresh one
+            let connected = match Self::get_ws_url(&http_client, &app_token).await {
+                Ok(ws_url) => tokio_tungstenite::connect_async(&ws_url)
+                    .await
+                    .map(|(stream, _)| stream)
+                    .map_err(|e| ZeniiError::Channel(format!("slack: ws connect failed: {e}"))),
+                Err(e) => Err(e),
             };
-
-            // Connect WebSocket
-            let ws_stream = match tokio_tungstenite::connect_async(&ws_url).await {
-                Ok((stream, _)) => stream,
+            let ws_stream = match connected {
+                Ok(stream) => stream,
                 Err(e) => {
-                    reconnect_attempts += 1;
-                    if reconnect_attempts > max_attempts {
-                        error!("Slack: max reconnect attempts reached, giving up");
-                        self.status.store(STATUS_DISCONNECTED, Ordering::SeqCst);
-                        return Err(ZeniiError::Channel(format!(
-                            "slack: ws connect failed: {e}"
-                        )));
+                    warn!("Slack: connection attempt failed: {e}");
+                    let Some(delay) = self.begin_reconnect(&mut backoff) else {
+                        return Err(e);
+                    };
+                    reconnecting = true;
+                    tokio::select! {
+                        _ = tokio::time::sleep(delay) => {}
+                        Ok(()) = shutdown_rx.changed() => {}
                     }
-                    warn!("Slack: WS connect failed (attempt {reconnect_attempts}): {e}");
-                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
                     continue;
                 }
             };
 
             info!("Slack Socket Mode connected");
+            backoff.reset();
+            self.status.store(STATUS_CONNECTED, Ordering::SeqCst);
+            if std::mem::take(&mut reconnecting)
+                && let Some(bus) = &self.event_bus
+            {
+                let _ = bus.publish(AppEvent::ChannelConnected {
+                    channel: self.display_name.clone(),
+                });
+            }
+            // Set when Slack announces it is about to close this socket
+            let mut refresh_requested = false;
             let (mut write, mut read) = ws_stream.split();
 
             loop {
@@ -372,11 +495,41 @@ impl Channel for SlackChannel {
                                         }
                                     }
 
+                                    // Slack cycles sockets every few hours and warns first;
+                                    // open the next one now instead of waiting for the close
+                                    if envelope["type"].as_str() == Some("disconnect") {
+                                        info!(
+                                            "Slack: server requested reconnect ({})",
+                                            envelope["reason"].as_str().unwrap_or("unknown")
+                                        );
+                                        refresh_requested = true;
+                                        let _ = futures::SinkExt::close(&mut write).await;
+                                        break;
+                                    }
+
+                                    // Approval button clicks (Socket Mode delivers interactivity here)
+                                    if envelope["type"].as_str() == Some("interactive") {
+                                        if let Some(broker) = &self.approval_broker
+                                            && let Some(action) = api::parse_approval_action(&envelope["payload"])
+                                        {
+                                            let allowed = self.may_resolve_approval(
+                                                &action.approval_id,
+                                                &action.user_id,
+                                                &action.channel_id,
+                                            );
+                                            resolve_approval_action(broker, &http_client, &action, allowed).await;
+                                        }
+                                        continue;
+                                    }
+
                                     // Process events_api type envelopes
                                     if envelope["type"].as_str() == Some("events_api")
                                         && let Some(event) = envelope["payload"]["event"].as_object()
                                         && event.get("type").and_then(|t| t.as_str()) == Some("message")
-                                        && event.get("subtype").is_none()
+                                        && event
+                                            .get("subtype")
+                                            .and_then(|t| t.as_str())
+                                            .is_none_or(|t| t == "file_share")
                                     {
                                         let text_content = event.get("text").and_then(|t| t.as_str()).unwrap_or("");
                                         let user = event.get("user").and_then(|u| u.as_str()).unwrap_or("");
@@ -411,6 +564,7 @@ impl Channel for SlackChannel {
 
                                         let mut metadata = HashMap::new();
                                         metadata.insert("channel_id".into(), channel_id.to_string());
+                                        metadata.insert("message_id".into(), ts.to_string());
                                         if let Some(tts) = thread_ts {
                                             metadata.insert("thread_ts".into(), tts.to_string());
                                         } else {
@@ -418,10 +572,26 @@ impl Channel for SlackChannel {
                                             metadata.insert("thread_ts".into(), ts.to_string());
                                         }
 
-                                        let channel_msg = ChannelMessage::new("slack", text_content)
+                                        if let Some(tts) = metadata.get("thread_ts") {
+                                            self.active_threads
+                                                .lock()
+                                                .insert(channel_id.to_string(), tts.clone());
+                                        }
+                                        self.record_sender(channel_id, user);
+
+                                        let mut channel_msg = ChannelMessage::new("slack", text_content)
                                             .with_sender(user)
                                             .with_metadata(metadata);
 
+                                        if let Some(token) = self.bot_token.get() {
+                                            for attachment in download_files(&http_client, token, event, self.max_attachment_bytes).await {
+                                                channel_msg = channel_msg.with_attachment(attachment);
+                                            }
+                                        }
+                                        if text_content.is_empty() && channel_msg.attachments.is_empty() {
+                                            continue;
+                                        }
+
                                         if let Err(e) = tx.send(channel_msg).await {
                                             error!("Slack: failed to send to router: {e}");
                                             return Ok(());
@@ -442,20 +612,21 @@ impl Channel for SlackChannel {
                 }
             }
 
-            // Auto-reconnect with backoff
-            reconnect_attempts += 1;
-            if reconnect_attempts > max_attempts {
-                error!("Slack: max reconnect attempts reached, giving up");
-                self.status.store(STATUS_DISCONNECTED, Ordering::SeqCst);
+            if refresh_requested {
+                continue;
+            }
+
+            // Socket dropped: reconnect with backoff
+            let Some(delay) = self.begin_reconnect(&mut backoff) else {
                 return Err(ZeniiError::Channel(
                     "slack: max reconnect attempts reached".into(),
                 ));
+            };
+            reconnecting = true;
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                Ok(()) = shutdown_rx.changed() => {}
             }
-            warn!(
-                "Slack: reconnecting (attempt {}/{})",
-                reconnect_attempts, max_attempts
-            );
-            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
         }
 
         info!("Slack listen loop stopped");
@@ -482,6 +653,44 @@ impl Channel for SlackChannel {
         false
     }
 
+    async fn set_typing(&self, recipient: &str, active: bool) -> Result<()> {
+        let bot_token = self
+            .bot_token
+            .get()
+            .ok_or_else(|| ZeniiError::Channel("slack: not connected".into()))?;
+        let thread_ts = self
+            .active_threads
+            .lock()
+            .get(recipient)
+            .cloned()
+            .ok_or_else(|| {
+                ZeniiError::Channel(format!("slack: no active thread in {recipient}"))
+            })?;
+
+        // Bots have no classic typing indicator; the assistant thread status is
+        // the closest equivalent (fails with not_allowed for non-assistant apps).
+        let status = if active { "is typing..." } else { "" };
+        let resp = self
+            .http_client
+            .post("https://slack.com/api/assistant.threads.setStatus")
+            .bearer_auth(bot_token)
+            .json(&api::set_status_payload(recipient, &thread_ts, status))
+            .send()
+            .await
+            .map_err(|e| ZeniiError::Channel(format!("slack: setStatus failed: {e}")))?;
+        let body: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| ZeniiError::Channel(format!("slack: setStatus parse failed: {e}")))?;
+        if !body["ok"].as_bool().unwrap_or(false) {
+            let err = body["error"].as_str().unwrap_or("unknown");
+            return Err(ZeniiError::Channel(format!(
+                "slack: setStatus error: {err}"
+            )));
+        }
+        Ok(())
+    }
+
     async fn on_agent_start(&self, _recipient: Option<&str>) {
         debug!("slack: on_agent_start");
     }
@@ -493,6 +702,261 @@ impl Channel for SlackChannel {
     async fn on_agent_complete(&self, _recipient: Option<&str>) {
         debug!("slack: on_agent_complete");
     }
+
+    async fn on_approval_requested(&self, request: &ApprovalRequest, recipient: Option<&str>) {
+        let (Some(bot_token), Some(channel_id)) = (self.bot_token.get(), recipient) else {
+            return;
+        };
+        let thread_ts = self.active_threads.lock().get(channel_id).cloned();
+        let payload = api::approval_prompt_payload(channel_id, thread_ts.as_deref(), request);
+        let posted = async {
+            let body: serde_json::Value = self
+                .http_client
+                .post("https://slack.com/api/chat.postMessage")
+                .bearer_auth(bot_token)
+                .json(&payload)
+                .send()
+                .await
+                .map_err(|e| ZeniiError::Channel(format!("slack: postMessage failed: {e}")))?
+                .json()
+                .await
+                .map_err(|e| {
+                    ZeniiError::Channel(format!("slack: postMessage parse failed: {e}"))
+                })?;
+            check_ok(&body, "postMessage")?;
+            Ok::<_, ZeniiError>(body["ts"].as_str().unwrap_or_default().to_string())
+        };
+        match posted.await {
+            Ok(ts) => self.track_approval_prompt(
+                &request.approval_id,
+                &request.tool_name,
+                channel_id,
+                &ts,
+            ),
+            Err(e) => warn!("Slack: failed to post approval prompt: {e}"),
+        }
+    }
+
+    async fn on_approval_resolved(&self, approval_id: &str, decision: &str, resolved_by: &str) {
+        let Some(pending) = self.pending_approvals.lock().remove(approval_id) else {
+            return;
+        };
+        let Some(bot_token) = self.bot_token.get() else {
+            return;
+        };
+        let payload = api::approval_resolved_payload(
+            &pending.channel_id,
+            &pending.ts,
+            &pending.tool_name,
+            &api::approval_outcome(decision, resolved_by),
+        );
+        let updated = async {
+            let body: serde_json::Value = self
+                .http_client
+                .post("https://slack.com/api/chat.update")
+                .bearer_auth(bot_token)
+                .json(&payload)
+                .send()
+                .await
+                .map_err(|e| ZeniiError::Channel(format!("slack: chat.update failed: {e}")))?
+                .json()
+                .await
+                .map_err(|e| {
+                    ZeniiError::Channel(format!("slack: chat.update parse failed: {e}"))
+                })?;
+            check_ok(&body, "chat.update")
+        };
+        if let Err(e) = updated.await {
+            warn!("Slack: failed to update approval prompt: {e}");
+        }
+    }
+
+    fn may_resolve_approval(&self, approval_id: &str, user_id: &str, conversation: &str) -> bool {
+        let pending = self.pending_approvals.lock();
+        let Some(prompt) = pending.get(approval_id) else {
+            return false;
+        };
+        prompt.channel_id == conversation
+            && prompt.requester_id.as_deref() == Some(user_id)
+            && self.is_channel_allowed(conversation)
+    }
+}
+
+/// Resolve the pending approval behind a button click. `allowed` is the
+/// channel's [`Channel::may_resolve_approval`] verdict; a click it rejects
+/// leaves the approval pending and gets an ephemeral reply. A click on a
+/// prompt whose approval is gone (decided elsewhere, timed out, daemon
+/// restarted) replaces the prompt through `response_url` so its buttons stop
+/// working. Returns whether the click resolved the approval.
+pub async fn resolve_approval_action(
+    broker: &ApprovalBroker,
+    http_client: &reqwest::Client,
+    action: &api::ApprovalAction,
+    allowed: bool,
+) -> bool {
+    let reply = if !allowed && broker.is_pending(&action.approval_id) {
+        warn!(
+            "Slack: rejected approval click on {} from {} in {}",
+            action.approval_id, action.user_id, action.channel_id
+        );
+        serde_json::json!({
+            "response_type": "ephemeral",
+            "replace_original": false,
+            "text": "Only the person who made this request can answer it."
+        })
+    } else {
+        let resolved_by = format!("slack:{}", action.user_id);
+        if allowed && broker.resolve(&action.approval_id, action.decision, &resolved_by) {
+            return true;
+        }
+        serde_json::json!({
+            "replace_original": true,
+            "text": "This approval request has expired."
+        })
+    };
+    if let Some(url) = &action.response_url
+        && let Err(e) = http_client.post(url).json(&reply).send().await
+    {
+        warn!("Slack: failed to answer approval click: {e}");
+    }
+    false
+}
+
+/// Check a Slack Web API response body, turning `ok: false` into an error.
+fn check_ok(body: &serde_json::Value, method: &str) -> Result<()> {
+    if body["ok"].as_bool().unwrap_or(false) {
+        Ok(())
+    } else {
+        let err = body["error"].as_str().unwrap_or("unknown");
+        Err(ZeniiError::Channel(format!("slack: {method} error: {err}")))
+    }
+}
+
+/// Turn an HTTP 429 from the Web API into a structured rate-limit error.
+fn check_rate_limit(resp: &reqwest::Response) -> Result<()> {
+    if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(ZeniiError::ChannelRateLimited {
+            message: "slack: chat.postMessage rate limited".into(),
+            retry_after_secs: api::retry_after_secs(resp.headers()),
+        });
+    }
+    Ok(())
+}
+
+/// Upload an attachment with the external upload flow (get URL, POST bytes,
+/// complete) and share it into `channel_id`.
+async fn upload_file(
+    http_client: &reqwest::Client,
+    bot_token: &str,
+    channel_id: &str,
+    thread_ts: Option<&str>,
+    attachment: &ChannelAttachment,
+) -> Result<()> {
+    let data = attachment.bytes().await.map_err(|e| {
+        ZeniiError::Channel(format!(
+            "slack: failed to read attachment {}: {e}",
+            attachment.file_name
+        ))
+    })?;
+
+    let body: serde_json::Value = http_client
+        .post("https://slack.com/api/files.getUploadURLExternal")
+        .bearer_auth(bot_token)
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(format!(
+            "filename={}&length={}",
+            urlencoding::encode(&attachment.file_name),
+            data.len()
+        ))
+        .send()
+        .await
+        .map_err(|e| ZeniiError::Channel(format!("slack: getUploadURLExternal failed: {e}")))?
+        .json()
+        .await
+        .map_err(|e| {
+            ZeniiError::Channel(format!("slack: getUploadURLExternal parse failed: {e}"))
+        })?;
+    check_ok(&body, "getUploadURLExternal")?;
+    let (Some(upload_url), Some(file_id)) = (body["upload_url"].as_str(), body["file_id"].as_str())
+    else {
+        return Err(ZeniiError::Channel(
+            "slack: getUploadURLExternal returned no upload_url".into(),
+        ));
+    };
+
+    http_client
+        .post(upload_url)
+        .body(data)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| ZeniiError::Channel(format!("slack: file upload failed: {e}")))?;
+
+    let body: serde_json::Value = http_client
+        .post("https://slack.com/api/files.completeUploadExternal")
+        .bearer_auth(bot_token)
+        .json(&api::complete_upload_payload(
+            file_id,
+            &attachment.file_name,
+            channel_id,
+            thread_ts,
+        ))
+        .send()
+        .await
+        .map_err(|e| ZeniiError::Channel(format!("slack: completeUploadExternal failed: {e}")))?
+        .json()
+        .await
+        .map_err(|e| {
+            ZeniiError::Channel(format!("slack: completeUploadExternal parse failed: {e}"))
+        })?;
+    check_ok(&body, "completeUploadExternal")
+}
+
+/// Download the files of a `file_share` message event. Files over
+/// `max_bytes` or that fail to download are skipped (and logged).
+async fn download_files(
+    http_client: &reqwest::Client,
+    bot_token: &str,
+    event: &serde_json::Map<String, serde_json::Value>,
+    max_bytes: u64,
+) -> Vec<ChannelAttachment> {
+    let Some(files) = event.get("files").and_then(|f| f.as_array()) else {
+        return Vec::new();
+    };
+
+    let mut attachments = Vec::with_capacity(files.len());
+    for file in files {
+        let name = file["name"].as_str().unwrap_or("file");
+        let size = file["size"].as_u64().unwrap_or(0);
+        if size > max_bytes {
+            warn!("Slack: skipping file {name} of {size} bytes (limit {max_bytes})");
+            continue;
+        }
+        let Some(url) = file["url_private_download"]
+            .as_str()
+            .or(file["url_private"].as_str())
+        else {
+            continue;
+        };
+
+        // Private file URLs need the bot token
+        let downloaded = match http_client.get(url).bearer_auth(bot_token).send().await {
+            Ok(resp) => match resp.error_for_status() {
+                Ok(resp) => resp.bytes().await,
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
+        };
+        match downloaded {
+            Ok(bytes) => attachments.push(ChannelAttachment::from_bytes(
+                name,
+                file["mimetype"].as_str().map(String::from),
+                bytes.to_vec(),
+            )),
+            Err(e) => warn!("Slack: failed to download file {name}: {e}"),
+        }
+    }
+    attachments
 }
 
 /// Lightweight send-only handle for Slack.
@@ -518,30 +982,46 @@ impl ChannelSender for SlackSender {
             .get("channel_id")
             .ok_or_else(|| ZeniiError::Channel("slack: missing channel_id in metadata".into()))?;
 
-        let formatted = super::slack::fmt::to_mrkdwn(&message.content);
-        let mut payload = api::post_message_payload(channel_id, &formatted);
+        let thread_ts = message.metadata.get("thread_ts").map(String::as_str);
 
-        if let Some(thread_ts) = message.metadata.get("thread_ts") {
-            payload["thread_ts"] = serde_json::Value::String(thread_ts.clone());
-        }
+        if !message.content.is_empty() {
+            let formatted = super::slack::fmt::to_mrkdwn(&message.content);
+            let mut payload = api::post_message_payload(channel_id, &formatted);
 
-        let resp = self
-            .http_client
-            .post("https://slack.com/api/chat.postMessage")
-            .bearer_auth(bot_token)
-            .json(&payload)
-            .send()
-            .await
-            .map_err(|e| ZeniiError::Channel(format!("slack send failed: {e}")))?;
+            if let Some(thread_ts) = thread_ts {
+                payload["thread_ts"] = serde_json::Value::String(thread_ts.to_string());
+            }
 
-        let body: serde_json::Value = resp
-            .json()
-            .await
-            .map_err(|e| ZeniiError::Channel(format!("slack send parse failed: {e}")))?;
+            let resp = self
+                .http_client
+                .post("https://slack.com/api/chat.postMessage")
+                .bearer_auth(bot_token)
+                .json(&payload)
+                .send()
+                .await
+                .map_err(|e| ZeniiError::Channel(format!("slack send failed: {e}")))?;
+            check_rate_limit(&resp)?;
 
-        if !body["ok"].as_bool().unwrap_or(false) {
-            let err = body["error"].as_str().unwrap_or("unknown");
-            return Err(ZeniiError::Channel(format!("slack send error: {err}")));
+            let body: serde_json::Value = resp
+                .json()
+                .await
+                .map_err(|e| ZeniiError::Channel(format!("slack send parse failed: {e}")))?;
+
+            if !body["ok"].as_bool().unwrap_or(false) {
+                let err = body["error"].as_str().unwrap_or("unknown");
+                return Err(ZeniiError::Channel(format!("slack send error: {err}")));
+            }
+        }
+
+        for attachment in &message.attachments {
+            upload_file(
+                &self.http_client,
+                bot_token,
+                channel_id,
+                thread_ts,
+                attachment,
+            )
+            .await?;
         }
 
         Ok(())
@@ -647,16 +1127,63 @@ mod tests {
         assert!(*ch.shutdown_rx.borrow());
     }
 
+    // set_typing needs a connection
+    #[tokio::test]
+    async fn set_typing_fails_without_connection() {
+        let ch = SlackChannel::new(test_credentials());
+        assert!(ch.set_typing("C123", true).await.is_err());
+    }
+
     // Channel allowlist
     #[test]
     fn channel_allowlist_filters() {
         let ch = SlackChannel::new(test_credentials())
-            .with_allowed_channels(vec!["C123".into(), "C456".into()]);
+            .with_allowed_channels(vec!["C123".into(), "C456".into()].into());
         assert!(ch.is_channel_allowed("C123"));
         assert!(ch.is_channel_allowed("C456"));
         assert!(!ch.is_channel_allowed("C789"));
     }
 
+    // SL.3 — only the requester, clicking in an allowed channel, may answer a prompt
+    #[tokio::test]
+    async fn approval_click_from_other_user_is_rejected() {
+        let ch = SlackChannel::new(test_credentials())
+            .with_allowed_channels(vec!["C1".into(), "C2".into()].into());
+        ch.record_sender("C1", "U1");
+        ch.track_approval_prompt("appr-1", "shell", "C1", "1.0");
+
+        assert!(ch.may_resolve_approval("appr-1", "U1", "C1"));
+        assert!(!ch.may_resolve_approval("appr-1", "U2", "C1"));
+        assert!(!ch.may_resolve_approval("appr-1", "U1", "C2"));
+        assert!(!ch.may_resolve_approval("appr-2", "U1", "C1"));
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let pool = crate::db::init_pool(&dir.path().join("test.db")).unwrap();
+        let broker = ApprovalBroker::new(pool);
+        let _rx = broker.register("appr-1");
+        let action = api::ApprovalAction {
+            approval_id: "appr-1".into(),
+            decision: crate::security::approval::ApprovalDecision::Approve,
+            user_id: "U2".into(),
+            channel_id: "C1".into(),
+            response_url: None,
+        };
+        let allowed =
+            ch.may_resolve_approval(&action.approval_id, &action.user_id, &action.channel_id);
+        assert!(!resolve_approval_action(&broker, &reqwest::Client::new(), &action, allowed).await);
+        assert!(broker.is_pending("appr-1"));
+    }
+
+    // A prompt posted into a channel later removed from the allowlist stops accepting clicks
+    #[test]
+    fn approval_click_from_disallowed_channel_is_rejected() {
+        let ch =
+            SlackChannel::new(test_credentials()).with_allowed_channels(vec!["C2".into()].into());
+        ch.record_sender("C1", "U1");
+        ch.track_approval_prompt("appr-1", "shell", "C1", "1.0");
+        assert!(!ch.may_resolve_approval("appr-1", "U1", "C1"));
+    }
+
     #[test]
     fn empty_allowlist_allows_all() {
         let ch = SlackChannel::new(test_credentials());
@@ -677,4 +1204,31 @@ mod tests {
         let ch = SlackChannel::new(test_credentials());
         assert!(ch.bot_id.get().is_none());
     }
+
+    // SL.RC1 — Each failed attempt emits a reconnecting event until the limit
+    #[tokio::test]
+    async fn slack_reconnect_emits_events_until_limit() {
+        let bus = Arc::new(crate::event_bus::TokioBroadcastBus::new(16));
+        let mut rx = bus.subscribe();
+        let channel = SlackChannel::new(test_credentials())
+            .with_max_reconnect_attempts(2)
+            .with_event_bus(bus);
+        let mut backoff = ReconnectBackoff::new(RECONNECT_INITIAL, RECONNECT_MAX);
+
+        for expected in 1..=2 {
+            let delay = channel.begin_reconnect(&mut backoff).unwrap();
+            assert!(delay <= RECONNECT_MAX);
+            assert_eq!(channel.status(), ChannelStatus::Reconnecting);
+            match rx.recv().await.unwrap() {
+                AppEvent::ChannelReconnecting { channel, attempt } => {
+                    assert_eq!(channel, "slack");
+                    assert_eq!(attempt, expected);
+                }
+                other => panic!("unexpected event: {other:?}"),
+            }
+        }
+
+        assert!(channel.begin_reconnect(&mut backoff).is_none());
+        assert_eq!(channel.status(), ChannelStatus::Disconnected);
+    }
 }
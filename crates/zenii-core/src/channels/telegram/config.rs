@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
+use crate::channels::allowlist::Allowlist;
 use crate::config::AppConfig;
 
 /// Controls who can DM the bot.
@@ -53,7 +54,8 @@ impl Default for RetryPolicy {
 /// Telegram-specific configuration (non-secret tunables).
 #[derive(Debug, Clone)]
 pub struct TelegramConfig {
-    pub allowed_chat_ids: Vec<i64>,
+    /// Shared with the registry so runtime edits apply without reconnecting.
+    pub allowed_chat_ids: Allowlist<i64>,
     pub polling_timeout_secs: u32,
     pub dm_policy: DmPolicy,
     pub retry: RetryPolicy,
@@ -64,7 +66,7 @@ pub struct TelegramConfig {
 impl TelegramConfig {
     pub fn from_app_config(config: &AppConfig) -> Self {
         Self {
-            allowed_chat_ids: vec![],
+            allowed_chat_ids: Allowlist::default(),
             polling_timeout_secs: config.telegram_polling_timeout_secs,
             dm_policy: DmPolicy::from_str_lossy(&config.telegram_dm_policy),
             retry: RetryPolicy {
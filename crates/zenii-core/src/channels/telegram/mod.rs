@@ -7,9 +7,10 @@ use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 
 use async_trait::async_trait;
 use teloxide::Bot;
+use teloxide::net::Download;
 use teloxide::payloads::{GetUpdatesSetters, SendMessageSetters};
 use teloxide::requests::Requester;
-use teloxide::types::{ChatId, MessageId, ParseMode, UpdateKind};
+use teloxide::types::{ChatId, FileMeta, InputFile, Message, MessageId, ParseMode, UpdateKind};
 use tokio::sync::{mpsc, watch};
 use tracing::{debug, error, info, warn};
 
@@ -18,7 +19,7 @@ use crate::config::AppConfig;
 use crate::credential::CredentialStore;
 use crate::error::ZeniiError;
 
-use super::message::ChannelMessage;
+use super::message::{AttachmentKind, ChannelAttachment, ChannelMessage};
 use super::traits::{Channel, ChannelLifecycle, ChannelSender, ChannelStatus};
 
 use config::{BotCommand, DmPolicy, TelegramConfig, parse_bot_command};
@@ -108,6 +109,67 @@ impl TelegramChannel {
         false
     }
 
+    /// Download an inbound media file into an attachment. Audio is capped by
+    /// `transcription_max_audio_mb`, everything else by `channel_attachment_max_mb`.
+    /// Returns `None` (and logs) if the file is too large or the download fails.
+    async fn download_attachment(
+        &self,
+        bot: &Bot,
+        media: InboundMedia<'_>,
+    ) -> Option<ChannelAttachment> {
+        let max_mb = if media.kind == AttachmentKind::Audio {
+            self.app_config.transcription_max_audio_mb
+        } else {
+            self.app_config.channel_attachment_max_mb
+        };
+        let max_bytes = max_mb * 1024 * 1024;
+        if u64::from(media.file.size) > max_bytes {
+            warn!(
+                "Telegram: skipping {:?} of {} bytes (limit {max_bytes})",
+                media.kind, media.file.size
+            );
+            return None;
+        }
+
+        let tg_file = match bot.get_file(media.file.id.clone()).await {
+            Ok(f) => f,
+            Err(e) => {
+                warn!("Telegram: failed to resolve {:?} file: {e}", media.kind);
+                return None;
+            }
+        };
+        let mut data = Vec::with_capacity(tg_file.size as usize);
+        if let Err(e) = bot.download_file(&tg_file.path, &mut data).await {
+            warn!("Telegram: failed to download {:?} file: {e}", media.kind);
+            return None;
+        }
+
+        // Voice notes and photos have no name; keep the server-side extension
+        // so the format can still be detected
+        let file_name = media.file_name.unwrap_or_else(|| {
+            tg_file
+                .path
+                .rsplit('/')
+                .next()
+                .filter(|n| n.contains('.'))
+                .unwrap_or(match media.kind {
+                    AttachmentKind::Audio => "voice.ogg",
+                    AttachmentKind::Image => "photo.jpg",
+                    AttachmentKind::Video => "video.mp4",
+                    _ => "file",
+                })
+                .to_string()
+        });
+
+        Some(ChannelAttachment {
+            kind: media.kind,
+            file_name,
+            mime_type: media.mime_type,
+            data,
+            path: None,
+        })
+    }
+
     /// Handle a bot command with a canned response.
     async fn handle_bot_command(&self, cmd: BotCommand, chat_id: ChatId) {
         let bot = match self.bot.get() {
@@ -143,6 +205,101 @@ impl TelegramChannel {
 /// Telegram max message size in bytes.
 const TELEGRAM_MAX_MESSAGE_BYTES: usize = 4096;
 
+/// Reply sent when a voice note without a caption is too large or fails to download.
+const AUDIO_FAILED_REPLY: &str = "Sorry, I couldn't process that audio message. Please try a shorter recording or send your message as text.";
+
+/// A downloadable media item on an inbound Telegram message.
+struct InboundMedia<'a> {
+    file: &'a FileMeta,
+    kind: AttachmentKind,
+    file_name: Option<String>,
+    mime_type: Option<String>,
+}
+
+/// Collect the voice note, audio, photo (largest size), video or document on a message.
+fn inbound_media(msg: &Message) -> Vec<InboundMedia<'_>> {
+    let mut media = Vec::new();
+    if let Some(voice) = msg.voice() {
+        media.push(InboundMedia {
+            file: &voice.file,
+            kind: AttachmentKind::Audio,
+            file_name: None,
+            mime_type: voice.mime_type.as_ref().map(|m| m.to_string()),
+        });
+    }
+    if let Some(audio) = msg.audio() {
+        media.push(InboundMedia {
+            file: &audio.file,
+            kind: AttachmentKind::Audio,
+            file_name: audio.file_name.clone(),
+            mime_type: audio.mime_type.as_ref().map(|m| m.to_string()),
+        });
+    }
+    if let Some(photo) = msg.photo().and_then(|sizes| sizes.last()) {
+        media.push(InboundMedia {
+            file: &photo.file,
+            kind: AttachmentKind::Image,
+            file_name: None,
+            mime_type: Some("image/jpeg".into()),
+        });
+    }
+    if let Some(video) = msg.video() {
+        media.push(InboundMedia {
+            file: &video.file,
+            kind: AttachmentKind::Video,
+            file_name: video.file_name.clone(),
+            mime_type: video.mime_type.as_ref().map(|m| m.to_string()),
+        });
+    }
+    if let Some(doc) = msg.document() {
+        let mime_type = doc.mime_type.as_ref().map(|m| m.to_string());
+        media.push(InboundMedia {
+            file: &doc.file,
+            kind: AttachmentKind::from_mime(mime_type.as_deref()),
+            file_name: doc.file_name.clone(),
+            mime_type,
+        });
+    }
+    media
+}
+
+/// Map a Bot API send failure, keeping flood-control waits structured so the
+/// registry can honor them.
+fn send_error(e: teloxide::RequestError) -> ZeniiError {
+    match e {
+        teloxide::RequestError::RetryAfter(wait) => ZeniiError::ChannelRateLimited {
+            message: format!("telegram flood control, retry after {}s", wait.seconds()),
+            retry_after_secs: Some(wait.seconds().into()),
+        },
+        e => ZeniiError::Channel(format!("telegram send failed: {e}")),
+    }
+}
+
+/// Send each attachment as a photo, video, audio file or document.
+async fn send_attachments(
+    bot: &Bot,
+    chat_id: ChatId,
+    attachments: &[ChannelAttachment],
+) -> Result<()> {
+    for attachment in attachments {
+        let data = attachment.bytes().await.map_err(|e| {
+            ZeniiError::Channel(format!(
+                "telegram: failed to read attachment {}: {e}",
+                attachment.file_name
+            ))
+        })?;
+        let file = InputFile::memory(data).file_name(attachment.file_name.clone());
+        let sent = match attachment.kind {
+            AttachmentKind::Image => bot.send_photo(chat_id, file).await.map(|_| ()),
+            AttachmentKind::Video => bot.send_video(chat_id, file).await.map(|_| ()),
+            AttachmentKind::Audio => bot.send_audio(chat_id, file).await.map(|_| ()),
+            _ => bot.send_document(chat_id, file).await.map(|_| ()),
+        };
+        sent.map_err(|e| ZeniiError::Channel(format!("telegram upload failed: {e}")))?;
+    }
+    Ok(())
+}
+
 #[async_trait]
 impl ChannelSender for TelegramChannel {
     fn channel_type(&self) -> &str {
@@ -163,7 +320,7 @@ impl ChannelSender for TelegramChannel {
             })?;
             vec![id]
         } else if !self.config.allowed_chat_ids.is_empty() {
-            self.config.allowed_chat_ids.clone()
+            self.config.allowed_chat_ids.to_vec()
         } else {
             return Err(ZeniiError::Channel(
                 "telegram: no chat_id in metadata and no allowed_chat_ids configured".into(),
@@ -176,12 +333,15 @@ impl ChannelSender for TelegramChannel {
         let html_content = fmt::markdown_to_html(&message.content);
         let parts = super::format::split_message(&html_content, TELEGRAM_MAX_MESSAGE_BYTES);
         for &cid in &chat_ids {
-            for part in &parts {
-                bot.send_message(ChatId(cid), part)
-                    .parse_mode(ParseMode::Html)
-                    .await
-                    .map_err(|e| ZeniiError::Channel(format!("telegram send failed: {e}")))?;
+            if !message.content.is_empty() {
+                for part in &parts {
+                    bot.send_message(ChatId(cid), part)
+                        .parse_mode(ParseMode::Html)
+                        .await
+                        .map_err(send_error)?;
+                }
             }
+            send_attachments(bot, ChatId(cid), &message.attachments).await?;
         }
 
         Ok(())
@@ -325,9 +485,12 @@ impl Channel for TelegramChannel {
                             for update in updates {
                                 offset = update.id.as_offset();
 
-                                if let UpdateKind::Message(msg) = update.kind
-                                    && let Some(text) = msg.text()
-                                {
+                                if let UpdateKind::Message(msg) = update.kind {
+                                    let media = inbound_media(&msg);
+                                    // Captions only accompany media we handle; they are not routed on their own
+                                    let Some(text) = msg.text().or_else(|| (!media.is_empty()).then(|| msg.caption().unwrap_or(""))) else {
+                                        continue;
+                                    };
                                     let chat_id = msg.chat.id.0;
 
                                     // Check DM policy
@@ -363,10 +526,27 @@ impl Channel for TelegramChannel {
                                     metadata.insert("chat_id".into(), chat_id.to_string());
                                     metadata.insert("message_id".into(), msg.id.0.to_string());
 
-                                    let channel_msg = ChannelMessage::new("telegram", text)
+                                    let mut channel_msg = ChannelMessage::new("telegram", text)
                                         .with_sender(&sender_name)
                                         .with_metadata(metadata);
 
+                                    let mut audio_failed = false;
+                                    for item in media {
+                                        let kind = item.kind;
+                                        match self.download_attachment(&bot, item).await {
+                                            Some(attachment) => channel_msg = channel_msg.with_attachment(attachment),
+                                            None if kind == AttachmentKind::Audio => audio_failed = true,
+                                            None => {}
+                                        }
+                                    }
+                                    if text.is_empty() && channel_msg.attachments.is_empty() {
+                                        // Tell the sender instead of dropping their voice note silently
+                                        if audio_failed && let Err(e) = bot.send_message(msg.chat.id, AUDIO_FAILED_REPLY).await {
+                                            warn!("Telegram: failed to send audio failure reply: {e}");
+                                        }
+                                        continue;
+                                    }
+
                                     if let Err(e) = tx.send(channel_msg).await {
                                         error!("Telegram: failed to send to router: {e}");
                                         break;
@@ -411,6 +591,24 @@ impl Channel for TelegramChannel {
         }
     }
 
+    async fn set_typing(&self, recipient: &str, active: bool) -> Result<()> {
+        // Telegram has no "stop typing": the action expires after ~5s or on the next message
+        if !active {
+            return Ok(());
+        }
+        let bot = self
+            .bot
+            .get()
+            .ok_or_else(|| ZeniiError::Channel("telegram: not connected".into()))?;
+        let chat_id = recipient
+            .parse::<i64>()
+            .map_err(|_| ZeniiError::Channel(format!("telegram: invalid chat_id {recipient}")))?;
+        bot.send_chat_action(ChatId(chat_id), teloxide::types::ChatAction::Typing)
+            .await
+            .map_err(|e| ZeniiError::Channel(format!("telegram: sendChatAction failed: {e}")))?;
+        Ok(())
+    }
+
     async fn on_agent_start(&self, recipient: Option<&str>) {
         let Some(bot) = self.bot.get() else { return };
         let Some(chat_id_str) = recipient else { return };
@@ -418,12 +616,7 @@ impl Channel for TelegramChannel {
             return;
         };
 
-        // 1. Send initial typing action
-        let _ = bot
-            .send_chat_action(ChatId(chat_id), teloxide::types::ChatAction::Typing)
-            .await;
-
-        // 2. Send status message
+        // 1. Send status message (the initial typing action comes from set_typing)
         if let Ok(msg) = bot
             .send_message(ChatId(chat_id), "Processing your request...")
             .await
@@ -432,7 +625,7 @@ impl Channel for TelegramChannel {
             status.insert(chat_id, msg.id);
         }
 
-        // 3. Spawn background typing refresh loop
+        // 2. Spawn background typing refresh loop
         let refresh_secs = self.app_config.telegram_status_refresh_secs;
         let bot_clone = bot.clone();
         let status_messages = self.status_messages.clone();
@@ -528,16 +721,18 @@ impl ChannelSender for TelegramSender {
             ZeniiError::Channel(format!("telegram: invalid chat_id: {chat_id_str}"))
         })?;
 
-        let html_content = fmt::markdown_to_html(&message.content);
-        let parts = super::format::split_message(&html_content, TELEGRAM_MAX_MESSAGE_BYTES);
-        for part in parts {
-            bot.send_message(ChatId(chat_id), &part)
-                .parse_mode(ParseMode::Html)
-                .await
-                .map_err(|e| ZeniiError::Channel(format!("telegram send failed: {e}")))?;
+        if !message.content.is_empty() {
+            let html_content = fmt::markdown_to_html(&message.content);
+            let parts = super::format::split_message(&html_content, TELEGRAM_MAX_MESSAGE_BYTES);
+            for part in parts {
+                bot.send_message(ChatId(chat_id), &part)
+                    .parse_mode(ParseMode::Html)
+                    .await
+                    .map_err(send_error)?;
+            }
         }
 
-        Ok(())
+        send_attachments(bot, ChatId(chat_id), &message.attachments).await
     }
 }
 
@@ -558,7 +753,7 @@ mod tests {
 
     fn test_config() -> TelegramConfig {
         TelegramConfig {
-            allowed_chat_ids: vec![100, 200],
+            allowed_chat_ids: vec![100, 200].into(),
             polling_timeout_secs: 30,
             dm_policy: DmPolicy::Allowlist,
             retry: RetryPolicy::default(),
@@ -668,6 +863,14 @@ mod tests {
         assert_eq!(parse_bot_command("/cancel"), Some(BotCommand::Cancel));
     }
 
+    // Typing needs a connection; clearing it is always a no-op
+    #[tokio::test]
+    async fn telegram_set_typing_requires_connection() {
+        let ch = TelegramChannel::new(test_config(), test_credentials(), test_app_config());
+        assert!(ch.set_typing("42", true).await.is_err());
+        assert!(ch.set_typing("42", false).await.is_ok());
+    }
+
     // 8.8.1 — Telegram on_agent_start does not panic
     #[tokio::test]
     async fn telegram_on_agent_start() {
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 
 use crate::Result;
+use crate::security::approval::ApprovalRequest;
 
 use super::message::ChannelMessage;
 
@@ -75,6 +76,13 @@ pub trait Channel: ChannelLifecycle + ChannelSender {
     async fn listen(&self, tx: mpsc::Sender<ChannelMessage>) -> Result<()>;
     async fn health_check(&self) -> bool;
 
+    /// Show (`active = true`) or clear the typing indicator for `recipient`.
+    /// Called by the router around each agent turn; best-effort, errors are
+    /// logged and ignored. Default: no-op.
+    async fn set_typing(&self, _recipient: &str, _active: bool) -> Result<()> {
+        Ok(())
+    }
+
     /// Called when the agent starts processing a message. Show typing/status.
     async fn on_agent_start(&self, _recipient: Option<&str>) {}
 
@@ -83,6 +91,26 @@ pub trait Channel: ChannelLifecycle + ChannelSender {
 
     /// Called when the agent completes processing. Cleanup status.
     async fn on_agent_complete(&self, _recipient: Option<&str>) {}
+
+    /// Called when a tool call in this conversation waits for approval.
+    /// Channels with interactive messages can offer approve/deny controls.
+    async fn on_approval_requested(&self, _request: &ApprovalRequest, _recipient: Option<&str>) {}
+
+    /// Called when that approval is decided, from any surface or by timeout
+    /// (`resolved_by == "timeout"`).
+    async fn on_approval_resolved(&self, _approval_id: &str, _decision: &str, _resolved_by: &str) {}
+
+    /// Whether `user_id`, clicking in `conversation`, may answer the prompt
+    /// this channel posted for `approval_id`. Default: no, since only
+    /// channels that post prompts receive clicks.
+    fn may_resolve_approval(
+        &self,
+        _approval_id: &str,
+        _user_id: &str,
+        _conversation: &str,
+    ) -> bool {
+        false
+    }
 }
 
 #[cfg(test)]
@@ -175,6 +203,14 @@ mod tests {
         // No panic = pass
     }
 
+    // Default set_typing is a no-op that succeeds
+    #[tokio::test]
+    async fn default_set_typing_noop() {
+        let ch = MockLifecycleChannel;
+        assert!(ch.set_typing("user1", true).await.is_ok());
+        assert!(ch.set_typing("user1", false).await.is_ok());
+    }
+
     #[test]
     fn status_default_disconnected() {
         let status = ChannelStatus::default();
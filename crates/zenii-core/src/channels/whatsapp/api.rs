@@ -0,0 +1,196 @@
+use serde_json::{Value, json};
+
+use crate::channels::signature::verify_hmac_sha256;
+
+/// Default Graph API base URL for the WhatsApp Business Cloud API.
+pub const GRAPH_API_BASE: &str = "https://graph.facebook.com/v21.0";
+
+/// A text message extracted from a WhatsApp webhook payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InboundMessage {
+    /// Sender's WhatsApp ID (phone number without `+`).
+    pub from: String,
+    pub message_id: String,
+    pub text: String,
+    /// Profile name the sender set in WhatsApp, if included.
+    pub profile_name: Option<String>,
+    /// Business phone number ID that received the message.
+    pub phone_number_id: Option<String>,
+}
+
+/// Verify the `X-Hub-Signature-256` header (`sha256=<hex>`) against the raw
+/// request body using the app secret. Comparison is constant-time.
+pub fn verify_signature(app_secret: &str, body: &[u8], header: &str) -> bool {
+    header
+        .strip_prefix("sha256=")
+        .is_some_and(|hex_sig| verify_hmac_sha256(app_secret, &[body], hex_sig))
+}
+
+/// Extract text messages from a webhook payload. Status updates, reactions and
+/// media messages are ignored.
+pub fn parse_webhook(payload: &Value) -> Vec<InboundMessage> {
+    let mut out = Vec::new();
+    let entries = payload["entry"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    for entry in entries {
+        let changes = entry["changes"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        for change in changes {
+            if change["field"].as_str() != Some("messages") {
+                continue;
+            }
+            let value = &change["value"];
+            let phone_number_id = value["metadata"]["phone_number_id"]
+                .as_str()
+                .map(str::to_string);
+            let contacts = value["contacts"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            let messages = value["messages"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            for msg in messages {
+                if msg["type"].as_str() != Some("text") {
+                    continue;
+                }
+                let (Some(from), Some(text)) = (msg["from"].as_str(), msg["text"]["body"].as_str())
+                else {
+                    continue;
+                };
+                let profile_name = contacts
+                    .iter()
+                    .find(|c| c["wa_id"].as_str() == Some(from))
+                    .and_then(|c| c["profile"]["name"].as_str())
+                    .map(str::to_string);
+                out.push(InboundMessage {
+                    from: from.to_string(),
+                    message_id: msg["id"].as_str().unwrap_or_default().to_string(),
+                    text: text.to_string(),
+                    profile_name,
+                    phone_number_id: phone_number_id.clone(),
+                });
+            }
+        }
+    }
+    out
+}
+
+/// Build JSON payload for the Graph API `/{phone_number_id}/messages` endpoint.
+pub fn text_message_payload(to: &str, body: &str) -> Value {
+    json!({
+        "messaging_product": "whatsapp",
+        "recipient_type": "individual",
+        "to": to,
+        "type": "text",
+        "text": { "preview_url": false, "body": body }
+    })
+}
+
+/// Strip everything but digits so `+1 (555) 010-0000` matches the `15550100000` wa_id.
+pub fn normalize_number(number: &str) -> String {
+    number.chars().filter(char::is_ascii_digit).collect()
+}
+
+/// Check if a sender number is allowed (empty list = allow all).
+pub fn is_number_allowed(allowed: &[String], number: &str) -> bool {
+    let number = normalize_number(number);
+    allowed.is_empty() || allowed.iter().any(|a| normalize_number(a) == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use hmac::{Hmac, KeyInit, Mac};
+    use sha2::Sha256;
+
+    use super::*;
+
+    fn sign(secret: &str, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        let digest = mac.finalize().into_bytes();
+        let hex: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+        format!("sha256={hex}")
+    }
+
+    fn sample_payload() -> Value {
+        json!({
+            "object": "whatsapp_business_account",
+            "entry": [{
+                "id": "WABA_ID",
+                "changes": [{
+                    "field": "messages",
+                    "value": {
+                        "messaging_product": "whatsapp",
+                        "metadata": { "display_phone_number": "15550001111", "phone_number_id": "PNID" },
+                        "contacts": [{ "profile": { "name": "Ada" }, "wa_id": "15551234567" }],
+                        "messages": [
+                            { "from": "15551234567", "id": "wamid.1", "timestamp": "1", "type": "text", "text": { "body": "hello" } },
+                            { "from": "15551234567", "id": "wamid.2", "timestamp": "2", "type": "image", "image": { "id": "m1" } }
+                        ]
+                    }
+                }]
+            }]
+        })
+    }
+
+    #[test]
+    fn signature_roundtrip() {
+        let body = br#"{"object":"whatsapp_business_account"}"#;
+        let header = sign("secret", body);
+        assert!(verify_signature("secret", body, &header));
+        assert!(!verify_signature("other", body, &header));
+        assert!(!verify_signature("secret", b"tampered", &header));
+    }
+
+    #[test]
+    fn signature_rejects_malformed_header() {
+        let body = b"{}";
+        assert!(!verify_signature("secret", body, ""));
+        assert!(!verify_signature("secret", body, "sha1=abcd"));
+        assert!(!verify_signature("secret", body, "sha256=zz"));
+        assert!(!verify_signature("secret", body, "sha256=abc"));
+    }
+
+    #[test]
+    fn parse_webhook_extracts_text_messages() {
+        let msgs = parse_webhook(&sample_payload());
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(msgs[0].from, "15551234567");
+        assert_eq!(msgs[0].message_id, "wamid.1");
+        assert_eq!(msgs[0].text, "hello");
+        assert_eq!(msgs[0].profile_name.as_deref(), Some("Ada"));
+        assert_eq!(msgs[0].phone_number_id.as_deref(), Some("PNID"));
+    }
+
+    #[test]
+    fn parse_webhook_ignores_status_updates() {
+        let payload = json!({
+            "entry": [{ "changes": [{ "field": "messages", "value": { "statuses": [{ "id": "wamid.1", "status": "read" }] } }] }]
+        });
+        assert!(parse_webhook(&payload).is_empty());
+        assert!(parse_webhook(&json!({})).is_empty());
+    }
+
+    #[test]
+    fn text_message_payload_test() {
+        let payload = text_message_payload("15551234567", "hi");
+        assert_eq!(payload["messaging_product"], "whatsapp");
+        assert_eq!(payload["to"], "15551234567");
+        assert_eq!(payload["type"], "text");
+        assert_eq!(payload["text"]["body"], "hi");
+    }
+
+    #[test]
+    fn number_allowlist_normalizes() {
+        let allowed = vec!["+1 (555) 123-4567".to_string()];
+        assert!(is_number_allowed(&allowed, "15551234567"));
+        assert!(!is_number_allowed(&allowed, "15559999999"));
+        assert!(is_number_allowed(&[], "15559999999"));
+    }
+}
@@ -0,0 +1,457 @@
+pub mod api;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use async_trait::async_trait;
+use tokio::sync::{OnceCell, mpsc, watch};
+use tracing::{debug, info};
+
+use crate::Result;
+use crate::credential::CredentialStore;
+use crate::error::ZeniiError;
+
+use super::format::{WHATSAPP_MAX_LENGTH, split_message};
+use super::message::ChannelMessage;
+use super::traits::{Channel, ChannelLifecycle, ChannelSender, ChannelStatus};
+
+// Status values
+const STATUS_DISCONNECTED: u8 = 0;
+const STATUS_CONNECTING: u8 = 1;
+const STATUS_CONNECTED: u8 = 2;
+
+/// WhatsApp channel using the WhatsApp Business Cloud API.
+///
+/// Outbound messages go through the Graph API. Inbound messages arrive on the
+/// gateway webhook (`/channels/whatsapp/webhook`), which verifies the
+/// `X-Hub-Signature-256` header and feeds them to the channel router, so
+/// `listen()` only parks until disconnect.
+pub struct WhatsAppChannel {
+    display_name: String,
+    allowed_numbers: Vec<String>,
+    status: AtomicU8,
+    credentials: Arc<dyn CredentialStore>,
+    http_client: reqwest::Client,
+    api_base: String,
+    // Shared with senders created before `connect()` (registration happens first on boot).
+    phone_number_id: Arc<OnceCell<String>>,
+    access_token: Arc<OnceCell<String>>,
+    shutdown_tx: watch::Sender<bool>,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl WhatsAppChannel {
+    pub fn new(credentials: Arc<dyn CredentialStore>) -> Self {
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        Self {
+            display_name: "whatsapp".to_string(),
+            allowed_numbers: vec![],
+            status: AtomicU8::new(STATUS_DISCONNECTED),
+            credentials,
+            http_client: reqwest::Client::new(),
+            api_base: api::GRAPH_API_BASE.to_string(),
+            phone_number_id: Arc::new(OnceCell::new()),
+            access_token: Arc::new(OnceCell::new()),
+            shutdown_tx,
+            shutdown_rx,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.display_name = name.to_string();
+        self
+    }
+
+    pub fn with_allowed_numbers(mut self, numbers: Vec<String>) -> Self {
+        self.allowed_numbers = numbers;
+        self
+    }
+
+    /// Override the Graph API base URL (tests, API version pinning).
+    pub fn with_api_base(mut self, base: &str) -> Self {
+        self.api_base = base.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Check if a sender number is allowed (empty list = allow all).
+    pub fn is_number_allowed(&self, number: &str) -> bool {
+        api::is_number_allowed(&self.allowed_numbers, number)
+    }
+
+    fn status_from_u8(val: u8) -> ChannelStatus {
+        match val {
+            STATUS_CONNECTING => ChannelStatus::Connecting,
+            STATUS_CONNECTED => ChannelStatus::Connected,
+            _ => ChannelStatus::Disconnected,
+        }
+    }
+
+    async fn credential(&self, field: &str) -> Result<String> {
+        self.credentials
+            .get(&format!("channel:whatsapp:{field}"))
+            .await
+            .map_err(|e| ZeniiError::Channel(format!("whatsapp: credential error: {e}")))?
+            .ok_or_else(|| ZeniiError::Channel(format!("whatsapp: {field} not configured")))
+    }
+
+    fn sender(&self) -> WhatsAppSender {
+        WhatsAppSender {
+            allowed_numbers: self.allowed_numbers.clone(),
+            http_client: self.http_client.clone(),
+            api_base: self.api_base.clone(),
+            phone_number_id: Arc::clone(&self.phone_number_id),
+            access_token: Arc::clone(&self.access_token),
+        }
+    }
+}
+
+impl Default for WhatsAppChannel {
+    fn default() -> Self {
+        Self::new(Arc::new(crate::credential::InMemoryCredentialStore::new()))
+    }
+}
+
+#[async_trait]
+impl ChannelSender for WhatsAppChannel {
+    fn channel_type(&self) -> &str {
+        "whatsapp"
+    }
+
+    async fn send_message(&self, message: ChannelMessage) -> Result<()> {
+        self.sender().send_message(message).await
+    }
+}
+
+#[async_trait]
+impl ChannelLifecycle for WhatsAppChannel {
+    fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    async fn connect(&self) -> Result<()> {
+        self.status.store(STATUS_CONNECTING, Ordering::SeqCst);
+
+        let creds = async {
+            Ok::<_, ZeniiError>((
+                self.credential("phone_number_id").await?,
+                self.credential("access_token").await?,
+            ))
+        }
+        .await;
+        let (phone_number_id, access_token) = match creds {
+            Ok(c) => c,
+            Err(e) => {
+                self.status.store(STATUS_DISCONNECTED, Ordering::SeqCst);
+                return Err(e);
+            }
+        };
+
+        // Validate the token against the phone number resource
+        let resp = self
+            .http_client
+            .get(format!("{}/{phone_number_id}", self.api_base))
+            .bearer_auth(&access_token)
+            .send()
+            .await
+            .map_err(|e| {
+                self.status.store(STATUS_DISCONNECTED, Ordering::SeqCst);
+                ZeniiError::Channel(format!("whatsapp: phone number lookup failed: {e}"))
+            })?;
+
+        if !resp.status().is_success() {
+            self.status.store(STATUS_DISCONNECTED, Ordering::SeqCst);
+            let status = resp.status();
+            let body: serde_json::Value = resp.json().await.unwrap_or_default();
+            let err = body["error"]["message"].as_str().unwrap_or("unknown");
+            return Err(ZeniiError::Channel(format!(
+                "whatsapp: phone number lookup error ({status}): {err}"
+            )));
+        }
+
+        let _ = self.phone_number_id.set(phone_number_id.clone());
+        let _ = self.access_token.set(access_token);
+        let _ = self.shutdown_tx.send(false);
+
+        info!("WhatsApp channel connected (phone_number_id={phone_number_id})");
+        self.status.store(STATUS_CONNECTED, Ordering::SeqCst);
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> Result<()> {
+        let _ = self.shutdown_tx.send(true);
+        self.status.store(STATUS_DISCONNECTED, Ordering::SeqCst);
+        info!("WhatsApp channel disconnected");
+        Ok(())
+    }
+
+    fn status(&self) -> ChannelStatus {
+        Self::status_from_u8(self.status.load(Ordering::SeqCst))
+    }
+
+    fn create_sender(&self) -> Box<dyn ChannelSender> {
+        Box::new(self.sender())
+    }
+}
+
+#[async_trait]
+impl Channel for WhatsAppChannel {
+    async fn listen(&self, _tx: mpsc::Sender<ChannelMessage>) -> Result<()> {
+        if self.access_token.get().is_none() {
+            return Err(ZeniiError::Channel(
+                "whatsapp: not connected, call connect() first".into(),
+            ));
+        }
+
+        // Inbound traffic is pushed to the gateway webhook; just wait for shutdown.
+        let mut shutdown_rx = self.shutdown_rx.clone();
+        info!("WhatsApp listen loop started (webhook mode)");
+        while !*shutdown_rx.borrow() {
+            if shutdown_rx.changed().await.is_err() {
+                break;
+            }
+        }
+        info!("WhatsApp listen loop stopped");
+        Ok(())
+    }
+
+    async fn health_check(&self) -> bool {
+        if self.status.load(Ordering::SeqCst) != STATUS_CONNECTED {
+            return false;
+        }
+        let (Some(phone_number_id), Some(token)) =
+            (self.phone_number_id.get(), self.access_token.get())
+        else {
+            return false;
+        };
+        self.http_client
+            .get(format!("{}/{phone_number_id}", self.api_base))
+            .bearer_auth(token)
+            .send()
+            .await
+            .map(|r| r.status().is_success())
+            .unwrap_or(false)
+    }
+
+    async fn on_agent_start(&self, _recipient: Option<&str>) {
+        debug!("whatsapp: on_agent_start");
+    }
+
+    async fn on_tool_use(&self, tool_name: &str, _recipient: Option<&str>) {
+        debug!("whatsapp: on_tool_use ({tool_name})");
+    }
+
+    async fn on_agent_complete(&self, _recipient: Option<&str>) {
+        debug!("whatsapp: on_agent_complete");
+    }
+}
+
+/// Lightweight send-only handle for WhatsApp.
+struct WhatsAppSender {
+    allowed_numbers: Vec<String>,
+    http_client: reqwest::Client,
+    api_base: String,
+    phone_number_id: Arc<OnceCell<String>>,
+    access_token: Arc<OnceCell<String>>,
+}
+
+#[async_trait]
+impl ChannelSender for WhatsAppSender {
+    fn channel_type(&self) -> &str {
+        "whatsapp"
+    }
+
+    async fn send_message(&self, message: ChannelMessage) -> Result<()> {
+        let (Some(phone_number_id), Some(token)) =
+            (self.phone_number_id.get(), self.access_token.get())
+        else {
+            return Err(ZeniiError::Channel("whatsapp: not connected".into()));
+        };
+
+        // Reply to the originating chat if known, otherwise broadcast to the
+        // allowlist (scheduler/notification use).
+        let recipients: Vec<String> = if let Some(to) = message.metadata.get("chat_id") {
+            vec![to.clone()]
+        } else if !self.allowed_numbers.is_empty() {
+            self.allowed_numbers
+                .iter()
+                .map(|n| api::normalize_number(n))
+                .collect()
+        } else {
+            return Err(ZeniiError::Channel(
+                "whatsapp: no chat_id in metadata and no allowed numbers configured".into(),
+            ));
+        };
+
+        let url = format!("{}/{phone_number_id}/messages", self.api_base);
+        for to in &recipients {
+            for part in split_message(&message.content, WHATSAPP_MAX_LENGTH) {
+                let resp = self
+                    .http_client
+                    .post(&url)
+                    .bearer_auth(token)
+                    .json(&api::text_message_payload(to, &part))
+                    .send()
+                    .await
+                    .map_err(|e| ZeniiError::Channel(format!("whatsapp send failed: {e}")))?;
+
+                if !resp.status().is_success() {
+                    let status = resp.status();
+                    let body: serde_json::Value = resp.json().await.unwrap_or_default();
+                    let err = body["error"]["message"].as_str().unwrap_or("unknown");
+                    return Err(ZeniiError::Channel(format!(
+                        "whatsapp send error ({status}): {err}"
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use crate::credential::InMemoryCredentialStore;
+
+    async fn test_credentials() -> Arc<dyn CredentialStore> {
+        let store = InMemoryCredentialStore::new();
+        store
+            .set("channel:whatsapp:phone_number_id", "PNID")
+            .await
+            .unwrap();
+        store
+            .set("channel:whatsapp:access_token", "token")
+            .await
+            .unwrap();
+        Arc::new(store)
+    }
+
+    /// Minimal Graph API stand-in: answers every request with 200 `{}` and
+    /// records the request bodies it received.
+    async fn mock_graph() -> (String, Arc<tokio::sync::Mutex<Vec<String>>>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let seen = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+        tokio::spawn(async move {
+            while let Ok((mut sock, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 64 * 1024];
+                let mut req = Vec::new();
+                loop {
+                    let n = sock.read(&mut buf).await.unwrap_or(0);
+                    if n == 0 {
+                        break;
+                    }
+                    req.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&req);
+                    if let Some(idx) = text.find("\r\n\r\n") {
+                        let len = text[..idx]
+                            .lines()
+                            .find_map(|l| {
+                                l.to_ascii_lowercase()
+                                    .strip_prefix("content-length:")
+                                    .map(|v| v.trim().parse::<usize>().unwrap_or(0))
+                            })
+                            .unwrap_or(0);
+                        if req.len() >= idx + 4 + len {
+                            seen_clone
+                                .lock()
+                                .await
+                                .push(String::from_utf8_lossy(&req[idx + 4..]).to_string());
+                            break;
+                        }
+                    }
+                }
+                let resp = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 2\r\nconnection: close\r\n\r\n{}";
+                let _ = sock.write_all(resp.as_bytes()).await;
+            }
+        });
+        (format!("http://{addr}"), seen)
+    }
+
+    #[test]
+    fn channel_type_whatsapp() {
+        let ch = WhatsAppChannel::default();
+        assert_eq!(ch.channel_type(), "whatsapp");
+        assert_eq!(ch.display_name(), "whatsapp");
+    }
+
+    #[test]
+    fn initial_status_disconnected() {
+        let ch = WhatsAppChannel::default();
+        assert_eq!(ch.status(), ChannelStatus::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn connect_fails_without_credentials() {
+        let ch = WhatsAppChannel::default();
+        assert!(ch.connect().await.is_err());
+        assert_eq!(ch.status(), ChannelStatus::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn send_and_listen_fail_without_connection() {
+        let ch = WhatsAppChannel::default();
+        let msg = ChannelMessage::new("whatsapp", "test");
+        assert!(ch.send_message(msg).await.is_err());
+        let (tx, _rx) = mpsc::channel(10);
+        assert!(ch.listen(tx).await.is_err());
+    }
+
+    #[test]
+    fn number_allowlist_filters() {
+        let ch = WhatsAppChannel::default().with_allowed_numbers(vec!["+15551234567".into()]);
+        assert!(ch.is_number_allowed("15551234567"));
+        assert!(!ch.is_number_allowed("15550000000"));
+        assert!(WhatsAppChannel::default().is_number_allowed("15550000000"));
+    }
+
+    // Long replies are split into multiple Graph API calls under the 4096 limit,
+    // and a sender created before connect() picks up the credentials.
+    #[tokio::test]
+    async fn send_splits_long_messages() {
+        let (base, seen) = mock_graph().await;
+        let ch = WhatsAppChannel::new(test_credentials().await).with_api_base(&base);
+        let sender = ch.create_sender();
+        ch.connect().await.unwrap();
+        assert_eq!(ch.status(), ChannelStatus::Connected);
+        seen.lock().await.clear();
+
+        let long = "word ".repeat(2000);
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("chat_id".to_string(), "15551234567".to_string());
+        let msg = ChannelMessage::new("whatsapp", &long).with_metadata(metadata);
+        sender.send_message(msg).await.unwrap();
+
+        let bodies = seen.lock().await;
+        assert_eq!(bodies.len(), 3);
+        for body in bodies.iter() {
+            let v: serde_json::Value = serde_json::from_str(body).unwrap();
+            assert_eq!(v["to"], "15551234567");
+            assert!(v["text"]["body"].as_str().unwrap().len() <= WHATSAPP_MAX_LENGTH);
+        }
+    }
+
+    #[tokio::test]
+    async fn disconnect_stops_listen() {
+        let (base, _seen) = mock_graph().await;
+        let ch = Arc::new(WhatsAppChannel::new(test_credentials().await).with_api_base(&base));
+        ch.connect().await.unwrap();
+        let (tx, _rx) = mpsc::channel(10);
+        let listener = {
+            let ch = Arc::clone(&ch);
+            tokio::spawn(async move { ch.listen(tx).await })
+        };
+        ch.disconnect().await.unwrap();
+        let result = tokio::time::timeout(std::time::Duration::from_secs(5), listener)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(result.is_ok());
+        assert_eq!(ch.status(), ChannelStatus::Disconnected);
+    }
+}
@@ -1,4 +1,5 @@
 mod schema;
+pub mod watcher;
 
 pub use schema::{AppConfig, McpServerConfig, McpTransport};
 
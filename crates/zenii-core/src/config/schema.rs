@@ -17,6 +17,9 @@ pub struct AppConfig {
     pub data_dir: Option<String>,
     pub db_path: Option<String>,
     pub memory_db_path: Option<String>,
+    /// How long a statement waits for another connection's lock on the main or
+    /// memory database before failing with `database is locked`.
+    pub db_busy_timeout_ms: u64,
     pub identity_name: String,
     pub identity_description: String,
     #[serde(alias = "default_provider")]
@@ -26,6 +29,18 @@ pub struct AppConfig {
     #[serde(alias = "default_model")]
     pub provider_model_id: String,
     pub provider_api_key_env: Option<String>,
+    /// Attempts per provider HTTP request (1 = no retry). Connect errors,
+    /// timeouts, 429 and 5xx are retried with exponential backoff.
+    pub provider_retry_max_attempts: u32,
+    /// Delay before the first provider retry; doubled for each further retry.
+    pub provider_retry_initial_backoff_ms: u64,
+    /// Seconds a provider connection test result is reused before the next
+    /// test makes a live request.
+    pub provider_health_ttl_secs: u64,
+    /// Mark the system prompt and tool schemas as cacheable for providers
+    /// that need explicit cache breakpoints (Anthropic). OpenAI and Gemini
+    /// cache repeated prefixes on their own.
+    pub provider_prompt_caching: bool,
     pub security_autonomy_level: String,
     pub max_tool_retries: u32,
 
@@ -40,6 +55,18 @@ pub struct AppConfig {
     pub security_rate_limit_max: u32,
     pub security_rate_limit_window_secs: u64,
     pub security_audit_log_capacity: usize,
+    /// Hosts the `http_request` tool may reach (empty = any public host).
+    pub security_http_allowed_hosts: Vec<String>,
+    /// Hosts the `http_request` tool must never reach.
+    pub security_http_denied_hosts: Vec<String>,
+    /// Per-tool autonomy overrides (tool name -> `readonly`/`supervised`/`full`),
+    /// consulted before `security_autonomy_level`.
+    pub security_tool_autonomy: HashMap<String, String>,
+    /// Seconds a built-in tool call may run before it is abandoned and
+    /// reported as failed. 0 disables the limit.
+    pub security_tool_timeout_secs: u64,
+    /// Per-tool overrides of `security_tool_timeout_secs` (tool name -> seconds).
+    pub security_tool_timeouts: HashMap<String, u64>,
 
     // Phase 2: Tools
     pub tool_shell_timeout_secs: u64,
@@ -48,6 +75,10 @@ pub struct AppConfig {
     pub tool_file_search_max_depth: usize,
     pub tool_file_search_follow_symlinks: bool,
     pub tool_process_list_limit: usize,
+    pub tool_http_timeout_secs: u64,
+    pub tool_http_max_response_bytes: usize,
+    /// Workspace root the `git` tool operates in. The tool is not registered when unset.
+    pub tool_git_workspace_root: Option<String>,
 
     // Content Search
     pub tool_content_search_max_results: usize,
@@ -57,19 +88,51 @@ pub struct AppConfig {
     // Web Search
     pub web_search_timeout_secs: u64,
     pub web_search_max_results: usize,
+    /// `auto`, `tavily`, `brave`, `searxng`, `duckduckgo` or `none`.
+    pub web_search_provider: String,
+    /// Base URL of a self-hosted SearXNG instance.
+    pub web_search_searxng_url: Option<String>,
 
     // Phase 3: Gateway
     pub gateway_auth_token: Option<String>,
     pub ws_max_connections: usize,
     pub gateway_cors_origins: Vec<String>,
+    /// Per-token token-bucket limits on gateway requests, by route class.
+    pub gateway_rate_limit_enabled: bool,
+    /// `POST /sessions` and WebSocket upgrades per minute.
+    pub gateway_rate_limit_create_per_min: u32,
+    /// Other mutating requests per minute.
+    pub gateway_rate_limit_write_per_min: u32,
+    /// `GET`/`HEAD` requests per minute.
+    pub gateway_rate_limit_read_per_min: u32,
+    /// Seconds to wait on shutdown for in-flight workflow runs before they are
+    /// aborted and recorded as failed.
+    pub gateway_shutdown_drain_secs: u64,
+    /// Seconds between checks of the config file for external edits, which are
+    /// applied without a restart. 0 disables the watcher.
+    pub config_watch_interval_secs: u64,
 
     // Phase 3: Agent
     pub agent_max_turns: usize,
+    /// Tool calls from one model response that may run at once. Only
+    /// read-only tools overlap; everything else still runs serially.
+    pub agent_tool_concurrency: usize,
     pub agent_max_tokens: usize,
     pub agent_system_prompt: Option<String>,
+    /// Standing instruction prepended to every agent's final system prompt.
+    pub agent_prompt_prefix: Option<String>,
+    /// Standing instruction appended to every agent's final system prompt.
+    pub agent_prompt_suffix: Option<String>,
 
     // Phase 4: Identity
     pub identity_dir: Option<String>,
+    /// Persona used when every identity file is blank or unreadable.
+    /// `None` uses the built-in generic assistant persona.
+    pub identity_fallback_prompt: Option<String>,
+    /// Identity prompt sections in assembly order (`soul`, `identity`, `user`,
+    /// `observations`, `skills`, `system_prompt`). Sections left out are not
+    /// included. Empty uses the default order with every section.
+    pub identity_prompt_sections: Vec<String>,
 
     // Phase 4: Skills
     pub skills_dir: Option<String>,
@@ -84,6 +147,8 @@ pub struct AppConfig {
 
     // Phase 19: Tool Permissions
     pub tool_permissions: ToolPermissions,
+    /// Named tool groups usable in delegation task allowlists: profile -> tool names.
+    pub tool_profiles: HashMap<String, Vec<String>>,
 
     // Phase 8: Channels (channel_tool_policy kept for backward TOML compat)
     pub channel_tool_policy: HashMap<String, Vec<String>>,
@@ -99,8 +164,28 @@ pub struct AppConfig {
     pub slack_allowed_channel_ids: Vec<String>,
     pub discord_allowed_guild_ids: Vec<u64>,
     pub discord_allowed_channel_ids: Vec<u64>,
+    /// Where Discord slash commands are registered: `off`, `global` or `guild`.
+    pub discord_slash_commands: String,
+    /// Sender phone numbers allowed to message the WhatsApp channel (empty = all).
+    pub whatsapp_allowed_numbers: Vec<String>,
     pub channel_router_buffer_size: usize,
     pub channel_reconnect_max_attempts: u32,
+    /// Inbound image, video and file attachments larger than this (in mebibytes)
+    /// are not downloaded.
+    pub channel_attachment_max_mb: u64,
+    /// Saved inbound attachments older than this are deleted when the next
+    /// attachment arrives on that channel. `0` keeps them forever.
+    pub channel_attachment_retention_hours: u64,
+    /// Fold older channel history into the session summary instead of dropping it.
+    pub channel_summarize_enabled: bool,
+    /// Most recent messages kept verbatim when older history is summarized.
+    pub channel_summary_keep_recent: usize,
+    /// Channel name -> identity persona (a subdirectory of the identity dir).
+    /// Unmapped channels use the default persona.
+    pub channel_personas: HashMap<String, String>,
+    /// Outbound messages per minute for each channel type (`telegram`,
+    /// `slack`, ...). Sends beyond the rate queue; unlisted types are not paced.
+    pub channel_send_rate_per_min: HashMap<String, u32>,
 
     // Channel Supervisor
     pub channel_supervisor_max_restarts: u32,
@@ -126,10 +211,23 @@ pub struct AppConfig {
     pub context_strategy: String,
     pub context_max_history_messages: usize,
     pub context_max_memory_results: usize,
+    /// Recall memories relevant to the user's message and inject them into
+    /// the prompt as a delimited background block.
+    pub context_memory_injection: bool,
+    /// Minimum relevance (0.0-1.0) for an injected memory; 0.0 keeps every match.
+    pub context_memory_min_relevance: f32,
     pub context_auto_extract: bool,
     pub context_extract_interval: usize,
     pub context_summary_model: String,
 
+    // Memory write-back: persist durable facts after each agent run
+    pub memory_writeback_enabled: bool,
+    /// "provider_id:model_id" for fact extraction. `None` uses the context summary model.
+    pub memory_writeback_model: Option<String>,
+    pub memory_writeback_max_facts: usize,
+    /// Seed the nightly `daily_summary` scheduler job at boot if it is missing.
+    pub memory_daily_summary_enabled: bool,
+
     // Phase 8: Scheduler
     pub scheduler_tick_interval_secs: u64,
     pub scheduler_stuck_threshold_secs: u64,
@@ -139,6 +237,9 @@ pub struct AppConfig {
     pub scheduler_heartbeat_file: Option<String>,
     /// Maximum consecutive failures before a job is auto-disabled.
     pub scheduler_max_consecutive_failures: u32,
+    /// Maximum jobs executing at once. Due jobs beyond the limit wait for a
+    /// free slot, highest priority first.
+    pub scheduler_max_concurrent_jobs: usize,
 
     // Phase 8.11: Autonomous Reasoning
     pub agent_max_continuations: u32,
@@ -146,9 +247,28 @@ pub struct AppConfig {
 
     // Audit: Agent execution safety
     pub agent_timeout_secs: u64,
+    /// Cumulative token budget for one chat turn, including tool-loop
+    /// completions and reasoning continuations (None = unbounded).
+    pub agent_max_total_tokens: Option<u64>,
+    /// Wall-clock limit for one chat turn (None = unbounded).
+    pub agent_max_wall_time_secs: Option<u64>,
+    /// Consecutive identical failures of one tool before the agent is asked to
+    /// reflect on its approach (0 = off). Requires `tool_dedup_enabled`.
+    pub agent_tool_failure_threshold: u32,
+    /// Stop the turn when a tool keeps failing the same way for another
+    /// `agent_tool_failure_threshold` calls after the reflection prompt.
+    pub agent_abort_on_repeated_failures: bool,
+    /// Time limit for one `POST /agent/oneshot` turn; exceeding it returns 504.
+    pub agent_oneshot_timeout_secs: u64,
+    /// Context window assumed for models whose limit the provider registry
+    /// does not know. History is trimmed to fit it before each request.
+    pub agent_default_context_limit: usize,
 
     // Audit: Event bus capacity
     pub event_bus_capacity: usize,
+    /// Recent events the bus keeps for `subscribe_filtered_with_replay`
+    /// (0 disables replay).
+    pub event_bus_replay_capacity: usize,
 
     // Audit: Session cleanup
     pub session_max_age_days: u32,
@@ -180,6 +300,12 @@ pub struct AppConfig {
     pub plugin_idle_timeout_secs: u64,
     pub plugin_max_restart_attempts: u32,
     pub plugin_execute_timeout_secs: u64,
+    /// Seconds between health probes of running plugin processes. 0 disables probing.
+    pub plugin_health_check_interval_secs: u64,
+    /// Seconds a plugin has to answer a health ping.
+    pub plugin_health_check_timeout_secs: u64,
+    /// Consecutive failed probes before a plugin is marked unhealthy.
+    pub plugin_health_failure_threshold: u32,
     pub plugin_auto_update: bool,
     pub official_plugins_repo: String,
 
@@ -204,6 +330,8 @@ pub struct AppConfig {
     pub compression_file_max_lines: usize,
     /// Maximum number of lines to keep for shell tool output.
     pub compression_shell_max_lines: usize,
+    /// Seconds the untruncated output of a compressed tool result stays fetchable.
+    pub tool_result_retention_secs: u64,
 
     // Phase 8.13: Prompt Efficiency
     pub prompt_max_preamble_tokens: usize,
@@ -228,9 +356,25 @@ pub struct AppConfig {
     pub delegation_decomposition_model: Option<String>,
     /// When true, delegation skips human approval for sub-agent tool calls.
     pub delegation_skip_approval: bool,
+    /// When set, each sub-agent's file tools are jailed to its own directory
+    /// `<dir>/<delegation_id>/<task_id>`, independent of the global path policy.
+    pub delegation_workspace_dir: Option<String>,
 
     // Tool Approval
+    /// Seconds a pending approval waits for an answer before it auto-resolves.
     pub approval_timeout_secs: u64,
+    /// What a timed-out approval resolves to on surfaces with no interactive
+    /// user (scheduler, channels): "deny" fails the tool call, "skip" lets the
+    /// agent continue without it. Interactive surfaces always deny.
+    pub approval_unattended_decision: String,
+
+    // Spend Limits
+    /// USD cap on estimated spend per local calendar day. `None` = unlimited.
+    pub budget_daily_usd: Option<f64>,
+    /// USD cap on estimated spend per local calendar month. `None` = unlimited.
+    pub budget_monthly_usd: Option<f64>,
+    /// USD cap on estimated spend within a single session. `None` = unlimited.
+    pub budget_session_usd: Option<f64>,
 
     // Phase 8: Self-Evolution
     pub self_evolution_enabled: bool,
@@ -326,6 +470,45 @@ pub struct AppConfig {
     pub routing_hint_vision: Option<String>,
     /// hint:summarize → this concrete "provider_id:model_id" string
     pub routing_hint_summarize: Option<String>,
+    /// hint:image → this concrete "provider_id:model_id" string. When set, the
+    /// `image_generate` tool is registered against that provider's images endpoint.
+    pub routing_hint_image: Option<String>,
+
+    // Model Routing: Failover
+    /// Ordered "provider_id:model_id" fallbacks tried when a completion fails
+    /// with a rate limit, server error or timeout. Keyed by hint name
+    /// ("reasoning", "fast", ...) or "default" for requests without a hint.
+    pub routing_fallbacks: HashMap<String, Vec<String>>,
+    /// Maximum fallback models tried after the primary fails. 0 disables failover.
+    pub routing_failover_max_attempts: usize,
+
+    // Image Generation
+    /// Directory generated images are saved to outside an agent workspace.
+    /// Default: {data_dir}/images/
+    pub image_gen_output_dir: Option<String>,
+    /// Image size used when the agent does not request one.
+    pub image_gen_default_size: String,
+    /// Maximum number of images a single tool call may generate.
+    pub image_gen_max_images: u32,
+
+    // Audio Transcription
+    /// "provider_id:model_id" used to transcribe inbound channel voice messages
+    /// (e.g. "openai:whisper-1"). When unset, audio messages get a fallback reply.
+    pub transcription_model: Option<String>,
+    /// Audio attachments larger than this (in mebibytes) are not downloaded.
+    pub transcription_max_audio_mb: u64,
+
+    // OAuth
+    /// Redirect URI registered with OAuth apps (e.g. Slack).
+    /// Default: http://{gateway_host}:{gateway_port}/oauth/callback
+    pub oauth_redirect_uri: Option<String>,
+    /// How long an authorization request may stay pending before its `state` expires.
+    pub oauth_state_ttl_secs: u64,
+
+    // Event Recording
+    /// Size cap for a debug event recording (`POST /system/events/recording/start`).
+    /// Recording stops on its own once the file would exceed this many bytes.
+    pub event_recording_max_bytes: u64,
 }
 
 /// Transport configuration for a single external MCP server.
@@ -374,6 +557,7 @@ impl Default for AppConfig {
             data_dir: None,
             db_path: None,
             memory_db_path: None,
+            db_busy_timeout_ms: crate::db::DEFAULT_BUSY_TIMEOUT_MS,
             identity_name: "Zenii".into(),
             identity_description: "AI-powered assistant".into(),
             provider_name: "anthropic".into(),
@@ -381,6 +565,10 @@ impl Default for AppConfig {
             provider_base_url: None,
             provider_model_id: "claude-sonnet-4-6".into(),
             provider_api_key_env: None,
+            provider_retry_max_attempts: 3,
+            provider_retry_initial_backoff_ms: 200,
+            provider_health_ttl_secs: 30,
+            provider_prompt_caching: true,
             security_autonomy_level: "full".into(),
             max_tool_retries: 3,
 
@@ -395,6 +583,14 @@ impl Default for AppConfig {
             security_rate_limit_max: 60,
             security_rate_limit_window_secs: 60,
             security_audit_log_capacity: 1000,
+            security_http_allowed_hosts: Vec::new(),
+            security_http_denied_hosts: Vec::new(),
+            security_tool_autonomy: ["memory", "file_read", "file_list"]
+                .into_iter()
+                .map(|tool| (tool.to_string(), "full".to_string()))
+                .collect(),
+            security_tool_timeout_secs: 300,
+            security_tool_timeouts: HashMap::new(),
 
             // Tools
             tool_shell_timeout_secs: 30,
@@ -403,6 +599,9 @@ impl Default for AppConfig {
             tool_file_search_max_depth: 20,
             tool_file_search_follow_symlinks: false,
             tool_process_list_limit: 200,
+            tool_http_timeout_secs: 30,
+            tool_http_max_response_bytes: 256 * 1024,
+            tool_git_workspace_root: None,
 
             // Content Search
             tool_content_search_max_results: 50,
@@ -412,6 +611,8 @@ impl Default for AppConfig {
             // Web Search
             web_search_timeout_secs: 30,
             web_search_max_results: 20,
+            web_search_provider: "auto".into(),
+            web_search_searxng_url: None,
 
             // Gateway
             gateway_auth_token: None,
@@ -421,14 +622,25 @@ impl Default for AppConfig {
                 "tauri://localhost".into(),
                 "https://tauri.localhost".into(),
             ],
+            gateway_rate_limit_enabled: true,
+            gateway_rate_limit_create_per_min: 60,
+            gateway_rate_limit_write_per_min: 300,
+            gateway_rate_limit_read_per_min: 1200,
+            gateway_shutdown_drain_secs: 30,
+            config_watch_interval_secs: 2,
 
             // Agent
             agent_max_turns: 8,
+            agent_tool_concurrency: 4,
             agent_max_tokens: 4096,
             agent_system_prompt: None,
+            agent_prompt_prefix: None,
+            agent_prompt_suffix: None,
 
             // Identity
             identity_dir: None,
+            identity_fallback_prompt: None,
+            identity_prompt_sections: Vec::new(),
 
             // Skills
             skills_dir: None,
@@ -441,6 +653,7 @@ impl Default for AppConfig {
 
             // Tool Permissions
             tool_permissions: ToolPermissions::default(),
+            tool_profiles: HashMap::new(),
 
             // Channels (channel_tool_policy kept for backward compat)
             channel_tool_policy: HashMap::new(),
@@ -456,8 +669,19 @@ impl Default for AppConfig {
             slack_allowed_channel_ids: vec![],
             discord_allowed_guild_ids: vec![],
             discord_allowed_channel_ids: vec![],
+            discord_slash_commands: "global".into(),
+            whatsapp_allowed_numbers: vec![],
             channel_router_buffer_size: 256,
             channel_reconnect_max_attempts: 10,
+            channel_attachment_max_mb: 20,
+            channel_attachment_retention_hours: 168,
+            channel_summarize_enabled: true,
+            channel_summary_keep_recent: 10,
+            channel_personas: HashMap::new(),
+            channel_send_rate_per_min: HashMap::from([
+                ("telegram".into(), 60),
+                ("slack".into(), 60),
+            ]),
 
             // Channel Supervisor
             channel_supervisor_max_restarts: 0, // 0 = infinite
@@ -483,10 +707,18 @@ impl Default for AppConfig {
             context_strategy: "balanced".into(),
             context_max_history_messages: 20,
             context_max_memory_results: 5,
+            context_memory_injection: true,
+            context_memory_min_relevance: 0.0,
             context_auto_extract: true,
             context_extract_interval: 3,
             context_summary_model: "gpt-4o-mini".into(),
 
+            // Memory write-back
+            memory_writeback_enabled: false,
+            memory_writeback_model: None,
+            memory_writeback_max_facts: 5,
+            memory_daily_summary_enabled: true,
+
             // Scheduler
             scheduler_tick_interval_secs: 1,
             scheduler_stuck_threshold_secs: 120,
@@ -495,6 +727,7 @@ impl Default for AppConfig {
             scheduler_agent_turn_timeout_secs: 120,
             scheduler_heartbeat_file: None,
             scheduler_max_consecutive_failures: 10,
+            scheduler_max_concurrent_jobs: 4,
 
             // Autonomous Reasoning
             agent_max_continuations: 1,
@@ -502,9 +735,16 @@ impl Default for AppConfig {
 
             // Agent execution safety
             agent_timeout_secs: 300,
+            agent_max_total_tokens: None,
+            agent_max_wall_time_secs: None,
+            agent_tool_failure_threshold: 3,
+            agent_abort_on_repeated_failures: false,
+            agent_oneshot_timeout_secs: 120,
+            agent_default_context_limit: 32_768,
 
             // Event bus capacity
             event_bus_capacity: 256,
+            event_bus_replay_capacity: crate::event_bus::DEFAULT_REPLAY_CAPACITY,
 
             // Session cleanup
             session_max_age_days: 90,
@@ -530,6 +770,9 @@ impl Default for AppConfig {
             plugin_idle_timeout_secs: 300,
             plugin_max_restart_attempts: 3,
             plugin_execute_timeout_secs: 60,
+            plugin_health_check_interval_secs: 60,
+            plugin_health_check_timeout_secs: 5,
+            plugin_health_failure_threshold: 3,
             plugin_auto_update: false,
             official_plugins_repo: "https://github.com/sprklai/zenii-plugins.git".into(),
 
@@ -543,6 +786,7 @@ impl Default for AppConfig {
             compression_web_search_results: 3,
             compression_file_max_lines: 200,
             compression_shell_max_lines: 100,
+            tool_result_retention_secs: 900,
 
             // Notification Routing
             notification_routing: NotificationRouting::default(),
@@ -569,9 +813,16 @@ impl Default for AppConfig {
             delegation_per_agent_timeout_secs: 120,
             delegation_decomposition_model: None,
             delegation_skip_approval: true,
+            delegation_workspace_dir: None,
 
             // Tool Approval
-            approval_timeout_secs: 120,
+            approval_timeout_secs: 300,
+            approval_unattended_decision: "deny".into(),
+
+            // Spend Limits
+            budget_daily_usd: None,
+            budget_monthly_usd: None,
+            budget_session_usd: None,
 
             // Self-Evolution
             self_evolution_enabled: true,
@@ -639,6 +890,27 @@ impl Default for AppConfig {
             routing_hint_fast: None,
             routing_hint_vision: None,
             routing_hint_summarize: None,
+            routing_hint_image: None,
+
+            // Model Routing: Failover
+            routing_fallbacks: HashMap::new(),
+            routing_failover_max_attempts: 2,
+
+            // Image Generation
+            image_gen_output_dir: None,
+            image_gen_default_size: "1024x1024".into(),
+            image_gen_max_images: 4,
+
+            // Audio Transcription
+            transcription_model: None,
+            transcription_max_audio_mb: 25,
+
+            // OAuth
+            oauth_redirect_uri: None,
+            oauth_state_ttl_secs: 600,
+
+            // Event Recording
+            event_recording_max_bytes: 50 * 1024 * 1024,
         }
     }
 }
@@ -650,8 +922,12 @@ impl AppConfig {
     pub fn validate(&mut self) -> Result<(), crate::ZeniiError> {
         // Clamp soft-range fields
         self.learning_min_confidence = self.learning_min_confidence.clamp(0.0, 1.0);
+        self.context_memory_min_relevance = self.context_memory_min_relevance.clamp(0.0, 1.0);
         self.agent_max_turns = self.agent_max_turns.clamp(1, 32);
+        self.agent_tool_concurrency = self.agent_tool_concurrency.clamp(1, 16);
         self.agent_max_continuations = self.agent_max_continuations.clamp(0, 5);
+        self.plugin_health_check_timeout_secs = self.plugin_health_check_timeout_secs.max(1);
+        self.plugin_health_failure_threshold = self.plugin_health_failure_threshold.max(1);
 
         // Hard-range fields — reject invalid values
         if self.workflow_max_concurrent == 0 || self.workflow_max_concurrent > 100 {
@@ -660,28 +936,169 @@ impl AppConfig {
                 self.workflow_max_concurrent
             )));
         }
+        if self.provider_retry_max_attempts == 0 || self.provider_retry_max_attempts > 10 {
+            return Err(crate::ZeniiError::Validation(format!(
+                "provider_retry_max_attempts must be between 1 and 10, got {}",
+                self.provider_retry_max_attempts
+            )));
+        }
         if self.agent_max_tokens == 0 || self.agent_max_tokens > 200_000 {
             return Err(crate::ZeniiError::Validation(format!(
                 "agent_max_tokens must be between 1 and 200000, got {}",
                 self.agent_max_tokens
             )));
         }
+        if let Some((tool, level)) = self
+            .security_tool_autonomy
+            .iter()
+            .find(|(_, level)| crate::security::policy::AutonomyLevel::parse(level).is_none())
+        {
+            return Err(crate::ZeniiError::Validation(format!(
+                "security_tool_autonomy.{tool} must be readonly, supervised or full, got {level}"
+            )));
+        }
+        if crate::security::approval::UnattendedDecision::parse(&self.approval_unattended_decision)
+            .is_none()
+        {
+            return Err(crate::ZeniiError::Validation(format!(
+                "approval_unattended_decision must be deny or skip, got {}",
+                self.approval_unattended_decision
+            )));
+        }
+        for (name, cap) in [
+            ("budget_daily_usd", self.budget_daily_usd),
+            ("budget_monthly_usd", self.budget_monthly_usd),
+            ("budget_session_usd", self.budget_session_usd),
+        ] {
+            if cap.is_some_and(|c| c.is_nan() || c <= 0.0) {
+                return Err(crate::ZeniiError::Validation(format!(
+                    "{name} must be > 0 when set"
+                )));
+            }
+        }
+        if let Some(unknown) = self.identity_prompt_sections.iter().find(|s| {
+            !crate::identity::composer::PROMPT_SECTIONS.contains(&s.to_ascii_lowercase().as_str())
+        }) {
+            return Err(crate::ZeniiError::Validation(format!(
+                "unknown identity_prompt_sections entry '{unknown}'; expected one of: {}",
+                crate::identity::composer::PROMPT_SECTIONS.join(", ")
+            )));
+        }
+        if self.scheduler_max_concurrent_jobs == 0 {
+            return Err(crate::ZeniiError::Validation(
+                "scheduler_max_concurrent_jobs must be > 0".into(),
+            ));
+        }
         // Timeout fields must be > 0
+        if self.approval_timeout_secs == 0 {
+            return Err(crate::ZeniiError::Validation(
+                "approval_timeout_secs must be > 0".into(),
+            ));
+        }
         if self.tool_shell_timeout_secs == 0 {
             return Err(crate::ZeniiError::Validation(
                 "tool_shell_timeout_secs must be > 0".into(),
             ));
         }
+        if self.gateway_rate_limit_enabled
+            && [
+                self.gateway_rate_limit_create_per_min,
+                self.gateway_rate_limit_write_per_min,
+                self.gateway_rate_limit_read_per_min,
+            ]
+            .contains(&0)
+        {
+            return Err(crate::ZeniiError::Validation(
+                "gateway_rate_limit_*_per_min must be > 0 when gateway_rate_limit_enabled is set"
+                    .into(),
+            ));
+        }
+        if self.tool_http_timeout_secs == 0 {
+            return Err(crate::ZeniiError::Validation(
+                "tool_http_timeout_secs must be > 0".into(),
+            ));
+        }
+        if self.tool_http_max_response_bytes == 0 {
+            return Err(crate::ZeniiError::Validation(
+                "tool_http_max_response_bytes must be > 0".into(),
+            ));
+        }
         if self.agent_timeout_secs == 0 {
             return Err(crate::ZeniiError::Validation(
                 "agent_timeout_secs must be > 0".into(),
             ));
         }
+        if self.agent_oneshot_timeout_secs == 0 {
+            return Err(crate::ZeniiError::Validation(
+                "agent_oneshot_timeout_secs must be > 0".into(),
+            ));
+        }
+        if self.agent_default_context_limit == 0 {
+            return Err(crate::ZeniiError::Validation(
+                "agent_default_context_limit must be > 0".into(),
+            ));
+        }
+        if self.agent_max_total_tokens == Some(0) {
+            return Err(crate::ZeniiError::Validation(
+                "agent_max_total_tokens must be > 0 when set".into(),
+            ));
+        }
+        if self.agent_max_wall_time_secs == Some(0) {
+            return Err(crate::ZeniiError::Validation(
+                "agent_max_wall_time_secs must be > 0 when set".into(),
+            ));
+        }
         if self.web_search_timeout_secs == 0 {
             return Err(crate::ZeniiError::Validation(
                 "web_search_timeout_secs must be > 0".into(),
             ));
         }
+        const SEARCH_PROVIDERS: &[&str] =
+            &["auto", "tavily", "brave", "searxng", "duckduckgo", "none"];
+        if !SEARCH_PROVIDERS.contains(&self.web_search_provider.to_lowercase().as_str()) {
+            return Err(crate::ZeniiError::Validation(format!(
+                "web_search_provider must be one of {}, got '{}'",
+                SEARCH_PROVIDERS.join(", "),
+                self.web_search_provider
+            )));
+        }
+        const SLASH_COMMAND_SCOPES: &[&str] = &["off", "global", "guild"];
+        if !SLASH_COMMAND_SCOPES.contains(&self.discord_slash_commands.as_str()) {
+            return Err(crate::ZeniiError::Validation(format!(
+                "discord_slash_commands must be one of {}, got '{}'",
+                SLASH_COMMAND_SCOPES.join(", "),
+                self.discord_slash_commands
+            )));
+        }
+        if self.channel_summarize_enabled
+            && (self.channel_summary_keep_recent == 0
+                || self.channel_summary_keep_recent >= self.context_max_history_messages)
+        {
+            return Err(crate::ZeniiError::Validation(format!(
+                "channel_summary_keep_recent must be between 1 and context_max_history_messages - 1 ({}), got {}",
+                self.context_max_history_messages.saturating_sub(1),
+                self.channel_summary_keep_recent
+            )));
+        }
+        if let Some((channel, _)) = self
+            .channel_send_rate_per_min
+            .iter()
+            .find(|(_, rate)| **rate == 0)
+        {
+            return Err(crate::ZeniiError::Validation(format!(
+                "channel_send_rate_per_min.{channel} must be > 0 (remove the entry to disable pacing)"
+            )));
+        }
+        if let Some(spec) = self
+            .routing_fallbacks
+            .values()
+            .flatten()
+            .find(|spec| !spec.contains(':'))
+        {
+            return Err(crate::ZeniiError::Validation(format!(
+                "routing_fallbacks entry '{spec}' must be 'provider_id:model_id'"
+            )));
+        }
         // URL fields: provider_base_url, if set, must be a valid http/https URL
         if let Some(ref url) = self.provider_base_url {
             let trimmed = url.trim();
@@ -775,6 +1192,7 @@ mod tests {
         let config = AppConfig::default();
         assert_eq!(config.scheduler_tick_interval_secs, 1);
         assert_eq!(config.scheduler_stuck_threshold_secs, 120);
+        assert_eq!(config.scheduler_max_concurrent_jobs, 4);
         assert_eq!(
             config.scheduler_error_backoff_secs,
             vec![30, 60, 300, 900, 3600]
@@ -845,6 +1263,8 @@ mod tests {
         assert_eq!(config.context_summary_provider_id, "openai");
         assert_eq!(config.context_reinject_gap_minutes, 30);
         assert_eq!(config.context_reinject_message_count, 20);
+        assert!(!config.memory_writeback_enabled);
+        assert!(config.memory_writeback_model.is_none());
     }
 
     // 15.3.41 — config evolution defaults
@@ -1028,6 +1448,16 @@ mod tests {
         assert_eq!(config.notification_routing.channel_message.len(), 2);
     }
 
+    // WS-3.6c — context_memory_min_relevance clamped to [0.0, 1.0]
+    #[test]
+    fn context_memory_min_relevance_clamped() {
+        let mut config = AppConfig::default();
+        assert!(config.context_memory_injection);
+        config.context_memory_min_relevance = 2.0;
+        config.validate().unwrap();
+        assert_eq!(config.context_memory_min_relevance, 1.0);
+    }
+
     // WS-3.6b — learning_min_confidence clamped to [0.0, 1.0]
     #[test]
     fn learning_min_confidence_clamped() {
@@ -1150,6 +1580,7 @@ mod tests {
         assert_eq!(config.delegation_per_agent_token_budget, 4000);
         assert_eq!(config.delegation_per_agent_timeout_secs, 120);
         assert!(config.delegation_decomposition_model.is_none());
+        assert!(config.delegation_workspace_dir.is_none());
     }
 
     #[test]
@@ -1159,6 +1590,7 @@ mod tests {
             delegation_per_agent_token_budget = 8000
             delegation_per_agent_timeout_secs = 300
             delegation_decomposition_model = "openai:gpt-4o"
+            delegation_workspace_dir = "/srv/zenii/agents"
         "#;
         let config: AppConfig = toml::from_str(toml_str).unwrap();
         assert_eq!(config.delegation_max_sub_agents, 8);
@@ -1168,6 +1600,10 @@ mod tests {
             config.delegation_decomposition_model.as_deref(),
             Some("openai:gpt-4o")
         );
+        assert_eq!(
+            config.delegation_workspace_dir.as_deref(),
+            Some("/srv/zenii/agents")
+        );
     }
 
     // AUDIT — default agent_timeout_secs is 300
@@ -1177,11 +1613,48 @@ mod tests {
         assert_eq!(config.agent_timeout_secs, 300);
     }
 
+    // Reflection on repeated tool failures is on by default; aborting is opt-in
+    #[test]
+    fn agent_tool_failure_reflection_defaults() {
+        let config = AppConfig::default();
+        assert_eq!(config.agent_tool_failure_threshold, 3);
+        assert!(!config.agent_abort_on_repeated_failures);
+
+        let config: AppConfig = toml::from_str(
+            "agent_tool_failure_threshold = 0\nagent_abort_on_repeated_failures = true",
+        )
+        .unwrap();
+        assert_eq!(config.agent_tool_failure_threshold, 0);
+        assert!(config.agent_abort_on_repeated_failures);
+    }
+
+    // Turn budgets default to unbounded and reject zero
+    #[test]
+    fn agent_turn_budgets_default_unbounded() {
+        let config = AppConfig::default();
+        assert_eq!(config.agent_max_total_tokens, None);
+        assert_eq!(config.agent_max_wall_time_secs, None);
+
+        let mut config: AppConfig =
+            toml::from_str("agent_max_total_tokens = 50000\nagent_max_wall_time_secs = 120")
+                .unwrap();
+        assert_eq!(config.agent_max_total_tokens, Some(50000));
+        assert_eq!(config.agent_max_wall_time_secs, Some(120));
+        assert!(config.validate().is_ok());
+
+        let mut config = AppConfig {
+            agent_max_total_tokens: Some(0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
     // AUDIT — default event_bus_capacity is 256
     #[test]
     fn audit_default_event_bus_capacity() {
         let config = AppConfig::default();
         assert_eq!(config.event_bus_capacity, 256);
+        assert_eq!(config.event_bus_replay_capacity, 200);
     }
 
     // AUDIT — default session_max_age_days is 90
@@ -1205,11 +1678,12 @@ mod tests {
         assert_eq!(config.session_max_age_days, 30);
     }
 
-    // TA.13 — approval_timeout_secs default value is 120
+    // TA.13 — approval_timeout_secs default value is 300
     #[test]
     fn approval_timeout_secs_default() {
         let config = AppConfig::default();
-        assert_eq!(config.approval_timeout_secs, 120);
+        assert_eq!(config.approval_timeout_secs, 300);
+        assert_eq!(config.approval_unattended_decision, "deny");
     }
 
     // TA.14 — approval_timeout_secs from TOML
@@ -1222,6 +1696,42 @@ mod tests {
         assert_eq!(config.approval_timeout_secs, 60);
     }
 
+    #[test]
+    fn validate_rejects_unknown_unattended_decision() {
+        let mut config = AppConfig {
+            approval_unattended_decision: "ask".into(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+        config.approval_unattended_decision = "skip".into();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_unknown_slash_command_scope() {
+        let mut config = AppConfig {
+            discord_slash_commands: "everywhere".into(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+        config.discord_slash_commands = "guild".into();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_non_positive_budget_caps() {
+        let mut config = AppConfig {
+            budget_daily_usd: Some(0.0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+        config.budget_daily_usd = Some(f64::NAN);
+        assert!(config.validate().is_err());
+        config.budget_daily_usd = Some(5.0);
+        config.budget_monthly_usd = Some(50.0);
+        assert!(config.validate().is_ok());
+    }
+
     // 5.50 — workflow config defaults
     #[test]
     fn workflow_config_defaults() {
@@ -1355,4 +1865,112 @@ mod tests {
         config.provider_base_url = None;
         assert!(config.validate().is_ok());
     }
+
+    // VAL.9 — routing_fallbacks entries must be provider_id:model_id
+    #[test]
+    fn validate_routing_fallbacks_format() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.routing_failover_max_attempts, 2);
+        config.routing_fallbacks =
+            HashMap::from([("default".into(), vec!["openai:gpt-4o".into()])]);
+        assert!(config.validate().is_ok());
+        config
+            .routing_fallbacks
+            .insert("fast".into(), vec!["gpt-4o-mini".into()]);
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("routing_fallbacks"), "{msg}");
+    }
+
+    // VAL.10 — channel_summary_keep_recent must leave room under the history window
+    #[test]
+    fn validate_channel_summary_keep_recent() {
+        let mut config = AppConfig::default();
+        assert!(config.channel_summarize_enabled);
+        assert_eq!(config.channel_summary_keep_recent, 10);
+        config.channel_summary_keep_recent = config.context_max_history_messages;
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("channel_summary_keep_recent"), "{msg}");
+        config.channel_summarize_enabled = false;
+        assert!(config.validate().is_ok());
+    }
+
+    // VAL.11 — gateway rate limits must be positive while enabled
+    #[test]
+    fn validate_gateway_rate_limits() {
+        let mut config = AppConfig::default();
+        assert!(config.gateway_rate_limit_enabled);
+        assert_eq!(config.gateway_rate_limit_create_per_min, 60);
+        config.gateway_rate_limit_read_per_min = 0;
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("gateway_rate_limit"), "{msg}");
+        config.gateway_rate_limit_enabled = false;
+        assert!(config.validate().is_ok());
+    }
+
+    // VAL.IDP — identity_prompt_sections only accepts known sections
+    #[test]
+    fn validate_identity_prompt_sections() {
+        let mut config = AppConfig {
+            identity_prompt_sections: vec!["SOUL".into(), "system_prompt".into()],
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        config.identity_prompt_sections.push("examples".into());
+        assert!(config.validate().is_err());
+    }
+
+    // VAL.12 — provider_retry_max_attempts must be between 1 and 10
+    #[test]
+    fn validate_provider_retry_max_attempts() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.provider_retry_max_attempts, 3);
+        assert_eq!(config.provider_retry_initial_backoff_ms, 200);
+        config.provider_retry_max_attempts = 0;
+        assert!(config.validate().is_err());
+        config.provider_retry_max_attempts = 11;
+        assert!(config.validate().is_err());
+        config.provider_retry_max_attempts = 1;
+        assert!(config.validate().is_ok());
+    }
+
+    // VAL.13 — security_tool_autonomy values must be known autonomy levels
+    #[test]
+    fn validate_security_tool_autonomy() {
+        let mut config = AppConfig::default();
+        assert_eq!(
+            config
+                .security_tool_autonomy
+                .get("file_list")
+                .map(String::as_str),
+            Some("full")
+        );
+        config
+            .security_tool_autonomy
+            .insert("shell".into(), "supervised".into());
+        assert!(config.validate().is_ok());
+        config
+            .security_tool_autonomy
+            .insert("patch".into(), "sometimes".into());
+        assert!(config.validate().is_err());
+    }
+
+    // VAL.14 — channel send rates must be positive
+    #[test]
+    fn validate_channel_send_rate_per_min() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.channel_send_rate_per_min.get("telegram"), Some(&60));
+        config.channel_send_rate_per_min.insert("discord".into(), 0);
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("channel_send_rate_per_min.discord"), "{msg}");
+    }
+
+    // VAL.15 — the default context limit must be positive
+    #[test]
+    fn validate_agent_default_context_limit() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.agent_default_context_limit, 32_768);
+        config.agent_default_context_limit = 0;
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("agent_default_context_limit"), "{msg}");
+    }
 }
@@ -0,0 +1,194 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use arc_swap::ArcSwap;
+use tracing::{info, warn};
+
+use super::AppConfig;
+use crate::Result;
+
+/// Read and validate the config file, failing on any parse or range error.
+/// Unlike [`super::load_config`], hard-range violations are errors so a bad
+/// edit never replaces a working runtime config.
+pub fn reload_config(path: &Path) -> Result<AppConfig> {
+    let content = std::fs::read_to_string(path)?;
+    let mut config: AppConfig = toml::from_str(&content)?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Fields read once at boot: changing them in a running daemon has no effect
+/// until it restarts.
+pub fn restart_required(old: &AppConfig, new: &AppConfig) -> Vec<&'static str> {
+    [
+        ("gateway_host", old.gateway_host != new.gateway_host),
+        ("gateway_port", old.gateway_port != new.gateway_port),
+        (
+            "gateway_cors_origins",
+            old.gateway_cors_origins != new.gateway_cors_origins,
+        ),
+        (
+            "allow_remote_binding",
+            old.allow_remote_binding != new.allow_remote_binding,
+        ),
+        ("data_dir", old.data_dir != new.data_dir),
+        ("db_path", old.db_path != new.db_path),
+        ("memory_db_path", old.memory_db_path != new.memory_db_path),
+        (
+            "db_busy_timeout_ms",
+            old.db_busy_timeout_ms != new.db_busy_timeout_ms,
+        ),
+        ("log_level", old.log_level != new.log_level),
+        (
+            "config_watch_interval_secs",
+            old.config_watch_interval_secs != new.config_watch_interval_secs,
+        ),
+        (
+            "scheduler_max_concurrent_jobs",
+            old.scheduler_max_concurrent_jobs != new.scheduler_max_concurrent_jobs,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, changed)| *changed)
+    .map(|(field, _)| field)
+    .collect()
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Poll `path` every `interval` and pass each changed, valid config to
+/// `on_reload`. Edits that fail to parse or validate are logged and the
+/// current config stays in place; reloads equal to `current` (such as the
+/// daemon's own `PUT /config` writes) are skipped.
+pub fn watch_config<F>(
+    path: PathBuf,
+    interval: Duration,
+    current: Arc<ArcSwap<AppConfig>>,
+    on_reload: F,
+) -> tokio::task::JoinHandle<()>
+where
+    F: Fn(AppConfig) + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut last_modified = modified(&path);
+        let mut ticker = tokio::time::interval(interval);
+        // The first tick completes immediately
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let stamp = modified(&path);
+            if stamp == last_modified {
+                continue;
+            }
+            last_modified = stamp;
+            // A deleted file keeps the running config
+            if stamp.is_none() {
+                continue;
+            }
+
+            let config = match reload_config(&path) {
+                Ok(config) => config,
+                Err(e) => {
+                    warn!(
+                        "Config file {} changed but is invalid, keeping the current config: {e}",
+                        path.display()
+                    );
+                    continue;
+                }
+            };
+            let old = current.load_full();
+            if *old == config {
+                continue;
+            }
+            for field in restart_required(&old, &config) {
+                warn!(
+                    field,
+                    "Config field changed on disk; restart the daemon to apply it"
+                );
+            }
+            info!("Config reloaded from {}", path.display());
+            on_reload(config);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    // CW.1 — Strict reload rejects hard-range violations
+    #[test]
+    fn reload_rejects_invalid_values() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "workflow_max_concurrent = 0\n").unwrap();
+        assert!(reload_config(&path).is_err());
+        std::fs::write(&path, "gateway_port = [").unwrap();
+        assert!(reload_config(&path).is_err());
+    }
+
+    // CW.2 — Boot-only fields are reported, live fields are not
+    #[test]
+    fn restart_required_lists_boot_fields() {
+        let old = AppConfig::default();
+        let new = AppConfig {
+            gateway_port: 9999,
+            gateway_cors_origins: vec!["https://app.example.com".into()],
+            security_autonomy_level: "readonly".into(),
+            // Read per request by the auth middleware
+            gateway_auth_token: Some("rotated".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            restart_required(&old, &new),
+            vec!["gateway_port", "gateway_cors_origins"]
+        );
+    }
+
+    async fn wait_for(
+        rx: &mut tokio::sync::mpsc::UnboundedReceiver<AppConfig>,
+    ) -> Option<AppConfig> {
+        tokio::time::timeout(Duration::from_secs(3), rx.recv())
+            .await
+            .ok()
+            .flatten()
+    }
+
+    // CW.3 — Valid edits are delivered; invalid ones keep the old config
+    #[tokio::test]
+    async fn watcher_applies_valid_edits_only() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "").unwrap();
+        let current = Arc::new(ArcSwap::from_pointee(AppConfig::default()));
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let swap = current.clone();
+        let handle = watch_config(
+            path.clone(),
+            Duration::from_millis(20),
+            current.clone(),
+            move |config| {
+                swap.store(Arc::new(config.clone()));
+                let _ = tx.send(config);
+            },
+        );
+
+        // mtime resolution can be as coarse as one second; wait before each write
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        std::fs::write(&path, "security_autonomy_level = \"readonly\"\n").unwrap();
+        let config = wait_for(&mut rx).await.expect("valid edit applied");
+        assert_eq!(config.security_autonomy_level, "readonly");
+
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        std::fs::write(&path, "security_autonomy_level = [").unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(rx.try_recv().is_err());
+        assert_eq!(current.load().security_autonomy_level, "readonly");
+
+        handle.abort();
+    }
+}
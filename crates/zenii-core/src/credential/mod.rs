@@ -1,6 +1,7 @@
 pub mod file_store;
 #[cfg(feature = "keyring")]
 pub mod keyring_store;
+pub mod oauth;
 
 use async_trait::async_trait;
 
@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use serde::Deserialize;
+
+use super::CredentialStore;
+use crate::{Result, ZeniiError};
+
+/// OAuth 2.0 authorization-code endpoints for a provider.
+#[derive(Debug, Clone)]
+pub struct OAuthEndpoints {
+    pub provider: String,
+    pub auth_url: String,
+    pub token_url: String,
+    pub scopes: Vec<String>,
+    /// Credential key the access token is stored under once the flow completes.
+    pub token_key: String,
+}
+
+impl OAuthEndpoints {
+    /// Credential key holding the OAuth app client ID.
+    pub fn client_id_key(&self) -> String {
+        format!("oauth:{}:client_id", self.provider)
+    }
+
+    /// Credential key holding the OAuth app client secret.
+    pub fn client_secret_key(&self) -> String {
+        format!("oauth:{}:client_secret", self.provider)
+    }
+
+    /// Build the URL the user opens in a browser to grant access.
+    pub fn authorize_url(&self, client_id: &str, redirect_uri: &str, state: &str) -> String {
+        format!(
+            "{}?client_id={}&redirect_uri={}&response_type=code&scope={}&state={}",
+            self.auth_url,
+            urlencoding::encode(client_id),
+            urlencoding::encode(redirect_uri),
+            urlencoding::encode(&self.scopes.join(",")),
+            urlencoding::encode(state),
+        )
+    }
+}
+
+/// Providers with a built-in OAuth flow.
+pub fn builtin_endpoints(provider: &str) -> Option<OAuthEndpoints> {
+    match provider {
+        "slack" => Some(OAuthEndpoints {
+            provider: "slack".into(),
+            auth_url: "https://slack.com/oauth/v2/authorize".into(),
+            token_url: "https://slack.com/api/oauth.v2.access".into(),
+            scopes: [
+                "app_mentions:read",
+                "channels:history",
+                "chat:write",
+                "im:history",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+            token_key: "channel:slack:bot_token".into(),
+        }),
+        _ => None,
+    }
+}
+
+/// Parameters delivered to the redirect URI when the user finishes (or aborts) consent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthCallback {
+    pub code: String,
+    pub state: String,
+}
+
+/// Parse the query string of an OAuth redirect (`code=...&state=...`).
+///
+/// A provider-reported `error` (e.g. `access_denied`) is returned as an error.
+pub fn parse_callback_query(query: &str) -> Result<OAuthCallback> {
+    let mut code = None;
+    let mut state = None;
+    let mut error = None;
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = urlencoding::decode(&value.replace('+', " "))
+            .map_err(|e| ZeniiError::Validation(format!("invalid OAuth callback encoding: {e}")))?
+            .into_owned();
+        match key {
+            "code" => code = Some(value),
+            "state" => state = Some(value),
+            "error" => error = Some(value),
+            _ => {}
+        }
+    }
+
+    if let Some(error) = error {
+        return Err(ZeniiError::Auth(format!(
+            "OAuth authorization failed: {error}"
+        )));
+    }
+    match (code, state) {
+        (Some(code), Some(state)) if !code.is_empty() && !state.is_empty() => {
+            Ok(OAuthCallback { code, state })
+        }
+        _ => Err(ZeniiError::Validation(
+            "OAuth callback is missing 'code' or 'state'".into(),
+        )),
+    }
+}
+
+/// Parse a full callback URL, e.g. `zenii://oauth/callback?code=...&state=...`
+/// or `http://127.0.0.1:18981/oauth/callback?code=...&state=...`.
+pub fn parse_callback_url(url: &str) -> Result<OAuthCallback> {
+    let (path, query) = url
+        .split_once('?')
+        .ok_or_else(|| ZeniiError::Validation("OAuth callback URL has no query".into()))?;
+    if !path.trim_end_matches('/').ends_with("oauth/callback") {
+        return Err(ZeniiError::Validation(format!(
+            "not an OAuth callback URL: {path}"
+        )));
+    }
+    // Drop any fragment the browser may have appended
+    let query = query.split('#').next().unwrap_or_default();
+    parse_callback_query(query)
+}
+
+/// An authorization request waiting for its callback.
+#[derive(Debug, Clone)]
+pub struct PendingAuth {
+    pub provider: String,
+    pub redirect_uri: String,
+    created_at: Instant,
+}
+
+/// Single-use `state` values for in-flight authorization requests.
+///
+/// Each state is random, bound to one provider, and consumed by the first
+/// callback that presents it, which protects the flow against CSRF and replay.
+pub struct OAuthStateStore {
+    pending: Mutex<HashMap<String, PendingAuth>>,
+    ttl: Duration,
+}
+
+impl OAuthStateStore {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            pending: Mutex::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    /// Register a new authorization request and return its `state` value.
+    pub fn begin(&self, provider: &str, redirect_uri: &str) -> String {
+        let state = uuid::Uuid::new_v4().simple().to_string();
+        let mut pending = self.pending.lock();
+        pending.retain(|_, p| p.created_at.elapsed() < self.ttl);
+        pending.insert(
+            state.clone(),
+            PendingAuth {
+                provider: provider.to_string(),
+                redirect_uri: redirect_uri.to_string(),
+                created_at: Instant::now(),
+            },
+        );
+        state
+    }
+
+    /// Consume `state`, returning the request it belongs to.
+    /// Unknown, already-used and expired states are rejected.
+    pub fn take(&self, state: &str) -> Result<PendingAuth> {
+        let pending = self
+            .pending
+            .lock()
+            .remove(state)
+            .ok_or_else(|| ZeniiError::Auth("unknown or already used OAuth state".into()))?;
+        if pending.created_at.elapsed() >= self.ttl {
+            return Err(ZeniiError::Auth("OAuth state expired".into()));
+        }
+        Ok(pending)
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.pending.lock().len()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    /// Slack-style envelope; standard OAuth servers omit it.
+    ok: Option<bool>,
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    error: Option<String>,
+}
+
+/// Tokens returned by a successful code exchange.
+#[derive(Debug, Clone)]
+pub struct OAuthTokens {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+}
+
+/// Exchange an authorization code for tokens at the provider's token endpoint.
+pub async fn exchange_code(
+    client: &reqwest::Client,
+    endpoints: &OAuthEndpoints,
+    client_id: &str,
+    client_secret: &str,
+    code: &str,
+    redirect_uri: &str,
+) -> Result<OAuthTokens> {
+    let body = [
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("redirect_uri", redirect_uri),
+        ("client_id", client_id),
+        ("client_secret", client_secret),
+    ]
+    .iter()
+    .map(|(k, v)| format!("{k}={}", urlencoding::encode(v)))
+    .collect::<Vec<_>>()
+    .join("&");
+
+    let resp = client
+        .post(&endpoints.token_url)
+        .header(
+            reqwest::header::CONTENT_TYPE,
+            "application/x-www-form-urlencoded",
+        )
+        .body(body)
+        .send()
+        .await?;
+    let status = resp.status();
+    let parsed: TokenResponse = resp
+        .json()
+        .await
+        .map_err(|e| ZeniiError::Auth(format!("OAuth token response parse failed: {e}")))?;
+
+    if !status.is_success() || parsed.ok == Some(false) {
+        let reason = parsed.error.unwrap_or_else(|| status.to_string());
+        return Err(ZeniiError::Auth(format!(
+            "OAuth token exchange failed: {reason}"
+        )));
+    }
+    let access_token = parsed
+        .access_token
+        .ok_or_else(|| ZeniiError::Auth("OAuth token response has no access_token".into()))?;
+    Ok(OAuthTokens {
+        access_token,
+        refresh_token: parsed.refresh_token,
+    })
+}
+
+/// Complete a flow: validate `state`, exchange the code and store the tokens.
+/// Returns the provider the tokens were stored for.
+pub async fn complete_flow(
+    states: &OAuthStateStore,
+    credentials: &dyn CredentialStore,
+    callback: &OAuthCallback,
+    endpoints_for: impl Fn(&str) -> Option<OAuthEndpoints>,
+) -> Result<String> {
+    let pending = states.take(&callback.state)?;
+    let endpoints = endpoints_for(&pending.provider).ok_or_else(|| {
+        ZeniiError::NotFound(format!("no OAuth flow for provider '{}'", pending.provider))
+    })?;
+
+    let client_id = credentials
+        .get(&endpoints.client_id_key())
+        .await?
+        .ok_or_else(|| ZeniiError::Credential(format!("{} not set", endpoints.client_id_key())))?;
+    let client_secret = credentials
+        .get(&endpoints.client_secret_key())
+        .await?
+        .ok_or_else(|| {
+            ZeniiError::Credential(format!("{} not set", endpoints.client_secret_key()))
+        })?;
+
+    let tokens = exchange_code(
+        &reqwest::Client::new(),
+        &endpoints,
+        &client_id,
+        &client_secret,
+        &callback.code,
+        &pending.redirect_uri,
+    )
+    .await?;
+
+    credentials
+        .set(&endpoints.token_key, &tokens.access_token)
+        .await?;
+    if let Some(refresh) = &tokens.refresh_token {
+        credentials
+            .set(&format!("{}:refresh", endpoints.token_key), refresh)
+            .await?;
+    }
+    Ok(pending.provider)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::InMemoryCredentialStore;
+
+    // OA.1 — Callback URL parsing (deep link and loopback forms)
+    #[test]
+    fn parse_callback_url_extracts_code_and_state() {
+        let cb = parse_callback_url("zenii://oauth/callback?code=abc%2F123&state=s1").unwrap();
+        assert_eq!(cb.code, "abc/123");
+        assert_eq!(cb.state, "s1");
+
+        let cb = parse_callback_url("http://127.0.0.1:18981/oauth/callback?state=s2&code=xyz#frag")
+            .unwrap();
+        assert_eq!(cb.code, "xyz");
+        assert_eq!(cb.state, "s2");
+
+        assert!(parse_callback_url("zenii://oauth/callback?code=abc").is_err());
+        assert!(parse_callback_url("zenii://other?code=a&state=b").is_err());
+        assert!(parse_callback_query("error=access_denied&state=s1").is_err());
+    }
+
+    // OA.2 — State is single-use and a mismatched state is rejected
+    #[test]
+    fn state_store_rejects_mismatched_state() {
+        let store = OAuthStateStore::new(Duration::from_secs(600));
+        let state = store.begin("slack", "http://127.0.0.1:18981/oauth/callback");
+
+        assert!(store.take("not-the-state").is_err());
+        let pending = store.take(&state).unwrap();
+        assert_eq!(pending.provider, "slack");
+        // Replaying the same state fails
+        assert!(store.take(&state).is_err());
+    }
+
+    // OA.3 — Expired state is rejected
+    #[test]
+    fn state_store_rejects_expired_state() {
+        let store = OAuthStateStore::new(Duration::ZERO);
+        let state = store.begin("slack", "http://localhost/oauth/callback");
+        assert!(store.take(&state).is_err());
+    }
+
+    // OA.4 — Full flow exchanges the code and stores the token
+    #[tokio::test]
+    async fn complete_flow_stores_access_token() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mock_body =
+            serde_json::json!({"ok": true, "access_token": "xoxb-new-token"}).to_string();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let _ = stream.readable().await;
+            let _ = stream.try_read(&mut buf);
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                mock_body.len(),
+                mock_body
+            );
+            let _ = stream.writable().await;
+            let _ = stream.try_write(response.as_bytes());
+        });
+
+        let creds = InMemoryCredentialStore::new();
+        creds.set("oauth:slack:client_id", "cid").await.unwrap();
+        creds
+            .set("oauth:slack:client_secret", "secret")
+            .await
+            .unwrap();
+        let states = OAuthStateStore::new(Duration::from_secs(600));
+        let state = states.begin("slack", "http://127.0.0.1:18981/oauth/callback");
+
+        let callback = OAuthCallback {
+            code: "code-1".into(),
+            state,
+        };
+        let provider = complete_flow(&states, &creds, &callback, |p| {
+            builtin_endpoints(p).map(|mut e| {
+                e.token_url = format!("http://{addr}/api/oauth.v2.access");
+                e
+            })
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(provider, "slack");
+        assert_eq!(
+            creds
+                .get("channel:slack:bot_token")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("xoxb-new-token")
+        );
+        assert_eq!(states.pending_count(), 0);
+    }
+}
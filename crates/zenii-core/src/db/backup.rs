@@ -0,0 +1,234 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use rusqlite::backup::Backup;
+use rusqlite::{Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
+
+use super::{DbPool, SCHEMA_VERSION, run_migrations};
+use crate::{Result, ZeniiError};
+
+/// Pages copied per backup step. Between steps the source is unlocked, so
+/// writers on other connections are only held up briefly.
+const PAGES_PER_STEP: i32 = 256;
+
+/// Pause between backup steps.
+const STEP_PAUSE: Duration = Duration::from_millis(10);
+
+/// Written next to every snapshot as `<snapshot>.manifest.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// `PRAGMA user_version` of the snapshot.
+    pub schema_version: u32,
+    /// Zenii version that took the snapshot.
+    pub zenii_version: String,
+    pub created_at: String,
+}
+
+/// Path of the manifest belonging to `snapshot`.
+pub fn manifest_path(snapshot: &Path) -> PathBuf {
+    let mut path = snapshot.as_os_str().to_owned();
+    path.push(".manifest.json");
+    PathBuf::from(path)
+}
+
+/// Copy the live database to `dest` with SQLite's online backup API and write
+/// its manifest. Refuses to overwrite an existing file.
+///
+/// Only the database is copied: provider API keys and channel tokens live in
+/// the OS keyring and are not part of a snapshot.
+pub async fn backup(pool: &DbPool, dest: &Path) -> Result<BackupManifest> {
+    if dest.exists() {
+        return Err(ZeniiError::Validation(format!(
+            "backup destination already exists: {}",
+            dest.display()
+        )));
+    }
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let dest = dest.to_path_buf();
+    super::with_db(pool, move |conn| {
+        let schema_version: u32 = conn.pragma_query_value(None, "user_version", |r| r.get(0))?;
+        let mut target = Connection::open(&dest)?;
+        Backup::new(conn, &mut target)?.run_to_completion(PAGES_PER_STEP, STEP_PAUSE, None)?;
+        drop(target);
+
+        let manifest = BackupManifest {
+            schema_version,
+            zenii_version: env!("CARGO_PKG_VERSION").into(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+        std::fs::write(
+            manifest_path(&dest),
+            serde_json::to_string_pretty(&manifest)?,
+        )?;
+        Ok(manifest)
+    })
+    .await
+}
+
+/// Replace the live database with the snapshot at `src`.
+///
+/// The pool lock is held for the whole copy, so in-flight queries finish
+/// first and new ones wait until the restored data is in place. Snapshots
+/// from an older schema are migrated afterwards; snapshots from a newer
+/// build are refused.
+pub async fn restore(pool: &DbPool, src: &Path) -> Result<BackupManifest> {
+    let src = src.to_path_buf();
+    let pool = pool.clone();
+    tokio::task::spawn_blocking(move || {
+        let manifest = read_manifest(&src)?;
+        check_compatible(&manifest)?;
+
+        let source = Connection::open_with_flags(&src, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        let found: u32 = source.pragma_query_value(None, "user_version", |r| r.get(0))?;
+        if found != manifest.schema_version {
+            return Err(ZeniiError::Validation(format!(
+                "snapshot schema version {found} does not match its manifest ({})",
+                manifest.schema_version
+            )));
+        }
+
+        let mut conn = pool.blocking_lock();
+        Backup::new(&source, &mut conn)?.run_to_completion(PAGES_PER_STEP, STEP_PAUSE, None)?;
+        run_migrations(&conn)?;
+        Ok(manifest)
+    })
+    .await
+    .map_err(|e| ZeniiError::Database(format!("spawn_blocking join error: {e}")))?
+}
+
+fn read_manifest(src: &Path) -> Result<BackupManifest> {
+    if !src.is_file() {
+        return Err(ZeniiError::NotFound(format!(
+            "snapshot not found: {}",
+            src.display()
+        )));
+    }
+    let path = manifest_path(src);
+    let text = std::fs::read_to_string(&path).map_err(|_| {
+        ZeniiError::Validation(format!(
+            "snapshot manifest missing: {} (was it created with `zenii db backup`?)",
+            path.display()
+        ))
+    })?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+fn check_compatible(manifest: &BackupManifest) -> Result<()> {
+    if manifest.schema_version > SCHEMA_VERSION {
+        return Err(ZeniiError::Validation(format!(
+            "snapshot schema version {} is newer than this build supports ({SCHEMA_VERSION}); \
+             it was taken by Zenii {}",
+            manifest.schema_version, manifest.zenii_version
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{init_pool, with_db};
+
+    async fn pool_with_session(dir: &Path, title: &str) -> DbPool {
+        let pool = init_pool(&dir.join("zenii.db")).unwrap();
+        let title = title.to_string();
+        with_db(&pool, move |conn| {
+            run_migrations(conn)?;
+            conn.execute(
+                "INSERT INTO sessions (id, title) VALUES ('s1', ?1)",
+                [title],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+        pool
+    }
+
+    async fn session_title(pool: &DbPool) -> Option<String> {
+        with_db(pool, |conn| {
+            Ok(conn
+                .query_row("SELECT title FROM sessions WHERE id = 's1'", [], |r| {
+                    r.get(0)
+                })
+                .ok())
+        })
+        .await
+        .unwrap()
+    }
+
+    // DB.BK.1 — A snapshot restores over later changes
+    #[tokio::test]
+    async fn backup_then_restore_round_trips() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let pool = pool_with_session(dir.path(), "before").await;
+        let snapshot = dir.path().join("snap/zenii.db");
+
+        let manifest = backup(&pool, &snapshot).await.unwrap();
+        assert_eq!(manifest.schema_version, SCHEMA_VERSION);
+        assert!(manifest_path(&snapshot).is_file());
+
+        with_db(&pool, |conn| {
+            conn.execute("UPDATE sessions SET title = 'after' WHERE id = 's1'", [])?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        restore(&pool, &snapshot).await.unwrap();
+        assert_eq!(session_title(&pool).await.as_deref(), Some("before"));
+    }
+
+    // DB.BK.2 — Existing files are never overwritten by a backup
+    #[tokio::test]
+    async fn backup_refuses_existing_destination() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let pool = pool_with_session(dir.path(), "t").await;
+        let dest = dir.path().join("existing.db");
+        std::fs::write(&dest, b"keep me").unwrap();
+
+        assert!(matches!(
+            backup(&pool, &dest).await,
+            Err(ZeniiError::Validation(_))
+        ));
+        assert_eq!(std::fs::read(&dest).unwrap(), b"keep me");
+    }
+
+    // DB.BK.3 — Snapshots from a newer schema are refused
+    #[tokio::test]
+    async fn restore_refuses_newer_schema() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let pool = pool_with_session(dir.path(), "live").await;
+        let snapshot = dir.path().join("snap.db");
+        backup(&pool, &snapshot).await.unwrap();
+
+        let mut manifest = read_manifest(&snapshot).unwrap();
+        manifest.schema_version = SCHEMA_VERSION + 1;
+        std::fs::write(
+            manifest_path(&snapshot),
+            serde_json::to_string(&manifest).unwrap(),
+        )
+        .unwrap();
+
+        let err = restore(&pool, &snapshot).await.unwrap_err();
+        assert!(err.to_string().contains("newer"), "{err}");
+        assert_eq!(session_title(&pool).await.as_deref(), Some("live"));
+    }
+
+    // DB.BK.4 — A snapshot without its manifest is refused
+    #[tokio::test]
+    async fn restore_requires_manifest() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let pool = pool_with_session(dir.path(), "live").await;
+        let snapshot = dir.path().join("snap.db");
+        backup(&pool, &snapshot).await.unwrap();
+        std::fs::remove_file(manifest_path(&snapshot)).unwrap();
+
+        let err = restore(&pool, &snapshot).await.unwrap_err();
+        assert!(err.to_string().contains("manifest missing"), "{err}");
+    }
+}
@@ -1,12 +1,30 @@
+pub mod backup;
+pub mod query;
+
 use crate::{Result, ZeniiError};
 use rusqlite::Connection;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 
 pub type DbPool = Arc<Mutex<Connection>>;
 
+/// Busy timeout applied by [`init_pool`].
+pub const DEFAULT_BUSY_TIMEOUT_MS: u64 = 5_000;
+
+/// `PRAGMA user_version` after [`run_migrations`]. Bump with every migration.
+pub const SCHEMA_VERSION: u32 = 29;
+
 pub fn init_pool(path: &Path) -> Result<DbPool> {
+    init_pool_with_busy_timeout(path, DEFAULT_BUSY_TIMEOUT_MS)
+}
+
+/// Open the database in WAL mode with foreign keys enforced. A statement that
+/// finds the file locked by another connection (the other database pool, the
+/// CLI, a second daemon) retries for up to `busy_timeout_ms` before failing
+/// with `database is locked`.
+pub fn init_pool_with_busy_timeout(path: &Path, busy_timeout_ms: u64) -> Result<DbPool> {
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)?;
     }
@@ -14,9 +32,9 @@ pub fn init_pool(path: &Path) -> Result<DbPool> {
     conn.execute_batch(
         "PRAGMA journal_mode = WAL;
          PRAGMA synchronous = NORMAL;
-         PRAGMA foreign_keys = ON;
-         PRAGMA busy_timeout = 5000;",
+         PRAGMA foreign_keys = ON;",
     )?;
+    conn.busy_timeout(Duration::from_millis(busy_timeout_ms))?;
     Ok(Arc::new(Mutex::new(conn)))
 }
 
@@ -433,6 +451,222 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
         conn.execute_batch("PRAGMA user_version = 15; COMMIT;")?;
     }
 
+    if version < 16 {
+        conn.execute_batch(
+            "BEGIN IMMEDIATE;
+            CREATE TABLE IF NOT EXISTS channel_settings (
+                channel TEXT PRIMARY KEY,
+                model TEXT,
+                autonomy_level TEXT,
+                system_prompt TEXT,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+
+            PRAGMA user_version = 16;
+            COMMIT;",
+        )?;
+    }
+
+    if version < 17 {
+        conn.execute_batch("BEGIN IMMEDIATE;")?;
+        // Per-session model override ("provider_id:model_id")
+        let has_model: bool = conn
+            .prepare("SELECT sql FROM sqlite_master WHERE type='table' AND name='sessions'")
+            .and_then(|mut stmt| stmt.query_row([], |row| row.get::<_, String>(0)))
+            .map(|sql| sql.contains("model TEXT"))
+            .unwrap_or(false);
+
+        if !has_model {
+            conn.execute_batch("ALTER TABLE sessions ADD COLUMN model TEXT;")?;
+        }
+
+        conn.execute_batch("PRAGMA user_version = 17; COMMIT;")?;
+    }
+
+    if version < 18 {
+        conn.execute_batch("BEGIN IMMEDIATE;")?;
+        // Per-model pricing in USD per million tokens, for cost estimates
+        let has_pricing: bool = conn
+            .prepare("SELECT sql FROM sqlite_master WHERE type='table' AND name='ai_models'")
+            .and_then(|mut stmt| stmt.query_row([], |row| row.get::<_, String>(0)))
+            .map(|sql| sql.contains("input_cost_per_mtok"))
+            .unwrap_or(false);
+
+        if !has_pricing {
+            conn.execute_batch(
+                "ALTER TABLE ai_models ADD COLUMN input_cost_per_mtok REAL;
+                 ALTER TABLE ai_models ADD COLUMN output_cost_per_mtok REAL;",
+            )?;
+        }
+
+        conn.execute_batch("PRAGMA user_version = 18; COMMIT;")?;
+    }
+
+    if version < 19 {
+        conn.execute_batch("BEGIN IMMEDIATE;")?;
+        // Number of oldest messages already folded into the session summary
+        let has_through: bool = conn
+            .prepare("SELECT sql FROM sqlite_master WHERE type='table' AND name='sessions'")
+            .and_then(|mut stmt| stmt.query_row([], |row| row.get::<_, String>(0)))
+            .map(|sql| sql.contains("summary_through"))
+            .unwrap_or(false);
+
+        if !has_through {
+            conn.execute_batch(
+                "ALTER TABLE sessions ADD COLUMN summary_through INTEGER NOT NULL DEFAULT 0;",
+            )?;
+        }
+
+        conn.execute_batch("PRAGMA user_version = 19; COMMIT;")?;
+    }
+
+    if version < 20 {
+        conn.execute_batch(
+            "BEGIN IMMEDIATE;
+            CREATE TABLE IF NOT EXISTS job_executions (
+                id TEXT PRIMARY KEY,
+                job_id TEXT NOT NULL,
+                status TEXT NOT NULL,
+                started_at TEXT NOT NULL,
+                completed_at TEXT,
+                error TEXT
+            );
+            CREATE INDEX IF NOT EXISTS idx_job_executions_job
+                ON job_executions(job_id, started_at DESC);
+
+            PRAGMA user_version = 20;
+            COMMIT;",
+        )?;
+    }
+
+    if version < 21 {
+        conn.execute_batch("BEGIN IMMEDIATE;")?;
+        // JSON Schema the session's chat replies must conform to
+        let has_format: bool = conn
+            .prepare("SELECT sql FROM sqlite_master WHERE type='table' AND name='sessions'")
+            .and_then(|mut stmt| stmt.query_row([], |row| row.get::<_, String>(0)))
+            .map(|sql| sql.contains("response_format"))
+            .unwrap_or(false);
+
+        if !has_format {
+            conn.execute_batch("ALTER TABLE sessions ADD COLUMN response_format TEXT;")?;
+        }
+
+        conn.execute_batch("PRAGMA user_version = 21; COMMIT;")?;
+    }
+
+    if version < 22 {
+        conn.execute_batch(
+            "BEGIN IMMEDIATE;
+            CREATE TABLE IF NOT EXISTS audit_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                session_id TEXT,
+                call_id TEXT,
+                tool_name TEXT NOT NULL,
+                args TEXT,
+                success INTEGER,
+                decision TEXT,
+                resolved_by TEXT
+            );
+            CREATE INDEX IF NOT EXISTS idx_audit_log_session
+                ON audit_log(session_id, timestamp);
+            CREATE INDEX IF NOT EXISTS idx_audit_log_timestamp
+                ON audit_log(timestamp);
+
+            PRAGMA user_version = 22;
+            COMMIT;",
+        )?;
+    }
+
+    if version < 23 {
+        conn.execute_batch(
+            "BEGIN IMMEDIATE;
+            ALTER TABLE scheduled_jobs ADD COLUMN priority INTEGER;
+            PRAGMA user_version = 23;
+            COMMIT;",
+        )?;
+    }
+
+    if version < 24 {
+        conn.execute_batch(
+            "BEGIN IMMEDIATE;
+            CREATE TABLE IF NOT EXISTS spend_ledger (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp TEXT NOT NULL,
+                session_id TEXT,
+                cost_usd REAL NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_spend_ledger_timestamp
+                ON spend_ledger(timestamp);
+            CREATE INDEX IF NOT EXISTS idx_spend_ledger_session
+                ON spend_ledger(session_id);
+
+            PRAGMA user_version = 24;
+            COMMIT;",
+        )?;
+    }
+
+    if version < 25 {
+        conn.execute_batch(
+            "BEGIN IMMEDIATE;
+            ALTER TABLE ai_providers ADD COLUMN supports_embeddings INTEGER NOT NULL DEFAULT 0;
+            PRAGMA user_version = 25;
+            COMMIT;",
+        )?;
+    }
+
+    if version < 26 {
+        conn.execute_batch(
+            "BEGIN IMMEDIATE;
+            ALTER TABLE ai_models ADD COLUMN input_modalities TEXT NOT NULL DEFAULT 'text';
+            PRAGMA user_version = 26;
+            COMMIT;",
+        )?;
+    }
+
+    if version < 27 {
+        conn.execute_batch(
+            "BEGIN IMMEDIATE;
+            ALTER TABLE delegation_tasks ADD COLUMN workspace_changes TEXT;
+            ALTER TABLE delegation_tasks ADD COLUMN workspace_diff TEXT;
+            PRAGMA user_version = 27;
+            COMMIT;",
+        )?;
+    }
+
+    if version < 28 {
+        conn.execute_batch(
+            "BEGIN IMMEDIATE;
+            CREATE TABLE IF NOT EXISTS lifecycle_transitions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp TEXT NOT NULL,
+                resource_type TEXT NOT NULL,
+                resource_id TEXT NOT NULL,
+                from_state TEXT,
+                to_state TEXT NOT NULL,
+                reason TEXT
+            );
+            CREATE INDEX IF NOT EXISTS idx_lifecycle_resource
+                ON lifecycle_transitions(resource_type, resource_id);
+            CREATE INDEX IF NOT EXISTS idx_lifecycle_timestamp
+                ON lifecycle_transitions(timestamp);
+
+            PRAGMA user_version = 28;
+            COMMIT;",
+        )?;
+    }
+
+    if version < 29 {
+        conn.execute_batch(
+            "BEGIN IMMEDIATE;
+            ALTER TABLE ai_providers ADD COLUMN extras TEXT NOT NULL DEFAULT '{}';
+            PRAGMA user_version = 29;
+            COMMIT;",
+        )?;
+    }
+
     Ok(())
 }
 
@@ -450,6 +684,25 @@ mod tests {
         drop(pool);
     }
 
+    #[test]
+    fn init_pool_applies_pragmas() {
+        let dir = TempDir::new().unwrap();
+        let pool = init_pool_with_busy_timeout(&dir.path().join("test.db"), 1_234).unwrap();
+        let conn = pool.blocking_lock();
+        let journal: String = conn
+            .pragma_query_value(None, "journal_mode", |r| r.get(0))
+            .unwrap();
+        let foreign_keys: i64 = conn
+            .pragma_query_value(None, "foreign_keys", |r| r.get(0))
+            .unwrap();
+        let busy: i64 = conn
+            .pragma_query_value(None, "busy_timeout", |r| r.get(0))
+            .unwrap();
+        assert_eq!(journal, "wal");
+        assert_eq!(foreign_keys, 1);
+        assert_eq!(busy, 1_234);
+    }
+
     #[test]
     fn run_migrations_creates_tables() {
         let dir = TempDir::new().unwrap();
@@ -482,7 +735,7 @@ mod tests {
         let version: u32 = conn
             .pragma_query_value(None, "user_version", |r| r.get(0))
             .unwrap();
-        assert_eq!(version, 15);
+        assert_eq!(version, SCHEMA_VERSION);
     }
 
     #[test]
@@ -666,7 +919,7 @@ mod tests {
         let version: u32 = conn
             .pragma_query_value(None, "user_version", |r| r.get(0))
             .unwrap();
-        assert_eq!(version, 15);
+        assert_eq!(version, SCHEMA_VERSION);
     }
 
     // IN.9 — Migration v9 adds channel_key column and unique index
@@ -772,7 +1025,222 @@ mod tests {
         let version: u32 = conn
             .pragma_query_value(None, "user_version", |r| r.get(0))
             .unwrap();
-        assert_eq!(version, 15);
+        assert_eq!(version, SCHEMA_VERSION);
+    }
+
+    // Migration v16 creates channel_settings table
+    #[test]
+    fn migration_v16_creates_channel_settings() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM channel_settings", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn migration_v17_adds_session_model() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        conn.execute(
+            "INSERT INTO sessions (id, title, created_at, updated_at, model) VALUES ('s1', 't', 'now', 'now', 'openai:gpt-4o')",
+            [],
+        )
+        .unwrap();
+        let model: Option<String> = conn
+            .query_row("SELECT model FROM sessions WHERE id = 's1'", [], |r| {
+                r.get(0)
+            })
+            .unwrap();
+        assert_eq!(model.as_deref(), Some("openai:gpt-4o"));
+    }
+
+    #[test]
+    fn migration_v18_adds_model_pricing() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        conn.execute(
+            "INSERT INTO ai_providers (id, name, base_url) VALUES ('p', 'P', 'http://x')",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO ai_models (id, provider_id, model_id, display_name, output_cost_per_mtok) VALUES ('p:m', 'p', 'm', 'M', 10.0)",
+            [],
+        )
+        .unwrap();
+        let (input, output): (Option<f64>, Option<f64>) = conn
+            .query_row(
+                "SELECT input_cost_per_mtok, output_cost_per_mtok FROM ai_models WHERE id = 'p:m'",
+                [],
+                |r| Ok((r.get(0)?, r.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((input, output), (None, Some(10.0)));
+    }
+
+    #[test]
+    fn migration_v19_adds_summary_through() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        conn.execute(
+            "INSERT INTO sessions (id, title, created_at, updated_at) VALUES ('s1', 't', 'now', 'now')",
+            [],
+        )
+        .unwrap();
+        let through: i64 = conn
+            .query_row(
+                "SELECT summary_through FROM sessions WHERE id = 's1'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(through, 0);
+    }
+
+    #[test]
+    fn migration_v20_creates_job_executions() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        conn.execute(
+            "INSERT INTO job_executions (id, job_id, status, started_at) VALUES ('e1', 'j1', 'success', 'now')",
+            [],
+        )
+        .unwrap();
+        let status: String = conn
+            .query_row(
+                "SELECT status FROM job_executions WHERE job_id = 'j1'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(status, "success");
+    }
+
+    #[test]
+    fn migration_v21_adds_session_response_format() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        conn.execute(
+            "INSERT INTO sessions (id, title, created_at, updated_at) VALUES ('s1', 't', 'now', 'now')",
+            [],
+        )
+        .unwrap();
+        let format: Option<String> = conn
+            .query_row(
+                "SELECT response_format FROM sessions WHERE id = 's1'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert!(format.is_none());
+    }
+
+    #[test]
+    fn migration_v22_creates_audit_log() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        conn.execute(
+            "INSERT INTO audit_log (timestamp, kind, tool_name) VALUES ('now', 'tool_start', 'shell')",
+            [],
+        )
+        .unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM audit_log", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn migration_v28_creates_lifecycle_transitions() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        conn.execute(
+            "INSERT INTO lifecycle_transitions (timestamp, resource_type, resource_id, to_state)
+             VALUES ('now', 'channel', 'telegram', 'connected')",
+            [],
+        )
+        .unwrap();
+        let from: Option<String> = conn
+            .query_row("SELECT from_state FROM lifecycle_transitions", [], |r| {
+                r.get(0)
+            })
+            .unwrap();
+        assert!(from.is_none());
+    }
+
+    #[test]
+    fn migration_v29_adds_provider_extras() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        conn.execute(
+            "INSERT INTO ai_providers (id, name, base_url) VALUES ('p', 'P', 'http://x')",
+            [],
+        )
+        .unwrap();
+        let extras: String = conn
+            .query_row("SELECT extras FROM ai_providers WHERE id = 'p'", [], |r| {
+                r.get(0)
+            })
+            .unwrap();
+        assert_eq!(extras, "{}");
+    }
+
+    #[test]
+    fn migration_v25_adds_provider_embeddings_flag() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        conn.execute(
+            "INSERT INTO ai_providers (id, name, base_url) VALUES ('p', 'P', 'http://x')",
+            [],
+        )
+        .unwrap();
+        let supports: i64 = conn
+            .query_row(
+                "SELECT supports_embeddings FROM ai_providers WHERE id = 'p'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(supports, 0);
+    }
+
+    #[test]
+    fn migration_v27_adds_delegation_workspace_diff() {
+        let dir = TempDir::new().unwrap();
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        run_migrations(&conn).unwrap();
+
+        let columns: Vec<String> = conn
+            .prepare("SELECT name FROM pragma_table_info('delegation_tasks')")
+            .unwrap()
+            .query_map([], |r| r.get(0))
+            .unwrap()
+            .collect::<std::result::Result<_, _>>()
+            .unwrap();
+        assert!(columns.contains(&"workspace_changes".to_string()));
+        assert!(columns.contains(&"workspace_diff".to_string()));
     }
 
     // Migration v13 creates delegation_tasks table
@@ -786,7 +1254,7 @@ mod tests {
         let version: u32 = conn
             .pragma_query_value(None, "user_version", |r| r.get(0))
             .unwrap();
-        assert_eq!(version, 15);
+        assert_eq!(version, SCHEMA_VERSION);
 
         // Verify table exists via SELECT
         let count: i64 = conn
@@ -0,0 +1,57 @@
+//! Helpers shared by the history queries (audit log, lifecycle transitions,
+//! token spend) that filter on a time range and page through rows.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::{Result, ZeniiError};
+
+/// Rows returned when a query sets no limit.
+pub const DEFAULT_QUERY_LIMIT: usize = 100;
+/// Upper bound on the rows a single query may return.
+pub const MAX_QUERY_LIMIT: usize = 1000;
+
+/// Fixed-width UTC timestamp so stored values sort and compare as text.
+pub fn normalize_timestamp(ts: DateTime<Utc>) -> String {
+    ts.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Parse an RFC 3339 `since`/`until` bound into the stored timestamp format.
+pub fn parse_bound(name: &str, value: &str) -> Result<String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|ts| normalize_timestamp(ts.with_timezone(&Utc)))
+        .map_err(|e| ZeniiError::Validation(format!("invalid {name} timestamp '{value}': {e}")))
+}
+
+/// `limit` clamped to `1..=MAX_QUERY_LIMIT`, ready to bind as `LIMIT ?`.
+pub fn clamp_limit(limit: Option<usize>) -> i64 {
+    limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .clamp(1, MAX_QUERY_LIMIT) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // DQ.1 — bounds in any offset normalize to the stored UTC form
+    #[test]
+    fn parse_bound_normalizes_to_utc_millis() {
+        let bound = parse_bound("since", "2026-01-02T03:04:05+02:00").unwrap();
+        assert_eq!(bound, "2026-01-02T01:04:05.000Z");
+    }
+
+    // DQ.2 — malformed bounds are validation errors naming the field
+    #[test]
+    fn parse_bound_rejects_garbage() {
+        let err = parse_bound("until", "yesterday").unwrap_err();
+        assert!(matches!(err, ZeniiError::Validation(ref m) if m.contains("until")));
+    }
+
+    // DQ.3 — limits default and clamp
+    #[test]
+    fn clamp_limit_defaults_and_caps() {
+        assert_eq!(clamp_limit(None), DEFAULT_QUERY_LIMIT as i64);
+        assert_eq!(clamp_limit(Some(0)), 1);
+        assert_eq!(clamp_limit(Some(5000)), MAX_QUERY_LIMIT as i64);
+    }
+}
@@ -72,6 +72,14 @@ pub enum ZeniiError {
     #[error("rate limited: {0}")]
     RateLimited(String),
 
+    /// A messaging platform rejected an outbound send with a rate limit.
+    /// `retry_after_secs` is the wait the platform asked for, if it gave one.
+    #[error("channel rate limited: {message}")]
+    ChannelRateLimited {
+        message: String,
+        retry_after_secs: Option<u64>,
+    },
+
     #[error("reqwest error: {0}")]
     Http(#[from] reqwest::Error),
 
@@ -114,12 +122,27 @@ pub enum ZeniiError {
     #[error("model capability error: {0}")]
     ModelCapability(String),
 
+    /// No provider/model is configured at all (as opposed to a configured provider
+    /// that is broken or unreachable, which surfaces as `Agent`/`Credential`).
+    #[error("no AI provider configured: {0}")]
+    ProviderNotConfigured(String),
+
     #[error("approval denied: {0}")]
     ApprovalDenied(String),
 
     #[error("approval timed out: {0}")]
     ApprovalTimeout(String),
 
+    /// A `budget_*_usd` spend cap was reached before a chat turn started.
+    /// Token and wall-time limits within a turn surface as
+    /// [`AgentError::BudgetExceeded`].
+    #[error("agent turn budget exceeded: {0}")]
+    BudgetExceeded(String),
+
+    /// An agent turn failed; the variant says why.
+    #[error(transparent)]
+    AgentTurn(#[from] AgentError),
+
     #[error("MCP error: {0}")]
     Mcp(String),
 
@@ -130,6 +153,141 @@ pub enum ZeniiError {
     Other(String),
 }
 
+/// Why an agent turn failed, so callers can react to the cause (exit code,
+/// HTTP status, channel reply) without parsing the message.
+#[derive(Debug, Error)]
+pub enum AgentError {
+    /// The provider rejected the API key.
+    #[error("provider authentication failed: {0}")]
+    ProviderAuth(String),
+
+    /// Any other provider-side failure: rate limits, 5xx, unknown model, network.
+    #[error("provider error: {0}")]
+    Provider(String),
+
+    /// A tool kept failing and the turn was stopped.
+    #[error("tool error: {0}")]
+    Tool(String),
+
+    /// The turn was cancelled before it produced an answer.
+    #[error("agent turn cancelled: {0}")]
+    Cancelled(String),
+
+    /// The turn hit `agent_max_total_tokens` or `agent_max_wall_time_secs`.
+    #[error("agent turn budget exceeded: {0}")]
+    BudgetExceeded(String),
+
+    /// The tool loop reached `agent_max_turns`.
+    #[error("agent reached its turn limit: {0}")]
+    MaxIterations(String),
+
+    /// The request did not fit the model's context window.
+    #[error("context window exceeded: {0}")]
+    ContextOverflow(String),
+}
+
+impl AgentError {
+    /// Classify a provider/runtime failure message. Providers only report
+    /// these as text, so the markers mirror the ones used for error hints.
+    pub fn from_message(message: String) -> Self {
+        let lower = message.to_lowercase();
+        if lower.contains("maxturn") || lower.contains("max turn") {
+            AgentError::MaxIterations(message)
+        } else if lower.contains("401")
+            || lower.contains("unauthorized")
+            || lower.contains("invalid api key")
+            || lower.contains("invalid x-api-key")
+            || lower.contains("authentication")
+        {
+            AgentError::ProviderAuth(message)
+        } else if lower.contains("context length")
+            || lower.contains("too many tokens")
+            || lower.contains("maximum context")
+            || lower.contains("token limit")
+            || lower.contains("input too long")
+        {
+            AgentError::ContextOverflow(message)
+        } else if lower.contains("toolcallerror") || lower.contains("toolnotfound") {
+            AgentError::Tool(message)
+        } else {
+            AgentError::Provider(message)
+        }
+    }
+}
+
+impl ZeniiError {
+    /// The agent-turn failure behind this error, if it is one.
+    pub fn agent_error(&self) -> Option<&AgentError> {
+        match self {
+            ZeniiError::AgentTurn(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Whether the turn was cancelled rather than failed. Cancellations are
+    /// expected and should not be logged or reported as errors.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, ZeniiError::AgentTurn(AgentError::Cancelled(_)))
+    }
+
+    /// Whether a spend cap or the turn's token/wall-time budget ran out.
+    pub fn is_budget_exceeded(&self) -> bool {
+        matches!(
+            self,
+            ZeniiError::BudgetExceeded(_) | ZeniiError::AgentTurn(AgentError::BudgetExceeded(_))
+        )
+    }
+
+    /// Whether retrying the same request may succeed without user action
+    /// (transient network, rate-limit or model-side failures). Auth, validation
+    /// and configuration failures, and turns that ran out of tools or turns,
+    /// would fail the same way again.
+    pub fn is_recoverable(&self) -> bool {
+        match self {
+            ZeniiError::Agent(msg) | ZeniiError::AgentTurn(AgentError::Provider(msg)) => {
+                !is_terminal_message(msg)
+            }
+            ZeniiError::Http(e) => !e.status().is_some_and(|s| s.is_client_error() && s != 429),
+            ZeniiError::RateLimited(_)
+            | ZeniiError::ChannelRateLimited { .. }
+            | ZeniiError::ApprovalTimeout(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Whether a provider failure message reports something a retry can't fix:
+/// rejected credentials, a malformed request or an unknown model.
+fn is_terminal_message(msg: &str) -> bool {
+    let lower = msg.to_lowercase();
+    [
+        "400",
+        "401",
+        "403",
+        "404",
+        "422",
+        "bad request",
+        "invalid_request",
+        "unauthorized",
+        "forbidden",
+        "authentication",
+        "invalid api key",
+        "invalid x-api-key",
+        "invalid_api_key",
+        "permission",
+        "context length",
+        "maximum context",
+        "maxturn",
+        "max turn",
+        "toolnotfound",
+        "does not exist",
+        "no agent configured",
+        "no provider",
+    ]
+    .iter()
+    .any(|marker| lower.contains(marker))
+}
+
 /// Produce an actionable hint for a ZeniiError, if one applies.
 /// Returns None for internal/opaque errors where no user action helps.
 pub fn enrich_error(err: &ZeniiError) -> Option<ErrorHint> {
@@ -138,6 +296,10 @@ pub fn enrich_error(err: &ZeniiError) -> Option<ErrorHint> {
             summary: "API key not configured".into(),
             action: "Set your API key in Settings > Providers, or via CLI: `zenii key set <provider> <key>`".into(),
         }),
+        ZeniiError::ProviderNotConfigured(_) => Some(ErrorHint {
+            summary: "No AI provider configured".into(),
+            action: "Set up a provider and API key in Settings > Providers, or via CLI: `zenii key set <provider> <key>`".into(),
+        }),
         ZeniiError::ModelCapability(_) => Some(ErrorHint {
             summary: "Model doesn't support this operation".into(),
             action: "Select a model that supports tool usage in Settings > Providers".into(),
@@ -150,9 +312,18 @@ pub fn enrich_error(err: &ZeniiError) -> Option<ErrorHint> {
             summary: "Approval request timed out".into(),
             action: "Respond to approval prompts faster, or increase `approval_timeout_secs` in config.toml".into(),
         }),
+        ZeniiError::BudgetExceeded(_) => Some(ErrorHint {
+            summary: "Spend budget reached".into(),
+            action: "Raise the `budget_*_usd` caps in config.toml, or wait for the budget period to reset".into(),
+        }),
+        ZeniiError::AgentTurn(e) => enrich_agent_turn(e),
         ZeniiError::RateLimited(_) => Some(ErrorHint {
             summary: "Rate limit exceeded".into(),
-            action: "Wait a moment and retry, or increase `security_rate_limit_max` in config.toml".into(),
+            action: "Wait a moment and retry, or increase `security_rate_limit_max` (tools) or the `gateway_rate_limit_*_per_min` limits (API) in config.toml".into(),
+        }),
+        ZeniiError::ChannelRateLimited { .. } => Some(ErrorHint {
+            summary: "Messaging platform rate limit hit".into(),
+            action: "Lower the channel's entry in `channel_send_rate_per_min` in config.toml".into(),
         }),
         ZeniiError::PolicyDenied(_) => Some(ErrorHint {
             summary: "Security policy blocked this action".into(),
@@ -166,6 +337,35 @@ pub fn enrich_error(err: &ZeniiError) -> Option<ErrorHint> {
     }
 }
 
+fn enrich_agent_turn(err: &AgentError) -> Option<ErrorHint> {
+    match err {
+        AgentError::ProviderAuth(_) => Some(ErrorHint {
+            summary: "API key is invalid or expired".into(),
+            action: "Check your API key in Settings > Providers, or set it via CLI: `zenii key set <provider> <key>`".into(),
+        }),
+        AgentError::BudgetExceeded(_) => Some(ErrorHint {
+            summary: "Agent turn exceeded its token or time budget".into(),
+            action: "Increase `agent_max_total_tokens` or `agent_max_wall_time_secs` in config.toml, or split the task into smaller requests".into(),
+        }),
+        AgentError::MaxIterations(_) => Some(ErrorHint {
+            summary: "Agent exceeded maximum tool loop turns".into(),
+            action:
+                "Increase `agent_max_turns` in config.toml (Settings > Agent) for tool-heavy tasks"
+                    .into(),
+        }),
+        AgentError::ContextOverflow(_) => Some(ErrorHint {
+            summary: "Input too long for the model's context window".into(),
+            action: "Shorten your message, start a new session, or use a model with a larger context window".into(),
+        }),
+        AgentError::Tool(_) => Some(ErrorHint {
+            summary: "A tool kept failing".into(),
+            action: "Check the tool's configuration and retry, or rephrase the request so the agent takes a different approach".into(),
+        }),
+        AgentError::Provider(msg) => enrich_agent_message(msg),
+        AgentError::Cancelled(_) => None,
+    }
+}
+
 fn enrich_agent_message(msg: &str) -> Option<ErrorHint> {
     let lower = msg.to_lowercase();
     if lower.contains("maxturn") || lower.contains("max turn") {
@@ -275,6 +475,19 @@ fn enrich_channel_message(msg: &str) -> Option<ErrorHint> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn recoverable_errors() {
+        assert!(ZeniiError::Agent("timeout".into()).is_recoverable());
+        assert!(ZeniiError::RateLimited("slow down".into()).is_recoverable());
+        assert!(!ZeniiError::ProviderNotConfigured("none".into()).is_recoverable());
+        assert!(!ZeniiError::Credential("missing".into()).is_recoverable());
+        assert!(!ZeniiError::Agent("ProviderError: 401 Unauthorized".into()).is_recoverable());
+        assert!(!ZeniiError::Agent("ProviderError: 400 bad request".into()).is_recoverable());
+        assert!(!ZeniiError::Validation("bad field".into()).is_recoverable());
+        assert!(!ZeniiError::Config("missing field".into()).is_recoverable());
+        assert!(!ZeniiError::Tool("invalid args".into()).is_recoverable());
+    }
+
     #[test]
     fn error_display() {
         let err = ZeniiError::Config("missing field".into());
@@ -329,6 +542,76 @@ mod tests {
         );
     }
 
+    // AE.1 — Provider messages are classified into typed agent errors
+    #[test]
+    fn agent_error_from_message() {
+        let classify = |m: &str| AgentError::from_message(m.into());
+        assert!(matches!(
+            classify("chat failed: MaxTurnError: (reached max turn limit: 4)"),
+            AgentError::MaxIterations(_)
+        ));
+        assert!(matches!(
+            classify("chat failed: CompletionError: ProviderError: 401 Unauthorized"),
+            AgentError::ProviderAuth(_)
+        ));
+        assert!(matches!(
+            classify("chat failed: CompletionError: context length exceeded"),
+            AgentError::ContextOverflow(_)
+        ));
+        assert!(matches!(
+            classify("chat failed: ToolCallError: shell timed out"),
+            AgentError::Tool(_)
+        ));
+        assert!(matches!(
+            classify("chat failed: CompletionError: 503 upstream down"),
+            AgentError::Provider(_)
+        ));
+    }
+
+    // AE.2 — Cancellation, budget and recoverability are read off the variant
+    #[test]
+    fn agent_turn_error_kinds() {
+        let cancelled: ZeniiError = AgentError::Cancelled("user stopped".into()).into();
+        assert!(cancelled.is_cancelled());
+        assert!(!cancelled.is_recoverable());
+        assert!(enrich_error(&cancelled).is_none());
+        assert_eq!(cancelled.to_string(), "agent turn cancelled: user stopped");
+
+        let budget: ZeniiError = AgentError::BudgetExceeded("token budget".into()).into();
+        assert!(budget.is_budget_exceeded());
+        assert!(ZeniiError::BudgetExceeded("daily cap".into()).is_budget_exceeded());
+        assert!(
+            enrich_error(&budget)
+                .unwrap()
+                .action
+                .contains("agent_max_total_tokens")
+        );
+
+        let auth: ZeniiError = AgentError::ProviderAuth("bad key".into()).into();
+        assert!(!auth.is_recoverable());
+        assert!(enrich_error(&auth).unwrap().action.contains("API key"));
+        assert!(matches!(
+            auth.agent_error(),
+            Some(AgentError::ProviderAuth(_))
+        ));
+
+        let provider: ZeniiError = AgentError::Provider("429 rate limit".into()).into();
+        assert!(provider.is_recoverable());
+        let forbidden: ZeniiError = AgentError::Provider("403 forbidden".into()).into();
+        assert!(!forbidden.is_recoverable());
+        let tool: ZeniiError = AgentError::Tool("shell kept failing".into()).into();
+        assert!(!tool.is_recoverable());
+        let turns: ZeniiError = AgentError::MaxIterations("limit 4".into()).into();
+        assert!(!turns.is_recoverable());
+        assert!(
+            enrich_error(&provider)
+                .unwrap()
+                .summary
+                .contains("rate limit")
+        );
+        assert!(ZeniiError::Other("x".into()).agent_error().is_none());
+    }
+
     // --- Error enrichment tests ---
 
     #[test]
@@ -0,0 +1,347 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use super::{AppEvent, EventBus};
+
+/// Tool calls kept for `GET /system/activity`, across all sessions.
+pub const DEFAULT_ACTIVITY_CAPACITY: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityStatus {
+    Running,
+    Success,
+    Failed,
+    /// Started but no result arrived within the stuck timeout.
+    Stuck,
+}
+
+/// One tool call, from its start event to its result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct Activity {
+    /// The tool call id shared by `AgentToolStarted` and `AgentToolCompleted`.
+    pub id: String,
+    pub session_id: String,
+    pub tool_name: String,
+    pub status: ActivityStatus,
+    pub started_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    /// The latest progress message a plugin tool reported for this call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<String>,
+}
+
+/// One page of tool calls, newest first. `next_cursor` is `None` on the last
+/// page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct ActivityPage {
+    pub activities: Vec<Activity>,
+    pub next_cursor: Option<String>,
+}
+
+/// Recent tool calls, one entry per call: the result event updates the entry
+/// its start event created instead of adding a second one.
+pub struct ActivityBuffer {
+    entries: Mutex<VecDeque<Activity>>,
+    capacity: usize,
+    stuck_after: Duration,
+}
+
+impl ActivityBuffer {
+    pub fn new(capacity: usize, stuck_after: Duration) -> Self {
+        Self {
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity: capacity.max(1),
+            stuck_after,
+        }
+    }
+
+    /// Create the buffer and start feeding it from `bus`.
+    pub fn spawn(bus: Arc<dyn EventBus>, capacity: usize, stuck_after: Duration) -> Arc<Self> {
+        let buffer = Arc::new(Self::new(capacity, stuck_after));
+
+        let mut rx = bus.subscribe();
+        let weak = Arc::downgrade(&buffer);
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => match weak.upgrade() {
+                        Some(buffer) => buffer.record(&event, Utc::now()),
+                        None => break,
+                    },
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!("Activity buffer lagged, {n} events dropped");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        buffer
+    }
+
+    fn record(&self, event: &AppEvent, now: DateTime<Utc>) {
+        let mut entries = self.entries.lock();
+        match event {
+            AppEvent::AgentToolStarted {
+                session_id,
+                call_id,
+                tool_name,
+                ..
+            } => {
+                if entries.len() >= self.capacity {
+                    entries.pop_front();
+                }
+                entries.push_back(Activity {
+                    id: call_id.clone(),
+                    session_id: session_id.clone(),
+                    tool_name: tool_name.clone(),
+                    status: ActivityStatus::Running,
+                    started_at: now,
+                    completed_at: None,
+                    duration_ms: None,
+                    progress: None,
+                });
+            }
+            AppEvent::AgentToolCompleted {
+                session_id,
+                call_id,
+                tool_name,
+                success,
+                duration_ms,
+                ..
+            } => {
+                let status = if *success {
+                    ActivityStatus::Success
+                } else {
+                    ActivityStatus::Failed
+                };
+                // A result after the entry was marked stuck still settles it
+                if let Some(entry) = entries
+                    .iter_mut()
+                    .rev()
+                    .find(|a| a.id == *call_id && a.completed_at.is_none())
+                {
+                    entry.status = status;
+                    entry.completed_at = Some(now);
+                    entry.duration_ms = Some(*duration_ms);
+                    return;
+                }
+                // Cached results, or a start that already left the buffer
+                if entries.len() >= self.capacity {
+                    entries.pop_front();
+                }
+                entries.push_back(Activity {
+                    id: call_id.clone(),
+                    session_id: session_id.clone(),
+                    tool_name: tool_name.clone(),
+                    status,
+                    started_at: now - chrono::Duration::milliseconds(*duration_ms as i64),
+                    completed_at: Some(now),
+                    duration_ms: Some(*duration_ms),
+                    progress: None,
+                });
+            }
+            AppEvent::PluginToolProgress { tool_name, message } => {
+                // Progress frames carry no call id; attribute them to the
+                // newest running call of that tool.
+                if let Some(entry) = entries
+                    .iter_mut()
+                    .rev()
+                    .find(|a| a.tool_name == *tool_name && a.completed_at.is_none())
+                {
+                    entry.progress = Some(message.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Up to `limit` tool calls, newest first by `(started_at, id)`. With
+    /// `before`, only calls that sort strictly after that key are returned,
+    /// so the last entry of one page is the cursor for the next. Running
+    /// calls older than the stuck timeout are reported as
+    /// [`ActivityStatus::Stuck`].
+    pub fn recent(&self, limit: usize, before: Option<(DateTime<Utc>, String)>) -> Vec<Activity> {
+        self.recent_at(limit, before, Utc::now())
+    }
+
+    fn recent_at(
+        &self,
+        limit: usize,
+        before: Option<(DateTime<Utc>, String)>,
+        now: DateTime<Utc>,
+    ) -> Vec<Activity> {
+        let stuck_before = chrono::Duration::from_std(self.stuck_after)
+            .ok()
+            .and_then(|d| now.checked_sub_signed(d));
+        let mut entries = self.entries.lock();
+        for entry in entries.iter_mut() {
+            if entry.status == ActivityStatus::Running
+                && stuck_before.is_some_and(|before| entry.started_at < before)
+            {
+                entry.status = ActivityStatus::Stuck;
+            }
+        }
+
+        // Unmatched results are back-dated, so insertion order is not
+        // start order
+        let mut page: Vec<Activity> = entries
+            .iter()
+            .filter(|a| match &before {
+                Some((ts, id)) => (a.started_at, &a.id) < (*ts, id),
+                None => true,
+            })
+            .cloned()
+            .collect();
+        page.sort_by(|a, b| (b.started_at, &b.id).cmp(&(a.started_at, &a.id)));
+        page.truncate(limit);
+        page
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn started(call_id: &str) -> AppEvent {
+        AppEvent::AgentToolStarted {
+            session_id: "s1".into(),
+            call_id: call_id.into(),
+            tool_name: "shell".into(),
+            args: serde_json::json!({}),
+        }
+    }
+
+    fn completed(call_id: &str, success: bool) -> AppEvent {
+        AppEvent::AgentToolCompleted {
+            session_id: "s1".into(),
+            call_id: call_id.into(),
+            tool_name: "shell".into(),
+            output: "ok".into(),
+            success,
+            duration_ms: 1500,
+        }
+    }
+
+    // ACT.1 — A result updates its start entry in place
+    #[test]
+    fn result_coalesces_with_start() {
+        let buffer = ActivityBuffer::new(10, Duration::from_secs(300));
+        let t0 = Utc::now();
+        buffer.record(&started("a"), t0);
+        buffer.record(&started("b"), t0);
+        buffer.record(&completed("a", false), t0 + chrono::Duration::seconds(2));
+
+        let recent = buffer.recent_at(10, None, t0 + chrono::Duration::seconds(3));
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].id, "b");
+        assert_eq!(recent[0].status, ActivityStatus::Running);
+        assert_eq!(recent[1].id, "a");
+        assert_eq!(recent[1].status, ActivityStatus::Failed);
+        assert_eq!(recent[1].started_at, t0);
+        assert_eq!(recent[1].duration_ms, Some(1500));
+    }
+
+    // ACT.2 — Calls without a result turn stuck; a late result still settles them
+    #[test]
+    fn unanswered_start_becomes_stuck() {
+        let buffer = ActivityBuffer::new(10, Duration::from_secs(60));
+        let t0 = Utc::now();
+        buffer.record(&started("a"), t0);
+
+        let later = t0 + chrono::Duration::seconds(61);
+        assert_eq!(
+            buffer.recent_at(10, None, later)[0].status,
+            ActivityStatus::Stuck
+        );
+
+        buffer.record(&completed("a", true), later);
+        let recent = buffer.recent_at(10, None, later);
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].status, ActivityStatus::Success);
+    }
+
+    // ACT.3 — Unmatched results are kept, and the oldest entries are evicted
+    #[test]
+    fn unmatched_result_and_capacity() {
+        let buffer = ActivityBuffer::new(2, Duration::from_secs(60));
+        let now = Utc::now();
+        buffer.record(&completed("cached", true), now);
+        buffer.record(&started("b"), now);
+        buffer.record(&started("c"), now);
+
+        let ids: Vec<_> = buffer
+            .recent_at(10, None, now)
+            .into_iter()
+            .map(|a| a.id)
+            .collect();
+        assert_eq!(ids, vec!["c", "b"]);
+
+        let buffer = ActivityBuffer::new(2, Duration::from_secs(60));
+        buffer.record(&completed("cached", true), now);
+        let recent = buffer.recent_at(10, None, now);
+        assert_eq!(recent[0].status, ActivityStatus::Success);
+        assert_eq!(
+            recent[0].started_at,
+            now - chrono::Duration::milliseconds(1500)
+        );
+    }
+
+    // ACT.4 — Plugin progress lands on the newest running call of that tool
+    #[test]
+    fn plugin_progress_updates_running_call() {
+        let buffer = ActivityBuffer::new(10, Duration::from_secs(60));
+        let now = Utc::now();
+        buffer.record(&started("a"), now);
+        buffer.record(&completed("a", true), now);
+        buffer.record(&started("b"), now);
+        buffer.record(
+            &AppEvent::PluginToolProgress {
+                tool_name: "shell".into(),
+                message: "step 2/3".into(),
+            },
+            now,
+        );
+
+        let recent = buffer.recent_at(10, None, now);
+        assert_eq!(recent[0].id, "b");
+        assert_eq!(recent[0].progress.as_deref(), Some("step 2/3"));
+        assert_eq!(recent[1].progress, None);
+    }
+
+    // ACT.5 — Pages follow (started_at, id), including calls that share a start time
+    #[test]
+    fn recent_pages_across_equal_timestamps() {
+        let buffer = ActivityBuffer::new(10, Duration::from_secs(60));
+        let now = Utc::now();
+        for id in ["b", "d", "a", "c"] {
+            buffer.record(&started(id), now);
+        }
+
+        let first = buffer.recent_at(2, None, now);
+        let ids: Vec<_> = first.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["d", "c"]);
+
+        let last = first.last().unwrap();
+        let cursor = Some((last.started_at, last.id.clone()));
+        let ids: Vec<_> = buffer
+            .recent_at(2, cursor, now)
+            .into_iter()
+            .map(|a| a.id)
+            .collect();
+        assert_eq!(ids, vec!["b", "a"]);
+    }
+}
@@ -0,0 +1,406 @@
+//! Durable history of resource state transitions.
+//!
+//! Channels, delegations, sub-agents, workflow runs, plugins and the scheduler
+//! report their state changes on the event bus. [`LifecycleLogger`] turns those
+//! events into `from_state -> to_state` rows in the `lifecycle_transitions`
+//! table. The previous state is read back from the table, so the history
+//! stays continuous across restarts.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use super::{AppEvent, EventBus};
+use crate::db::query::{clamp_limit, normalize_timestamp, parse_bound};
+use crate::db::{self, DbPool};
+use crate::{Result, ZeniiError};
+
+/// One recorded state change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct StateTransition {
+    pub id: i64,
+    pub timestamp: String,
+    /// `channel`, `delegation`, `sub_agent`, `workflow_run`, `plugin` or `scheduler`.
+    pub resource_type: String,
+    pub resource_id: String,
+    /// `None` for the first transition recorded for a resource.
+    pub from_state: Option<String>,
+    pub to_state: String,
+    pub reason: Option<String>,
+}
+
+/// Filter for [`query`].
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LifecycleQuery {
+    pub resource_type: Option<String>,
+    pub resource_id: Option<String>,
+    /// Matches transitions into this state.
+    pub state: Option<String>,
+    /// Inclusive lower bound, RFC 3339.
+    pub since: Option<String>,
+    /// Exclusive upper bound, RFC 3339.
+    pub until: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+/// `(resource_type, resource_id, to_state, reason)` for events that move a
+/// resource to a new state.
+fn transition_for(event: &AppEvent) -> Option<(&'static str, String, String, Option<String>)> {
+    let transition = match event {
+        AppEvent::ChannelConnected { channel } => {
+            ("channel", channel.clone(), "connected".into(), None)
+        }
+        AppEvent::ChannelDisconnected { channel, reason } => (
+            "channel",
+            channel.clone(),
+            "disconnected".into(),
+            Some(reason.clone()),
+        ),
+        AppEvent::ChannelReconnecting { channel, attempt } => (
+            "channel",
+            channel.clone(),
+            "reconnecting".into(),
+            Some(format!("attempt {attempt}")),
+        ),
+        AppEvent::DelegationStarted { delegation_id, .. } => {
+            ("delegation", delegation_id.clone(), "running".into(), None)
+        }
+        AppEvent::DelegationCompleted { delegation_id, .. } => (
+            "delegation",
+            delegation_id.clone(),
+            "completed".into(),
+            None,
+        ),
+        AppEvent::SubAgentSpawned {
+            delegation_id,
+            agent_id,
+            ..
+        } => (
+            "sub_agent",
+            format!("{delegation_id}/{agent_id}"),
+            "running".into(),
+            None,
+        ),
+        AppEvent::SubAgentCompleted {
+            delegation_id,
+            agent_id,
+            status,
+            ..
+        } => (
+            "sub_agent",
+            format!("{delegation_id}/{agent_id}"),
+            status.to_lowercase(),
+            None,
+        ),
+        AppEvent::SubAgentFailed {
+            delegation_id,
+            agent_id,
+            error,
+            ..
+        } => (
+            "sub_agent",
+            format!("{delegation_id}/{agent_id}"),
+            "failed".into(),
+            Some(error.clone()),
+        ),
+        AppEvent::WorkflowStarted { run_id, .. } => {
+            ("workflow_run", run_id.clone(), "running".into(), None)
+        }
+        AppEvent::WorkflowCompleted { run_id, status, .. } => {
+            ("workflow_run", run_id.clone(), status.to_lowercase(), None)
+        }
+        AppEvent::PluginHealthChanged {
+            plugin,
+            state,
+            error,
+            ..
+        } => ("plugin", plugin.clone(), state.clone(), error.clone()),
+        AppEvent::SchedulerStarted => ("scheduler", "scheduler".into(), "running".into(), None),
+        AppEvent::SchedulerStopped => ("scheduler", "scheduler".into(), "stopped".into(), None),
+        _ => return None,
+    };
+    Some(transition)
+}
+
+/// Record a move of `resource_id` to `to_state`. Repeats of the current state
+/// (a channel reporting another reconnect attempt) are not recorded.
+async fn record(
+    db: &DbPool,
+    resource_type: &'static str,
+    resource_id: String,
+    to_state: String,
+    reason: Option<String>,
+) -> Result<()> {
+    let timestamp = normalize_timestamp(Utc::now());
+    db::with_db(db, move |conn| {
+        let from_state: Option<String> = match conn.query_row(
+            "SELECT to_state FROM lifecycle_transitions
+             WHERE resource_type = ?1 AND resource_id = ?2
+             ORDER BY id DESC LIMIT 1",
+            rusqlite::params![resource_type, resource_id],
+            |row| row.get(0),
+        ) {
+            Ok(state) => Some(state),
+            Err(rusqlite::Error::QueryReturnedNoRows) => None,
+            Err(e) => return Err(ZeniiError::Sqlite(e)),
+        };
+        if from_state.as_deref() == Some(to_state.as_str()) {
+            return Ok(());
+        }
+        conn.execute(
+            "INSERT INTO lifecycle_transitions (timestamp, resource_type, resource_id, from_state, to_state, reason)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![timestamp, resource_type, resource_id, from_state, to_state, reason],
+        )?;
+        Ok(())
+    })
+    .await
+}
+
+/// Writes resource state transitions from the event bus to the
+/// `lifecycle_transitions` table.
+pub struct LifecycleLogger;
+
+impl LifecycleLogger {
+    /// Start persisting transitions from `bus` until the bus closes.
+    pub fn spawn(bus: Arc<dyn EventBus>, db: DbPool) -> JoinHandle<()> {
+        let mut rx = bus.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => {
+                        if let Some((resource_type, resource_id, to_state, reason)) =
+                            transition_for(&event)
+                            && let Err(e) =
+                                record(&db, resource_type, resource_id, to_state, reason).await
+                        {
+                            warn!("Failed to record lifecycle transition: {e}");
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!("Lifecycle logger lagged, {n} events not recorded");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
+
+/// Transitions matching `filter`, newest first.
+pub async fn query(db: &DbPool, filter: &LifecycleQuery) -> Result<Vec<StateTransition>> {
+    let since = filter
+        .since
+        .as_deref()
+        .map(|s| parse_bound("since", s))
+        .transpose()?;
+    let until = filter
+        .until
+        .as_deref()
+        .map(|s| parse_bound("until", s))
+        .transpose()?;
+    let (resource_type, resource_id, state) = (
+        filter.resource_type.clone(),
+        filter.resource_id.clone(),
+        filter.state.clone(),
+    );
+    let limit = clamp_limit(filter.limit);
+    let offset = filter.offset.unwrap_or(0) as i64;
+
+    db::with_db(db, move |conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, timestamp, resource_type, resource_id, from_state, to_state, reason
+             FROM lifecycle_transitions
+             WHERE (?1 IS NULL OR resource_type = ?1)
+               AND (?2 IS NULL OR resource_id = ?2)
+               AND (?3 IS NULL OR to_state = ?3)
+               AND (?4 IS NULL OR timestamp >= ?4)
+               AND (?5 IS NULL OR timestamp < ?5)
+             ORDER BY id DESC
+             LIMIT ?6 OFFSET ?7",
+        )?;
+        let rows = stmt
+            .query_map(
+                rusqlite::params![
+                    resource_type,
+                    resource_id,
+                    state,
+                    since,
+                    until,
+                    limit,
+                    offset
+                ],
+                |row| {
+                    Ok(StateTransition {
+                        id: row.get(0)?,
+                        timestamp: row.get(1)?,
+                        resource_type: row.get(2)?,
+                        resource_id: row.get(3)?,
+                        from_state: row.get(4)?,
+                        to_state: row.get(5)?,
+                        reason: row.get(6)?,
+                    })
+                },
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(rows)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_bus::TokioBroadcastBus;
+
+    async fn test_db(dir: &tempfile::TempDir) -> DbPool {
+        let db = db::init_pool(&dir.path().join("lifecycle.db")).unwrap();
+        db::with_db(&db, |conn| db::run_migrations(conn))
+            .await
+            .unwrap();
+        db
+    }
+
+    // LC.1 — Events become from/to transitions; repeated states are collapsed
+    #[tokio::test]
+    async fn logger_records_transitions() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = test_db(&dir).await;
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let handle = LifecycleLogger::spawn(bus.clone(), db.clone());
+
+        for event in [
+            AppEvent::ChannelConnected {
+                channel: "telegram".into(),
+            },
+            AppEvent::ChannelDisconnected {
+                channel: "telegram".into(),
+                reason: "network".into(),
+            },
+            AppEvent::ChannelReconnecting {
+                channel: "telegram".into(),
+                attempt: 1,
+            },
+            AppEvent::ChannelReconnecting {
+                channel: "telegram".into(),
+                attempt: 2,
+            },
+            AppEvent::SubAgentFailed {
+                delegation_id: "d1".into(),
+                agent_id: "a1".into(),
+                error: "timeout".into(),
+                tool_uses: 0,
+                duration_ms: 10,
+            },
+            AppEvent::ConfigUpdated,
+        ] {
+            bus.publish(event).unwrap();
+        }
+
+        let mut all = Vec::new();
+        for _ in 0..50 {
+            all = query(&db, &LifecycleQuery::default()).await.unwrap();
+            if all.len() == 4 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert_eq!(all.len(), 4);
+
+        let channel = query(
+            &db,
+            &LifecycleQuery {
+                resource_type: Some("channel".into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let moves: Vec<_> = channel
+            .iter()
+            .map(|t| (t.from_state.as_deref(), t.to_state.as_str()))
+            .collect();
+        assert_eq!(
+            moves,
+            [
+                (Some("disconnected"), "reconnecting"),
+                (Some("connected"), "disconnected"),
+                (None, "connected"),
+            ]
+        );
+        assert_eq!(channel[0].reason.as_deref(), Some("attempt 1"));
+
+        let failed = query(
+            &db,
+            &LifecycleQuery {
+                state: Some("failed".into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].resource_id, "d1/a1");
+        assert_eq!(failed[0].reason.as_deref(), Some("timeout"));
+        handle.abort();
+    }
+
+    // LC.2 — The previous state survives a restart; paging and bounds apply
+    #[tokio::test]
+    async fn history_persists_and_pages() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = test_db(&dir).await;
+        record(&db, "plugin", "weather".into(), "healthy".into(), None)
+            .await
+            .unwrap();
+        drop(db);
+
+        let db = test_db(&dir).await;
+        record(
+            &db,
+            "plugin",
+            "weather".into(),
+            "unhealthy".into(),
+            Some("no pong".into()),
+        )
+        .await
+        .unwrap();
+        record(&db, "plugin", "weather".into(), "healthy".into(), None)
+            .await
+            .unwrap();
+
+        let page = |limit, offset| LifecycleQuery {
+            resource_id: Some("weather".into()),
+            limit: Some(limit),
+            offset: Some(offset),
+            ..Default::default()
+        };
+        let first = query(&db, &page(2, 0)).await.unwrap();
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[1].from_state.as_deref(), Some("healthy"));
+        assert_eq!(first[1].to_state, "unhealthy");
+        let rest = query(&db, &page(2, 2)).await.unwrap();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].from_state, None);
+
+        let future = LifecycleQuery {
+            since: Some("2999-01-01T00:00:00Z".into()),
+            ..Default::default()
+        };
+        assert!(query(&db, &future).await.unwrap().is_empty());
+        let bad = LifecycleQuery {
+            until: Some("tomorrow".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            query(&db, &bad).await,
+            Err(ZeniiError::Validation(_))
+        ));
+    }
+}
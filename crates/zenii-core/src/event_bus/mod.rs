@@ -1,8 +1,19 @@
+pub mod activity;
+pub mod lifecycle;
+pub mod recorder;
+pub mod session_stream;
+
+use std::collections::VecDeque;
+
 use crate::Result;
 use async_trait::async_trait;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast;
 
+/// Recent events `TokioBroadcastBus::new` keeps for replay.
+pub const DEFAULT_REPLAY_CAPACITY: usize = 200;
+
 /// Agent info included in delegation lifecycle events.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DelegationAgentInfo {
@@ -114,6 +125,86 @@ pub enum AppEvent {
         channel: String,
         session_id: String,
     },
+    /// A gateway chat turn (HTTP or WebSocket) started running the agent.
+    AgentStarted {
+        session_id: String,
+        surface: String,
+    },
+    /// Incremental assistant text streamed during a gateway chat turn.
+    AgentToken {
+        session_id: String,
+        delta: String,
+    },
+    AgentToolStarted {
+        session_id: String,
+        call_id: String,
+        tool_name: String,
+        args: serde_json::Value,
+    },
+    AgentToolCompleted {
+        session_id: String,
+        call_id: String,
+        tool_name: String,
+        output: String,
+        success: bool,
+        duration_ms: u64,
+    },
+    AgentCompleted {
+        session_id: String,
+        duration_ms: u64,
+        /// Why the turn ended early, e.g. a spent token or wall-time budget.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+    },
+    /// Token usage of a finished agent run, summed across its turns.
+    /// `None` when the provider reported no usage or the model has no pricing.
+    AgentUsage {
+        session_id: String,
+        total_tokens: Option<u64>,
+        cost_usd: Option<f64>,
+    },
+    /// The agent failed to complete a turn (provider, model or tool-loop failure).
+    /// Distinct from system faults; `recoverable` tells whether a retry may succeed.
+    AgentError {
+        session_id: Option<String>,
+        message: String,
+        recoverable: bool,
+    },
+    /// A completion failed with a retryable provider error and the turn moved on
+    /// to the next model in the `routing_fallbacks` chain.
+    ModelFailover {
+        failed_model: String,
+        next_model: String,
+        error: String,
+    },
+    /// The oldest history messages were dropped so a request would fit the
+    /// model's context window.
+    ContextTruncated {
+        model: String,
+        dropped_messages: usize,
+        estimated_tokens: usize,
+        context_limit: usize,
+    },
+    /// A tool failed the same way repeatedly. The agent was asked to reflect,
+    /// or, when `aborted`, the turn was stopped.
+    ToolFailureLoop {
+        tool_name: String,
+        failures: u32,
+        error: String,
+        aborted: bool,
+    },
+    /// A plugin tool reported progress while its call is still running.
+    PluginToolProgress {
+        tool_name: String,
+        message: String,
+    },
+    /// Health probes moved a plugin between `healthy` and `unhealthy`.
+    PluginHealthChanged {
+        plugin: String,
+        state: String,
+        consecutive_failures: u32,
+        error: Option<String>,
+    },
     ApprovalRequested {
         approval_id: String,
         call_id: String,
@@ -122,6 +213,17 @@ pub enum AppEvent {
         risk_level: String,
         reason: String,
         timeout_secs: u64,
+        /// Session whose agent turn asked, if the call ran in one.
+        session_id: Option<String>,
+    },
+    /// A pending approval was decided. `resolved_by` is the surface that
+    /// answered (`http`, `ws`) or `timeout` when nobody did.
+    ApprovalResolved {
+        approval_id: String,
+        call_id: String,
+        tool_name: String,
+        decision: String,
+        resolved_by: String,
     },
     SessionCreated {
         session_id: String,
@@ -137,9 +239,29 @@ pub enum AppEvent {
         role: String,
     },
     MemoryChanged,
+    /// A memory was written automatically (e.g. facts learned after an agent run).
+    MemoryStored {
+        key: String,
+        category: String,
+        session_id: Option<String>,
+    },
     SchedulerJobsChanged,
     CredentialsChanged,
     ProvidersChanged,
+    /// Provider config and keys were re-read on request; `active_model` is the
+    /// model that now resolves, if any.
+    ProviderReloaded {
+        active_model: Option<String>,
+    },
+    /// An OAuth authorization flow finished and its token was stored.
+    OAuthCompleted {
+        provider: String,
+    },
+    /// An OAuth callback was rejected (bad state, denied consent, failed exchange).
+    OAuthFailed {
+        provider: Option<String>,
+        error: String,
+    },
     SkillsChanged,
     IdentityChanged,
     WorkflowsChanged,
@@ -148,26 +270,203 @@ pub enum AppEvent {
     Shutdown,
 }
 
+impl AppEvent {
+    /// Variant name, as used in serialized events and `EventFilter::kinds`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ConfigUpdated => "ConfigUpdated",
+            Self::ChannelConnected { .. } => "ChannelConnected",
+            Self::ChannelDisconnected { .. } => "ChannelDisconnected",
+            Self::ChannelReconnecting { .. } => "ChannelReconnecting",
+            Self::ChannelMessageReceived { .. } => "ChannelMessageReceived",
+            Self::HeartbeatTick { .. } => "HeartbeatTick",
+            Self::CronFired { .. } => "CronFired",
+            Self::HeartbeatAlert { .. } => "HeartbeatAlert",
+            Self::SchedulerStarted => "SchedulerStarted",
+            Self::SchedulerStopped => "SchedulerStopped",
+            Self::SchedulerNotification { .. } => "SchedulerNotification",
+            Self::SchedulerJobCompleted { .. } => "SchedulerJobCompleted",
+            Self::DelegationStarted { .. } => "DelegationStarted",
+            Self::SubAgentSpawned { .. } => "SubAgentSpawned",
+            Self::SubAgentProgress { .. } => "SubAgentProgress",
+            Self::SubAgentCompleted { .. } => "SubAgentCompleted",
+            Self::SubAgentFailed { .. } => "SubAgentFailed",
+            Self::DelegationCompleted { .. } => "DelegationCompleted",
+            Self::WorkflowStarted { .. } => "WorkflowStarted",
+            Self::WorkflowCompleted { .. } => "WorkflowCompleted",
+            Self::WorkflowStepCompleted { .. } => "WorkflowStepCompleted",
+            Self::ChannelAgentStarted { .. } => "ChannelAgentStarted",
+            Self::ChannelAgentCompleted { .. } => "ChannelAgentCompleted",
+            Self::AgentStarted { .. } => "AgentStarted",
+            Self::AgentToken { .. } => "AgentToken",
+            Self::AgentToolStarted { .. } => "AgentToolStarted",
+            Self::AgentToolCompleted { .. } => "AgentToolCompleted",
+            Self::AgentCompleted { .. } => "AgentCompleted",
+            Self::AgentUsage { .. } => "AgentUsage",
+            Self::AgentError { .. } => "AgentError",
+            Self::ModelFailover { .. } => "ModelFailover",
+            Self::ContextTruncated { .. } => "ContextTruncated",
+            Self::ToolFailureLoop { .. } => "ToolFailureLoop",
+            Self::PluginToolProgress { .. } => "PluginToolProgress",
+            Self::PluginHealthChanged { .. } => "PluginHealthChanged",
+            Self::ApprovalRequested { .. } => "ApprovalRequested",
+            Self::ApprovalResolved { .. } => "ApprovalResolved",
+            Self::SessionCreated { .. } => "SessionCreated",
+            Self::SessionDeleted { .. } => "SessionDeleted",
+            Self::MessageAdded { .. } => "MessageAdded",
+            Self::MemoryChanged => "MemoryChanged",
+            Self::MemoryStored { .. } => "MemoryStored",
+            Self::SchedulerJobsChanged => "SchedulerJobsChanged",
+            Self::CredentialsChanged => "CredentialsChanged",
+            Self::ProvidersChanged => "ProvidersChanged",
+            Self::ProviderReloaded { .. } => "ProviderReloaded",
+            Self::OAuthCompleted { .. } => "OAuthCompleted",
+            Self::OAuthFailed { .. } => "OAuthFailed",
+            Self::SkillsChanged => "SkillsChanged",
+            Self::IdentityChanged => "IdentityChanged",
+            Self::WorkflowsChanged => "WorkflowsChanged",
+            Self::PluginsChanged => "PluginsChanged",
+            Self::PermissionsChanged => "PermissionsChanged",
+            Self::Shutdown => "Shutdown",
+        }
+    }
+
+    /// Build an `AgentError` event from the error that ended an agent turn.
+    pub fn agent_error(session_id: Option<&str>, err: &crate::ZeniiError) -> Self {
+        AppEvent::AgentError {
+            session_id: session_id.map(String::from),
+            message: err.to_string(),
+            recoverable: err.is_recoverable(),
+        }
+    }
+
+    /// `AgentCompleted` carrying a "budget exceeded" note when `err` is a spent
+    /// turn budget, so listeners see the turn end rather than just an error.
+    pub fn budget_exceeded(
+        session_id: &str,
+        duration_ms: u64,
+        err: &crate::ZeniiError,
+    ) -> Option<Self> {
+        err.is_budget_exceeded().then(|| AppEvent::AgentCompleted {
+            session_id: session_id.to_string(),
+            duration_ms,
+            note: Some(err.to_string()),
+        })
+    }
+}
+
+/// An event numbered in publish order.
+#[derive(Debug, Clone)]
+pub struct SequencedEvent {
+    pub seq: u64,
+    pub event: AppEvent,
+}
+
+/// Which events a filtered subscription delivers.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    /// Variant names (see `AppEvent::kind`) to deliver; empty delivers all.
+    pub kinds: Vec<String>,
+}
+
+impl EventFilter {
+    pub fn matches(&self, event: &AppEvent) -> bool {
+        self.kinds.is_empty() || self.kinds.iter().any(|k| k == event.kind())
+    }
+}
+
+/// Buffered events that matched the filter, followed by live ones.
+pub struct ReplaySubscription {
+    replay: VecDeque<SequencedEvent>,
+    live: broadcast::Receiver<SequencedEvent>,
+    filter: EventFilter,
+}
+
+impl ReplaySubscription {
+    /// Next matching event, oldest buffered ones first.
+    pub async fn recv(
+        &mut self,
+    ) -> std::result::Result<SequencedEvent, broadcast::error::RecvError> {
+        if let Some(event) = self.replay.pop_front() {
+            return Ok(event);
+        }
+        loop {
+            let event = self.live.recv().await?;
+            if self.filter.matches(&event.event) {
+                return Ok(event);
+            }
+        }
+    }
+}
+
 #[async_trait]
 pub trait EventBus: Send + Sync {
     fn publish(&self, event: AppEvent) -> Result<()>;
     fn subscribe(&self) -> broadcast::Receiver<AppEvent>;
+    /// Subscribe to events matching `filter`, first replaying the buffered
+    /// ones with a `seq` after `since` (all buffered ones when `None`).
+    fn subscribe_filtered_with_replay(
+        &self,
+        filter: EventFilter,
+        since: Option<u64>,
+    ) -> ReplaySubscription;
+}
+
+struct ReplayBuffer {
+    events: VecDeque<SequencedEvent>,
+    next_seq: u64,
 }
 
 pub struct TokioBroadcastBus {
     sender: broadcast::Sender<AppEvent>,
+    sequenced: broadcast::Sender<SequencedEvent>,
+    replay: Mutex<ReplayBuffer>,
+    replay_capacity: usize,
 }
 
 impl TokioBroadcastBus {
     pub fn new(capacity: usize) -> Self {
+        Self::with_replay(capacity, DEFAULT_REPLAY_CAPACITY)
+    }
+
+    /// Bus that keeps the last `replay_capacity` events for late subscribers.
+    pub fn with_replay(capacity: usize, replay_capacity: usize) -> Self {
         let (sender, _) = broadcast::channel(capacity);
-        Self { sender }
+        let (sequenced, _) = broadcast::channel(capacity);
+        Self {
+            sender,
+            sequenced,
+            replay: Mutex::new(ReplayBuffer {
+                events: VecDeque::with_capacity(replay_capacity),
+                next_seq: 1,
+            }),
+            replay_capacity,
+        }
     }
 }
 
 #[async_trait]
 impl EventBus for TokioBroadcastBus {
     fn publish(&self, event: AppEvent) -> Result<()> {
+        {
+            let mut buffer = self.replay.lock();
+            let sequenced = SequencedEvent {
+                seq: buffer.next_seq,
+                event: event.clone(),
+            };
+            buffer.next_seq += 1;
+            // Token deltas are live-only; buffering them would push every
+            // other event out of the replay window
+            if self.replay_capacity > 0 && !matches!(event, AppEvent::AgentToken { .. }) {
+                if buffer.events.len() >= self.replay_capacity {
+                    buffer.events.pop_front();
+                }
+                buffer.events.push_back(sequenced.clone());
+            }
+            // Sent under the lock so a replay subscription sees every event
+            // exactly once, either buffered or live
+            let _ = self.sequenced.send(sequenced);
+        }
         // Ignore error when there are no subscribers — this is expected during startup
         let _ = self.sender.send(event);
         Ok(())
@@ -176,6 +475,26 @@ impl EventBus for TokioBroadcastBus {
     fn subscribe(&self) -> broadcast::Receiver<AppEvent> {
         self.sender.subscribe()
     }
+
+    fn subscribe_filtered_with_replay(
+        &self,
+        filter: EventFilter,
+        since: Option<u64>,
+    ) -> ReplaySubscription {
+        let buffer = self.replay.lock();
+        let live = self.sequenced.subscribe();
+        let replay = buffer
+            .events
+            .iter()
+            .filter(|e| since.is_none_or(|since| e.seq > since) && filter.matches(&e.event))
+            .cloned()
+            .collect();
+        ReplaySubscription {
+            replay,
+            live,
+            filter,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -193,6 +512,62 @@ mod tests {
         assert!(matches!(event, AppEvent::ConfigUpdated));
     }
 
+    // EB.1 — Late subscribers get buffered events, oldest dropped at capacity
+    #[tokio::test]
+    async fn replay_delivers_buffered_then_live() {
+        let bus = TokioBroadcastBus::with_replay(16, 2);
+        bus.publish(AppEvent::ConfigUpdated).unwrap();
+        bus.publish(AppEvent::SkillsChanged).unwrap();
+        bus.publish(AppEvent::MemoryChanged).unwrap();
+
+        let mut sub = bus.subscribe_filtered_with_replay(EventFilter::default(), None);
+        bus.publish(AppEvent::Shutdown).unwrap();
+
+        let seqs_and_kinds: Vec<_> = [
+            sub.recv().await.unwrap(),
+            sub.recv().await.unwrap(),
+            sub.recv().await.unwrap(),
+        ]
+        .into_iter()
+        .map(|e| (e.seq, e.event.kind()))
+        .collect();
+        assert_eq!(
+            seqs_and_kinds,
+            [(2, "SkillsChanged"), (3, "MemoryChanged"), (4, "Shutdown")]
+        );
+        assert!(matches!(
+            sub.live.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+    }
+
+    // EB.2 — Replay and live delivery both respect the filter and `since`
+    #[tokio::test]
+    async fn replay_respects_filter_and_since() {
+        let bus = TokioBroadcastBus::new(16);
+        bus.publish(AppEvent::SkillsChanged).unwrap();
+        bus.publish(AppEvent::ConfigUpdated).unwrap();
+        bus.publish(AppEvent::SkillsChanged).unwrap();
+        bus.publish(AppEvent::AgentToken {
+            session_id: "s1".into(),
+            delta: "hi".into(),
+        })
+        .unwrap();
+
+        let filter = EventFilter {
+            kinds: vec!["SkillsChanged".into(), "AgentToken".into()],
+        };
+        let mut sub = bus.subscribe_filtered_with_replay(filter, Some(1));
+        bus.publish(AppEvent::ConfigUpdated).unwrap();
+        bus.publish(AppEvent::SkillsChanged).unwrap();
+
+        // seq 1 is before `since`, ConfigUpdated is filtered out and the
+        // token delta was never buffered
+        assert_eq!(sub.recv().await.unwrap().seq, 3);
+        let live = sub.recv().await.unwrap();
+        assert_eq!((live.seq, live.event.kind()), (6, "SkillsChanged"));
+    }
+
     #[tokio::test]
     async fn multi_subscriber_fanout() {
         let bus = TokioBroadcastBus::new(16);
@@ -666,14 +1041,34 @@ mod tests {
             risk_level: "medium".into(),
             reason: "Command needs approval: cargo build".into(),
             timeout_secs: 120,
+            session_id: Some("sess-1".into()),
         };
         let json = serde_json::to_string(&event).unwrap();
         let back: AppEvent = serde_json::from_str(&json).unwrap();
         assert!(
-            matches!(back, AppEvent::ApprovalRequested { approval_id, call_id, tool_name, args_summary, risk_level, reason, timeout_secs }
-                if approval_id == "apr-1" && call_id == "call-1" && tool_name == "shell"
+            matches!(back, AppEvent::ApprovalRequested { approval_id, call_id, tool_name, args_summary, risk_level, reason, timeout_secs, session_id }
+                if approval_id == "apr-1" && session_id.as_deref() == Some("sess-1") && call_id == "call-1" && tool_name == "shell"
                 && args_summary == "cargo build" && risk_level == "medium"
                 && reason == "Command needs approval: cargo build" && timeout_secs == 120)
         );
     }
+
+    #[test]
+    fn budget_exceeded_completes_with_note() {
+        let err = crate::ZeniiError::BudgetExceeded("token budget of 10 exceeded".into());
+        match AppEvent::budget_exceeded("s1", 42, &err) {
+            Some(AppEvent::AgentCompleted {
+                session_id,
+                duration_ms,
+                note,
+            }) => {
+                assert_eq!(session_id, "s1");
+                assert_eq!(duration_ms, 42);
+                assert!(note.unwrap().contains("budget exceeded"));
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+        let other = crate::ZeniiError::Agent("boom".into());
+        assert!(AppEvent::budget_exceeded("s1", 42, &other).is_none());
+    }
 }
@@ -0,0 +1,288 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{Mutex, broadcast, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use super::{AppEvent, EventBus};
+use crate::{Result, ZeniiError};
+
+/// Summary of a finished (or running) event recording.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingSummary {
+    pub path: PathBuf,
+    pub events: u64,
+    pub bytes: u64,
+    /// True when recording stopped early because the size cap was reached.
+    pub truncated: bool,
+}
+
+struct ActiveRecording {
+    path: PathBuf,
+    stop_tx: oneshot::Sender<()>,
+    handle: JoinHandle<Result<RecordingSummary>>,
+}
+
+/// Records every `AppEvent` on the bus to a JSON Lines file for debugging.
+///
+/// Only one recording can run at a time. The file is appended to, one
+/// serialized event per line, until `stop` is called or `max_bytes` is reached.
+#[derive(Default)]
+pub struct EventRecorder {
+    active: Mutex<Option<ActiveRecording>>,
+}
+
+impl EventRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Path of the running recording, if any.
+    pub async fn active_path(&self) -> Option<PathBuf> {
+        self.active.lock().await.as_ref().map(|a| a.path.clone())
+    }
+
+    /// Start recording the full bus to `path`.
+    pub async fn start(&self, bus: Arc<dyn EventBus>, path: PathBuf, max_bytes: u64) -> Result<()> {
+        let mut active = self.active.lock().await;
+        if let Some(existing) = active.as_ref()
+            && !existing.handle.is_finished()
+        {
+            return Err(ZeniiError::Validation(format!(
+                "event recording already running: {}",
+                existing.path.display()
+            )));
+        }
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await?;
+
+        // Subscribe before returning so no event published after start() is missed
+        let rx = bus.subscribe();
+        let (stop_tx, stop_rx) = oneshot::channel();
+        let task_path = path.clone();
+        let handle = tokio::spawn(record_loop(rx, stop_rx, file, task_path, max_bytes));
+
+        info!(path = %path.display(), max_bytes, "Event recording started");
+        *active = Some(ActiveRecording {
+            path,
+            stop_tx,
+            handle,
+        });
+        Ok(())
+    }
+
+    /// Stop the running recording and return its summary.
+    pub async fn stop(&self) -> Result<RecordingSummary> {
+        let recording = self
+            .active
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| ZeniiError::NotFound("no event recording is running".into()))?;
+
+        // The task may already have exited on the size cap; ignore a closed receiver
+        let _ = recording.stop_tx.send(());
+        let summary = recording
+            .handle
+            .await
+            .map_err(|e| ZeniiError::Other(format!("event recorder task failed: {e}")))??;
+        info!(
+            path = %summary.path.display(),
+            events = summary.events,
+            bytes = summary.bytes,
+            "Event recording stopped"
+        );
+        Ok(summary)
+    }
+}
+
+async fn record_loop(
+    mut rx: broadcast::Receiver<AppEvent>,
+    mut stop_rx: oneshot::Receiver<()>,
+    mut file: tokio::fs::File,
+    path: PathBuf,
+    max_bytes: u64,
+) -> Result<RecordingSummary> {
+    let mut summary = RecordingSummary {
+        path,
+        events: 0,
+        bytes: 0,
+        truncated: false,
+    };
+
+    loop {
+        let event = tokio::select! {
+            _ = &mut stop_rx => break,
+            result = rx.recv() => match result {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("Event recorder lagged, {n} events dropped");
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+        };
+
+        let mut line = serde_json::to_string(&event)?;
+        line.push('\n');
+        if summary.bytes + line.len() as u64 > max_bytes {
+            warn!(
+                path = %summary.path.display(),
+                max_bytes, "Event recording reached size cap, stopping"
+            );
+            summary.truncated = true;
+            break;
+        }
+        file.write_all(line.as_bytes()).await?;
+        summary.bytes += line.len() as u64;
+        summary.events += 1;
+    }
+
+    file.flush().await?;
+    Ok(summary)
+}
+
+/// Publish every event from a recording back onto the bus, in order.
+///
+/// Dev-only: replayed events reach every live subscriber, including the
+/// scheduler and notification router, so never run this against real data.
+#[cfg(debug_assertions)]
+pub async fn replay_events(bus: &dyn EventBus, path: &std::path::Path) -> Result<usize> {
+    let content = tokio::fs::read_to_string(path).await?;
+    let mut count = 0;
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        let event: AppEvent = serde_json::from_str(line)?;
+        // publish only errors when nobody is subscribed; that is not a replay failure
+        let _ = bus.publish(event);
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_bus::TokioBroadcastBus;
+
+    async fn settle() {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    // ER.1 — Recording captures published events as JSON lines
+    #[tokio::test]
+    async fn recording_captures_published_events() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("trace").join("events.jsonl");
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let recorder = EventRecorder::new();
+
+        recorder
+            .start(bus.clone(), path.clone(), 1024 * 1024)
+            .await
+            .unwrap();
+        assert_eq!(recorder.active_path().await, Some(path.clone()));
+
+        bus.publish(AppEvent::ConfigUpdated).unwrap();
+        bus.publish(AppEvent::CronFired {
+            job_id: "j1".into(),
+            name: "daily".into(),
+        })
+        .unwrap();
+        settle().await;
+
+        let summary = recorder.stop().await.unwrap();
+        assert_eq!(summary.events, 2);
+        assert!(!summary.truncated);
+        assert!(recorder.active_path().await.is_none());
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let events: Vec<AppEvent> = content
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], AppEvent::ConfigUpdated));
+        assert!(matches!(&events[1], AppEvent::CronFired { job_id, .. } if job_id == "j1"));
+        assert_eq!(summary.bytes, content.len() as u64);
+    }
+
+    // ER.2 — Size cap stops the recording early
+    #[tokio::test]
+    async fn recording_stops_at_size_cap() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let recorder = EventRecorder::new();
+
+        let line_len = serde_json::to_string(&AppEvent::ConfigUpdated)
+            .unwrap()
+            .len() as u64
+            + 1;
+        recorder
+            .start(bus.clone(), path.clone(), line_len * 2)
+            .await
+            .unwrap();
+        for _ in 0..5 {
+            bus.publish(AppEvent::ConfigUpdated).unwrap();
+        }
+        settle().await;
+
+        let summary = recorder.stop().await.unwrap();
+        assert_eq!(summary.events, 2);
+        assert!(summary.truncated);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+    }
+
+    // ER.3 — Only one recording at a time; stop without start errors
+    #[tokio::test]
+    async fn recording_is_exclusive() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let recorder = EventRecorder::new();
+
+        assert!(recorder.stop().await.is_err());
+        recorder
+            .start(bus.clone(), dir.path().join("a.jsonl"), 1024)
+            .await
+            .unwrap();
+        assert!(
+            recorder
+                .start(bus.clone(), dir.path().join("b.jsonl"), 1024)
+                .await
+                .is_err()
+        );
+        recorder.stop().await.unwrap();
+    }
+
+    // ER.4 — Replay publishes recorded events back onto the bus
+    #[cfg(debug_assertions)]
+    #[tokio::test]
+    async fn replay_republishes_events() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let lines = [
+            serde_json::to_string(&AppEvent::ConfigUpdated).unwrap(),
+            serde_json::to_string(&AppEvent::ProvidersChanged).unwrap(),
+        ];
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let bus = TokioBroadcastBus::new(16);
+        let mut rx = bus.subscribe();
+        assert_eq!(replay_events(&bus, &path).await.unwrap(), 2);
+        assert!(matches!(rx.recv().await.unwrap(), AppEvent::ConfigUpdated));
+        assert!(matches!(
+            rx.recv().await.unwrap(),
+            AppEvent::ProvidersChanged
+        ));
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use super::{AppEvent, EventBus};
+
+/// Frames kept for `Last-Event-ID` replay, across all sessions.
+pub const DEFAULT_REPLAY_CAPACITY: usize = 512;
+
+/// One agent event as delivered on `GET /sessions/{id}/stream`.
+#[derive(Debug, Clone, Serialize)]
+pub struct StreamFrame {
+    /// Monotonic id; sent as the SSE `id` and accepted back via `Last-Event-ID`.
+    pub seq: u64,
+    /// SSE event name: `agent_started`, `agent_token`, `agent_tool_start`,
+    /// `agent_tool_result`, `agent_complete`, `agent_usage`, `agent_error` or
+    /// `approval_needed`.
+    pub event: &'static str,
+    pub session_id: String,
+    pub data: serde_json::Value,
+}
+
+impl StreamFrame {
+    pub fn is_for(&self, session_id: &str) -> bool {
+        self.session_id == session_id
+    }
+}
+
+/// SSE event name and session for the bus events that make up the agent stream.
+fn classify(event: &AppEvent) -> Option<(&'static str, String)> {
+    let (name, session_id) = match event {
+        AppEvent::AgentStarted { session_id, .. }
+        | AppEvent::ChannelAgentStarted { session_id, .. } => ("agent_started", session_id),
+        AppEvent::AgentToken { session_id, .. } => ("agent_token", session_id),
+        AppEvent::AgentToolStarted { session_id, .. } => ("agent_tool_start", session_id),
+        AppEvent::AgentToolCompleted { session_id, .. } => ("agent_tool_result", session_id),
+        AppEvent::AgentCompleted { session_id, .. }
+        | AppEvent::ChannelAgentCompleted { session_id, .. } => ("agent_complete", session_id),
+        AppEvent::AgentUsage { session_id, .. } => ("agent_usage", session_id),
+        AppEvent::AgentError {
+            session_id: Some(session_id),
+            ..
+        } => ("agent_error", session_id),
+        // Approvals raised outside a session turn have no stream to go to
+        AppEvent::ApprovalRequested {
+            session_id: Some(session_id),
+            ..
+        } => ("approval_needed", session_id),
+        _ => return None,
+    };
+    Some((name, session_id.clone()))
+}
+
+/// Payload of an externally tagged `AppEvent`, without the variant wrapper.
+fn event_data(event: &AppEvent) -> serde_json::Value {
+    match serde_json::to_value(event) {
+        Ok(serde_json::Value::Object(map)) => map.into_iter().next().map(|(_, v)| v),
+        _ => None,
+    }
+    .unwrap_or(serde_json::Value::Null)
+}
+
+struct LogInner {
+    frames: VecDeque<StreamFrame>,
+    next_seq: u64,
+}
+
+/// Numbers agent events from the bus and keeps the most recent ones so an SSE
+/// client reconnecting with `Last-Event-ID` receives what it missed.
+pub struct SessionStreamLog {
+    inner: Mutex<LogInner>,
+    live: broadcast::Sender<StreamFrame>,
+    capacity: usize,
+}
+
+impl SessionStreamLog {
+    /// Create the log and start feeding it from `bus`.
+    pub fn spawn(bus: Arc<dyn EventBus>, capacity: usize) -> Arc<Self> {
+        let (live, _) = broadcast::channel(256);
+        let log = Arc::new(Self {
+            inner: Mutex::new(LogInner {
+                frames: VecDeque::with_capacity(capacity),
+                next_seq: 1,
+            }),
+            live,
+            capacity,
+        });
+
+        let mut rx = bus.subscribe();
+        let weak = Arc::downgrade(&log);
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => match weak.upgrade() {
+                        Some(log) => log.record(&event),
+                        None => break,
+                    },
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!("Session stream log lagged, {n} events dropped");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        log
+    }
+
+    fn record(&self, event: &AppEvent) {
+        let Some((name, session_id)) = classify(event) else {
+            return;
+        };
+        let mut inner = self.inner.lock();
+        let frame = StreamFrame {
+            seq: inner.next_seq,
+            event: name,
+            session_id,
+            data: event_data(event),
+        };
+        inner.next_seq += 1;
+        // Token deltas are live-only; buffering them would push the turn's
+        // other events out of the replay window
+        if frame.event != "agent_token" {
+            if inner.frames.len() >= self.capacity {
+                inner.frames.pop_front();
+            }
+            inner.frames.push_back(frame.clone());
+        }
+        // Sent under the lock so `subscribe` sees every frame exactly once
+        let _ = self.live.send(frame);
+    }
+
+    /// Subscribe to live frames. With `after`, also returns the buffered frames
+    /// for `session_id` with a larger `seq`, oldest first.
+    pub fn subscribe(
+        &self,
+        session_id: &str,
+        after: Option<u64>,
+    ) -> (Vec<StreamFrame>, broadcast::Receiver<StreamFrame>) {
+        let inner = self.inner.lock();
+        let rx = self.live.subscribe();
+        let replay = match after {
+            Some(after) => inner
+                .frames
+                .iter()
+                .filter(|f| f.seq > after && f.is_for(session_id))
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        };
+        (replay, rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_bus::TokioBroadcastBus;
+
+    async fn settle() {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    fn started(session_id: &str) -> AppEvent {
+        AppEvent::AgentStarted {
+            session_id: session_id.into(),
+            surface: "desktop".into(),
+        }
+    }
+
+    // SS.1 — Only agent events are recorded, scoped to their session
+    #[tokio::test]
+    async fn records_session_scoped_agent_events() {
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let log = SessionStreamLog::spawn(bus.clone(), 16);
+
+        bus.publish(started("s1")).unwrap();
+        bus.publish(AppEvent::ConfigUpdated).unwrap();
+        bus.publish(started("s2")).unwrap();
+        bus.publish(AppEvent::AgentCompleted {
+            session_id: "s1".into(),
+            duration_ms: 12,
+            note: None,
+        })
+        .unwrap();
+        settle().await;
+
+        let (replay, _) = log.subscribe("s1", Some(0));
+        let names: Vec<_> = replay.iter().map(|f| f.event).collect();
+        assert_eq!(names, ["agent_started", "agent_complete"]);
+        assert_eq!(replay[0].data["surface"], "desktop");
+        assert_eq!(replay[1].data["duration_ms"], 12);
+        assert!(replay[0].seq < replay[1].seq);
+    }
+
+    // SS.4 — Approvals go only to the stream of the session that asked
+    #[tokio::test]
+    async fn approvals_scoped_to_their_session() {
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let log = SessionStreamLog::spawn(bus.clone(), 16);
+
+        for (id, session_id) in [("a1", Some("s1")), ("a2", Some("s2")), ("a3", None)] {
+            bus.publish(AppEvent::ApprovalRequested {
+                approval_id: id.into(),
+                call_id: "c1".into(),
+                tool_name: "shell".into(),
+                args_summary: "ls".into(),
+                risk_level: "medium".into(),
+                reason: "needs approval".into(),
+                timeout_secs: 60,
+                session_id: session_id.map(String::from),
+            })
+            .unwrap();
+        }
+        settle().await;
+
+        let (replay, _) = log.subscribe("s1", Some(0));
+        assert_eq!(replay.len(), 1);
+        assert_eq!(replay[0].event, "approval_needed");
+        assert_eq!(replay[0].data["approval_id"], "a1");
+    }
+
+    // SS.2 — Replay starts after Last-Event-ID and drops the oldest at capacity
+    #[tokio::test]
+    async fn replay_after_last_event_id() {
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let log = SessionStreamLog::spawn(bus.clone(), 3);
+
+        for _ in 0..4 {
+            bus.publish(started("s1")).unwrap();
+        }
+        settle().await;
+
+        let (replay, _) = log.subscribe("s1", Some(0));
+        assert_eq!(replay.iter().map(|f| f.seq).collect::<Vec<_>>(), [2, 3, 4]);
+        let (replay, _) = log.subscribe("s1", Some(3));
+        assert_eq!(replay.len(), 1);
+        assert!(log.subscribe("s1", None).0.is_empty());
+    }
+
+    // SS.3 — Token deltas reach live subscribers but are not kept for replay
+    #[tokio::test]
+    async fn token_frames_are_live_only() {
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let log = SessionStreamLog::spawn(bus.clone(), 16);
+        let (_, mut rx) = log.subscribe("s1", None);
+
+        bus.publish(AppEvent::AgentToken {
+            session_id: "s1".into(),
+            delta: "Hel".into(),
+        })
+        .unwrap();
+        bus.publish(started("s1")).unwrap();
+        settle().await;
+
+        let live = rx.try_recv().unwrap();
+        assert_eq!(live.event, "agent_token");
+        assert_eq!(live.data["delta"], "Hel");
+
+        let (replay, _) = log.subscribe("s1", Some(0));
+        let names: Vec<_> = replay.iter().map(|f| f.event).collect();
+        assert_eq!(names, ["agent_started"]);
+    }
+}
@@ -0,0 +1,435 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use arc_swap::ArcSwap;
+use axum::http::Method;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+use crate::config::AppConfig;
+use crate::{Result, ZeniiError};
+
+/// File name of the scoped token list inside the data directory.
+pub const TOKENS_FILE: &str = "gateway_tokens.json";
+
+/// Access level of a gateway token. Each scope includes the ones below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TokenScope {
+    /// List and read state.
+    Read,
+    /// Also chat, run agents, edit sessions and memory, answer approvals.
+    Agent,
+    /// Everything, including config, credentials, plugins and token management.
+    Admin,
+}
+
+impl fmt::Display for TokenScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read => write!(f, "read"),
+            Self::Agent => write!(f, "agent"),
+            Self::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl FromStr for TokenScope {
+    type Err = ZeniiError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "read" => Ok(Self::Read),
+            "agent" => Ok(Self::Agent),
+            "admin" => Ok(Self::Admin),
+            other => Err(ZeniiError::Validation(format!(
+                "unknown token scope '{other}' (expected read, agent or admin)"
+            ))),
+        }
+    }
+}
+
+impl TokenScope {
+    /// Scope a request needs.
+    ///
+    /// Reads need `read`, except credentials, the tool listing, the raw config
+    /// file and token management, which need `admin`. Writes to chat, sessions, memory,
+    /// approvals and agent runs need `agent`; every other write needs `admin`.
+    pub fn required_for(method: &Method, path: &str) -> Self {
+        let admin_only = path == "/credentials"
+            || path.starts_with("/credentials/")
+            || path == "/config/file"
+            || path == "/tools"
+            || path.starts_with("/auth/");
+        if admin_only {
+            return Self::Admin;
+        }
+        if path == "/ws/chat" {
+            return Self::Agent;
+        }
+        if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+            return Self::Read;
+        }
+        let agent = path == "/chat"
+            || path.starts_with("/chat/")
+            || path == "/agent/oneshot"
+            || path == "/sessions"
+            || path.starts_with("/sessions/")
+            || path == "/memory"
+            || path.starts_with("/memory/")
+            || path == "/wiki/query"
+            || path.starts_with("/approvals/") && path.ends_with("/respond")
+            || path.starts_with("/agents/") && path.ends_with("/cancel");
+        if agent { Self::Agent } else { Self::Admin }
+    }
+}
+
+/// A scoped token as listed by `GET /auth/tokens`. The secret is never returned
+/// after minting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct ApiToken {
+    pub id: String,
+    pub name: String,
+    pub scope: TokenScope,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredToken {
+    #[serde(flatten)]
+    info: ApiToken,
+    /// Hex SHA-256 of the secret.
+    hash: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TokensFile {
+    tokens: Vec<StoredToken>,
+}
+
+fn hash_secret(secret: &str) -> String {
+    Sha256::digest(secret.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Scoped gateway tokens, persisted as hashes in a JSON file.
+pub struct TokenStore {
+    /// `None` keeps tokens in memory only (tests).
+    path: Option<PathBuf>,
+    tokens: RwLock<Vec<StoredToken>>,
+    /// Serializes saves so the file never ends up with an older snapshot.
+    save_lock: tokio::sync::Mutex<()>,
+}
+
+impl TokenStore {
+    /// Load tokens from `path`. A missing file means no tokens.
+    pub fn open(path: &Path) -> Result<Self> {
+        let tokens = match std::fs::read_to_string(path) {
+            Ok(raw) => {
+                serde_json::from_str::<TokensFile>(&raw)
+                    .map_err(|e| {
+                        ZeniiError::Config(format!("invalid token file {}: {e}", path.display()))
+                    })?
+                    .tokens
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            path: Some(path.to_path_buf()),
+            tokens: RwLock::new(tokens),
+            save_lock: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            tokens: RwLock::new(Vec::new()),
+            save_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.read().is_empty()
+    }
+
+    /// Whether any stored token has the `admin` scope.
+    pub fn has_admin(&self) -> bool {
+        self.tokens
+            .read()
+            .iter()
+            .any(|t| t.info.scope == TokenScope::Admin)
+    }
+
+    pub fn list(&self) -> Vec<ApiToken> {
+        self.tokens.read().iter().map(|t| t.info.clone()).collect()
+    }
+
+    /// Scope of `secret`, or `None` if it matches no token.
+    pub fn authenticate(&self, secret: &str) -> Option<TokenScope> {
+        self.identify(secret).map(|p| p.scope)
+    }
+
+    /// The token `secret` belongs to, or `None` if it matches no token.
+    pub fn identify(&self, secret: &str) -> Option<Principal> {
+        let hash = hash_secret(secret);
+        self.tokens
+            .read()
+            .iter()
+            .find(|t| bool::from(t.hash.as_bytes().ct_eq(hash.as_bytes())))
+            .map(|t| Principal {
+                id: t.info.id.clone(),
+                scope: t.info.scope,
+            })
+    }
+
+    /// Create a token. Returns its listing and the secret, which is shown only once.
+    pub async fn mint(&self, name: &str, scope: TokenScope) -> Result<(ApiToken, String)> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ZeniiError::Validation("token name cannot be empty".into()));
+        }
+        let secret = format!(
+            "zk_{}{}",
+            uuid::Uuid::new_v4().simple(),
+            uuid::Uuid::new_v4().simple()
+        );
+        let info = ApiToken {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            scope,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        let _saving = self.save_lock.lock().await;
+        let snapshot = {
+            let mut tokens = self.tokens.write();
+            if tokens.iter().any(|t| t.info.name == info.name) {
+                return Err(ZeniiError::Validation(format!(
+                    "a token named '{name}' already exists"
+                )));
+            }
+            tokens.push(StoredToken {
+                info: info.clone(),
+                hash: hash_secret(&secret),
+            });
+            tokens.clone()
+        };
+        if let Err(e) = self.save(snapshot).await {
+            self.tokens.write().retain(|t| t.info.id != info.id);
+            return Err(e);
+        }
+        Ok((info, secret))
+    }
+
+    /// Delete the token with this id or name. Returns `false` if none matched.
+    pub async fn revoke(&self, id_or_name: &str) -> Result<bool> {
+        let _saving = self.save_lock.lock().await;
+        let (index, removed, snapshot) = {
+            let mut tokens = self.tokens.write();
+            let Some(index) = tokens
+                .iter()
+                .position(|t| t.info.id == id_or_name || t.info.name == id_or_name)
+            else {
+                return Ok(false);
+            };
+            let removed = tokens.remove(index);
+            (index, removed, tokens.clone())
+        };
+        if let Err(e) = self.save(snapshot).await {
+            let mut tokens = self.tokens.write();
+            let index = index.min(tokens.len());
+            tokens.insert(index, removed);
+            return Err(e);
+        }
+        Ok(true)
+    }
+
+    /// Write `tokens` to the token file on the blocking pool. Callers hold
+    /// `save_lock`.
+    async fn save(&self, tokens: Vec<StoredToken>) -> Result<()> {
+        let Some(path) = self.path.clone() else {
+            return Ok(());
+        };
+        tokio::task::spawn_blocking(move || write_tokens_file(&path, tokens))
+            .await
+            .map_err(|e| ZeniiError::Other(format!("token file write task failed: {e}")))?
+    }
+}
+
+fn write_tokens_file(path: &Path, tokens: Vec<StoredToken>) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(&TokensFile { tokens })?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&tmp, std::fs::Permissions::from_mode(0o600))?;
+    }
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Credentials accepted by the gateway: the legacy `gateway_auth_token`, which
+/// acts as an `admin` token, plus the scoped tokens in the [`TokenStore`].
+///
+/// The legacy token is read from the live config on every request, so rotating
+/// it in config.toml takes effect without a restart.
+pub struct GatewayAuth {
+    config: std::sync::Arc<ArcSwap<AppConfig>>,
+    tokens: std::sync::Arc<TokenStore>,
+}
+
+impl GatewayAuth {
+    pub fn new(
+        config: std::sync::Arc<ArcSwap<AppConfig>>,
+        tokens: std::sync::Arc<TokenStore>,
+    ) -> Self {
+        Self { config, tokens }
+    }
+
+    /// Auth is enforced once any token exists.
+    pub fn enabled(&self) -> bool {
+        self.config.load().gateway_auth_token.is_some() || !self.tokens.is_empty()
+    }
+
+    pub fn authenticate(&self, secret: &str) -> Option<TokenScope> {
+        self.identify(secret).map(|p| p.scope)
+    }
+
+    /// The caller `secret` authenticates as. The legacy config token is the
+    /// `legacy` principal; scoped tokens are identified by their id.
+    pub fn identify(&self, secret: &str) -> Option<Principal> {
+        if let Some(legacy) = &self.config.load().gateway_auth_token
+            && legacy.len() == secret.len()
+            && bool::from(legacy.as_bytes().ct_eq(secret.as_bytes()))
+        {
+            return Some(Principal {
+                id: "legacy".into(),
+                scope: TokenScope::Admin,
+            });
+        }
+        self.tokens.identify(secret)
+    }
+}
+
+/// An authenticated gateway caller, added to request extensions by the auth
+/// middleware.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    pub id: String,
+    pub scope: TokenScope,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // AUTH.1 — Minted tokens authenticate with their scope, persist and revoke
+    #[tokio::test]
+    async fn mint_authenticate_revoke() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(TOKENS_FILE);
+        let store = TokenStore::open(&path).unwrap();
+        assert!(store.is_empty());
+
+        let (info, secret) = store.mint("monitoring", TokenScope::Read).await.unwrap();
+        assert!(secret.starts_with("zk_"));
+        assert_eq!(store.authenticate(&secret), Some(TokenScope::Read));
+        assert_eq!(store.identify(&secret).unwrap().id, info.id);
+        assert_eq!(store.authenticate("zk_wrong"), None);
+        assert!(store.mint("monitoring", TokenScope::Admin).await.is_err());
+
+        // The file holds only the hash
+        let raw = std::fs::read_to_string(&path).unwrap();
+        assert!(!raw.contains(&secret));
+        let reopened = TokenStore::open(&path).unwrap();
+        assert_eq!(reopened.list(), vec![info.clone()]);
+        assert_eq!(reopened.authenticate(&secret), Some(TokenScope::Read));
+
+        assert!(reopened.revoke(&info.id).await.unwrap());
+        assert!(!reopened.revoke("monitoring").await.unwrap());
+        assert_eq!(reopened.authenticate(&secret), None);
+        assert!(TokenStore::open(&path).unwrap().is_empty());
+    }
+
+    // AUTH.2 — Route scopes: reads, agent writes, admin writes and admin-only reads
+    #[test]
+    fn required_scope_per_route() {
+        let scope = |m: Method, p: &str| TokenScope::required_for(&m, p);
+        assert_eq!(scope(Method::GET, "/sessions"), TokenScope::Read);
+        assert_eq!(scope(Method::GET, "/scheduler/jobs"), TokenScope::Read);
+        assert_eq!(scope(Method::GET, "/providers"), TokenScope::Read);
+        assert_eq!(scope(Method::POST, "/sessions"), TokenScope::Agent);
+        assert_eq!(scope(Method::DELETE, "/sessions/abc"), TokenScope::Agent);
+        assert_eq!(scope(Method::POST, "/chat"), TokenScope::Agent);
+        assert_eq!(scope(Method::GET, "/ws/chat"), TokenScope::Agent);
+        assert_eq!(
+            scope(Method::POST, "/approvals/abc/respond"),
+            TokenScope::Agent
+        );
+        assert_eq!(
+            scope(Method::DELETE, "/approvals/rules/1"),
+            TokenScope::Admin
+        );
+        assert_eq!(scope(Method::PUT, "/config"), TokenScope::Admin);
+        assert_eq!(
+            scope(Method::DELETE, "/scheduler/jobs/abc"),
+            TokenScope::Admin
+        );
+        assert_eq!(scope(Method::GET, "/credentials"), TokenScope::Admin);
+        assert_eq!(scope(Method::GET, "/config/file"), TokenScope::Admin);
+        assert_eq!(scope(Method::GET, "/tools"), TokenScope::Admin);
+        assert_eq!(scope(Method::GET, "/auth/tokens"), TokenScope::Admin);
+    }
+
+    fn config_with_token(token: Option<&str>) -> std::sync::Arc<ArcSwap<AppConfig>> {
+        std::sync::Arc::new(ArcSwap::from_pointee(AppConfig {
+            gateway_auth_token: token.map(str::to_string),
+            ..Default::default()
+        }))
+    }
+
+    // AUTH.3 — The legacy config token is an admin token
+    #[tokio::test]
+    async fn legacy_token_is_admin() {
+        let tokens = std::sync::Arc::new(TokenStore::in_memory());
+        let auth = GatewayAuth::new(config_with_token(None), tokens.clone());
+        assert!(!auth.enabled());
+
+        let (_, secret) = tokens.mint("script", TokenScope::Agent).await.unwrap();
+        assert!(auth.enabled());
+        assert_eq!(auth.authenticate(&secret), Some(TokenScope::Agent));
+
+        let auth = GatewayAuth::new(config_with_token(Some("legacy")), tokens);
+        assert_eq!(auth.authenticate("legacy"), Some(TokenScope::Admin));
+        assert_eq!(auth.authenticate("legac"), None);
+        assert_eq!(auth.identify("legacy").unwrap().id, "legacy");
+    }
+
+    // AUTH.6 — A rotated legacy token applies to the next request
+    #[test]
+    fn legacy_token_rotation_applies_live() {
+        let config = config_with_token(Some("old"));
+        let auth = GatewayAuth::new(config.clone(), std::sync::Arc::new(TokenStore::in_memory()));
+        assert_eq!(auth.authenticate("old"), Some(TokenScope::Admin));
+
+        config.store(std::sync::Arc::new(AppConfig {
+            gateway_auth_token: Some("new".into()),
+            ..Default::default()
+        }));
+        assert_eq!(auth.authenticate("old"), None);
+        assert_eq!(auth.authenticate("new"), Some(TokenScope::Admin));
+    }
+}
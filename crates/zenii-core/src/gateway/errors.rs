@@ -4,7 +4,13 @@ use axum::response::{IntoResponse, Response};
 use serde::Serialize;
 use tracing::error;
 
-use crate::ZeniiError;
+use crate::{AgentError, ZeniiError};
+
+/// Non-standard 499 "client closed request", returned for cancelled agent turns.
+const CLIENT_CLOSED_REQUEST: StatusCode = match StatusCode::from_u16(499) {
+    Ok(status) => status,
+    Err(_) => StatusCode::BAD_REQUEST,
+};
 
 #[derive(Debug, Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
@@ -55,6 +61,20 @@ fn classify_agent_error_code(msg: &str) -> &'static str {
     }
 }
 
+/// Error code for a typed agent-turn failure. Provider errors are further
+/// split by message (rate limit, timeout, ...).
+fn agent_turn_error_code(err: &AgentError) -> &'static str {
+    match err {
+        AgentError::ProviderAuth(_) => "ZENII_AGENT_AUTH",
+        AgentError::Provider(msg) => classify_agent_error_code(msg),
+        AgentError::Tool(_) => "ZENII_AGENT_TOOL_ERROR",
+        AgentError::Cancelled(_) => "ZENII_AGENT_CANCELLED",
+        AgentError::BudgetExceeded(_) => "ZENII_BUDGET_EXCEEDED",
+        AgentError::MaxIterations(_) => "ZENII_AGENT_MAX_TURNS",
+        AgentError::ContextOverflow(_) => "ZENII_AGENT_CONTEXT_LENGTH",
+    }
+}
+
 /// Map an agent sub-classified error code to a specific HTTP status.
 fn status_for_agent_code(code: &str) -> StatusCode {
     match code {
@@ -66,6 +86,9 @@ fn status_for_agent_code(code: &str) -> StatusCode {
         "ZENII_AGENT_TIMEOUT" => StatusCode::GATEWAY_TIMEOUT,
         "ZENII_AGENT_CONNECTION" => StatusCode::BAD_GATEWAY,
         "ZENII_AGENT_NOT_CONFIGURED" => StatusCode::SERVICE_UNAVAILABLE,
+        "ZENII_AGENT_TOOL_ERROR" => StatusCode::INTERNAL_SERVER_ERROR,
+        "ZENII_AGENT_CANCELLED" => CLIENT_CLOSED_REQUEST,
+        "ZENII_BUDGET_EXCEEDED" => StatusCode::UNPROCESSABLE_ENTITY,
         // "ZENII_AGENT_ERROR" and anything else
         _ => StatusCode::BAD_GATEWAY,
     }
@@ -79,6 +102,7 @@ pub fn error_code_for(err: &ZeniiError) -> &'static str {
         ZeniiError::NotFound(_) => "ZENII_NOT_FOUND",
         ZeniiError::PolicyDenied(_) => "ZENII_POLICY_DENIED",
         ZeniiError::RateLimited(_) => "ZENII_RATE_LIMITED",
+        ZeniiError::ChannelRateLimited { .. } => "ZENII_CHANNEL_RATE_LIMITED",
         ZeniiError::Auth(_) => "ZENII_AUTH_REQUIRED",
         ZeniiError::Serialization(_) => "ZENII_BAD_REQUEST",
         ZeniiError::TomlParse(_) => "ZENII_TOML_PARSE_ERROR",
@@ -86,6 +110,7 @@ pub fn error_code_for(err: &ZeniiError) -> &'static str {
         ZeniiError::Database(_) => "ZENII_DB_ERROR",
         ZeniiError::Sqlite(_) => "ZENII_SQLITE_ERROR",
         ZeniiError::Agent(msg) => classify_agent_error_code(msg),
+        ZeniiError::AgentTurn(e) => agent_turn_error_code(e),
         ZeniiError::Http(_) => "ZENII_HTTP_ERROR",
         ZeniiError::Tool(_) => "ZENII_TOOL_ERROR",
         ZeniiError::Memory(_) => "ZENII_MEMORY_ERROR",
@@ -110,8 +135,10 @@ pub fn error_code_for(err: &ZeniiError) -> &'static str {
         ZeniiError::PluginNotFound(_) => "ZENII_PLUGIN_NOT_FOUND",
         ZeniiError::Workflow(_) => "ZENII_WORKFLOW_ERROR",
         ZeniiError::ModelCapability(_) => "ZENII_MODEL_CAPABILITY",
+        ZeniiError::ProviderNotConfigured(_) => "ZENII_AGENT_NOT_CONFIGURED",
         ZeniiError::ApprovalDenied(_) => "ZENII_APPROVAL_DENIED",
         ZeniiError::ApprovalTimeout(_) => "ZENII_APPROVAL_TIMEOUT",
+        ZeniiError::BudgetExceeded(_) => "ZENII_BUDGET_EXCEEDED",
         ZeniiError::Mcp(_) => "ZENII_MCP_ERROR",
         ZeniiError::Conversion(_) => "ZENII_CONVERSION",
         ZeniiError::Other(_) => "ZENII_INTERNAL_ERROR",
@@ -125,14 +152,18 @@ fn status_code_for(err: &ZeniiError) -> StatusCode {
     match err {
         ZeniiError::NotFound(_) => StatusCode::NOT_FOUND,
         ZeniiError::PolicyDenied(_) => StatusCode::FORBIDDEN,
-        ZeniiError::RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+        ZeniiError::RateLimited(_) | ZeniiError::ChannelRateLimited { .. } => {
+            StatusCode::TOO_MANY_REQUESTS
+        }
         ZeniiError::Auth(_) => StatusCode::UNAUTHORIZED,
         ZeniiError::Serialization(_) => StatusCode::BAD_REQUEST,
         ZeniiError::TomlParse(_) => StatusCode::BAD_REQUEST,
         ZeniiError::Config(_) => StatusCode::UNPROCESSABLE_ENTITY,
         ZeniiError::Database(_) => StatusCode::SERVICE_UNAVAILABLE,
         ZeniiError::Sqlite(_) => StatusCode::SERVICE_UNAVAILABLE,
-        ZeniiError::Agent(_) => status_for_agent_code(error_code_for(err)),
+        ZeniiError::Agent(_) | ZeniiError::AgentTurn(_) => {
+            status_for_agent_code(error_code_for(err))
+        }
         ZeniiError::Http(_) => StatusCode::BAD_GATEWAY,
         ZeniiError::Tool(_) => StatusCode::INTERNAL_SERVER_ERROR,
         ZeniiError::Memory(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -157,8 +188,10 @@ fn status_code_for(err: &ZeniiError) -> StatusCode {
         ZeniiError::PluginNotFound(_) => StatusCode::NOT_FOUND,
         ZeniiError::Workflow(_) => StatusCode::INTERNAL_SERVER_ERROR,
         ZeniiError::ModelCapability(_) => StatusCode::BAD_REQUEST,
+        ZeniiError::ProviderNotConfigured(_) => StatusCode::SERVICE_UNAVAILABLE,
         ZeniiError::ApprovalDenied(_) => StatusCode::FORBIDDEN,
         ZeniiError::ApprovalTimeout(_) => StatusCode::REQUEST_TIMEOUT,
+        ZeniiError::BudgetExceeded(_) => StatusCode::UNPROCESSABLE_ENTITY,
         ZeniiError::Mcp(_) => StatusCode::INTERNAL_SERVER_ERROR,
         ZeniiError::Conversion(_) => StatusCode::UNPROCESSABLE_ENTITY,
         ZeniiError::Other(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -316,6 +349,10 @@ mod tests {
             ZeniiError::NotFound("t".into()),
             ZeniiError::PolicyDenied("t".into()),
             ZeniiError::RateLimited("t".into()),
+            ZeniiError::ChannelRateLimited {
+                message: "t".into(),
+                retry_after_secs: None,
+            },
             ZeniiError::Auth("t".into()),
             ZeniiError::Serialization(json_err),
             ZeniiError::TomlParse(toml_err),
@@ -355,6 +392,7 @@ mod tests {
             ZeniiError::ModelCapability("t".into()),
             ZeniiError::ApprovalDenied("t".into()),
             ZeniiError::ApprovalTimeout("t".into()),
+            ZeniiError::BudgetExceeded("t".into()),
             ZeniiError::Other("t".into()),
         ];
 
@@ -364,8 +402,8 @@ mod tests {
             assert!(codes.insert(code.clone()), "duplicate error code: {code}");
         }
 
-        // 35 variants tested (Http skipped because reqwest::Error can't be easily constructed)
-        assert_eq!(codes.len(), 35);
+        // 37 variants tested (Http skipped because reqwest::Error can't be easily constructed)
+        assert_eq!(codes.len(), 37);
     }
 
     #[test]
@@ -464,6 +502,61 @@ mod tests {
         assert_eq!(status, StatusCode::BAD_GATEWAY);
     }
 
+    #[test]
+    fn agent_turn_errors_map_by_kind() {
+        let cases = [
+            (
+                AgentError::ProviderAuth("t".into()),
+                "ZENII_AGENT_AUTH",
+                StatusCode::UNAUTHORIZED,
+            ),
+            (
+                AgentError::Provider("429 rate limit exceeded".into()),
+                "ZENII_AGENT_RATE_LIMIT",
+                StatusCode::TOO_MANY_REQUESTS,
+            ),
+            (
+                AgentError::Tool("t".into()),
+                "ZENII_AGENT_TOOL_ERROR",
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                AgentError::Cancelled("t".into()),
+                "ZENII_AGENT_CANCELLED",
+                CLIENT_CLOSED_REQUEST,
+            ),
+            (
+                AgentError::BudgetExceeded("t".into()),
+                "ZENII_BUDGET_EXCEEDED",
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                AgentError::MaxIterations("t".into()),
+                "ZENII_AGENT_MAX_TURNS",
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                AgentError::ContextOverflow("t".into()),
+                "ZENII_AGENT_CONTEXT_LENGTH",
+                StatusCode::BAD_REQUEST,
+            ),
+        ];
+        for (err, expected_code, expected_status) in cases {
+            let (status, code) = response_parts(err.into());
+            assert_eq!(code, expected_code);
+            assert_eq!(status, expected_status);
+        }
+        assert_eq!(CLIENT_CLOSED_REQUEST.as_u16(), 499);
+    }
+
+    #[test]
+    fn provider_not_configured_maps_to_503() {
+        let (status, code) =
+            response_parts(ZeniiError::ProviderNotConfigured("no default model".into()));
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(code, "ZENII_AGENT_NOT_CONFIGURED");
+    }
+
     #[test]
     fn error_response_has_hint() {
         // Agent("401") should produce a hint via enrich_error
@@ -10,10 +10,14 @@ use crate::gateway::state::AppState;
 ///
 /// Returns a JSON document describing Zenii's capabilities for
 /// agent-to-agent discovery per the A2A protocol spec.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/.well-known/agent.json", tag = "Discovery",
+    responses((status = 200, description = "A2A Agent Card", body = Object))
+))]
 pub async fn agent_card(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let cfg = state.config.load();
     let version = env!("CARGO_PKG_VERSION");
-    let has_auth = cfg.gateway_auth_token.is_some();
+    let has_auth = cfg.gateway_auth_token.is_some() || !state.api_tokens.is_empty();
 
     Json(serde_json::json!({
         "name": "Zenii",
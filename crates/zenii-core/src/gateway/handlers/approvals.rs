@@ -1,14 +1,22 @@
 use std::sync::Arc;
 
 use axum::Json;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use serde::Deserialize;
 
 use crate::ZeniiError;
 use crate::gateway::state::AppState;
 use crate::security::approval::{ApprovalDecision, ApprovalRule};
+use crate::security::audit::{AuditQuery, AuditRecord};
 
 /// List all persistent approval rules.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/approvals/rules", tag = "Approvals",
+    responses(
+        (status = 200, description = "Persistent approval rules", body = Object),
+        (status = 500, description = "Approval broker not initialized", body = Object),
+    )
+))]
 pub async fn list_rules(
     State(state): State<Arc<AppState>>,
 ) -> Result<Json<Vec<ApprovalRule>>, ZeniiError> {
@@ -21,6 +29,14 @@ pub async fn list_rules(
 }
 
 /// Delete a persistent approval rule by ID.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    delete, path = "/approvals/rules/{id}", tag = "Approvals",
+    params(("id" = String, Path, description = "Approval rule ID")),
+    responses(
+        (status = 200, description = "Rule deleted", body = Object),
+        (status = 404, description = "Rule not found", body = Object),
+    )
+))]
 pub async fn delete_rule(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -40,11 +56,21 @@ pub async fn delete_rule(
 }
 
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct ApprovalResponse {
     pub decision: String,
 }
 
 /// Respond to a pending approval request via REST.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/approvals/{id}/respond", tag = "Approvals",
+    params(("id" = String, Path, description = "Pending approval ID")),
+    request_body = ApprovalResponse,
+    responses(
+        (status = 200, description = "Approval resolved", body = Object),
+        (status = 404, description = "No pending approval with this ID", body = Object),
+    )
+))]
 pub async fn respond_approval(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -55,7 +81,7 @@ pub async fn respond_approval(
         .as_ref()
         .ok_or_else(|| ZeniiError::Gateway("approval broker not initialized".into()))?;
     let decision = ApprovalDecision::from_str_lossy(&body.decision);
-    let resolved = broker.resolve(&id, decision);
+    let resolved = broker.resolve(&id, decision, "http");
     if resolved {
         Ok(Json(
             serde_json::json!({"resolved": true, "decision": decision.as_str()}),
@@ -67,6 +93,30 @@ pub async fn respond_approval(
     }
 }
 
+/// GET /audit-log — recorded tool calls and approval decisions, newest first.
+/// Filters: `session_id`, `since`/`until` (RFC 3339) and `limit`.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/audit-log", tag = "Approvals",
+    params(
+        ("session_id" = Option<String>, Query, description = "Only records for this session"),
+        ("since" = Option<String>, Query, description = "RFC 3339 lower bound (inclusive)"),
+        ("until" = Option<String>, Query, description = "RFC 3339 upper bound (exclusive)"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of records (default 100)"),
+    ),
+    responses(
+        (status = 200, description = "Audit records, newest first", body = Object),
+        (status = 400, description = "Invalid time bound", body = Object),
+    )
+))]
+pub async fn audit_log(
+    State(state): State<Arc<AppState>>,
+    Query(filter): Query<AuditQuery>,
+) -> Result<Json<Vec<AuditRecord>>, ZeniiError> {
+    Ok(Json(
+        crate::security::audit::query(&state.db, &filter).await?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,4 +176,49 @@ mod tests {
         let resp = app.oneshot(req).await.unwrap();
         assert_eq!(resp.status(), StatusCode::NOT_FOUND);
     }
+
+    #[tokio::test]
+    async fn audit_log_filters_by_session_and_rejects_bad_bounds() {
+        let (_dir, state) = test_state().await;
+        crate::db::with_db(&state.db, |conn| {
+            conn.execute_batch(
+                "INSERT INTO audit_log (timestamp, kind, session_id, tool_name)
+                 VALUES ('2026-01-01T00:00:00.000Z', 'tool_start', 's1', 'shell'),
+                        ('2026-01-02T00:00:00.000Z', 'tool_start', 's2', 'file_read');",
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let resp = build_router(state.clone())
+            .oneshot(get("/audit-log?session_id=s2"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let rows: Vec<AuditRecord> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].tool_name, "file_read");
+
+        let resp = build_router(state.clone())
+            .oneshot(get("/audit-log?until=2026-01-01T12:00:00Z"))
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let rows: Vec<AuditRecord> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].session_id.as_deref(), Some("s1"));
+
+        let resp = build_router(state)
+            .oneshot(get("/audit-log?since=yesterday"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
 }
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Query, State};
+use serde::Deserialize;
+
+use crate::ZeniiError;
+use crate::gateway::state::AppState;
+use crate::security::budget::BudgetStatus;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct BudgetQuery {
+    pub session_id: Option<String>,
+}
+
+/// GET /budget — spend today, this month and (optionally) in one session
+/// against the configured `budget_*_usd` caps.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/budget", tag = "Budget",
+    params(("session_id" = Option<String>, Query, description = "Also report spend for this session")),
+    responses((status = 200, description = "Current spend vs. caps", body = BudgetStatus))
+))]
+pub async fn budget_status(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<BudgetQuery>,
+) -> Result<Json<BudgetStatus>, ZeniiError> {
+    Ok(Json(
+        state.budget.status(query.session_id.as_deref()).await?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn budget_status_reports_spend_and_caps() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        state.config.store(Arc::new(crate::config::AppConfig {
+            budget_monthly_usd: Some(20.0),
+            ..Default::default()
+        }));
+        state.budget.record(Some("s1"), 2.5).await.unwrap();
+
+        let app = crate::gateway::routes::build_router(state);
+        let req = Request::builder()
+            .uri("/budget?session_id=s1")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(resp.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let status: BudgetStatus = serde_json::from_slice(&body).unwrap();
+        assert!((status.monthly.spent_usd - 2.5).abs() < 1e-9);
+        assert_eq!(status.monthly.cap_usd, Some(20.0));
+        assert!(status.daily.cap_usd.is_none());
+        assert!((status.session.unwrap().spent_usd - 2.5).abs() < 1e-9);
+    }
+}
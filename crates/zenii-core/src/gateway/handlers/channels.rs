@@ -5,7 +5,9 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use serde::{Deserialize, Serialize};
 
+use crate::channels::allowlist::{AllowlistTarget, ChannelAllowlists};
 use crate::channels::message::ChannelMessage;
+use crate::channels::settings::ChannelSettings;
 use crate::gateway::state::AppState;
 
 #[derive(Serialize, Deserialize)]
@@ -129,17 +131,8 @@ pub async fn connect_channel(
                 &state.config.load(),
             );
 
-            // Parse allowed_chat_ids from credentials if stored
-            if let Ok(Some(ids_str)) = state
-                .credentials
-                .get("channel:telegram:allowed_chat_ids")
-                .await
-            {
-                tg_config.allowed_chat_ids = ids_str
-                    .split(',')
-                    .filter_map(|s| s.trim().parse::<i64>().ok())
-                    .collect();
-            }
+            // Share the live allowlist so runtime edits reach this channel
+            tg_config.allowed_chat_ids = state.channel_registry.allowlists().telegram_chats.clone();
 
             // The token is needed by the channel but TelegramChannel reads it
             // from credentials at connect time via the bot API.
@@ -166,9 +159,16 @@ pub async fn connect_channel(
                     )
                 })?;
 
-            Arc::new(crate::channels::slack::SlackChannel::new(
-                state.credentials.clone(),
-            ))
+            let mut slack = crate::channels::slack::SlackChannel::new(state.credentials.clone())
+                .with_allowed_channels(state.channel_registry.allowlists().slack_channels.clone())
+                .with_max_attachment_bytes(
+                    state.config.load().channel_attachment_max_mb * 1024 * 1024,
+                )
+                .with_event_bus(state.event_bus.clone());
+            if let Some(broker) = &state.approval_broker {
+                slack = slack.with_approval_broker(broker.clone());
+            }
+            Arc::new(slack)
         }
         #[cfg(feature = "channels-discord")]
         "discord" => {
@@ -184,14 +184,36 @@ pub async fn connect_channel(
                     )
                 })?;
 
-            let dc_config = crate::channels::discord::config::DiscordConfig::from_app_config(
+            let mut dc_config = crate::channels::discord::config::DiscordConfig::from_app_config(
                 &state.config.load(),
             );
+            let allowlists = state.channel_registry.allowlists();
+            dc_config.allowed_guild_ids = allowlists.discord_guilds.clone();
+            dc_config.allowed_channel_ids = allowlists.discord_channels.clone();
             Arc::new(crate::channels::discord::DiscordChannel::new(
                 dc_config,
                 state.credentials.clone(),
             ))
         }
+        #[cfg(feature = "channels-whatsapp")]
+        "whatsapp" => {
+            state
+                .credentials
+                .get("channel:whatsapp:access_token")
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .ok_or_else(|| {
+                    (
+                        StatusCode::BAD_REQUEST,
+                        "WhatsApp access token not configured".to_string(),
+                    )
+                })?;
+
+            Arc::new(
+                crate::channels::whatsapp::WhatsAppChannel::new(state.credentials.clone())
+                    .with_allowed_numbers(state.config.load().whatsapp_allowed_numbers.clone()),
+            )
+        }
         _ => {
             return Err((
                 StatusCode::BAD_REQUEST,
@@ -378,6 +400,208 @@ pub async fn webhook_message(
     Ok(StatusCode::ACCEPTED)
 }
 
+/// WhatsApp webhook verification query (`hub.*` parameters sent by Meta).
+#[cfg(feature = "channels-whatsapp")]
+#[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::IntoParams))]
+pub struct WhatsAppVerifyQuery {
+    #[serde(rename = "hub.mode")]
+    pub mode: Option<String>,
+    #[serde(rename = "hub.verify_token")]
+    pub verify_token: Option<String>,
+    #[serde(rename = "hub.challenge")]
+    pub challenge: Option<String>,
+}
+
+/// GET /channels/whatsapp/webhook -- Meta subscription handshake: echo
+/// `hub.challenge` when `hub.verify_token` matches `channel:whatsapp:verify_token`.
+#[cfg(feature = "channels-whatsapp")]
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/channels/whatsapp/webhook", tag = "Channels",
+    params(WhatsAppVerifyQuery),
+    responses(
+        (status = 200, description = "Challenge echoed", body = String),
+        (status = 403, description = "Verify token mismatch")
+    )
+))]
+pub async fn whatsapp_verify(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Query(query): axum::extract::Query<WhatsAppVerifyQuery>,
+) -> Result<String, (StatusCode, String)> {
+    use subtle::ConstantTimeEq;
+
+    let expected = state
+        .credentials
+        .get("channel:whatsapp:verify_token")
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or_else(|| {
+            (
+                StatusCode::FORBIDDEN,
+                "WhatsApp verify token not configured".to_string(),
+            )
+        })?;
+
+    let token = query.verify_token.unwrap_or_default();
+    let token_ok: bool = token.as_bytes().ct_eq(expected.as_bytes()).into();
+    if query.mode.as_deref() != Some("subscribe") || !token_ok {
+        return Err((StatusCode::FORBIDDEN, "verification failed".into()));
+    }
+    Ok(query.challenge.unwrap_or_default())
+}
+
+/// POST /channels/whatsapp/webhook -- inbound WhatsApp messages, authenticated by
+/// the `X-Hub-Signature-256` HMAC of the raw body with `channel:whatsapp:app_secret`.
+#[cfg(feature = "channels-whatsapp")]
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/channels/whatsapp/webhook", tag = "Channels",
+    request_body = String,
+    responses(
+        (status = 200, description = "Webhook processed"),
+        (status = 400, description = "Malformed payload"),
+        (status = 401, description = "Missing or invalid signature"),
+        (status = 404, description = "WhatsApp channel not connected")
+    )
+))]
+pub async fn whatsapp_webhook(
+    State(state): State<Arc<AppState>>,
+    headers: axum::http::HeaderMap,
+    body: axum::body::Bytes,
+) -> Result<StatusCode, (StatusCode, String)> {
+    use crate::channels::whatsapp::api;
+
+    let app_secret = state
+        .credentials
+        .get("channel:whatsapp:app_secret")
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or_else(|| {
+            (
+                StatusCode::UNAUTHORIZED,
+                "WhatsApp app secret not configured".to_string(),
+            )
+        })?;
+    let signature = headers
+        .get("x-hub-signature-256")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    if !api::verify_signature(&app_secret, &body, signature) {
+        return Err((StatusCode::UNAUTHORIZED, "invalid signature".into()));
+    }
+
+    if state.channel_registry.status("whatsapp").is_none() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "channel not found: whatsapp".to_string(),
+        ));
+    }
+
+    let payload: serde_json::Value = serde_json::from_slice(&body)
+        .map_err(|e| (StatusCode::BAD_REQUEST, format!("invalid payload: {e}")))?;
+
+    let allowed = state.config.load().whatsapp_allowed_numbers.clone();
+    for inbound in api::parse_webhook(&payload) {
+        if !api::is_number_allowed(&allowed, &inbound.from) {
+            tracing::debug!(
+                "WhatsApp: dropping message from disallowed number {}",
+                inbound.from
+            );
+            continue;
+        }
+
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("chat_id".to_string(), inbound.from.clone());
+        metadata.insert("message_id".to_string(), inbound.message_id);
+        if let Some(name) = inbound.profile_name {
+            metadata.insert("profile_name".to_string(), name);
+        }
+        let msg = ChannelMessage::new("whatsapp", &inbound.text)
+            .with_sender(&inbound.from)
+            .with_metadata(metadata);
+
+        #[cfg(feature = "gateway")]
+        {
+            let state_clone = state.clone();
+            tokio::spawn(async move {
+                crate::channels::router::ChannelRouter::handle_message_static(msg, &state_clone)
+                    .await;
+            });
+        }
+    }
+
+    Ok(StatusCode::OK)
+}
+
+/// POST /channels/slack/interactions -- Block Kit button clicks for apps that
+/// use a Request URL instead of Socket Mode. Authenticated by the
+/// `X-Slack-Signature` HMAC keyed with `channel:slack:signing_secret`.
+#[cfg(feature = "channels-slack")]
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/channels/slack/interactions", tag = "Channels",
+    request_body(content = String, content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Interaction processed"),
+        (status = 400, description = "Malformed payload"),
+        (status = 401, description = "Missing or invalid signature")
+    )
+))]
+pub async fn slack_interactions(
+    State(state): State<Arc<AppState>>,
+    headers: axum::http::HeaderMap,
+    body: axum::body::Bytes,
+) -> Result<StatusCode, (StatusCode, String)> {
+    use crate::channels::slack::api;
+
+    let signing_secret = state
+        .credentials
+        .get("channel:slack:signing_secret")
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or_else(|| {
+            (
+                StatusCode::UNAUTHORIZED,
+                "Slack signing secret not configured".to_string(),
+            )
+        })?;
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+    };
+    if !api::verify_signature(
+        &signing_secret,
+        header("x-slack-request-timestamp"),
+        &body,
+        header("x-slack-signature"),
+        chrono::Utc::now().timestamp(),
+    ) {
+        return Err((StatusCode::UNAUTHORIZED, "invalid signature".into()));
+    }
+
+    let payload = api::interaction_payload(&body)
+        .ok_or_else(|| (StatusCode::BAD_REQUEST, "invalid payload".to_string()))?;
+    // Other interactions (shortcuts, modals) are acknowledged and ignored
+    if let Some(action) = api::parse_approval_action(&payload)
+        && let Some(broker) = &state.approval_broker
+    {
+        let allowed = state
+            .channel_registry
+            .get_channel("slack")
+            .is_some_and(|ch| {
+                ch.may_resolve_approval(&action.approval_id, &action.user_id, &action.channel_id)
+            });
+        crate::channels::slack::resolve_approval_action(
+            broker,
+            &reqwest::Client::new(),
+            &action,
+            allowed,
+        )
+        .await;
+    }
+    Ok(StatusCode::OK)
+}
+
 /// GET /channels/:name/health -- health check
 #[cfg_attr(feature = "api-docs", utoipa::path(
     get, path = "/channels/{name}/health", tag = "Channels",
@@ -400,6 +624,180 @@ pub async fn health_check(
     Ok(Json(ChannelHealthResponse { name, healthy }))
 }
 
+/// GET /channels/:name/settings -- per-channel agent overrides
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/channels/{name}/settings", tag = "Channels",
+    params(("name" = String, Path, description = "Channel name")),
+    responses((status = 200, description = "Channel agent settings", body = ChannelSettings))
+))]
+pub async fn get_channel_settings(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> crate::Result<Json<ChannelSettings>> {
+    Ok(Json(
+        crate::channels::settings::get_channel_settings(&state.db, &name).await?,
+    ))
+}
+
+/// PUT /channels/:name/settings -- replace per-channel agent overrides
+///
+/// Applied to the next message the channel receives; omitted or blank fields
+/// fall back to the global configuration.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    put, path = "/channels/{name}/settings", tag = "Channels",
+    params(("name" = String, Path, description = "Channel name")),
+    request_body = ChannelSettings,
+    responses(
+        (status = 200, description = "Saved settings", body = ChannelSettings),
+        (status = 400, description = "Invalid model or autonomy level")
+    )
+))]
+pub async fn set_channel_settings(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Json(settings): Json<ChannelSettings>,
+) -> crate::Result<Json<ChannelSettings>> {
+    let saved = crate::channels::settings::set_channel_settings(&state.db, &name, settings).await?;
+    Ok(Json(saved))
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct AllowlistResponse {
+    pub list: String,
+    pub entries: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct AllowlistEntryRequest {
+    pub entry: String,
+}
+
+/// Persist an allowlist after a live edit: Telegram to the credential store,
+/// the rest to the config file. Caller holds `config_write_lock`.
+async fn persist_allowlist(state: &AppState, target: AllowlistTarget) -> crate::Result<()> {
+    let allowlists = state.channel_registry.allowlists();
+    if !ChannelAllowlists::is_config_backed(target) {
+        return allowlists
+            .persist_telegram(state.credentials.as_ref())
+            .await;
+    }
+    let mut config = crate::config::load_config(&state.config_path)?;
+    allowlists.write_to_config(target, &mut config);
+    crate::config::save_config(&state.config_path, &config)?;
+    state.apply_config(config);
+    Ok(())
+}
+
+fn allowlist_response(state: &AppState, target: AllowlistTarget) -> Json<AllowlistResponse> {
+    Json(AllowlistResponse {
+        list: target.as_str().to_string(),
+        entries: state.channel_registry.allowlists().list(target),
+    })
+}
+
+/// GET /channels/:name/allowlist -- entries allowed to reach the channel
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/channels/{name}/allowlist", tag = "Channels",
+    params(("name" = String, Path, description = "telegram, discord, discord-channels or slack")),
+    responses(
+        (status = 200, description = "Allowlist entries (empty = no restriction)", body = AllowlistResponse),
+        (status = 404, description = "Unknown allowlist")
+    )
+))]
+pub async fn get_allowlist(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> crate::Result<Json<AllowlistResponse>> {
+    let target = AllowlistTarget::parse(&name)?;
+    Ok(allowlist_response(&state, target))
+}
+
+/// POST /channels/:name/allowlist -- add an entry
+///
+/// Applies to the live channel at once and is persisted; Telegram and
+/// Discord ids must be numeric.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/channels/{name}/allowlist", tag = "Channels",
+    params(("name" = String, Path, description = "telegram, discord, discord-channels or slack")),
+    request_body = AllowlistEntryRequest,
+    responses(
+        (status = 200, description = "Updated allowlist", body = AllowlistResponse),
+        (status = 400, description = "Invalid id"),
+        (status = 404, description = "Unknown allowlist")
+    )
+))]
+pub async fn add_allowlist_entry(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Json(req): Json<AllowlistEntryRequest>,
+) -> crate::Result<Json<AllowlistResponse>> {
+    let target = AllowlistTarget::parse(&name)?;
+    let _lock = state.config_write_lock.lock().await;
+    let allowlists = state.channel_registry.allowlists();
+    if allowlists.add(target, &req.entry)?
+        && let Err(e) = persist_allowlist(&state, target).await
+    {
+        allowlists.remove(target, &req.entry)?;
+        return Err(e);
+    }
+    Ok(allowlist_response(&state, target))
+}
+
+/// Query params for removing an allowlist entry.
+#[derive(Deserialize)]
+pub struct RemoveAllowlistQuery {
+    /// Confirm removing the last entry, which opens the channel to everyone.
+    #[serde(default)]
+    pub allow_all: bool,
+}
+
+/// DELETE /channels/:name/allowlist/:entry -- remove an entry
+///
+/// An empty list allows everyone, so removing the last entry is refused
+/// unless `allow_all=true` is passed.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    delete, path = "/channels/{name}/allowlist/{entry}", tag = "Channels",
+    params(
+        ("name" = String, Path, description = "telegram, discord, discord-channels or slack"),
+        ("entry" = String, Path, description = "Id to remove"),
+        ("allow_all" = Option<bool>, Query, description = "Allow removing the last entry, opening the channel to everyone")
+    ),
+    responses(
+        (status = 204, description = "Entry removed"),
+        (status = 400, description = "Last entry without allow_all"),
+        (status = 404, description = "Unknown allowlist or entry")
+    )
+))]
+pub async fn remove_allowlist_entry(
+    State(state): State<Arc<AppState>>,
+    Path((name, entry)): Path<(String, String)>,
+    axum::extract::Query(query): axum::extract::Query<RemoveAllowlistQuery>,
+) -> crate::Result<StatusCode> {
+    let target = AllowlistTarget::parse(&name)?;
+    let _lock = state.config_write_lock.lock().await;
+    let allowlists = state.channel_registry.allowlists();
+    if allowlists.would_empty(target, &entry)? {
+        if !query.allow_all {
+            return Err(crate::ZeniiError::Validation(format!(
+                "'{entry}' is the last entry of the {name} allowlist; removing it allows everyone (pass allow_all=true to confirm)"
+            )));
+        }
+        tracing::warn!("Removing the last {name} allowlist entry; the channel now allows everyone");
+    }
+    if !allowlists.remove(target, &entry)? {
+        return Err(crate::ZeniiError::NotFound(format!(
+            "'{entry}' is not in the {name} allowlist"
+        )));
+    }
+    if let Err(e) = persist_allowlist(&state, target).await {
+        allowlists.add(target, &entry)?;
+        return Err(e);
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -683,4 +1081,300 @@ mod tests {
         // Missing required field 'content' causes axum JSON deserialization to return 422
         assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
     }
+
+    // CS.5 — Settings round-trip through the API
+    #[tokio::test]
+    async fn channel_settings_put_then_get() {
+        let (_dir, state) = test_state().await;
+        let app = Router::new()
+            .route(
+                "/channels/{name}/settings",
+                get(get_channel_settings).put(set_channel_settings),
+            )
+            .with_state(state);
+
+        let req = Request::builder()
+            .method("PUT")
+            .uri("/channels/telegram/settings")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::json!({"model": "openai:gpt-4o-mini", "autonomy_level": "readonly"})
+                    .to_string(),
+            ))
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let req = Request::builder()
+            .uri("/channels/telegram/settings")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), 4096).await.unwrap();
+        let settings: ChannelSettings = serde_json::from_slice(&body).unwrap();
+        assert_eq!(settings.model.as_deref(), Some("openai:gpt-4o-mini"));
+        assert_eq!(settings.autonomy_level.as_deref(), Some("readonly"));
+        assert_eq!(settings.system_prompt, None);
+    }
+
+    #[cfg(feature = "channels-slack")]
+    fn slack_interaction_request(secret: &str, approval_id: &str, user_id: &str) -> Request<Body> {
+        use hmac::{Hmac, KeyInit, Mac};
+
+        let payload = serde_json::json!({
+            "type": "block_actions",
+            "user": { "id": user_id },
+            "channel": { "id": "C42" },
+            "actions": [{
+                "action_id": crate::channels::slack::api::APPROVE_ACTION_ID,
+                "value": approval_id
+            }]
+        });
+        let body = format!("payload={}", urlencoding::encode(&payload.to_string()));
+        let ts = chrono::Utc::now().timestamp().to_string();
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(format!("v0:{ts}:{body}").as_bytes());
+        let hex: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        Request::post("/channels/slack/interactions")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .header("x-slack-request-timestamp", ts)
+            .header("x-slack-signature", format!("v0={hex}"))
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    /// Register a Slack channel that posted `approval_id` in C42 for `requester`.
+    #[cfg(feature = "channels-slack")]
+    fn register_slack_prompt(state: &AppState, approval_id: &str, requester: &str) {
+        let slack = crate::channels::slack::SlackChannel::new(state.credentials.clone());
+        slack.record_sender("C42", requester);
+        slack.track_approval_prompt(approval_id, "shell", "C42", "1.0");
+        state.channel_registry.register(Arc::new(slack)).unwrap();
+    }
+
+    // SL.1 — a signed Approve click from the requester resolves the pending approval
+    #[cfg(feature = "channels-slack")]
+    #[tokio::test]
+    async fn slack_interaction_resolves_approval() {
+        use crate::security::approval::ApprovalDecision;
+
+        let (_dir, state) = test_state().await;
+        state
+            .credentials
+            .set("channel:slack:signing_secret", "signing")
+            .await
+            .unwrap();
+        let broker = state.approval_broker.clone().unwrap();
+        let rx = broker.register("appr-1");
+        register_slack_prompt(&state, "appr-1", "U42");
+        let app = Router::new()
+            .route(
+                "/channels/slack/interactions",
+                axum::routing::post(slack_interactions),
+            )
+            .with_state(state);
+
+        let resp = app
+            .clone()
+            .oneshot(slack_interaction_request("wrong", "appr-1", "U42"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        // Someone other than the requester cannot answer
+        let resp = app
+            .clone()
+            .oneshot(slack_interaction_request("signing", "appr-1", "U7"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(broker.is_pending("appr-1"));
+
+        let resp = app
+            .oneshot(slack_interaction_request("signing", "appr-1", "U42"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resolution = rx.await.unwrap();
+        assert_eq!(resolution.decision, ApprovalDecision::Approve);
+        assert_eq!(resolution.resolved_by, "slack:U42");
+    }
+
+    #[cfg(feature = "channels-whatsapp")]
+    fn whatsapp_router(state: Arc<AppState>) -> Router {
+        Router::new()
+            .route(
+                "/channels/whatsapp/webhook",
+                get(whatsapp_verify).post(whatsapp_webhook),
+            )
+            .with_state(state)
+    }
+
+    // WA.1 — verification handshake echoes the challenge only for the stored verify token
+    #[cfg(feature = "channels-whatsapp")]
+    #[tokio::test]
+    async fn whatsapp_verify_handshake() {
+        let (_dir, state) = test_state().await;
+        state
+            .credentials
+            .set("channel:whatsapp:verify_token", "vt")
+            .await
+            .unwrap();
+
+        let req = Request::get(
+            "/channels/whatsapp/webhook?hub.mode=subscribe&hub.verify_token=vt&hub.challenge=42",
+        )
+        .body(Body::empty())
+        .unwrap();
+        let resp = whatsapp_router(state.clone()).oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"42");
+
+        let req = Request::get(
+            "/channels/whatsapp/webhook?hub.mode=subscribe&hub.verify_token=nope&hub.challenge=42",
+        )
+        .body(Body::empty())
+        .unwrap();
+        let resp = whatsapp_router(state).oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    // WA.2 — inbound webhook requires a valid X-Hub-Signature-256
+    #[cfg(feature = "channels-whatsapp")]
+    #[tokio::test]
+    async fn whatsapp_webhook_checks_signature() {
+        use hmac::{Hmac, KeyInit, Mac};
+
+        let (_dir, state) = test_state().await;
+        state
+            .credentials
+            .set("channel:whatsapp:app_secret", "app-secret")
+            .await
+            .unwrap();
+        state
+            .channel_registry
+            .register(Arc::new(
+                crate::channels::whatsapp::WhatsAppChannel::default(),
+            ))
+            .unwrap();
+
+        let payload = br#"{"entry":[{"changes":[{"field":"messages","value":{"statuses":[]}}]}]}"#;
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(b"app-secret").unwrap();
+        mac.update(payload);
+        let hex: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+
+        let req = Request::post("/channels/whatsapp/webhook")
+            .header("x-hub-signature-256", "sha256=00")
+            .body(Body::from(&payload[..]))
+            .unwrap();
+        let resp = whatsapp_router(state.clone()).oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let req = Request::post("/channels/whatsapp/webhook")
+            .header("x-hub-signature-256", format!("sha256={hex}"))
+            .body(Body::from(&payload[..]))
+            .unwrap();
+        let resp = whatsapp_router(state).oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    // CS.6 — Allowlist edits reach the live list and are persisted
+    #[tokio::test]
+    async fn allowlist_add_list_remove() {
+        let (_dir, state) = test_state().await;
+        let live = state.channel_registry.allowlists().discord_guilds.clone();
+        let app = Router::new()
+            .route(
+                "/channels/{name}/allowlist",
+                get(get_allowlist).post(add_allowlist_entry),
+            )
+            .route(
+                "/channels/{name}/allowlist/{entry}",
+                axum::routing::delete(remove_allowlist_entry),
+            )
+            .with_state(state.clone());
+
+        let add = |name: &str, entry: &str| {
+            Request::builder()
+                .method("POST")
+                .uri(format!("/channels/{name}/allowlist"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "entry": entry }).to_string(),
+                ))
+                .unwrap()
+        };
+        let resp = app.clone().oneshot(add("discord", "42")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(live.contains(&42));
+        let saved = crate::config::load_config(&state.config_path).unwrap();
+        assert_eq!(saved.discord_allowed_guild_ids, vec![42]);
+
+        let resp = app.clone().oneshot(add("telegram", "abc")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let resp = app.clone().oneshot(add("telegram", "12345")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            state
+                .credentials
+                .get(crate::channels::allowlist::TELEGRAM_ALLOWLIST_KEY)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("12345")
+        );
+
+        let req = Request::builder()
+            .uri("/channels/telegram/allowlist")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["entries"], serde_json::json!(["12345"]));
+
+        let remove = |uri: &str| {
+            Request::builder()
+                .method("DELETE")
+                .uri(uri)
+                .body(Body::empty())
+                .unwrap()
+        };
+        let resp = app
+            .clone()
+            .oneshot(remove("/channels/discord/allowlist/42"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert!(live.contains(&42));
+        let resp = app
+            .clone()
+            .oneshot(remove("/channels/discord/allowlist/42?allow_all=true"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert!(live.is_empty());
+        let saved = crate::config::load_config(&state.config_path).unwrap();
+        assert!(saved.discord_allowed_guild_ids.is_empty());
+        let resp = app
+            .oneshot(remove("/channels/discord/allowlist/42"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
 }
@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use serde::{Deserialize, Serialize};
 
 use crate::Result;
+use crate::ai::adapter::{ToolCallEvent, ToolCallPhase};
 use crate::ai::prompt::AssemblyRequest;
 use crate::ai::resolve_agent;
 use crate::event_bus::AppEvent;
@@ -18,8 +20,14 @@ pub struct ChatRequest {
     pub prompt: String,
     pub session_id: Option<String>,
     pub model: Option<String>,
+    /// With `provider`, `model` is validated and recorded as the session's override.
+    pub provider: Option<String>,
     #[serde(default)]
     pub delegation: Option<bool>,
+    /// JSON Schema the reply must conform to. Overrides the session's
+    /// `response_format` for this turn; recorded on auto-created sessions.
+    #[serde(default)]
+    pub response_format: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize)]
@@ -41,15 +49,45 @@ pub async fn chat(
     State(state): State<Arc<AppState>>,
     Json(req): Json<ChatRequest>,
 ) -> Result<impl IntoResponse> {
+    if let Some(schema) = &req.response_format {
+        crate::ai::structured::check_schema(schema)?;
+    }
+    state
+        .budget
+        .check(req.session_id.as_deref())
+        .await
+        .inspect_err(|e| {
+            let _ = state
+                .event_bus
+                .publish(AppEvent::agent_error(req.session_id.as_deref(), e));
+        })?;
+
     // Auto-create session when none provided (so CLI messages persist)
+    let mut response_format = req.response_format.clone();
     let session_id = match req.session_id.clone() {
-        Some(sid) => sid,
+        Some(sid) => {
+            if response_format.is_none() {
+                response_format = state
+                    .session_manager
+                    .get_session(&sid)
+                    .await
+                    .ok()
+                    .and_then(|s| s.response_format);
+            }
+            sid
+        }
         None => {
             let title: String = req.prompt.chars().take(50).collect();
             let session = state
                 .session_manager
                 .create_session_with_source(&title, "api")
                 .await?;
+            if let Some(schema) = &response_format {
+                state
+                    .session_manager
+                    .set_session_response_format(&session.id, Some(schema))
+                    .await?;
+            }
             let _ = state.event_bus.publish(AppEvent::SessionCreated {
                 session_id: session.id.clone(),
                 title: session.title.clone(),
@@ -59,6 +97,14 @@ pub async fn chat(
         }
     };
 
+    let requested_model = super::sessions::resolve_session_model(
+        &state,
+        Some(&session_id),
+        req.model.as_deref(),
+        req.provider.as_deref(),
+    )
+    .await?;
+
     // Build context parts via ContextBuilder
     let (history, _memories, _user_obs) = state
         .context_builder
@@ -75,7 +121,7 @@ pub async fn chat(
 
     // Assemble preamble via PromptStrategy
     let config = state.config.load_full();
-    let model_display = req.model.as_deref().unwrap_or("default");
+    let model_display = requested_model.as_deref().unwrap_or("default");
     let assembly_request = AssemblyRequest {
         boot_context: state.boot_context.clone(),
         model_display: model_display.into(),
@@ -90,13 +136,18 @@ pub async fn chat(
     let preamble = state.prompt_strategy.assemble(&assembly_request).await?;
 
     let agent = resolve_agent(
-        req.model.as_deref(),
+        requested_model.as_deref(),
         &state,
         None,
         Some(&preamble),
         "desktop",
     )
-    .await?;
+    .await
+    .inspect_err(|e| {
+        let _ = state
+            .event_bus
+            .publish(AppEvent::agent_error(Some(&session_id), e));
+    })?;
 
     // Store the user message
     if let Ok(msg) = state
@@ -114,14 +165,57 @@ pub async fn chat(
         });
     }
 
+    let _ = state.event_bus.publish(AppEvent::AgentStarted {
+        session_id: session_id.clone(),
+        surface: "desktop".into(),
+    });
+
     // Use reasoning engine for multi-turn continuity with autonomous reasoning
     let start = std::time::Instant::now();
-    let chat_result = state
-        .reasoning_engine
-        .chat(&agent, &req.prompt, history)
-        .await?;
+    let chat_result = crate::security::audit::session_scope(Some(session_id.clone()), async {
+        match &response_format {
+            Some(schema) => {
+                crate::ai::structured::chat_structured(
+                    &state.reasoning_engine,
+                    &agent,
+                    &req.prompt,
+                    history,
+                    schema,
+                )
+                .await
+            }
+            None => {
+                state
+                    .reasoning_engine
+                    .chat(&agent, &req.prompt, history)
+                    .await
+            }
+        }
+    })
+    .await
+    .inspect_err(|e| {
+        let _ = state
+            .event_bus
+            .publish(AppEvent::agent_error(Some(&session_id), e));
+        if let Some(event) =
+            AppEvent::budget_exceeded(&session_id, start.elapsed().as_millis() as u64, e)
+        {
+            let _ = state.event_bus.publish(event);
+        }
+    })?;
     let duration_ms = start.elapsed().as_millis() as u64;
     let response = chat_result.response;
+    let _ = state.event_bus.publish(AppEvent::AgentCompleted {
+        session_id: session_id.clone(),
+        duration_ms,
+        note: None,
+    });
+    let estimated_cost_usd = agent.estimate_cost(&chat_result.usage);
+    let _ = state.event_bus.publish(AppEvent::AgentUsage {
+        session_id: session_id.clone(),
+        total_tokens: chat_result.usage.reported_total(),
+        cost_usd: estimated_cost_usd,
+    });
 
     // Log usage
     let record = UsageRecord {
@@ -142,6 +236,7 @@ pub async fn chat(
         context_level: "Full".into(),
         binary: state.usage_logger.binary_name().to_string(),
         success: true,
+        estimated_cost_usd,
     };
     let logger = state.usage_logger.clone();
     tokio::spawn(async move {
@@ -153,6 +248,7 @@ pub async fn chat(
         .context_builder
         .extract_facts(&req.prompt, &response, Some(&session_id))
         .await;
+    crate::ai::memory_writeback::spawn_write_back(&state, &session_id, &req.prompt, &response);
 
     // Store the assistant response
     if let Ok(msg) = state
@@ -176,12 +272,265 @@ pub async fn chat(
     }))
 }
 
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct OneshotRequest {
+    pub prompt: String,
+    /// Replaces the assembled identity preamble for this turn.
+    pub system_prompt: Option<String>,
+    pub model: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct OneshotUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub total_tokens: u64,
+    pub estimated_cost_usd: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct OneshotToolCall {
+    pub call_id: String,
+    pub tool_name: String,
+    pub args: serde_json::Value,
+    /// `None` when the call never reported completion.
+    pub success: Option<bool>,
+    pub duration_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct OneshotResponse {
+    pub response: String,
+    pub usage: OneshotUsage,
+    pub tool_calls: Vec<OneshotToolCall>,
+}
+
+/// Fold the tool events of a finished turn into one entry per call.
+fn collect_tool_calls(
+    rx: &mut tokio::sync::broadcast::Receiver<ToolCallEvent>,
+) -> Vec<OneshotToolCall> {
+    use tokio::sync::broadcast::error::TryRecvError;
+
+    let mut calls: Vec<OneshotToolCall> = Vec::new();
+    loop {
+        let event = match rx.try_recv() {
+            Ok(event) => event,
+            Err(TryRecvError::Lagged(_)) => continue,
+            Err(_) => break,
+        };
+        let (success, duration_ms) = match event.phase {
+            ToolCallPhase::Started { args } => {
+                calls.push(OneshotToolCall {
+                    call_id: event.call_id,
+                    tool_name: event.tool_name,
+                    args,
+                    success: None,
+                    duration_ms: None,
+                });
+                continue;
+            }
+            ToolCallPhase::Completed {
+                success,
+                duration_ms,
+                ..
+            } => (success, duration_ms),
+            ToolCallPhase::Cached { success, .. } => (success, 0),
+            _ => continue,
+        };
+        match calls.iter_mut().find(|c| c.call_id == event.call_id) {
+            Some(call) => {
+                call.success = Some(success);
+                call.duration_ms = Some(duration_ms);
+            }
+            None => calls.push(OneshotToolCall {
+                call_id: event.call_id,
+                tool_name: event.tool_name,
+                args: serde_json::Value::Null,
+                success: Some(success),
+                duration_ms: Some(duration_ms),
+            }),
+        }
+    }
+    calls
+}
+
+/// Run one agent turn to completion in a throwaway session and return the
+/// full result. Nothing is persisted; the session is deleted afterwards.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/agent/oneshot", tag = "Chat",
+    request_body = OneshotRequest,
+    responses(
+        (status = 200, description = "Completed agent turn", body = OneshotResponse),
+        (status = 502, description = "Agent error", body = Object),
+        (status = 504, description = "Turn exceeded agent_oneshot_timeout_secs", body = Object),
+    )
+))]
+pub async fn oneshot(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<OneshotRequest>,
+) -> Result<impl IntoResponse> {
+    state.budget.check(None).await?;
+
+    let title: String = req.prompt.chars().take(50).collect();
+    let session = state
+        .session_manager
+        .create_session_with_source(&title, "oneshot")
+        .await?;
+    let session_id = session.id;
+
+    let result = run_oneshot(&state, &session_id, &req).await;
+
+    if let Err(e) = state.session_manager.delete_session(&session_id).await {
+        tracing::warn!("Failed to delete oneshot session {session_id}: {e}");
+    }
+    result.map(Json)
+}
+
+async fn run_oneshot(
+    state: &Arc<AppState>,
+    session_id: &str,
+    req: &OneshotRequest,
+) -> Result<OneshotResponse> {
+    let config = state.config.load_full();
+    let model_display = req.model.as_deref().unwrap_or("default");
+    let preamble = match &req.system_prompt {
+        Some(system_prompt) => system_prompt.clone(),
+        None => {
+            let assembly_request = AssemblyRequest {
+                boot_context: state.boot_context.clone(),
+                model_display: model_display.into(),
+                session_id: Some(session_id.to_string()),
+                user_message: Some(req.prompt.clone()),
+                conversation_summary: None,
+                channel_hint: None,
+                tool_count: state.tools.len(),
+                skill_count: state.skill_registry.list().await.len(),
+                version: config.identity_name.clone(),
+            };
+            state.prompt_strategy.assemble(&assembly_request).await?
+        }
+    };
+
+    let (tool_event_tx, mut tool_event_rx) = tokio::sync::broadcast::channel(256);
+    let agent = resolve_agent(
+        req.model.as_deref(),
+        state,
+        Some(tool_event_tx),
+        Some(&preamble),
+        "desktop",
+    )
+    .await
+    .inspect_err(|e| {
+        let _ = state
+            .event_bus
+            .publish(AppEvent::agent_error(Some(session_id), e));
+    })?;
+
+    let _ = state.event_bus.publish(AppEvent::AgentStarted {
+        session_id: session_id.to_string(),
+        surface: "desktop".into(),
+    });
+
+    let timeout_secs = config.agent_oneshot_timeout_secs;
+    let start = std::time::Instant::now();
+    let chat_result = match crate::security::audit::session_scope(
+        Some(session_id.to_string()),
+        tokio::time::timeout(
+            std::time::Duration::from_secs(timeout_secs),
+            state.reasoning_engine.chat(&agent, &req.prompt, vec![]),
+        ),
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(_) => Err(crate::ZeniiError::Agent(format!(
+            "oneshot agent turn timed out after {timeout_secs}s"
+        ))),
+    }
+    .inspect_err(|e| {
+        let _ = state
+            .event_bus
+            .publish(AppEvent::agent_error(Some(session_id), e));
+    })?;
+    let duration_ms = start.elapsed().as_millis() as u64;
+    let _ = state.event_bus.publish(AppEvent::AgentCompleted {
+        session_id: session_id.to_string(),
+        duration_ms,
+        note: None,
+    });
+
+    let usage = chat_result.usage;
+    let estimated_cost_usd = agent.estimate_cost(&usage);
+    let record = UsageRecord {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        session_id: None,
+        model_id: model_display.to_string(),
+        provider_id: model_display
+            .split(':')
+            .next()
+            .unwrap_or("unknown")
+            .to_string(),
+        input_tokens: usage.input_tokens,
+        output_tokens: usage.output_tokens,
+        total_tokens: usage.total_tokens,
+        cached_input_tokens: usage.cached_input_tokens,
+        tool_calls_count: agent.tool_calls_made(),
+        duration_ms,
+        context_level: "Full".into(),
+        binary: state.usage_logger.binary_name().to_string(),
+        success: true,
+        estimated_cost_usd,
+    };
+    let logger = state.usage_logger.clone();
+    tokio::spawn(async move {
+        let _ = logger.log(&record).await;
+    });
+
+    Ok(OneshotResponse {
+        response: chat_result.response,
+        usage: OneshotUsage {
+            input_tokens: usage.input_tokens,
+            output_tokens: usage.output_tokens,
+            total_tokens: usage.total_tokens,
+            estimated_cost_usd,
+        },
+        tool_calls: collect_tool_calls(&mut tool_event_rx),
+    })
+}
+
+/// Cancel a running WebSocket chat stream by the `stream_id` it was started with.
+/// The stream ends with a `cancelled` message carrying the text generated so far.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/chat/streams/{id}/cancel", tag = "Chat",
+    params(("id" = String, Path, description = "Stream ID sent with the WebSocket prompt")),
+    responses(
+        (status = 204, description = "Cancellation requested"),
+        (status = 404, description = "No running stream with this ID", body = Object),
+    )
+))]
+pub async fn cancel_stream(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode> {
+    if state.stream_cancels.cancel(&id) {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(crate::ZeniiError::NotFound(format!(
+            "stream '{id}' not found"
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use axum::Router;
     use axum::body::Body;
-    use axum::http::{Request, StatusCode};
+    use axum::http::Request;
     use axum::routing::post;
     use serde_json::json;
     use tower::ServiceExt;
@@ -229,4 +578,215 @@ mod tests {
         // so resolve_agent fails with a Credential error (500).
         assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
     }
+
+    // 3.4.2 — agent failure publishes AgentError carrying the session id
+    #[tokio::test]
+    async fn chat_agent_failure_emits_agent_error() {
+        let (_dir, state) = test_state().await;
+        let session = state.session_manager.create_session("t").await.unwrap();
+        let mut rx = state.event_bus.subscribe();
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/chat")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!({"prompt": "hello", "session_id": session.id}).to_string(),
+            ))
+            .unwrap();
+        let resp = app(state).oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let mut found = None;
+        while let Ok(event) = rx.try_recv() {
+            if let AppEvent::AgentError {
+                session_id,
+                recoverable,
+                ..
+            } = event
+            {
+                found = Some((session_id, recoverable));
+            }
+        }
+        let (session_id, recoverable) = found.expect("AgentError published");
+        assert_eq!(session_id.as_deref(), Some(session.id.as_str()));
+        // Missing API key needs user action, so a blind retry would not help
+        assert!(!recoverable);
+    }
+
+    /// Register a priced `mock:priced` model served from a local socket.
+    /// With `respond = false` the server accepts requests and never answers.
+    async fn mock_provider(state: &AppState, respond: bool) {
+        use crate::ai::test_support::{MockReply, completion, openai_mock};
+
+        let mut body = completion("Hello there.");
+        body["model"] = json!("priced");
+        body["usage"] =
+            json!({"prompt_tokens": 1000, "completion_tokens": 200, "total_tokens": 1200});
+        let url = openai_mock(move |_, _| {
+            if respond {
+                MockReply::json("200 OK", body.clone())
+            } else {
+                MockReply::Hang
+            }
+        })
+        .await;
+        state
+            .provider_registry
+            .add_user_provider("mock", "Mock", &url, false, &[])
+            .await
+            .unwrap();
+        state
+            .provider_registry
+            .add_custom_model(
+                "mock",
+                "priced",
+                "Priced",
+                true,
+                Some(crate::ai::provider_registry::ModelPricing {
+                    input_per_mtok: 3.0,
+                    output_per_mtok: 15.0,
+                }),
+            )
+            .await
+            .unwrap();
+    }
+
+    // 3.4.3 — a completed chat publishes AgentUsage with the estimated cost
+    #[tokio::test]
+    async fn chat_publishes_usage_with_cost() {
+        let (_dir, state) = test_state().await;
+        mock_provider(&state, true).await;
+
+        let session = state.session_manager.create_session("t").await.unwrap();
+        let mut rx = state.event_bus.subscribe();
+        let req = Request::builder()
+            .method("POST")
+            .uri("/chat")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!({"prompt": "hello", "session_id": session.id, "model": "mock:priced"})
+                    .to_string(),
+            ))
+            .unwrap();
+        let resp = app(state).oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let mut usage = None;
+        while let Ok(event) = rx.try_recv() {
+            if let AppEvent::AgentUsage {
+                session_id,
+                total_tokens,
+                cost_usd,
+            } = event
+            {
+                usage = Some((session_id, total_tokens, cost_usd));
+            }
+        }
+        let (session_id, total_tokens, cost_usd) = usage.expect("AgentUsage published");
+        assert_eq!(session_id, session.id);
+        assert_eq!(total_tokens, Some(1200));
+        assert!((cost_usd.unwrap() - 0.006).abs() < 1e-9);
+    }
+
+    fn oneshot_app(state: Arc<AppState>) -> Router {
+        Router::new()
+            .route("/agent/oneshot", post(oneshot))
+            .with_state(state)
+    }
+
+    fn oneshot_request(body: serde_json::Value) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri("/agent/oneshot")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    // 3.4.4 — oneshot returns the full result and leaves no session behind
+    #[tokio::test]
+    async fn oneshot_returns_result_and_drops_session() {
+        let (_dir, state) = test_state().await;
+        mock_provider(&state, true).await;
+
+        let resp = oneshot_app(state.clone())
+            .oneshot(oneshot_request(json!({
+                "prompt": "hello",
+                "system_prompt": "Be brief.",
+                "model": "mock:priced"
+            })))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["response"], "Hello there.");
+        assert_eq!(json["usage"]["total_tokens"], 1200);
+        assert_eq!(json["tool_calls"], json!([]));
+        assert!(
+            state
+                .session_manager
+                .list_sessions()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    // 3.4.5 — a turn exceeding agent_oneshot_timeout_secs returns 504
+    #[tokio::test]
+    async fn oneshot_timeout_returns_504() {
+        let (_dir, state) = test_state().await;
+        mock_provider(&state, false).await;
+        state.config.store(Arc::new(crate::config::AppConfig {
+            agent_oneshot_timeout_secs: 1,
+            ..(**state.config.load()).clone()
+        }));
+
+        let resp = oneshot_app(state.clone())
+            .oneshot(oneshot_request(
+                json!({"prompt": "hello", "model": "mock:priced"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::GATEWAY_TIMEOUT);
+        assert!(
+            state
+                .session_manager
+                .list_sessions()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    // 3.4.6 — cancelling a stream wakes it; unknown ids return 404
+    #[tokio::test]
+    async fn cancel_stream_by_id() {
+        let (_dir, state) = test_state().await;
+        let guard = state.stream_cancels.register("st-1").unwrap();
+        let app = Router::new()
+            .route("/chat/streams/{id}/cancel", post(cancel_stream))
+            .with_state(state);
+        let cancel = |id: &str| {
+            Request::builder()
+                .method("POST")
+                .uri(format!("/chat/streams/{id}/cancel"))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let resp = app.clone().oneshot(cancel("st-1")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        tokio::time::timeout(std::time::Duration::from_secs(1), guard.cancelled())
+            .await
+            .unwrap();
+
+        let resp = app.oneshot(cancel("missing")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
 }
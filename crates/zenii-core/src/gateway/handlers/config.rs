@@ -144,6 +144,12 @@ pub async fn update_config(
         if let Some(v) = obj.get("agent_system_prompt") {
             config.agent_system_prompt = v.as_str().map(|s| s.to_string());
         }
+        if let Some(v) = obj.get("agent_prompt_prefix") {
+            config.agent_prompt_prefix = v.as_str().map(|s| s.to_string());
+        }
+        if let Some(v) = obj.get("agent_prompt_suffix") {
+            config.agent_prompt_suffix = v.as_str().map(|s| s.to_string());
+        }
         if let Some(v) = obj.get("context_strategy").and_then(|v| v.as_str()) {
             // Validate: only accept known strategy values
             match v {
@@ -243,6 +249,27 @@ pub async fn update_config(
         {
             config.discord_allowed_channel_ids = v.iter().filter_map(|x| x.as_u64()).collect();
         }
+        if let Some(v) = obj
+            .get("whatsapp_allowed_numbers")
+            .and_then(|v| v.as_array())
+        {
+            config.whatsapp_allowed_numbers = v
+                .iter()
+                .filter_map(|x| x.as_str().map(|s| s.to_string()))
+                .collect();
+        }
+        if let Some(v) = obj
+            .get("channel_summarize_enabled")
+            .and_then(|v| v.as_bool())
+        {
+            config.channel_summarize_enabled = v;
+        }
+        if let Some(v) = obj
+            .get("channel_summary_keep_recent")
+            .and_then(|v| v.as_u64())
+        {
+            config.channel_summary_keep_recent = v as usize;
+        }
         // Notification routing
         if let Some(v) = obj.get("notification_routing") {
             match serde_json::from_value::<crate::notification::routing::NotificationRouting>(
@@ -333,6 +360,56 @@ pub async fn update_config(
         if let Some(v) = obj.get("agent_timeout_secs").and_then(|v| v.as_u64()) {
             config.agent_timeout_secs = v;
         }
+        if let Some(v) = obj
+            .get("agent_oneshot_timeout_secs")
+            .and_then(|v| v.as_u64())
+        {
+            config.agent_oneshot_timeout_secs = v;
+        }
+        if let Some(v) = obj
+            .get("agent_default_context_limit")
+            .and_then(|v| v.as_u64())
+        {
+            config.agent_default_context_limit = v as usize;
+        }
+        // Per-turn budgets: a number sets the limit, null clears it
+        if let Some(v) = obj.get("agent_max_total_tokens") {
+            config.agent_max_total_tokens = v.as_u64();
+        }
+        if let Some(v) = obj.get("agent_max_wall_time_secs") {
+            config.agent_max_wall_time_secs = v.as_u64();
+        }
+        // Gateway rate limits
+        if let Some(v) = obj
+            .get("gateway_rate_limit_enabled")
+            .and_then(|v| v.as_bool())
+        {
+            config.gateway_rate_limit_enabled = v;
+        }
+        for (key, field) in [
+            (
+                "gateway_rate_limit_create_per_min",
+                &mut config.gateway_rate_limit_create_per_min,
+            ),
+            (
+                "gateway_rate_limit_write_per_min",
+                &mut config.gateway_rate_limit_write_per_min,
+            ),
+            (
+                "gateway_rate_limit_read_per_min",
+                &mut config.gateway_rate_limit_read_per_min,
+            ),
+        ] {
+            if let Some(v) = obj.get(key).and_then(|v| v.as_u64()) {
+                *field = v.min(u64::from(u32::MAX)) as u32;
+            }
+        }
+        if let Some(v) = obj
+            .get("gateway_shutdown_drain_secs")
+            .and_then(|v| v.as_u64())
+        {
+            config.gateway_shutdown_drain_secs = v;
+        }
         // Tool timeout tunables
         if let Some(v) = obj.get("tool_shell_timeout_secs").and_then(|v| v.as_u64()) {
             config.tool_shell_timeout_secs = v;
@@ -340,6 +417,64 @@ pub async fn update_config(
         if let Some(v) = obj.get("web_search_timeout_secs").and_then(|v| v.as_u64()) {
             config.web_search_timeout_secs = v;
         }
+        if let Some(v) = obj.get("web_search_provider").and_then(|v| v.as_str()) {
+            config.web_search_provider = v.to_string();
+        }
+        if let Some(v) = obj.get("web_search_searxng_url") {
+            config.web_search_searxng_url = v.as_str().map(|s| s.to_string());
+        }
+        if let Some(v) = obj.get("tool_http_timeout_secs").and_then(|v| v.as_u64()) {
+            config.tool_http_timeout_secs = v;
+        }
+        if let Some(v) = obj
+            .get("tool_http_max_response_bytes")
+            .and_then(|v| v.as_u64())
+        {
+            config.tool_http_max_response_bytes = v as usize;
+        }
+        // HTTP tool host lists
+        if let Some(v) = obj
+            .get("security_http_allowed_hosts")
+            .and_then(|v| v.as_array())
+        {
+            config.security_http_allowed_hosts = v
+                .iter()
+                .filter_map(|x| x.as_str().map(|s| s.to_string()))
+                .collect();
+        }
+        if let Some(v) = obj
+            .get("security_http_denied_hosts")
+            .and_then(|v| v.as_array())
+        {
+            config.security_http_denied_hosts = v
+                .iter()
+                .filter_map(|x| x.as_str().map(|s| s.to_string()))
+                .collect();
+        }
+        if let Some(v) = obj
+            .get("security_tool_autonomy")
+            .and_then(|v| v.as_object())
+        {
+            config.security_tool_autonomy = v
+                .iter()
+                .filter_map(|(tool, level)| level.as_str().map(|l| (tool.clone(), l.to_string())))
+                .collect();
+        }
+        if let Some(v) = obj
+            .get("security_tool_timeout_secs")
+            .and_then(|v| v.as_u64())
+        {
+            config.security_tool_timeout_secs = v;
+        }
+        if let Some(v) = obj
+            .get("security_tool_timeouts")
+            .and_then(|v| v.as_object())
+        {
+            config.security_tool_timeouts = v
+                .iter()
+                .filter_map(|(tool, secs)| secs.as_u64().map(|s| (tool.clone(), s)))
+                .collect();
+        }
     }
 
     // Validate before saving — returns HTTP 400 on invalid field values
@@ -347,12 +482,7 @@ pub async fn update_config(
 
     crate::config::save_config(&state.config_path, &config)?;
 
-    // Swap the runtime config so all readers see the update immediately
-    state.config.store(Arc::new(config));
-
-    let _ = state
-        .event_bus
-        .publish(crate::event_bus::AppEvent::ConfigUpdated);
+    state.apply_config(config);
     tracing::info!("Config updated and persisted to {:?}", state.config_path);
 
     Ok((
@@ -439,6 +569,12 @@ mod tests {
             config_write_lock: tokio::sync::Mutex::new(()),
             db: base_state.db.clone(),
             event_bus: base_state.event_bus.clone(),
+            event_recorder: base_state.event_recorder.clone(),
+            oauth_states: base_state.oauth_states.clone(),
+            api_tokens: base_state.api_tokens.clone(),
+            tool_results: base_state.tool_results.clone(),
+            session_streams: base_state.session_streams.clone(),
+            activity: base_state.activity.clone(),
             memory: base_state.memory.clone(),
             credentials: base_state.credentials.clone(),
             security: base_state.security.clone(),
@@ -446,11 +582,14 @@ mod tests {
             session_manager: base_state.session_manager.clone(),
             agent: None,
             provider_registry: base_state.provider_registry.clone(),
+            provider_health: base_state.provider_health.clone(),
+            stream_cancels: base_state.stream_cancels.clone(),
             boot_context: base_state.boot_context.clone(),
             last_used_model: base_state.last_used_model.clone(),
             context_builder: base_state.context_builder.clone(),
             reasoning_engine: Arc::new(crate::ai::reasoning::ReasoningEngine::new(3)),
             prompt_strategy: base_state.prompt_strategy.clone(),
+            transcriber: base_state.transcriber.clone(),
             context_injection_enabled: base_state.context_injection_enabled.clone(),
             self_evolution_enabled: base_state.self_evolution_enabled.clone(),
             soul_loader: base_state.soul_loader.clone(),
@@ -477,6 +616,8 @@ mod tests {
             usage_logger: base_state.usage_logger.clone(),
             embedding_model_available: base_state.embedding_model_available.clone(),
             approval_broker: base_state.approval_broker.clone(),
+            audit: base_state.audit.clone(),
+            budget: base_state.budget.clone(),
             wiki: base_state.wiki.clone(),
             converter: base_state.converter.clone(),
         });
@@ -502,7 +643,9 @@ mod tests {
         let resp = app.oneshot(req).await.unwrap();
         assert_eq!(resp.status(), StatusCode::OK);
 
-        let body = axum::body::to_bytes(resp.into_body(), 8192).await.unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
         let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
         assert!(json.get("gateway_host").is_some());
         assert!(json.get("gateway_port").is_some());
@@ -521,7 +664,9 @@ mod tests {
         let resp = app.oneshot(req).await.unwrap();
         assert_eq!(resp.status(), StatusCode::OK);
 
-        let body = axum::body::to_bytes(resp.into_body(), 8192).await.unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
         let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
         assert!(
             json["gateway_auth_token"].is_null(),
@@ -812,4 +957,48 @@ mod tests {
         assert_eq!(json["status"], "persisted");
         assert_eq!(json["fields"]["log_level"], "debug");
     }
+
+    // CFG.SEC.1 — PUT /config security_tool_autonomy updates the live policy
+    #[tokio::test]
+    async fn put_config_tool_autonomy_applies_immediately() {
+        use crate::security::policy::AutonomyLevel;
+
+        let (_dir, state) = test_state().await;
+        let put = |body: serde_json::Value| {
+            Request::builder()
+                .method("PUT")
+                .uri("/config")
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+
+        let resp = app(state.clone())
+            .oneshot(put(serde_json::json!({
+                "security_tool_autonomy": {"shell": "readonly"}
+            })))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            state.security.autonomy_for("shell"),
+            AutonomyLevel::ReadOnly
+        );
+        assert_eq!(
+            state.config.load().security_tool_autonomy.get("shell"),
+            Some(&"readonly".to_string())
+        );
+
+        let resp = app(state.clone())
+            .oneshot(put(serde_json::json!({
+                "security_tool_autonomy": {"shell": "yolo"}
+            })))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            state.security.autonomy_for("shell"),
+            AutonomyLevel::ReadOnly
+        );
+    }
 }
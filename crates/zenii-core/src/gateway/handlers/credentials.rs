@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
 use axum::Json;
-use axum::extract::{Path, State};
-use axum::response::IntoResponse;
+use axum::extract::{Path, RawQuery, State};
+use axum::http::StatusCode;
+use axum::response::{Html, IntoResponse};
 use serde::{Deserialize, Serialize};
 
+use crate::credential::oauth::{builtin_endpoints, complete_flow, parse_callback_query};
+use crate::event_bus::AppEvent;
 use crate::gateway::state::AppState;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -101,6 +104,112 @@ pub async fn credential_exists(
     }))
 }
 
+/// POST /oauth/{provider}/start -- begin an OAuth authorization-code flow.
+///
+/// Requires `oauth:{provider}:client_id` and `oauth:{provider}:client_secret`
+/// credentials. Returns the URL to open in a browser.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/oauth/{provider}/start", tag = "Credentials",
+    params(("provider" = String, Path, description = "OAuth provider, e.g. slack")),
+    responses(
+        (status = 200, description = "Authorization URL and state", body = Object),
+        (status = 404, description = "Provider has no OAuth flow")
+    )
+))]
+pub async fn start_oauth(
+    State(state): State<Arc<AppState>>,
+    Path(provider): Path<String>,
+) -> crate::Result<impl IntoResponse> {
+    let endpoints = builtin_endpoints(&provider).ok_or_else(|| {
+        crate::ZeniiError::NotFound(format!("no OAuth flow for provider '{provider}'"))
+    })?;
+    let client_id = state
+        .credentials
+        .get(&endpoints.client_id_key())
+        .await?
+        .ok_or_else(|| {
+            crate::ZeniiError::Credential(format!("{} not set", endpoints.client_id_key()))
+        })?;
+
+    let config = state.config.load();
+    let redirect_uri = config.oauth_redirect_uri.clone().unwrap_or_else(|| {
+        format!(
+            "http://{}:{}/oauth/callback",
+            config.gateway_host, config.gateway_port
+        )
+    });
+    let oauth_state = state.oauth_states.begin(&provider, &redirect_uri);
+
+    Ok(Json(serde_json::json!({
+        "authorize_url": endpoints.authorize_url(&client_id, &redirect_uri, &oauth_state),
+        "state": oauth_state,
+        "redirect_uri": redirect_uri,
+    })))
+}
+
+/// GET /oauth/callback -- redirect target that completes an OAuth flow.
+///
+/// Validates `state`, exchanges `code` for tokens and stores them. Called by the
+/// user's browser, so it is exempt from bearer auth and answers with HTML.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/oauth/callback", tag = "Credentials",
+    params(
+        ("code" = String, Query, description = "Authorization code"),
+        ("state" = String, Query, description = "State issued by /oauth/{provider}/start")
+    ),
+    responses(
+        (status = 200, description = "Flow completed"),
+        (status = 400, description = "Invalid state or failed exchange")
+    )
+))]
+pub async fn oauth_callback(
+    State(state): State<Arc<AppState>>,
+    RawQuery(query): RawQuery,
+) -> impl IntoResponse {
+    let result = match parse_callback_query(query.as_deref().unwrap_or_default()) {
+        Ok(callback) => {
+            complete_flow(
+                &state.oauth_states,
+                state.credentials.as_ref(),
+                &callback,
+                builtin_endpoints,
+            )
+            .await
+        }
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(provider) => {
+            tracing::info!("OAuth flow completed for {provider}");
+            let _ = state.event_bus.publish(AppEvent::CredentialsChanged);
+            let _ = state.event_bus.publish(AppEvent::OAuthCompleted {
+                provider: provider.clone(),
+            });
+            (
+                StatusCode::OK,
+                Html(format!(
+                    "<h3>Connected to {provider}.</h3><p>You can close this window.</p>"
+                )),
+            )
+        }
+        Err(e) => {
+            tracing::warn!("OAuth callback rejected: {e}");
+            let _ = state.event_bus.publish(AppEvent::OAuthFailed {
+                provider: None,
+                error: e.to_string(),
+            });
+            (
+                StatusCode::BAD_REQUEST,
+                Html(
+                    "<h3>Authorization failed.</h3><p>Start the connection again from Zenii.</p>"
+                        .to_string(),
+                ),
+            )
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -253,4 +362,29 @@ mod tests {
         let result: CredentialExistsResponse = serde_json::from_slice(&body).unwrap();
         assert!(!result.exists);
     }
+
+    #[tokio::test]
+    async fn oauth_callback_rejects_mismatched_state() {
+        let (_dir, state) = test_state().await;
+        state
+            .oauth_states
+            .begin("slack", "http://127.0.0.1:18981/oauth/callback");
+        let mut rx = state.event_bus.subscribe();
+        let app = Router::new()
+            .route("/oauth/callback", get(oauth_callback))
+            .with_state(state.clone());
+
+        let req = Request::builder()
+            .uri("/oauth/callback?code=abc&state=forged")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            AppEvent::OAuthFailed { .. }
+        ));
+        // The legitimate pending request is untouched
+        assert_eq!(state.oauth_states.pending_count(), 1);
+    }
 }
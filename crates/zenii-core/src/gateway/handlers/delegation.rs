@@ -6,19 +6,33 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use serde::Serialize;
 
+use crate::ai::delegation::workspace_diff::WorkspaceDiff;
 use crate::gateway::state::AppState;
 use crate::{Result, ZeniiError};
 
 #[derive(Debug, Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct ActiveAgentsResponse {
     pub agents: Vec<String>,
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/agents/active", tag = "Delegation",
+    responses((status = 200, description = "IDs of running sub-agents", body = ActiveAgentsResponse))
+))]
 pub async fn list_active_agents(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let agents = state.coordinator.active_agents();
     Json(ActiveAgentsResponse { agents })
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/agents/{id}/cancel", tag = "Delegation",
+    params(("id" = String, Path, description = "Sub-agent ID")),
+    responses(
+        (status = 204, description = "Agent cancelled"),
+        (status = 404, description = "Agent not found", body = Object),
+    )
+))]
 pub async fn cancel_agent(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -30,6 +44,33 @@ pub async fn cancel_agent(
     }
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/delegations/{id}/tasks/{task_id}/diff", tag = "Delegation",
+    params(
+        ("id" = String, Path, description = "Delegation ID"),
+        ("task_id" = String, Path, description = "Task (sub-agent) ID"),
+    ),
+    responses(
+        (status = 200, description = "Files the task changed in its workspace", body = WorkspaceDiff),
+        (status = 404, description = "No workspace diff recorded for this task", body = Object),
+    )
+))]
+pub async fn get_task_diff(
+    State(state): State<Arc<AppState>>,
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<WorkspaceDiff>> {
+    state
+        .session_manager
+        .get_workspace_diff(&id, &task_id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| {
+            ZeniiError::NotFound(format!(
+                "no workspace diff for task '{task_id}' of delegation '{id}'"
+            ))
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +136,23 @@ mod tests {
         let resp = app.oneshot(req).await.unwrap();
         assert_eq!(resp.status(), StatusCode::NOT_FOUND);
     }
+
+    // 7.37
+    #[tokio::test]
+    async fn task_diff_not_found() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let app = crate::gateway::routes::build_router(state);
+
+        let req = Request::builder()
+            .uri("/delegations/d1/tasks/t1/diff")
+            .body(Body::empty())
+            .unwrap();
+
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
 }
@@ -1,5 +1,6 @@
 pub mod agent_card;
 pub mod approvals;
+pub mod budget;
 #[cfg(feature = "channels")]
 pub mod channels;
 pub mod channels_test;
@@ -22,6 +23,7 @@ pub mod sessions;
 pub mod skill_proposals;
 pub mod skills;
 pub mod system;
+pub mod tokens;
 pub mod tools;
 pub mod user;
 pub mod wiki;
@@ -107,12 +109,40 @@ pub(crate) mod tests {
             crate::wiki::WikiManager::new(wiki_dir).unwrap(),
         ));
 
+        let event_bus = Arc::new(crate::event_bus::TokioBroadcastBus::new(16));
+        let session_streams = crate::event_bus::session_stream::SessionStreamLog::spawn(
+            event_bus.clone(),
+            crate::event_bus::session_stream::DEFAULT_REPLAY_CAPACITY,
+        );
+        let activity = crate::event_bus::activity::ActivityBuffer::spawn(
+            event_bus.clone(),
+            crate::event_bus::activity::DEFAULT_ACTIVITY_CAPACITY,
+            std::time::Duration::from_secs(330),
+        );
+
+        let oauth_states = Arc::new(crate::credential::oauth::OAuthStateStore::new(
+            std::time::Duration::from_secs(config.oauth_state_ttl_secs),
+        ));
+        let config = Arc::new(arc_swap::ArcSwap::from(config));
+        let budget = Arc::new(crate::security::budget::BudgetTracker::new(
+            pool.clone(),
+            config.clone(),
+        ));
+
         let state = Arc::new(AppState {
-            config: Arc::new(arc_swap::ArcSwap::from(config)),
+            config,
             config_path: dir.path().join("config.toml"),
             config_write_lock: tokio::sync::Mutex::new(()),
             db: pool.clone(),
-            event_bus: Arc::new(crate::event_bus::TokioBroadcastBus::new(16)),
+            event_bus,
+            event_recorder: Arc::new(crate::event_bus::recorder::EventRecorder::new()),
+            oauth_states,
+            api_tokens: Arc::new(crate::gateway::auth::TokenStore::in_memory()),
+            tool_results: Arc::new(crate::ai::tool_results::ToolResultStore::new(
+                std::time::Duration::from_secs(900),
+            )),
+            session_streams,
+            activity,
             memory,
             credentials: credentials.clone(),
             security: Arc::new(SecurityPolicy::default_policy()),
@@ -120,6 +150,8 @@ pub(crate) mod tests {
             session_manager,
             agent: None,
             provider_registry,
+            provider_health: Arc::default(),
+            stream_cancels: Arc::default(),
             boot_context: crate::ai::context::BootContext::from_system(),
             last_used_model: Arc::new(RwLock::new(None)),
             context_builder,
@@ -131,6 +163,7 @@ pub(crate) mod tests {
                     crate::ai::context::BootContext::from_system(),
                 ))
             },
+            transcriber: None,
             context_injection_enabled: Arc::new(std::sync::atomic::AtomicBool::new(true)),
             self_evolution_enabled: Arc::new(std::sync::atomic::AtomicBool::new(true)),
             soul_loader,
@@ -171,6 +204,8 @@ pub(crate) mod tests {
             approval_broker: Some(Arc::new(crate::security::approval::ApprovalBroker::new(
                 pool.clone(),
             ))),
+            audit: None,
+            budget,
             wiki,
             converter: std::sync::Arc::new(crate::wiki::convert::MarkItDownConverter::new(
                 "markitdown",
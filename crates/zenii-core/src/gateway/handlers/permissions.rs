@@ -15,6 +15,7 @@ pub struct PermissionsResponse {
 }
 
 #[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct AllPermissionsResponse {
     pub surfaces: Vec<String>,
 }
@@ -25,6 +26,10 @@ pub struct SetPermissionRequest {
 }
 
 /// GET /permissions — list all known surfaces.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/permissions", tag = "Permissions",
+    responses((status = 200, description = "Known surfaces", body = AllPermissionsResponse))
+))]
 pub async fn list_surfaces(State(state): State<Arc<AppState>>) -> Json<AllPermissionsResponse> {
     let config = state.config.load();
     let mut surfaces: Vec<String> = config.tool_permissions.overrides.keys().cloned().collect();
@@ -41,6 +46,11 @@ pub async fn list_surfaces(State(state): State<Arc<AppState>>) -> Json<AllPermis
 }
 
 /// GET /permissions/{surface} — list tool permissions for a surface.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/permissions/{surface}", tag = "Permissions",
+    params(("surface" = String, Path, description = "Surface name (desktop, cli, telegram, ...)")),
+    responses((status = 200, description = "Effective tool permissions for the surface", body = Object))
+))]
 pub async fn get_permissions(
     State(state): State<Arc<AppState>>,
     Path(surface): Path<String>,
@@ -53,6 +63,18 @@ pub async fn get_permissions(
 }
 
 /// PUT /permissions/{surface}/{tool} — set a permission override.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    put, path = "/permissions/{surface}/{tool}", tag = "Permissions",
+    params(
+        ("surface" = String, Path, description = "Surface name"),
+        ("tool" = String, Path, description = "Tool name"),
+    ),
+    request_body(content = Object, description = "`{\"state\": \"allowed\" | \"denied\" | \"ask_once\" | \"ask_always\"}`"),
+    responses(
+        (status = 204, description = "Override saved"),
+        (status = 404, description = "Unknown tool"),
+    )
+))]
 pub async fn set_permission(
     State(state): State<Arc<AppState>>,
     Path((surface, tool)): Path<(String, String)>,
@@ -87,6 +109,14 @@ pub async fn set_permission(
 }
 
 /// DELETE /permissions/{surface}/{tool} — remove an override (fall back to default).
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    delete, path = "/permissions/{surface}/{tool}", tag = "Permissions",
+    params(
+        ("surface" = String, Path, description = "Surface name"),
+        ("tool" = String, Path, description = "Tool name"),
+    ),
+    responses((status = 204, description = "Override removed"))
+))]
 pub async fn delete_permission(
     State(state): State<Arc<AppState>>,
     Path((surface, tool)): Path<(String, String)>,
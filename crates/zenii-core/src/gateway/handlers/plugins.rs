@@ -9,6 +9,8 @@ use tracing::info;
 use crate::ZeniiError;
 use crate::gateway::state::AppState;
 use crate::plugins::PluginManifest;
+use crate::plugins::health::PluginHealth;
+use crate::plugins::installer::PluginReloadSummary;
 use crate::plugins::registry::InstalledPlugin;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -165,6 +167,7 @@ pub async fn toggle_plugin(
 
     if plugin.enabled {
         state.plugin_registry.disable(&name)?;
+        state.plugin_installer.deactivate(&name);
     } else {
         state.plugin_registry.enable(&name)?;
     }
@@ -173,12 +176,54 @@ pub async fn toggle_plugin(
         .plugin_registry
         .get(&name)
         .ok_or_else(|| ZeniiError::Plugin("plugin disappeared after toggle".into()))?;
+    if updated.enabled {
+        state.plugin_installer.activate(&updated).await?;
+    }
     let _ = state
         .event_bus
         .publish(crate::event_bus::AppEvent::PluginsChanged);
     Ok(Json(updated))
 }
 
+/// POST /plugins/reload — Rescan the plugins directory and swap tools into
+/// the live registry without restarting the daemon.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/plugins/reload", tag = "Plugins",
+    responses((status = 200, description = "Plugins reloaded", body = PluginReloadSummary))
+))]
+pub async fn reload_plugins(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<PluginReloadSummary>, ZeniiError> {
+    let summary = state.plugin_installer.reload().await?;
+    let _ = state
+        .event_bus
+        .publish(crate::event_bus::AppEvent::PluginsChanged);
+    Ok(Json(summary))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct PluginHealthItem {
+    name: String,
+    #[serde(flatten)]
+    health: PluginHealth,
+}
+
+/// GET /plugins/health — Health of each active plugin from the periodic ping probes.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/plugins/health", tag = "Plugins",
+    responses((status = 200, description = "Plugin health", body = Vec<PluginHealthItem>))
+))]
+pub async fn plugins_health(State(state): State<Arc<AppState>>) -> Json<Vec<PluginHealthItem>> {
+    let items = state
+        .plugin_installer
+        .health_report()
+        .into_iter()
+        .map(|(name, health)| PluginHealthItem { name, health })
+        .collect();
+    Json(items)
+}
+
 /// POST /plugins/{name}/update — Update plugin to latest.
 #[cfg_attr(feature = "api-docs", utoipa::path(
     post, path = "/plugins/{name}/update", tag = "Plugins",
@@ -405,6 +450,24 @@ mod tests {
         assert!(plugins.is_empty());
     }
 
+    // PH.6 — GET /plugins/health is routed ahead of /plugins/{name}
+    #[tokio::test]
+    async fn get_plugins_health_empty() {
+        let (_dir, app) = test_app().await;
+        let req = Request::builder()
+            .uri("/plugins/health")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let items: Vec<PluginHealthItem> = serde_json::from_slice(&body).unwrap();
+        assert!(items.is_empty());
+    }
+
     // 9.0.21 — Gateway POST /plugins/install (local)
     #[tokio::test]
     async fn install_plugin_local() {
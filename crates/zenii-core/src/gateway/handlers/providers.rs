@@ -1,10 +1,15 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use axum::Json;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::response::IntoResponse;
 use serde::{Deserialize, Serialize};
 
+use crate::ai::provider_health::{ProbeOutcome, ProviderHealth};
+use crate::ai::provider_registry::{
+    ModelModality, ModelPricing, ProviderExtras, secret_header_key,
+};
 use crate::gateway::state::AppState;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,6 +19,9 @@ pub struct CreateProviderRequest {
     pub name: String,
     pub base_url: String,
     pub requires_api_key: bool,
+    /// Whether the provider serves an OpenAI-compatible `/embeddings` endpoint.
+    #[serde(default)]
+    pub supports_embeddings: bool,
     #[serde(default)]
     pub models: Vec<CreateModelEntry>,
 }
@@ -34,7 +42,18 @@ pub struct CreateModelEntry {
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct UpdateProviderRequest {
-    pub base_url: String,
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Replaces the plain extra headers sent with every request.
+    #[serde(default)]
+    pub extra_headers: Option<HashMap<String, String>>,
+    /// Replaces the extra query parameters, e.g. `{"api-version": "2024-10-21"}`.
+    #[serde(default)]
+    pub extra_query: Option<HashMap<String, String>>,
+    /// Replaces the secret headers, whose values go to the credential store.
+    /// An empty value keeps the stored one; omitted names are removed.
+    #[serde(default)]
+    pub secret_headers: Option<HashMap<String, String>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -44,6 +63,15 @@ pub struct AddModelRequest {
     pub display_name: String,
     #[serde(default = "default_true")]
     pub supports_tools: bool,
+    /// USD per million input tokens; cost estimates need both rates.
+    #[serde(default)]
+    pub input_cost_per_mtok: Option<f64>,
+    /// USD per million output tokens.
+    #[serde(default)]
+    pub output_cost_per_mtok: Option<f64>,
+    /// Input kinds beyond text the model accepts, e.g. `["image"]`.
+    #[serde(default)]
+    pub input_modalities: Vec<ModelModality>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -118,6 +146,55 @@ pub async fn set_default_model(
     Ok(Json(serde_json::json!({ "ok": true })))
 }
 
+/// POST /providers/reload -- re-read provider config and API keys, then re-resolve
+/// the active model so running subsystems pick up out-of-band key changes.
+///
+/// A remembered `last_used_model` that no longer resolves is dropped in favour of
+/// the default model. Publishes `ProviderReloaded` either way.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/providers/reload", tag = "Providers",
+    responses((status = 200, description = "Providers reloaded", body = Object))
+))]
+pub async fn reload_providers(
+    State(state): State<Arc<AppState>>,
+) -> crate::Result<impl IntoResponse> {
+    state.provider_registry.seed_builtin_providers().await?;
+    // Keys or endpoints may have changed; the next test should be live.
+    state.provider_health.clear();
+
+    let mut result = crate::ai::resolve_agent(None, &state, None, None, "reload").await;
+    if result.is_err() && state.last_used_model.read().await.is_some() {
+        *state.last_used_model.write().await = None;
+        result = crate::ai::resolve_agent(None, &state, None, None, "reload").await;
+    }
+
+    let active_model = match state.last_used_model.read().await.clone() {
+        Some(model) => Some(model),
+        None => state
+            .provider_registry
+            .get_default_model()
+            .await?
+            .map(|(pid, mid)| format!("{pid}:{mid}")),
+    };
+    let error = result.as_ref().err().map(|e| e.to_string());
+    let ready = result.is_ok();
+    if let Some(ref e) = error {
+        tracing::info!("Provider reload: no usable provider ({e})");
+    }
+
+    let _ = state
+        .event_bus
+        .publish(crate::event_bus::AppEvent::ProviderReloaded {
+            active_model: active_model.clone().filter(|_| ready),
+        });
+
+    Ok(Json(serde_json::json!({
+        "ready": ready,
+        "active_model": active_model,
+        "error": error,
+    })))
+}
+
 /// GET /providers/{id} -- get a specific provider by ID.
 #[cfg_attr(feature = "api-docs", utoipa::path(
     get, path = "/providers/{id}", tag = "Providers",
@@ -158,6 +235,12 @@ pub async fn create_user_provider(
             &models,
         )
         .await?;
+    if req.supports_embeddings {
+        state
+            .provider_registry
+            .set_supports_embeddings(&req.id, true)
+            .await?;
+    }
     let _ = state
         .event_bus
         .publish(crate::event_bus::AppEvent::ProvidersChanged);
@@ -165,7 +248,48 @@ pub async fn create_user_provider(
     Ok(Json(serde_json::json!({ "ok": true })))
 }
 
-/// PUT /providers/{id} -- update provider base_url.
+/// Apply keyring writes (`None` deletes) in order, returning the previous
+/// values so the caller can undo them. Stops at the first failure after
+/// undoing the writes already made.
+async fn write_secrets(
+    credentials: &dyn crate::credential::CredentialStore,
+    writes: &[(String, Option<String>)],
+) -> crate::Result<Vec<(String, Option<String>)>> {
+    let mut previous = Vec::with_capacity(writes.len());
+    for (key, value) in writes {
+        let result = async {
+            let old = credentials.get(key).await?;
+            match value {
+                Some(value) => credentials.set(key, value).await?,
+                None => {
+                    credentials.delete(key).await?;
+                }
+            }
+            Ok::<_, crate::ZeniiError>(old)
+        }
+        .await;
+        match result {
+            Ok(old) => previous.push((key.clone(), old)),
+            Err(e) => {
+                for (key, old) in previous.iter().rev() {
+                    match old {
+                        Some(old) => {
+                            let _ = credentials.set(key, old).await;
+                        }
+                        None => {
+                            let _ = credentials.delete(key).await;
+                        }
+                    }
+                }
+                return Err(e);
+            }
+        }
+    }
+    previous.reverse();
+    Ok(previous)
+}
+
+/// PUT /providers/{id} -- update provider base_url and extra headers/query.
 #[cfg_attr(feature = "api-docs", utoipa::path(
     put, path = "/providers/{id}", tag = "Providers",
     params(("id" = String, Path, description = "Provider ID")),
@@ -177,10 +301,74 @@ pub async fn update_provider(
     Path(id): Path<String>,
     Json(req): Json<UpdateProviderRequest>,
 ) -> crate::Result<impl IntoResponse> {
-    state
-        .provider_registry
-        .update_provider(&id, &req.base_url)
-        .await?;
+    let current = state.provider_registry.get_provider(&id).await?.provider;
+
+    // Validate everything before touching the keyring, so a rejected request
+    // leaves both the keyring and the database as they were.
+    let mut extras = None;
+    let mut secret_writes: Vec<(String, Option<String>)> = Vec::new();
+    if req.extra_headers.is_some() || req.extra_query.is_some() || req.secret_headers.is_some() {
+        let mut updated = ProviderExtras {
+            extra_headers: req
+                .extra_headers
+                .unwrap_or_else(|| current.extras.extra_headers.clone()),
+            extra_query: req
+                .extra_query
+                .unwrap_or_else(|| current.extras.extra_query.clone()),
+            secret_headers: current.extras.secret_headers.clone(),
+        };
+        if let Some(secrets) = &req.secret_headers {
+            updated.secret_headers = secrets.keys().cloned().collect();
+            updated.secret_headers.sort();
+        }
+        updated.validate()?;
+
+        if let Some(secrets) = req.secret_headers {
+            for (name, value) in &secrets {
+                let key = secret_header_key(&id, name);
+                if !value.is_empty() {
+                    secret_writes.push((key, Some(value.clone())));
+                } else if state.credentials.get(&key).await?.is_none() {
+                    return Err(crate::ZeniiError::Validation(format!(
+                        "secret header '{name}' has no stored value"
+                    )));
+                }
+            }
+            for name in &current.extras.secret_headers {
+                if !secrets.keys().any(|kept| kept.eq_ignore_ascii_case(name)) {
+                    secret_writes.push((secret_header_key(&id, name), None));
+                }
+            }
+        }
+        extras = Some(updated);
+    }
+
+    let previous = write_secrets(state.credentials.as_ref(), &secret_writes).await?;
+    let saved = async {
+        if let Some(extras) = &extras {
+            state.provider_registry.set_extras(&id, extras).await?;
+        }
+        if let Some(base_url) = &req.base_url {
+            state
+                .provider_registry
+                .update_provider(&id, base_url)
+                .await?;
+        }
+        Ok::<_, crate::ZeniiError>(())
+    }
+    .await;
+    if let Err(e) = saved {
+        if extras.is_some() {
+            let _ = state
+                .provider_registry
+                .set_extras(&id, &current.extras)
+                .await;
+        }
+        let _ = write_secrets(state.credentials.as_ref(), &previous).await;
+        return Err(e);
+    }
+
+    state.provider_health.invalidate(&id);
     let _ = state
         .event_bus
         .publish(crate::event_bus::AppEvent::ProvidersChanged);
@@ -197,7 +385,18 @@ pub async fn delete_user_provider(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
 ) -> crate::Result<impl IntoResponse> {
+    let secret_headers = match state.provider_registry.get_provider(&id).await {
+        Ok(p) => p.provider.extras.secret_headers,
+        Err(_) => Vec::new(),
+    };
     state.provider_registry.delete_user_provider(&id).await?;
+    for name in &secret_headers {
+        let _ = state
+            .credentials
+            .delete(&secret_header_key(&id, name))
+            .await;
+    }
+    state.provider_health.invalidate(&id);
     let _ = state
         .event_bus
         .publish(crate::event_bus::AppEvent::ProvidersChanged);
@@ -216,6 +415,18 @@ pub async fn add_model(
     Path(provider_id): Path<String>,
     Json(req): Json<AddModelRequest>,
 ) -> crate::Result<impl IntoResponse> {
+    let pricing = match (req.input_cost_per_mtok, req.output_cost_per_mtok) {
+        (Some(input_per_mtok), Some(output_per_mtok)) => Some(ModelPricing {
+            input_per_mtok,
+            output_per_mtok,
+        }),
+        (None, None) => None,
+        _ => {
+            return Err(crate::ZeniiError::Validation(
+                "input_cost_per_mtok and output_cost_per_mtok must be set together".into(),
+            ));
+        }
+    };
     state
         .provider_registry
         .add_custom_model(
@@ -223,24 +434,48 @@ pub async fn add_model(
             &req.model_id,
             &req.display_name,
             req.supports_tools,
+            pricing,
         )
         .await?;
+    if !req.input_modalities.is_empty() {
+        state
+            .provider_registry
+            .set_input_modalities(
+                &format!("{provider_id}:{}", req.model_id),
+                &req.input_modalities,
+            )
+            .await?;
+    }
     let _ = state
         .event_bus
         .publish(crate::event_bus::AppEvent::ProvidersChanged);
     Ok(Json(serde_json::json!({ "ok": true })))
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct TestConnectionQuery {
+    /// Skip the cached result and make a live request.
+    #[serde(default)]
+    pub force: bool,
+}
+
 /// POST /providers/{id}/test -- test connection to a provider.
+///
+/// Results are cached per provider for `provider_health_ttl_secs`; concurrent
+/// tests of one provider share a single request.
 #[cfg_attr(feature = "api-docs", utoipa::path(
     post, path = "/providers/{id}/test", tag = "Providers",
-    params(("id" = String, Path, description = "Provider ID")),
-    responses((status = 200, description = "Connection test result", body = Object))
+    params(
+        ("id" = String, Path, description = "Provider ID"),
+        ("force" = Option<bool>, Query, description = "Bypass the cached result"),
+    ),
+    responses((status = 200, description = "Connection test result", body = ProviderHealth))
 ))]
 pub async fn test_connection(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
-) -> crate::Result<impl IntoResponse> {
+    Query(query): Query<TestConnectionQuery>,
+) -> crate::Result<Json<ProviderHealth>> {
     let provider_with_models = state.provider_registry.get_provider(&id).await?;
     let provider = &provider_with_models.provider;
 
@@ -251,9 +486,14 @@ pub async fn test_connection(
     )
     .await?;
 
-    let url = format!("{}/models", provider.base_url.trim_end_matches('/'));
+    let extras = crate::ai::providers::resolve_request_extras(
+        &id,
+        &provider.extras,
+        state.credentials.as_ref(),
+    )
+    .await?;
 
-    let start = std::time::Instant::now();
+    let url = format!("{}/models", provider.base_url.trim_end_matches('/'));
     let client = reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(10))
         .build()
@@ -270,32 +510,40 @@ pub async fn test_connection(
             request = request.header("Authorization", format!("Bearer {api_key}"));
         }
     }
-
-    let resp = request.send().await;
-
-    let latency_ms = start.elapsed().as_millis() as u64;
-
-    match resp {
-        Ok(r) if r.status().is_success() => Ok(Json(serde_json::json!({
-            "success": true,
-            "message": "Connected successfully",
-            "latency_ms": latency_ms,
-        }))),
-        Ok(r) => {
-            let status = r.status().as_u16();
-            let body = r.text().await.unwrap_or_default();
-            Ok(Json(serde_json::json!({
-                "success": false,
-                "message": format!("HTTP {status}: {body}"),
-                "latency_ms": latency_ms,
-            })))
-        }
-        Err(e) => Ok(Json(serde_json::json!({
-            "success": false,
-            "message": format!("Connection failed: {e}"),
-            "latency_ms": latency_ms,
-        }))),
-    }
+    let request = extras.apply_to_request(request);
+
+    let ttl = std::time::Duration::from_secs(state.config.load().provider_health_ttl_secs);
+    let health = state
+        .provider_health
+        .check(&id, ttl, query.force, || async move {
+            let start = std::time::Instant::now();
+            let resp = request.send().await;
+            let latency_ms = start.elapsed().as_millis() as u64;
+
+            match resp {
+                Ok(r) if r.status().is_success() => ProbeOutcome {
+                    success: true,
+                    message: "Connected successfully".into(),
+                    latency_ms,
+                },
+                Ok(r) => {
+                    let status = r.status().as_u16();
+                    let body = r.text().await.unwrap_or_default();
+                    ProbeOutcome {
+                        success: false,
+                        message: format!("HTTP {status}: {body}"),
+                        latency_ms,
+                    }
+                }
+                Err(e) => ProbeOutcome {
+                    success: false,
+                    message: format!("Connection failed: {e}"),
+                    latency_ms,
+                },
+            }
+        })
+        .await;
+    Ok(Json(health))
 }
 
 /// DELETE /providers/{id}/models/{model_id} -- delete a custom model.
@@ -350,11 +598,66 @@ mod tests {
                     .put(update_provider)
                     .delete(delete_user_provider),
             )
+            .route("/providers/reload", post(reload_providers))
             .route("/providers/{id}/models", post(add_model))
             .route("/providers/{id}/models/{model_id}", delete(delete_model))
             .with_state(state)
     }
 
+    // PR.1 — reload after adding a key makes the active provider resolve, no restart
+    #[tokio::test]
+    async fn reload_after_adding_key_resolves_provider() {
+        let (_dir, state) = test_state().await;
+        let mut events = state.event_bus.subscribe();
+
+        let reload = || {
+            app(state.clone()).oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/providers/reload")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+        };
+
+        let resp = reload().await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), 4096).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["ready"], false);
+        assert!(json["error"].is_string());
+
+        // Key added behind the gateway's back (e.g. directly in the keyring)
+        state
+            .credentials
+            .set("api_key:anthropic", "sk-ant-test")
+            .await
+            .unwrap();
+
+        let resp = reload().await.unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), 4096).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["ready"], true);
+        assert_eq!(json["active_model"], "anthropic:claude-sonnet-4-6");
+        assert!(
+            crate::ai::resolve_agent(None, &state, None, None, "desktop")
+                .await
+                .is_ok()
+        );
+
+        // First reload reports no model, second the resolved one
+        let mut reloaded = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            if let crate::event_bus::AppEvent::ProviderReloaded { active_model } = event {
+                reloaded.push(active_model);
+            }
+        }
+        assert_eq!(
+            reloaded,
+            vec![None, Some("anthropic:claude-sonnet-4-6".to_string())]
+        );
+    }
+
     #[tokio::test]
     async fn list_providers_test() {
         let (_dir, state) = test_state().await;
@@ -430,6 +733,7 @@ mod tests {
                     name: "My API".into(),
                     base_url: "https://my.api/v1".into(),
                     requires_api_key: true,
+                    supports_embeddings: true,
                     models: vec![CreateModelEntry {
                         model_id: "model-1".into(),
                         display_name: "Model 1".into(),
@@ -451,6 +755,7 @@ mod tests {
             .unwrap();
         assert_eq!(p.provider.name, "My API");
         assert!(p.provider.is_user_defined);
+        assert!(p.provider.supports_embeddings);
     }
 
     #[tokio::test]
@@ -473,6 +778,104 @@ mod tests {
         assert_eq!(resp.status(), StatusCode::OK);
     }
 
+    // PR.2 — Extras are stored per provider; secret header values only in the keyring
+    #[tokio::test]
+    async fn update_provider_extras() {
+        let (_dir, state) = test_state().await;
+        let put = |body: serde_json::Value| {
+            app(state.clone()).oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/providers/openai")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+        };
+
+        let resp = put(serde_json::json!({
+            "extra_headers": { "X-Org-Id": "acme" },
+            "extra_query": { "api-version": "2024-10-21" },
+            "secret_headers": { "X-Proxy-Token": "s3cret" },
+        }))
+        .await
+        .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let key = secret_header_key("openai", "X-Proxy-Token");
+        assert_eq!(
+            state.credentials.get(&key).await.unwrap().as_deref(),
+            Some("s3cret")
+        );
+        let provider = state
+            .provider_registry
+            .get_provider("openai")
+            .await
+            .unwrap()
+            .provider;
+        assert_eq!(provider.extras.extra_headers["X-Org-Id"], "acme");
+        assert_eq!(provider.extras.extra_query["api-version"], "2024-10-21");
+        assert_eq!(provider.extras.secret_headers, vec!["X-Proxy-Token"]);
+        assert!(!serde_json::to_string(&provider).unwrap().contains("s3cret"));
+
+        // Base URL alone leaves the extras; dropping a secret deletes its value
+        let resp = put(serde_json::json!({ "base_url": "https://proxy.test/v1" }))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = put(serde_json::json!({ "secret_headers": {} }))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let provider = state
+            .provider_registry
+            .get_provider("openai")
+            .await
+            .unwrap()
+            .provider;
+        assert_eq!(provider.base_url, "https://proxy.test/v1");
+        assert_eq!(provider.extras.extra_query["api-version"], "2024-10-21");
+        assert!(provider.extras.secret_headers.is_empty());
+        assert!(state.credentials.get(&key).await.unwrap().is_none());
+
+        let resp = put(serde_json::json!({ "extra_headers": { "bad header": "x" } }))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    // PR.3 — A failed database update undoes the keyring writes
+    #[tokio::test]
+    async fn update_provider_restores_secrets_on_db_failure() {
+        let (_dir, state) = test_state().await;
+        let key = secret_header_key("openai", "X-Proxy-Token");
+        state.credentials.set(&key, "old").await.unwrap();
+        crate::db::with_db(&state.db, |conn| {
+            conn.execute_batch(
+                "CREATE TRIGGER fail_extras BEFORE UPDATE OF extras ON ai_providers
+                 BEGIN SELECT RAISE(ABORT, 'extras update failed'); END;",
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let req = Request::builder()
+            .method("PUT")
+            .uri("/providers/openai")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::json!({ "secret_headers": { "X-Proxy-Token": "new" } }).to_string(),
+            ))
+            .unwrap();
+        let resp = app(state.clone()).oneshot(req).await.unwrap();
+        assert!(resp.status().is_server_error());
+        assert_eq!(
+            state.credentials.get(&key).await.unwrap().as_deref(),
+            Some("old")
+        );
+    }
+
     #[tokio::test]
     async fn delete_builtin_rejected() {
         let (_dir, state) = test_state().await;
@@ -534,4 +937,46 @@ mod tests {
         assert_eq!(result["provider_id"], "openai");
         assert_eq!(result["model_id"], "gpt-4o");
     }
+
+    #[tokio::test]
+    async fn add_model_with_pricing() {
+        let (_dir, state) = test_state().await;
+        let post = |body: serde_json::Value| {
+            app(state.clone()).oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/providers/openai/models")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+        };
+
+        let resp = post(serde_json::json!({
+            "model_id": "half-priced",
+            "display_name": "Half",
+            "input_cost_per_mtok": 1.0
+        }))
+        .await
+        .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let resp = post(serde_json::json!({
+            "model_id": "priced",
+            "display_name": "Priced",
+            "input_cost_per_mtok": 1.0,
+            "output_cost_per_mtok": 4.0
+        }))
+        .await
+        .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let info = state
+            .provider_registry
+            .get_model_info("openai", "priced")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.output_cost_per_mtok, Some(4.0));
+    }
 }
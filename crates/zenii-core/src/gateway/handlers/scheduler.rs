@@ -1,13 +1,15 @@
 use std::sync::Arc;
 
 use axum::Json;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use serde::{Deserialize, Serialize};
 
 use crate::ZeniiError;
 use crate::gateway::state::AppState;
-use crate::scheduler::traits::{JobExecution, ScheduledJob, Scheduler};
+use crate::scheduler::traits::{
+    ImportConflict, ImportReport, JobExecution, JobsExport, ScheduledJob, Scheduler,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
@@ -36,6 +38,22 @@ pub struct ToggleResponse {
     pub enabled: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct CancelJobResponse {
+    pub id: String,
+    /// `false` when the job was not running; nothing was changed.
+    pub cancelled: bool,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::IntoParams))]
+pub struct ImportQuery {
+    /// What to do with jobs whose name already exists (default: skip).
+    #[serde(default)]
+    pub on_conflict: ImportConflict,
+}
+
 /// GET /scheduler/jobs
 #[cfg_attr(feature = "api-docs", utoipa::path(
     get, path = "/scheduler/jobs", tag = "Scheduler",
@@ -97,6 +115,28 @@ pub async fn toggle_job(
     Ok(Json(ToggleResponse { id, enabled }))
 }
 
+/// POST /scheduler/jobs/:id/cancel -- stop the job's in-flight run. The run is
+/// recorded as `cancelled`; the job stays enabled for its next regular run.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/scheduler/jobs/{id}/cancel", tag = "Scheduler",
+    params(("id" = String, Path, description = "Job ID")),
+    responses(
+        (status = 200, description = "Cancel requested, or the job was not running", body = CancelJobResponse),
+        (status = 404, description = "Job not found")
+    )
+))]
+pub async fn cancel_job(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<CancelJobResponse>, ZeniiError> {
+    let scheduler = state
+        .scheduler
+        .as_ref()
+        .ok_or_else(|| ZeniiError::Scheduler("scheduler not initialized".into()))?;
+    let cancelled = scheduler.cancel_running(&id)?;
+    Ok(Json(CancelJobResponse { id, cancelled }))
+}
+
 /// PUT /scheduler/jobs/:id
 #[cfg_attr(feature = "api-docs", utoipa::path(
     put, path = "/scheduler/jobs/{id}", tag = "Scheduler",
@@ -162,6 +202,49 @@ pub async fn job_history(
     Ok(Json(history))
 }
 
+/// GET /scheduler/export
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/scheduler/export", tag = "Scheduler",
+    responses((status = 200, description = "Portable definitions of all jobs", body = JobsExport))
+))]
+pub async fn export_jobs(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<JobsExport>, ZeniiError> {
+    let scheduler = state
+        .scheduler
+        .as_ref()
+        .ok_or_else(|| ZeniiError::Scheduler("scheduler not initialized".into()))?;
+    Ok(Json(scheduler.export_jobs()))
+}
+
+/// POST /scheduler/import
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/scheduler/import", tag = "Scheduler",
+    params(ImportQuery),
+    request_body = JobsExport,
+    responses(
+        (status = 200, description = "Jobs imported", body = ImportReport),
+        (status = 400, description = "An entry is invalid; nothing was imported")
+    )
+))]
+pub async fn import_jobs(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ImportQuery>,
+    Json(export): Json<JobsExport>,
+) -> Result<Json<ImportReport>, ZeniiError> {
+    let scheduler = state
+        .scheduler
+        .as_ref()
+        .ok_or_else(|| ZeniiError::Scheduler("scheduler not initialized".into()))?;
+    let report = scheduler.import_jobs(export, query.on_conflict).await?;
+    if !report.created.is_empty() || !report.updated.is_empty() {
+        let _ = state
+            .event_bus
+            .publish(crate::event_bus::AppEvent::SchedulerJobsChanged);
+    }
+    Ok(Json(report))
+}
+
 /// GET /scheduler/status
 #[cfg_attr(feature = "api-docs", utoipa::path(
     get, path = "/scheduler/status", tag = "Scheduler",
@@ -215,9 +298,10 @@ mod tests {
                     enabled: true,
                     error_count: 0,
                     next_run: None,
-                    active_hours: None,
+                    active_hours: Vec::new(),
                     delete_after_run: false,
                     timeout_secs: None,
+                    priority: None,
                 })
                 .await
                 .unwrap();
@@ -306,9 +390,10 @@ mod tests {
                     enabled: true,
                     error_count: 0,
                     next_run: None,
-                    active_hours: None,
+                    active_hours: Vec::new(),
                     delete_after_run: false,
                     timeout_secs: None,
+                    priority: None,
                 })
                 .await
                 .unwrap()
@@ -350,9 +435,10 @@ mod tests {
                     enabled: true,
                     error_count: 0,
                     next_run: None,
-                    active_hours: None,
+                    active_hours: Vec::new(),
                     delete_after_run: false,
                     timeout_secs: None,
+                    priority: None,
                 })
                 .await
                 .unwrap()
@@ -408,9 +494,10 @@ mod tests {
                     enabled: true,
                     error_count: 0,
                     next_run: None,
-                    active_hours: None,
+                    active_hours: Vec::new(),
                     delete_after_run: false,
                     timeout_secs: None,
+                    priority: None,
                 })
                 .await
                 .unwrap()
@@ -484,4 +571,135 @@ mod tests {
         let status: SchedulerStatusResponse = serde_json::from_slice(&body).unwrap();
         assert!(status.running);
     }
+
+    // 16.38 — POST /scheduler/import then GET /scheduler/export round-trips
+    #[tokio::test]
+    async fn import_then_export() {
+        let (_dir, state) = test_state().await;
+        let app = build_router(state);
+
+        let file = serde_json::json!({
+            "version": 1,
+            "jobs": [{
+                "name": "standup",
+                "schedule": {"type": "cron", "expr": "0 9 * * 1-5"},
+                "payload": {"type": "notify", "message": "standup"}
+            }]
+        });
+        let import = |query: &'static str| {
+            Request::builder()
+                .method("POST")
+                .uri(format!("/scheduler/import{query}"))
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&file).unwrap()))
+                .unwrap()
+        };
+
+        let resp = app.clone().oneshot(import("")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let report: ImportReport = serde_json::from_slice(&body).unwrap();
+        assert_eq!(report.created, vec!["standup"]);
+
+        let resp = app
+            .clone()
+            .oneshot(import("?on_conflict=overwrite"))
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let report: ImportReport = serde_json::from_slice(&body).unwrap();
+        assert_eq!(report.unchanged, vec!["standup"]);
+
+        let req = Request::builder()
+            .uri("/scheduler/export")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let export: JobsExport = serde_json::from_slice(&body).unwrap();
+        assert_eq!(export.jobs.len(), 1);
+        assert!(export.jobs[0].enabled);
+    }
+
+    // 16.39 — POST /scheduler/import with an invalid entry returns 400
+    #[tokio::test]
+    async fn import_invalid_returns_400() {
+        let (_dir, state) = test_state().await;
+        let app = build_router(state.clone());
+
+        let file = serde_json::json!({
+            "version": 1,
+            "jobs": [
+                {"name": "ok", "schedule": {"type": "interval", "secs": 60}, "payload": {"type": "heartbeat"}},
+                {"name": "bad", "schedule": {"type": "cron", "expr": "nope"}, "payload": {"type": "heartbeat"}}
+            ]
+        });
+        let req = Request::builder()
+            .method("POST")
+            .uri("/scheduler/import")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&file).unwrap()))
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(state.scheduler.as_ref().unwrap().job_count(), 0);
+    }
+
+    // SCH.C2 — Cancelling an idle job is a no-op; an unknown job is 404
+    #[tokio::test]
+    async fn cancel_job_not_running_is_noop() {
+        let (_dir, state) = test_state().await;
+        let sched = state.scheduler.clone().unwrap();
+        let id = sched
+            .add_job(crate::scheduler::ScheduledJob {
+                id: String::new(),
+                name: "idle_job".into(),
+                schedule: crate::scheduler::Schedule::Interval { secs: 60 },
+                session_target: crate::scheduler::SessionTarget::Main,
+                payload: crate::scheduler::JobPayload::Notify {
+                    message: "hi".into(),
+                },
+                enabled: true,
+                error_count: 0,
+                next_run: None,
+                active_hours: Vec::new(),
+                delete_after_run: false,
+                timeout_secs: None,
+                priority: None,
+            })
+            .await
+            .unwrap();
+
+        let post = |uri: String| {
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .body(Body::empty())
+                .unwrap()
+        };
+        let resp = build_router(state.clone())
+            .oneshot(post(format!("/scheduler/jobs/{id}/cancel")))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: CancelJobResponse = serde_json::from_slice(&body).unwrap();
+        assert!(!result.cancelled);
+        assert!(sched.job_history(&id).await.is_empty());
+
+        let resp = build_router(state)
+            .oneshot(post("/scheduler/jobs/missing/cancel".into()))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
 }
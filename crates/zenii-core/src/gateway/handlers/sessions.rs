@@ -2,9 +2,12 @@ use std::sync::Arc;
 
 use axum::Json;
 use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, StatusCode};
 use axum::response::IntoResponse;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures::StreamExt;
 use serde::Deserialize;
+use tokio::sync::broadcast;
 use tracing::warn;
 
 use crate::Result;
@@ -12,10 +15,19 @@ use crate::ai::resolve_agent;
 use crate::event_bus::AppEvent;
 use crate::gateway::state::AppState;
 
+/// Upper bound on `GET /sessions?limit=`.
+const MAX_SESSIONS_PAGE_SIZE: usize = 500;
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct CreateSessionRequest {
     pub title: String,
+    /// Model override for this session: `provider_id:model_id`, or a model id with `provider`.
+    pub model: Option<String>,
+    pub provider: Option<String>,
+    /// JSON Schema that chat replies in this session must conform to.
+    #[serde(default)]
+    pub response_format: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -28,6 +40,10 @@ pub struct UpdateSessionRequest {
 pub struct ListSessionsQuery {
     /// If true, include internal sessions (e.g. delegation sub-agent sessions). Default: false.
     pub include_internal: Option<bool>,
+    /// Page size. When `limit` or `cursor` is set the response is a `SessionPage`.
+    pub limit: Option<usize>,
+    /// `next_cursor` from the previous page.
+    pub cursor: Option<String>,
 }
 
 #[cfg_attr(feature = "api-docs", utoipa::path(
@@ -42,7 +58,28 @@ pub async fn create_session(
     State(state): State<Arc<AppState>>,
     Json(req): Json<CreateSessionRequest>,
 ) -> Result<impl IntoResponse> {
-    let session = state.session_manager.create_session(&req.title).await?;
+    let model = state
+        .provider_registry
+        .validate_model_override(req.model.as_deref(), req.provider.as_deref())
+        .await?;
+    if let Some(schema) = &req.response_format {
+        crate::ai::structured::check_schema(schema)?;
+    }
+    let mut session = state.session_manager.create_session(&req.title).await?;
+    if let Some(model) = model {
+        state
+            .session_manager
+            .set_session_model(&session.id, Some(&model))
+            .await?;
+        session.model = Some(model);
+    }
+    if let Some(schema) = req.response_format {
+        state
+            .session_manager
+            .set_session_response_format(&session.id, Some(&schema))
+            .await?;
+        session.response_format = Some(schema);
+    }
     let _ = state.event_bus.publish(AppEvent::SessionCreated {
         session_id: session.id.clone(),
         title: session.title.clone(),
@@ -51,20 +88,78 @@ pub async fn create_session(
     Ok((StatusCode::CREATED, Json(session)))
 }
 
+/// Model for a chat turn. An explicit `provider` makes `model` a validated
+/// override that is recorded on the session; a bare `model` keeps its
+/// per-request meaning; otherwise the session's recorded override applies.
+pub(crate) async fn resolve_session_model(
+    state: &AppState,
+    session_id: Option<&str>,
+    model: Option<&str>,
+    provider: Option<&str>,
+) -> Result<Option<String>> {
+    if provider.is_some() {
+        let model = state
+            .provider_registry
+            .validate_model_override(model, provider)
+            .await?;
+        if let Some(sid) = session_id {
+            state
+                .session_manager
+                .set_session_model(sid, model.as_deref())
+                .await?;
+        }
+        return Ok(model);
+    }
+    if let Some(model) = model {
+        return Ok(Some(model.to_string()));
+    }
+    Ok(match session_id {
+        Some(sid) => state
+            .session_manager
+            .get_session(sid)
+            .await
+            .ok()
+            .and_then(|s| s.model),
+        None => None,
+    })
+}
+
+/// GET /sessions — all sessions as an array, or one `SessionPage` when
+/// `limit`/`cursor` is given.
 #[cfg_attr(feature = "api-docs", utoipa::path(
     get, path = "/sessions", tag = "Sessions",
-    responses((status = 200, description = "List of sessions", body = Vec<Object>))
+    params(
+        ("include_internal" = Option<bool>, Query, description = "Include delegation sessions"),
+        ("limit" = Option<usize>, Query, description = "Page size"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page")
+    ),
+    responses(
+        (status = 200, description = "List of sessions, or a page with next_cursor", body = Vec<Object>),
+        (status = 400, description = "Invalid cursor", body = Object),
+    )
 ))]
 pub async fn list_sessions(
     State(state): State<Arc<AppState>>,
     axum::extract::Query(query): axum::extract::Query<ListSessionsQuery>,
-) -> Result<impl IntoResponse> {
+) -> Result<axum::response::Response> {
     let include_internal = query.include_internal.unwrap_or(false);
-    let sessions = state
+    if query.limit.is_none() && query.cursor.is_none() {
+        let sessions = state
+            .session_manager
+            .list_sessions_filtered(include_internal)
+            .await?;
+        return Ok(Json(sessions).into_response());
+    }
+
+    let limit = query
+        .limit
+        .unwrap_or(state.config.load().inbox_sessions_page_size)
+        .clamp(1, MAX_SESSIONS_PAGE_SIZE);
+    let page = state
         .session_manager
-        .list_sessions_filtered(include_internal)
+        .list_sessions_page(include_internal, limit, query.cursor.as_deref())
         .await?;
-    Ok(Json(sessions))
+    Ok(Json(page).into_response())
 }
 
 #[cfg_attr(feature = "api-docs", utoipa::path(
@@ -83,6 +178,57 @@ pub async fn get_session(
     Ok(Json(session))
 }
 
+/// GET /sessions/{id}/stream — Server-Sent Events feed of the session's agent activity.
+///
+/// Emits `agent_started`, `agent_tool_start`, `agent_tool_result`, `agent_complete`,
+/// `agent_error` and `approval_needed` events. Each carries an `id`; reconnecting
+/// with `Last-Event-ID` first replays the buffered events that were missed.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/sessions/{id}/stream", tag = "Sessions",
+    params(
+        ("id" = String, Path, description = "Session ID"),
+        ("Last-Event-ID" = Option<u64>, Header, description = "Resume after this event id"),
+    ),
+    responses(
+        (status = 200, description = "text/event-stream of agent events"),
+        (status = 404, description = "Session not found", body = Object),
+    )
+))]
+pub async fn stream_session(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse> {
+    state.session_manager.get_session(&id).await?;
+
+    let last_event_id = headers
+        .get("last-event-id")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok());
+    let (replay, live) = state.session_streams.subscribe(&id, last_event_id);
+
+    let live = futures::stream::unfold(live, |mut rx| async move {
+        loop {
+            match rx.recv().await {
+                Ok(frame) => return Some((frame, rx)),
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("Session stream lagged, {n} events dropped");
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    })
+    .filter(move |frame| std::future::ready(frame.is_for(&id)));
+
+    let stream = futures::stream::iter(replay).chain(live).map(|frame| {
+        Event::default()
+            .id(frame.seq.to_string())
+            .event(frame.event)
+            .json_data(&frame.data)
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
 #[cfg_attr(feature = "api-docs", utoipa::path(
     put, path = "/sessions/{id}", tag = "Sessions",
     params(("id" = String, Path, description = "Session ID")),
@@ -215,6 +361,7 @@ mod tests {
                 "/sessions/{id}",
                 get(get_session).put(update_session).delete(delete_session),
             )
+            .route("/sessions/{id}/stream", get(stream_session))
             .with_state(state)
     }
 
@@ -274,6 +421,47 @@ mod tests {
         assert_eq!(sessions.len(), 2);
     }
 
+    // 3.2.2b — GET /sessions?limit= returns pages linked by next_cursor
+    #[tokio::test]
+    async fn list_sessions_paginated() {
+        let (_dir, state) = test_state().await;
+        for title in ["A", "B", "C"] {
+            state.session_manager.create_session(title).await.unwrap();
+        }
+
+        let get = |uri: String| {
+            let app = app(state.clone());
+            async move {
+                let req = Request::builder().uri(uri).body(Body::empty()).unwrap();
+                let resp = app.oneshot(req).await.unwrap();
+                let status = resp.status();
+                let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+                    .await
+                    .unwrap();
+                (
+                    status,
+                    serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+                )
+            }
+        };
+
+        let (status, first) = get("/sessions?limit=2".into()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(first["sessions"].as_array().unwrap().len(), 2);
+        let cursor = first["next_cursor"].as_str().unwrap().to_string();
+
+        let (_, second) = get(format!(
+            "/sessions?limit=2&cursor={}",
+            cursor.replace('+', "%2B").replace('|', "%7C")
+        ))
+        .await;
+        assert_eq!(second["sessions"].as_array().unwrap().len(), 1);
+        assert!(second["next_cursor"].is_null());
+
+        let (status, _) = get("/sessions?cursor=bogus".into()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
     // 3.2.3 — GET /sessions/{id} returns 200
     #[tokio::test]
     async fn get_session_returns_200() {
@@ -395,4 +583,141 @@ mod tests {
         let error: ErrorResponse = serde_json::from_slice(&body).unwrap();
         assert_eq!(error.error_code, "ZENII_NOT_FOUND");
     }
+
+    // 3.2.8 — A session created with a model override uses it for completions
+    #[tokio::test]
+    async fn session_model_override_used_for_chat() {
+        let (_dir, state) = test_state().await;
+
+        let url = crate::ai::test_support::openai_text_mock("reply from session model").await;
+        state
+            .provider_registry
+            .add_user_provider(
+                "mock",
+                "Mock",
+                &url,
+                false,
+                &[("mock-model".into(), "Mock".into(), true)],
+            )
+            .await
+            .unwrap();
+
+        let app = Router::new()
+            .route("/sessions", post(create_session))
+            .route("/chat", post(crate::gateway::handlers::chat::chat))
+            .with_state(state);
+
+        // Unknown providers are rejected
+        let req = Request::builder()
+            .method("POST")
+            .uri("/sessions")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::json!({"title": "t", "model": "x", "provider": "nope"}).to_string(),
+            ))
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/sessions")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::json!({"title": "Routed", "model": "mock-model", "provider": "mock"})
+                    .to_string(),
+            ))
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let session: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(session["model"], "mock:mock-model");
+
+        // The default model has no API key; only the session override can answer
+        let req = Request::builder()
+            .method("POST")
+            .uri("/chat")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::json!({"prompt": "hello", "session_id": session["id"]}).to_string(),
+            ))
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let chat: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(chat["response"], "reply from session model");
+    }
+
+    // 3.2.9 — SSE stream replays missed session events after Last-Event-ID
+    #[tokio::test]
+    async fn session_stream_replays_after_last_event_id() {
+        let (_dir, state) = test_state().await;
+        let session = state.session_manager.create_session("SSE").await.unwrap();
+
+        for event in [
+            AppEvent::AgentStarted {
+                session_id: session.id.clone(),
+                surface: "desktop".into(),
+            },
+            AppEvent::AgentStarted {
+                session_id: "other-session".into(),
+                surface: "desktop".into(),
+            },
+            AppEvent::AgentToolStarted {
+                session_id: session.id.clone(),
+                call_id: "c1".into(),
+                tool_name: "web_search".into(),
+                args: serde_json::json!({"query": "rust"}),
+            },
+            AppEvent::AgentCompleted {
+                session_id: session.id.clone(),
+                duration_ms: 5,
+                note: None,
+            },
+        ] {
+            state.event_bus.publish(event).unwrap();
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let req = Request::builder()
+            .uri(format!("/sessions/{}/stream", session.id))
+            .header("last-event-id", "1")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app(state.clone()).oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers()["content-type"].to_str().unwrap(),
+            "text/event-stream"
+        );
+
+        let mut body = resp.into_body().into_data_stream();
+        let mut text = String::new();
+        while !text.contains("agent_complete") {
+            let chunk = tokio::time::timeout(std::time::Duration::from_secs(2), body.next())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            text.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+        assert!(!text.contains("agent_started"));
+        assert!(!text.contains("other-session"));
+        assert!(text.contains("id: 3\nevent: agent_tool_start\n"));
+        assert!(text.contains("\"tool_name\":\"web_search\""));
+        assert!(text.contains("id: 4\nevent: agent_complete\n"));
+
+        let req = Request::builder()
+            .uri("/sessions/nonexistent-id/stream")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app(state).oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
 }
@@ -1,8 +1,19 @@
+use std::sync::Arc;
+
 use axum::Json;
+use axum::extract::{Query, State};
 use axum::response::IntoResponse;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Deserialize;
 use serde_json::json;
 use sysinfo::System;
 
+#[cfg(feature = "ai")]
+use crate::ai::routing::{ModelRouter, TaskType};
+use crate::event_bus::activity::{Activity, ActivityPage};
+use crate::event_bus::lifecycle::{LifecycleQuery, StateTransition};
+use crate::gateway::state::AppState;
+
 /// GET /system/info -- returns host system information.
 #[cfg_attr(feature = "api-docs", utoipa::path(
     get, path = "/system/info", tag = "System",
@@ -23,6 +34,358 @@ pub async fn system_info() -> impl IntoResponse {
     }))
 }
 
+/// Number of recent lifecycle transitions included in the runtime snapshot.
+const RUNTIME_RECENT_TRANSITIONS: usize = 10;
+
+/// GET /runtime -- one JSON snapshot of the effective agent runtime.
+///
+/// Aggregates tools, skills, identity files, provider/model, scheduler,
+/// channels, feature toggles and recent lifecycle transitions so users can
+/// attach it to bug reports.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/runtime", tag = "System",
+    responses((status = 200, description = "Agent runtime snapshot", body = Object))
+))]
+pub async fn runtime_info(State(state): State<Arc<AppState>>) -> crate::Result<impl IntoResponse> {
+    let mut tools: Vec<String> = state.tools.list().into_iter().map(|t| t.name).collect();
+    tools.sort();
+
+    let skills: Vec<_> = state
+        .skill_registry
+        .list()
+        .await
+        .into_iter()
+        .map(|s| json!({"id": s.id, "category": s.category, "enabled": s.enabled}))
+        .collect();
+
+    #[cfg(feature = "ai")]
+    let provider = {
+        let default_model = state
+            .provider_registry
+            .get_default_model()
+            .await?
+            .map(|(pid, mid)| format!("{pid}:{mid}"));
+        let cfg = state.config.load();
+        let router = ModelRouter::new(&cfg);
+        let routing: serde_json::Map<String, serde_json::Value> = TaskType::ALL
+            .into_iter()
+            .filter_map(|task| {
+                router
+                    .route_task(task)
+                    .map(|m| (task.hint().to_string(), json!(m)))
+            })
+            .collect();
+        json!({
+            "default_model": default_model,
+            "last_used_model": state.last_used_model.read().await.clone(),
+            "routing": routing,
+            "boot_agent": state.agent.is_some(),
+        })
+    };
+    #[cfg(not(feature = "ai"))]
+    let provider = serde_json::Value::Null;
+
+    #[cfg(feature = "ai")]
+    let transcription_enabled = state.transcriber.is_some();
+    #[cfg(not(feature = "ai"))]
+    let transcription_enabled = false;
+
+    #[cfg(feature = "scheduler")]
+    let scheduler = state.scheduler.as_ref().map(|s| {
+        json!({
+            "running": s.is_running(),
+            "job_count": s.job_count(),
+        })
+    });
+    #[cfg(not(feature = "scheduler"))]
+    let scheduler: Option<serde_json::Value> = None;
+
+    #[cfg(feature = "channels")]
+    let channels: Vec<_> = state
+        .channel_registry
+        .list()
+        .into_iter()
+        .map(|name| {
+            let status = state.channel_registry.status(&name).map(|s| s.to_string());
+            json!({"name": name, "status": status})
+        })
+        .collect();
+    #[cfg(not(feature = "channels"))]
+    let channels: Vec<serde_json::Value> = Vec::new();
+
+    let plugins: Vec<_> = state
+        .plugin_registry
+        .list()
+        .into_iter()
+        .map(|p| json!({"name": p.manifest.plugin.name, "enabled": p.enabled}))
+        .collect();
+
+    let recent_transitions = crate::event_bus::lifecycle::query(
+        &state.db,
+        &LifecycleQuery {
+            limit: Some(RUNTIME_RECENT_TRANSITIONS),
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "provider": provider,
+        "tools": tools,
+        "skills": skills,
+        "identity": {
+            "name": state.soul_loader.get().await.meta.name,
+            "files": state.soul_loader.list_files().await,
+        },
+        "scheduler": scheduler,
+        "channels": channels,
+        "plugins": plugins,
+        "features": {
+            "context_injection_enabled": state.context_injection_enabled.load(std::sync::atomic::Ordering::Relaxed),
+            "self_evolution_enabled": state.self_evolution_enabled.load(std::sync::atomic::Ordering::Relaxed),
+            "embedding_model_available": state.embedding_model_available.load(std::sync::atomic::Ordering::Relaxed),
+            "transcription_enabled": transcription_enabled,
+        },
+        "lifecycle": {
+            "active_delegations": state.coordinator.active_agents().len(),
+            "recent_transitions": recent_transitions,
+        },
+    })))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ActivityQuery {
+    /// Maximum number of entries. Default: 50
+    #[serde(default = "default_activity_limit")]
+    pub limit: usize,
+    /// `next_cursor` from the previous page.
+    pub before: Option<String>,
+}
+
+fn default_activity_limit() -> usize {
+    50
+}
+
+/// Parse an activity cursor of the form `{started_at}|{id}`.
+fn parse_activity_cursor(cursor: &str) -> crate::Result<(DateTime<Utc>, String)> {
+    let invalid = || crate::ZeniiError::Validation(format!("invalid cursor: {cursor}"));
+    let (ts, id) = cursor.split_once('|').ok_or_else(invalid)?;
+    let ts = DateTime::parse_from_rfc3339(ts).map_err(|_| invalid())?;
+    Ok((ts.with_timezone(&Utc), id.to_string()))
+}
+
+fn activity_cursor(entry: &Activity) -> String {
+    format!(
+        "{}|{}",
+        entry.started_at.to_rfc3339_opts(SecondsFormat::Nanos, true),
+        entry.id
+    )
+}
+
+/// GET /system/activity -- recent tool calls, one entry per call with its
+/// current status (`running`, `success`, `failed` or `stuck`).
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/system/activity", tag = "System",
+    params(
+        ("limit" = Option<usize>, Query, description = "Maximum number of entries (default 50)"),
+        ("before" = Option<String>, Query, description = "next_cursor from the previous page")
+    ),
+    responses(
+        (status = 200, description = "Tool calls, newest first", body = ActivityPage),
+        (status = 400, description = "Invalid cursor", body = Object),
+    )
+))]
+pub async fn activity(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ActivityQuery>,
+) -> crate::Result<Json<ActivityPage>> {
+    let before = query
+        .before
+        .as_deref()
+        .map(parse_activity_cursor)
+        .transpose()?;
+    let limit = query.limit.max(1);
+
+    // Fetch one extra entry to learn whether another page follows
+    let mut activities = state.activity.recent(limit + 1, before);
+    let next_cursor = if activities.len() > limit {
+        activities.truncate(limit);
+        activities.last().map(activity_cursor)
+    } else {
+        None
+    };
+    Ok(Json(ActivityPage {
+        activities,
+        next_cursor,
+    }))
+}
+
+/// Resolve a client-supplied file name under `{data_dir}/{dir}`. Names with
+/// path separators or `..` are rejected, so gateway clients cannot reach
+/// files outside that directory.
+fn data_dir_file(
+    config: &crate::config::AppConfig,
+    dir: &str,
+    name: &str,
+) -> crate::Result<std::path::PathBuf> {
+    let name = name.trim();
+    if name.is_empty() || name.contains(['/', '\\', ':']) || name.contains("..") {
+        return Err(crate::ZeniiError::Validation(format!(
+            "'{name}' must be a plain file name inside {{data_dir}}/{dir}/"
+        )));
+    }
+    Ok(config
+        .data_dir
+        .as_ref()
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(crate::config::default_data_dir)
+        .join(dir)
+        .join(name))
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct StartEventRecordingRequest {
+    /// File name inside {data_dir}/recordings/. Default: events-<timestamp>.jsonl
+    pub name: Option<String>,
+}
+
+/// POST /system/events/recording/start -- record every bus event to a JSON Lines file.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/system/events/recording/start", tag = "System",
+    request_body = StartEventRecordingRequest,
+    responses(
+        (status = 200, description = "Recording started", body = Object),
+        (status = 400, description = "A recording is already running, or the name is not a plain file name")
+    )
+))]
+pub async fn start_event_recording(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<StartEventRecordingRequest>,
+) -> crate::Result<impl IntoResponse> {
+    let config = state.config.load();
+    let name = match req.name.filter(|n| !n.trim().is_empty()) {
+        Some(name) => name,
+        None => format!(
+            "events-{}.jsonl",
+            chrono::Utc::now().format("%Y%m%dT%H%M%SZ")
+        ),
+    };
+    let path = data_dir_file(&config, "recordings", &name)?;
+
+    state
+        .event_recorder
+        .start(
+            state.event_bus.clone(),
+            path.clone(),
+            config.event_recording_max_bytes,
+        )
+        .await?;
+
+    Ok(Json(json!({
+        "path": path,
+        "max_bytes": config.event_recording_max_bytes,
+    })))
+}
+
+/// POST /system/events/recording/stop -- stop the running event recording.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/system/events/recording/stop", tag = "System",
+    responses(
+        (status = 200, description = "Recording summary", body = Object),
+        (status = 404, description = "No recording is running")
+    )
+))]
+pub async fn stop_event_recording(
+    State(state): State<Arc<AppState>>,
+) -> crate::Result<impl IntoResponse> {
+    let summary = state.event_recorder.stop().await?;
+    Ok(Json(summary))
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct DbSnapshotRequest {
+    /// Snapshot file name inside {data_dir}/backups/; its manifest lives
+    /// beside it as `<name>.manifest.json`.
+    pub name: String,
+}
+
+/// POST /system/db/backup -- snapshot the database while the daemon runs.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/system/db/backup", tag = "System",
+    request_body = DbSnapshotRequest,
+    responses(
+        (status = 200, description = "Snapshot written", body = Object),
+        (status = 400, description = "Destination already exists, or the name is not a plain file name")
+    )
+))]
+pub async fn db_backup(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<DbSnapshotRequest>,
+) -> crate::Result<impl IntoResponse> {
+    let path = data_dir_file(&state.config.load(), "backups", &req.name)?;
+    let manifest = crate::db::backup::backup(&state.db, &path).await?;
+    Ok(Json(json!({
+        "path": path,
+        "manifest_path": crate::db::backup::manifest_path(&path),
+        "manifest": manifest,
+    })))
+}
+
+/// POST /system/db/restore -- replace the database with a snapshot.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/system/db/restore", tag = "System",
+    request_body = DbSnapshotRequest,
+    responses(
+        (status = 200, description = "Snapshot restored", body = Object),
+        (status = 400, description = "Manifest missing, schema newer than this build, or the name is not a plain file name"),
+        (status = 404, description = "Snapshot not found")
+    )
+))]
+pub async fn db_restore(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<DbSnapshotRequest>,
+) -> crate::Result<impl IntoResponse> {
+    let path = data_dir_file(&state.config.load(), "backups", &req.name)?;
+    let manifest = crate::db::backup::restore(&state.db, &path).await?;
+    // Scheduled jobs and channel state were loaded at boot and still reflect
+    // the old database.
+    Ok(Json(json!({
+        "path": path,
+        "manifest": manifest,
+        "restart_required": true,
+    })))
+}
+
+/// GET /system/lifecycle -- recorded state transitions of channels,
+/// delegations, sub-agents, workflow runs, plugins and the scheduler, newest first.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/system/lifecycle", tag = "System",
+    params(
+        ("resource_type" = Option<String>, Query, description = "channel, delegation, sub_agent, workflow_run, plugin or scheduler"),
+        ("resource_id" = Option<String>, Query, description = "Only transitions of this resource"),
+        ("state" = Option<String>, Query, description = "Only transitions into this state"),
+        ("since" = Option<String>, Query, description = "RFC 3339 lower bound (inclusive)"),
+        ("until" = Option<String>, Query, description = "RFC 3339 upper bound (exclusive)"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of transitions (default 100)"),
+        ("offset" = Option<usize>, Query, description = "Transitions to skip, for paging"),
+    ),
+    responses(
+        (status = 200, description = "Transitions, newest first", body = Vec<StateTransition>),
+        (status = 400, description = "Invalid time bound", body = Object),
+    )
+))]
+pub async fn lifecycle_history(
+    State(state): State<Arc<AppState>>,
+    Query(filter): Query<LifecycleQuery>,
+) -> crate::Result<Json<Vec<StateTransition>>> {
+    Ok(Json(
+        crate::event_bus::lifecycle::query(&state.db, &filter).await?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use axum::Router;
@@ -33,6 +396,13 @@ mod tests {
 
     use super::*;
 
+    /// Point `data_dir` at the test's temp dir so files land there.
+    fn use_data_dir(state: &AppState, dir: &std::path::Path) {
+        let mut config = (**state.config.load()).clone();
+        config.data_dir = Some(dir.to_string_lossy().into());
+        state.config.store(Arc::new(config));
+    }
+
     #[tokio::test]
     async fn system_info_returns_200() {
         let app = Router::new().route("/system/info", get(system_info));
@@ -60,4 +430,337 @@ mod tests {
         // total_memory_bytes should be a positive number on any real system
         assert!(json["total_memory_bytes"].as_u64().unwrap_or(0) > 0);
     }
+
+    #[tokio::test]
+    async fn runtime_info_includes_core_sections() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let app = Router::new()
+            .route("/runtime", get(runtime_info))
+            .with_state(state);
+        let req = Request::builder()
+            .uri("/runtime")
+            .body(Body::empty())
+            .expect("build request");
+
+        let resp = app.oneshot(req).await.expect("response");
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(resp.into_body(), 1 << 20)
+            .await
+            .expect("read body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("parse json");
+
+        for section in [
+            "version",
+            "provider",
+            "tools",
+            "skills",
+            "identity",
+            "scheduler",
+            "channels",
+            "features",
+            "lifecycle",
+        ] {
+            assert!(json.get(section).is_some(), "missing section {section}");
+        }
+        assert!(json["tools"].is_array());
+        assert!(json["identity"]["files"].is_array());
+        assert!(json["features"]["self_evolution_enabled"].is_boolean());
+        assert!(json["lifecycle"]["active_delegations"].is_u64());
+        assert!(json["lifecycle"]["recent_transitions"].is_array());
+    }
+
+    #[tokio::test]
+    async fn event_recording_start_stop_via_api() {
+        let (dir, state) = crate::gateway::handlers::tests::test_state().await;
+        use_data_dir(&state, dir.path());
+        let app = Router::new()
+            .route(
+                "/system/events/recording/start",
+                axum::routing::post(start_event_recording),
+            )
+            .route(
+                "/system/events/recording/stop",
+                axum::routing::post(stop_event_recording),
+            )
+            .with_state(state.clone());
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/system/events/recording/start")
+            .header("content-type", "application/json")
+            .body(Body::from(json!({"name": "events.jsonl"}).to_string()))
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), 4096).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let path = std::path::PathBuf::from(json["path"].as_str().unwrap());
+        assert_eq!(path, dir.path().join("recordings").join("events.jsonl"));
+
+        state
+            .event_bus
+            .publish(crate::event_bus::AppEvent::ConfigUpdated)
+            .unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/system/events/recording/stop")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), 4096).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["events"], 1);
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .contains("ConfigUpdated")
+        );
+
+        // Stopping again reports that nothing is running
+        let req = Request::builder()
+            .method("POST")
+            .uri("/system/events/recording/stop")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    // Recording names cannot escape {data_dir}/recordings
+    #[tokio::test]
+    async fn event_recording_rejects_paths() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let app = Router::new()
+            .route(
+                "/system/events/recording/start",
+                axum::routing::post(start_event_recording),
+            )
+            .with_state(state);
+
+        for name in [
+            "/tmp/evil.jsonl",
+            "../evil.jsonl",
+            "sub/evil.jsonl",
+            "..\\evil",
+        ] {
+            let req = Request::builder()
+                .method("POST")
+                .uri("/system/events/recording/start")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": name}).to_string()))
+                .unwrap();
+            let resp = app.clone().oneshot(req).await.unwrap();
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{name}");
+        }
+    }
+
+    #[tokio::test]
+    async fn db_backup_and_restore_via_api() {
+        let (dir, state) = crate::gateway::handlers::tests::test_state().await;
+        use_data_dir(&state, dir.path());
+        let app = Router::new()
+            .route("/system/db/backup", axum::routing::post(db_backup))
+            .route("/system/db/restore", axum::routing::post(db_restore))
+            .with_state(state);
+
+        let post_name = |uri: &str, name: &str| {
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": name}).to_string()))
+                .unwrap()
+        };
+        let post = |uri: &str| post_name(uri, "zenii.db");
+
+        let resp = app
+            .clone()
+            .oneshot(post("/system/db/backup"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), 4096).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            json["manifest"]["schema_version"],
+            crate::db::SCHEMA_VERSION
+        );
+        assert!(dir.path().join("backups").join("zenii.db").is_file());
+
+        // A second backup to the same path is refused
+        let resp = app
+            .clone()
+            .oneshot(post("/system/db/backup"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        // Neither endpoint accepts a path outside {data_dir}/backups
+        for uri in ["/system/db/backup", "/system/db/restore"] {
+            for name in ["/tmp/zenii.db", "../test.db"] {
+                let resp = app.clone().oneshot(post_name(uri, name)).await.unwrap();
+                assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{uri} {name}");
+            }
+        }
+
+        let resp = app.oneshot(post("/system/db/restore")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), 4096).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["restart_required"], true);
+    }
+
+    #[tokio::test]
+    async fn lifecycle_history_filters_and_pages() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        crate::db::with_db(&state.db, |conn| {
+            conn.execute_batch(
+                "INSERT INTO lifecycle_transitions (timestamp, resource_type, resource_id, from_state, to_state)
+                 VALUES ('2026-01-01T00:00:00.000Z', 'channel', 'telegram', NULL, 'connected'),
+                        ('2026-01-02T00:00:00.000Z', 'channel', 'telegram', 'connected', 'disconnected'),
+                        ('2026-01-03T00:00:00.000Z', 'plugin', 'weather', NULL, 'unhealthy');",
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+        let app = Router::new()
+            .route("/system/lifecycle", get(lifecycle_history))
+            .with_state(state);
+        let fetch = |uri: &'static str| {
+            let app = app.clone();
+            async move {
+                let req = Request::builder().uri(uri).body(Body::empty()).unwrap();
+                let resp = app.oneshot(req).await.unwrap();
+                let status = resp.status();
+                let body = axum::body::to_bytes(resp.into_body(), 65536).await.unwrap();
+                (status, body)
+            }
+        };
+
+        let (status, body) =
+            fetch("/system/lifecycle?resource_type=channel&limit=1&offset=1").await;
+        assert_eq!(status, StatusCode::OK);
+        let rows: Vec<StateTransition> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].to_state, "connected");
+
+        let (_, body) = fetch("/system/lifecycle?state=unhealthy&since=2026-01-02T12:00:00Z").await;
+        let rows: Vec<StateTransition> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].resource_id, "weather");
+
+        let (status, _) = fetch("/system/lifecycle?until=soon").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn activity_returns_one_entry_per_tool_call() {
+        use crate::event_bus::AppEvent;
+        use crate::event_bus::activity::ActivityStatus;
+
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        state
+            .event_bus
+            .publish(AppEvent::AgentToolStarted {
+                session_id: "s1".into(),
+                call_id: "call-1".into(),
+                tool_name: "shell".into(),
+                args: json!({}),
+            })
+            .unwrap();
+        state
+            .event_bus
+            .publish(AppEvent::AgentToolCompleted {
+                session_id: "s1".into(),
+                call_id: "call-1".into(),
+                tool_name: "shell".into(),
+                output: "ok".into(),
+                success: true,
+                duration_ms: 12,
+            })
+            .unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let app = Router::new()
+            .route("/system/activity", get(activity))
+            .with_state(state);
+        let req = Request::builder()
+            .uri("/system/activity?limit=10")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), 65536).await.unwrap();
+        let page: ActivityPage = serde_json::from_slice(&body).unwrap();
+        assert_eq!(page.activities.len(), 1);
+        assert_eq!(page.activities[0].id, "call-1");
+        assert_eq!(page.activities[0].status, ActivityStatus::Success);
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn activity_pages_follow_next_cursor() {
+        use crate::event_bus::AppEvent;
+
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        // Published back to back, so several calls can share a start time
+        for i in 0..5 {
+            state
+                .event_bus
+                .publish(AppEvent::AgentToolStarted {
+                    session_id: "s1".into(),
+                    call_id: format!("call-{i}"),
+                    tool_name: "shell".into(),
+                    args: json!({}),
+                })
+                .unwrap();
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let app = Router::new()
+            .route("/system/activity", get(activity))
+            .with_state(state);
+        let fetch = |uri: String| {
+            let app = app.clone();
+            async move {
+                let req = Request::builder().uri(uri).body(Body::empty()).unwrap();
+                let resp = app.oneshot(req).await.unwrap();
+                let status = resp.status();
+                let body = axum::body::to_bytes(resp.into_body(), 65536).await.unwrap();
+                (status, body)
+            }
+        };
+
+        let mut seen = Vec::new();
+        let mut uri = "/system/activity?limit=2".to_string();
+        loop {
+            let (status, body) = fetch(uri).await;
+            assert_eq!(status, StatusCode::OK);
+            let page: ActivityPage = serde_json::from_slice(&body).unwrap();
+            assert!(page.activities.len() <= 2);
+            seen.extend(page.activities.into_iter().map(|a| a.id));
+            match page.next_cursor {
+                Some(cursor) => {
+                    uri = format!(
+                        "/system/activity?limit=2&before={}",
+                        cursor.replace('|', "%7C")
+                    )
+                }
+                None => break,
+            }
+        }
+        let mut sorted = seen.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(seen.len(), 5, "every call appears exactly once: {seen:?}");
+        assert_eq!(sorted.len(), 5);
+
+        let (status, _) = fetch("/system/activity?before=bogus".into()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
 }
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::ZeniiError;
+use crate::gateway::auth::{ApiToken, TokenScope};
+use crate::gateway::state::AppState;
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct CreateTokenRequest {
+    pub name: String,
+    pub scope: TokenScope,
+}
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct CreatedToken {
+    #[serde(flatten)]
+    pub info: ApiToken,
+    /// The bearer secret. Only returned here; store it now.
+    pub token: String,
+}
+
+/// GET /auth/tokens — list scoped gateway tokens (without secrets).
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/auth/tokens", tag = "Auth",
+    responses((status = 200, description = "Scoped tokens", body = Vec<ApiToken>))
+))]
+pub async fn list_tokens(State(state): State<Arc<AppState>>) -> Json<Vec<ApiToken>> {
+    Json(state.api_tokens.list())
+}
+
+/// POST /auth/tokens — mint a scoped token.
+///
+/// While no admin credential exists (no `gateway_auth_token` and no admin
+/// token), this route is unauthenticated and minting turns auth on, so the
+/// first token must be `admin`: anything narrower would lock every caller
+/// out of `/auth/*`, including the one revoking it.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/auth/tokens", tag = "Auth",
+    request_body = CreateTokenRequest,
+    responses(
+        (status = 201, description = "Token created; the secret is shown once", body = CreatedToken),
+        (status = 400, description = "Empty or duplicate name, or a first token that is not admin", body = Object),
+    )
+))]
+pub async fn create_token(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<CreateTokenRequest>,
+) -> crate::Result<impl IntoResponse> {
+    let has_admin =
+        state.config.load().gateway_auth_token.is_some() || state.api_tokens.has_admin();
+    if !has_admin && body.scope != TokenScope::Admin {
+        return Err(ZeniiError::Validation(format!(
+            "the first token must have the admin scope, not '{}': minting it turns on \
+             authentication and only admin tokens can manage tokens",
+            body.scope
+        )));
+    }
+    let (info, token) = state.api_tokens.mint(&body.name, body.scope).await?;
+    Ok((StatusCode::CREATED, Json(CreatedToken { info, token })))
+}
+
+/// DELETE /auth/tokens/{id} — revoke a token by id or name.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    delete, path = "/auth/tokens/{id}", tag = "Auth",
+    params(("id" = String, Path, description = "Token id or name")),
+    responses(
+        (status = 204, description = "Token revoked"),
+        (status = 404, description = "No such token", body = Object),
+    )
+))]
+pub async fn revoke_token(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> crate::Result<impl IntoResponse> {
+    if state.api_tokens.revoke(&id).await? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ZeniiError::NotFound(format!("token '{id}' not found")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::{delete, get};
+    use tower::ServiceExt;
+
+    use crate::gateway::auth::GatewayAuth;
+
+    fn app(state: Arc<AppState>) -> Router {
+        Router::new()
+            .route("/auth/tokens", get(list_tokens).post(create_token))
+            .route("/auth/tokens/{id}", delete(revoke_token))
+            .with_state(state)
+    }
+
+    fn mint_request(body: &'static str, bearer: Option<&str>) -> Request<Body> {
+        let mut req = Request::builder()
+            .method("POST")
+            .uri("/auth/tokens")
+            .header("content-type", "application/json");
+        if let Some(token) = bearer {
+            req = req.header("authorization", format!("Bearer {token}"));
+        }
+        req.body(Body::from(body)).unwrap()
+    }
+
+    // AUTH.4 — Minting returns the secret once; listing hides it; revoke by name
+    #[tokio::test]
+    async fn mint_list_revoke() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        state
+            .api_tokens
+            .mint("ops", TokenScope::Admin)
+            .await
+            .unwrap();
+        let app = app(state.clone());
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/auth/tokens")
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"name":"monitor","scope":"read"}"#))
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(created["scope"], "read");
+        let secret = created["token"].as_str().unwrap();
+        assert_eq!(
+            state.api_tokens.authenticate(secret),
+            Some(TokenScope::Read)
+        );
+
+        let req = Request::builder()
+            .uri("/auth/tokens")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let listed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(listed[1]["name"], "monitor");
+        assert!(listed[1].get("token").is_none() && listed[1].get("hash").is_none());
+
+        let revoke = |uri: &str| {
+            Request::builder()
+                .method("DELETE")
+                .uri(uri)
+                .body(Body::empty())
+                .unwrap()
+        };
+        let resp = app
+            .clone()
+            .oneshot(revoke("/auth/tokens/monitor"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let resp = app.oneshot(revoke("/auth/tokens/monitor")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    // AUTH.5 — Without an admin credential the first token must be admin, and
+    // the caller can keep managing tokens with it once auth is on
+    #[tokio::test]
+    async fn first_mint_does_not_lock_out_caller() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let app = app(state.clone()).layer(axum::middleware::from_fn_with_state(
+            Arc::new(GatewayAuth::new(
+                state.config.clone(),
+                state.api_tokens.clone(),
+            )),
+            crate::gateway::middleware::auth_middleware,
+        ));
+
+        let resp = app
+            .clone()
+            .oneshot(mint_request(r#"{"name":"monitor","scope":"read"}"#, None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert!(state.api_tokens.is_empty());
+
+        let resp = app
+            .clone()
+            .oneshot(mint_request(r#"{"name":"ops","scope":"admin"}"#, None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let admin = created["token"].as_str().unwrap().to_string();
+
+        // Auth is on now: anonymous callers are refused, the minted token works
+        let resp = app
+            .clone()
+            .oneshot(mint_request(r#"{"name":"monitor","scope":"read"}"#, None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let resp = app
+            .clone()
+            .oneshot(mint_request(
+                r#"{"name":"monitor","scope":"read"}"#,
+                Some(&admin),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let req = Request::builder()
+            .method("DELETE")
+            .uri("/auth/tokens/monitor")
+            .header("authorization", format!("Bearer {admin}"))
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    }
+}
@@ -15,10 +15,11 @@ pub struct ExecuteToolRequest {
     pub args: serde_json::Value,
 }
 
-/// GET /tools — list all registered tools.
+/// GET /tools — list all registered tools with their parameter JSON Schemas,
+/// sorted by name.
 #[cfg_attr(feature = "api-docs", utoipa::path(
     get, path = "/tools", tag = "Tools",
-    responses((status = 200, description = "List of registered tools"))
+    responses((status = 200, description = "List of registered tools", body = Vec<crate::tools::ToolInfo>))
 ))]
 pub async fn list_tools(State(state): State<Arc<AppState>>) -> crate::Result<impl IntoResponse> {
     Ok(Json(state.tools.list()))
@@ -62,6 +63,26 @@ pub async fn execute_tool(
     Ok(Json(result))
 }
 
+/// GET /tools/results/{id} — full output of a tool result that was truncated for the model.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/tools/results/{id}", tag = "Tools",
+    params(("id" = String, Path, description = "`full_output_id` from the tool_result event")),
+    responses(
+        (status = 200, description = "Untruncated tool output", body = crate::ai::tool_results::StoredToolResult),
+        (status = 404, description = "Unknown or expired id")
+    )
+))]
+pub async fn get_tool_result(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> crate::Result<impl IntoResponse> {
+    let result = state
+        .tool_results
+        .get(&id)
+        .ok_or_else(|| ZeniiError::NotFound(format!("tool result not found or expired: {id}")))?;
+    Ok(Json(result))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,6 +138,12 @@ mod tests {
             config_write_lock: tokio::sync::Mutex::new(()),
             db: base_state.db.clone(),
             event_bus: base_state.event_bus.clone(),
+            event_recorder: base_state.event_recorder.clone(),
+            oauth_states: base_state.oauth_states.clone(),
+            api_tokens: base_state.api_tokens.clone(),
+            tool_results: base_state.tool_results.clone(),
+            session_streams: base_state.session_streams.clone(),
+            activity: base_state.activity.clone(),
             memory: base_state.memory.clone(),
             credentials: base_state.credentials.clone(),
             security: base_state.security.clone(),
@@ -124,11 +151,14 @@ mod tests {
             session_manager: base_state.session_manager.clone(),
             agent: None,
             provider_registry: base_state.provider_registry.clone(),
+            provider_health: base_state.provider_health.clone(),
+            stream_cancels: base_state.stream_cancels.clone(),
             boot_context: base_state.boot_context.clone(),
             last_used_model: base_state.last_used_model.clone(),
             context_builder: base_state.context_builder.clone(),
             reasoning_engine: Arc::new(crate::ai::reasoning::ReasoningEngine::new(3)),
             prompt_strategy: base_state.prompt_strategy.clone(),
+            transcriber: base_state.transcriber.clone(),
             context_injection_enabled: base_state.context_injection_enabled.clone(),
             self_evolution_enabled: base_state.self_evolution_enabled.clone(),
             soul_loader: base_state.soul_loader.clone(),
@@ -155,6 +185,8 @@ mod tests {
             usage_logger: base_state.usage_logger.clone(),
             embedding_model_available: base_state.embedding_model_available.clone(),
             approval_broker: base_state.approval_broker.clone(),
+            audit: base_state.audit.clone(),
+            budget: base_state.budget.clone(),
             wiki: base_state.wiki.clone(),
             converter: base_state.converter.clone(),
         });
@@ -165,6 +197,7 @@ mod tests {
         Router::new()
             .route("/tools", get(list_tools))
             .route("/tools/{name}/execute", post(execute_tool))
+            .route("/tools/results/{id}", get(get_tool_result))
             .with_state(state)
     }
 
@@ -190,6 +223,43 @@ mod tests {
         assert!(tools[0]["parameters"].is_object());
     }
 
+    #[tokio::test]
+    async fn list_tools_requires_admin_scope() {
+        use crate::gateway::auth::{GatewayAuth, TokenScope};
+
+        let echo: Arc<dyn Tool> = Arc::new(EchoTool);
+        let (_dir, state) = test_state_with_tools(vec![echo]).await;
+        let (_, read) = state
+            .api_tokens
+            .mint("monitor", TokenScope::Read)
+            .await
+            .unwrap();
+        let (_, admin) = state
+            .api_tokens
+            .mint("ops", TokenScope::Admin)
+            .await
+            .unwrap();
+        let app = app(state.clone()).layer(axum::middleware::from_fn_with_state(
+            Arc::new(GatewayAuth::new(
+                state.config.clone(),
+                state.api_tokens.clone(),
+            )),
+            crate::gateway::middleware::auth_middleware,
+        ));
+
+        let list = |token: &str| {
+            Request::builder()
+                .uri("/tools")
+                .header("authorization", format!("Bearer {token}"))
+                .body(Body::empty())
+                .unwrap()
+        };
+        let resp = app.clone().oneshot(list(&read)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let resp = app.oneshot(list(&admin)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
     #[tokio::test]
     async fn execute_tool_returns_result() {
         let echo: Arc<dyn Tool> = Arc::new(EchoTool);
@@ -259,4 +329,30 @@ mod tests {
         let resp = app.oneshot(req).await.unwrap();
         assert_eq!(resp.status(), StatusCode::NOT_FOUND);
     }
+
+    #[tokio::test]
+    async fn get_tool_result_returns_full_output() {
+        let (_dir, state) = test_state_with_tools(vec![]).await;
+        let full = "line\n".repeat(5000);
+        let id = state.tool_results.put("shell", full.clone());
+        let app = app(state);
+
+        let req = Request::builder()
+            .uri(format!("/tools/results/{id}"))
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), 65536).await.unwrap();
+        let result: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["tool_name"], "shell");
+        assert_eq!(result["output"], full);
+
+        let req = Request::builder()
+            .uri("/tools/results/unknown")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
 }
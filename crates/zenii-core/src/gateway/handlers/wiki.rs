@@ -25,11 +25,13 @@ pub struct ListQuery {
 }
 
 #[derive(Deserialize, Default)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct LintRequest {
     pub auto_fix: Option<bool>,
 }
 
 #[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct IngestRequest {
     pub content: String,
     pub filename: String,
@@ -37,6 +39,7 @@ pub struct IngestRequest {
 }
 
 #[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct QueryRequest {
     pub question: String,
     pub save: Option<bool>,
@@ -75,11 +78,13 @@ struct LintResponse {
 }
 
 #[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct RegenerateRequest {
     pub model: Option<String>,
 }
 
 #[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct SetPromptRequest {
     pub content: String,
 }
@@ -141,6 +146,14 @@ const QUERY_MIN_SCORE: u32 = 1;
 // ── Handlers ─────────────────────────────────────────────────────────────────
 
 /// GET /wiki?limit=50&offset=0 — list wiki pages with pagination (L4).
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/wiki", tag = "Wiki",
+    params(
+        ("limit" = Option<usize>, Query, description = "Page size"),
+        ("offset" = Option<usize>, Query, description = "Page offset"),
+    ),
+    responses((status = 200, description = "Wiki pages", body = Object))
+))]
 pub async fn list_wiki_pages(
     State(state): State<Arc<AppState>>,
     Query(params): Query<ListQuery>,
@@ -178,6 +191,14 @@ pub async fn list_wiki_pages(
 }
 
 /// GET /wiki/{slug} — fetch a single wiki page by slug.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/wiki/{slug}", tag = "Wiki",
+    params(("slug" = String, Path, description = "Page slug")),
+    responses(
+        (status = 200, description = "Wiki page", body = Object),
+        (status = 404, description = "Page not found", body = Object),
+    )
+))]
 pub async fn get_wiki_page(
     State(state): State<Arc<AppState>>,
     Path(slug): Path<String>,
@@ -201,6 +222,11 @@ pub async fn get_wiki_page(
 }
 
 /// GET /wiki/search?q= — full-text search over wiki pages.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/wiki/search", tag = "Wiki",
+    params(("q" = Option<String>, Query, description = "Search text")),
+    responses((status = 200, description = "Matching pages", body = Object))
+))]
 pub async fn search_wiki_pages(
     State(state): State<Arc<AppState>>,
     Query(params): Query<SearchQuery>,
@@ -231,6 +257,14 @@ pub async fn search_wiki_pages(
 ///
 /// Uses staged builds (write to unique tempdir first, swap on success) and updates the manifest
 /// so future regenerate/delete operations have accurate source lineage.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/wiki/ingest", tag = "Wiki",
+    request_body = IngestRequest,
+    responses(
+        (status = 200, description = "Source ingested into wiki pages", body = Object),
+        (status = 400, description = "Invalid source", body = Object),
+    )
+))]
 pub async fn ingest_wiki_source(
     State(state): State<Arc<AppState>>,
     Json(body): Json<IngestRequest>,
@@ -521,6 +555,10 @@ pub async fn ingest_wiki_source(
 }
 
 /// GET /wiki/sources — list all ingested source files with manifest metadata.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/wiki/sources", tag = "Wiki",
+    responses((status = 200, description = "Ingested sources", body = Object))
+))]
 pub async fn list_wiki_sources(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let wiki = Arc::clone(&state.wiki).lock_owned().await;
     let result = tokio::task::spawn_blocking(move || {
@@ -563,6 +601,10 @@ pub async fn list_wiki_sources(State(state): State<Arc<AppState>>) -> impl IntoR
 }
 
 /// GET /wiki/dir — return the absolute path to the wiki sources directory (for the Open Folder button).
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/wiki/dir", tag = "Wiki",
+    responses((status = 200, description = "Wiki directory on disk", body = Object))
+))]
 pub async fn get_wiki_dir(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let wiki = Arc::clone(&state.wiki).lock_owned().await;
     let path = tokio::task::spawn_blocking(move || {
@@ -580,6 +622,11 @@ pub async fn get_wiki_dir(State(state): State<Arc<AppState>>) -> impl IntoRespon
 ///
 /// Preserves `user_query` pages. Uses staged builds for atomicity: pages are never
 /// deleted until after the LLM compile succeeds and the staged build is committed.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/wiki/regenerate", tag = "Wiki",
+    request_body = RegenerateRequest,
+    responses((status = 200, description = "Wiki regenerated from all sources", body = Object))
+))]
 pub async fn regenerate_wiki(
     State(state): State<Arc<AppState>>,
     Json(body): Json<RegenerateRequest>,
@@ -866,6 +913,15 @@ pub async fn regenerate_wiki(
 /// (H3 fix: no pre-delete). Shared pages (those that list multiple contributing sources)
 /// are deleted and rebuilt from this source only — run a full regeneration to restore
 /// multi-source synthesis.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/wiki/sources/{filename}/regenerate", tag = "Wiki",
+    params(("filename" = String, Path, description = "Source filename")),
+    request_body(content = Option<RegenerateRequest>, description = "Optional model override"),
+    responses(
+        (status = 200, description = "Pages regenerated from the source", body = Object),
+        (status = 404, description = "Source not found", body = Object),
+    )
+))]
 pub async fn regenerate_wiki_source(
     Path(filename): Path<String>,
     State(state): State<Arc<AppState>>,
@@ -1146,6 +1202,14 @@ pub async fn regenerate_wiki_source(
 /// Text and markdown files are read directly as UTF-8.
 ///
 /// After conversion, the content is fed into the standard wiki ingest pipeline.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/wiki/upload", tag = "Wiki",
+    request_body(content = Object, content_type = "multipart/form-data", description = "File to convert and ingest"),
+    responses(
+        (status = 200, description = "Uploaded file ingested", body = Object),
+        (status = 400, description = "Missing or unsupported file", body = Object),
+    )
+))]
 pub async fn upload_wiki_source(
     State(state): State<Arc<AppState>>,
     mut multipart: Multipart,
@@ -1309,6 +1373,17 @@ pub async fn upload_wiki_source(
 ///
 /// Pages that only belonged to this source are deleted. Pages shared with other sources
 /// are rebuilt from their remaining contributors. User-query pages are never affected.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    delete, path = "/wiki/sources/{filename}", tag = "Wiki",
+    params(
+        ("filename" = String, Path, description = "Source filename"),
+        ("model" = Option<String>, Query, description = "Model used to rebuild shared pages"),
+    ),
+    responses(
+        (status = 200, description = "Source and derived pages removed", body = Object),
+        (status = 404, description = "Source not found", body = Object),
+    )
+))]
 pub async fn delete_wiki_source(
     State(state): State<Arc<AppState>>,
     Path(filename): Path<String>,
@@ -1837,6 +1912,10 @@ fn extract_llm_pages(raw: &str) -> Result<Vec<LlmPage>, String> {
 }
 
 /// POST /wiki/sync — sync compiled wiki pages into the memory store.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/wiki/sync", tag = "Wiki",
+    responses((status = 200, description = "Wiki pages synced into memory", body = Object))
+))]
 pub async fn sync_wiki_to_memory(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     match state
         .wiki
@@ -1855,6 +1934,10 @@ pub async fn sync_wiki_to_memory(State(state): State<Arc<AppState>>) -> impl Int
 }
 
 /// GET /wiki/graph — return wiki knowledge graph (nodes + edges).
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/wiki/graph", tag = "Wiki",
+    responses((status = 200, description = "Page link graph", body = Object))
+))]
 pub async fn get_wiki_graph(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let wiki = Arc::clone(&state.wiki).lock_owned().await;
     let result = tokio::task::spawn_blocking(move || wiki.graph()).await;
@@ -1877,6 +1960,11 @@ pub async fn get_wiki_graph(State(state): State<Arc<AppState>>) -> impl IntoResp
 ///
 /// Reads the current index and all page bodies as context, calls the configured LLM to
 /// synthesize an answer with citations. Optionally saves the answer as a query page.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/wiki/query", tag = "Wiki",
+    request_body = QueryRequest,
+    responses((status = 200, description = "Answer with citations", body = Object))
+))]
 pub async fn query_wiki(
     State(state): State<Arc<AppState>>,
     Json(body): Json<QueryRequest>,
@@ -2049,6 +2137,11 @@ pub async fn query_wiki(
 /// Checks for: broken wikilinks, orphan pages, missing index entries, and pages
 /// without an `updated` frontmatter field. Appends a log entry with the summary.
 /// Accepts optional JSON body `{ "auto_fix": true }` to auto-fix deterministic issues.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/wiki/lint", tag = "Wiki",
+    request_body(content = Option<LintRequest>, description = "Optional auto-fix flag"),
+    responses((status = 200, description = "Lint issues and applied fixes", body = Object))
+))]
 pub async fn lint_wiki(
     State(state): State<Arc<AppState>>,
     body: Option<Json<LintRequest>>,
@@ -2127,6 +2220,10 @@ pub async fn lint_wiki(
 }
 
 /// GET /wiki/prompt — read current INGEST_PROMPT.md content.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/wiki/prompt", tag = "Wiki",
+    responses((status = 200, description = "Current ingest prompt", body = Object))
+))]
 pub async fn get_wiki_prompt(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let wiki = Arc::clone(&state.wiki).lock_owned().await;
     let result = tokio::task::spawn_blocking(move || wiki.read_ingest_prompt()).await;
@@ -2146,6 +2243,11 @@ pub async fn get_wiki_prompt(State(state): State<Arc<AppState>>) -> impl IntoRes
 }
 
 /// PUT /wiki/prompt — write new content to INGEST_PROMPT.md.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    put, path = "/wiki/prompt", tag = "Wiki",
+    request_body = SetPromptRequest,
+    responses((status = 200, description = "Prompt updated", body = Object))
+))]
 pub async fn set_wiki_prompt(
     State(state): State<Arc<AppState>>,
     Json(body): Json<SetPromptRequest>,
@@ -2177,6 +2279,10 @@ pub async fn set_wiki_prompt(
 }
 
 /// DELETE /wiki/sources — delete all source files, clear manifest source records, and remove ingest pages.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    delete, path = "/wiki/sources", tag = "Wiki",
+    responses((status = 200, description = "All sources removed", body = Object))
+))]
 pub async fn delete_all_wiki_sources(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let log_max_lines = state.config.load().wiki_log_max_lines;
     let wiki = Arc::clone(&state.wiki).lock_owned().await;
@@ -2238,6 +2344,14 @@ pub async fn delete_all_wiki_sources(State(state): State<Arc<AppState>>) -> impl
 }
 
 /// DELETE /wiki/pages/{slug} — delete a single wiki page by slug.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    delete, path = "/wiki/pages/{slug}", tag = "Wiki",
+    params(("slug" = String, Path, description = "Page slug")),
+    responses(
+        (status = 200, description = "Page deleted", body = Object),
+        (status = 404, description = "Page not found", body = Object),
+    )
+))]
 pub async fn delete_wiki_page(
     State(state): State<Arc<AppState>>,
     axum::extract::Path(slug): axum::extract::Path<String>,
@@ -2277,6 +2391,10 @@ pub async fn delete_wiki_page(
 }
 
 /// DELETE /wiki/pages — delete all wiki pages and reset index.md.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    delete, path = "/wiki/pages", tag = "Wiki",
+    responses((status = 200, description = "All pages deleted", body = Object))
+))]
 pub async fn delete_wiki_pages(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let wiki = Arc::clone(&state.wiki).lock_owned().await;
     let result = tokio::task::spawn_blocking(move || wiki.delete_all_pages()).await;
@@ -2411,6 +2529,10 @@ fn slugify_question(question: &str) -> String {
 ///
 /// Also probes for an available Python package manager to build a user-friendly install hint.
 /// Checked in order: pipx → pip3 → pip → uv. Falls back to `pip install markitdown[all]`.
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/wiki/converter/status", tag = "Wiki",
+    responses((status = 200, description = "Document converter availability", body = Object))
+))]
 pub async fn get_converter_status(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let cfg = state.config.load();
     let bin = cfg.doc_converter_bin.clone();
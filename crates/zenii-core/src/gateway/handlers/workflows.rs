@@ -23,11 +23,13 @@ fn is_valid_workflow_id(id: &str) -> bool {
 const MAX_DESCRIPTION_BYTES: usize = 4000;
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct GenerateWorkflowRequest {
     pub description: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct GenerateWorkflowResponse {
     pub toml: String,
     pub confidence: String,
@@ -38,6 +40,14 @@ pub struct GenerateWorkflowResponse {
     pub saved: bool,
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/workflows/generate", tag = "Workflows",
+    request_body = GenerateWorkflowRequest,
+    responses(
+        (status = 200, description = "Generated workflow TOML", body = GenerateWorkflowResponse),
+        (status = 400, description = "Description too long", body = Object),
+    )
+))]
 pub async fn generate_workflow(
     State(state): State<Arc<AppState>>,
     Json(req): Json<GenerateWorkflowRequest>,
@@ -103,10 +113,19 @@ pub async fn generate_workflow(
 }
 
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct CreateWorkflowRequest {
     pub toml_content: String,
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/workflows", tag = "Workflows",
+    request_body = CreateWorkflowRequest,
+    responses(
+        (status = 201, description = "Workflow created", body = Object),
+        (status = 400, description = "Invalid workflow TOML", body = Object),
+    )
+))]
 pub async fn create_workflow(
     State(state): State<Arc<AppState>>,
     Json(req): Json<CreateWorkflowRequest>,
@@ -134,6 +153,10 @@ pub async fn create_workflow(
     Ok((StatusCode::CREATED, Json(workflow)))
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/workflows", tag = "Workflows",
+    responses((status = 200, description = "All workflows", body = Object))
+))]
 pub async fn list_workflows(State(state): State<Arc<AppState>>) -> Result<impl IntoResponse> {
     let registry = state
         .workflow_registry
@@ -145,6 +168,14 @@ pub async fn list_workflows(State(state): State<Arc<AppState>>) -> Result<impl I
     Ok(Json(workflows))
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/workflows/{id}", tag = "Workflows",
+    params(("id" = String, Path, description = "Workflow ID")),
+    responses(
+        (status = 200, description = "Workflow definition", body = Object),
+        (status = 404, description = "Workflow not found", body = Object),
+    )
+))]
 pub async fn get_workflow(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -163,6 +194,15 @@ pub async fn get_workflow(
         .ok_or_else(|| ZeniiError::NotFound(format!("workflow '{id}' not found")))
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    put, path = "/workflows/{id}", tag = "Workflows",
+    params(("id" = String, Path, description = "Workflow ID")),
+    request_body = CreateWorkflowRequest,
+    responses(
+        (status = 200, description = "Workflow updated", body = Object),
+        (status = 404, description = "Workflow not found", body = Object),
+    )
+))]
 pub async fn update_workflow(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -210,6 +250,11 @@ pub async fn update_workflow(
     Ok(Json(workflow))
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/workflows/{id}/raw", tag = "Workflows",
+    params(("id" = String, Path, description = "Workflow ID")),
+    responses((status = 200, description = "Workflow TOML source", content_type = "text/plain", body = String))
+))]
 pub async fn get_workflow_raw(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -227,6 +272,14 @@ pub async fn get_workflow_raw(
         .ok_or_else(|| ZeniiError::NotFound(format!("workflow '{id}' not found")))
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    delete, path = "/workflows/{id}", tag = "Workflows",
+    params(("id" = String, Path, description = "Workflow ID")),
+    responses(
+        (status = 204, description = "Workflow deleted"),
+        (status = 404, description = "Workflow not found", body = Object),
+    )
+))]
 pub async fn delete_workflow(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -256,6 +309,12 @@ pub async fn delete_workflow(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/workflows/{id}/run", tag = "Workflows",
+    params(("id" = String, Path, description = "Workflow ID")),
+    responses((status = 202, description = "Run started", body = Object),
+        (status = 404, description = "Workflow not found", body = Object),)
+))]
 pub async fn run_workflow(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -332,6 +391,14 @@ pub async fn run_workflow(
     ))
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    post, path = "/workflows/{id}/runs/{run_id}/cancel", tag = "Workflows",
+    params(("id" = String, Path, description = "Workflow ID"), ("run_id" = String, Path, description = "Run ID")),
+    responses(
+        (status = 204, description = "Run cancelled"),
+        (status = 404, description = "Run not active", body = Object),
+    )
+))]
 pub async fn cancel_workflow_run(
     State(state): State<Arc<AppState>>,
     Path((workflow_id, run_id)): Path<(String, String)>,
@@ -365,6 +432,11 @@ pub async fn cancel_workflow_run(
     }
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/workflows/{id}/history", tag = "Workflows",
+    params(("id" = String, Path, description = "Workflow ID")),
+    responses((status = 200, description = "Past runs", body = Object))
+))]
 pub async fn workflow_history(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -392,6 +464,14 @@ pub async fn workflow_history(
     Ok(Json(history))
 }
 
+#[cfg_attr(feature = "api-docs", utoipa::path(
+    get, path = "/workflows/{id}/runs/{run_id}", tag = "Workflows",
+    params(("id" = String, Path, description = "Workflow ID"), ("run_id" = String, Path, description = "Run ID")),
+    responses(
+        (status = 200, description = "Run with step results", body = Object),
+        (status = 404, description = "Run not found", body = Object),
+    )
+))]
 pub async fn get_run_details(
     State(state): State<Arc<AppState>>,
     Path((workflow_id, run_id)): Path<(String, String)>,
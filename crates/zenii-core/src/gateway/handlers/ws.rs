@@ -18,8 +18,13 @@ struct WsRequest {
     prompt: String,
     session_id: Option<String>,
     model: Option<String>,
+    provider: Option<String>,
     #[serde(default)]
     delegation: Option<bool>,
+    /// Client-chosen id under which the stream can be cancelled, either by a
+    /// `{"type":"cancel"}` frame on this socket or `POST /chat/streams/{id}/cancel`.
+    #[serde(default)]
+    stream_id: Option<String>,
 }
 
 /// Tagged enum for all outbound WebSocket messages.
@@ -28,6 +33,9 @@ struct WsRequest {
 pub(crate) enum WsOutbound {
     #[serde(rename = "text")]
     Text { content: String },
+    /// Incremental assistant text; the final `text` message carries the full answer.
+    #[serde(rename = "token")]
+    Token { delta: String },
     #[serde(rename = "tool_call")]
     ToolCall {
         call_id: String,
@@ -41,6 +49,9 @@ pub(crate) enum WsOutbound {
         output: String,
         success: bool,
         duration_ms: u64,
+        /// Set when `output` was truncated; fetch the full text via `GET /tools/results/{id}`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        full_output_id: Option<String>,
     },
     #[serde(rename = "notification")]
     Notification {
@@ -121,6 +132,33 @@ pub(crate) enum WsOutbound {
     },
     #[serde(rename = "channel_agent_completed")]
     ChannelAgentCompleted { channel: String, session_id: String },
+    #[serde(rename = "agent_error")]
+    AgentError {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        session_id: Option<String>,
+        message: String,
+        recoverable: bool,
+    },
+    #[serde(rename = "model_failover")]
+    ModelFailover {
+        failed_model: String,
+        next_model: String,
+        error: String,
+    },
+    #[serde(rename = "context_truncated")]
+    ContextTruncated {
+        model: String,
+        dropped_messages: usize,
+        estimated_tokens: usize,
+        context_limit: usize,
+    },
+    #[serde(rename = "tool_failure_loop")]
+    ToolFailureLoop {
+        tool_name: String,
+        failures: u32,
+        error: String,
+        aborted: bool,
+    },
     #[serde(rename = "approval_request")]
     ApprovalRequest {
         approval_id: String,
@@ -154,7 +192,18 @@ pub(crate) enum WsOutbound {
     #[serde(rename = "data_changed")]
     DataChanged { domain: String },
     #[serde(rename = "done")]
-    Done,
+    Done {
+        /// Token usage for the completed turn, when the agent reported it.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        usage: Option<crate::ai::agent::TokenUsage>,
+    },
+    /// Terminal message of a cancelled stream; `content` is the text generated so far.
+    #[serde(rename = "cancelled")]
+    Cancelled {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        stream_id: Option<String>,
+        content: String,
+    },
     #[serde(rename = "warning")]
     Warning { warning: String },
     #[serde(rename = "error")]
@@ -307,7 +356,39 @@ async fn handle_notifications(mut socket: WebSocket, state: Arc<AppState>) {
                             break;
                         }
                     }
-                    Ok(crate::event_bus::AppEvent::ApprovalRequested { approval_id, call_id, tool_name, args_summary, risk_level, reason, timeout_secs }) => {
+                    Ok(crate::event_bus::AppEvent::AgentError { session_id, message, recoverable }) => {
+                        let outbound = WsOutbound::AgentError { session_id, message, recoverable };
+                        if let Ok(json) = serde_json::to_string(&outbound)
+                            && socket.send(Message::Text(json.into())).await.is_err()
+                        {
+                            break;
+                        }
+                    }
+                    Ok(crate::event_bus::AppEvent::ModelFailover { failed_model, next_model, error }) => {
+                        let outbound = WsOutbound::ModelFailover { failed_model, next_model, error };
+                        if let Ok(json) = serde_json::to_string(&outbound)
+                            && socket.send(Message::Text(json.into())).await.is_err()
+                        {
+                            break;
+                        }
+                    }
+                    Ok(crate::event_bus::AppEvent::ContextTruncated { model, dropped_messages, estimated_tokens, context_limit }) => {
+                        let outbound = WsOutbound::ContextTruncated { model, dropped_messages, estimated_tokens, context_limit };
+                        if let Ok(json) = serde_json::to_string(&outbound)
+                            && socket.send(Message::Text(json.into())).await.is_err()
+                        {
+                            break;
+                        }
+                    }
+                    Ok(crate::event_bus::AppEvent::ToolFailureLoop { tool_name, failures, error, aborted }) => {
+                        let outbound = WsOutbound::ToolFailureLoop { tool_name, failures, error, aborted };
+                        if let Ok(json) = serde_json::to_string(&outbound)
+                            && socket.send(Message::Text(json.into())).await.is_err()
+                        {
+                            break;
+                        }
+                    }
+                    Ok(crate::event_bus::AppEvent::ApprovalRequested { approval_id, call_id, tool_name, args_summary, risk_level, reason, timeout_secs, .. }) => {
                         let outbound = WsOutbound::ApprovalRequest { approval_id, call_id, tool_name, args_summary, risk_level, reason, timeout_secs };
                         if let Ok(json) = serde_json::to_string(&outbound)
                             && socket.send(Message::Text(json.into())).await.is_err()
@@ -378,7 +459,7 @@ async fn handle_notifications(mut socket: WebSocket, state: Arc<AppState>) {
                             break;
                         }
                     }
-                    Ok(crate::event_bus::AppEvent::MemoryChanged) => {
+                    Ok(crate::event_bus::AppEvent::MemoryChanged | crate::event_bus::AppEvent::MemoryStored { .. }) => {
                         send_outbound(&mut socket, &WsOutbound::DataChanged { domain: "memory".into() }).await;
                     }
                     Ok(crate::event_bus::AppEvent::ConfigUpdated) => {
@@ -387,10 +468,10 @@ async fn handle_notifications(mut socket: WebSocket, state: Arc<AppState>) {
                     Ok(crate::event_bus::AppEvent::SchedulerJobsChanged) => {
                         send_outbound(&mut socket, &WsOutbound::DataChanged { domain: "scheduler".into() }).await;
                     }
-                    Ok(crate::event_bus::AppEvent::CredentialsChanged) => {
+                    Ok(crate::event_bus::AppEvent::CredentialsChanged | crate::event_bus::AppEvent::OAuthCompleted { .. }) => {
                         send_outbound(&mut socket, &WsOutbound::DataChanged { domain: "credentials".into() }).await;
                     }
-                    Ok(crate::event_bus::AppEvent::ProvidersChanged) => {
+                    Ok(crate::event_bus::AppEvent::ProvidersChanged | crate::event_bus::AppEvent::ProviderReloaded { .. }) => {
                         send_outbound(&mut socket, &WsOutbound::DataChanged { domain: "providers".into() }).await;
                     }
                     Ok(crate::event_bus::AppEvent::SkillsChanged) => {
@@ -449,6 +530,30 @@ async fn send_outbound(socket: &mut WebSocket, msg: &WsOutbound) {
     }
 }
 
+/// Send a streamed text delta to the client and publish it on the event bus.
+async fn forward_token(
+    socket: &mut WebSocket,
+    state: &AppState,
+    session_id: Option<&str>,
+    delta: String,
+) {
+    send_outbound(
+        socket,
+        &WsOutbound::Token {
+            delta: delta.clone(),
+        },
+    )
+    .await;
+    if let Some(sid) = session_id {
+        let _ = state
+            .event_bus
+            .publish(crate::event_bus::AppEvent::AgentToken {
+                session_id: sid.to_string(),
+                delta,
+            });
+    }
+}
+
 async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
     while let Some(Ok(msg)) = socket.recv().await {
         let text = match msg {
@@ -465,6 +570,21 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
             }
         };
 
+        let requested_model = match super::sessions::resolve_session_model(
+            &state,
+            request.session_id.as_deref(),
+            request.model.as_deref(),
+            request.provider.as_deref(),
+        )
+        .await
+        {
+            Ok(m) => m,
+            Err(e) => {
+                send_outbound(&mut socket, &ws_error_from_zenii(&e)).await;
+                continue;
+            }
+        };
+
         // Build context parts via ContextBuilder
         let (history, _memories, _user_obs) = match state
             .context_builder
@@ -492,7 +612,7 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
 
         // Assemble preamble via PromptStrategy
         let config = state.config.load_full();
-        let model_display = request.model.as_deref().unwrap_or("default");
+        let model_display = requested_model.as_deref().unwrap_or("default");
         let assembly_request = AssemblyRequest {
             boot_context: state.boot_context.clone(),
             model_display: model_display.into(),
@@ -519,6 +639,17 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
             &request.prompt[..request.prompt.len().min(80)]
         );
 
+        if let Err(e) = state.budget.check(request.session_id.as_deref()).await {
+            let _ = state
+                .event_bus
+                .publish(crate::event_bus::AppEvent::agent_error(
+                    request.session_id.as_deref(),
+                    &e,
+                ));
+            send_outbound(&mut socket, &ws_error_from_zenii(&e)).await;
+            continue;
+        }
+
         // Delegation path: decompose into sub-agents with progress tracking
         if request.delegation == Some(true) {
             handle_delegation(
@@ -531,11 +662,22 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
             continue;
         }
 
+        let stream_guard = match request.stream_id.as_deref() {
+            Some(id) => match state.stream_cancels.register(id) {
+                Ok(guard) => Some(guard),
+                Err(e) => {
+                    send_outbound(&mut socket, &ws_error_from_zenii(&e)).await;
+                    continue;
+                }
+            },
+            None => None,
+        };
+
         // Create per-request broadcast channel for tool events
         let (tool_tx, mut tool_rx) = broadcast::channel::<ToolCallEvent>(128);
 
         let agent = match resolve_agent(
-            request.model.as_deref(),
+            requested_model.as_deref(),
             &state,
             Some(tool_tx),
             Some(&merged_preamble),
@@ -545,6 +687,12 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
         {
             Ok(a) => a,
             Err(e) => {
+                let _ = state
+                    .event_bus
+                    .publish(crate::event_bus::AppEvent::agent_error(
+                        request.session_id.as_deref(),
+                        &e,
+                    ));
                 send_outbound(&mut socket, &ws_error_from_zenii(&e)).await;
                 continue;
             }
@@ -553,16 +701,34 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
         // Note: user message is stored by the frontend via POST /sessions/{id}/messages
         // before the WS stream starts. Do not duplicate here.
 
+        if let Some(ref sid) = request.session_id {
+            let _ = state
+                .event_bus
+                .publish(crate::event_bus::AppEvent::AgentStarted {
+                    session_id: sid.clone(),
+                    surface: "desktop".into(),
+                });
+        }
+
         // Spawn agent work in background with reasoning engine
         let prompt = request.prompt.clone();
         let reasoning_engine = state.reasoning_engine.clone();
         let (result_tx, mut result_rx) = tokio::sync::oneshot::channel();
         let chat_start = std::time::Instant::now();
         let agent_timeout_secs = state.config.load().agent_timeout_secs;
+        let pricing_agent = Arc::clone(&agent);
+        let (token_tx, mut token_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+        let audit_session = request.session_id.clone();
         let agent_handle: JoinHandle<()> = tokio::spawn(async move {
-            let timeout_result = tokio::time::timeout(
-                std::time::Duration::from_secs(agent_timeout_secs),
-                reasoning_engine.chat(&agent, &prompt, history),
+            let on_token = move |delta: &str| {
+                let _ = token_tx.send(delta.to_string());
+            };
+            let timeout_result = crate::security::audit::session_scope(
+                audit_session,
+                tokio::time::timeout(
+                    std::time::Duration::from_secs(agent_timeout_secs),
+                    reasoning_engine.chat_streaming(&agent, &prompt, history, &on_token),
+                ),
             )
             .await;
             let result = match timeout_result {
@@ -576,6 +742,8 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
 
         // Collect tool events for DB persistence
         let mut tool_events = Vec::new();
+        // Text streamed so far, kept if the stream is cancelled
+        let mut partial = String::new();
 
         // Concurrently forward tool events, wait for agent result, and detect client disconnect
         loop {
@@ -589,21 +757,44 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                             break;
                         }
                         Some(Ok(Message::Text(text))) => {
+                            let val = serde_json::from_str::<serde_json::Value>(&text).ok();
+                            let msg_type = val.as_ref().and_then(|v| v.get("type")).and_then(|v| v.as_str());
+                            if msg_type == Some("cancel") {
+                                info!("WS: client cancelled the stream");
+                                agent_handle.abort();
+                                finish_cancelled(&mut socket, &state, request.session_id.as_deref(), request.stream_id.clone(), partial, &mut token_rx, chat_start).await;
+                                break;
+                            }
                             // Handle approval_response messages from the client
-                            if let Ok(val) = serde_json::from_str::<serde_json::Value>(&text)
-                                && val.get("type").and_then(|v| v.as_str()) == Some("approval_response")
+                            if msg_type == Some("approval_response")
+                                && let Some(val) = &val
                             {
                                 let approval_id = val.get("approval_id").and_then(|v| v.as_str()).unwrap_or("");
                                 let decision_str = val.get("decision").and_then(|v| v.as_str()).unwrap_or("deny");
                                 let decision = crate::security::approval::ApprovalDecision::from_str_lossy(decision_str);
                                 if let Some(ref broker) = state.approval_broker {
-                                    broker.resolve(approval_id, decision);
+                                    broker.resolve(approval_id, decision, "ws");
                                 }
                             }
                         }
                         _ => {} // Ignore other messages during execution
                     }
                 }
+                Some(delta) = token_rx.recv() => {
+                    partial.push_str(&delta);
+                    forward_token(&mut socket, &state, request.session_id.as_deref(), delta).await;
+                }
+                () = async {
+                    match &stream_guard {
+                        Some(guard) => guard.cancelled().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    info!("WS: stream cancelled via cancel map");
+                    agent_handle.abort();
+                    finish_cancelled(&mut socket, &state, request.session_id.as_deref(), request.stream_id.clone(), partial, &mut token_rx, chat_start).await;
+                    break;
+                }
                 event = tool_rx.recv() => {
                     match event {
                         Ok(evt) => {
@@ -613,12 +804,13 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                                     tool_name: evt.tool_name.clone(),
                                     args: args.clone(),
                                 },
-                                ToolCallPhase::Completed { output, success, duration_ms } => WsOutbound::ToolResult {
+                                ToolCallPhase::Completed { output, success, duration_ms, full_output_id } => WsOutbound::ToolResult {
                                     call_id: evt.call_id.clone(),
                                     tool_name: evt.tool_name.clone(),
                                     output: output.clone(),
                                     success: *success,
                                     duration_ms: *duration_ms,
+                                    full_output_id: full_output_id.clone(),
                                 },
                                 ToolCallPhase::Cached { output, success } => WsOutbound::ToolResult {
                                     call_id: evt.call_id.clone(),
@@ -626,8 +818,9 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                                     output: output.clone(),
                                     success: *success,
                                     duration_ms: 0,
+                                    full_output_id: None,
                                 },
-                                ToolCallPhase::ApprovalRequested { approval_id, reason, risk_level, timeout_secs } => {
+                                ToolCallPhase::ApprovalRequested { approval_id, reason, risk_level, timeout_secs, .. } => {
                                     let args_summary = evt.tool_name.clone();
                                     WsOutbound::ApprovalRequest {
                                         approval_id: approval_id.clone(),
@@ -639,7 +832,7 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                                         timeout_secs: *timeout_secs,
                                     }
                                 }
-                                ToolCallPhase::ApprovalResolved { approval_id, decision } => {
+                                ToolCallPhase::ApprovalResolved { approval_id, decision, .. } => {
                                     WsOutbound::ApprovalResolved {
                                         approval_id: approval_id.clone(),
                                         decision: decision.clone(),
@@ -648,6 +841,11 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                                 }
                             };
                             send_outbound(&mut socket, &outbound).await;
+                            if let Some(ref sid) = request.session_id
+                                && let Some(app_event) = evt.to_app_event(sid)
+                            {
+                                let _ = state.event_bus.publish(app_event);
+                            }
                             tool_events.push(evt);
                         }
                         Err(broadcast::error::RecvError::Closed) => {
@@ -662,6 +860,9 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                     }
                 }
                 result = &mut result_rx => {
+                    while let Ok(delta) = token_rx.try_recv() {
+                        forward_token(&mut socket, &state, request.session_id.as_deref(), delta).await;
+                    }
                     // Drain any remaining tool events that arrived before/during result
                     while let Ok(evt) = tool_rx.try_recv() {
                         let outbound = match &evt.phase {
@@ -670,12 +871,13 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                                 tool_name: evt.tool_name.clone(),
                                 args: args.clone(),
                             },
-                            ToolCallPhase::Completed { output, success, duration_ms } => WsOutbound::ToolResult {
+                            ToolCallPhase::Completed { output, success, duration_ms, full_output_id } => WsOutbound::ToolResult {
                                 call_id: evt.call_id.clone(),
                                 tool_name: evt.tool_name.clone(),
                                 output: output.clone(),
                                 success: *success,
                                 duration_ms: *duration_ms,
+                                full_output_id: full_output_id.clone(),
                             },
                             ToolCallPhase::Cached { output, success } => WsOutbound::ToolResult {
                                 call_id: evt.call_id.clone(),
@@ -683,8 +885,9 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                                 output: output.clone(),
                                 success: *success,
                                 duration_ms: 0,
+                                full_output_id: None,
                             },
-                            ToolCallPhase::ApprovalRequested { approval_id, reason, risk_level, timeout_secs } => {
+                            ToolCallPhase::ApprovalRequested { approval_id, reason, risk_level, timeout_secs, .. } => {
                                 WsOutbound::ApprovalRequest {
                                     approval_id: approval_id.clone(),
                                     call_id: evt.call_id.clone(),
@@ -695,7 +898,7 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                                     timeout_secs: *timeout_secs,
                                 }
                             }
-                            ToolCallPhase::ApprovalResolved { approval_id, decision } => {
+                            ToolCallPhase::ApprovalResolved { approval_id, decision, .. } => {
                                 WsOutbound::ApprovalResolved {
                                     approval_id: approval_id.clone(),
                                     decision: decision.clone(),
@@ -704,6 +907,11 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                             }
                         };
                         send_outbound(&mut socket, &outbound).await;
+                        if let Some(ref sid) = request.session_id
+                            && let Some(app_event) = evt.to_app_event(sid)
+                        {
+                            let _ = state.event_bus.publish(app_event);
+                        }
                         tool_events.push(evt);
                     }
 
@@ -712,6 +920,19 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                             let duration_ms = chat_start.elapsed().as_millis() as u64;
                             let response = chat_result.response;
                             send_outbound(&mut socket, &WsOutbound::Text { content: response.clone() }).await;
+                            let estimated_cost_usd = pricing_agent.estimate_cost(&chat_result.usage);
+                            if let Some(ref sid) = request.session_id {
+                                let _ = state.event_bus.publish(crate::event_bus::AppEvent::AgentCompleted {
+                                    session_id: sid.clone(),
+                                    duration_ms,
+                                    note: None,
+                                });
+                                let _ = state.event_bus.publish(crate::event_bus::AppEvent::AgentUsage {
+                                    session_id: sid.clone(),
+                                    total_tokens: chat_result.usage.reported_total(),
+                                    cost_usd: estimated_cost_usd,
+                                });
+                            }
 
                             // Log usage
                             let record = crate::logging::UsageRecord {
@@ -728,6 +949,7 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                                 context_level: "Full".into(),
                                 binary: state.usage_logger.binary_name().to_string(),
                                 success: true,
+                                estimated_cost_usd,
                             };
                             let logger = state.usage_logger.clone();
                             tokio::spawn(async move {
@@ -789,11 +1011,28 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
                                     .context_builder
                                     .extract_facts(&request.prompt, &response, Some(sid))
                                     .await;
+
+                                crate::ai::memory_writeback::spawn_write_back(&state, sid, &request.prompt, &response);
                             }
 
-                            send_outbound(&mut socket, &WsOutbound::Done).await;
+                            send_outbound(&mut socket, &WsOutbound::Done {
+                                usage: Some(chat_result.usage),
+                            }).await;
                         }
                         Ok(Err(e)) => {
+                            let _ = state.event_bus.publish(crate::event_bus::AppEvent::agent_error(
+                                request.session_id.as_deref(),
+                                &e,
+                            ));
+                            if let Some(ref sid) = request.session_id
+                                && let Some(event) = crate::event_bus::AppEvent::budget_exceeded(
+                                    sid,
+                                    chat_start.elapsed().as_millis() as u64,
+                                    &e,
+                                )
+                            {
+                                let _ = state.event_bus.publish(event);
+                            }
                             send_outbound(&mut socket, &ws_error_from_zenii(&e)).await;
                         }
                         Err(_) => {
@@ -807,6 +1046,57 @@ async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
     }
 }
 
+/// End a cancelled stream: keep the text generated so far in the session and
+/// send the terminal `cancelled` message so the client can finalize it.
+async fn finish_cancelled(
+    socket: &mut WebSocket,
+    state: &AppState,
+    session_id: Option<&str>,
+    stream_id: Option<String>,
+    mut partial: String,
+    token_rx: &mut tokio::sync::mpsc::UnboundedReceiver<String>,
+    chat_start: std::time::Instant,
+) {
+    while let Ok(delta) = token_rx.try_recv() {
+        partial.push_str(&delta);
+    }
+    if let Some(sid) = session_id {
+        if !partial.is_empty() {
+            match state
+                .session_manager
+                .append_message(sid, "assistant", &partial)
+                .await
+            {
+                Ok(m) => {
+                    let _ = state
+                        .event_bus
+                        .publish(crate::event_bus::AppEvent::MessageAdded {
+                            session_id: sid.to_string(),
+                            message_id: m.id,
+                            role: "assistant".into(),
+                        });
+                }
+                Err(e) => warn!("WS: failed to store partial response for session={sid}: {e}"),
+            }
+        }
+        let _ = state
+            .event_bus
+            .publish(crate::event_bus::AppEvent::AgentCompleted {
+                session_id: sid.to_string(),
+                duration_ms: chat_start.elapsed().as_millis() as u64,
+                note: Some("cancelled".into()),
+            });
+    }
+    send_outbound(
+        socket,
+        &WsOutbound::Cancelled {
+            stream_id,
+            content: partial,
+        },
+    )
+    .await;
+}
+
 /// Handle a delegation request: decompose into sub-agents, stream progress events.
 async fn handle_delegation(
     socket: &mut WebSocket,
@@ -999,7 +1289,7 @@ async fn handle_delegation(
                             }
                         }
 
-                        send_outbound(socket, &WsOutbound::Done).await;
+                        send_outbound(socket, &WsOutbound::Done { usage: None }).await;
                     }
                     Ok(Err(e)) => {
                         send_outbound(socket, &ws_error_from_zenii(&e)).await;
@@ -1195,6 +1485,7 @@ mod tests {
             output: "results".into(),
             success: true,
             duration_ms: 150,
+            full_output_id: None,
         };
         let json = serde_json::to_value(&msg).unwrap();
         assert_eq!(json["type"], "tool_result");
@@ -1203,14 +1494,29 @@ mod tests {
         assert_eq!(json["output"], "results");
         assert_eq!(json["success"], true);
         assert_eq!(json["duration_ms"], 150);
+        assert!(json.get("full_output_id").is_none());
     }
 
     // TV.14 — WsOutbound::Done serializes to {"type":"done"}
     #[test]
     fn ws_outbound_done_serializes() {
-        let msg = WsOutbound::Done;
+        let msg = WsOutbound::Done { usage: None };
         let json = serde_json::to_value(&msg).unwrap();
         assert_eq!(json["type"], "done");
+        assert!(json.get("usage").is_none());
+    }
+
+    // TV.14a — WsOutbound::Cancelled carries the partial text
+    #[test]
+    fn ws_outbound_cancelled_serializes() {
+        let msg = WsOutbound::Cancelled {
+            stream_id: Some("st-1".into()),
+            content: "Partial ans".into(),
+        };
+        let json = serde_json::to_value(&msg).unwrap();
+        assert_eq!(json["type"], "cancelled");
+        assert_eq!(json["stream_id"], "st-1");
+        assert_eq!(json["content"], "Partial ans");
     }
 
     // TC-I1 — Cached phase maps to ToolResult with duration_ms=0
@@ -1232,6 +1538,7 @@ mod tests {
                 output: output.clone(),
                 success: *success,
                 duration_ms: 0,
+                full_output_id: None,
             },
             _ => unreachable!(),
         };
@@ -1433,6 +1740,49 @@ mod tests {
         assert_eq!(json["session_id"], "sess-2");
     }
 
+    // TA.5b — WsOutbound::AgentError serializes with session link
+    #[test]
+    fn ws_outbound_agent_error_serializes() {
+        let msg = WsOutbound::AgentError {
+            session_id: Some("sess-3".into()),
+            message: "agent error: upstream timeout".into(),
+            recoverable: true,
+        };
+        let json = serde_json::to_value(&msg).unwrap();
+        assert_eq!(json["type"], "agent_error");
+        assert_eq!(json["session_id"], "sess-3");
+        assert_eq!(json["recoverable"], true);
+    }
+
+    #[test]
+    fn ws_outbound_context_truncated_serializes() {
+        let msg = WsOutbound::ContextTruncated {
+            model: "openai:gpt-4o".into(),
+            dropped_messages: 6,
+            estimated_tokens: 120_000,
+            context_limit: 128_000,
+        };
+        let json = serde_json::to_value(&msg).unwrap();
+        assert_eq!(json["type"], "context_truncated");
+        assert_eq!(json["dropped_messages"], 6);
+        assert_eq!(json["context_limit"], 128_000);
+    }
+
+    #[test]
+    fn ws_outbound_tool_failure_loop_serializes() {
+        let msg = WsOutbound::ToolFailureLoop {
+            tool_name: "shell".into(),
+            failures: 3,
+            error: "permission denied".into(),
+            aborted: false,
+        };
+        let json = serde_json::to_value(&msg).unwrap();
+        assert_eq!(json["type"], "tool_failure_loop");
+        assert_eq!(json["tool_name"], "shell");
+        assert_eq!(json["failures"], 3);
+        assert_eq!(json["aborted"], false);
+    }
+
     // TA.6 — WsOutbound::ApprovalRequest serializes correctly
     #[test]
     fn ws_outbound_approval_request_serializes() {
@@ -1,75 +1,346 @@
-use axum::extract::{Request, State};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use arc_swap::ArcSwap;
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{HeaderValue, Method, header};
 use axum::middleware::Next;
-use axum::response::Response;
+use axum::response::{IntoResponse, Response};
+use parking_lot::Mutex;
 use percent_encoding::percent_decode_str;
-use subtle::ConstantTimeEq;
 
+use super::auth::{GatewayAuth, Principal, TokenScope};
 use crate::ZeniiError;
+use crate::config::AppConfig;
 
-/// Constant-time string comparison to prevent timing side-channel attacks on token validation.
-fn constant_time_eq(a: &str, b: &str) -> bool {
-    // Length comparison is inherently variable-time, but we need it
-    // for ct_eq which requires equal-length slices. The length itself
-    // doesn't leak the token value.
-    a.len() == b.len() && a.as_bytes().ct_eq(b.as_bytes()).into()
+/// Requests that skip auth (and rate limiting): GET /health, /api-docs/*,
+/// GET /setup/status, GET /oauth/callback, the WhatsApp webhook and Slack
+/// interactivity.
+fn is_public(request: &Request) -> bool {
+    let path = request.uri().path();
+    let get = request.method() == Method::GET;
+    get && path == "/health"
+        || path.starts_with("/api-docs")
+        || get && path == "/setup/status"
+        || get && path == "/oauth/callback"
+        || path == "/channels/whatsapp/webhook"
+        || path == "/channels/slack/interactions"
 }
 
 /// Bearer token authentication middleware.
 ///
-/// If `auth_token` state is `None`, all requests pass through (no auth configured).
-/// If `auth_token` is `Some(token)`, validates the `Authorization: Bearer <token>` header.
-/// Skips auth for `GET /health`.
+/// If no token is configured (`gateway_auth_token` unset and no scoped tokens),
+/// all requests pass through. Otherwise validates the `Authorization: Bearer <token>`
+/// header and checks the token's scope against [`TokenScope::required_for`],
+/// returning `403` when it is too narrow.
+/// Skips auth for `GET /health` and `GET /oauth/callback` (guarded by its single-use `state`),
+/// and for `/channels/whatsapp/webhook` (guarded by its verify token and body signature)
+/// and `/channels/slack/interactions` (guarded by the Slack request signature).
 /// For WebSocket endpoints (paths starting with `/ws`), also accepts `?token=<token>` query param.
+/// Authenticated requests carry their [`Principal`] as an extension.
 pub async fn auth_middleware(
-    State(auth_token): State<Option<String>>,
-    request: Request,
+    State(auth): State<Arc<GatewayAuth>>,
+    mut request: Request,
     next: Next,
 ) -> Result<Response, ZeniiError> {
-    let auth_token = match auth_token {
-        Some(token) => token,
-        None => return Ok(next.run(request).await),
-    };
+    if !auth.enabled() || is_public(&request) {
+        return Ok(next.run(request).await);
+    }
 
-    // Skip auth for GET /health, /api-docs/*, and GET /setup/status
-    if request.method() == axum::http::Method::GET && request.uri().path() == "/health"
-        || request.uri().path().starts_with("/api-docs")
-        || request.method() == axum::http::Method::GET && request.uri().path() == "/setup/status"
+    let principal = if let Some(header_value) = request.headers().get(header::AUTHORIZATION) {
+        header_value
+            .to_str()
+            .ok()
+            .and_then(|h| h.strip_prefix("Bearer "))
+            .and_then(|token| auth.identify(token))
+            .ok_or_else(|| ZeniiError::Auth("invalid bearer token".into()))?
+    } else if request.uri().path().starts_with("/ws")
+        && let Some(token) = query_token(&request)
     {
+        auth.identify(&token)
+            .ok_or_else(|| ZeniiError::Auth("invalid query token".into()))?
+    } else {
+        return Err(ZeniiError::Auth("missing authorization".into()));
+    };
+
+    let scope = principal.scope;
+    let required = TokenScope::required_for(request.method(), request.uri().path());
+    if scope < required {
+        return Err(ZeniiError::PolicyDenied(format!(
+            "token scope '{scope}' cannot access {} {}; requires '{required}'",
+            request.method(),
+            request.uri().path()
+        )));
+    }
+    request.extensions_mut().insert(principal);
+    Ok(next.run(request).await)
+}
+
+/// The `?token=` query parameter, percent-decoded.
+fn query_token(request: &Request) -> Option<String> {
+    request.uri().query()?.split('&').find_map(|pair| {
+        pair.strip_prefix("token=")
+            .map(|raw| percent_decode_str(raw).decode_utf8_lossy().into_owned())
+    })
+}
+
+/// Reject WebSocket upgrades from browser origins not in `gateway_cors_origins`.
+///
+/// Browsers do not apply CORS to WebSocket handshakes, so without this check any
+/// web page could open `/ws/*` on a local gateway. Requests without an `Origin`
+/// header (CLI, TUI, native clients) and same-origin pages are always allowed;
+/// `["*"]` allows every origin.
+pub async fn ws_origin_middleware(
+    State(config): State<Arc<ArcSwap<AppConfig>>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ZeniiError> {
+    if !request.uri().path().starts_with("/ws") {
         return Ok(next.run(request).await);
     }
+    let Some(origin) = request.headers().get(header::ORIGIN) else {
+        return Ok(next.run(request).await);
+    };
+    let origin = origin.to_str().unwrap_or_default();
+
+    let same_origin = request
+        .headers()
+        .get(header::HOST)
+        .and_then(|h| h.to_str().ok())
+        .is_some_and(|host| {
+            origin
+                .split_once("://")
+                .is_some_and(|(_, rest)| rest == host)
+        });
+    let allowed = same_origin
+        || config
+            .load()
+            .gateway_cors_origins
+            .iter()
+            .any(|o| o == "*" || o == origin);
+    if !allowed {
+        return Err(ZeniiError::PolicyDenied(format!(
+            "WebSocket origin not allowed: {origin}"
+        )));
+    }
+    Ok(next.run(request).await)
+}
 
-    // Check Authorization header
-    if let Some(header_value) = request.headers().get(axum::http::header::AUTHORIZATION) {
-        if let Ok(header_str) = header_value.to_str()
-            && let Some(bearer_token) = header_str.strip_prefix("Bearer ")
-            && constant_time_eq(bearer_token, &auth_token)
-        {
-            return Ok(next.run(request).await);
+/// Request classes with separate per-client rate limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RouteClass {
+    /// `POST /sessions` and WebSocket upgrades.
+    Create,
+    /// Any other mutating request.
+    Write,
+    /// `GET`, `HEAD` and `OPTIONS`.
+    Read,
+}
+
+impl RouteClass {
+    pub fn of(method: &Method, path: &str) -> Self {
+        if path.starts_with("/ws") || method == Method::POST && path == "/sessions" {
+            Self::Create
+        } else if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+            Self::Read
+        } else {
+            Self::Write
         }
-        return Err(ZeniiError::Auth("invalid bearer token".into()));
     }
 
-    // For WS endpoints, check ?token= query param
-    if request.uri().path().starts_with("/ws")
-        && let Some(query) = request.uri().query()
-    {
-        for pair in query.split('&') {
-            if let Some(raw_value) = pair.strip_prefix("token=") {
-                let value = percent_decode_str(raw_value).decode_utf8_lossy();
-                if constant_time_eq(&value, &auth_token) {
-                    return Ok(next.run(request).await);
-                }
-                return Err(ZeniiError::Auth("invalid query token".into()));
-            }
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Write => "write",
+            Self::Read => "read",
+        }
+    }
+}
+
+/// Requests per minute allowed for each [`RouteClass`], per client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GatewayRateLimits {
+    pub create_per_min: u32,
+    pub write_per_min: u32,
+    pub read_per_min: u32,
+}
+
+impl GatewayRateLimits {
+    /// Limits from config, or `None` when gateway rate limiting is disabled.
+    pub fn from_config(config: &AppConfig) -> Option<Self> {
+        config.gateway_rate_limit_enabled.then_some(Self {
+            create_per_min: config.gateway_rate_limit_create_per_min,
+            write_per_min: config.gateway_rate_limit_write_per_min,
+            read_per_min: config.gateway_rate_limit_read_per_min,
+        })
+    }
+
+    pub fn per_min(&self, class: RouteClass) -> u32 {
+        match class {
+            RouteClass::Create => self.create_per_min,
+            RouteClass::Write => self.write_per_min,
+            RouteClass::Read => self.read_per_min,
+        }
+    }
+}
+
+/// Token bucket holding up to one minute's worth of requests.
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn full(per_min: u32, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(per_min),
+            updated: now,
         }
     }
 
-    Err(ZeniiError::Auth("missing authorization".into()))
+    /// Take one token, or return how long until one is available.
+    fn take(&mut self, per_min: u32, now: Instant) -> Result<(), Duration> {
+        let capacity = f64::from(per_min);
+        let per_sec = capacity / 60.0;
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * per_sec).min(capacity);
+        self.updated = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / per_sec))
+        }
+    }
+}
+
+/// A bucket idle this long has refilled completely, so dropping it changes nothing.
+const BUCKET_IDLE: Duration = Duration::from_secs(60);
+
+/// Upper bound on tracked buckets; past it the least recently used are dropped.
+const MAX_BUCKETS: usize = 10_000;
+
+struct Buckets {
+    map: HashMap<(String, RouteClass), TokenBucket>,
+    swept: Instant,
+}
+
+impl Buckets {
+    /// Drop buckets idle for [`BUCKET_IDLE`], at most once per interval, and
+    /// the least recently used ones while over [`MAX_BUCKETS`].
+    fn evict(&mut self, now: Instant) {
+        if now.saturating_duration_since(self.swept) >= BUCKET_IDLE {
+            self.map
+                .retain(|_, b| now.saturating_duration_since(b.updated) < BUCKET_IDLE);
+            self.swept = now;
+        }
+        if self.map.len() >= MAX_BUCKETS {
+            let mut updated: Vec<Instant> = self.map.values().map(|b| b.updated).collect();
+            let excess = self.map.len() + 1 - MAX_BUCKETS;
+            updated.select_nth_unstable(excess - 1);
+            let cutoff = updated[excess - 1];
+            self.map.retain(|_, b| b.updated > cutoff);
+        }
+    }
+}
+
+/// Per-client, per-route-class request limiter for the gateway. Limits are read
+/// from the live config on every request, so changes apply without a restart.
+pub struct GatewayRateLimiter {
+    config: Arc<ArcSwap<AppConfig>>,
+    buckets: Mutex<Buckets>,
+}
+
+impl GatewayRateLimiter {
+    pub fn new(config: Arc<ArcSwap<AppConfig>>) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(Buckets {
+                map: HashMap::new(),
+                swept: Instant::now(),
+            }),
+        }
+    }
+
+    /// Record a request from `key`; on rejection returns the limit and how long
+    /// the caller should wait.
+    pub fn check(&self, key: &str, class: RouteClass) -> Result<(), (u32, Duration)> {
+        let Some(limits) = GatewayRateLimits::from_config(&self.config.load()) else {
+            return Ok(());
+        };
+        let per_min = limits.per_min(class);
+        self.take(key, class, per_min, Instant::now())
+            .map_err(|wait| (per_min, wait))
+    }
+
+    fn take(
+        &self,
+        key: &str,
+        class: RouteClass,
+        per_min: u32,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock();
+        let bucket_key = (key.to_string(), class);
+        if !buckets.map.contains_key(&bucket_key) {
+            buckets.evict(now);
+        }
+        buckets
+            .map
+            .entry(bucket_key)
+            .or_insert_with(|| TokenBucket::full(per_min, now))
+            .take(per_min, now)
+    }
+}
+
+/// Bucket key for a request: the authenticated [`Principal`], else the peer IP,
+/// else a shared anonymous bucket. Never the raw token string, so rotating
+/// garbage tokens does not mint fresh buckets.
+fn client_key(request: &Request) -> String {
+    if let Some(principal) = request.extensions().get::<Principal>() {
+        return format!("token:{}", principal.id);
+    }
+    match request.extensions().get::<ConnectInfo<SocketAddr>>() {
+        Some(ConnectInfo(addr)) => format!("ip:{}", addr.ip()),
+        None => "anonymous".into(),
+    }
+}
+
+/// Rate limiting middleware. Runs after auth, so requests are keyed on the
+/// caller's identity.
+/// Rejected requests get `429` with a `Retry-After` header in whole seconds.
+pub async fn rate_limit_middleware(
+    State(limiter): State<Arc<GatewayRateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if is_public(&request) {
+        return next.run(request).await;
+    }
+    let class = RouteClass::of(request.method(), request.uri().path());
+    match limiter.check(&client_key(&request), class) {
+        Ok(()) => next.run(request).await,
+        Err((per_min, wait)) => {
+            let retry_after = wait.as_secs_f64().ceil().max(1.0) as u64;
+            let mut response = ZeniiError::RateLimited(format!(
+                "gateway {} limit of {per_min} requests per minute exceeded",
+                class.as_str()
+            ))
+            .into_response();
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+            response
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gateway::auth::TokenStore;
     use axum::Router;
     use axum::body::Body;
     use axum::http::{Request as HttpRequest, StatusCode};
@@ -82,15 +353,93 @@ mod tests {
     }
 
     fn app_with_auth(token: Option<String>) -> Router {
+        app_with_scoped_auth(token, Arc::new(TokenStore::in_memory()))
+    }
+
+    fn app_with_scoped_auth(token: Option<String>, tokens: Arc<TokenStore>) -> Router {
         Router::new()
             .route("/health", get(ok_handler))
             .route("/api/test", get(ok_handler))
             .route("/ws/chat", get(ok_handler))
+            .route(
+                "/channels/whatsapp/webhook",
+                get(ok_handler).post(ok_handler),
+            )
+            .route(
+                "/channels/slack/interactions",
+                axum::routing::post(ok_handler),
+            )
+            .route("/sessions", get(ok_handler).post(ok_handler))
+            .route("/config", axum::routing::put(ok_handler))
             .layer(middleware::from_fn_with_state(
-                token.clone(),
+                Arc::new(GatewayAuth::new(
+                    Arc::new(ArcSwap::from_pointee(AppConfig {
+                        gateway_auth_token: token,
+                        ..Default::default()
+                    })),
+                    tokens,
+                )),
                 auth_middleware,
             ))
-            .with_state(token)
+    }
+
+    // Scoped tokens: read can list but not create, agent can create but not configure
+    #[tokio::test]
+    async fn token_scope_is_enforced() {
+        let tokens = Arc::new(TokenStore::in_memory());
+        let (_, read) = tokens.mint("monitor", TokenScope::Read).await.unwrap();
+        let (_, agent) = tokens.mint("bot", TokenScope::Agent).await.unwrap();
+        let app = app_with_scoped_auth(Some("admin-secret".into()), tokens);
+        let status = |method: &str, uri: &str, token: &str| {
+            let app = app.clone();
+            let req = request(method, uri, Some(token));
+            async move { app.oneshot(req).await.unwrap().status() }
+        };
+
+        assert_eq!(status("GET", "/sessions", &read).await, StatusCode::OK);
+        assert_eq!(
+            status("POST", "/sessions", &read).await,
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(status("POST", "/sessions", &agent).await, StatusCode::OK);
+        assert_eq!(
+            status("PUT", "/config", &agent).await,
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(
+            status("PUT", "/config", "admin-secret").await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status("GET", "/ws/chat", &read).await,
+            StatusCode::FORBIDDEN
+        );
+    }
+
+    // An admin token turns auth on even without gateway_auth_token
+    #[tokio::test]
+    async fn admin_token_enables_auth() {
+        let tokens = Arc::new(TokenStore::in_memory());
+        let app = app_with_scoped_auth(None, tokens.clone());
+        let resp = app
+            .clone()
+            .oneshot(request("GET", "/sessions", None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let (_, admin) = tokens.mint("ops", TokenScope::Admin).await.unwrap();
+        let resp = app
+            .clone()
+            .oneshot(request("GET", "/sessions", None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let resp = app
+            .oneshot(request("PUT", "/config", Some(&admin)))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
     }
 
     #[tokio::test]
@@ -155,6 +504,29 @@ mod tests {
         assert_eq!(resp.status(), StatusCode::OK);
     }
 
+    // The WhatsApp webhook and Slack interactivity authenticate with their own signatures
+    #[tokio::test]
+    async fn signed_webhooks_bypass_auth() {
+        let app = app_with_auth(Some("secret123".into()));
+        let req = HttpRequest::builder()
+            .method("POST")
+            .uri("/channels/whatsapp/webhook")
+            .body(Body::empty())
+            .expect("build request");
+
+        let resp = app.oneshot(req).await.expect("response");
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let app = app_with_auth(Some("secret123".into()));
+        let req = HttpRequest::builder()
+            .method("POST")
+            .uri("/channels/slack/interactions")
+            .body(Body::empty())
+            .expect("build request");
+        let resp = app.oneshot(req).await.expect("response");
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
     #[tokio::test]
     async fn ws_token_in_query_param() {
         let app = app_with_auth(Some("secret123".into()));
@@ -166,4 +538,255 @@ mod tests {
         let resp = app.oneshot(req).await.expect("response");
         assert_eq!(resp.status(), StatusCode::OK);
     }
+
+    /// Rate-limited app behind the real auth middleware. Returns the app and two
+    /// minted token secrets; with `auth` false no tokens exist and auth is off.
+    async fn app_with_rate_limit(create_per_min: u32, auth: bool) -> (Router, String, String) {
+        let config = AppConfig {
+            gateway_rate_limit_create_per_min: create_per_min,
+            ..Default::default()
+        };
+        let limiter = Arc::new(GatewayRateLimiter::new(Arc::new(ArcSwap::from_pointee(
+            config,
+        ))));
+        let tokens = Arc::new(TokenStore::in_memory());
+        let (a, b) = if auth {
+            (
+                tokens.mint("a", TokenScope::Admin).await.unwrap().1,
+                tokens.mint("b", TokenScope::Admin).await.unwrap().1,
+            )
+        } else {
+            (String::new(), String::new())
+        };
+        let app = Router::new()
+            .route("/health", get(ok_handler))
+            .route("/sessions", get(ok_handler).post(ok_handler))
+            .route("/ws/chat", get(ok_handler))
+            .layer(middleware::from_fn_with_state(
+                limiter,
+                rate_limit_middleware,
+            ))
+            .layer(middleware::from_fn_with_state(
+                Arc::new(GatewayAuth::new(
+                    Arc::new(ArcSwap::from_pointee(AppConfig::default())),
+                    tokens,
+                )),
+                auth_middleware,
+            ));
+        (app, a, b)
+    }
+
+    fn request(method: &str, uri: &str, token: Option<&str>) -> HttpRequest<Body> {
+        let mut builder = HttpRequest::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            builder = builder.header("Authorization", format!("Bearer {token}"));
+        }
+        builder.body(Body::empty()).expect("build request")
+    }
+
+    fn from_peer(mut request: HttpRequest<Body>, ip: &str) -> HttpRequest<Body> {
+        let addr: SocketAddr = format!("{ip}:4000").parse().unwrap();
+        request.extensions_mut().insert(ConnectInfo(addr));
+        request
+    }
+
+    #[test]
+    fn route_class_of_request() {
+        assert_eq!(
+            RouteClass::of(&Method::POST, "/sessions"),
+            RouteClass::Create
+        );
+        assert_eq!(RouteClass::of(&Method::GET, "/ws/chat"), RouteClass::Create);
+        assert_eq!(RouteClass::of(&Method::GET, "/sessions"), RouteClass::Read);
+        assert_eq!(
+            RouteClass::of(&Method::DELETE, "/sessions/1"),
+            RouteClass::Write
+        );
+    }
+
+    #[test]
+    fn token_bucket_refills_over_time() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::full(60, start);
+        for _ in 0..60 {
+            assert!(bucket.take(60, start).is_ok());
+        }
+        let wait = bucket.take(60, start).unwrap_err();
+        assert!((wait.as_secs_f64() - 1.0).abs() < 0.01, "{wait:?}");
+        assert!(bucket.take(60, start + Duration::from_secs(1)).is_ok());
+    }
+
+    // Session creation beyond the limit gets 429 + Retry-After; other tokens are unaffected
+    #[tokio::test]
+    async fn create_limit_returns_429_with_retry_after() {
+        let (app, a, b) = app_with_rate_limit(2, true).await;
+        for _ in 0..2 {
+            let resp = app
+                .clone()
+                .oneshot(request("POST", "/sessions", Some(&a)))
+                .await
+                .unwrap();
+            assert_eq!(resp.status(), StatusCode::OK);
+        }
+        let resp = app
+            .clone()
+            .oneshot(request("POST", "/sessions", Some(&a)))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(resp.headers()[header::RETRY_AFTER], "30");
+
+        // Reads use their own bucket, other tokens their own buckets
+        let resp = app
+            .clone()
+            .oneshot(request("GET", "/sessions", Some(&a)))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = app
+            .clone()
+            .oneshot(request("POST", "/sessions", Some(&b)))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = app.oneshot(request("GET", "/health", None)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    // WebSocket upgrades (token in the query) share the creation bucket
+    #[tokio::test]
+    async fn ws_upgrades_count_against_create_bucket() {
+        let (app, a, _) = app_with_rate_limit(1, true).await;
+        let resp = app
+            .clone()
+            .oneshot(request("POST", "/sessions", Some(&a)))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = app
+            .oneshot(request("GET", &format!("/ws/chat?token={a}"), None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    // Without auth, rotating token strings does not get fresh buckets; peers are keyed by IP
+    #[tokio::test]
+    async fn unauthenticated_requests_keyed_on_peer_ip() {
+        let (app, _, _) = app_with_rate_limit(1, false).await;
+        let resp = app
+            .clone()
+            .oneshot(from_peer(
+                request("POST", "/sessions", Some("junk-1")),
+                "10.0.0.1",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = app
+            .clone()
+            .oneshot(from_peer(
+                request("POST", "/sessions", Some("junk-2")),
+                "10.0.0.1",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        let resp = app
+            .oneshot(from_peer(request("POST", "/sessions", None), "10.0.0.2"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    // Idle buckets are swept, and the map never grows past MAX_BUCKETS
+    #[test]
+    fn idle_buckets_are_evicted() {
+        let limiter =
+            GatewayRateLimiter::new(Arc::new(ArcSwap::from_pointee(AppConfig::default())));
+        let start = Instant::now();
+        for i in 0..MAX_BUCKETS + 10 {
+            let now = start + Duration::from_millis(i as u64);
+            limiter
+                .take(&format!("ip:{i}"), RouteClass::Read, 60, now)
+                .unwrap();
+        }
+        assert_eq!(limiter.buckets.lock().map.len(), MAX_BUCKETS);
+        // The oldest keys went first
+        assert!(
+            !limiter
+                .buckets
+                .lock()
+                .map
+                .contains_key(&("ip:0".to_string(), RouteClass::Read))
+        );
+
+        let later = start + Duration::from_secs(120);
+        limiter.take("ip:new", RouteClass::Read, 60, later).unwrap();
+        assert_eq!(limiter.buckets.lock().map.len(), 1);
+    }
+
+    fn app_with_ws_origins(origins: &[&str]) -> Router {
+        let config = AppConfig {
+            gateway_cors_origins: origins.iter().map(|o| o.to_string()).collect(),
+            ..Default::default()
+        };
+        Router::new()
+            .route("/ws/chat", get(ok_handler))
+            .route("/api/test", get(ok_handler))
+            .layer(middleware::from_fn_with_state(
+                Arc::new(ArcSwap::from_pointee(config)),
+                ws_origin_middleware,
+            ))
+    }
+
+    fn with_origin(uri: &str, origin: Option<&str>) -> HttpRequest<Body> {
+        let mut builder = HttpRequest::builder()
+            .uri(uri)
+            .header("host", "127.0.0.1:18981");
+        if let Some(origin) = origin {
+            builder = builder.header("origin", origin);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    // WebSocket upgrades from unlisted browser origins are refused
+    #[tokio::test]
+    async fn ws_origin_must_be_allowed() {
+        let app = app_with_ws_origins(&["http://localhost:5173"]);
+        let status = |req: HttpRequest<Body>| {
+            let app = app.clone();
+            async move { app.oneshot(req).await.unwrap().status() }
+        };
+
+        assert_eq!(
+            status(with_origin("/ws/chat", Some("https://evil.example"))).await,
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(
+            status(with_origin("/ws/chat", Some("http://localhost:5173"))).await,
+            StatusCode::OK
+        );
+        // Same origin as the gateway, no Origin header, and non-WS routes pass
+        assert_eq!(
+            status(with_origin("/ws/chat", Some("http://127.0.0.1:18981"))).await,
+            StatusCode::OK
+        );
+        assert_eq!(status(with_origin("/ws/chat", None)).await, StatusCode::OK);
+        assert_eq!(
+            status(with_origin("/api/test", Some("https://evil.example"))).await,
+            StatusCode::OK
+        );
+    }
+
+    // `*` allows WebSocket upgrades from any origin
+    #[tokio::test]
+    async fn ws_origin_wildcard_allows_all() {
+        let app = app_with_ws_origins(&["*"]);
+        let resp = app
+            .oneshot(with_origin("/ws/chat", Some("https://anywhere.example")))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
 }
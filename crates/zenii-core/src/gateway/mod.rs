@@ -1,3 +1,4 @@
+pub mod auth;
 pub mod errors;
 pub mod handlers;
 pub mod middleware;
@@ -11,10 +12,13 @@ use std::sync::Arc;
 use tokio::net::TcpListener;
 use tracing::{info, warn};
 
+use crate::plugins::health::PluginHealthState;
 use crate::{Result, ZeniiError};
 use state::AppState;
 
 const WIKI_MAINTENANCE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(6 * 60 * 60);
+/// How often a paused plugin health loop checks whether probing was re-enabled.
+const PLUGIN_HEALTH_IDLE_RECHECK: std::time::Duration = std::time::Duration::from_secs(60);
 
 /// The gateway HTTP+WS server.
 pub struct GatewayServer {
@@ -51,11 +55,16 @@ impl GatewayServer {
         }
 
         tokio::spawn(wiki_maintenance_loop(Arc::clone(&self.state)));
+        tokio::spawn(plugin_health_loop(Arc::clone(&self.state)));
 
-        axum::serve(listener, router)
-            .with_graceful_shutdown(shutdown)
-            .await
-            .map_err(|e| ZeniiError::Gateway(format!("server error: {e}")))?;
+        // Peer addresses key the rate limiter for unauthenticated callers
+        axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+        )
+        .with_graceful_shutdown(shutdown)
+        .await
+        .map_err(|e| ZeniiError::Gateway(format!("server error: {e}")))?;
 
         info!("Gateway shut down cleanly");
         Ok(())
@@ -97,6 +106,53 @@ async fn wiki_maintenance_loop(state: Arc<AppState>) {
     }
 }
 
+/// Pings running plugin processes every `plugin_health_check_interval_secs`
+/// and publishes [`AppEvent::PluginHealthChanged`](crate::event_bus::AppEvent)
+/// when a plugin turns healthy or unhealthy. Settings are re-read each round,
+/// so an interval of 0 pauses probing until it is raised again.
+async fn plugin_health_loop(state: Arc<AppState>) {
+    loop {
+        let (interval, timeout) = {
+            let config = state.config.load();
+            (
+                config.plugin_health_check_interval_secs,
+                config.plugin_health_check_timeout_secs,
+            )
+        };
+        if interval == 0 {
+            tokio::time::sleep(PLUGIN_HEALTH_IDLE_RECHECK).await;
+            continue;
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+
+        let timeout = std::time::Duration::from_secs(timeout.max(1));
+        for (plugin, health) in state.plugin_installer.probe_health(timeout).await {
+            let state_name = match health.state {
+                PluginHealthState::Healthy => "healthy",
+                PluginHealthState::Unhealthy => "unhealthy",
+                PluginHealthState::Unknown => "unknown",
+            };
+            if health.state == PluginHealthState::Unhealthy {
+                warn!(
+                    "Plugin '{plugin}' is unhealthy after {} failed probes: {}",
+                    health.consecutive_failures,
+                    health.last_error.as_deref().unwrap_or("unknown error")
+                );
+            } else {
+                info!("Plugin '{plugin}' is {state_name}");
+            }
+            let _ = state
+                .event_bus
+                .publish(crate::event_bus::AppEvent::PluginHealthChanged {
+                    plugin,
+                    state: state_name.into(),
+                    consecutive_failures: health.consecutive_failures,
+                    error: health.last_error,
+                });
+        }
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "ai")]
 mod tests {
@@ -22,18 +22,29 @@ use super::state::AppState;
         // System
         handlers::health::health,
         handlers::system::system_info,
+        handlers::system::runtime_info,
+        handlers::system::lifecycle_history,
+        handlers::system::activity,
+        handlers::system::start_event_recording,
+        handlers::system::stop_event_recording,
+        handlers::system::db_backup,
+        handlers::system::db_restore,
         // Sessions
         handlers::sessions::create_session,
         handlers::sessions::list_sessions,
         handlers::sessions::get_session,
+        handlers::sessions::stream_session,
         handlers::sessions::update_session,
         handlers::sessions::delete_session,
         handlers::sessions::generate_title,
         // Messages
         handlers::messages::get_messages,
         handlers::messages::send_message,
+        handlers::messages::delete_messages_from,
         // Chat
         handlers::chat::chat,
+        handlers::chat::oneshot,
+        handlers::chat::cancel_stream,
         // Memory
         handlers::memory::create_memory,
         handlers::memory::recall_memories,
@@ -51,11 +62,14 @@ use super::state::AppState;
         handlers::credentials::delete_credential,
         handlers::credentials::get_credential_value,
         handlers::credentials::credential_exists,
+        handlers::credentials::start_oauth,
+        handlers::credentials::oauth_callback,
         // Providers
         handlers::providers::list_providers,
         handlers::providers::list_with_key_status,
         handlers::providers::get_default_model,
         handlers::providers::set_default_model,
+        handlers::providers::reload_providers,
         handlers::providers::get_provider,
         handlers::providers::create_user_provider,
         handlers::providers::update_provider,
@@ -66,6 +80,7 @@ use super::state::AppState;
         // Tools
         handlers::tools::list_tools,
         handlers::tools::execute_tool,
+        handlers::tools::get_tool_result,
         // Models
         handlers::models::list_models,
         // Identity
@@ -104,9 +119,55 @@ use super::state::AppState;
         handlers::plugins::install_plugin,
         handlers::plugins::remove_plugin,
         handlers::plugins::toggle_plugin,
+        handlers::plugins::reload_plugins,
+        handlers::plugins::plugins_health,
         handlers::plugins::update_plugin,
         handlers::plugins::get_plugin_config,
         handlers::plugins::update_plugin_config,
+        handlers::plugins::list_available_plugins,
+        // Permissions
+        handlers::permissions::list_surfaces,
+        handlers::permissions::get_permissions,
+        handlers::permissions::set_permission,
+        handlers::permissions::delete_permission,
+        // Approvals
+        handlers::approvals::list_rules,
+        handlers::approvals::delete_rule,
+        handlers::approvals::respond_approval,
+        handlers::approvals::audit_log,
+        // Scoped tokens
+        handlers::tokens::list_tokens,
+        handlers::tokens::create_token,
+        handlers::tokens::revoke_token,
+        // Budget
+        handlers::budget::budget_status,
+        // Delegation
+        handlers::delegation::list_active_agents,
+        handlers::delegation::cancel_agent,
+        handlers::delegation::get_task_diff,
+        // Wiki
+        handlers::wiki::list_wiki_pages,
+        handlers::wiki::search_wiki_pages,
+        handlers::wiki::ingest_wiki_source,
+        handlers::wiki::upload_wiki_source,
+        handlers::wiki::sync_wiki_to_memory,
+        handlers::wiki::get_wiki_graph,
+        handlers::wiki::query_wiki,
+        handlers::wiki::lint_wiki,
+        handlers::wiki::get_wiki_prompt,
+        handlers::wiki::set_wiki_prompt,
+        handlers::wiki::list_wiki_sources,
+        handlers::wiki::delete_all_wiki_sources,
+        handlers::wiki::delete_wiki_source,
+        handlers::wiki::regenerate_wiki_source,
+        handlers::wiki::delete_wiki_pages,
+        handlers::wiki::delete_wiki_page,
+        handlers::wiki::regenerate_wiki,
+        handlers::wiki::get_wiki_dir,
+        handlers::wiki::get_converter_status,
+        handlers::wiki::get_wiki_page,
+        // Discovery
+        handlers::agent_card::agent_card,
         // Channel credential test (always available)
         handlers::channels_test::test_channel_credentials,
         // WebSocket
@@ -123,6 +184,10 @@ use super::state::AppState;
             handlers::messages::MessageWithToolCalls,
             handlers::chat::ChatRequest,
             handlers::chat::ChatResponse,
+            handlers::chat::OneshotRequest,
+            handlers::chat::OneshotResponse,
+            handlers::chat::OneshotUsage,
+            handlers::chat::OneshotToolCall,
             handlers::memory::StoreMemoryRequest,
             handlers::memory::UpdateMemoryRequest,
             handlers::memory::RecallQuery,
@@ -132,8 +197,16 @@ use super::state::AppState;
             handlers::providers::CreateModelEntry,
             handlers::providers::UpdateProviderRequest,
             handlers::providers::AddModelRequest,
+            crate::ai::provider_registry::ModelModality,
+            crate::ai::provider_registry::ProviderExtras,
+            crate::ai::provider_health::ProviderHealth,
             handlers::providers::SetDefaultModelRequest,
+            handlers::system::StartEventRecordingRequest,
+            handlers::system::DbSnapshotRequest,
             handlers::tools::ExecuteToolRequest,
+            crate::tools::ToolInfo,
+            crate::security::RiskLevel,
+            crate::ai::tool_results::StoredToolResult,
             handlers::identity::IdentityListResponse,
             handlers::identity::IdentityFileInfo,
             handlers::identity::IdentityFileResponse,
@@ -149,7 +222,33 @@ use super::state::AppState;
             handlers::embeddings::EmbedTestResult,
             handlers::plugins::PluginListItem,
             handlers::plugins::InstallRequest,
+            crate::plugins::installer::PluginReloadSummary,
+            handlers::plugins::PluginHealthItem,
+            crate::plugins::health::PluginHealth,
+            crate::plugins::health::PluginHealthState,
+            crate::event_bus::lifecycle::StateTransition,
+            crate::event_bus::activity::Activity,
+            crate::event_bus::activity::ActivityPage,
+            crate::event_bus::activity::ActivityStatus,
             handlers::channels_test::ChannelTestResult,
+            handlers::permissions::AllPermissionsResponse,
+            handlers::approvals::ApprovalResponse,
+            crate::gateway::auth::TokenScope,
+            crate::gateway::auth::ApiToken,
+            handlers::tokens::CreateTokenRequest,
+            handlers::tokens::CreatedToken,
+            crate::security::budget::BudgetStatus,
+            crate::security::budget::SpendWindow,
+            handlers::delegation::ActiveAgentsResponse,
+            crate::ai::delegation::workspace_diff::WorkspaceDiff,
+            crate::ai::delegation::workspace_diff::FileChange,
+            crate::ai::delegation::workspace_diff::FileChangeKind,
+            crate::ai::delegation::workspace_diff::WorkspaceDiffSummary,
+            handlers::wiki::IngestRequest,
+            handlers::wiki::QueryRequest,
+            handlers::wiki::LintRequest,
+            handlers::wiki::RegenerateRequest,
+            handlers::wiki::SetPromptRequest,
         )
     ),
     security(("bearer" = [])),
@@ -170,15 +269,38 @@ struct BaseApiDoc;
         handlers::channels::list_channel_messages,
         handlers::channels::webhook_message,
         handlers::channels::health_check,
+        handlers::channels::get_channel_settings,
+        handlers::channels::set_channel_settings,
+        handlers::channels::get_allowlist,
+        handlers::channels::add_allowlist_entry,
+        handlers::channels::remove_allowlist_entry,
     ),
     components(schemas(
+        crate::channels::settings::ChannelSettings,
         handlers::channels::ChannelInfo,
         handlers::channels::ChannelHealthResponse,
         handlers::channels::SendMessageRequest,
+        handlers::channels::AllowlistResponse,
+        handlers::channels::AllowlistEntryRequest,
     ))
 )]
 struct ChannelsApiDoc;
 
+/// WhatsApp webhook spec (feature-gated).
+#[cfg(feature = "channels-whatsapp")]
+#[derive(OpenApi)]
+#[openapi(paths(
+    handlers::channels::whatsapp_verify,
+    handlers::channels::whatsapp_webhook,
+))]
+struct WhatsAppApiDoc;
+
+/// Slack interactivity spec (feature-gated).
+#[cfg(feature = "channels-slack")]
+#[derive(OpenApi)]
+#[openapi(paths(handlers::channels::slack_interactions))]
+struct SlackApiDoc;
+
 /// Scheduler API spec (feature-gated).
 #[cfg(feature = "scheduler")]
 #[derive(OpenApi)]
@@ -186,19 +308,49 @@ struct ChannelsApiDoc;
     paths(
         handlers::scheduler::list_jobs,
         handlers::scheduler::create_job,
+        handlers::scheduler::update_job,
         handlers::scheduler::toggle_job,
         handlers::scheduler::delete_job,
         handlers::scheduler::job_history,
+        handlers::scheduler::cancel_job,
         handlers::scheduler::scheduler_status,
+        handlers::scheduler::export_jobs,
+        handlers::scheduler::import_jobs,
     ),
     components(schemas(
         handlers::scheduler::SchedulerStatusResponse,
         handlers::scheduler::CreateJobResponse,
         handlers::scheduler::ToggleResponse,
+        handlers::scheduler::CancelJobResponse,
     ))
 )]
 struct SchedulerApiDoc;
 
+/// Workflow API spec (feature-gated).
+#[cfg(feature = "workflows")]
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handlers::workflows::generate_workflow,
+        handlers::workflows::create_workflow,
+        handlers::workflows::list_workflows,
+        handlers::workflows::get_workflow,
+        handlers::workflows::update_workflow,
+        handlers::workflows::get_workflow_raw,
+        handlers::workflows::delete_workflow,
+        handlers::workflows::run_workflow,
+        handlers::workflows::cancel_workflow_run,
+        handlers::workflows::workflow_history,
+        handlers::workflows::get_run_details,
+    ),
+    components(schemas(
+        handlers::workflows::GenerateWorkflowRequest,
+        handlers::workflows::GenerateWorkflowResponse,
+        handlers::workflows::CreateWorkflowRequest,
+    ))
+)]
+struct WorkflowsApiDoc;
+
 /// Build the merged OpenAPI spec.
 pub fn build_openapi() -> utoipa::openapi::OpenApi {
     let mut spec = BaseApiDoc::openapi();
@@ -213,9 +365,18 @@ pub fn build_openapi() -> utoipa::openapi::OpenApi {
     #[cfg(feature = "channels")]
     spec.merge(ChannelsApiDoc::openapi());
 
+    #[cfg(feature = "channels-whatsapp")]
+    spec.merge(WhatsAppApiDoc::openapi());
+
+    #[cfg(feature = "channels-slack")]
+    spec.merge(SlackApiDoc::openapi());
+
     #[cfg(feature = "scheduler")]
     spec.merge(SchedulerApiDoc::openapi());
 
+    #[cfg(feature = "workflows")]
+    spec.merge(WorkflowsApiDoc::openapi());
+
     spec
 }
 
@@ -265,6 +426,35 @@ mod tests {
         assert!(paths.paths.contains_key("/identity"));
         assert!(paths.paths.contains_key("/skills"));
         assert!(paths.paths.contains_key("/plugins"));
+        assert!(paths.paths.contains_key("/permissions/{surface}/{tool}"));
+        assert!(paths.paths.contains_key("/approvals/{id}/respond"));
+        assert!(paths.paths.contains_key("/audit-log"));
+        assert!(paths.paths.contains_key("/agents/active"));
+        assert!(paths.paths.contains_key("/wiki"));
+        assert!(paths.paths.contains_key("/wiki/{slug}"));
+        assert!(paths.paths.contains_key("/.well-known/agent.json"));
+    }
+
+    #[cfg(feature = "scheduler")]
+    #[test]
+    fn openapi_spec_includes_job_update() {
+        let spec = build_openapi();
+        let item = spec
+            .paths
+            .paths
+            .get("/scheduler/jobs/{id}")
+            .expect("job path should be documented");
+        assert!(item.put.is_some());
+        assert!(item.delete.is_some());
+    }
+
+    #[cfg(feature = "workflows")]
+    #[test]
+    fn openapi_spec_includes_workflows() {
+        let spec = build_openapi();
+        let paths = spec.paths.paths;
+        assert!(paths.contains_key("/workflows"));
+        assert!(paths.contains_key("/workflows/{id}/runs/{run_id}/cancel"));
     }
 
     #[test]
@@ -329,6 +519,12 @@ mod tests {
             config_write_lock: tokio::sync::Mutex::new(()),
             db: base_state.db.clone(),
             event_bus: base_state.event_bus.clone(),
+            event_recorder: base_state.event_recorder.clone(),
+            oauth_states: base_state.oauth_states.clone(),
+            api_tokens: base_state.api_tokens.clone(),
+            tool_results: base_state.tool_results.clone(),
+            session_streams: base_state.session_streams.clone(),
+            activity: base_state.activity.clone(),
             memory: base_state.memory.clone(),
             credentials: base_state.credentials.clone(),
             security: base_state.security.clone(),
@@ -336,11 +532,14 @@ mod tests {
             session_manager: base_state.session_manager.clone(),
             agent: None,
             provider_registry: base_state.provider_registry.clone(),
+            provider_health: base_state.provider_health.clone(),
+            stream_cancels: base_state.stream_cancels.clone(),
             boot_context: base_state.boot_context.clone(),
             last_used_model: base_state.last_used_model.clone(),
             context_builder: base_state.context_builder.clone(),
             reasoning_engine: base_state.reasoning_engine.clone(),
             prompt_strategy: base_state.prompt_strategy.clone(),
+            transcriber: base_state.transcriber.clone(),
             context_injection_enabled: base_state.context_injection_enabled.clone(),
             self_evolution_enabled: base_state.self_evolution_enabled.clone(),
             soul_loader: base_state.soul_loader.clone(),
@@ -367,6 +566,8 @@ mod tests {
             usage_logger: base_state.usage_logger.clone(),
             embedding_model_available: base_state.embedding_model_available.clone(),
             approval_broker: base_state.approval_broker.clone(),
+            audit: base_state.audit.clone(),
+            budget: base_state.budget.clone(),
             wiki: base_state.wiki.clone(),
             converter: base_state.converter.clone(),
         });
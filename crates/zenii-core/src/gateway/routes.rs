@@ -1,13 +1,17 @@
 use std::sync::Arc;
 
 use axum::Router;
+use axum::http::{HeaderValue, header};
 use axum::middleware;
 use axum::routing::{delete, get, post, put};
 use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::trace::TraceLayer;
 
+use super::auth::GatewayAuth;
 use super::handlers;
-use super::middleware::auth_middleware;
+use super::middleware::{
+    GatewayRateLimiter, auth_middleware, rate_limit_middleware, ws_origin_middleware,
+};
 use super::state::AppState;
 
 /// Build the complete axum Router with all routes, middleware, and state.
@@ -32,6 +36,10 @@ pub fn build_router(state: Arc<AppState>) -> Router {
                 .put(handlers::sessions::update_session)
                 .delete(handlers::sessions::delete_session),
         )
+        .route(
+            "/sessions/{id}/stream",
+            get(handlers::sessions::stream_session),
+        )
         .route(
             "/sessions/{id}/generate-title",
             post(handlers::sessions::generate_title),
@@ -47,6 +55,11 @@ pub fn build_router(state: Arc<AppState>) -> Router {
         )
         // Chat
         .route("/chat", post(handlers::chat::chat))
+        .route("/agent/oneshot", post(handlers::chat::oneshot))
+        .route(
+            "/chat/streams/{id}/cancel",
+            post(handlers::chat::cancel_stream),
+        )
         // Memory
         .route(
             "/memory",
@@ -121,6 +134,15 @@ pub fn build_router(state: Arc<AppState>) -> Router {
             "/credentials/{key}/exists",
             get(handlers::credentials::credential_exists),
         )
+        // OAuth
+        .route(
+            "/oauth/{provider}/start",
+            post(handlers::credentials::start_oauth),
+        )
+        .route(
+            "/oauth/callback",
+            get(handlers::credentials::oauth_callback),
+        )
         // Providers (Phase 8 -- multi-provider)
         .route(
             "/providers",
@@ -135,6 +157,10 @@ pub fn build_router(state: Arc<AppState>) -> Router {
             "/providers/default",
             get(handlers::providers::get_default_model).put(handlers::providers::set_default_model),
         )
+        .route(
+            "/providers/reload",
+            post(handlers::providers::reload_providers),
+        )
         .route(
             "/providers/{id}",
             get(handlers::providers::get_provider)
@@ -156,6 +182,7 @@ pub fn build_router(state: Arc<AppState>) -> Router {
         // Tools
         .route("/tools", get(handlers::tools::list_tools))
         .route("/tools/{name}/execute", post(handlers::tools::execute_tool))
+        .route("/tools/results/{id}", get(handlers::tools::get_tool_result))
         // Permissions (Phase 19)
         .route("/permissions", get(handlers::permissions::list_surfaces))
         .route(
@@ -169,6 +196,22 @@ pub fn build_router(state: Arc<AppState>) -> Router {
         )
         // System info
         .route("/system/info", get(handlers::system::system_info))
+        .route("/runtime", get(handlers::system::runtime_info))
+        .route(
+            "/system/lifecycle",
+            get(handlers::system::lifecycle_history),
+        )
+        .route("/system/activity", get(handlers::system::activity))
+        .route(
+            "/system/events/recording/start",
+            post(handlers::system::start_event_recording),
+        )
+        .route(
+            "/system/events/recording/stop",
+            post(handlers::system::stop_event_recording),
+        )
+        .route("/system/db/backup", post(handlers::system::db_backup))
+        .route("/system/db/restore", post(handlers::system::db_restore))
         // Models
         .route("/models", get(handlers::models::list_models))
         // Identity (Phase 4)
@@ -247,6 +290,8 @@ pub fn build_router(state: Arc<AppState>) -> Router {
         // Plugins (Phase 9)
         .route("/plugins", get(handlers::plugins::list_plugins))
         .route("/plugins/install", post(handlers::plugins::install_plugin))
+        .route("/plugins/reload", post(handlers::plugins::reload_plugins))
+        .route("/plugins/health", get(handlers::plugins::plugins_health))
         .route(
             "/plugins/available",
             get(handlers::plugins::list_available_plugins),
@@ -287,6 +332,10 @@ pub fn build_router(state: Arc<AppState>) -> Router {
             "/agents/{id}/cancel",
             post(handlers::delegation::cancel_agent),
         )
+        .route(
+            "/delegations/{id}/tasks/{task_id}/diff",
+            get(handlers::delegation::get_task_diff),
+        )
         // Approvals
         .route("/approvals/rules", get(handlers::approvals::list_rules))
         .route(
@@ -297,16 +346,38 @@ pub fn build_router(state: Arc<AppState>) -> Router {
             "/approvals/{id}/respond",
             post(handlers::approvals::respond_approval),
         )
+        .route("/audit-log", get(handlers::approvals::audit_log))
+        // Scoped gateway tokens (admin scope)
+        .route(
+            "/auth/tokens",
+            get(handlers::tokens::list_tokens).post(handlers::tokens::create_token),
+        )
+        .route("/auth/tokens/{id}", delete(handlers::tokens::revoke_token))
+        // Spend limits
+        .route("/budget", get(handlers::budget::budget_status))
         // WebSocket
         .route("/ws/chat", get(handlers::ws::ws_chat))
         .route("/ws/notifications", get(handlers::ws::ws_notifications))
         // API Documentation (feature-gated)
         .merge(api_docs_routes())
-        // Auth middleware
+        // Rate limiting (inside auth, so only authenticated requests consume buckets)
         .layer(middleware::from_fn_with_state(
-            state.config.load().gateway_auth_token.clone(),
+            Arc::new(GatewayRateLimiter::new(state.config.clone())),
+            rate_limit_middleware,
+        ))
+        // Auth middleware: legacy config token (admin) plus scoped tokens
+        .layer(middleware::from_fn_with_state(
+            Arc::new(GatewayAuth::new(
+                state.config.clone(),
+                state.api_tokens.clone(),
+            )),
             auth_middleware,
         ))
+        // Browsers skip CORS on WebSocket handshakes; check their origin here
+        .layer(middleware::from_fn_with_state(
+            state.config.clone(),
+            ws_origin_middleware,
+        ))
         // CORS
         .layer(cors)
         // Tracing
@@ -348,10 +419,26 @@ fn channel_routes() -> Router<Arc<AppState>> {
                 "/channels/{name}/health",
                 get(handlers::channels::health_check),
             )
+            .route(
+                "/channels/{name}/settings",
+                get(handlers::channels::get_channel_settings)
+                    .put(handlers::channels::set_channel_settings),
+            )
             .route(
                 "/channels/{name}/message",
                 post(handlers::channels::webhook_message),
             )
+            .route(
+                "/channels/{name}/allowlist",
+                get(handlers::channels::get_allowlist)
+                    .post(handlers::channels::add_allowlist_entry),
+            )
+            .route(
+                "/channels/{name}/allowlist/{entry}",
+                delete(handlers::channels::remove_allowlist_entry),
+            )
+            .merge(whatsapp_routes())
+            .merge(slack_routes())
     }
     #[cfg(not(feature = "channels"))]
     {
@@ -359,6 +446,38 @@ fn channel_routes() -> Router<Arc<AppState>> {
     }
 }
 
+/// Build the WhatsApp webhook routes, conditionally compiled.
+#[cfg(feature = "channels")]
+fn whatsapp_routes() -> Router<Arc<AppState>> {
+    #[cfg(feature = "channels-whatsapp")]
+    {
+        Router::new().route(
+            "/channels/whatsapp/webhook",
+            get(handlers::channels::whatsapp_verify).post(handlers::channels::whatsapp_webhook),
+        )
+    }
+    #[cfg(not(feature = "channels-whatsapp"))]
+    {
+        Router::new()
+    }
+}
+
+/// Build the Slack interactivity route, conditionally compiled.
+#[cfg(feature = "channels")]
+fn slack_routes() -> Router<Arc<AppState>> {
+    #[cfg(feature = "channels-slack")]
+    {
+        Router::new().route(
+            "/channels/slack/interactions",
+            post(handlers::channels::slack_interactions),
+        )
+    }
+    #[cfg(not(feature = "channels-slack"))]
+    {
+        Router::new()
+    }
+}
+
 /// Build scheduler routes, conditionally compiled.
 fn scheduler_routes() -> Router<Arc<AppState>> {
     #[cfg(feature = "scheduler")]
@@ -381,10 +500,16 @@ fn scheduler_routes() -> Router<Arc<AppState>> {
                 "/scheduler/jobs/{id}/history",
                 get(handlers::scheduler::job_history),
             )
+            .route(
+                "/scheduler/jobs/{id}/cancel",
+                post(handlers::scheduler::cancel_job),
+            )
             .route(
                 "/scheduler/status",
                 get(handlers::scheduler::scheduler_status),
             )
+            .route("/scheduler/export", get(handlers::scheduler::export_jobs))
+            .route("/scheduler/import", post(handlers::scheduler::import_jobs))
     }
     #[cfg(not(feature = "scheduler"))]
     {
@@ -450,21 +575,31 @@ fn workflow_routes() -> Router<Arc<AppState>> {
     }
 }
 
+/// Build the CORS layer from `gateway_cors_origins`.
+///
+/// Allowed request headers are listed explicitly: a `*` wildcard does not cover
+/// `Authorization` in browser preflights. Credentials are never allowed; the
+/// gateway authenticates with a bearer token, not cookies.
 fn build_cors(origins: &[String]) -> CorsLayer {
+    let cors = CorsLayer::new()
+        .allow_methods(tower_http::cors::Any)
+        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE, header::ACCEPT])
+        .expose_headers([header::RETRY_AFTER]);
     if origins.iter().any(|o| o == "*") {
-        CorsLayer::permissive()
-    } else if origins.is_empty() {
-        // Empty origins = deny all cross-origin requests (safe default)
-        CorsLayer::new()
-            .allow_origin(AllowOrigin::list(Vec::<axum::http::HeaderValue>::new()))
-            .allow_methods(tower_http::cors::Any)
-            .allow_headers(tower_http::cors::Any)
+        cors.allow_origin(tower_http::cors::Any)
     } else {
-        let origins: Vec<_> = origins.iter().filter_map(|o| o.parse().ok()).collect();
-        CorsLayer::new()
-            .allow_origin(AllowOrigin::list(origins))
-            .allow_methods(tower_http::cors::Any)
-            .allow_headers(tower_http::cors::Any)
+        // Empty origins = deny all cross-origin requests (safe default)
+        let origins: Vec<HeaderValue> = origins
+            .iter()
+            .filter_map(|o| match o.parse() {
+                Ok(value) => Some(value),
+                Err(_) => {
+                    tracing::warn!("Ignoring invalid CORS origin: {o}");
+                    None
+                }
+            })
+            .collect();
+        cors.allow_origin(AllowOrigin::list(origins))
     }
 }
 
@@ -528,4 +663,67 @@ mod tests {
         let resp = app.oneshot(req).await.unwrap();
         assert_eq!(resp.status(), StatusCode::OK);
     }
+
+    // 4.1.4 — Preflight allows the Authorization header for listed origins only
+    #[tokio::test]
+    async fn preflight_allows_authorization_header() {
+        let (_dir, state) = test_state().await;
+        let app = build_router(state);
+        let preflight = |origin: &str| {
+            Request::builder()
+                .method("OPTIONS")
+                .uri("/sessions")
+                .header("origin", origin)
+                .header("access-control-request-method", "POST")
+                .header(
+                    "access-control-request-headers",
+                    "authorization,content-type",
+                )
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let resp = app
+            .clone()
+            .oneshot(preflight("http://localhost:18971"))
+            .await
+            .unwrap();
+        let headers = resp.headers();
+        assert_eq!(
+            headers["access-control-allow-origin"],
+            "http://localhost:18971"
+        );
+        let allowed = headers["access-control-allow-headers"].to_str().unwrap();
+        assert!(allowed.contains("authorization"), "{allowed}");
+        assert!(headers.get("access-control-allow-credentials").is_none());
+
+        let resp = app
+            .oneshot(preflight("https://evil.example"))
+            .await
+            .unwrap();
+        assert!(resp.headers().get("access-control-allow-origin").is_none());
+    }
+
+    // 4.1.5 — `*` allows any origin without credentials
+    #[tokio::test]
+    async fn wildcard_origin_allows_any() {
+        let (_dir, state) = test_state().await;
+        let mut config = (**state.config.load()).clone();
+        config.gateway_cors_origins = vec!["*".into()];
+        state.config.store(Arc::new(config));
+        let app = build_router(state);
+
+        let req = Request::builder()
+            .uri("/health")
+            .header("origin", "https://anywhere.example")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.headers()["access-control-allow-origin"], "*");
+        assert!(
+            resp.headers()
+                .get("access-control-allow-credentials")
+                .is_none()
+        );
+    }
 }
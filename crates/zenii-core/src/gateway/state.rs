@@ -44,6 +44,18 @@ pub struct AppState {
     pub config_write_lock: tokio::sync::Mutex<()>,
     pub db: DbPool,
     pub event_bus: Arc<dyn EventBus>,
+    /// Debug recorder that writes the full event stream to a JSON Lines file.
+    pub event_recorder: Arc<crate::event_bus::recorder::EventRecorder>,
+    /// Pending OAuth authorization requests, keyed by their `state` value.
+    pub oauth_states: Arc<crate::credential::oauth::OAuthStateStore>,
+    /// Scoped gateway tokens; the auth middleware reads them on every request.
+    pub api_tokens: Arc<crate::gateway::auth::TokenStore>,
+    /// Untruncated outputs of tool calls whose results were compressed for the model.
+    pub tool_results: Arc<crate::ai::tool_results::ToolResultStore>,
+    /// Recent agent events per session, for `GET /sessions/{id}/stream` replay.
+    pub session_streams: Arc<crate::event_bus::session_stream::SessionStreamLog>,
+    /// Recent tool calls, one entry per call, for `GET /system/activity`.
+    pub activity: Arc<crate::event_bus::activity::ActivityBuffer>,
     pub memory: Arc<dyn Memory>,
     pub credentials: Arc<dyn CredentialStore>,
     pub security: Arc<SecurityPolicy>,
@@ -54,6 +66,12 @@ pub struct AppState {
     pub agent: Option<Arc<ZeniiAgent>>,
     #[cfg(feature = "ai")]
     pub provider_registry: Arc<ProviderRegistry>,
+    /// Cached results of provider connection tests.
+    #[cfg(feature = "ai")]
+    pub provider_health: Arc<crate::ai::provider_health::ProviderHealthCache>,
+    /// Cancellation flags of running WebSocket chat streams.
+    #[cfg(feature = "ai")]
+    pub stream_cancels: Arc<crate::ai::stream_cancel::StreamCancelMap>,
     #[cfg(feature = "ai")]
     pub boot_context: BootContext,
     #[cfg(feature = "ai")]
@@ -64,6 +82,9 @@ pub struct AppState {
     pub reasoning_engine: Arc<ReasoningEngine>,
     #[cfg(feature = "ai")]
     pub prompt_strategy: Arc<dyn PromptStrategy>,
+    /// Speech-to-text for inbound channel audio. `None` when no transcription model is configured.
+    #[cfg(feature = "ai")]
+    pub transcriber: Option<Arc<dyn crate::ai::transcription::TranscriptionProvider>>,
     /// Runtime toggle: context injection (mutable via PUT /config)
     pub context_injection_enabled: Arc<AtomicBool>,
     /// Runtime toggle: self-evolution / learning (mutable via PUT /config)
@@ -93,6 +114,10 @@ pub struct AppState {
     /// Whether the local embedding model is downloaded and ready.
     pub embedding_model_available: Arc<AtomicBool>,
     pub approval_broker: Option<Arc<crate::security::approval::ApprovalBroker>>,
+    /// Durable audit log writer for tool calls. `None` leaves them unaudited.
+    pub audit: Option<crate::security::audit::AuditSink>,
+    /// Spend ledger and `budget_*_usd` cap enforcement.
+    pub budget: Arc<crate::security::budget::BudgetTracker>,
     pub wiki: Arc<tokio::sync::Mutex<crate::wiki::WikiManager>>,
     pub converter: std::sync::Arc<dyn crate::wiki::convert::DocumentConverter>,
 }
@@ -117,6 +142,55 @@ impl AppState {
         }
     }
 
+    /// Swap in a new runtime config, apply the parts that take effect live
+    /// (runtime toggles, security autonomy) and publish `ConfigUpdated` so
+    /// subscribers such as the scheduler re-read it.
+    pub fn apply_config(&self, config: AppConfig) {
+        use std::sync::atomic::Ordering;
+
+        self.context_injection_enabled
+            .store(config.context_injection_enabled, Ordering::Relaxed);
+        self.self_evolution_enabled
+            .store(config.self_evolution_enabled, Ordering::Relaxed);
+        self.security
+            .set_autonomy_level(crate::security::policy::AutonomyLevel::from_str_lossy(
+                &config.security_autonomy_level,
+            ));
+        self.security
+            .set_tool_autonomy(crate::security::policy::tool_autonomy_from_config(&config));
+
+        #[cfg(feature = "channels")]
+        self.channel_registry.allowlists().sync_from_config(&config);
+
+        // Swap the runtime config so all readers see the update immediately
+        self.config.store(Arc::new(config));
+
+        let _ = self
+            .event_bus
+            .publish(crate::event_bus::AppEvent::ConfigUpdated);
+    }
+
+    /// Watch `config_path` for external edits and apply them without a restart.
+    /// Disabled when `config_watch_interval_secs` is 0.
+    pub fn watch_config(self: &Arc<Self>) {
+        let interval_secs = self.config.load().config_watch_interval_secs;
+        if interval_secs == 0 {
+            return;
+        }
+        let state = Arc::downgrade(self);
+        crate::config::watcher::watch_config(
+            self.config_path.clone(),
+            std::time::Duration::from_secs(interval_secs),
+            self.config.clone(),
+            move |config| {
+                if let Some(state) = state.upgrade() {
+                    state.apply_config(config);
+                }
+            },
+        );
+        tracing::info!("Watching {} for config changes", self.config_path.display());
+    }
+
     /// Wire channels: start the router and begin listen loops for connected channels.
     /// Call this after constructing Arc<AppState>.
     #[cfg(feature = "channels")]
@@ -147,6 +221,63 @@ impl AppState {
             tracing::info!("Channel router wired with AppState");
         }
     }
+
+    /// Drain in-flight work before exit. Stops the scheduler so no new jobs
+    /// start, waits up to `timeout` for active workflow runs, then aborts the
+    /// rest and records them as failed so no run is left `running` in the DB.
+    pub async fn drain(&self, timeout: std::time::Duration) {
+        #[cfg(feature = "scheduler")]
+        if let Some(ref scheduler) = self.scheduler {
+            crate::scheduler::traits::Scheduler::stop(scheduler.as_ref()).await;
+        }
+        #[cfg(feature = "workflows")]
+        self.drain_workflow_runs(timeout).await;
+        #[cfg(not(feature = "workflows"))]
+        let _ = timeout;
+    }
+
+    #[cfg(feature = "workflows")]
+    async fn drain_workflow_runs(&self, timeout: std::time::Duration) {
+        if self.active_workflow_runs.is_empty() {
+            return;
+        }
+        tracing::info!(
+            "Waiting up to {}s for {} workflow run(s) to finish",
+            timeout.as_secs(),
+            self.active_workflow_runs.len()
+        );
+        let deadline = tokio::time::Instant::now() + timeout;
+        while !self.active_workflow_runs.is_empty() && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+
+        let remaining: Vec<String> = self
+            .active_workflow_runs
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect();
+        for run_id in remaining {
+            let Some((_, handle)) = self.active_workflow_runs.remove(&run_id) else {
+                continue;
+            };
+            handle.abort();
+            tracing::warn!("Aborted workflow run {run_id} at shutdown");
+            if let Some(ref executor) = self.workflow_executor {
+                let completed_at = chrono::Utc::now().to_rfc3339();
+                if let Err(e) = executor
+                    .persist_run_end(
+                        &run_id,
+                        "failed",
+                        Some(crate::workflows::executor::INTERRUPTED_RUN_ERROR),
+                        &completed_at,
+                    )
+                    .await
+                {
+                    tracing::warn!("Failed to record aborted workflow run {run_id}: {e}");
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -189,6 +320,34 @@ mod tests {
         assert!(agent.is_ok(), "Should resolve using last_used_model");
     }
 
+    // 15.3.35b — no provider at all is reported distinctly from a broken provider
+    #[tokio::test]
+    async fn resolve_agent_without_provider_is_not_configured() {
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        assert!(state.agent.is_none());
+        // Drop the seeded default model so nothing is configured
+        crate::db::with_db(&state.db, |conn| {
+            conn.execute("DELETE FROM ai_models WHERE id = '_default_model'", [])?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let err = crate::ai::resolve_agent(None, &state, None, None, "telegram")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, crate::ZeniiError::ProviderNotConfigured(_)),
+            "got {err:?}"
+        );
+
+        // A configured provider with a missing key is a different failure
+        let err = crate::ai::resolve_agent(Some("openai:gpt-4o"), &state, None, None, "telegram")
+            .await
+            .unwrap_err();
+        assert!(!matches!(err, crate::ZeniiError::ProviderNotConfigured(_)));
+    }
+
     // 15.3.36 — resolve_agent explicit model overrides last_used
     #[tokio::test]
     async fn resolve_agent_explicit_overrides_last_used() {
@@ -236,4 +395,41 @@ mod tests {
         let last = state.last_used_model.read().await;
         assert_eq!(last.as_deref(), Some("openai:gpt-4o"));
     }
+
+    // Drain aborts workflow runs that outlive the timeout and fails their rows
+    #[cfg(feature = "workflows")]
+    #[tokio::test]
+    async fn drain_aborts_and_fails_lingering_workflow_runs() {
+        use crate::workflows::definition::WorkflowRunStatus;
+
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let mut state =
+            Arc::try_unwrap(state).unwrap_or_else(|_| panic!("test_state must return unique Arc"));
+        let executor = Arc::new(crate::workflows::executor::WorkflowExecutor::new(
+            state.db.clone(),
+            50,
+            300,
+            0,
+        ));
+        state.workflow_executor = Some(executor.clone());
+        executor
+            .persist_run_start("r1", "wf", "Wf", &chrono::Utc::now().to_rfc3339())
+            .await
+            .unwrap();
+        let task = tokio::spawn(tokio::time::sleep(std::time::Duration::from_secs(60)));
+        state
+            .active_workflow_runs
+            .insert("r1".into(), task.abort_handle());
+
+        state.drain(std::time::Duration::from_millis(50)).await;
+
+        assert!(state.active_workflow_runs.is_empty());
+        assert!(task.await.unwrap_err().is_cancelled());
+        let run = executor.get_run("r1").await.unwrap().unwrap();
+        assert_eq!(run.status, WorkflowRunStatus::Failed);
+        assert_eq!(
+            run.error.as_deref(),
+            Some(crate::workflows::executor::INTERRUPTED_RUN_ERROR)
+        );
+    }
 }
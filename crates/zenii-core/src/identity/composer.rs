@@ -2,67 +2,135 @@ use crate::config::AppConfig;
 
 use super::types::Identity;
 
+/// Prompt sections in their default order. `identity_prompt_sections` picks a
+/// subset and order; an empty list uses this one.
+pub const PROMPT_SECTIONS: &[&str] = &[
+    "soul",
+    "identity",
+    "user",
+    "observations",
+    "skills",
+    "system_prompt",
+];
+
+/// Values substituted for `{{date}}`, `{{channel}}` and `{{user_name}}` in
+/// the composed prompt. Unset values render as an empty string.
+#[derive(Debug, Clone, Default)]
+pub struct PromptVars {
+    pub date: String,
+    pub channel: Option<String>,
+    pub user_name: Option<String>,
+}
+
+impl PromptVars {
+    /// Today's date and the configured user name, for a prompt built for `channel`.
+    pub fn from_config(config: &AppConfig, channel: Option<&str>) -> Self {
+        Self {
+            date: chrono::Local::now().format("%Y-%m-%d").to_string(),
+            channel: channel.map(str::to_string),
+            user_name: config.user_name.clone(),
+        }
+    }
+
+    /// Replace the known `{{name}}` placeholders in `text`; others are kept.
+    pub fn render(&self, text: &str) -> String {
+        if !text.contains("{{") {
+            return text.to_string();
+        }
+        text.replace("{{date}}", &self.date)
+            .replace("{{channel}}", self.channel.as_deref().unwrap_or(""))
+            .replace("{{user_name}}", self.user_name.as_deref().unwrap_or(""))
+    }
+}
+
 /// Assembles a dynamic system prompt from identity files, skills, observations, and config.
 pub struct PromptComposer;
 
 impl PromptComposer {
-    /// Compose the full system prompt in the prescribed order:
+    /// Compose the full system prompt with the date and user name filled in
+    /// and no channel. See [`PromptComposer::compose_with`].
+    pub fn compose(
+        identity: &Identity,
+        active_skills: &[(String, String)], // (name, content)
+        observations: &str,
+        config: &AppConfig,
+    ) -> String {
+        let vars = PromptVars::from_config(config, None);
+        Self::compose_with(identity, active_skills, observations, config, &vars)
+    }
+
+    /// Compose the full system prompt from the sections in
+    /// `identity_prompt_sections`, by default in this order:
     /// 1. SOUL.md content
     /// 2. IDENTITY.md metadata (name, version)
     /// 3. USER.md content
     /// 4. User observations
     /// 5. Active skills
     /// 6. agent_system_prompt config override (appended)
-    pub fn compose(
+    ///
+    /// Placeholders are rendered from `vars` once the sections are joined.
+    pub fn compose_with(
         identity: &Identity,
-        active_skills: &[(String, String)], // (name, content)
+        active_skills: &[(String, String)],
         observations: &str,
         config: &AppConfig,
+        vars: &PromptVars,
     ) -> String {
-        let mut parts = Vec::new();
-
-        // 1. SOUL
-        if let Some(soul) = identity.files.get("SOUL")
-            && !soul.content.trim().is_empty()
-        {
-            parts.push(soul.content.clone());
-        }
-
-        // 2. IDENTITY metadata
-        parts.push(format!(
-            "## Agent Identity\n- Name: {}\n- Version: {}\n- Description: {}",
-            identity.meta.name, identity.meta.version, identity.meta.description
-        ));
-
-        // 3. USER
-        if let Some(user) = identity.files.get("USER")
-            && !user.content.trim().is_empty()
-        {
-            parts.push(user.content.clone());
-        }
+        let order: Vec<&str> = if config.identity_prompt_sections.is_empty() {
+            PROMPT_SECTIONS.to_vec()
+        } else {
+            config
+                .identity_prompt_sections
+                .iter()
+                .map(String::as_str)
+                .collect()
+        };
 
-        // 4. Observations
-        if !observations.trim().is_empty() {
-            parts.push(format!("## Known Preferences\n{observations}"));
-        }
+        let parts: Vec<String> = order
+            .into_iter()
+            .filter_map(|section| {
+                Self::section(section, identity, active_skills, observations, config)
+            })
+            .collect();
+        vars.render(&parts.join("\n\n"))
+    }
 
-        // 5. Skills
-        if !active_skills.is_empty() {
-            let mut skills_section = String::from("## Active Skills\n");
-            for (name, content) in active_skills {
-                skills_section.push_str(&format!("\n### {name}\n{content}\n"));
+    /// Content of one named section, or `None` when it is empty or unknown.
+    fn section(
+        name: &str,
+        identity: &Identity,
+        active_skills: &[(String, String)],
+        observations: &str,
+        config: &AppConfig,
+    ) -> Option<String> {
+        match name.to_ascii_lowercase().as_str() {
+            "soul" | "user" => identity
+                .files
+                .get(&name.to_ascii_uppercase())
+                .filter(|f| !f.content.trim().is_empty())
+                .map(|f| f.content.clone()),
+            "identity" => Some(format!(
+                "## Agent Identity\n- Name: {}\n- Version: {}\n- Description: {}",
+                identity.meta.name, identity.meta.version, identity.meta.description
+            )),
+            "observations" if !observations.trim().is_empty() => {
+                Some(format!("## Known Preferences\n{observations}"))
             }
-            parts.push(skills_section);
-        }
-
-        // 6. Config override (append, not replace)
-        if let Some(ref override_prompt) = config.agent_system_prompt
-            && !override_prompt.trim().is_empty()
-        {
-            parts.push(override_prompt.clone());
+            "skills" if !active_skills.is_empty() => {
+                let mut skills_section = String::from("## Active Skills\n");
+                for (name, content) in active_skills {
+                    skills_section.push_str(&format!("\n### {name}\n{content}\n"));
+                }
+                Some(skills_section)
+            }
+            // Config override (append, not replace)
+            "system_prompt" => config
+                .agent_system_prompt
+                .as_ref()
+                .filter(|p| !p.trim().is_empty())
+                .cloned(),
+            _ => None,
         }
-
-        parts.join("\n\n")
     }
 }
 
@@ -185,4 +253,49 @@ mod tests {
         let result = PromptComposer::compose(&identity, &[], "", &config);
         assert!(!result.contains("Known Preferences"));
     }
+
+    // IDP.1 — Sections follow the configured order; omitted ones are dropped
+    #[test]
+    fn compose_respects_configured_sections() {
+        let identity = test_identity();
+        let config = AppConfig {
+            identity_prompt_sections: vec!["USER".into(), "soul".into()],
+            agent_system_prompt: Some("OVERRIDE".into()),
+            ..Default::default()
+        };
+        let result = PromptComposer::compose(&identity, &[], "likes tests", &config);
+        assert_eq!(
+            result,
+            "User is a Rust developer.\n\nYou are a helpful assistant."
+        );
+    }
+
+    // IDP.2 — Known placeholders are rendered, unknown ones are kept
+    #[test]
+    fn compose_renders_variables() {
+        let mut identity = test_identity();
+        identity.files.insert(
+            "USER".into(),
+            PersonaFile::new(
+                "USER",
+                "Talking to {{user_name}} on {{channel}} ({{date}}), {{mood}}",
+                false,
+            ),
+        );
+        let config = AppConfig {
+            identity_prompt_sections: vec!["user".into()],
+            ..Default::default()
+        };
+        let vars = PromptVars {
+            date: "2026-10-17".into(),
+            channel: Some("telegram".into()),
+            user_name: Some("Sam".into()),
+        };
+        let result = PromptComposer::compose_with(&identity, &[], "", &config, &vars);
+        assert_eq!(result, "Talking to Sam on telegram (2026-10-17), {{mood}}");
+
+        let result =
+            PromptComposer::compose_with(&identity, &[], "", &config, &PromptVars::default());
+        assert_eq!(result, "Talking to  on  (), {{mood}}");
+    }
 }
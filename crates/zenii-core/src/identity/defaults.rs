@@ -2,6 +2,11 @@ pub const DEFAULT_SOUL: &str = include_str!("defaults/SOUL.md");
 pub const DEFAULT_IDENTITY: &str = include_str!("defaults/IDENTITY.md");
 pub const DEFAULT_USER: &str = include_str!("defaults/USER.md");
 
+/// Persona used when no identity file has any content.
+pub const FALLBACK_PERSONA: &str = "You are a helpful, knowledgeable AI assistant. \
+Answer clearly and accurately, say so when you are unsure, and ask a short \
+clarifying question when a request is ambiguous.";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,13 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use tokio::sync::RwLock;
-use tracing::info;
+use tracing::{info, warn};
 
+use crate::config::AppConfig;
 use crate::{Result, ZeniiError};
 
+use super::composer::{PromptComposer, PromptVars};
 use super::defaults;
 use super::types::{Identity, IdentityMeta, PersonaFile};
 
@@ -14,41 +17,71 @@ const IDENTITY_FILES: &[(&str, &str)] = &[
     ("USER", defaults::DEFAULT_USER),
 ];
 
+/// Name that always resolves to the top-level identity files.
+pub const DEFAULT_PERSONA: &str = "default";
+
 /// Loads and manages identity files from disk.
+///
+/// The top-level files form the default persona. Each subdirectory is a named
+/// persona with its own `SOUL.md`/`IDENTITY.md`/`USER.md`; files it omits are
+/// inherited from the default.
 pub struct SoulLoader {
     dir: PathBuf,
+    fallback: String,
     identity: RwLock<Identity>,
+    personas: RwLock<HashMap<String, Identity>>,
 }
 
 impl SoulLoader {
     /// Create a new SoulLoader, writing default files if the directory is empty.
     pub fn new(dir: &Path) -> Result<Self> {
-        std::fs::create_dir_all(dir)?;
+        Self::with_fallback(dir, None)
+    }
+
+    /// Like `new`, but with the persona used when every identity file is blank
+    /// or unreadable. `None` uses the built-in `FALLBACK_PERSONA`.
+    pub fn with_fallback(dir: &Path, fallback: Option<String>) -> Result<Self> {
+        let fallback = fallback
+            .filter(|f| !f.trim().is_empty())
+            .unwrap_or_else(|| defaults::FALLBACK_PERSONA.to_string());
+
+        // An unwritable directory is not fatal; loading falls back below
+        if let Err(e) = std::fs::create_dir_all(dir) {
+            warn!("Cannot create identity dir {}: {e}", dir.display());
+        }
 
         // Write defaults for any missing files
         for (name, default_content) in IDENTITY_FILES {
             let path = dir.join(format!("{name}.md"));
             if !path.exists() {
-                std::fs::write(&path, default_content)?;
-                info!("Wrote default identity file: {}", path.display());
+                match std::fs::write(&path, default_content) {
+                    Ok(()) => info!("Wrote default identity file: {}", path.display()),
+                    Err(e) => warn!("Cannot write identity file {}: {e}", path.display()),
+                }
             }
         }
 
-        let identity = Self::load_from_disk(dir)?;
+        let identity = Self::load_from_disk(dir, &fallback)?;
+        let personas = Self::load_personas(dir, &identity);
 
         Ok(Self {
             dir: dir.to_path_buf(),
+            fallback,
             identity: RwLock::new(identity),
+            personas: RwLock::new(personas),
         })
     }
 
-    fn load_from_disk(dir: &Path) -> Result<Identity> {
+    fn load_from_disk(dir: &Path, fallback: &str) -> Result<Identity> {
         let mut identity = Identity::new(IdentityMeta::default());
 
         for (name, default_content) in IDENTITY_FILES {
             let path = dir.join(format!("{name}.md"));
             let content = if path.exists() {
-                std::fs::read_to_string(&path)?
+                std::fs::read_to_string(&path).unwrap_or_else(|e| {
+                    warn!("Cannot read identity file {}: {e}", path.display());
+                    String::new()
+                })
             } else {
                 (*default_content).to_string()
             };
@@ -68,9 +101,67 @@ impl SoulLoader {
             );
         }
 
+        // A blank system prompt silently degrades every agent; use the fallback persona
+        if identity.files.values().all(|f| f.content.trim().is_empty()) {
+            warn!(
+                "No identity content loaded from {}, using fallback persona",
+                dir.display()
+            );
+            identity
+                .files
+                .insert("SOUL".into(), PersonaFile::new("SOUL", fallback, true));
+        }
+
         Ok(identity)
     }
 
+    /// Load every persona subdirectory of `dir`, layered over `base`.
+    fn load_personas(dir: &Path, base: &Identity) -> HashMap<String, Identity> {
+        let mut personas = HashMap::new();
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return personas;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if name.starts_with('.') || name == DEFAULT_PERSONA {
+                continue;
+            }
+
+            let mut persona = base.clone();
+            for (file, _) in IDENTITY_FILES {
+                let file_path = path.join(format!("{file}.md"));
+                if !file_path.exists() {
+                    continue;
+                }
+                let content = match std::fs::read_to_string(&file_path) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        warn!("Cannot read persona file {}: {e}", file_path.display());
+                        continue;
+                    }
+                };
+                if *file == "IDENTITY"
+                    && let Some(meta) = parse_identity_frontmatter(&content)
+                {
+                    persona.meta = meta;
+                }
+                persona
+                    .files
+                    .insert((*file).to_string(), PersonaFile::new(*file, content, false));
+            }
+            personas.insert(name.to_string(), persona);
+        }
+
+        personas
+    }
+
     /// Get the current identity (read lock).
     pub async fn get(&self) -> Identity {
         self.identity.read().await.clone()
@@ -123,15 +214,52 @@ impl SoulLoader {
         Ok(())
     }
 
-    /// Reload all files from disk.
+    /// Reload all files from disk, including persona subdirectories.
     pub async fn reload(&self) -> Result<()> {
-        let new_identity = Self::load_from_disk(&self.dir)?;
+        let new_identity = Self::load_from_disk(&self.dir, &self.fallback)?;
+        let new_personas = Self::load_personas(&self.dir, &new_identity);
         let mut identity = self.identity.write().await;
+        let mut personas = self.personas.write().await;
         *identity = new_identity;
+        *personas = new_personas;
         info!("Identity files reloaded from {}", self.dir.display());
         Ok(())
     }
 
+    /// Get a named persona, falling back to the default identity when `name`
+    /// is [`DEFAULT_PERSONA`] or unknown.
+    pub async fn get_persona(&self, name: &str) -> Identity {
+        if name != DEFAULT_PERSONA
+            && let Some(persona) = self.personas.read().await.get(name)
+        {
+            return persona.clone();
+        }
+        if name != DEFAULT_PERSONA {
+            warn!("Unknown persona '{name}', using default identity");
+        }
+        self.get().await
+    }
+
+    /// List named persona subdirectories (excluding the default).
+    pub async fn list_personas(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.personas.read().await.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Compose the identity system prompt for `persona`, with `{{channel}}`
+    /// rendered as `channel`.
+    pub async fn build_system_prompt_for(
+        &self,
+        persona: &str,
+        config: &AppConfig,
+        channel: Option<&str>,
+    ) -> String {
+        let identity = self.get_persona(persona).await;
+        let vars = PromptVars::from_config(config, channel);
+        PromptComposer::compose_with(&identity, &[], "", config, &vars)
+    }
+
     /// List all identity file names.
     pub async fn list_files(&self) -> Vec<String> {
         let identity = self.identity.read().await;
@@ -286,6 +414,119 @@ mod tests {
         assert!(files.contains(&"USER".to_string()));
     }
 
+    // ID.1 — Blank identity files yield the built-in fallback persona
+    #[tokio::test]
+    async fn blank_identity_uses_fallback_persona() {
+        let dir = TempDir::new().unwrap();
+        let identity_dir = dir.path().join("identity");
+        std::fs::create_dir_all(&identity_dir).unwrap();
+        for name in ["SOUL", "IDENTITY", "USER"] {
+            std::fs::write(identity_dir.join(format!("{name}.md")), "  \n").unwrap();
+        }
+
+        let loader = SoulLoader::new(&identity_dir).unwrap();
+        let soul = loader.get_file("SOUL").await.unwrap();
+        assert_eq!(soul.content, defaults::FALLBACK_PERSONA);
+        assert!(soul.is_default);
+        // The fallback is in memory only; the user's files are left untouched
+        assert_eq!(
+            std::fs::read_to_string(identity_dir.join("SOUL.md")).unwrap(),
+            "  \n"
+        );
+    }
+
+    // ID.2 — Configured fallback overrides the built-in persona, including on reload
+    #[tokio::test]
+    async fn configured_fallback_persona_used_on_reload() {
+        let dir = TempDir::new().unwrap();
+        let identity_dir = dir.path().join("identity");
+        let loader =
+            SoulLoader::with_fallback(&identity_dir, Some("You are Ops Bot.".into())).unwrap();
+        assert!(
+            loader
+                .get_file("SOUL")
+                .await
+                .unwrap()
+                .content
+                .contains("Zenii")
+        );
+
+        for name in ["SOUL", "IDENTITY", "USER"] {
+            std::fs::write(identity_dir.join(format!("{name}.md")), "").unwrap();
+        }
+        loader.reload().await.unwrap();
+        assert_eq!(
+            loader.get_file("SOUL").await.unwrap().content,
+            "You are Ops Bot."
+        );
+    }
+
+    // ID.3 — Persona subdirectories inherit missing files from the default
+    #[tokio::test]
+    async fn persona_subdirectory_overrides_default() {
+        let dir = TempDir::new().unwrap();
+        let identity_dir = dir.path().join("identity");
+        std::fs::create_dir_all(identity_dir.join("support")).unwrap();
+        std::fs::write(identity_dir.join("USER.md"), "Shared user notes").unwrap();
+        std::fs::write(
+            identity_dir.join("support/SOUL.md"),
+            "You are Support Bot, patient and brief.",
+        )
+        .unwrap();
+        std::fs::write(
+            identity_dir.join("support/IDENTITY.md"),
+            "---\nname: Support Bot\nversion: \"1.0\"\ndescription: Helpdesk\n---\n",
+        )
+        .unwrap();
+
+        let loader = SoulLoader::new(&identity_dir).unwrap();
+        assert_eq!(loader.list_personas().await, vec!["support".to_string()]);
+
+        let config = AppConfig::default();
+        let prompt = loader
+            .build_system_prompt_for("support", &config, None)
+            .await;
+        assert!(prompt.contains("Support Bot, patient"));
+        assert!(prompt.contains("Name: Support Bot"));
+        assert!(prompt.contains("Shared user notes"));
+
+        let default = loader
+            .build_system_prompt_for("missing", &config, None)
+            .await;
+        assert!(default.contains("Name: Zenii"));
+        assert_eq!(
+            default,
+            loader
+                .build_system_prompt_for(DEFAULT_PERSONA, &config, None)
+                .await
+        );
+    }
+
+    // ID.4 — Reload picks up persona edits and new personas
+    #[tokio::test]
+    async fn reload_refreshes_personas() {
+        let dir = TempDir::new().unwrap();
+        let identity_dir = dir.path().join("identity");
+        let loader = SoulLoader::new(&identity_dir).unwrap();
+        assert!(loader.list_personas().await.is_empty());
+
+        std::fs::create_dir_all(identity_dir.join("ops")).unwrap();
+        std::fs::write(identity_dir.join("ops/SOUL.md"), "You are Ops Bot.").unwrap();
+        loader.reload().await.unwrap();
+
+        let ops = loader.get_persona("ops").await;
+        assert_eq!(ops.files["SOUL"].content, "You are Ops Bot.");
+        // The default identity is untouched
+        assert!(
+            loader
+                .get_file("SOUL")
+                .await
+                .unwrap()
+                .content
+                .contains("Zenii")
+        );
+    }
+
     #[test]
     fn parse_frontmatter_valid() {
         let content = "---\nname: Test\nversion: \"1.0\"\ndescription: A test\n---\n# Body";
@@ -3,6 +3,6 @@ pub mod defaults;
 pub mod loader;
 pub mod types;
 
-pub use composer::PromptComposer;
-pub use loader::SoulLoader;
+pub use composer::{PromptComposer, PromptVars};
+pub use loader::{DEFAULT_PERSONA, SoulLoader};
 pub use types::{Identity, IdentityMeta, PersonaFile};
@@ -31,6 +31,6 @@ pub mod scheduler;
 #[cfg(feature = "workflows")]
 pub mod workflows;
 
-pub use error::ZeniiError;
+pub use error::{AgentError, ZeniiError};
 
 pub type Result<T> = std::result::Result<T, ZeniiError>;
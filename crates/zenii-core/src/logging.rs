@@ -22,6 +22,9 @@ pub struct UsageRecord {
     pub context_level: String,
     pub binary: String,
     pub success: bool,
+    /// Estimated cost in USD, when the model's pricing is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_cost_usd: Option<f64>,
 }
 
 /// Async JSONL writer for AI usage records.
@@ -295,6 +298,7 @@ mod tests {
             context_level: "Full".into(),
             binary: "daemon".into(),
             success: true,
+            estimated_cost_usd: None,
         };
         let json = serde_json::to_string(&record).unwrap();
         assert!(json.contains("\"input_tokens\":100"));
@@ -343,6 +347,7 @@ mod tests {
             context_level: "Minimal".into(),
             binary: "test".into(),
             success: true,
+            estimated_cost_usd: None,
         };
         logger.log(&record).await.unwrap();
 
@@ -379,6 +384,7 @@ mod tests {
             context_level: "Full".into(),
             binary: "test".into(),
             success: true,
+            estimated_cost_usd: None,
         };
         logger.log(&record).await.unwrap();
 
@@ -423,6 +429,7 @@ mod tests {
             context_level: "Full".into(),
             binary: "test".into(),
             success: true,
+            estimated_cost_usd: None,
         };
         logger.log(&record).await.unwrap();
 
@@ -113,6 +113,36 @@ mod inner {
             }
         }
 
+        /// Send an MCP `ping` over the server's live session and return the
+        /// round-trip latency. Fails when there is no session, the server
+        /// answers with an error, or no reply arrives within `timeout`.
+        pub async fn ping(
+            &self,
+            server_id: &str,
+            timeout: std::time::Duration,
+        ) -> Result<std::time::Duration> {
+            use rmcp::model::{ClientRequest, PingRequest};
+
+            let peer = self.peers.get(server_id).ok_or_else(|| {
+                ZeniiError::Mcp(format!("no active session for MCP server '{server_id}'"))
+            })?;
+
+            let started = std::time::Instant::now();
+            tokio::time::timeout(
+                timeout,
+                peer.send_request(ClientRequest::PingRequest(PingRequest::default())),
+            )
+            .await
+            .map_err(|_| {
+                ZeniiError::Mcp(format!(
+                    "MCP server '{server_id}' did not answer ping within {}ms",
+                    timeout.as_millis()
+                ))
+            })?
+            .map_err(|e| ZeniiError::Mcp(format!("MCP ping to '{server_id}' failed: {e}")))?;
+            Ok(started.elapsed())
+        }
+
         // ── Private helpers ───────────────────────────────────────────────────
 
         async fn connect_server(
@@ -354,6 +384,18 @@ mod inner {
             assert!(matches!(result.unwrap_err(), ZeniiError::Mcp(_)));
         }
 
+        #[tokio::test]
+        async fn ping_without_session_returns_error() {
+            let manager = McpClientManager::connect_all(&[stdio_cfg("bad", true)])
+                .await
+                .unwrap();
+            let err = manager
+                .ping("bad", std::time::Duration::from_secs(1))
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("no active session"), "{err}");
+        }
+
         #[test]
         fn tools_for_unknown_server_is_empty() {
             let manager = McpClientManager {
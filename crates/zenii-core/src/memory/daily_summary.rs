@@ -0,0 +1,96 @@
+use super::traits::{MemoryCategory, MemoryEntry};
+
+/// Category the end-of-day summaries are stored under.
+pub const CATEGORY: &str = "daily_summary";
+
+/// Id of the scheduler job seeded at boot.
+pub const JOB_ID: &str = "daily-summary";
+
+/// System prompt for the summarizing model.
+pub const PREAMBLE: &str = "You write a short end-of-day digest of what an assistant \
+     learned and recorded about its user. Output only the digest, nothing else.";
+
+/// Per-entry character cap in the summarization prompt.
+const MAX_ENTRY_CHARS: usize = 1_000;
+
+/// Most entries included in one summary; the newest win.
+const MAX_ENTRIES: usize = 200;
+
+pub fn category() -> MemoryCategory {
+    MemoryCategory::Custom(CATEGORY.into())
+}
+
+/// Memory key of the summary for `date` (`YYYY-MM-DD`).
+pub fn key(date: &str) -> String {
+    format!("{CATEGORY}:{date}")
+}
+
+/// Build the summarization prompt from the day's entries, skipping earlier
+/// summaries. Returns `None` when there is nothing to summarize.
+pub fn build_prompt(date: &str, entries: &[MemoryEntry]) -> Option<String> {
+    let entries: Vec<&MemoryEntry> = entries
+        .iter()
+        .filter(|e| e.category.to_string() != CATEGORY)
+        .collect();
+    if entries.is_empty() {
+        return None;
+    }
+
+    let mut listing = String::new();
+    for entry in &entries[entries.len().saturating_sub(MAX_ENTRIES)..] {
+        let content: String = entry.content.chars().take(MAX_ENTRY_CHARS).collect();
+        let ellipsis = if content.len() < entry.content.len() {
+            " …"
+        } else {
+            ""
+        };
+        listing.push_str(&format!(
+            "- [{}] {}: {content}{ellipsis}\n",
+            entry.category, entry.key
+        ));
+    }
+
+    Some(format!(
+        "Memories recorded on {date}:\n\n\
+         {listing}\n\
+         Rules:\n\
+         - Summarize what happened and what was learned in a few short bullet points\n\
+         - Keep names, decisions, deadlines and preferences\n\
+         - Merge duplicates and drop trivia"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: &str, content: &str, category: MemoryCategory) -> MemoryEntry {
+        MemoryEntry {
+            id: key.into(),
+            key: key.into(),
+            content: content.into(),
+            category,
+            score: 1.0,
+            created_at: "2026-10-17 10:00:00".into(),
+            updated_at: "2026-10-17 10:00:00".into(),
+            content_hash: None,
+        }
+    }
+
+    #[test]
+    fn prompt_lists_entries_and_skips_summaries() {
+        let entries = vec![
+            entry("pref", "Prefers tea", MemoryCategory::Core),
+            entry(&key("2026-10-16"), "old digest", category()),
+        ];
+        let prompt = build_prompt("2026-10-17", &entries).unwrap();
+        assert!(prompt.contains("- [core] pref: Prefers tea"));
+        assert!(!prompt.contains("old digest"));
+    }
+
+    #[test]
+    fn prompt_none_without_entries() {
+        let entries = vec![entry(&key("2026-10-16"), "old digest", category())];
+        assert!(build_prompt("2026-10-17", &entries).is_none());
+    }
+}
@@ -9,6 +9,16 @@ use crate::Result;
 #[async_trait]
 pub trait EmbeddingProvider: Send + Sync {
     async fn embed(&self, text: &str) -> Result<Vec<f32>>;
+
+    /// Embed several texts, returning vectors in input order. Providers with a
+    /// batch endpoint override this; the default embeds one text at a time.
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let mut vectors = Vec::with_capacity(texts.len());
+        for text in texts {
+            vectors.push(self.embed(text).await?);
+        }
+        Ok(vectors)
+    }
 }
 
 pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
@@ -91,11 +91,23 @@ impl Memory for InMemoryStore {
         dates.sort_by(|a, b| b.cmp(a)); // descending
         Ok(dates)
     }
+
+    async fn entries_for_date(&self, date: &str) -> Result<Vec<MemoryEntry>> {
+        let memories = self.memories.lock().await;
+        let mut entries: Vec<MemoryEntry> = memories
+            .values()
+            .filter(|e| e.updated_at.starts_with(date))
+            .cloned()
+            .collect();
+        entries.sort_by(|a, b| a.updated_at.cmp(&b.updated_at));
+        Ok(entries)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::memory::traits::MemoryQuery;
 
     #[tokio::test]
     async fn store_and_recall_round_trip() {
@@ -263,4 +275,38 @@ mod tests {
         let r2 = store.recall("key2", 1, 0).await.unwrap();
         assert_ne!(r1[0].id, r2[0].id);
     }
+
+    #[tokio::test]
+    async fn query_without_text_filters_by_category() {
+        let store = InMemoryStore::new();
+        store
+            .store("a", "billing plan", MemoryCategory::Core)
+            .await
+            .unwrap();
+        store
+            .store("b", "billing chat", MemoryCategory::Conversation)
+            .await
+            .unwrap();
+        let results = store
+            .query(&MemoryQuery {
+                category: Some(MemoryCategory::Core),
+                limit: 10,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].key, "a");
+
+        let future = chrono::Utc::now() + chrono::Duration::hours(1);
+        let none = store
+            .query(&MemoryQuery {
+                after: Some(future),
+                limit: 10,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(none.is_empty());
+    }
 }
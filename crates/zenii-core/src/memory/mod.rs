@@ -1,3 +1,4 @@
+pub mod daily_summary;
 pub mod embeddings;
 pub mod in_memory_store;
 #[cfg(feature = "local-embeddings")]
@@ -5,7 +5,8 @@ use crate::{Result, ZeniiError};
 use super::embeddings::EmbeddingProvider;
 
 /// OpenAI-compatible embedding provider using the /v1/embeddings endpoint.
-/// Reuses the existing OpenAI API key (no separate credential).
+/// Reuses the existing OpenAI API key (no separate credential), or any
+/// registry provider's key via `ProviderRegistry::embedding_provider`.
 pub struct OpenAiEmbeddingProvider {
     client: reqwest::Client,
     api_key: String,
@@ -21,43 +22,21 @@ impl OpenAiEmbeddingProvider {
             api_key,
             model,
             dimensions,
-            base_url: "https://api.openai.com".into(),
+            base_url: "https://api.openai.com/v1".into(),
         }
     }
 
+    /// Override the API base URL (must include the version segment, e.g. `.../v1`).
     pub fn with_base_url(mut self, base_url: String) -> Self {
-        self.base_url = base_url;
+        self.base_url = base_url.trim_end_matches('/').to_string();
         self
     }
-}
-
-#[derive(serde::Serialize)]
-struct EmbeddingRequest {
-    input: String,
-    model: String,
-    dimensions: usize,
-}
-
-#[derive(serde::Deserialize)]
-struct EmbeddingResponse {
-    data: Vec<EmbeddingData>,
-}
-
-#[derive(serde::Deserialize)]
-struct EmbeddingData {
-    embedding: Vec<f32>,
-}
-
-#[async_trait]
-impl EmbeddingProvider for OpenAiEmbeddingProvider {
-    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
-        if text.trim().is_empty() {
-            return Err(ZeniiError::Embedding("text cannot be empty".into()));
-        }
 
-        let url = format!("{}/v1/embeddings", self.base_url);
+    async fn request(&self, input: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        let url = format!("{}/embeddings", self.base_url);
+        let expected = input.len();
         let body = EmbeddingRequest {
-            input: text.to_string(),
+            input,
             model: self.model.clone(),
             dimensions: self.dimensions,
         };
@@ -79,18 +58,63 @@ impl EmbeddingProvider for OpenAiEmbeddingProvider {
             )));
         }
 
-        let result: EmbeddingResponse = resp
+        let mut result: EmbeddingResponse = resp
             .json()
             .await
             .map_err(|e| ZeniiError::Embedding(format!("response parse failed: {e}")))?;
 
-        result
-            .data
-            .into_iter()
-            .next()
-            .map(|d| d.embedding)
+        if result.data.len() != expected {
+            return Err(ZeniiError::Embedding(format!(
+                "expected {expected} embeddings, got {}",
+                result.data.len()
+            )));
+        }
+        result.data.sort_by_key(|d| d.index);
+        Ok(result.data.into_iter().map(|d| d.embedding).collect())
+    }
+}
+
+#[derive(serde::Serialize)]
+struct EmbeddingRequest {
+    input: Vec<String>,
+    model: String,
+    dimensions: usize,
+}
+
+#[derive(serde::Deserialize)]
+struct EmbeddingResponse {
+    data: Vec<EmbeddingData>,
+}
+
+#[derive(serde::Deserialize)]
+struct EmbeddingData {
+    embedding: Vec<f32>,
+    #[serde(default)]
+    index: usize,
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenAiEmbeddingProvider {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        if text.trim().is_empty() {
+            return Err(ZeniiError::Embedding("text cannot be empty".into()));
+        }
+
+        self.request(vec![text.to_string()])
+            .await?
+            .pop()
             .ok_or_else(|| ZeniiError::Embedding("empty response from OpenAI".into()))
     }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        if texts.iter().any(|t| t.trim().is_empty()) {
+            return Err(ZeniiError::Embedding("text cannot be empty".into()));
+        }
+        self.request(texts.to_vec()).await
+    }
 }
 
 /// Resolve the OpenAI API key from credential store or environment variable.
@@ -121,7 +145,7 @@ mod tests {
             OpenAiEmbeddingProvider::new("sk-test".into(), "text-embedding-3-small".into(), 384);
         assert_eq!(provider.model, "text-embedding-3-small");
         assert_eq!(provider.dimensions, 384);
-        assert_eq!(provider.base_url, "https://api.openai.com");
+        assert_eq!(provider.base_url, "https://api.openai.com/v1");
     }
 
     // 18.2 — Embed returns vector of correct dimensions (mocked HTTP)
@@ -157,7 +181,7 @@ mod tests {
 
         let provider =
             OpenAiEmbeddingProvider::new("sk-test".into(), "text-embedding-3-small".into(), 384)
-                .with_base_url(format!("http://{addr}"));
+                .with_base_url(format!("http://{addr}/v1"));
 
         let result = provider.embed("hello world").await.unwrap();
         assert_eq!(result.len(), 384);
@@ -203,4 +227,40 @@ mod tests {
         let result2 = provider.embed("   ").await;
         assert!(result2.is_err());
     }
+
+    // 18.5 — Batch embedding returns vectors in input order
+    #[tokio::test]
+    async fn openai_embed_batch_orders_by_index() {
+        let mock_body = serde_json::json!({
+            "data": [
+                { "embedding": [2.0, 2.0], "index": 1 },
+                { "embedding": [1.0, 1.0], "index": 0 }
+            ]
+        })
+        .to_string();
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let _ = stream.readable().await;
+            let _ = stream.try_read(&mut buf);
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                mock_body.len(),
+                mock_body
+            );
+            let _ = stream.writable().await;
+            let _ = stream.try_write(response.as_bytes());
+        });
+
+        let provider = OpenAiEmbeddingProvider::new("sk-test".into(), "m".into(), 2)
+            .with_base_url(format!("http://{addr}/v1/"));
+        let vectors = provider
+            .embed_batch(&["first".into(), "second".into()])
+            .await
+            .unwrap();
+        assert_eq!(vectors, vec![vec![1.0, 1.0], vec![2.0, 2.0]]);
+    }
 }
@@ -8,7 +8,9 @@ use crate::db::DbPool;
 use crate::{Result, ZeniiError};
 
 use super::embeddings::EmbeddingProvider;
-use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use super::traits::{
+    Memory, MemoryCategory, MemoryEntry, MemoryQuery, QUERY_SCAN_LIMIT, parse_timestamp,
+};
 use super::vector_index::VectorIndex;
 
 fn content_hash(content: &str) -> String {
@@ -225,16 +227,11 @@ impl SqliteMemoryStore {
 }
 
 fn days_since_update(updated_at: &str) -> f32 {
-    use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
-    let dt = if let Ok(dt) = updated_at.parse::<DateTime<Utc>>() {
-        dt
-    } else if let Ok(naive) = NaiveDateTime::parse_from_str(updated_at, "%Y-%m-%d %H:%M:%S") {
-        Utc.from_utc_datetime(&naive)
-    } else {
+    let Some(dt) = parse_timestamp(updated_at) else {
         tracing::warn!(raw = %updated_at, "memory: unparseable updated_at timestamp; decay skipped for this entry");
         return 0.0;
     };
-    let duration = Utc::now().signed_duration_since(dt);
+    let duration = chrono::Utc::now().signed_duration_since(dt);
     duration.num_seconds().max(0) as f32 / 86400.0
 }
 
@@ -580,6 +577,91 @@ impl Memory for SqliteMemoryStore {
         })
         .await
     }
+
+    async fn entries_for_date(&self, date: &str) -> Result<Vec<MemoryEntry>> {
+        let pool = self.pool.clone();
+        let date = date.to_string();
+
+        crate::db::with_db(&pool, move |conn| {
+            // updated_at is either `YYYY-MM-DD HH:MM:SS` or RFC 3339; both lead with the date
+            let mut stmt = conn
+                .prepare(
+                    "SELECT id, key, content, category, created_at, updated_at, content_hash
+                     FROM memories
+                     WHERE substr(updated_at, 1, 10) = ?1
+                     ORDER BY updated_at ASC",
+                )
+                .map_err(ZeniiError::from)?;
+            let entries = stmt
+                .query_map(rusqlite::params![date], |row| {
+                    Ok(MemoryEntry {
+                        id: row.get(0)?,
+                        key: row.get(1)?,
+                        content: row.get(2)?,
+                        category: MemoryCategory::from(row.get::<_, String>(3)?.as_str()),
+                        score: 1.0,
+                        created_at: row.get(4)?,
+                        updated_at: row.get(5)?,
+                        content_hash: row.get(6)?,
+                    })
+                })
+                .map_err(ZeniiError::from)?
+                .filter_map(|r| r.ok())
+                .collect();
+            Ok(entries)
+        })
+        .await
+    }
+
+    async fn query(&self, filter: &MemoryQuery) -> Result<Vec<MemoryEntry>> {
+        if let Some(text) = filter.text() {
+            let candidates = self.recall(text, QUERY_SCAN_LIMIT, 0).await?;
+            return Ok(filter.apply(candidates));
+        }
+
+        let pool = self.pool.clone();
+        let category = filter.category.as_ref().map(|c| c.to_string());
+        let bound = |ts: Option<chrono::DateTime<chrono::Utc>>| {
+            ts.map(|ts| ts.format("%Y-%m-%d %H:%M:%S%.3f").to_string())
+        };
+        let after = bound(filter.after);
+        let before = bound(filter.before);
+        let limit = filter.limit as i64;
+
+        crate::db::with_db(&pool, move |conn| {
+            // created_at is either `YYYY-MM-DD HH:MM:SS` or RFC 3339; julianday()
+            // reads both, so bounds compare by time rather than as text
+            let mut stmt = conn
+                .prepare(
+                    "SELECT id, key, content, category, created_at, updated_at, content_hash
+                     FROM memories
+                     WHERE (?1 IS NULL OR category = ?1)
+                       AND (?2 IS NULL OR julianday(created_at) >= julianday(?2))
+                       AND (?3 IS NULL OR julianday(created_at) < julianday(?3))
+                     ORDER BY julianday(created_at) DESC
+                     LIMIT ?4",
+                )
+                .map_err(ZeniiError::from)?;
+            let entries = stmt
+                .query_map(rusqlite::params![category, after, before, limit], |row| {
+                    Ok(MemoryEntry {
+                        id: row.get(0)?,
+                        key: row.get(1)?,
+                        content: row.get(2)?,
+                        category: MemoryCategory::from(row.get::<_, String>(3)?.as_str()),
+                        score: 1.0,
+                        created_at: row.get(4)?,
+                        updated_at: row.get(5)?,
+                        content_hash: row.get(6)?,
+                    })
+                })
+                .map_err(ZeniiError::from)?
+                .filter_map(|r| r.ok())
+                .collect();
+            Ok(entries)
+        })
+        .await
+    }
 }
 
 #[cfg(test)]
@@ -670,6 +752,75 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn query_filters_by_category_and_created_at() {
+        let (_dir, store) = setup().await;
+        for (key, category, created_at) in [
+            ("billing-old", MemoryCategory::Core, "2026-03-01 09:00:00"),
+            (
+                "billing-new",
+                MemoryCategory::Core,
+                "2026-03-10T09:00:00+00:00",
+            ),
+            ("chat", MemoryCategory::Conversation, "2026-03-11 09:00:00"),
+        ] {
+            store
+                .store(key, &format!("{key} invoice notes"), category)
+                .await
+                .unwrap();
+            let pool = store.pool.clone();
+            let key = key.to_string();
+            db::with_db(&pool, move |conn| {
+                conn.execute(
+                    "UPDATE memories SET created_at = ?1 WHERE key = ?2",
+                    rusqlite::params![created_at, key],
+                )
+                .map_err(ZeniiError::from)
+            })
+            .await
+            .unwrap();
+        }
+        let at = |s: &str| s.parse::<chrono::DateTime<chrono::Utc>>().unwrap();
+
+        // Filters only: newest first
+        let recent = store
+            .query(&MemoryQuery {
+                after: Some(at("2026-03-05T00:00:00Z")),
+                limit: 10,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let keys: Vec<_> = recent.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["chat", "billing-new"]);
+
+        let core_before = store
+            .query(&MemoryQuery {
+                category: Some(MemoryCategory::Core),
+                before: Some(at("2026-03-10T09:00:00Z")),
+                limit: 10,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(core_before.len(), 1);
+        assert_eq!(core_before[0].key, "billing-old");
+
+        // Query plus filters
+        let ranked = store
+            .query(&MemoryQuery {
+                query: Some("invoice".into()),
+                category: Some(MemoryCategory::Core),
+                after: Some(at("2026-03-05T00:00:00Z")),
+                limit: 10,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].key, "billing-new");
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn store_daily_and_recall() {
         let (_dir, store) = setup().await;
@@ -699,6 +850,26 @@ mod tests {
         assert!(dates.len() >= 3);
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn entries_for_date_filters_by_day() {
+        let (_dir, store) = setup().await;
+        store
+            .store("today", "written today", MemoryCategory::Core)
+            .await
+            .unwrap();
+        let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        let entries = store.entries_for_date(&today).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key, "today");
+        assert!(
+            store
+                .entries_for_date("2001-01-01")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn recall_respects_limit() {
         let (_dir, store) = setup().await;
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::Result;
@@ -68,6 +69,68 @@ pub struct MemoryEntry {
     pub content_hash: Option<String>,
 }
 
+/// Candidates fetched via `recall` before `MemoryQuery` filters are applied.
+pub const QUERY_SCAN_LIMIT: usize = 500;
+
+/// Filters for `Memory::query`. Unset fields match every entry.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryQuery {
+    /// Text to rank by; without it entries are returned newest first.
+    pub query: Option<String>,
+    pub category: Option<MemoryCategory>,
+    /// Only entries created at or after this time.
+    pub after: Option<DateTime<Utc>>,
+    /// Only entries created before this time.
+    pub before: Option<DateTime<Utc>>,
+    pub limit: usize,
+}
+
+impl MemoryQuery {
+    /// The search text, if it is not blank.
+    pub fn text(&self) -> Option<&str> {
+        self.query
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+    }
+
+    pub fn matches(&self, entry: &MemoryEntry) -> bool {
+        if self.category.as_ref().is_some_and(|c| *c != entry.category) {
+            return false;
+        }
+        if self.after.is_none() && self.before.is_none() {
+            return true;
+        }
+        let Some(created) = parse_timestamp(&entry.created_at) else {
+            return false;
+        };
+        self.after.is_none_or(|after| created >= after)
+            && self.before.is_none_or(|before| created < before)
+    }
+
+    /// Filter recalled `entries`, order them and cut to `limit`. Ranked
+    /// results keep their order; unranked ones are sorted newest first.
+    pub fn apply(&self, entries: Vec<MemoryEntry>) -> Vec<MemoryEntry> {
+        let mut entries: Vec<MemoryEntry> =
+            entries.into_iter().filter(|e| self.matches(e)).collect();
+        if self.text().is_none() {
+            entries.sort_by_cached_key(|e| std::cmp::Reverse(parse_timestamp(&e.created_at)));
+        }
+        entries.truncate(self.limit);
+        entries
+    }
+}
+
+/// Parse a stored timestamp: RFC 3339 or SQLite's `YYYY-MM-DD HH:MM:SS` (UTC).
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = value.parse::<DateTime<Utc>>() {
+        return Some(dt);
+    }
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|naive| Utc.from_utc_datetime(&naive))
+}
+
 #[async_trait]
 pub trait Memory: Send + Sync {
     async fn store(&self, key: &str, content: &str, category: MemoryCategory) -> Result<()>;
@@ -76,4 +139,16 @@ pub trait Memory: Send + Sync {
     async fn store_daily(&self, content: &str) -> Result<()>;
     async fn recall_daily(&self, date: &str) -> Result<Option<String>>;
     async fn list_daily_dates(&self) -> Result<Vec<String>>;
+    /// Entries last written on `date` (`YYYY-MM-DD`, UTC), oldest first.
+    async fn entries_for_date(&self, date: &str) -> Result<Vec<MemoryEntry>>;
+
+    /// Entries matching `filter`, ranked by relevance when it has a query and
+    /// newest first otherwise. The default filters the top `QUERY_SCAN_LIMIT`
+    /// results of `recall`; stores that can filter natively should override it.
+    async fn query(&self, filter: &MemoryQuery) -> Result<Vec<MemoryEntry>> {
+        let candidates = self
+            .recall(filter.text().unwrap_or(""), QUERY_SCAN_LIMIT, 0)
+            .await?;
+        Ok(filter.apply(candidates))
+    }
 }
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use serde_json::Value;
@@ -6,6 +7,7 @@ use tokio::sync::Mutex;
 
 use super::process::PluginProcess;
 use crate::Result;
+use crate::event_bus::{AppEvent, EventBus};
 use crate::tools::{Tool, ToolResult};
 
 /// Wraps a PluginProcess to implement the Tool trait.
@@ -15,6 +17,9 @@ pub struct PluginToolAdapter {
     description: String,
     parameters_schema: Value,
     process: Arc<Mutex<PluginProcess>>,
+    dispatch: bool,
+    timeout: Option<Duration>,
+    event_bus: Option<Arc<dyn EventBus>>,
 }
 
 impl PluginToolAdapter {
@@ -29,8 +34,31 @@ impl PluginToolAdapter {
             description,
             parameters_schema,
             process,
+            dispatch: false,
+            timeout: None,
+            event_bus: None,
         }
     }
+
+    /// Send `{"tool": <name>, "arguments": <args>}` on `execute` instead of the
+    /// bare arguments, so a process serving several tools can dispatch.
+    pub fn with_dispatch(mut self) -> Self {
+        self.dispatch = true;
+        self
+    }
+
+    /// Override the process's execute timeout for this tool.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Publish the plugin's progress notifications as
+    /// [`AppEvent::PluginToolProgress`] while a call runs.
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
 }
 
 #[async_trait]
@@ -47,12 +75,31 @@ impl Tool for PluginToolAdapter {
         self.parameters_schema.clone()
     }
 
+    fn enforces_timeout(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: Value) -> Result<ToolResult> {
         let mut proc = self.process.lock().await;
         if !proc.is_running() {
             proc.spawn().await?;
         }
-        proc.execute(args).await
+        let params = if self.dispatch {
+            serde_json::json!({ "tool": self.name, "arguments": args })
+        } else {
+            args
+        };
+        let timeout = self.timeout.unwrap_or_else(|| proc.execute_timeout());
+        let mut on_progress = |message: &str| {
+            if let Some(bus) = &self.event_bus {
+                let _ = bus.publish(AppEvent::PluginToolProgress {
+                    tool_name: self.name.clone(),
+                    message: message.to_string(),
+                });
+            }
+        };
+        proc.execute_with_progress(&self.name, params, timeout, &mut on_progress)
+            .await
     }
 }
 
@@ -163,6 +210,108 @@ done
         }
     }
 
+    // Sub-tools sharing one process receive their name on execute
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn adapter_dispatch_sends_tool_name() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let script_path = dir.path().join("multi-tool.sh");
+        std::fs::write(
+            &script_path,
+            r#"#!/bin/bash
+while IFS= read -r line; do
+    id=$(echo "$line" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+    tool=$(echo "$line" | grep -o '"tool":"[^"]*"' | head -1 | cut -d'"' -f4)
+    echo "{\"jsonrpc\":\"2.0\",\"result\":{\"output\":\"ran $tool\",\"success\":true},\"id\":$id}"
+done
+"#,
+        )
+        .unwrap();
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        std::fs::File::open(&script_path)
+            .unwrap()
+            .sync_all()
+            .unwrap();
+
+        let process = Arc::new(Mutex::new(PluginProcess::new("multi", script_path, 10, 3)));
+        let read = PluginToolAdapter::new(
+            "files_read".into(),
+            "Read".into(),
+            serde_json::json!({}),
+            process.clone(),
+        )
+        .with_dispatch();
+        let list = PluginToolAdapter::new(
+            "files_list".into(),
+            "List".into(),
+            serde_json::json!({}),
+            process,
+        )
+        .with_dispatch();
+
+        let result = read
+            .execute(serde_json::json!({"path": "a"}))
+            .await
+            .unwrap();
+        assert_eq!(result.output, "ran files_read");
+        let result = list.execute(serde_json::json!({})).await.unwrap();
+        assert_eq!(result.output, "ran files_list");
+    }
+
+    // Progress notifications become events; the agent sees only the result
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn adapter_publishes_progress() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let script_path = dir.path().join("progress.sh");
+        std::fs::write(
+            &script_path,
+            r#"#!/bin/bash
+while IFS= read -r line; do
+    id=$(echo "$line" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+    echo '{"jsonrpc":"2.0","method":"progress","params":{"message":"indexing"}}'
+    echo "{\"jsonrpc\":\"2.0\",\"result\":{\"output\":\"indexed\",\"success\":true},\"id\":$id}"
+done
+"#,
+        )
+        .unwrap();
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        std::fs::File::open(&script_path)
+            .unwrap()
+            .sync_all()
+            .unwrap();
+
+        let bus: Arc<dyn EventBus> = Arc::new(crate::event_bus::TokioBroadcastBus::new(16));
+        let mut rx = bus.subscribe();
+        let process = Arc::new(Mutex::new(PluginProcess::new(
+            "indexer",
+            script_path,
+            10,
+            3,
+        )));
+        let adapter = PluginToolAdapter::new(
+            "indexer".into(),
+            "Index".into(),
+            serde_json::json!({}),
+            process,
+        )
+        .with_event_bus(bus);
+
+        let result = adapter.execute(serde_json::json!({})).await.unwrap();
+        assert_eq!(result.output, "indexed");
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            AppEvent::PluginToolProgress { tool_name, message }
+                if tool_name == "indexer" && message == "indexing"
+        ));
+    }
+
     // 9.1.31 — Adapter end-to-end: real word-count plugin
     #[tokio::test]
     async fn adapter_real_word_count() {
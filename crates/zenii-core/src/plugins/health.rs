@@ -0,0 +1,141 @@
+//! Health state of activated plugins, fed by periodic ping probes.
+//!
+//! A plugin starts out `unknown` and becomes `healthy` on its first answered
+//! probe. After `failure_threshold` failed probes in a row it moves to
+//! `unhealthy` and stays there until a probe succeeds again. Plugins whose
+//! processes have not been started yet are not probed and keep their state.
+
+use std::time::Duration;
+
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum PluginHealthState {
+    #[default]
+    Unknown,
+    Healthy,
+    Unhealthy,
+}
+
+/// Latest probe outcome for one plugin.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct PluginHealth {
+    pub state: PluginHealthState,
+    /// Round trip of the last answered ping, in milliseconds.
+    pub latency_ms: Option<u64>,
+    pub consecutive_failures: u32,
+    pub last_error: Option<String>,
+    /// RFC 3339 time of the last probe.
+    pub checked_at: Option<String>,
+}
+
+/// Per-plugin health, keyed by plugin name.
+pub struct PluginHealthMonitor {
+    failure_threshold: u32,
+    health: DashMap<String, PluginHealth>,
+}
+
+impl PluginHealthMonitor {
+    pub fn new(failure_threshold: u32) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            health: DashMap::new(),
+        }
+    }
+
+    /// Record a probe outcome (`Ok(None)` means nothing was probed). Returns
+    /// the new health when the plugin's state changed.
+    pub fn record(&self, plugin: &str, outcome: Result<Option<Duration>>) -> Option<PluginHealth> {
+        let mut entry = self.health.entry(plugin.to_string()).or_default();
+        let before = entry.state;
+        match outcome {
+            Ok(None) => return None,
+            Ok(Some(latency)) => {
+                entry.state = PluginHealthState::Healthy;
+                entry.latency_ms = Some(latency.as_millis() as u64);
+                entry.consecutive_failures = 0;
+                entry.last_error = None;
+            }
+            Err(e) => {
+                entry.consecutive_failures += 1;
+                entry.last_error = Some(e.to_string());
+                if entry.consecutive_failures >= self.failure_threshold {
+                    entry.state = PluginHealthState::Unhealthy;
+                }
+            }
+        }
+        entry.checked_at = Some(chrono::Utc::now().to_rfc3339());
+        (entry.state != before).then(|| entry.clone())
+    }
+
+    pub fn get(&self, plugin: &str) -> PluginHealth {
+        self.health
+            .get(plugin)
+            .map(|h| h.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn remove(&self, plugin: &str) {
+        self.health.remove(plugin);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ZeniiError;
+
+    fn fail() -> Result<Option<Duration>> {
+        Err(ZeniiError::Plugin("no pong".into()))
+    }
+
+    // PH.3 — Repeated failed probes mark a plugin unhealthy; a pong restores it
+    #[test]
+    fn transitions_after_threshold() {
+        let monitor = PluginHealthMonitor::new(3);
+        assert_eq!(monitor.get("weather").state, PluginHealthState::Unknown);
+
+        let changed = monitor
+            .record("weather", Ok(Some(Duration::from_millis(12))))
+            .unwrap();
+        assert_eq!(changed.state, PluginHealthState::Healthy);
+        assert_eq!(changed.latency_ms, Some(12));
+
+        assert!(monitor.record("weather", fail()).is_none());
+        assert!(monitor.record("weather", fail()).is_none());
+        let changed = monitor.record("weather", fail()).unwrap();
+        assert_eq!(changed.state, PluginHealthState::Unhealthy);
+        assert_eq!(changed.consecutive_failures, 3);
+        assert_eq!(changed.last_error.as_deref(), Some("plugin error: no pong"));
+        assert!(monitor.record("weather", fail()).is_none());
+
+        let changed = monitor
+            .record("weather", Ok(Some(Duration::from_millis(3))))
+            .unwrap();
+        assert_eq!(changed.state, PluginHealthState::Healthy);
+        assert_eq!(changed.consecutive_failures, 0);
+    }
+
+    // PH.4 — Unprobed plugins keep their state, and a success resets the streak
+    #[test]
+    fn idle_and_success_reset() {
+        let monitor = PluginHealthMonitor::new(2);
+        assert!(monitor.record("idle", Ok(None)).is_none());
+        assert!(monitor.get("idle").checked_at.is_none());
+
+        monitor.record("flaky", fail());
+        monitor.record("flaky", Ok(Some(Duration::ZERO)));
+        monitor.record("flaky", fail());
+        assert_eq!(monitor.get("flaky").state, PluginHealthState::Healthy);
+        assert_eq!(monitor.get("flaky").consecutive_failures, 1);
+
+        monitor.remove("flaky");
+        assert_eq!(monitor.get("flaky").state, PluginHealthState::Unknown);
+    }
+}
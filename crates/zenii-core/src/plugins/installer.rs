@@ -1,13 +1,16 @@
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
-use tokio::sync::Mutex;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use super::adapter::PluginToolAdapter;
+use super::health::{PluginHealth, PluginHealthMonitor};
 use super::manifest::PluginManifest;
 use super::process::PluginProcess;
 use super::registry::{InstalledPlugin, PluginRegistry, PluginSource};
+use crate::event_bus::EventBus;
 use crate::skills::SkillRegistry;
 use crate::tools::ToolRegistry;
 use crate::{Result, ZeniiError};
@@ -19,6 +22,27 @@ pub struct PluginInstaller {
     skill_registry: Arc<SkillRegistry>,
     execute_timeout_secs: u64,
     max_restart_attempts: u32,
+    /// Tool names currently registered by each plugin, so they can be
+    /// swapped or removed live.
+    active_tools: DashMap<String, Vec<String>>,
+    /// Process handles behind each active plugin's tools, for health probes.
+    processes: DashMap<String, Vec<Arc<tokio::sync::Mutex<PluginProcess>>>>,
+    health: PluginHealthMonitor,
+    /// Receives progress notifications from running plugin tools.
+    event_bus: Option<Arc<dyn EventBus>>,
+}
+
+/// Result of a live plugin reload.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct PluginReloadSummary {
+    /// Tools registered that were not active before the reload.
+    pub added: Vec<String>,
+    /// Tools unregistered because their plugin was removed, disabled or
+    /// no longer declares them.
+    pub removed: Vec<String>,
+    /// Tools that stayed active and were swapped for a fresh instance.
+    pub reloaded: Vec<String>,
 }
 
 impl PluginInstaller {
@@ -35,9 +59,25 @@ impl PluginInstaller {
             skill_registry,
             execute_timeout_secs,
             max_restart_attempts,
+            active_tools: DashMap::new(),
+            processes: DashMap::new(),
+            health: PluginHealthMonitor::new(3),
+            event_bus: None,
         }
     }
 
+    /// Publish plugin tool progress on this bus (builder pattern).
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// Failed probes in a row before a plugin is marked unhealthy (builder pattern).
+    pub fn with_health_failure_threshold(mut self, threshold: u32) -> Self {
+        self.health = PluginHealthMonitor::new(threshold);
+        self
+    }
+
     /// Install a plugin from a git URL.
     ///
     /// Supports monorepo subdirectories via URL fragment:
@@ -141,7 +181,7 @@ impl PluginInstaller {
         };
 
         // Register plugin, tools, and skills
-        self.register_plugin_assets(&installed).await?;
+        self.activate(&installed).await?;
         self.registry.register(installed.clone())?;
 
         info!("Installed plugin '{}' from git", name);
@@ -181,7 +221,7 @@ impl PluginInstaller {
             },
         };
 
-        self.register_plugin_assets(&installed).await?;
+        self.activate(&installed).await?;
         self.registry.register(installed.clone())?;
 
         info!("Installed plugin '{}' from local path", name);
@@ -265,49 +305,54 @@ impl PluginInstaller {
                 .map_err(|e| ZeniiError::Plugin(format!("remove dir failed: {e}")))?;
         }
 
+        self.deactivate(name);
         self.registry.unregister(name)?;
         info!("Removed plugin '{}'", name);
         Ok(())
     }
 
     /// Register plugin tools and skills into their respective registries.
-    async fn register_plugin_assets(&self, plugin: &InstalledPlugin) -> Result<()> {
+    ///
+    /// Tools this plugin already owns are swapped in place; any other name
+    /// clash (e.g. with a built-in tool) is rejected. Calls already running
+    /// against a swapped tool finish on the old instance.
+    pub async fn activate(&self, plugin: &InstalledPlugin) -> Result<()> {
+        let plugin_name = &plugin.manifest.plugin.name;
+        let previous = self
+            .active_tools
+            .get(plugin_name)
+            .map(|r| r.value().clone())
+            .unwrap_or_default();
+
         // Register tools
-        for tool_def in &plugin.manifest.tools {
-            let binary = plugin.install_path.join(&tool_def.binary);
-
-            // Fetch real schema from the plugin's info() JSON-RPC method
-            let schema = super::fetch_plugin_schema(
-                &binary,
-                &tool_def.name,
-                self.execute_timeout_secs,
-                self.max_restart_attempts,
-            )
-            .await;
-
-            // Create a fresh process for the adapter (the one used for schema fetch is consumed)
-            let process = PluginProcess::new(
-                &tool_def.name,
-                binary,
-                self.execute_timeout_secs,
-                self.max_restart_attempts,
-            );
-            let adapter = PluginToolAdapter::new(
-                tool_def.name.clone(),
-                tool_def.description.clone(),
-                schema,
-                Arc::new(Mutex::new(process)),
-            );
-            self.tool_registry
-                .register(Arc::new(adapter))
-                .unwrap_or_else(|e| {
-                    tracing::warn!(
-                        "Failed to register tool '{}' from plugin '{}': {e}",
-                        tool_def.name,
-                        plugin.manifest.plugin.name
-                    );
-                });
+        let (tools, processes) = super::build_plugin_tools(
+            plugin,
+            self.execute_timeout_secs,
+            self.max_restart_attempts,
+            self.event_bus.clone(),
+        )
+        .await;
+        let mut active = Vec::with_capacity(tools.len());
+        for tool in tools {
+            let name = tool.name().to_string();
+            if previous.contains(&name) {
+                self.tool_registry.replace(tool);
+                active.push(name);
+            } else {
+                match self.tool_registry.register(tool) {
+                    Ok(()) => active.push(name),
+                    Err(e) => tracing::warn!(
+                        "Failed to register tool '{name}' from plugin '{plugin_name}': {e}"
+                    ),
+                }
+            }
         }
+        for stale in previous.iter().filter(|n| !active.contains(n)) {
+            self.tool_registry.unregister(stale);
+        }
+        self.active_tools.insert(plugin_name.clone(), active);
+        self.processes.insert(plugin_name.clone(), processes);
+        self.health.remove(plugin_name);
 
         // Register skills
         for skill_def in &plugin.manifest.skills {
@@ -325,6 +370,129 @@ impl PluginInstaller {
         Ok(())
     }
 
+    /// Unregister the tools a plugin registered. Returns the removed names.
+    pub fn deactivate(&self, name: &str) -> Vec<String> {
+        self.processes.remove(name);
+        self.health.remove(name);
+        let Some((_, tools)) = self.active_tools.remove(name) else {
+            return Vec::new();
+        };
+        for tool in &tools {
+            self.tool_registry.unregister(tool);
+        }
+        tools
+    }
+
+    /// Ping every running process of each active plugin and record the
+    /// outcome. A process that is busy with a tool call is not probed. Returns
+    /// the plugins whose health state changed.
+    pub async fn probe_health(&self, timeout: Duration) -> Vec<(String, PluginHealth)> {
+        let targets: Vec<(String, Vec<Arc<tokio::sync::Mutex<PluginProcess>>>)> = self
+            .processes
+            .iter()
+            .map(|r| (r.key().clone(), r.value().clone()))
+            .collect();
+
+        let mut changed = Vec::new();
+        for (plugin, processes) in targets {
+            let mut outcome = Ok(None);
+            for process in processes {
+                let Ok(mut process) = process.try_lock() else {
+                    continue;
+                };
+                match process.ping(timeout).await {
+                    Ok(Some(latency)) => {
+                        if let Ok(prev) = &outcome {
+                            outcome = Ok(Some(prev.map_or(latency, |p: Duration| p.max(latency))));
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        outcome = Err(e);
+                        break;
+                    }
+                }
+            }
+            if let Some(health) = self.health.record(&plugin, outcome) {
+                changed.push((plugin, health));
+            }
+        }
+        changed
+    }
+
+    /// Health of every active plugin, sorted by name.
+    pub fn health_report(&self) -> Vec<(String, PluginHealth)> {
+        let mut report: Vec<(String, PluginHealth)> = self
+            .active_tools
+            .iter()
+            .map(|r| (r.key().clone(), self.health.get(r.key())))
+            .collect();
+        report.sort_by(|a, b| a.0.cmp(&b.0));
+        report
+    }
+
+    /// Rescan the plugins directory and bring the live `ToolRegistry` in line
+    /// with it: new or re-enabled plugins are activated, removed or disabled
+    /// ones deactivated, and the rest re-registered from their current manifest.
+    pub async fn reload(&self) -> Result<PluginReloadSummary> {
+        self.registry.rescan()?;
+
+        let before: Vec<String> = self
+            .active_tools
+            .iter()
+            .flat_map(|r| r.value().clone())
+            .collect();
+
+        let plugins = self.registry.list();
+        let stale: Vec<String> = self
+            .active_tools
+            .iter()
+            .map(|r| r.key().clone())
+            .filter(|name| {
+                !plugins
+                    .iter()
+                    .any(|p| p.enabled && &p.manifest.plugin.name == name)
+            })
+            .collect();
+        for name in stale {
+            self.deactivate(&name);
+        }
+        for plugin in plugins.iter().filter(|p| p.enabled) {
+            self.activate(plugin).await?;
+        }
+
+        let after: Vec<String> = self
+            .active_tools
+            .iter()
+            .flat_map(|r| r.value().clone())
+            .collect();
+
+        let summary = PluginReloadSummary {
+            added: after
+                .iter()
+                .filter(|n| !before.contains(n))
+                .cloned()
+                .collect(),
+            removed: before
+                .iter()
+                .filter(|n| !after.contains(n))
+                .cloned()
+                .collect(),
+            reloaded: after
+                .iter()
+                .filter(|n| before.contains(n))
+                .cloned()
+                .collect(),
+        };
+        info!(
+            "Reloaded plugins: {} added, {} removed, {} reloaded",
+            summary.added.len(),
+            summary.removed.len(),
+            summary.reloaded.len()
+        );
+        Ok(summary)
+    }
+
     /// Recursively copy a directory.
     fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
         std::fs::create_dir_all(dst)
@@ -444,6 +612,85 @@ binary = "{name}-tool"
         );
     }
 
+    // PH.5 — Active plugins are reported; idle processes are not probed
+    #[tokio::test]
+    async fn health_report_covers_active_plugins() {
+        let (_plugins_dir, _skills_dir, registry, tool_registry, skill_registry) = setup_test_env();
+        let source_dir = TempDir::new().unwrap();
+        let plugin_path = create_local_plugin(&source_dir, "probed");
+
+        let installer = PluginInstaller::new(registry, tool_registry, skill_registry, 60, 3)
+            .with_health_failure_threshold(2);
+        installer.install_from_local(&plugin_path).await.unwrap();
+
+        assert!(
+            installer
+                .probe_health(Duration::from_secs(1))
+                .await
+                .is_empty()
+        );
+        let report = installer.health_report();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].0, "probed");
+        assert_eq!(
+            report[0].1.state,
+            crate::plugins::health::PluginHealthState::Unknown
+        );
+
+        installer.deactivate("probed");
+        assert!(installer.health_report().is_empty());
+    }
+
+    // Reload activates new plugins and drops disabled ones without a restart
+    #[tokio::test]
+    async fn reload_updates_live_tool_registry() {
+        let (plugins_dir, _skills_dir, registry, tool_registry, skill_registry) = setup_test_env();
+        let source_dir = TempDir::new().unwrap();
+        let plugin_path = create_local_plugin(&source_dir, "hot");
+
+        let installer = PluginInstaller::new(
+            registry.clone(),
+            tool_registry.clone(),
+            skill_registry,
+            60,
+            3,
+        );
+        installer.install_from_local(&plugin_path).await.unwrap();
+        let in_flight = tool_registry.get("hot-tool").unwrap();
+
+        // Drop a new plugin straight into the plugins dir and disable the old one
+        create_local_plugin(&plugins_dir, "fresh");
+        registry.disable("hot").unwrap();
+
+        let summary = installer.reload().await.unwrap();
+        assert_eq!(summary.added, vec!["fresh-tool".to_string()]);
+        assert_eq!(summary.removed, vec!["hot-tool".to_string()]);
+        assert!(tool_registry.get("hot-tool").is_none());
+        assert!(tool_registry.get("fresh-tool").is_some());
+        assert_eq!(in_flight.name(), "hot-tool");
+
+        // A second reload just refreshes what is active
+        let summary = installer.reload().await.unwrap();
+        assert!(summary.added.is_empty() && summary.removed.is_empty());
+        assert_eq!(summary.reloaded, vec!["fresh-tool".to_string()]);
+    }
+
+    // Removing a plugin unregisters its tools
+    #[tokio::test]
+    async fn remove_unregisters_tools() {
+        let (_plugins_dir, _skills_dir, registry, tool_registry, skill_registry) = setup_test_env();
+        let source_dir = TempDir::new().unwrap();
+        let plugin_path = create_local_plugin(&source_dir, "gone");
+
+        let installer =
+            PluginInstaller::new(registry, tool_registry.clone(), skill_registry, 60, 3);
+        installer.install_from_local(&plugin_path).await.unwrap();
+        assert!(tool_registry.get("gone-tool").is_some());
+
+        installer.remove("gone").await.unwrap();
+        assert!(tool_registry.get("gone-tool").is_none());
+    }
+
     // 9.0.18 — Install validates manifest
     #[tokio::test]
     async fn install_validates_manifest() {
@@ -14,6 +14,22 @@ pub struct PluginManifest {
     pub skills: Vec<PluginSkillDef>,
     #[serde(default)]
     pub config: HashMap<String, PluginConfigField>,
+    #[serde(default)]
+    pub limits: PluginLimits,
+}
+
+/// Resource limits for the plugin's processes. Per-call time limits are set
+/// per tool with `timeout_secs`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct PluginLimits {
+    /// Address-space limit in MiB. Enforced with `setrlimit(RLIMIT_AS)` on
+    /// Unix; ignored elsewhere. Runtimes that reserve large virtual ranges
+    /// (Node, the JVM) need a generous value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_limit_mb: Option<u64>,
+    /// Largest JSON-RPC response line accepted from the process, in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_bytes: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,8 +48,16 @@ pub struct PluginToolDef {
     pub name: String,
     pub description: String,
     pub binary: String,
+    /// JSON Schema for the tool's arguments. When absent the schema is fetched
+    /// from the plugin's `info` method.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
     #[serde(default)]
     pub permissions: PluginPermissions,
+    /// Per-call execute timeout in seconds, overriding
+    /// `plugin_execute_timeout_secs`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -65,6 +89,17 @@ pub struct PluginConfigField {
 }
 
 impl PluginManifest {
+    /// Whether `tool` shares its binary with another tool in this manifest.
+    /// Shared binaries run as one process and receive the tool name on
+    /// `execute` so they can dispatch.
+    pub fn shares_binary(&self, tool: &PluginToolDef) -> bool {
+        self.tools
+            .iter()
+            .filter(|t| t.binary == tool.binary)
+            .nth(1)
+            .is_some()
+    }
+
     /// Parse a manifest from a TOML string.
     pub fn parse(content: &str) -> Result<Self> {
         let manifest: Self = toml::from_str(content)
@@ -99,7 +134,8 @@ impl PluginManifest {
                 "plugin name must be alphanumeric with hyphens/underscores only".into(),
             ));
         }
-        // Validate tool binary paths are relative
+        // Validate tool binary paths are relative and tool names unique
+        let mut seen = std::collections::HashSet::new();
         for tool in &self.tools {
             if Path::new(&tool.binary).is_absolute() {
                 return Err(ZeniiError::Plugin(format!(
@@ -107,6 +143,25 @@ impl PluginManifest {
                     tool.name
                 )));
             }
+            if !seen.insert(tool.name.as_str()) {
+                return Err(ZeniiError::Plugin(format!(
+                    "tool '{}' is declared more than once",
+                    tool.name
+                )));
+            }
+            if let Some(params) = &tool.parameters
+                && !params.is_object()
+            {
+                return Err(ZeniiError::Plugin(format!(
+                    "tool '{}' parameters must be a table",
+                    tool.name
+                )));
+            }
+        }
+        if self.limits.memory_limit_mb == Some(0) || self.limits.max_output_bytes == Some(0) {
+            return Err(ZeniiError::Plugin(
+                "limits.memory_limit_mb and limits.max_output_bytes must be > 0".into(),
+            ));
         }
         // Validate skill file paths are relative
         for skill in &self.skills {
@@ -493,4 +548,92 @@ binary = "/usr/bin/test"
         }
         assert_eq!(names.len(), 10);
     }
+
+    // Multiple tools can share one binary with inline parameter schemas
+    #[test]
+    fn parse_sub_tools_with_parameters() {
+        let toml = r#"
+[plugin]
+name = "files"
+version = "1.0.0"
+description = "File operations"
+
+[[tools]]
+name = "files_read"
+description = "Read a file"
+binary = "files-server"
+
+[tools.parameters]
+type = "object"
+required = ["path"]
+
+[tools.parameters.properties.path]
+type = "string"
+
+[[tools]]
+name = "files_list"
+description = "List a directory"
+binary = "files-server"
+
+[[tools]]
+name = "files_other"
+description = "Standalone"
+binary = "other"
+"#;
+        let m = PluginManifest::parse(toml).unwrap();
+        assert_eq!(m.tools.len(), 3);
+        let params = m.tools[0].parameters.as_ref().unwrap();
+        assert_eq!(params["required"][0], "path");
+        assert_eq!(params["properties"]["path"]["type"], "string");
+        assert!(m.tools[1].parameters.is_none());
+        assert!(m.shares_binary(&m.tools[0]));
+        assert!(m.shares_binary(&m.tools[1]));
+        assert!(!m.shares_binary(&m.tools[2]));
+    }
+
+    #[test]
+    fn duplicate_tool_names_rejected() {
+        let toml = r#"
+[plugin]
+name = "dup"
+version = "1.0.0"
+description = "Dup"
+
+[[tools]]
+name = "same"
+description = "A"
+binary = "bin"
+
+[[tools]]
+name = "same"
+description = "B"
+binary = "bin"
+"#;
+        assert!(PluginManifest::parse(toml).is_err());
+    }
+
+    #[test]
+    fn parse_manifest_limits() {
+        let toml = r#"
+[plugin]
+name = "limited"
+version = "0.1.0"
+description = "Limited plugin"
+
+[limits]
+memory_limit_mb = 256
+max_output_bytes = 65536
+
+[[tools]]
+name = "limited"
+description = "Limited tool"
+binary = "bin/limited"
+"#;
+        let m = PluginManifest::parse(toml).unwrap();
+        assert_eq!(m.limits.memory_limit_mb, Some(256));
+        assert_eq!(m.limits.max_output_bytes, Some(65536));
+
+        let zero = toml.replace("memory_limit_mb = 256", "memory_limit_mb = 0");
+        assert!(PluginManifest::parse(&zero).is_err());
+    }
 }
@@ -1,6 +1,14 @@
+use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::event_bus::EventBus;
+use crate::tools::Tool;
 
 pub mod adapter;
+pub mod health;
 pub mod installer;
 pub mod manifest;
 pub mod process;
@@ -43,6 +51,66 @@ pub async fn fetch_plugin_schema(
     schema
 }
 
+/// Build the `Tool` adapters for every `[[tools]]` entry of an installed plugin.
+///
+/// Entries that share a binary share one process and are invoked with their
+/// tool name so the plugin can dispatch. A tool's schema comes from its
+/// manifest `parameters` table, falling back to the plugin's `info()` response.
+/// The shared process handles are returned alongside so they can be probed.
+/// When an event bus is given, progress notifications from the tools are
+/// published on it.
+pub async fn build_plugin_tools(
+    plugin: &InstalledPlugin,
+    timeout_secs: u64,
+    max_restart: u32,
+    event_bus: Option<Arc<dyn EventBus>>,
+) -> (Vec<Arc<dyn Tool>>, Vec<Arc<Mutex<process::PluginProcess>>>) {
+    let mut processes: HashMap<&str, Arc<Mutex<process::PluginProcess>>> = HashMap::new();
+    let mut tools: Vec<Arc<dyn Tool>> = Vec::new();
+
+    for tool_def in &plugin.manifest.tools {
+        let binary = plugin.install_path.join(&tool_def.binary);
+
+        let schema = match &tool_def.parameters {
+            Some(params) => params.clone(),
+            None => fetch_plugin_schema(&binary, &tool_def.name, timeout_secs, max_restart).await,
+        };
+
+        let process = processes
+            .entry(tool_def.binary.as_str())
+            .or_insert_with(|| {
+                Arc::new(Mutex::new(
+                    process::PluginProcess::new(&tool_def.name, binary, timeout_secs, max_restart)
+                        .with_limits(plugin.manifest.limits.clone()),
+                ))
+            })
+            .clone();
+
+        let adapter = adapter::PluginToolAdapter::new(
+            tool_def.name.clone(),
+            tool_def.description.clone(),
+            schema,
+            process,
+        );
+        let adapter = if plugin.manifest.shares_binary(tool_def) {
+            adapter.with_dispatch()
+        } else {
+            adapter
+        };
+        let adapter = match tool_def.timeout_secs {
+            Some(secs) => adapter.with_timeout(std::time::Duration::from_secs(secs)),
+            None => adapter,
+        };
+        let adapter = match &event_bus {
+            Some(bus) => adapter.with_event_bus(bus.clone()),
+            None => adapter,
+        };
+        tools.push(Arc::new(adapter));
+    }
+
+    (tools, processes.into_values().collect())
+}
+
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use std::path::PathBuf;
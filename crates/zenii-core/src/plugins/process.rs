@@ -4,13 +4,17 @@ use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{Child, ChildStdin, ChildStdout, Command};
 use tracing::{debug, warn};
 
+use super::manifest::PluginLimits;
 use crate::tools::ToolResult;
 use crate::{Result, ZeniiError};
 
+/// Response size cap when the manifest sets no `limits.max_output_bytes`.
+pub const DEFAULT_MAX_OUTPUT_BYTES: usize = 8 * 1024 * 1024;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct JsonRpcRequest {
     jsonrpc: String,
@@ -38,6 +42,34 @@ struct JsonRpcError {
     data: Option<Value>,
 }
 
+/// A line without an `id`: a notification the plugin sends while a request
+/// is in flight, e.g. `{"jsonrpc":"2.0","method":"progress","params":{"message":"..."}}`.
+#[derive(Debug, Clone, Deserialize)]
+struct JsonRpcNotification {
+    method: String,
+    #[serde(default)]
+    params: Option<Value>,
+    #[serde(default)]
+    id: Option<Value>,
+}
+
+impl JsonRpcNotification {
+    /// Parse `line` as a notification; responses and garbage yield `None`.
+    fn parse(line: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(line.trim_ascii())
+            .ok()
+            .filter(|n| n.id.is_none())
+    }
+
+    /// Text of a `progress` notification.
+    fn progress_message(&self) -> Option<&str> {
+        if self.method != "progress" {
+            return None;
+        }
+        self.params.as_ref()?.get("message")?.as_str()
+    }
+}
+
 /// Info returned by the `info` JSON-RPC method.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginToolInfo {
@@ -58,6 +90,9 @@ pub struct PluginProcess {
     execute_timeout: Duration,
     restart_attempts: u32,
     max_restart_attempts: u32,
+    limits: PluginLimits,
+    /// Set on the first successful spawn; idle plugins are not health-probed.
+    spawned: bool,
 }
 
 impl PluginProcess {
@@ -77,9 +112,22 @@ impl PluginProcess {
             execute_timeout: Duration::from_secs(execute_timeout_secs),
             restart_attempts: 0,
             max_restart_attempts,
+            limits: PluginLimits::default(),
+            spawned: false,
         }
     }
 
+    /// Apply the manifest's resource limits to every spawn (builder pattern).
+    pub fn with_limits(mut self, limits: PluginLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Time an `execute` call may take unless the tool overrides it.
+    pub fn execute_timeout(&self) -> Duration {
+        self.execute_timeout
+    }
+
     /// Spawn the plugin process.
     pub async fn spawn(&mut self) -> Result<()> {
         if self.is_running() {
@@ -92,15 +140,19 @@ impl PluginProcess {
             self.binary_path.display()
         );
 
-        let mut child = Command::new(&self.binary_path)
+        let mut command = Command::new(&self.binary_path);
+        command
             .stdin(std::process::Stdio::piped())
             .stdout(std::process::Stdio::piped())
             .stderr(std::process::Stdio::null())
-            .kill_on_drop(true)
-            .spawn()
-            .map_err(|e| {
-                ZeniiError::Plugin(format!("failed to spawn plugin '{}': {e}", self.name))
-            })?;
+            .kill_on_drop(true);
+        if let Some(mb) = self.limits.memory_limit_mb {
+            limit_memory(&mut command, &self.name, mb);
+        }
+
+        let mut child = command.spawn().map_err(|e| {
+            ZeniiError::Plugin(format!("failed to spawn plugin '{}': {e}", self.name))
+        })?;
 
         let stdin = child.stdin.take().ok_or_else(|| {
             ZeniiError::Plugin(format!("plugin '{}' stdin not available", self.name))
@@ -113,6 +165,7 @@ impl PluginProcess {
         self.stdin = Some(stdin);
         self.stdout_reader = Some(BufReader::new(stdout));
         self.restart_attempts = 0;
+        self.spawned = true;
 
         debug!("Plugin '{}' spawned successfully", self.name);
         Ok(())
@@ -129,6 +182,52 @@ impl PluginProcess {
 
     /// Send a JSON-RPC call and wait for the response.
     async fn call(&mut self, method: &str, params: Option<Value>) -> Result<Value> {
+        let timeout = self.execute_timeout;
+        self.call_within(method, params, timeout, &mut |_: &str| {})
+            .await?
+            .ok_or_else(|| {
+                ZeniiError::Plugin(format!(
+                    "plugin '{}' {method} timed out after {}s",
+                    self.name,
+                    timeout.as_secs()
+                ))
+            })
+    }
+
+    /// Send a JSON-RPC call and wait up to `timeout` for the response,
+    /// passing `progress` notifications that arrive first to `on_progress`.
+    /// Returns `None` when the deadline passes; the process is then killed and
+    /// reaped, and respawned on the next call.
+    async fn call_within(
+        &mut self,
+        method: &str,
+        params: Option<Value>,
+        timeout: Duration,
+        on_progress: &mut (dyn FnMut(&str) + Send),
+    ) -> Result<Option<Value>> {
+        let Some(response) = self.exchange(method, params, timeout, on_progress).await? else {
+            return Ok(None);
+        };
+        if let Some(error) = response.error {
+            Err(ZeniiError::Plugin(format!(
+                "plugin '{}' error ({}): {}",
+                self.name, error.code, error.message
+            )))
+        } else {
+            Ok(Some(response.result.unwrap_or(Value::Null)))
+        }
+    }
+
+    /// Write one request and read lines until its response, JSON-RPC errors
+    /// included. Notifications before the response are not answers: progress
+    /// goes to `on_progress`, anything else is ignored.
+    async fn exchange(
+        &mut self,
+        method: &str,
+        params: Option<Value>,
+        timeout: Duration,
+        on_progress: &mut (dyn FnMut(&str) + Send),
+    ) -> Result<Option<JsonRpcResponse>> {
         if !self.is_running() {
             self.try_restart().await?;
         }
@@ -163,37 +262,70 @@ impl PluginProcess {
             ZeniiError::Plugin(format!("failed to flush plugin '{}': {e}", self.name))
         })?;
 
-        // Read response with timeout
-        let mut response_line = String::new();
-        let read_result =
-            tokio::time::timeout(self.execute_timeout, reader.read_line(&mut response_line)).await;
+        // Read response with timeout, never buffering more than the output cap
+        // per line. The timeout covers the whole call, progress included.
+        let max_output = self
+            .limits
+            .max_output_bytes
+            .unwrap_or(DEFAULT_MAX_OUTPUT_BYTES);
+        let name = &self.name;
+        let read_result = tokio::time::timeout(timeout, async {
+            loop {
+                let mut line = Vec::new();
+                let n = (&mut *reader)
+                    .take(max_output as u64 + 1)
+                    .read_until(b'\n', &mut line)
+                    .await?;
+                if n == 0 || (n > max_output && !line.ends_with(b"\n")) {
+                    return Ok::<_, std::io::Error>((n, line));
+                }
+                match JsonRpcNotification::parse(&line) {
+                    Some(notification) => match notification.progress_message() {
+                        Some(message) => on_progress(message),
+                        None => debug!(
+                            "Plugin '{name}' sent notification '{}' during {method}, ignoring",
+                            notification.method
+                        ),
+                    },
+                    None => return Ok((n, line)),
+                }
+            }
+        })
+        .await;
 
         match read_result {
-            Ok(Ok(0)) => {
+            Ok(Ok((0, _))) => {
                 // Process closed stdout — it crashed
                 self.cleanup();
+                let hint = match self.limits.memory_limit_mb {
+                    Some(mb) => format!(" (it may have exceeded its {mb} MiB memory limit)"),
+                    None => String::new(),
+                };
                 Err(ZeniiError::Plugin(format!(
-                    "plugin '{}' closed unexpectedly",
+                    "plugin '{}' closed unexpectedly{hint}",
                     self.name
                 )))
             }
-            Ok(Ok(_)) => {
-                let response: JsonRpcResponse = serde_json::from_str(response_line.trim())
+            Ok(Ok((n, response_line))) if n > max_output && !response_line.ends_with(b"\n") => {
+                warn!(
+                    "Plugin '{}' {method} response exceeded {max_output} bytes, stopping process",
+                    self.name
+                );
+                self.terminate().await;
+                Err(ZeniiError::Plugin(format!(
+                    "plugin '{}' response exceeded the {max_output}-byte output limit; plugin process stopped",
+                    self.name
+                )))
+            }
+            Ok(Ok((_, response_line))) => {
+                let response: JsonRpcResponse = serde_json::from_slice(response_line.trim_ascii())
                     .map_err(|e| {
                         ZeniiError::Plugin(format!(
                             "plugin '{}' invalid JSON-RPC response: {e}",
                             self.name
                         ))
                     })?;
-
-                if let Some(error) = response.error {
-                    Err(ZeniiError::Plugin(format!(
-                        "plugin '{}' error ({}): {}",
-                        self.name, error.code, error.message
-                    )))
-                } else {
-                    Ok(response.result.unwrap_or(Value::Null))
-                }
+                Ok(Some(response))
             }
             Ok(Err(e)) => {
                 self.cleanup();
@@ -203,17 +335,41 @@ impl PluginProcess {
                 )))
             }
             Err(_) => {
-                // Timeout
-                self.cleanup();
-                Err(ZeniiError::Plugin(format!(
-                    "plugin '{}' execute timed out after {}s",
+                warn!(
+                    "Plugin '{}' {method} timed out after {}s, stopping process",
                     self.name,
-                    self.execute_timeout.as_secs()
-                )))
+                    timeout.as_secs()
+                );
+                self.terminate().await;
+                Ok(None)
             }
         }
     }
 
+    /// Probe the running process with a `ping` request and return the
+    /// round-trip latency. Any well-formed reply counts as a pong, so plugins
+    /// that answer `ping` with "method not found" are still alive. Returns
+    /// `None` when the process has never been started; a process that was
+    /// started and has since died is restarted, so a plugin that cannot come
+    /// back keeps failing the probe.
+    pub async fn ping(&mut self, timeout: Duration) -> Result<Option<Duration>> {
+        if !self.spawned {
+            return Ok(None);
+        }
+        let started = std::time::Instant::now();
+        match self
+            .exchange("ping", None, timeout, &mut |_: &str| {})
+            .await?
+        {
+            Some(_) => Ok(Some(started.elapsed())),
+            None => Err(ZeniiError::Plugin(format!(
+                "plugin '{}' did not answer ping within {}ms",
+                self.name,
+                timeout.as_millis()
+            ))),
+        }
+    }
+
     /// Call the `info` JSON-RPC method.
     pub async fn info(&mut self) -> Result<PluginToolInfo> {
         let result = self.call("info", None).await?;
@@ -222,9 +378,45 @@ impl PluginProcess {
         })
     }
 
-    /// Call the `execute` JSON-RPC method.
+    /// Call the `execute` JSON-RPC method with the process's default timeout.
     pub async fn execute(&mut self, args: Value) -> Result<ToolResult> {
-        let result = self.call("execute", Some(args)).await?;
+        let name = self.name.clone();
+        let timeout = self.execute_timeout;
+        self.execute_with_timeout(&name, args, timeout).await
+    }
+
+    /// Call the `execute` JSON-RPC method on behalf of `tool_name`. A call that
+    /// outlives `timeout` stops the process and yields a failed [`ToolResult`]
+    /// so the agent can carry on.
+    pub async fn execute_with_timeout(
+        &mut self,
+        tool_name: &str,
+        args: Value,
+        timeout: Duration,
+    ) -> Result<ToolResult> {
+        self.execute_with_progress(tool_name, args, timeout, &mut |_: &str| {})
+            .await
+    }
+
+    /// Like [`execute_with_timeout`](Self::execute_with_timeout), passing the
+    /// plugin's `progress` notifications to `on_progress` as they arrive. Only
+    /// the final response becomes the [`ToolResult`].
+    pub async fn execute_with_progress(
+        &mut self,
+        tool_name: &str,
+        args: Value,
+        timeout: Duration,
+        on_progress: &mut (dyn FnMut(&str) + Send),
+    ) -> Result<ToolResult> {
+        let Some(result) = self
+            .call_within("execute", Some(args), timeout, on_progress)
+            .await?
+        else {
+            return Ok(ToolResult::err(format!(
+                "tool '{tool_name}' timed out after {}s; plugin process stopped",
+                timeout.as_secs()
+            )));
+        };
 
         let output = result
             .get("output")
@@ -284,6 +476,14 @@ impl PluginProcess {
         self.spawn().await
     }
 
+    /// Kill the process and wait for it to exit so no zombie is left behind.
+    async fn terminate(&mut self) {
+        if let Some(ref mut child) = self.child {
+            let _ = child.kill().await;
+        }
+        self.cleanup();
+    }
+
     fn cleanup(&mut self) {
         if let Some(mut child) = self.child.take() {
             let _ = child.start_kill();
@@ -293,6 +493,34 @@ impl PluginProcess {
     }
 }
 
+/// Cap the child's address space at `mb` MiB once it is forked.
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, _name: &str, mb: u64) {
+    let bytes = mb.saturating_mul(1024 * 1024) as libc::rlim_t;
+    // SAFETY: the closure runs in the forked child before exec and only calls
+    // setrlimit, which is async-signal-safe.
+    #[allow(unsafe_code)]
+    unsafe {
+        command.pre_exec(move || {
+            let limit = libc::rlimit {
+                rlim_cur: bytes,
+                rlim_max: bytes,
+            };
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_command: &mut Command, name: &str, mb: u64) {
+    warn!(
+        "Plugin '{name}' sets a {mb} MiB memory limit, but memory limits are unavailable on this platform; only time limits apply"
+    );
+}
+
 impl Drop for PluginProcess {
     fn drop(&mut self) {
         self.cleanup();
@@ -415,9 +643,10 @@ done
 
         let mut process = PluginProcess::new("slow", script_path, 1, 0);
         process.spawn().await.unwrap();
-        let result = process.execute(serde_json::json!({})).await;
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("timed out"));
+        let result = process.execute(serde_json::json!({})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("timed out after 1s"));
+        assert!(!process.is_running());
     }
 
     // 9.0.10 — Process crash recovery
@@ -455,6 +684,164 @@ done
         }
     }
 
+    // 9.0.11 — Oversized responses are refused and the process is stopped
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn oversized_response_stops_process() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let script_path = dir.path().join("chatty-plugin.sh");
+        std::fs::write(
+            &script_path,
+            "#!/bin/bash\nwhile IFS= read -r line; do\nprintf '%0500d\\n' 0\ndone\n",
+        )
+        .unwrap();
+
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        // Brief yield to avoid "Text file busy" race on Linux
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let mut process =
+            PluginProcess::new("chatty", script_path, 5, 0).with_limits(PluginLimits {
+                max_output_bytes: Some(100),
+                ..Default::default()
+            });
+        process.spawn().await.unwrap();
+        let err = process.execute(serde_json::json!({})).await.unwrap_err();
+        assert!(err.to_string().contains("100-byte output limit"), "{err}");
+        assert!(!process.is_running());
+    }
+
+    // 9.0.12 — A memory limit is applied to the child process
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn memory_limit_applies_to_child() {
+        let (_dir, script_path) = mock_plugin_script();
+
+        let mut roomy =
+            PluginProcess::new("mock", script_path.clone(), 10, 0).with_limits(PluginLimits {
+                memory_limit_mb: Some(4096),
+                ..Default::default()
+            });
+        roomy.spawn().await.unwrap();
+        assert_eq!(roomy.info().await.unwrap().name, "mock");
+        roomy.shutdown().await.unwrap();
+
+        // 1 MiB of address space is too little to even start bash.
+        let mut starved =
+            PluginProcess::new("mock", script_path, 10, 0).with_limits(PluginLimits {
+                memory_limit_mb: Some(1),
+                ..Default::default()
+            });
+        let result = match starved.spawn().await {
+            Ok(()) => starved.info().await.map(|_| ()),
+            Err(e) => Err(e),
+        };
+        assert!(result.is_err());
+    }
+
+    // 9.0.13 — Progress notifications are forwarded; only the response is the result
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn progress_notifications_before_result() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let script_path = dir.path().join("progress-plugin.sh");
+        std::fs::write(
+            &script_path,
+            r#"#!/bin/bash
+while IFS= read -r line; do
+    id=$(echo "$line" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+    echo '{"jsonrpc":"2.0","method":"progress","params":{"message":"step 1/2"}}'
+    echo '{"jsonrpc":"2.0","method":"log","params":{"level":"debug"}}'
+    echo '{"jsonrpc":"2.0","method":"progress","params":{"message":"step 2/2"}}'
+    echo '{"jsonrpc":"2.0","result":{"output":"done","success":true},"id":'"$id"'}'
+done
+"#,
+        )
+        .unwrap();
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        // Brief yield to avoid "Text file busy" race on Linux
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let mut process = PluginProcess::new("progress", script_path, 10, 0);
+        process.spawn().await.unwrap();
+        let mut progress = Vec::new();
+        let result = process
+            .execute_with_progress(
+                "progress",
+                serde_json::json!({}),
+                Duration::from_secs(10),
+                &mut |message: &str| progress.push(message.to_string()),
+            )
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.output, "done");
+        assert_eq!(progress, ["step 1/2", "step 2/2"]);
+
+        // Without a listener the frames are skipped, and the stream stays in step
+        let result = process.execute(serde_json::json!({})).await.unwrap();
+        assert_eq!(result.output, "done");
+    }
+
+    // PH.1 — Ping measures a round trip; "method not found" still counts as a pong
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn ping_reports_latency() {
+        let (_dir, script_path) = mock_plugin_script();
+        let mut process = PluginProcess::new("mock", script_path, 10, 0);
+        assert!(
+            process
+                .ping(Duration::from_secs(5))
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        process.spawn().await.unwrap();
+        let latency = process.ping(Duration::from_secs(5)).await.unwrap();
+        assert!(latency.is_some());
+        // The stream stays in step: the next call reads its own reply
+        assert_eq!(process.info().await.unwrap().name, "mock");
+        process.shutdown().await.unwrap();
+    }
+
+    // PH.2 — A hung plugin fails the ping and is stopped
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn ping_times_out_on_hung_plugin() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let script_path = dir.path().join("hung-plugin.sh");
+        std::fs::write(
+            &script_path,
+            "#!/bin/bash\nwhile IFS= read -r line; do\nsleep 10\ndone\n",
+        )
+        .unwrap();
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        // Brief yield to avoid "Text file busy" race on Linux
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let mut process = PluginProcess::new("hung", script_path, 10, 0);
+        process.spawn().await.unwrap();
+        let err = process.ping(Duration::from_millis(200)).await.unwrap_err();
+        assert!(err.to_string().contains("did not answer ping"), "{err}");
+        assert!(!process.is_running());
+
+        // No restarts allowed, so the next probe fails too
+        assert!(process.ping(Duration::from_millis(200)).await.is_err());
+    }
+
     // ── Group D: Real plugin integration tests (9.1.21–9.1.30) ──
 
     use crate::plugins::test_helpers::{has_interpreter, real_plugins_path};
@@ -110,6 +110,9 @@ impl PluginRegistry {
             match PluginManifest::from_file(&manifest_path) {
                 Ok(manifest) => {
                     let name = manifest.plugin.name.clone();
+                    if self.plugins.contains_key(&name) {
+                        continue;
+                    }
                     self.plugins.insert(
                         name.clone(),
                         InstalledPlugin {
@@ -133,6 +136,25 @@ impl PluginRegistry {
         Ok(())
     }
 
+    /// Re-read plugins from disk without a restart.
+    ///
+    /// Plugins whose manifest disappeared are dropped, known plugins get their
+    /// manifest refreshed (keeping enabled state and source), and new plugin
+    /// directories are discovered as enabled.
+    pub fn rescan(&self) -> Result<()> {
+        self.plugins
+            .retain(|_, p| p.install_path.join("zenii-plugin.toml").exists());
+        for mut entry in self.plugins.iter_mut() {
+            let manifest_path = entry.install_path.join("zenii-plugin.toml");
+            match PluginManifest::from_file(&manifest_path) {
+                Ok(manifest) => entry.manifest = manifest,
+                Err(e) => tracing::warn!("Invalid plugin at {}: {e}", manifest_path.display()),
+            }
+        }
+        self.scan_directory()?;
+        self.save_index()
+    }
+
     /// List all installed plugins.
     pub fn list(&self) -> Vec<InstalledPlugin> {
         self.plugins.iter().map(|r| r.value().clone()).collect()
@@ -273,6 +295,25 @@ description = "Test""#,
         assert!(registry.get("test").is_none());
     }
 
+    // Rescan picks up new plugin dirs, keeps disabled state, drops removed ones
+    #[test]
+    fn registry_rescan_discovers_and_drops() {
+        let dir = TempDir::new().unwrap();
+        make_plugin_dir(dir.path(), "alpha");
+        let registry = PluginRegistry::new(dir.path().to_path_buf()).unwrap();
+        registry.disable("alpha").unwrap();
+
+        make_plugin_dir(dir.path(), "beta");
+        registry.rescan().unwrap();
+        assert!(!registry.get("alpha").unwrap().enabled);
+        assert!(registry.get("beta").unwrap().enabled);
+
+        std::fs::remove_dir_all(dir.path().join("alpha")).unwrap();
+        registry.rescan().unwrap();
+        assert!(registry.get("alpha").is_none());
+        assert_eq!(registry.list().len(), 1);
+    }
+
     // 9.0.14 — Registry enable/disable
     #[test]
     fn registry_enable_disable() {
@@ -2,6 +2,8 @@ use std::sync::Arc;
 
 use tracing::{info, warn};
 
+#[cfg(feature = "gateway")]
+use crate::ZeniiError;
 use crate::event_bus::{AppEvent, EventBus};
 #[cfg(feature = "gateway")]
 use crate::gateway::state::AppState;
@@ -15,6 +17,8 @@ use super::traits::{JobPayload, JobStatus, ScheduledJob};
 /// - AgentTurn: resolves agent, runs chat with timeout
 /// - Heartbeat: gathers sysinfo, publishes HeartbeatAlert
 /// - SendViaChannel: sends via channel registry (feature-gated)
+/// - ChannelNotify: sends a fixed message to one recipient (feature-gated)
+/// - DailySummary: summarizes the day's memories via the active model
 #[cfg(feature = "gateway")]
 pub async fn execute(
     job: &ScheduledJob,
@@ -30,7 +34,13 @@ pub async fn execute(
         JobPayload::SendViaChannel { channel, message } => {
             execute_send_via_channel(job, channel, message, app_state).await
         }
+        JobPayload::ChannelNotify {
+            channel,
+            recipient,
+            message,
+        } => execute_channel_notify(job, channel, recipient, message, app_state).await,
         JobPayload::Workflow { workflow_id } => execute_workflow(job, workflow_id, app_state).await,
+        JobPayload::DailySummary => execute_daily_summary(job, app_state, event_bus).await,
     };
 
     // Publish completion event
@@ -39,6 +49,7 @@ pub async fn execute(
         JobStatus::Failed => "failed",
         JobStatus::Stuck => "stuck",
         JobStatus::Skipped => "skipped",
+        JobStatus::Cancelled => "cancelled",
     };
     let _ = event_bus.publish(AppEvent::SchedulerJobCompleted {
         job_id: job.id.clone(),
@@ -78,6 +89,21 @@ async fn execute_agent_turn(
         return JobStatus::Skipped;
     };
 
+    // Cheap gate: a previous job already found no provider and nothing has changed since
+    if waiting_for_provider(state) {
+        tracing::debug!(
+            "Scheduler job '{}': AgentTurn skipped — waiting for a provider",
+            job.name
+        );
+        return JobStatus::Skipped;
+    }
+
+    if let Err(e) = state.budget.check(None).await {
+        warn!("Scheduler job '{}': AgentTurn refused: {e}", job.name);
+        let _ = event_bus.publish(AppEvent::agent_error(None, &e));
+        return JobStatus::Skipped;
+    }
+
     // Build full preamble so the agent has identity + environment + reasoning protocol
     let preamble = {
         let config = state.config.load();
@@ -122,20 +148,18 @@ async fn execute_agent_turn(
         }
     };
 
-    let agent =
-        match crate::ai::resolve_agent(None, state, None, preamble.as_deref(), "scheduler").await {
-            Ok(a) => a,
-            Err(e) => {
-                warn!(
-                    "Scheduler job '{}': AgentTurn failed to resolve agent: {e}",
-                    job.name
-                );
-                return JobStatus::Failed;
-            }
-        };
+    let agent = match resolve_job_agent(job, "AgentTurn", state, preamble.as_deref(), None).await {
+        Ok(a) => a,
+        Err(status) => return status,
+    };
 
     match state.reasoning_engine.chat(&agent, prompt, vec![]).await {
         Ok(chat_result) => {
+            if let Some(cost) = agent.estimate_cost(&chat_result.usage)
+                && let Err(e) = state.budget.record(None, cost).await
+            {
+                warn!("Scheduler job '{}': failed to record spend: {e}", job.name);
+            }
             let response = chat_result.response;
             info!(
                 "Scheduler job '{}': AgentTurn completed, response len={}",
@@ -152,6 +176,10 @@ async fn execute_agent_turn(
 
             JobStatus::Success
         }
+        Err(e) if e.is_cancelled() => {
+            info!("Scheduler job '{}': AgentTurn cancelled: {e}", job.name);
+            JobStatus::Cancelled
+        }
         Err(e) => {
             warn!("Scheduler job '{}': AgentTurn chat failed: {e}", job.name);
             JobStatus::Failed
@@ -159,6 +187,177 @@ async fn execute_agent_turn(
     }
 }
 
+/// Whether an earlier job already found no provider and nothing has changed
+/// since; a cheap gate before building prompts.
+#[cfg(feature = "gateway")]
+fn waiting_for_provider(state: &AppState) -> bool {
+    state
+        .scheduler
+        .as_ref()
+        .is_some_and(|s| s.provider_missing())
+}
+
+/// Resolve the agent for a scheduled job. On failure returns the status to
+/// report: `Skipped` while no provider is configured, `Failed` otherwise.
+#[cfg(feature = "gateway")]
+async fn resolve_job_agent(
+    job: &ScheduledJob,
+    kind: &str,
+    state: &Arc<AppState>,
+    preamble: Option<&str>,
+    tools: Option<Vec<Arc<dyn crate::tools::Tool>>>,
+) -> Result<Arc<crate::ai::ZeniiAgent>, JobStatus> {
+    match crate::ai::resolve_agent_with_tools(
+        None,
+        state,
+        None,
+        preamble,
+        tools,
+        "scheduler",
+        false,
+    )
+    .await
+    {
+        Ok(a) => {
+            if let Some(ref sched) = state.scheduler {
+                sched.set_provider_missing(false);
+            }
+            Ok(a)
+        }
+        Err(e @ (ZeniiError::ProviderNotConfigured(_) | ZeniiError::Credential(_))) => {
+            // Provider may be configured later; wait instead of tripping the circuit breaker
+            warn!(
+                "Scheduler job '{}': {kind} skipped until a provider is configured: {e}",
+                job.name
+            );
+            if let Some(ref sched) = state.scheduler {
+                sched.set_provider_missing(true);
+            }
+            Err(JobStatus::Skipped)
+        }
+        Err(e) => {
+            warn!(
+                "Scheduler job '{}': {kind} failed to resolve agent: {e}",
+                job.name
+            );
+            Err(JobStatus::Failed)
+        }
+    }
+}
+
+/// Execute a DailySummary payload: summarize today's (UTC) memories with the
+/// active model and store the digest under the `daily_summary` category.
+#[cfg(feature = "gateway")]
+async fn execute_daily_summary(
+    job: &ScheduledJob,
+    app_state: Option<&Arc<AppState>>,
+    event_bus: &Arc<dyn EventBus>,
+) -> JobStatus {
+    use crate::memory::daily_summary;
+
+    let Some(state) = app_state else {
+        warn!(
+            "Scheduler job '{}': DailySummary skipped — no AppState wired",
+            job.name
+        );
+        return JobStatus::Skipped;
+    };
+
+    if waiting_for_provider(state) {
+        tracing::debug!(
+            "Scheduler job '{}': DailySummary skipped — waiting for a provider",
+            job.name
+        );
+        return JobStatus::Skipped;
+    }
+
+    let date = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    let entries = match state.memory.entries_for_date(&date).await {
+        Ok(entries) => entries,
+        Err(e) => {
+            warn!(
+                "Scheduler job '{}': failed to load memories for {date}: {e}",
+                job.name
+            );
+            return JobStatus::Failed;
+        }
+    };
+    let Some(prompt) = daily_summary::build_prompt(&date, &entries) else {
+        info!(
+            "Scheduler job '{}': no memories recorded on {date}, nothing to summarize",
+            job.name
+        );
+        return JobStatus::Skipped;
+    };
+
+    if let Err(e) = state.budget.check(None).await {
+        warn!("Scheduler job '{}': DailySummary refused: {e}", job.name);
+        let _ = event_bus.publish(AppEvent::agent_error(None, &e));
+        return JobStatus::Skipped;
+    }
+
+    // No tools: the model only condenses the listed memories
+    let agent = match resolve_job_agent(
+        job,
+        "DailySummary",
+        state,
+        Some(daily_summary::PREAMBLE),
+        Some(vec![]),
+    )
+    .await
+    {
+        Ok(a) => a,
+        Err(status) => return status,
+    };
+
+    let response = match agent.prompt(&prompt).await {
+        Ok(r) => r,
+        Err(e) => {
+            warn!("Scheduler job '{}': DailySummary failed: {e}", job.name);
+            return JobStatus::Failed;
+        }
+    };
+    if let Some(cost) = agent.estimate_cost(&response.usage)
+        && let Err(e) = state.budget.record(None, cost).await
+    {
+        warn!("Scheduler job '{}': failed to record spend: {e}", job.name);
+    }
+
+    let summary = response.output.trim();
+    if summary.is_empty() {
+        warn!(
+            "Scheduler job '{}': model returned an empty summary",
+            job.name
+        );
+        return JobStatus::Failed;
+    }
+    match state
+        .memory
+        .store(
+            &daily_summary::key(&date),
+            summary,
+            daily_summary::category(),
+        )
+        .await
+    {
+        Ok(()) => {
+            info!(
+                "Scheduler job '{}': summarized {} memories for {date}",
+                job.name,
+                entries.len()
+            );
+            JobStatus::Success
+        }
+        Err(e) => {
+            warn!(
+                "Scheduler job '{}': failed to store daily summary: {e}",
+                job.name
+            );
+            JobStatus::Failed
+        }
+    }
+}
+
 /// Execute a Heartbeat payload: gather sysinfo, publish HeartbeatAlert.
 #[cfg(feature = "gateway")]
 async fn execute_heartbeat(
@@ -237,6 +436,53 @@ async fn execute_send_via_channel(
     }
 }
 
+/// Execute a ChannelNotify payload. Unlike SendViaChannel, a channel that is
+/// missing or rejects the message fails the job so it backs off and retries.
+#[cfg(feature = "gateway")]
+async fn execute_channel_notify(
+    job: &ScheduledJob,
+    channel: &str,
+    recipient: &str,
+    message: &str,
+    app_state: Option<&Arc<AppState>>,
+) -> JobStatus {
+    #[cfg(feature = "channels")]
+    {
+        let Some(state) = app_state else {
+            warn!(
+                "Scheduler job '{}': ChannelNotify skipped — no AppState wired",
+                job.name
+            );
+            return JobStatus::Skipped;
+        };
+
+        let ch_msg = crate::channels::message::ChannelMessage::new(channel, message)
+            .with_recipient(recipient);
+        match state.channel_registry.send(channel, ch_msg).await {
+            Ok(()) => {
+                info!(
+                    "Scheduler job '{}': notified {recipient} via channel '{channel}'",
+                    job.name
+                );
+                JobStatus::Success
+            }
+            Err(e) => {
+                warn!("Scheduler job '{}': ChannelNotify failed: {e}", job.name);
+                JobStatus::Failed
+            }
+        }
+    }
+    #[cfg(not(feature = "channels"))]
+    {
+        let _ = (app_state, channel, recipient, message);
+        warn!(
+            "Scheduler job '{}': ChannelNotify failed — channels feature not enabled",
+            job.name
+        );
+        JobStatus::Failed
+    }
+}
+
 /// Execute a Workflow payload.
 #[cfg(feature = "gateway")]
 async fn execute_workflow(
@@ -330,9 +576,10 @@ mod tests {
             enabled: true,
             error_count: 0,
             next_run: None,
-            active_hours: None,
+            active_hours: Vec::new(),
             delete_after_run: false,
             timeout_secs: None,
+            priority: None,
         }
     }
 
@@ -412,6 +659,31 @@ mod tests {
         assert_eq!(status, JobStatus::Skipped);
     }
 
+    #[tokio::test]
+    async fn agent_turn_refused_over_spend_cap() {
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let mut rx = bus.subscribe();
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        state.config.store(Arc::new(crate::config::AppConfig {
+            budget_daily_usd: Some(1.0),
+            ..Default::default()
+        }));
+        state.budget.record(None, 1.5).await.unwrap();
+
+        let job = make_job(
+            "test_agent",
+            JobPayload::AgentTurn {
+                prompt: "hello".into(),
+            },
+        );
+        let status = execute(&job, &bus, Some(&state)).await;
+        assert_eq!(status, JobStatus::Skipped);
+        assert!(matches!(
+            rx.recv().await.unwrap(),
+            AppEvent::AgentError { message, .. } if message.contains("daily spend cap")
+        ));
+    }
+
     // 8.6.1.12 — AgentTurn with AppState but no API key returns Failed
     #[tokio::test]
     async fn agent_turn_no_api_key_failed() {
@@ -429,6 +701,100 @@ mod tests {
         assert!(status == JobStatus::Failed || status == JobStatus::Skipped);
     }
 
+    // 8.6.1.12b — AgentTurn waits for a provider and runs once one is configured
+    #[tokio::test]
+    async fn agent_turn_runs_after_provider_configured() {
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let sched = state.scheduler.clone().unwrap();
+
+        // Nothing configured: no default model, no boot agent
+        crate::db::with_db(&state.db, |conn| {
+            conn.execute("DELETE FROM ai_models WHERE id = '_default_model'", [])?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let job = make_job(
+            "wait_for_provider",
+            JobPayload::AgentTurn {
+                prompt: "hello".into(),
+            },
+        );
+        assert_eq!(execute(&job, &bus, Some(&state)).await, JobStatus::Skipped);
+        assert!(sched.provider_missing());
+
+        // Mock OpenAI-compatible provider becomes available after construction
+        let url = crate::ai::test_support::openai_text_mock("scheduled reply").await;
+        state
+            .provider_registry
+            .add_user_provider(
+                "mock",
+                "Mock",
+                &url,
+                false,
+                &[("mock-model".into(), "Mock".into(), true)],
+            )
+            .await
+            .unwrap();
+        state
+            .provider_registry
+            .set_default_model("mock", "mock-model")
+            .await
+            .unwrap();
+
+        // Still gated until the provider cache is invalidated
+        assert_eq!(execute(&job, &bus, Some(&state)).await, JobStatus::Skipped);
+        sched.set_provider_missing(false); // what ProvidersChanged does in the tick loop
+
+        let mut rx = bus.subscribe();
+        assert_eq!(execute(&job, &bus, Some(&state)).await, JobStatus::Success);
+        assert!(!sched.provider_missing());
+        let event = rx.recv().await.unwrap();
+        assert!(matches!(
+            event,
+            AppEvent::SchedulerNotification { message, .. } if message.contains("scheduled reply")
+        ));
+    }
+
+    // DailySummary with nothing recorded today skips without touching the provider
+    #[tokio::test]
+    async fn daily_summary_skips_without_memories() {
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let job = make_job("daily", JobPayload::DailySummary);
+        assert_eq!(execute(&job, &bus, Some(&state)).await, JobStatus::Skipped);
+        assert_eq!(execute(&job, &bus, None).await, JobStatus::Skipped);
+    }
+
+    // DailySummary waits for a provider instead of failing into backoff
+    #[tokio::test]
+    async fn daily_summary_skips_without_provider() {
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let sched = state.scheduler.clone().unwrap();
+        crate::db::with_db(&state.db, |conn| {
+            conn.execute("DELETE FROM ai_models WHERE id = '_default_model'", [])?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+        state
+            .memory
+            .store(
+                "pref",
+                "Prefers tea",
+                crate::memory::traits::MemoryCategory::Core,
+            )
+            .await
+            .unwrap();
+
+        let job = make_job("daily", JobPayload::DailySummary);
+        assert_eq!(execute(&job, &bus, Some(&state)).await, JobStatus::Skipped);
+        assert!(sched.provider_missing());
+    }
+
     // 8.6.1.13 — SendViaChannel without channels feature returns Skipped
     #[tokio::test]
     async fn send_via_channel_skipped_without_feature() {
@@ -540,6 +906,99 @@ mod tests {
         );
     }
 
+    // 8.6.1.22 — ChannelNotify to a missing channel fails the job
+    #[tokio::test]
+    async fn channel_notify_failure_is_job_error() {
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+
+        let job = make_job(
+            "standup",
+            JobPayload::ChannelNotify {
+                channel: "nonexistent_channel".into(),
+                recipient: "123".into(),
+                message: "standup!".into(),
+            },
+        );
+        assert_eq!(execute(&job, &bus, Some(&state)).await, JobStatus::Failed);
+    }
+
+    // 8.6.1.23 — ChannelNotify sends the message to its recipient
+    #[cfg(feature = "channels")]
+    #[tokio::test]
+    async fn channel_notify_sends_to_recipient() {
+        use crate::channels::message::ChannelMessage;
+        use crate::channels::traits::{Channel, ChannelLifecycle, ChannelSender, ChannelStatus};
+
+        #[derive(Clone)]
+        struct CaptureChannel(Arc<parking_lot::Mutex<Vec<ChannelMessage>>>);
+
+        #[async_trait::async_trait]
+        impl ChannelSender for CaptureChannel {
+            fn channel_type(&self) -> &str {
+                "telegram"
+            }
+            async fn send_message(&self, message: ChannelMessage) -> crate::Result<()> {
+                self.0.lock().push(message);
+                Ok(())
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl ChannelLifecycle for CaptureChannel {
+            fn display_name(&self) -> &str {
+                "telegram"
+            }
+            async fn connect(&self) -> crate::Result<()> {
+                Ok(())
+            }
+            async fn disconnect(&self) -> crate::Result<()> {
+                Ok(())
+            }
+            fn status(&self) -> ChannelStatus {
+                ChannelStatus::Connected
+            }
+            fn create_sender(&self) -> Box<dyn ChannelSender> {
+                Box::new(self.clone())
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl Channel for CaptureChannel {
+            async fn listen(
+                &self,
+                _tx: tokio::sync::mpsc::Sender<ChannelMessage>,
+            ) -> crate::Result<()> {
+                Ok(())
+            }
+            async fn health_check(&self) -> bool {
+                true
+            }
+        }
+
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let (_dir, state) = crate::gateway::handlers::tests::test_state().await;
+        let sent = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        state
+            .channel_registry
+            .register(Arc::new(CaptureChannel(sent.clone())))
+            .unwrap();
+
+        let job = make_job(
+            "standup",
+            JobPayload::ChannelNotify {
+                channel: "telegram".into(),
+                recipient: "123".into(),
+                message: "standup!".into(),
+            },
+        );
+        assert_eq!(execute(&job, &bus, Some(&state)).await, JobStatus::Success);
+        let sent = sent.lock();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].content, "standup!");
+        assert_eq!(sent[0].metadata.get("chat_id").unwrap(), "123");
+    }
+
     // 8.6.1.14 — execute always publishes SchedulerJobCompleted
     #[tokio::test]
     async fn execute_publishes_completion() {
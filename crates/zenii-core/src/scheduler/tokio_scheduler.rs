@@ -7,8 +7,8 @@ use std::time::Duration;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use dashmap::DashMap;
-use tokio::sync::watch;
-use tracing::{error, info};
+use tokio::sync::{Notify, Semaphore, watch};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use crate::config::AppConfig;
@@ -26,6 +26,17 @@ fn normalize_cron_expr(expr: &str) -> String {
     }
 }
 
+/// Timezone of an active-hours window; `None` for host-local (validated on add).
+fn window_tz(window: &ActiveHours) -> Option<chrono_tz::Tz> {
+    window.tz.as_deref().and_then(|tz| tz.parse().ok())
+}
+
+/// Parse an IANA timezone name for a cron schedule.
+fn parse_cron_tz(tz: &str) -> Result<chrono_tz::Tz> {
+    tz.parse::<chrono_tz::Tz>()
+        .map_err(|_| ZeniiError::Validation(format!("unknown timezone '{tz}'")))
+}
+
 use super::heartbeat::backoff_secs;
 use super::traits::*;
 
@@ -42,6 +53,7 @@ type JobRow = (
     Option<String>, // active_hours_json
     i32,            // delete_after_run
     Option<i64>,    // timeout_secs
+    Option<i32>,    // priority
 );
 
 /// Tokio-driven scheduler with DashMap registry and SQLite persistence.
@@ -57,12 +69,29 @@ pub struct TokioScheduler {
     max_history_per_job: usize,
     error_backoff_secs: Vec<u64>,
     max_consecutive_failures: u32,
+    /// Caps concurrently executing jobs; due jobs without a permit stay due.
+    job_slots: Arc<Semaphore>,
+    /// Cancel signal of each executing job, keyed by job id.
+    running_jobs: Arc<DashMap<String, Arc<Notify>>>,
     running: AtomicBool,
     loop_handle: Arc<tokio::sync::Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    /// Cached provider resolution for AgentTurn jobs: set once a job finds no usable
+    /// provider, cleared when providers, credentials or config change.
+    provider_missing: Arc<AtomicBool>,
     #[cfg(feature = "gateway")]
     app_state: Arc<tokio::sync::OnceCell<Arc<AppState>>>,
 }
 
+/// Raw `job_executions` row: id, job_id, status, started_at, completed_at, error.
+type ExecutionRow = (
+    String,
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+);
+
 impl TokioScheduler {
     pub fn new(db: DbPool, event_bus: Arc<dyn EventBus>, config: &AppConfig) -> Arc<Self> {
         let (stop_tx, stop_rx) = watch::channel(false);
@@ -78,13 +107,27 @@ impl TokioScheduler {
             max_history_per_job: config.scheduler_max_history_per_job,
             error_backoff_secs: config.scheduler_error_backoff_secs.clone(),
             max_consecutive_failures: config.scheduler_max_consecutive_failures,
+            job_slots: Arc::new(Semaphore::new(config.scheduler_max_concurrent_jobs.max(1))),
+            running_jobs: Arc::new(DashMap::new()),
             running: AtomicBool::new(false),
             loop_handle: Arc::new(tokio::sync::Mutex::new(None)),
+            provider_missing: Arc::new(AtomicBool::new(false)),
             #[cfg(feature = "gateway")]
             app_state: Arc::new(tokio::sync::OnceCell::new()),
         })
     }
 
+    /// Whether the last AgentTurn resolution found no usable provider.
+    /// AgentTurn jobs are skipped (not failed) while this is set.
+    pub fn provider_missing(&self) -> bool {
+        self.provider_missing.load(Ordering::SeqCst)
+    }
+
+    /// Record the outcome of an AgentTurn provider resolution.
+    pub fn set_provider_missing(&self, missing: bool) {
+        self.provider_missing.store(missing, Ordering::SeqCst);
+    }
+
     /// Wire the scheduler with AppState for payload execution.
     /// Idempotent — subsequent calls are no-ops.
     #[cfg(feature = "gateway")]
@@ -105,7 +148,7 @@ impl TokioScheduler {
             let mut stmt = conn.prepare(
                 "SELECT id, name, schedule_json, session_target, payload_json, \
                  enabled, error_count, next_run, created_at, active_hours_json, \
-                 delete_after_run, timeout_secs FROM scheduled_jobs",
+                 delete_after_run, timeout_secs, priority FROM scheduled_jobs",
             )?;
             let jobs: Vec<JobRow> = stmt
                 .query_map([], |row| {
@@ -122,6 +165,7 @@ impl TokioScheduler {
                         row.get(9)?,
                         row.get(10)?,
                         row.get(11)?,
+                        row.get(12)?,
                     ))
                 })?
                 .filter_map(|r| {
@@ -147,6 +191,7 @@ impl TokioScheduler {
             active_hours_json,
             delete_after_run,
             timeout_secs_val,
+            priority,
         ) in rows
         {
             let schedule: Schedule = match serde_json::from_str(&schedule_json) {
@@ -165,8 +210,12 @@ impl TokioScheduler {
             let next_run = next_run_str
                 .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
                 .map(|dt| dt.with_timezone(&Utc));
-            let active_hours: Option<ActiveHours> =
-                active_hours_json.and_then(|s| serde_json::from_str(&s).ok());
+            // Rows written before jobs could have several windows hold one object
+            let active_hours = active_hours_json
+                .and_then(|s| {
+                    deserialize_active_hours(&mut serde_json::Deserializer::from_str(&s)).ok()
+                })
+                .unwrap_or_default();
 
             let job = ScheduledJob {
                 id: id.clone(),
@@ -180,6 +229,7 @@ impl TokioScheduler {
                 active_hours,
                 delete_after_run: delete_after_run != 0,
                 timeout_secs: timeout_secs_val.map(|v: i64| v as u64),
+                priority,
             };
             self.jobs.insert(id.clone(), job);
             count += 1;
@@ -198,6 +248,19 @@ impl TokioScheduler {
             }
         }
         info!("Scheduler loaded {count} job(s) from DB");
+
+        // Warm the history cache so it matches what is persisted
+        match Self::load_executions(&self.db, None, self.max_history_per_job).await {
+            Ok(executions) => {
+                for exec in executions {
+                    self.history
+                        .entry(exec.job_id.clone())
+                        .or_default()
+                        .push_back(exec);
+                }
+            }
+            Err(e) => warn!("Failed to load job history from DB: {e}"),
+        }
         Ok(count)
     }
 
@@ -209,30 +272,31 @@ impl TokioScheduler {
             .map_err(|e| ZeniiError::Scheduler(e.to_string()))?;
         let session_target = format!("{:?}", job.session_target).to_lowercase();
         let next_run = job.next_run.map(|t| t.to_rfc3339());
-        let active_hours_json = job
-            .active_hours
-            .as_ref()
-            .and_then(|h| serde_json::to_string(h).ok());
+        let active_hours_json = (!job.active_hours.is_empty())
+            .then(|| serde_json::to_string(&job.active_hours).ok())
+            .flatten();
         let id = job.id.clone();
         let name = job.name.clone();
         let enabled = if job.enabled { 1i32 } else { 0 };
         let error_count = job.error_count as i32;
         let delete_after_run = if job.delete_after_run { 1i32 } else { 0 };
         let timeout_secs = job.timeout_secs.map(|v| v as i64);
+        let priority = job.priority;
 
         let pool = db.clone();
         db::with_db(&pool, move |conn| {
             conn.execute(
                 "INSERT INTO scheduled_jobs \
                  (id, name, schedule_json, session_target, payload_json, \
-                  enabled, error_count, next_run, created_at, active_hours_json, delete_after_run, timeout_secs) \
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, datetime('now'), ?9, ?10, ?11) \
+                  enabled, error_count, next_run, created_at, active_hours_json, delete_after_run, timeout_secs, priority) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, datetime('now'), ?9, ?10, ?11, ?12) \
                  ON CONFLICT(id) DO UPDATE SET \
                   name=excluded.name, schedule_json=excluded.schedule_json, \
                   session_target=excluded.session_target, payload_json=excluded.payload_json, \
                   enabled=excluded.enabled, error_count=excluded.error_count, \
                   next_run=excluded.next_run, active_hours_json=excluded.active_hours_json, \
-                  delete_after_run=excluded.delete_after_run, timeout_secs=excluded.timeout_secs",
+                  delete_after_run=excluded.delete_after_run, timeout_secs=excluded.timeout_secs, \
+                  priority=excluded.priority",
                 rusqlite::params![
                     id,
                     name,
@@ -245,6 +309,7 @@ impl TokioScheduler {
                     active_hours_json,
                     delete_after_run,
                     timeout_secs,
+                    priority,
                 ],
             )?;
             Ok(())
@@ -252,6 +317,111 @@ impl TokioScheduler {
         .await
     }
 
+    /// Persist an execution and trim the job's stored history to `max` entries.
+    async fn persist_execution(db: &DbPool, exec: &JobExecution, max: usize) -> Result<()> {
+        let status = serde_json::to_value(&exec.status)
+            .map_err(|e| ZeniiError::Scheduler(e.to_string()))?
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        let id = exec.id.clone();
+        let job_id = exec.job_id.clone();
+        let started_at = exec.started_at.to_rfc3339();
+        let completed_at = exec.completed_at.map(|t| t.to_rfc3339());
+        let error = exec.error.clone();
+
+        let pool = db.clone();
+        db::with_db(&pool, move |conn| {
+            conn.execute(
+                "INSERT OR REPLACE INTO job_executions \
+                 (id, job_id, status, started_at, completed_at, error) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                rusqlite::params![id, job_id, status, started_at, completed_at, error],
+            )?;
+            conn.execute(
+                "DELETE FROM job_executions WHERE job_id = ?1 AND id NOT IN \
+                 (SELECT id FROM job_executions WHERE job_id = ?1 \
+                  ORDER BY started_at DESC LIMIT ?2)",
+                rusqlite::params![job_id, max as i64],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Load persisted executions, newest first per job, at most `max` per job.
+    /// With `job_id`, only that job's executions are returned.
+    async fn load_executions(
+        db: &DbPool,
+        job_id: Option<&str>,
+        max: usize,
+    ) -> Result<Vec<JobExecution>> {
+        let pool = db.clone();
+        let job_id = job_id.map(str::to_string);
+        let rows = db::with_db(&pool, move |conn| {
+            let mut stmt = conn.prepare(
+                "SELECT id, job_id, status, started_at, completed_at, error FROM ( \
+                   SELECT *, ROW_NUMBER() OVER \
+                     (PARTITION BY job_id ORDER BY started_at DESC) AS rn \
+                   FROM job_executions WHERE ?1 IS NULL OR job_id = ?1 \
+                 ) WHERE rn <= ?2 ORDER BY job_id, started_at DESC",
+            )?;
+            let rows: Vec<ExecutionRow> = stmt
+                .query_map(rusqlite::params![job_id, max as i64], |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        row.get(5)?,
+                    ))
+                })?
+                .filter_map(|r| {
+                    r.map_err(|e| tracing::warn!("DB row error in load_executions: {e}"))
+                        .ok()
+                })
+                .collect();
+            Ok(rows)
+        })
+        .await?;
+
+        let parse_time = |s: &str| {
+            DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|dt| dt.with_timezone(&Utc))
+        };
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id, job_id, status, started_at, completed_at, error)| {
+                Some(JobExecution {
+                    id,
+                    job_id,
+                    status: serde_json::from_value(serde_json::Value::String(status)).ok()?,
+                    started_at: parse_time(&started_at)?,
+                    completed_at: completed_at.as_deref().and_then(parse_time),
+                    error,
+                })
+            })
+            .collect())
+    }
+
+    /// Record an execution in SQLite and the in-memory history cache. A failed
+    /// write is logged; the cache is still updated.
+    async fn store_execution(
+        db: &DbPool,
+        history: &DashMap<String, VecDeque<JobExecution>>,
+        exec: JobExecution,
+        max: usize,
+    ) {
+        if let Err(e) = Self::persist_execution(db, &exec, max).await {
+            warn!("Failed to persist execution of job '{}': {e}", exec.job_id);
+        }
+        let mut entry = history.entry(exec.job_id.clone()).or_default();
+        entry.push_front(exec);
+        entry.truncate(max);
+    }
+
     /// Delete a job from SQLite.
     async fn delete_job_from_db(db: &DbPool, id: &str) -> Result<()> {
         let pool = db.clone();
@@ -303,39 +473,90 @@ impl TokioScheduler {
     pub fn compute_next_run(schedule: &Schedule) -> Result<DateTime<Utc>> {
         match schedule {
             Schedule::Interval { secs } => Ok(Utc::now() + chrono::Duration::seconds(*secs as i64)),
-            Schedule::Cron { expr } => {
+            Schedule::Cron { expr, tz } => {
                 let schedule = cron::Schedule::from_str(&normalize_cron_expr(expr))
                     .map_err(|e| ZeniiError::Scheduler(format!("invalid cron: {e}")))?;
-                schedule
-                    .upcoming(chrono::Local)
-                    .next()
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .ok_or_else(|| ZeniiError::Scheduler("cron has no upcoming time".into()))
+                let next = match tz {
+                    Some(tz) => schedule
+                        .upcoming(parse_cron_tz(tz)?)
+                        .next()
+                        .map(|dt| dt.with_timezone(&Utc)),
+                    None => schedule
+                        .upcoming(chrono::Local)
+                        .next()
+                        .map(|dt| dt.with_timezone(&Utc)),
+                };
+                next.ok_or_else(|| ZeniiError::Scheduler("cron has no upcoming time".into()))
             }
             Schedule::Human { datetime } => Self::parse_human_datetime(datetime),
+            // Absolute even when past, so the tick loop picks it up immediately
+            Schedule::Once { at } => Ok(*at),
         }
     }
 
-    /// Check if the current local hour is within active hours.
-    /// Supports overnight wraparound (e.g. start=22, end=6 means 22:00-05:59).
-    fn is_in_active_hours(active_hours: &Option<ActiveHours>) -> bool {
-        match active_hours {
-            None => true,
-            Some(hours) => {
-                use chrono::Timelike;
-                let local_hour = chrono::Local::now().hour() as u8;
-                Self::hour_in_window(local_hour, hours.start_hour, hours.end_hour)
-            }
+    /// Whether `now` falls in any of `windows`. No windows means always active.
+    fn is_in_active_hours(windows: &[ActiveHours], now: DateTime<Utc>) -> bool {
+        windows.is_empty()
+            || windows.iter().any(|w| match window_tz(w) {
+                Some(tz) => Self::window_contains(w, &now.with_timezone(&tz)),
+                None => Self::window_contains(w, &now.with_timezone(&chrono::Local)),
+            })
+    }
+
+    /// The earliest time after `now` at which one of `windows` opens.
+    fn next_active_start(windows: &[ActiveHours], now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        windows
+            .iter()
+            .filter_map(|w| match window_tz(w) {
+                Some(tz) => Self::window_next_start(w, &now.with_timezone(&tz)),
+                None => Self::window_next_start(w, &now.with_timezone(&chrono::Local)),
+            })
+            .min()
+    }
+
+    fn window_contains<Tz: chrono::TimeZone>(window: &ActiveHours, local: &DateTime<Tz>) -> bool {
+        use chrono::{Datelike, Timelike};
+        let minute = (local.hour() * 60 + local.minute()) as u16;
+        let (start, end) = (window.start(), window.end());
+        if !Self::time_in_window(minute, start, end) {
+            return false;
         }
+        // The early-morning part of an overnight window belongs to the day before
+        let day = if start > end && minute < end {
+            local.weekday().pred()
+        } else {
+            local.weekday()
+        };
+        window.days.is_empty() || window.days.contains(&day)
     }
 
-    /// Check if `hour` is within `[start, end)`, handling overnight wraparound.
-    fn hour_in_window(hour: u8, start: u8, end: u8) -> bool {
+    fn window_next_start<Tz: chrono::TimeZone>(
+        window: &ActiveHours,
+        local: &DateTime<Tz>,
+    ) -> Option<DateTime<Utc>> {
+        use chrono::Datelike;
+        let today = local.date_naive();
+        (0..=7).find_map(|offset| {
+            let date = today + chrono::Days::new(offset);
+            if !window.days.is_empty() && !window.days.contains(&date.weekday()) {
+                return None;
+            }
+            let naive =
+                date.and_hms_opt(window.start_hour.into(), window.start_minute.into(), 0)?;
+            // Skipped when the start falls in a DST gap that day
+            let start = local.timezone().from_local_datetime(&naive).earliest()?;
+            (start > *local).then(|| start.with_timezone(&Utc))
+        })
+    }
+
+    /// Check if `t` is within `[start, end)`, handling overnight wraparound.
+    /// Unit-agnostic: hours or minutes past midnight.
+    fn time_in_window(t: u16, start: u16, end: u16) -> bool {
         if start < end {
-            hour >= start && hour < end
+            t >= start && t < end
         } else {
             // Overnight: e.g. 22..6 means 22,23,0,1,2,3,4,5
-            hour >= start || hour < end
+            t >= start || t < end
         }
     }
 
@@ -356,36 +577,76 @@ impl TokioScheduler {
 
     /// Shared validation for add_job and update_job.
     fn validate_job(job: &mut ScheduledJob) -> Result<()> {
-        // Human schedules always auto-delete after execution
-        if matches!(job.schedule, Schedule::Human { .. }) {
+        // One-time schedules always auto-delete after execution
+        if matches!(job.schedule, Schedule::Human { .. } | Schedule::Once { .. }) {
             job.delete_after_run = true;
         }
 
-        // Validate active hours: start == end would block all hours
-        if let Some(ref hours) = job.active_hours
-            && hours.start_hour == hours.end_hour
-        {
-            return Err(ZeniiError::Validation(format!(
-                "active_hours start_hour ({}) cannot equal end_hour — job would never fire",
-                hours.start_hour
-            )));
+        if matches!(job.schedule, Schedule::Interval { secs: 0 }) {
+            return Err(ZeniiError::Validation(
+                "interval must be at least 1 second".into(),
+            ));
+        }
+
+        for window in &job.active_hours {
+            if window.start_hour > 23
+                || window.end_hour > 23
+                || window.start_minute > 59
+                || window.end_minute > 59
+            {
+                return Err(ZeniiError::Validation(
+                    "active_hours hours must be 0-23 and minutes 0-59".into(),
+                ));
+            }
+            // start == end would block all hours
+            if window.start() == window.end() {
+                return Err(ZeniiError::Validation(format!(
+                    "active_hours start ({:02}:{:02}) cannot equal end — job would never fire",
+                    window.start_hour, window.start_minute
+                )));
+            }
+            if let Some(ref tz) = window.tz {
+                parse_cron_tz(tz)?;
+            }
         }
 
         // Validate cron expression if applicable
-        if let Schedule::Cron { ref expr } = job.schedule {
+        if let Schedule::Cron { ref expr, ref tz } = job.schedule {
             cron::Schedule::from_str(&normalize_cron_expr(expr))
                 .map_err(|e| ZeniiError::Scheduler(format!("invalid cron expression: {e}")))?;
+            if let Some(tz) = tz {
+                parse_cron_tz(tz)?;
+            }
         }
 
         Ok(())
     }
 
-    /// Record an execution in the history ring buffer.
-    pub fn record_execution(&self, exec: JobExecution) {
-        let job_id = exec.job_id.clone();
-        let mut entry = self.history.entry(job_id).or_default();
-        entry.push_front(exec);
-        entry.truncate(self.max_history_per_job);
+    /// Record an execution in SQLite and the history cache.
+    pub async fn record_execution(&self, exec: JobExecution) {
+        Self::store_execution(&self.db, &self.history, exec, self.max_history_per_job).await;
+    }
+
+    /// Whether job `id` is executing right now.
+    pub fn is_job_running(&self, id: &str) -> bool {
+        self.running_jobs.contains_key(id)
+    }
+
+    /// Stop the in-flight run of job `id`; the run is recorded as cancelled.
+    /// Returns `false` when the job exists but is not running.
+    pub fn cancel_running(&self, id: &str) -> Result<bool> {
+        if !self.jobs.contains_key(id) {
+            return Err(ZeniiError::NotFound(format!("job '{id}' not found")));
+        }
+        match self.running_jobs.get(id) {
+            Some(cancel) => {
+                // notify_one keeps a permit, so a cancel that lands before the
+                // run starts waiting is not lost.
+                cancel.notify_one();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
     }
 
     /// Check if scheduler is running.
@@ -397,6 +658,94 @@ impl TokioScheduler {
     pub fn job_count(&self) -> usize {
         self.jobs.len()
     }
+
+    /// Portable definitions of all jobs, sorted by name.
+    pub fn export_jobs(&self) -> JobsExport {
+        let mut jobs: Vec<JobSpec> = self
+            .jobs
+            .iter()
+            .map(|entry| JobSpec::from(entry.value()))
+            .collect();
+        jobs.sort_by(|a, b| a.name.cmp(&b.name));
+        JobsExport {
+            version: JOBS_EXPORT_VERSION,
+            jobs,
+        }
+    }
+
+    /// Create jobs from an export, matching existing jobs by name.
+    ///
+    /// Every entry is validated before any job is created, so a bad entry
+    /// fails the whole import without changing anything. Importing the same
+    /// file twice leaves the second import with nothing to do.
+    pub async fn import_jobs(
+        &self,
+        export: JobsExport,
+        on_conflict: ImportConflict,
+    ) -> Result<ImportReport> {
+        if export.version > JOBS_EXPORT_VERSION {
+            return Err(ZeniiError::Validation(format!(
+                "job export version {} is newer than supported version {JOBS_EXPORT_VERSION}",
+                export.version
+            )));
+        }
+
+        let mut errors = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+        let mut jobs = Vec::with_capacity(export.jobs.len());
+        for spec in export.jobs {
+            let name = spec.name.clone();
+            if name.trim().is_empty() {
+                errors.push("a job has an empty name".to_string());
+                continue;
+            }
+            if !seen.insert(name.clone()) {
+                errors.push(format!("job '{name}' appears more than once"));
+                continue;
+            }
+            let mut job = spec.into_job(String::new());
+            match Self::validate_job(&mut job)
+                .and_then(|()| Self::compute_next_run(&job.schedule).map(|_| ()))
+            {
+                Ok(()) => jobs.push(job),
+                Err(e) => errors.push(format!("job '{name}': {e}")),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(ZeniiError::Validation(format!(
+                "import rejected, no jobs were changed: {}",
+                errors.join("; ")
+            )));
+        }
+
+        let mut report = ImportReport::default();
+        for job in jobs {
+            let existing = self
+                .jobs
+                .iter()
+                .find(|entry| entry.value().name == job.name)
+                .map(|entry| (entry.key().clone(), JobSpec::from(entry.value())));
+            match existing {
+                None => {
+                    let name = job.name.clone();
+                    self.add_job(job).await?;
+                    report.created.push(name);
+                }
+                Some((_, current)) if current == JobSpec::from(&job) => {
+                    report.unchanged.push(job.name);
+                }
+                Some(_) if on_conflict == ImportConflict::Skip => {
+                    report.skipped.push(job.name);
+                }
+                Some((id, _)) => {
+                    let name = job.name.clone();
+                    self.update_job(&id, job).await?;
+                    report.updated.push(name);
+                }
+            }
+        }
+        Ok(report)
+    }
 }
 
 #[async_trait]
@@ -416,11 +765,16 @@ impl Scheduler for TokioScheduler {
         let bus = self.event_bus.clone();
         let db = self.db.clone();
         let mut stop_rx = self.stop_rx.clone();
-        let tick_secs = self.tick_interval_secs;
-        let stuck_threshold = self.stuck_threshold_secs;
-        let max_history = self.max_history_per_job;
-        let error_backoff = self.error_backoff_secs.clone();
-        let max_consecutive_failures = self.max_consecutive_failures;
+        // Reassigned on ConfigUpdated so edits apply without restarting the loop
+        let mut tick_secs = self.tick_interval_secs;
+        let mut stuck_threshold = self.stuck_threshold_secs;
+        let mut max_history = self.max_history_per_job;
+        let mut error_backoff = self.error_backoff_secs.clone();
+        let mut max_consecutive_failures = self.max_consecutive_failures;
+        let provider_missing = self.provider_missing.clone();
+        let job_slots = self.job_slots.clone();
+        let running_jobs = self.running_jobs.clone();
+        let mut events = bus.subscribe();
         #[cfg(feature = "gateway")]
         let app_state_cell = self.app_state.clone();
 
@@ -433,7 +787,7 @@ impl Scheduler for TokioScheduler {
                 tokio::select! {
                     _ = ticker.tick() => {
                         let now = Utc::now();
-                        let due: Vec<ScheduledJob> = jobs
+                        let mut due: Vec<ScheduledJob> = jobs
                             .iter()
                             .filter(|entry| entry.value().enabled)
                             .filter(|entry| {
@@ -441,19 +795,38 @@ impl Scheduler for TokioScheduler {
                             })
                             .map(|entry| entry.value().clone())
                             .collect();
+                        // Highest priority first; among equals, the longest-overdue first
+                        due.sort_by(|a, b| {
+                            b.effective_priority()
+                                .cmp(&a.effective_priority())
+                                .then(a.next_run.cmp(&b.next_run))
+                        });
 
                         for job in due {
-                            // Active hours gate (cheap, synchronous — check before spawning)
-                            if !TokioScheduler::is_in_active_hours(&job.active_hours) {
-                                // Reschedule
+                            // Active hours gate (cheap, synchronous — check before spawning).
+                            // Outside every window the run is deferred, not dropped.
+                            if !TokioScheduler::is_in_active_hours(&job.active_hours, now) {
                                 if let Some(mut entry) = jobs.get_mut(&job.id)
-                                    && let Ok(next) = TokioScheduler::compute_next_run(&entry.schedule)
+                                    && let Some(next) = TokioScheduler::next_active_start(&job.active_hours, now)
+                                        .or_else(|| TokioScheduler::compute_next_run(&entry.schedule).ok())
                                 {
+                                    debug!(job_id = %job.id, %next, "Scheduler: job outside active hours, deferred");
                                     entry.next_run = Some(next);
                                 }
                                 continue;
                             }
 
+                            // Concurrency gate: without a free slot the job stays due
+                            // (next_run untouched) and is retried on the next tick. It is
+                            // neither recorded nor counted as an error.
+                            let Ok(permit) = job_slots.clone().try_acquire_owned() else {
+                                debug!(
+                                    job_id = %job.id,
+                                    "Scheduler: concurrency limit reached, job deferred"
+                                );
+                                continue;
+                            };
+
                             // Set next_run = None as in-flight guard to prevent re-pickup
                             let scheduled_time = {
                                 if let Some(mut entry) = jobs.get_mut(&job.id) {
@@ -483,11 +856,17 @@ impl Scheduler for TokioScheduler {
                             let bus = bus.clone();
                             let db = db.clone();
                             let error_backoff = error_backoff.clone();
+                            let running_jobs = running_jobs.clone();
+                            let provider_missing = provider_missing.clone();
+                            let cancel = Arc::new(Notify::new());
+                            running_jobs.insert(job.id.clone(), cancel.clone());
                             #[cfg(feature = "gateway")]
                             let app_state_ref = app_state_cell.clone();
 
                             // Spawn each job in its own task for parallel execution
                             tokio::spawn(async move {
+                                // Hold the slot until the job is fully recorded
+                                let _permit = permit;
                                 let started_at = Utc::now();
 
                                 // Execute with stuck detection timeout (per-job override or global)
@@ -498,7 +877,7 @@ impl Scheduler for TokioScheduler {
                                 let job_ref = job.clone();
                                 #[cfg(feature = "gateway")]
                                 let app_state_exec = app_state_ref.clone();
-                                let status = tokio::time::timeout(timeout, async move {
+                                let run = tokio::time::timeout(timeout, async move {
                                     #[cfg(feature = "gateway")]
                                     {
                                         super::payload_executor::execute(
@@ -523,19 +902,34 @@ impl Scheduler for TokioScheduler {
                                             _ => JobStatus::Success,
                                         }
                                     }
-                                })
-                                .await;
+                                });
+                                // A cancel drops the run, halting whatever it was awaiting
+                                let status = tokio::select! {
+                                    status = run => Some(status),
+                                    _ = cancel.notified() => None,
+                                };
+                                running_jobs.remove(&job.id);
 
                                 let completed_at = Utc::now();
                                 let (job_status, error_msg) = match status {
-                                    Ok(s) => (s, None),
-                                    Err(_) => (
+                                    Some(Ok(s)) => (s, None),
+                                    Some(Err(_)) => (
                                         JobStatus::Stuck,
                                         Some(format!(
                                             "Job '{}' stuck after {timeout_secs}s",
                                             job.name
                                         )),
                                     ),
+                                    None => {
+                                        info!(job_id = %job.id, "Scheduler: job '{}' cancelled", job.name);
+                                        let _ = bus.publish(AppEvent::SchedulerJobCompleted {
+                                            job_id: job.id.clone(),
+                                            job_name: job.name.clone(),
+                                            status: "cancelled".into(),
+                                            error: None,
+                                        });
+                                        (JobStatus::Cancelled, Some("cancelled by user".into()))
+                                    }
                                 };
 
                                 // Record history
@@ -547,13 +941,7 @@ impl Scheduler for TokioScheduler {
                                     completed_at: Some(completed_at),
                                     error: error_msg,
                                 };
-                                {
-                                    let mut entry = history
-                                        .entry(job.id.clone())
-                                        .or_default();
-                                    entry.push_front(exec);
-                                    entry.truncate(max_history);
-                                }
+                                TokioScheduler::store_execution(&db, &history, exec, max_history).await;
 
                                 // Reschedule / one-shot / error tracking
                                 if job.delete_after_run && job_status == JobStatus::Success {
@@ -577,6 +965,27 @@ impl Scheduler for TokioScheduler {
                                                 } else if let Ok(next) = TokioScheduler::compute_next_run(&entry.schedule) {
                                                     entry.next_run = Some(next);
                                                 }
+                                            } else if job_status == JobStatus::Cancelled {
+                                                // Wait for the next regular run; a one-shot job stays
+                                                // unscheduled instead of firing again right away
+                                                entry.next_run = TokioScheduler::compute_next_run(&entry.schedule)
+                                                    .ok()
+                                                    .filter(|next| *next > Utc::now());
+                                            } else if job_status == JobStatus::Skipped
+                                                && provider_missing.load(Ordering::SeqCst)
+                                            {
+                                                // Waiting for a provider: keep the normal cadence and
+                                                // don't count it towards the circuit breaker. Other skips
+                                                // back off like failures.
+                                                let retry = Utc::now() + chrono::Duration::seconds(
+                                                    TokioScheduler::compute_backoff(&error_backoff, 0) as i64,
+                                                );
+                                                entry.next_run = Some(
+                                                    TokioScheduler::compute_next_run(&entry.schedule)
+                                                        .ok()
+                                                        .filter(|next| *next > Utc::now())
+                                                        .unwrap_or(retry),
+                                                );
                                             } else {
                                                 entry.error_count += 1;
                                                 // Circuit breaker: disable job after too many consecutive failures
@@ -616,6 +1025,34 @@ impl Scheduler for TokioScheduler {
                             });
                         }
                     }
+                    event = events.recv() => {
+                        // Provider setup changed: let waiting AgentTurn jobs re-resolve
+                        if matches!(
+                            event,
+                            Ok(AppEvent::ProvidersChanged
+                                | AppEvent::ProviderReloaded { .. }
+                                | AppEvent::CredentialsChanged
+                                | AppEvent::ConfigUpdated)
+                                | Err(tokio::sync::broadcast::error::RecvError::Lagged(_))
+                        ) && provider_missing.swap(false, Ordering::SeqCst)
+                        {
+                            info!("Scheduler: provider configuration changed, AgentTurn jobs re-enabled");
+                        }
+                        #[cfg(feature = "gateway")]
+                        if matches!(event, Ok(AppEvent::ConfigUpdated))
+                            && let Some(state) = app_state_cell.get()
+                        {
+                            let config = state.config.load();
+                            if config.scheduler_tick_interval_secs != tick_secs {
+                                tick_secs = config.scheduler_tick_interval_secs;
+                                ticker = tokio::time::interval(Duration::from_secs(tick_secs.max(1)));
+                            }
+                            stuck_threshold = config.scheduler_stuck_threshold_secs;
+                            max_history = config.scheduler_max_history_per_job;
+                            error_backoff = config.scheduler_error_backoff_secs.clone();
+                            max_consecutive_failures = config.scheduler_max_consecutive_failures;
+                        }
+                    }
                     Ok(()) = stop_rx.changed() => {
                         if *stop_rx.borrow() {
                             let _ = bus.publish(AppEvent::SchedulerStopped);
@@ -704,6 +1141,13 @@ impl Scheduler for TokioScheduler {
             .remove(id)
             .ok_or_else(|| ZeniiError::NotFound(format!("job '{id}' not found")))?;
         Self::delete_job_from_db(&self.db, id).await?;
+        self.history.remove(id);
+        let job_id = id.to_string();
+        db::with_db(&self.db, move |conn| {
+            conn.execute("DELETE FROM job_executions WHERE job_id = ?1", [&job_id])?;
+            Ok(())
+        })
+        .await?;
         Ok(())
     }
 
@@ -739,10 +1183,22 @@ impl Scheduler for TokioScheduler {
     }
 
     async fn job_history(&self, id: &str) -> Vec<JobExecution> {
-        self.history
-            .get(id)
-            .map(|entry| entry.value().iter().cloned().collect())
-            .unwrap_or_default()
+        if let Some(entry) = self.history.get(id) {
+            return entry.value().iter().cloned().collect();
+        }
+        match Self::load_executions(&self.db, Some(id), self.max_history_per_job).await {
+            Ok(executions) => {
+                if !executions.is_empty() {
+                    self.history
+                        .insert(id.to_string(), executions.iter().cloned().collect());
+                }
+                executions
+            }
+            Err(e) => {
+                warn!("Failed to load history of job '{id}': {e}");
+                Vec::new()
+            }
+        }
     }
 }
 
@@ -782,9 +1238,10 @@ mod tests {
             enabled: true,
             error_count: 0,
             next_run: None,
-            active_hours: None,
+            active_hours: Vec::new(),
             delete_after_run: false,
             timeout_secs: None,
+            priority: None,
         }
     }
 
@@ -869,6 +1326,7 @@ mod tests {
         let mut job = test_job("cron_job");
         job.schedule = Schedule::Cron {
             expr: "0 */5 * * * *".into(),
+            tz: None,
         };
         let id = sched.add_job(job).await.unwrap();
         assert!(!id.is_empty());
@@ -883,11 +1341,51 @@ mod tests {
         let mut job = test_job("bad_cron");
         job.schedule = Schedule::Cron {
             expr: "not a cron".into(),
+            tz: None,
         };
         let result = sched.add_job(job).await;
         assert!(result.is_err());
     }
 
+    // 16.15b — Cron with a timezone fires at that zone's wall-clock time
+    #[test]
+    fn cron_next_run_honors_timezone() {
+        use chrono::Timelike;
+        let schedule = Schedule::Cron {
+            expr: "30 9 * * *".into(),
+            tz: Some("Asia/Kolkata".into()),
+        };
+        let next = TokioScheduler::compute_next_run(&schedule).unwrap();
+        let local = next.with_timezone(&chrono_tz::Asia::Kolkata);
+        assert_eq!((local.hour(), local.minute()), (9, 30));
+        // Kolkata is UTC+5:30 with no DST
+        assert_eq!((next.hour(), next.minute()), (4, 0));
+    }
+
+    // 16.15c — Unknown timezone is rejected when the job is created
+    #[tokio::test]
+    async fn cron_invalid_timezone_rejected() {
+        let (_dir, sched) = test_scheduler();
+        let mut job = test_job("bad_tz");
+        job.schedule = Schedule::Cron {
+            expr: "0 9 * * 1-5".into(),
+            tz: Some("Mars/Olympus_Mons".into()),
+        };
+        let result = sched.add_job(job).await;
+        assert!(matches!(result, Err(ZeniiError::Validation(_))));
+        assert!(sched.list_jobs().await.is_empty());
+    }
+
+    // 16.15d — Once next_run is the absolute time, even in the past
+    #[test]
+    fn once_next_run_is_absolute() {
+        let at: DateTime<Utc> = "2025-06-01T09:00:00Z".parse().unwrap();
+        assert_eq!(
+            TokioScheduler::compute_next_run(&Schedule::Once { at }).unwrap(),
+            at
+        );
+    }
+
     // 16.16 — Interval next_run calculation
     #[test]
     fn interval_next_run() {
@@ -919,11 +1417,12 @@ mod tests {
     fn active_hours_in_window() {
         use chrono::Timelike;
         let current_hour = chrono::Local::now().hour() as u8;
-        let hours = Some(ActiveHours {
+        let hours = vec![ActiveHours {
             start_hour: current_hour,
-            end_hour: current_hour + 1,
-        });
-        assert!(TokioScheduler::is_in_active_hours(&hours));
+            end_hour: (current_hour + 1) % 24,
+            ..Default::default()
+        }];
+        assert!(TokioScheduler::is_in_active_hours(&hours, Utc::now()));
     }
 
     // 16.20 — Active hours gate blocks out-of-window
@@ -933,21 +1432,122 @@ mod tests {
         let current_hour = chrono::Local::now().hour() as u8;
         // Set window to a different hour
         let other_hour = (current_hour + 12) % 24;
-        let hours = Some(ActiveHours {
+        let hours = vec![ActiveHours {
             start_hour: other_hour,
             end_hour: (other_hour + 1) % 24,
-        });
+            ..Default::default()
+        }];
         // This may or may not block depending on edge cases with wrapping,
         // so test the simple case where we know we're outside
         if other_hour < (other_hour + 1) % 24 {
-            assert!(!TokioScheduler::is_in_active_hours(&hours));
+            assert!(!TokioScheduler::is_in_active_hours(&hours, Utc::now()));
         }
     }
 
-    // 16.21 — Active hours None means always active
+    // 16.21 — No active hours means always active
     #[test]
     fn active_hours_none_always() {
-        assert!(TokioScheduler::is_in_active_hours(&None));
+        assert!(TokioScheduler::is_in_active_hours(&[], Utc::now()));
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    // 16.21a — Any matching window activates the job; weekdays are honoured
+    #[test]
+    fn active_hours_multiple_windows_and_days() {
+        use chrono::Weekday::*;
+        // Weekdays 9-17 and Saturday 8:30-12, in UTC
+        let windows = vec![
+            ActiveHours {
+                start_hour: 9,
+                end_hour: 17,
+                days: vec![Mon, Tue, Wed, Thu, Fri],
+                tz: Some("UTC".into()),
+                ..Default::default()
+            },
+            ActiveHours {
+                start_hour: 8,
+                start_minute: 30,
+                end_hour: 12,
+                days: vec![Sat],
+                tz: Some("UTC".into()),
+                ..Default::default()
+            },
+        ];
+        // 2026-10-16 is a Friday
+        assert!(TokioScheduler::is_in_active_hours(
+            &windows,
+            utc("2026-10-16T10:00:00Z")
+        ));
+        assert!(!TokioScheduler::is_in_active_hours(
+            &windows,
+            utc("2026-10-16T18:00:00Z")
+        ));
+        assert!(TokioScheduler::is_in_active_hours(
+            &windows,
+            utc("2026-10-17T08:30:00Z")
+        ));
+        assert!(!TokioScheduler::is_in_active_hours(
+            &windows,
+            utc("2026-10-17T12:00:00Z")
+        ));
+        assert!(!TokioScheduler::is_in_active_hours(
+            &windows,
+            utc("2026-10-18T10:00:00Z")
+        ));
+
+        // Friday evening defers to Saturday morning; Saturday noon to Monday
+        assert_eq!(
+            TokioScheduler::next_active_start(&windows, utc("2026-10-16T18:00:00Z")),
+            Some(utc("2026-10-17T08:30:00Z"))
+        );
+        assert_eq!(
+            TokioScheduler::next_active_start(&windows, utc("2026-10-17T12:00:00Z")),
+            Some(utc("2026-10-19T09:00:00Z"))
+        );
+    }
+
+    // 16.21b — Overnight windows belong to the day they open on; timezones apply
+    #[test]
+    fn active_hours_overnight_day_and_timezone() {
+        let friday_night = vec![ActiveHours {
+            start_hour: 22,
+            end_hour: 6,
+            days: vec![chrono::Weekday::Fri],
+            tz: Some("UTC".into()),
+            ..Default::default()
+        }];
+        // Saturday 03:00 is still Friday's window; Friday 03:00 is Thursday's
+        assert!(TokioScheduler::is_in_active_hours(
+            &friday_night,
+            utc("2026-10-17T03:00:00Z")
+        ));
+        assert!(!TokioScheduler::is_in_active_hours(
+            &friday_night,
+            utc("2026-10-16T03:00:00Z")
+        ));
+
+        // 9-17 in Tokyo (UTC+9) is 00-08 UTC
+        let tokyo = vec![ActiveHours {
+            start_hour: 9,
+            end_hour: 17,
+            tz: Some("Asia/Tokyo".into()),
+            ..Default::default()
+        }];
+        assert!(TokioScheduler::is_in_active_hours(
+            &tokyo,
+            utc("2026-10-16T01:00:00Z")
+        ));
+        assert!(!TokioScheduler::is_in_active_hours(
+            &tokyo,
+            utc("2026-10-16T09:00:00Z")
+        ));
+        assert_eq!(
+            TokioScheduler::next_active_start(&tokyo, utc("2026-10-16T09:00:00Z")),
+            Some(utc("2026-10-17T00:00:00Z"))
+        );
     }
 
     // 16.22 — One-shot job deleted after run
@@ -978,6 +1578,27 @@ mod tests {
         assert!(sched.jobs.get(&id).is_none());
     }
 
+    // 16.22b — Once job created with a past `at` fires on the next tick, then is deleted
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn once_in_past_fires_and_deletes() {
+        let (_dir, sched) = test_scheduler();
+        let mut job = test_job("once_past");
+        job.schedule = Schedule::Once {
+            at: Utc::now() - chrono::Duration::hours(1),
+        };
+        let id = sched.add_job(job).await.unwrap();
+        assert!(sched.jobs.get(&id).unwrap().delete_after_run);
+
+        sched.start().await;
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        sched.stop().await;
+
+        assert!(
+            sched.jobs.get(&id).is_none(),
+            "Once job should run and be removed"
+        );
+    }
+
     // 16.23 — Job execution history recorded
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn execution_history_recorded() {
@@ -1003,6 +1624,53 @@ mod tests {
         assert_eq!(history[0].status, JobStatus::Success);
     }
 
+    // Concurrency limit runs due jobs one at a time, highest priority first,
+    // without counting the deferred job as failed
+    #[tokio::test]
+    async fn concurrency_limit_orders_by_priority() {
+        let (_dir, pool) = test_db();
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(64));
+        let config = AppConfig {
+            scheduler_max_concurrent_jobs: 1,
+            ..Default::default()
+        };
+        let sched = TokioScheduler::new(pool, bus.clone(), &config);
+        let mut events = bus.subscribe();
+
+        let mut low = test_job("low");
+        low.schedule = Schedule::Interval { secs: 3600 };
+        let low_id = sched.add_job(low).await.unwrap();
+        let mut high = test_job("high");
+        high.schedule = Schedule::Interval { secs: 3600 };
+        high.priority = Some(5);
+        let high_id = sched.add_job(high).await.unwrap();
+
+        // Both due; the low-priority job is the more overdue one
+        for (id, ago) in [(&low_id, 10), (&high_id, 1)] {
+            if let Some(mut entry) = sched.jobs.get_mut(id.as_str()) {
+                entry.next_run = Some(Utc::now() - chrono::Duration::seconds(ago));
+            }
+        }
+
+        sched.start().await;
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        sched.stop().await;
+
+        let mut fired = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            if let AppEvent::CronFired { name, .. } = event {
+                fired.push(name);
+            }
+        }
+        assert_eq!(fired, vec!["high".to_string(), "low".to_string()]);
+        for id in [&low_id, &high_id] {
+            let history = sched.job_history(id).await;
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].status, JobStatus::Success);
+            assert_eq!(sched.jobs.get(id.as_str()).unwrap().error_count, 0);
+        }
+    }
+
     // 16.24 — Job history max entries enforced
     #[tokio::test]
     async fn history_max_entries() {
@@ -1010,14 +1678,16 @@ mod tests {
         // Add more entries than max
         let max = sched.max_history_per_job;
         for i in 0..max + 5 {
-            sched.record_execution(JobExecution {
-                id: format!("exec-{i}"),
-                job_id: "j1".into(),
-                status: JobStatus::Success,
-                started_at: Utc::now(),
-                completed_at: Some(Utc::now()),
-                error: None,
-            });
+            sched
+                .record_execution(JobExecution {
+                    id: format!("exec-{i}"),
+                    job_id: "j1".into(),
+                    status: JobStatus::Success,
+                    started_at: Utc::now(),
+                    completed_at: Some(Utc::now()),
+                    error: None,
+                })
+                .await;
         }
         let history = sched.job_history("j1").await;
         assert_eq!(history.len(), max);
@@ -1066,6 +1736,49 @@ mod tests {
         assert_eq!(jobs[0].name, "persist_test");
     }
 
+    // 16.26b — Execution history survives a restart and stays trimmed
+    #[tokio::test]
+    async fn history_persists_across_restart() {
+        let (_dir, pool) = test_db();
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let config = AppConfig {
+            scheduler_max_history_per_job: 3,
+            ..Default::default()
+        };
+
+        let sched1 = TokioScheduler::new(pool.clone(), bus.clone(), &config);
+        let start = Utc::now();
+        for i in 0..5 {
+            sched1
+                .record_execution(JobExecution {
+                    id: format!("exec-{i}"),
+                    job_id: "j1".into(),
+                    status: if i == 4 {
+                        JobStatus::Failed
+                    } else {
+                        JobStatus::Success
+                    },
+                    started_at: start + chrono::Duration::seconds(i),
+                    completed_at: Some(start + chrono::Duration::seconds(i)),
+                    error: (i == 4).then(|| "boom".to_string()),
+                })
+                .await;
+        }
+
+        // Lazily loaded without load_from_db
+        let sched2 = TokioScheduler::new(pool.clone(), bus.clone(), &config);
+        let history = sched2.job_history("j1").await;
+        let ids: Vec<_> = history.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["exec-4", "exec-3", "exec-2"]);
+        assert_eq!(history[0].status, JobStatus::Failed);
+        assert_eq!(history[0].error.as_deref(), Some("boom"));
+
+        // Warmed by load_from_db
+        let sched3 = TokioScheduler::new(pool, bus, &config);
+        sched3.load_from_db().await.unwrap();
+        assert_eq!(sched3.history.get("j1").unwrap().len(), 3);
+    }
+
     // 16.27 — Disabled job skipped during tick
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn disabled_job_skipped() {
@@ -1183,21 +1896,21 @@ mod tests {
     // WS-6.4 — ActiveHours overnight window (22:00-06:00)
     #[test]
     fn active_hours_overnight_window() {
-        assert!(TokioScheduler::hour_in_window(23, 22, 6));
-        assert!(TokioScheduler::hour_in_window(0, 22, 6));
-        assert!(TokioScheduler::hour_in_window(5, 22, 6));
-        assert!(!TokioScheduler::hour_in_window(6, 22, 6));
-        assert!(!TokioScheduler::hour_in_window(12, 22, 6));
-        assert!(TokioScheduler::hour_in_window(22, 22, 6));
+        assert!(TokioScheduler::time_in_window(23, 22, 6));
+        assert!(TokioScheduler::time_in_window(0, 22, 6));
+        assert!(TokioScheduler::time_in_window(5, 22, 6));
+        assert!(!TokioScheduler::time_in_window(6, 22, 6));
+        assert!(!TokioScheduler::time_in_window(12, 22, 6));
+        assert!(TokioScheduler::time_in_window(22, 22, 6));
     }
 
     // WS-6.4 — ActiveHours normal daytime window (09:00-17:00)
     #[test]
     fn active_hours_normal_window() {
-        assert!(TokioScheduler::hour_in_window(9, 9, 17));
-        assert!(TokioScheduler::hour_in_window(12, 9, 17));
-        assert!(!TokioScheduler::hour_in_window(17, 9, 17));
-        assert!(!TokioScheduler::hour_in_window(8, 9, 17));
+        assert!(TokioScheduler::time_in_window(9, 9, 17));
+        assert!(TokioScheduler::time_in_window(12, 9, 17));
+        assert!(!TokioScheduler::time_in_window(17, 9, 17));
+        assert!(!TokioScheduler::time_in_window(8, 9, 17));
     }
 
     // AUDIT-L2.1 — add_job rejects active_hours with start == end
@@ -1205,10 +1918,11 @@ mod tests {
     async fn add_job_rejects_equal_active_hours() {
         let (_dir, sched) = test_scheduler();
         let mut job = test_job("bad_hours");
-        job.active_hours = Some(super::super::traits::ActiveHours {
+        job.active_hours = vec![super::super::traits::ActiveHours {
             start_hour: 9,
             end_hour: 9,
-        });
+            ..Default::default()
+        }];
         let result = sched.add_job(job).await;
         assert!(result.is_err());
         assert!(matches!(
@@ -1222,10 +1936,11 @@ mod tests {
     async fn add_job_accepts_valid_active_hours() {
         let (_dir, sched) = test_scheduler();
         let mut job = test_job("good_hours");
-        job.active_hours = Some(super::super::traits::ActiveHours {
+        job.active_hours = vec![super::super::traits::ActiveHours {
             start_hour: 9,
             end_hour: 17,
-        });
+            ..Default::default()
+        }];
         let result = sched.add_job(job).await;
         assert!(result.is_ok());
     }
@@ -1291,6 +2006,7 @@ mod tests {
         let mut job = test_job("cron-test");
         job.schedule = Schedule::Cron {
             expr: "not valid".into(),
+            tz: None,
         };
         let result = sched.update_job(&id, job).await;
         assert!(result.is_err());
@@ -1303,10 +2019,11 @@ mod tests {
         let id = sched.add_job(test_job("hours-test")).await.unwrap();
 
         let mut job = test_job("hours-test");
-        job.active_hours = Some(super::super::traits::ActiveHours {
+        job.active_hours = vec![super::super::traits::ActiveHours {
             start_hour: 9,
             end_hour: 9,
-        });
+            ..Default::default()
+        }];
         let result = sched.update_job(&id, job).await;
         assert!(result.is_err());
         assert!(matches!(
@@ -1314,4 +2031,203 @@ mod tests {
             ZeniiError::Validation(msg) if msg.contains("cannot equal")
         ));
     }
+
+    // PG.2 — only provider-wait skips keep the cadence; other skips back off
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn skip_keeps_cadence_only_while_waiting_for_provider() {
+        let mut ids = Vec::new();
+        let mut schedulers = Vec::new();
+        for waiting in [true, false] {
+            let (dir, sched) = test_scheduler();
+            sched.set_provider_missing(waiting);
+            // No AppState is wired, so the AgentTurn job is skipped
+            let mut job = test_job("agent");
+            job.schedule = Schedule::Interval { secs: 3600 };
+            job.payload = JobPayload::AgentTurn {
+                prompt: "hello".into(),
+            };
+            let id = sched.add_job(job).await.unwrap();
+            if let Some(mut entry) = sched.jobs.get_mut(id.as_str()) {
+                entry.next_run = Some(Utc::now() - chrono::Duration::seconds(1));
+            }
+            sched.start().await;
+            ids.push(id);
+            schedulers.push((dir, sched));
+        }
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        for (_, sched) in &schedulers {
+            sched.stop().await;
+        }
+
+        let (waiting, other) = (&schedulers[0].1, &schedulers[1].1);
+        let waiting_job = waiting.jobs.get(ids[0].as_str()).unwrap();
+        assert_eq!(waiting_job.error_count, 0);
+        assert!(waiting_job.next_run.unwrap() > Utc::now() + chrono::Duration::minutes(30));
+        assert_eq!(other.jobs.get(ids[1].as_str()).unwrap().error_count, 1);
+    }
+
+    // PG.1 — provider cache cleared when providers change
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn provider_cache_invalidated_on_providers_changed() {
+        let (_dir, sched) = test_scheduler();
+        sched.set_provider_missing(true);
+
+        sched.start().await;
+        let _ = sched.event_bus.publish(AppEvent::ProvidersChanged);
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+        while sched.provider_missing() && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        sched.stop().await;
+
+        assert!(!sched.provider_missing());
+    }
+
+    // PG.3 — provider cache cleared when providers are reloaded
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn provider_cache_invalidated_on_provider_reload() {
+        let (_dir, sched) = test_scheduler();
+        sched.set_provider_missing(true);
+
+        sched.start().await;
+        let _ = sched.event_bus.publish(AppEvent::ProviderReloaded {
+            active_model: Some("openai:gpt-4o".into()),
+        });
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+        while sched.provider_missing() && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        sched.stop().await;
+
+        assert!(!sched.provider_missing());
+    }
+
+    // EXP.1 — Export then import into another scheduler round-trips, and a
+    // second import changes nothing
+    #[tokio::test]
+    async fn export_import_round_trip_is_idempotent() {
+        let (_dir, source) = test_scheduler();
+        source.add_job(test_job("b-job")).await.unwrap();
+        let mut cron = test_job("a-job");
+        cron.schedule = Schedule::Cron {
+            expr: "0 9 * * 1-5".into(),
+            tz: Some("Europe/Berlin".into()),
+        };
+        source.add_job(cron).await.unwrap();
+        let export = source.export_jobs();
+        assert_eq!(export.jobs[0].name, "a-job");
+
+        let json = serde_json::to_string(&export).unwrap();
+        let parsed: JobsExport = serde_json::from_str(&json).unwrap();
+
+        let (_dir2, target) = test_scheduler();
+        let report = target
+            .import_jobs(parsed.clone(), ImportConflict::Overwrite)
+            .await
+            .unwrap();
+        assert_eq!(report.created, vec!["a-job", "b-job"]);
+        assert_eq!(target.export_jobs(), export);
+
+        let again = target
+            .import_jobs(parsed, ImportConflict::Overwrite)
+            .await
+            .unwrap();
+        assert!(again.created.is_empty() && again.updated.is_empty());
+        assert_eq!(again.unchanged.len(), 2);
+        assert_eq!(target.job_count(), 2);
+    }
+
+    // EXP.2 — One invalid entry rejects the whole import
+    #[tokio::test]
+    async fn import_validates_before_creating() {
+        let (_dir, sched) = test_scheduler();
+        let good = JobSpec::from(&test_job("good"));
+        let mut bad = JobSpec::from(&test_job("bad"));
+        bad.schedule = Schedule::Cron {
+            expr: "not a cron".into(),
+            tz: None,
+        };
+        let mut zero = JobSpec::from(&test_job("zero"));
+        zero.schedule = Schedule::Interval { secs: 0 };
+        let export = JobsExport {
+            version: JOBS_EXPORT_VERSION,
+            jobs: vec![good.clone(), bad, zero],
+        };
+
+        let err = sched
+            .import_jobs(export, ImportConflict::Skip)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("job 'bad'"), "{err}");
+        assert!(err.contains("job 'zero'"), "{err}");
+        assert_eq!(sched.job_count(), 0);
+
+        let duplicate = JobsExport {
+            version: JOBS_EXPORT_VERSION,
+            jobs: vec![good.clone(), good],
+        };
+        assert!(
+            sched
+                .import_jobs(duplicate, ImportConflict::Skip)
+                .await
+                .is_err()
+        );
+        assert_eq!(sched.job_count(), 0);
+    }
+
+    // EXP.3 — Existing names are skipped or overwritten per the flag
+    #[tokio::test]
+    async fn import_conflict_modes() {
+        let (_dir, sched) = test_scheduler();
+        let id = sched.add_job(test_job("daily")).await.unwrap();
+        let mut changed = JobSpec::from(&test_job("daily"));
+        changed.schedule = Schedule::Interval { secs: 120 };
+        let export = JobsExport {
+            version: JOBS_EXPORT_VERSION,
+            jobs: vec![changed],
+        };
+
+        let report = sched
+            .import_jobs(export.clone(), ImportConflict::Skip)
+            .await
+            .unwrap();
+        assert_eq!(report.skipped, vec!["daily"]);
+        assert_eq!(
+            sched.jobs.get(&id).unwrap().schedule,
+            Schedule::Interval { secs: 60 }
+        );
+
+        let report = sched
+            .import_jobs(export, ImportConflict::Overwrite)
+            .await
+            .unwrap();
+        assert_eq!(report.updated, vec!["daily"]);
+        let job = sched.jobs.get(&id).unwrap().clone();
+        assert_eq!(job.schedule, Schedule::Interval { secs: 120 });
+        assert_eq!(sched.job_count(), 1);
+    }
+
+    // SCH.C1 — Cancel wakes a running job, is a no-op when idle, errors when unknown
+    #[tokio::test]
+    async fn cancel_running_job() {
+        let (_dir, sched) = test_scheduler();
+        let id = sched.add_job(test_job("long")).await.unwrap();
+
+        assert!(matches!(
+            sched.cancel_running("missing"),
+            Err(ZeniiError::NotFound(_))
+        ));
+        assert!(!sched.cancel_running(&id).unwrap());
+
+        let cancel = Arc::new(Notify::new());
+        sched.running_jobs.insert(id.clone(), cancel.clone());
+        assert!(sched.is_job_running(&id));
+        assert!(sched.cancel_running(&id).unwrap());
+        tokio::time::timeout(Duration::from_secs(1), cancel.notified())
+            .await
+            .expect("run should be woken by cancel");
+    }
 }
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 
 pub type JobId = String;
@@ -12,19 +12,71 @@ pub enum Schedule {
     /// Run every `secs` seconds.
     Interval { secs: u64 },
     /// Run according to a cron expression.
-    Cron { expr: String },
+    Cron {
+        expr: String,
+        /// IANA timezone the expression is evaluated in (e.g. "America/New_York").
+        /// `None` uses the host's local timezone.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tz: Option<String>,
+    },
     /// Run once at a specific local datetime (e.g. "2026-03-20T00:53" or "2026-03-20 00:53").
     Human { datetime: String },
+    /// Run once when the clock passes `at`. A time already in the past fires on
+    /// the next tick. The job is deleted after it runs.
+    Once { at: DateTime<Utc> },
 }
 
-/// Optional local-time window during which a job may fire.
-/// Both values are 24-hour format (0–23). Job is skipped when
-/// local hour is outside `[start_hour, end_hour)`.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// A local-time window during which a job may fire: `[start, end)` on the
+/// listed days. Hours are 24-hour format (0–23). A window whose end is before
+/// its start runs overnight and belongs to the day it opens on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 #[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct ActiveHours {
     pub start_hour: u8,
+    #[serde(default)]
+    pub start_minute: u8,
     pub end_hour: u8,
+    #[serde(default)]
+    pub end_minute: u8,
+    /// Days the window opens on (e.g. `["Mon", "Tue"]`). Empty means every day.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "api-docs", schema(value_type = Vec<String>))]
+    pub days: Vec<Weekday>,
+    /// IANA timezone of the window. `None` uses the host's local timezone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tz: Option<String>,
+}
+
+impl ActiveHours {
+    /// Window start in minutes past midnight.
+    pub fn start(&self) -> u16 {
+        u16::from(self.start_hour) * 60 + u16::from(self.start_minute)
+    }
+
+    /// Window end in minutes past midnight.
+    pub fn end(&self) -> u16 {
+        u16::from(self.end_hour) * 60 + u16::from(self.end_minute)
+    }
+}
+
+/// Accepts a list of windows, a single window object (the pre-list format) or null.
+pub(crate) fn deserialize_active_hours<'de, D>(
+    deserializer: D,
+) -> Result<Vec<ActiveHours>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(ActiveHours),
+        Many(Vec<ActiveHours>),
+    }
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(OneOrMany::One(window)) => vec![window],
+        Some(OneOrMany::Many(windows)) => windows,
+    })
 }
 
 /// Which session context a job runs in.
@@ -51,8 +103,19 @@ pub enum JobPayload {
     Notify { message: String },
     /// Send a message via a named channel.
     SendViaChannel { channel: String, message: String },
+    /// Send a fixed message to one recipient on a channel, without the LLM.
+    /// A failed send counts as a job error.
+    ChannelNotify {
+        channel: String,
+        /// Chat or channel ID on that channel, e.g. a Telegram chat ID.
+        recipient: String,
+        message: String,
+    },
     /// Execute a workflow by ID.
     Workflow { workflow_id: String },
+    /// Summarize the day's memories with the active model and store the
+    /// result in the `daily_summary` memory category.
+    DailySummary,
 }
 
 /// A registered job in the scheduler.
@@ -71,20 +134,140 @@ pub struct ScheduledJob {
     pub error_count: u32,
     #[serde(default)]
     pub next_run: Option<DateTime<Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub active_hours: Option<ActiveHours>,
+    /// Windows the job may fire in; empty means any time. A job that comes
+    /// due outside every window is deferred to the start of the next one.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_active_hours",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub active_hours: Vec<ActiveHours>,
     #[serde(default)]
     pub delete_after_run: bool,
     /// Per-job timeout override in seconds. When `None`, falls back to the
     /// global `scheduler_agent_turn_timeout_secs` config value.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timeout_secs: Option<u64>,
+    /// Higher runs first when several jobs are due in the same tick.
+    /// When `None`, heartbeats use [`HEARTBEAT_PRIORITY`] and everything else 0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+}
+
+/// Default priority of heartbeat jobs, below user-created jobs.
+pub const HEARTBEAT_PRIORITY: i32 = -10;
+
+impl ScheduledJob {
+    /// The job's priority, falling back to the payload default.
+    pub fn effective_priority(&self) -> i32 {
+        self.priority.unwrap_or(match self.payload {
+            JobPayload::Heartbeat => HEARTBEAT_PRIORITY,
+            _ => 0,
+        })
+    }
 }
 
 fn default_true() -> bool {
     true
 }
 
+/// Version written to job export files; imports of newer versions are refused.
+pub const JOBS_EXPORT_VERSION: u32 = 1;
+
+/// A job's portable definition: a [`ScheduledJob`] without its id and runtime
+/// state (`next_run`, `error_count`). Jobs are matched by name on import.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct JobSpec {
+    pub name: String,
+    pub schedule: Schedule,
+    #[serde(default)]
+    pub session_target: SessionTarget,
+    pub payload: JobPayload,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_active_hours",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub active_hours: Vec<ActiveHours>,
+    #[serde(default)]
+    pub delete_after_run: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+}
+
+impl From<&ScheduledJob> for JobSpec {
+    fn from(job: &ScheduledJob) -> Self {
+        Self {
+            name: job.name.clone(),
+            schedule: job.schedule.clone(),
+            session_target: job.session_target.clone(),
+            payload: job.payload.clone(),
+            enabled: job.enabled,
+            active_hours: job.active_hours.clone(),
+            delete_after_run: job.delete_after_run,
+            timeout_secs: job.timeout_secs,
+            priority: job.priority,
+        }
+    }
+}
+
+impl JobSpec {
+    /// A new job with this definition and fresh runtime state.
+    pub fn into_job(self, id: JobId) -> ScheduledJob {
+        ScheduledJob {
+            id,
+            name: self.name,
+            schedule: self.schedule,
+            session_target: self.session_target,
+            payload: self.payload,
+            enabled: self.enabled,
+            error_count: 0,
+            next_run: None,
+            active_hours: self.active_hours,
+            delete_after_run: self.delete_after_run,
+            timeout_secs: self.timeout_secs,
+            priority: self.priority,
+        }
+    }
+}
+
+/// Contents of a job export file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct JobsExport {
+    pub version: u32,
+    pub jobs: Vec<JobSpec>,
+}
+
+/// What an import does with a job whose name already exists.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ImportConflict {
+    /// Keep the existing job.
+    #[default]
+    Skip,
+    /// Replace the existing job's definition, keeping its id.
+    Overwrite,
+}
+
+/// Job names affected by an import.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct ImportReport {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    /// Existing jobs kept because of [`ImportConflict::Skip`].
+    pub skipped: Vec<String>,
+    /// Existing jobs that already matched the imported definition.
+    pub unchanged: Vec<String>,
+}
+
 /// Outcome of a single job execution.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
@@ -94,6 +277,8 @@ pub enum JobStatus {
     Failed,
     Stuck,
     Skipped,
+    /// Stopped while running via `POST /scheduler/jobs/{id}/cancel`.
+    Cancelled,
 }
 
 /// Record of one job run.
@@ -139,6 +324,16 @@ mod tests {
     fn schedule_cron_serde() {
         let s = Schedule::Cron {
             expr: "0 */5 * * * *".into(),
+            tz: None,
+        };
+        let json = serde_json::to_string(&s).unwrap();
+        assert!(!json.contains("tz"));
+        let back: Schedule = serde_json::from_str(&json).unwrap();
+        assert_eq!(s, back);
+
+        let s = Schedule::Cron {
+            expr: "0 9 * * 1-5".into(),
+            tz: Some("America/New_York".into()),
         };
         let json = serde_json::to_string(&s).unwrap();
         let back: Schedule = serde_json::from_str(&json).unwrap();
@@ -156,6 +351,19 @@ mod tests {
         assert_eq!(s, back);
     }
 
+    // 16.2c — Schedule::Once serializes `at` as RFC 3339
+    #[test]
+    fn schedule_once_serde() {
+        let s = Schedule::Once {
+            at: "2025-06-01T09:00:00Z".parse().unwrap(),
+        };
+        let json = serde_json::to_value(&s).unwrap();
+        assert_eq!(json["type"], "once");
+        assert_eq!(json["at"], "2025-06-01T09:00:00Z");
+        let back: Schedule = serde_json::from_value(json).unwrap();
+        assert_eq!(s, back);
+    }
+
     // 16.3 — JobPayload::Heartbeat serialization
     #[test]
     fn payload_heartbeat_serde() {
@@ -188,6 +396,23 @@ mod tests {
         assert_eq!(p, back);
     }
 
+    // 16.5b — JobPayload::ChannelNotify serialization
+    #[test]
+    fn payload_channel_notify_serde() {
+        let json = r#"{"type":"channel_notify","channel":"telegram","recipient":"123","message":"standup!"}"#;
+        let p: JobPayload = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            p,
+            JobPayload::ChannelNotify {
+                channel: "telegram".into(),
+                recipient: "123".into(),
+                message: "standup!".into(),
+            }
+        );
+        let back: JobPayload = serde_json::from_str(&serde_json::to_string(&p).unwrap()).unwrap();
+        assert_eq!(p, back);
+    }
+
     // 16.6 — ScheduledJob default enabled state
     #[test]
     fn job_default_enabled() {
@@ -208,13 +433,42 @@ mod tests {
         let h = ActiveHours {
             start_hour: 9,
             end_hour: 17,
+            ..Default::default()
         };
-        assert!(h.start_hour < h.end_hour);
+        assert!(h.start() < h.end());
         let json = serde_json::to_string(&h).unwrap();
         let back: ActiveHours = serde_json::from_str(&json).unwrap();
         assert_eq!(h, back);
     }
 
+    // 16.7a — A single window (the old format) reads as a one-element list
+    #[test]
+    fn active_hours_single_window_back_compat() {
+        let job = |active_hours: &str| -> ScheduledJob {
+            serde_json::from_str(&format!(
+                r#"{{"id": "j", "name": "j", "schedule": {{"type": "interval", "secs": 60}},
+                    "payload": {{"type": "heartbeat"}}, "active_hours": {active_hours}}}"#
+            ))
+            .unwrap()
+        };
+
+        let single = job(r#"{"start_hour": 9, "end_hour": 17}"#);
+        assert_eq!(single.active_hours.len(), 1);
+        assert_eq!(single.active_hours[0].end(), 17 * 60);
+        assert!(job("null").active_hours.is_empty());
+
+        let many = job(
+            r#"[{"start_hour": 9, "end_hour": 17, "days": ["Mon", "Tue", "Wed", "Thu", "Fri"]},
+                {"start_hour": 8, "start_minute": 30, "end_hour": 12, "days": ["Sat"], "tz": "Europe/Berlin"}]"#,
+        );
+        assert_eq!(many.active_hours.len(), 2);
+        assert_eq!(many.active_hours[1].days, vec![Weekday::Sat]);
+        assert_eq!(many.active_hours[1].start(), 8 * 60 + 30);
+
+        let json = serde_json::to_value(&many).unwrap();
+        assert!(json["active_hours"].is_array());
+    }
+
     // 5.56 — JobPayload::Workflow variant serde round-trip
     #[test]
     fn job_payload_workflow_variant() {
@@ -257,4 +511,42 @@ mod tests {
             SessionTarget::Isolated
         );
     }
+
+    // 16.8a — Exported specs drop the id and runtime state
+    #[test]
+    fn job_spec_drops_runtime_fields() {
+        let job: ScheduledJob = serde_json::from_str(
+            r#"{"id":"1","name":"hb","schedule":{"type":"interval","secs":60},
+                "payload":{"type":"heartbeat"},"error_count":4,"next_run":"2025-06-01T09:00:00Z"}"#,
+        )
+        .unwrap();
+        let spec = JobSpec::from(&job);
+        let json = serde_json::to_value(&spec).unwrap();
+        assert!(json.get("id").is_none());
+        assert!(json.get("next_run").is_none());
+        assert!(json.get("error_count").is_none());
+
+        let back = spec.clone().into_job("2".into());
+        assert_eq!(back.error_count, 0);
+        assert_eq!(back.next_run, None);
+        assert_eq!(JobSpec::from(&back), spec);
+    }
+
+    #[test]
+    fn effective_priority_defaults() {
+        let mut job: ScheduledJob = serde_json::from_str(
+            r#"{"id":"1","name":"hb","schedule":{"type":"interval","secs":60},"payload":{"type":"heartbeat"}}"#,
+        )
+        .unwrap();
+        assert_eq!(job.priority, None);
+        assert_eq!(job.effective_priority(), HEARTBEAT_PRIORITY);
+
+        job.payload = JobPayload::AgentTurn {
+            prompt: "hi".into(),
+        };
+        assert_eq!(job.effective_priority(), 0);
+
+        job.priority = Some(5);
+        assert_eq!(job.effective_priority(), 5);
+    }
 }
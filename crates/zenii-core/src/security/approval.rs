@@ -34,6 +34,41 @@ impl ApprovalDecision {
     }
 }
 
+/// What a timed-out approval resolves to on a surface with no interactive
+/// user (scheduled runs, channel messages).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnattendedDecision {
+    /// Fail the tool call as if the user had denied it.
+    #[default]
+    Deny,
+    /// Skip the tool call and let the agent continue without its result.
+    Skip,
+}
+
+impl UnattendedDecision {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Deny => "deny",
+            Self::Skip => "skip",
+        }
+    }
+
+    /// Parse a config value; `None` for anything unrecognized.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "deny" => Some(Self::Deny),
+            "skip" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+}
+
+/// Whether someone is at the keyboard to answer approval prompts on `surface`.
+pub fn is_interactive_surface(surface: &str) -> bool {
+    matches!(surface, "desktop" | "cli" | "tui")
+}
+
 /// A request for user approval of a tool call.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApprovalRequest {
@@ -46,6 +81,14 @@ pub struct ApprovalRequest {
     pub timeout_secs: u64,
 }
 
+/// A decision delivered to a waiting tool call, with who made it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalResolution {
+    pub decision: ApprovalDecision,
+    /// Surface the decision came from, e.g. `http` or `ws`.
+    pub resolved_by: String,
+}
+
 /// A persistent approval rule saved to the database.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApprovalRule {
@@ -61,7 +104,7 @@ pub struct ApprovalRule {
 pub struct ApprovalBroker {
     /// Pending approval requests waiting for a response.
     /// Key: approval_id, Value: oneshot sender for the decision.
-    pending: DashMap<String, oneshot::Sender<ApprovalDecision>>,
+    pending: DashMap<String, oneshot::Sender<ApprovalResolution>>,
     /// Session-scoped cache: key = "tool_name" or "tool_name:command_pattern", value = decision.
     session_cache: DashMap<String, ApprovalDecision>,
     db: DbPool,
@@ -123,18 +166,29 @@ impl ApprovalBroker {
     }
 
     /// Register a pending approval request. Returns a receiver to await the decision.
-    pub fn register(&self, approval_id: &str) -> oneshot::Receiver<ApprovalDecision> {
+    pub fn register(&self, approval_id: &str) -> oneshot::Receiver<ApprovalResolution> {
         let (tx, rx) = oneshot::channel();
         self.pending.insert(approval_id.to_string(), tx);
         rx
     }
 
-    /// Resolve a pending approval request with the user's decision.
-    /// Returns `true` if the approval was found and resolved.
-    pub fn resolve(&self, approval_id: &str, decision: ApprovalDecision) -> bool {
+    /// Resolve a pending approval request with the user's decision, made on
+    /// the `resolved_by` surface. Returns `true` if the approval was found and resolved.
+    pub fn resolve(
+        &self,
+        approval_id: &str,
+        decision: ApprovalDecision,
+        resolved_by: &str,
+    ) -> bool {
         if let Some((_, tx)) = self.pending.remove(approval_id) {
-            let _ = tx.send(decision);
-            info!("approval: resolved {approval_id} -> {:?}", decision);
+            let _ = tx.send(ApprovalResolution {
+                decision,
+                resolved_by: resolved_by.to_string(),
+            });
+            info!(
+                "approval: resolved {approval_id} -> {:?} via {resolved_by}",
+                decision
+            );
             true
         } else {
             debug!("approval: no pending request for {approval_id}");
@@ -306,6 +360,11 @@ impl ApprovalBroker {
         self.pending.remove(approval_id);
     }
 
+    /// Whether `approval_id` is still waiting for a decision.
+    pub fn is_pending(&self, approval_id: &str) -> bool {
+        self.pending.contains_key(approval_id)
+    }
+
     /// Number of pending approval requests (for testing/diagnostics).
     pub fn pending_count(&self) -> usize {
         self.pending.len()
@@ -353,6 +412,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unattended_decision_parse() {
+        assert_eq!(
+            UnattendedDecision::parse("deny"),
+            Some(UnattendedDecision::Deny)
+        );
+        assert_eq!(
+            UnattendedDecision::parse("SKIP"),
+            Some(UnattendedDecision::Skip)
+        );
+        assert_eq!(UnattendedDecision::parse("maybe"), None);
+        assert_eq!(UnattendedDecision::Skip.as_str(), "skip");
+        assert!(is_interactive_surface("cli"));
+        assert!(!is_interactive_surface("scheduler"));
+        assert!(!is_interactive_surface("telegram"));
+    }
+
     #[test]
     fn glob_match_wildcard() {
         assert!(glob_match("*", "anything"));
@@ -368,16 +444,17 @@ mod tests {
         let rx = broker.register("req-1");
         assert_eq!(broker.pending_count(), 1);
 
-        broker.resolve("req-1", ApprovalDecision::Approve);
-        let decision = rx.await.unwrap();
-        assert_eq!(decision, ApprovalDecision::Approve);
+        broker.resolve("req-1", ApprovalDecision::Approve, "http");
+        let resolution = rx.await.unwrap();
+        assert_eq!(resolution.decision, ApprovalDecision::Approve);
+        assert_eq!(resolution.resolved_by, "http");
         assert_eq!(broker.pending_count(), 0);
     }
 
     #[tokio::test]
     async fn resolve_unknown_returns_false() {
         let (_dir, broker) = test_broker().await;
-        assert!(!broker.resolve("nonexistent", ApprovalDecision::Deny));
+        assert!(!broker.resolve("nonexistent", ApprovalDecision::Deny, "http"));
     }
 
     #[tokio::test]
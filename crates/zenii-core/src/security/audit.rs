@@ -0,0 +1,532 @@
+use std::future::Future;
+use std::sync::{Arc, LazyLock};
+
+use chrono::Utc;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
+use tracing::{error, warn};
+
+use crate::Result;
+use crate::db::query::{clamp_limit, normalize_timestamp, parse_bound};
+use crate::db::{self, DbPool};
+use crate::event_bus::{AppEvent, EventBus};
+
+/// Placeholder written in place of scrubbed values.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Single-word key segments that mark an argument as a secret.
+const SENSITIVE_SEGMENTS: &[&str] = &[
+    "token",
+    "secret",
+    "password",
+    "passwd",
+    "authorization",
+    "cookie",
+    "credential",
+    "credentials",
+    "apikey",
+];
+
+/// Multi-word key names that mark an argument as a secret.
+const SENSITIVE_KEYS: &[&str] = &["api_key", "private_key", "access_key", "client_secret"];
+
+/// Secret-looking substrings in free text: auth headers, well-known key
+/// prefixes, and `NAME=value` / `--flag value` pairs with sensitive names.
+static SECRET_PATTERNS: LazyLock<Vec<(Regex, &'static str)>> = LazyLock::new(|| {
+    [
+        (
+            r"(?i)\b(bearer|basic)\s+[A-Za-z0-9._~+/=-]+",
+            "$1 [REDACTED]",
+        ),
+        (r"\b(?:sk|pk|rk)-[A-Za-z0-9_-]{8,}", REDACTED),
+        (r"\bgh[pousr]_[A-Za-z0-9]{20,}", REDACTED),
+        (r"\bxox[abpr]-[A-Za-z0-9-]{10,}", REDACTED),
+        (r"\bAKIA[0-9A-Z]{16}\b", REDACTED),
+        (
+            r"(?i)\b([A-Z0-9_]*(?:API_?KEY|TOKEN|SECRET|PASSWORD)[A-Z0-9_]*)=\S+",
+            "$1=[REDACTED]",
+        ),
+        (
+            r"(?i)(--?(?:api-key|token|password|secret))(=|\s+)\S+",
+            "$1$2[REDACTED]",
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(pattern, replacement)| match Regex::new(pattern) {
+        Ok(re) => Some((re, replacement)),
+        Err(e) => {
+            warn!("Invalid audit redaction pattern {pattern}: {e}");
+            None
+        }
+    })
+    .collect()
+});
+
+fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_lowercase().replace('-', "_");
+    SENSITIVE_KEYS.iter().any(|k| key.contains(k))
+        || key
+            .split('_')
+            .any(|segment| SENSITIVE_SEGMENTS.contains(&segment))
+}
+
+/// Scrub secret-looking substrings from free text.
+pub fn redact_text(text: &str) -> String {
+    SECRET_PATTERNS
+        .iter()
+        .fold(text.to_string(), |acc, (re, replacement)| {
+            re.replace_all(&acc, *replacement).into_owned()
+        })
+}
+
+/// Copy of tool arguments with secret-named fields replaced by [`REDACTED`]
+/// and secret-looking substrings scrubbed from every string.
+pub fn redact_args(args: &serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match args {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| {
+                    let v = if is_sensitive_key(k) && !v.is_null() {
+                        Value::String(REDACTED.into())
+                    } else {
+                        redact_args(v)
+                    };
+                    (k.clone(), v)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_args).collect()),
+        Value::String(s) => Value::String(redact_text(s)),
+        other => other.clone(),
+    }
+}
+
+tokio::task_local! {
+    static AUDIT_SESSION: String;
+}
+
+/// Run `fut` with `session_id` attached to the audit records of the tool
+/// calls it makes. `None` runs it without a session.
+pub async fn session_scope<F: Future>(session_id: Option<String>, fut: F) -> F::Output {
+    match session_id {
+        Some(id) => AUDIT_SESSION.scope(id, fut).await,
+        None => fut.await,
+    }
+}
+
+/// Session attached by the enclosing [`session_scope`], if any.
+pub(crate) fn current_session() -> Option<String> {
+    AUDIT_SESSION.try_with(|id| id.clone()).ok()
+}
+
+/// Writes tool invocation records to the `audit_log` table in the order they
+/// are submitted. Submitting never blocks and never drops a record.
+#[derive(Clone)]
+pub struct AuditSink {
+    tx: mpsc::UnboundedSender<AuditRecord>,
+}
+
+impl AuditSink {
+    /// Start the writer task for `db`.
+    pub fn spawn(db: DbPool) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<AuditRecord>();
+        tokio::spawn(async move {
+            while let Some(record) = rx.recv().await {
+                if let Err(e) = insert(&db, record).await {
+                    warn!("Failed to write audit log entry: {e}");
+                }
+            }
+        });
+        Self { tx }
+    }
+
+    /// A tool is about to run with `args` (stored redacted).
+    pub fn tool_started(&self, call_id: &str, tool_name: &str, args: &serde_json::Value) {
+        let mut record = AuditRecord::new("tool_start", tool_name);
+        record.session_id = current_session();
+        record.call_id = Some(call_id.into());
+        record.args = Some(redact_args(args).to_string());
+        self.submit(record);
+    }
+
+    /// A tool call finished, or was answered from the dedup cache.
+    pub fn tool_finished(&self, call_id: &str, tool_name: &str, success: bool) {
+        let mut record = AuditRecord::new("tool_result", tool_name);
+        record.session_id = current_session();
+        record.call_id = Some(call_id.into());
+        record.success = Some(success);
+        self.submit(record);
+    }
+
+    fn submit(&self, record: AuditRecord) {
+        if self.tx.send(record).is_err() {
+            error!("Audit log writer has stopped; tool call not recorded");
+        }
+    }
+}
+
+/// One row of the durable audit log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub id: i64,
+    pub timestamp: String,
+    /// `tool_start`, `tool_result`, `approval_requested`, `approval_resolved`,
+    /// or `events_dropped` when the logger fell behind the event bus.
+    pub kind: String,
+    pub session_id: Option<String>,
+    pub call_id: Option<String>,
+    pub tool_name: String,
+    /// Redacted arguments (JSON) for `tool_start`, redacted summary for approvals.
+    pub args: Option<String>,
+    pub success: Option<bool>,
+    pub decision: Option<String>,
+    pub resolved_by: Option<String>,
+}
+
+/// Filter for [`query`].
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuditQuery {
+    pub session_id: Option<String>,
+    /// Inclusive lower bound, RFC 3339.
+    pub since: Option<String>,
+    /// Exclusive upper bound, RFC 3339.
+    pub until: Option<String>,
+    pub limit: Option<usize>,
+}
+
+impl AuditRecord {
+    fn new(kind: &str, tool_name: &str) -> Self {
+        Self {
+            id: 0,
+            timestamp: normalize_timestamp(Utc::now()),
+            kind: kind.into(),
+            session_id: None,
+            call_id: None,
+            tool_name: tool_name.into(),
+            args: None,
+            success: None,
+            decision: None,
+            resolved_by: None,
+        }
+    }
+}
+
+/// Audit row for an approval event, or `None` for events that are not
+/// audited. Tool calls are recorded by [`AuditSink`] instead.
+fn record_for(event: &AppEvent) -> Option<AuditRecord> {
+    match event {
+        AppEvent::ApprovalRequested {
+            call_id,
+            tool_name,
+            args_summary,
+            ..
+        } => {
+            let mut record = AuditRecord::new("approval_requested", tool_name);
+            record.call_id = Some(call_id.clone());
+            record.args = Some(redact_text(args_summary));
+            Some(record)
+        }
+        AppEvent::ApprovalResolved {
+            call_id,
+            tool_name,
+            decision,
+            resolved_by,
+            ..
+        } => {
+            let mut record = AuditRecord::new("approval_resolved", tool_name);
+            record.call_id = Some(call_id.clone());
+            record.decision = Some(decision.clone());
+            record.resolved_by = Some(resolved_by.clone());
+            Some(record)
+        }
+        _ => None,
+    }
+}
+
+async fn insert(db: &DbPool, record: AuditRecord) -> Result<()> {
+    db::with_db(db, move |conn| {
+        conn.execute(
+            "INSERT INTO audit_log (timestamp, kind, session_id, call_id, tool_name, args, success, decision, resolved_by)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            rusqlite::params![
+                record.timestamp,
+                record.kind,
+                record.session_id,
+                record.call_id,
+                record.tool_name,
+                record.args,
+                record.success,
+                record.decision,
+                record.resolved_by,
+            ],
+        )?;
+        Ok(())
+    })
+    .await
+}
+
+/// Writes approval decisions from the event bus to the `audit_log` table.
+/// Arguments are redacted before they are stored.
+pub struct AuditLogger;
+
+impl AuditLogger {
+    /// Start persisting audit events from `bus` until the bus closes.
+    pub fn spawn(bus: Arc<dyn EventBus>, db: DbPool) -> JoinHandle<()> {
+        let mut rx = bus.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => {
+                        if let Some(record) = record_for(&event)
+                            && let Err(e) = insert(&db, record).await
+                        {
+                            warn!("Failed to write audit log entry: {e}");
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        // Leave a gap marker so the audit trail shows it is incomplete
+                        error!("Audit logger lagged, {n} events not recorded");
+                        let mut record = AuditRecord::new("events_dropped", "");
+                        record.args = Some(n.to_string());
+                        if let Err(e) = insert(&db, record).await {
+                            warn!("Failed to write audit log entry: {e}");
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
+
+/// Audit rows matching `filter`, newest first.
+pub async fn query(db: &DbPool, filter: &AuditQuery) -> Result<Vec<AuditRecord>> {
+    let since = filter
+        .since
+        .as_deref()
+        .map(|s| parse_bound("since", s))
+        .transpose()?;
+    let until = filter
+        .until
+        .as_deref()
+        .map(|s| parse_bound("until", s))
+        .transpose()?;
+    let session_id = filter.session_id.clone();
+    let limit = clamp_limit(filter.limit);
+
+    db::with_db(db, move |conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, timestamp, kind, session_id, call_id, tool_name, args, success, decision, resolved_by
+             FROM audit_log
+             WHERE (?1 IS NULL OR session_id = ?1)
+               AND (?2 IS NULL OR timestamp >= ?2)
+               AND (?3 IS NULL OR timestamp < ?3)
+             ORDER BY id DESC
+             LIMIT ?4",
+        )?;
+        let rows = stmt
+            .query_map(rusqlite::params![session_id, since, until, limit], |row| {
+                Ok(AuditRecord {
+                    id: row.get(0)?,
+                    timestamp: row.get(1)?,
+                    kind: row.get(2)?,
+                    session_id: row.get(3)?,
+                    call_id: row.get(4)?,
+                    tool_name: row.get(5)?,
+                    args: row.get(6)?,
+                    success: row.get(7)?,
+                    decision: row.get(8)?,
+                    resolved_by: row.get(9)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(rows)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::ZeniiError;
+    use crate::event_bus::TokioBroadcastBus;
+
+    // AUD.1 — Secret-named fields and secret-looking strings are scrubbed
+    #[test]
+    fn redact_args_scrubs_secrets() {
+        let args = json!({
+            "url": "https://api.example.com",
+            "headers": {"Authorization": "Bearer abc.def", "Accept": "json"},
+            "api_key": "plain",
+            "max_tokens": 100,
+            "command": "curl -H 'X: 1' --token hunter2 && OPENAI_API_KEY=sk-abcdefghijkl run",
+            "notes": ["ghp_abcdefghijklmnopqrstuvwxyz"]
+        });
+        let redacted = redact_args(&args);
+        assert_eq!(redacted["url"], "https://api.example.com");
+        assert_eq!(redacted["headers"]["Authorization"], REDACTED);
+        assert_eq!(redacted["headers"]["Accept"], "json");
+        assert_eq!(redacted["api_key"], REDACTED);
+        assert_eq!(redacted["max_tokens"], 100);
+        let command = redacted["command"].as_str().unwrap();
+        assert!(!command.contains("hunter2"), "{command}");
+        assert!(!command.contains("sk-abcdefghijkl"), "{command}");
+        assert!(command.contains("OPENAI_API_KEY=[REDACTED]"), "{command}");
+        assert_eq!(redacted["notes"][0], REDACTED);
+        assert_eq!(
+            redact_text("Authorization: Bearer xyz123"),
+            "Authorization: Bearer [REDACTED]"
+        );
+    }
+
+    // AUD.2 — Tool calls and approval events are persisted and filtered by session and time
+    #[tokio::test]
+    async fn logger_persists_and_filters_events() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = db::init_pool(&dir.path().join("audit.db")).unwrap();
+        db::with_db(&db, |conn| db::run_migrations(conn))
+            .await
+            .unwrap();
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let handle = AuditLogger::spawn(bus.clone(), db.clone());
+        let start = normalize_timestamp(Utc::now() - chrono::Duration::seconds(1));
+
+        for event in [
+            AppEvent::ApprovalRequested {
+                approval_id: "a1".into(),
+                call_id: "c1".into(),
+                tool_name: "shell".into(),
+                args_summary: "deploy --token s3cret".into(),
+                risk_level: "medium".into(),
+                reason: "needs approval".into(),
+                timeout_secs: 60,
+                session_id: None,
+            },
+            AppEvent::ApprovalResolved {
+                approval_id: "a1".into(),
+                call_id: "c1".into(),
+                tool_name: "shell".into(),
+                decision: "approve".into(),
+                resolved_by: "ws".into(),
+            },
+            // Tool calls are audited by the sink, not from the bus
+            AppEvent::AgentToolStarted {
+                session_id: "s1".into(),
+                call_id: "c1".into(),
+                tool_name: "shell".into(),
+                args: json!({"command": "deploy --token s3cret"}),
+            },
+            AppEvent::ConfigUpdated,
+        ] {
+            bus.publish(event).unwrap();
+        }
+        let sink = AuditSink::spawn(db.clone());
+        session_scope(Some("s1".into()), async {
+            sink.tool_started("c1", "shell", &json!({"command": "deploy --token s3cret"}));
+            sink.tool_finished("c1", "shell", true);
+        })
+        .await;
+        session_scope(Some("s2".into()), async {
+            sink.tool_started("c2", "file_read", &json!({"path": "a.txt"}));
+        })
+        .await;
+
+        let mut all = Vec::new();
+        for _ in 0..50 {
+            all = query(&db, &AuditQuery::default()).await.unwrap();
+            if all.len() == 5 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert_eq!(all.len(), 5);
+        assert!(
+            all.iter()
+                .all(|r| !r.args.as_deref().unwrap_or("").contains("s3cret"))
+        );
+        let resolved = all.iter().find(|r| r.kind == "approval_resolved").unwrap();
+        assert_eq!(resolved.decision.as_deref(), Some("approve"));
+        assert_eq!(resolved.resolved_by.as_deref(), Some("ws"));
+
+        let s1 = query(
+            &db,
+            &AuditQuery {
+                session_id: Some("s1".into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let kinds: Vec<_> = s1.iter().map(|r| r.kind.as_str()).collect();
+        assert_eq!(kinds, ["tool_result", "tool_start"]);
+        assert_eq!(s1[0].success, Some(true));
+
+        let future = query(
+            &db,
+            &AuditQuery {
+                since: Some("2999-01-01T00:00:00Z".into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert!(future.is_empty());
+        let bounded = query(
+            &db,
+            &AuditQuery {
+                since: Some(start),
+                until: Some("2999-01-01T00:00:00+02:00".into()),
+                limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(bounded.len(), 2);
+
+        let bad = AuditQuery {
+            since: Some("yesterday".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            query(&db, &bad).await,
+            Err(ZeniiError::Validation(_))
+        ));
+        handle.abort();
+    }
+
+    // AUD.3 — A lagged logger leaves a gap marker instead of dropping silently
+    #[tokio::test]
+    async fn lagged_logger_records_gap() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = db::init_pool(&dir.path().join("audit.db")).unwrap();
+        db::with_db(&db, |conn| db::run_migrations(conn))
+            .await
+            .unwrap();
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(2));
+        let handle = AuditLogger::spawn(bus.clone(), db.clone());
+        // The logger task has not run yet, so it falls 3 events behind
+        for _ in 0..5 {
+            bus.publish(AppEvent::ConfigUpdated).unwrap();
+        }
+
+        let mut rows = Vec::new();
+        for _ in 0..50 {
+            rows = query(&db, &AuditQuery::default()).await.unwrap();
+            if !rows.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].kind, "events_dropped");
+        assert_eq!(rows[0].args.as_deref(), Some("3"));
+        handle.abort();
+    }
+}
@@ -0,0 +1,309 @@
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use crate::config::AppConfig;
+use crate::db::query::normalize_timestamp;
+use crate::db::{self, DbPool};
+use crate::event_bus::{AppEvent, EventBus};
+use crate::{Result, ZeniiError};
+
+/// Spend against one cap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct SpendWindow {
+    pub spent_usd: f64,
+    /// Configured cap, `None` when unlimited.
+    pub cap_usd: Option<f64>,
+    /// When this window starts over (RFC 3339), `None` for sessions.
+    pub resets_at: Option<String>,
+}
+
+impl SpendWindow {
+    pub fn exceeded(&self) -> bool {
+        self.cap_usd.is_some_and(|cap| self.spent_usd >= cap)
+    }
+}
+
+/// Current spend vs. caps, as shown by the dashboard gauge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct BudgetStatus {
+    pub daily: SpendWindow,
+    pub monthly: SpendWindow,
+    /// Present when the status was requested for a session.
+    pub session: Option<SpendWindow>,
+}
+
+/// Start of `date` in the local timezone. Falls back to the latest valid
+/// instant when midnight is skipped by a DST change.
+fn local_midnight(date: NaiveDate) -> DateTime<Utc> {
+    let naive = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+    match Local.from_local_datetime(&naive) {
+        chrono::LocalResult::Single(ts) | chrono::LocalResult::Ambiguous(ts, _) => ts,
+        chrono::LocalResult::None => Local
+            .from_local_datetime(&(naive + chrono::Duration::hours(1)))
+            .earliest()
+            .unwrap_or_else(Local::now),
+    }
+    .with_timezone(&Utc)
+}
+
+/// `[start, end)` of the local day containing `now`.
+fn day_window(now: DateTime<Local>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let today = now.date_naive();
+    let tomorrow = today.succ_opt().unwrap_or(today);
+    (local_midnight(today), local_midnight(tomorrow))
+}
+
+/// `[start, end)` of the local month containing `now`.
+fn month_window(now: DateTime<Local>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let first = NaiveDate::from_ymd_opt(now.year(), now.month(), 1).unwrap_or(now.date_naive());
+    let next = if now.month() == 12 {
+        NaiveDate::from_ymd_opt(now.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(now.year(), now.month() + 1, 1)
+    }
+    .unwrap_or(first);
+    (local_midnight(first), local_midnight(next))
+}
+
+/// Accumulates estimated completion costs in the `spend_ledger` table and
+/// enforces the `budget_*_usd` caps. Daily and monthly windows follow the
+/// local calendar, so they reset at local midnight and on the 1st.
+pub struct BudgetTracker {
+    db: DbPool,
+    config: Arc<ArcSwap<AppConfig>>,
+}
+
+impl BudgetTracker {
+    pub fn new(db: DbPool, config: Arc<ArcSwap<AppConfig>>) -> Self {
+        Self { db, config }
+    }
+
+    /// Record every costed `AgentUsage` event from `bus` until the bus closes.
+    pub fn spawn_recorder(self: &Arc<Self>, bus: Arc<dyn EventBus>) -> JoinHandle<()> {
+        let tracker = Arc::clone(self);
+        let mut rx = bus.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(AppEvent::AgentUsage {
+                        session_id,
+                        cost_usd: Some(cost),
+                        ..
+                    }) => {
+                        if let Err(e) = tracker.record(Some(&session_id), cost).await {
+                            warn!("Failed to record spend: {e}");
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!("Budget tracker lagged, {n} events not recorded");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    /// Add `cost_usd` to the ledger.
+    pub async fn record(&self, session_id: Option<&str>, cost_usd: f64) -> Result<()> {
+        if !cost_usd.is_finite() || cost_usd <= 0.0 {
+            return Ok(());
+        }
+        let timestamp = normalize_timestamp(Utc::now());
+        let session_id = session_id.map(str::to_string);
+        db::with_db(&self.db, move |conn| {
+            conn.execute(
+                "INSERT INTO spend_ledger (timestamp, session_id, cost_usd) VALUES (?1, ?2, ?3)",
+                rusqlite::params![timestamp, session_id, cost_usd],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Spend so far in the current day and month, and in `session_id` if given.
+    pub async fn status(&self, session_id: Option<&str>) -> Result<BudgetStatus> {
+        let config = self.config.load();
+        let now = Local::now();
+        let (day_start, day_end) = day_window(now);
+        let (month_start, month_end) = month_window(now);
+        let day_from = normalize_timestamp(day_start);
+        let month_from = normalize_timestamp(month_start);
+        let session = session_id.map(str::to_string);
+
+        let (daily, monthly, session_spent) = db::with_db(&self.db, move |conn| {
+            let sum_since = |from: &str| -> rusqlite::Result<f64> {
+                conn.query_row(
+                    "SELECT COALESCE(SUM(cost_usd), 0) FROM spend_ledger WHERE timestamp >= ?1",
+                    [from],
+                    |row| row.get(0),
+                )
+            };
+            let daily = sum_since(&day_from)?;
+            let monthly = sum_since(&month_from)?;
+            let session_spent = match session {
+                Some(ref id) => Some(conn.query_row(
+                    "SELECT COALESCE(SUM(cost_usd), 0) FROM spend_ledger WHERE session_id = ?1",
+                    [id],
+                    |row| row.get(0),
+                )?),
+                None => None,
+            };
+            Ok((daily, monthly, session_spent))
+        })
+        .await?;
+
+        Ok(BudgetStatus {
+            daily: SpendWindow {
+                spent_usd: daily,
+                cap_usd: config.budget_daily_usd,
+                resets_at: Some(normalize_timestamp(day_end)),
+            },
+            monthly: SpendWindow {
+                spent_usd: monthly,
+                cap_usd: config.budget_monthly_usd,
+                resets_at: Some(normalize_timestamp(month_end)),
+            },
+            session: session_spent.map(|spent_usd| SpendWindow {
+                spent_usd,
+                cap_usd: config.budget_session_usd,
+                resets_at: None,
+            }),
+        })
+    }
+
+    /// Fail with [`ZeniiError::BudgetExceeded`] if any cap is already reached.
+    pub async fn check(&self, session_id: Option<&str>) -> Result<()> {
+        self.check_with_pending(session_id, 0.0).await
+    }
+
+    /// Like [`Self::check`], counting `pending_usd` not yet in the ledger
+    /// (e.g. the cost of the turn in progress).
+    pub async fn check_with_pending(
+        &self,
+        session_id: Option<&str>,
+        pending_usd: f64,
+    ) -> Result<()> {
+        let config = self.config.load();
+        if config.budget_daily_usd.is_none()
+            && config.budget_monthly_usd.is_none()
+            && (config.budget_session_usd.is_none() || session_id.is_none())
+        {
+            return Ok(());
+        }
+        let status = self.status(session_id).await?;
+        let windows = [
+            ("daily", Some(&status.daily)),
+            ("monthly", Some(&status.monthly)),
+            ("session", status.session.as_ref()),
+        ];
+        for (name, window) in windows {
+            let Some(window) = window else { continue };
+            let spent = window.spent_usd + pending_usd;
+            if let Some(cap) = window.cap_usd
+                && spent >= cap
+            {
+                let resets = window
+                    .resets_at
+                    .as_deref()
+                    .map(|at| format!(", resets at {at}"))
+                    .unwrap_or_default();
+                return Err(ZeniiError::BudgetExceeded(format!(
+                    "{name} spend cap of ${cap:.2} reached (${spent:.2} spent{resets})"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_bus::TokioBroadcastBus;
+
+    async fn tracker(config: AppConfig) -> (tempfile::TempDir, Arc<BudgetTracker>) {
+        let dir = tempfile::tempdir().unwrap();
+        let pool = db::init_pool(&dir.path().join("test.db")).unwrap();
+        db::with_db(&pool, db::run_migrations).await.unwrap();
+        let config = Arc::new(ArcSwap::from_pointee(config));
+        (dir, Arc::new(BudgetTracker::new(pool, config)))
+    }
+
+    #[test]
+    fn windows_cover_now() {
+        let now = Local::now();
+        let utc = now.with_timezone(&Utc);
+        let (day_start, day_end) = day_window(now);
+        assert!(day_start <= utc && utc < day_end);
+        let (month_start, month_end) = month_window(now);
+        assert!(month_start <= day_start && day_end <= month_end);
+    }
+
+    #[tokio::test]
+    async fn unlimited_by_default() {
+        let (_dir, tracker) = tracker(AppConfig::default()).await;
+        tracker.record(Some("s1"), 1000.0).await.unwrap();
+        assert!(tracker.check(Some("s1")).await.is_ok());
+
+        let status = tracker.status(Some("s1")).await.unwrap();
+        assert!((status.daily.spent_usd - 1000.0).abs() < 1e-9);
+        assert!(!status.daily.exceeded());
+        assert!(status.daily.resets_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn caps_refuse_once_reached() {
+        let config = AppConfig {
+            budget_daily_usd: Some(1.0),
+            budget_session_usd: Some(0.5),
+            ..Default::default()
+        };
+        let (_dir, tracker) = tracker(config).await;
+        tracker.record(Some("s1"), 0.6).await.unwrap();
+
+        let err = tracker.check(Some("s1")).await.unwrap_err();
+        assert!(matches!(err, ZeniiError::BudgetExceeded(ref m) if m.contains("session")));
+        assert!(tracker.check(Some("s2")).await.is_ok());
+
+        let err = tracker
+            .check_with_pending(Some("s2"), 0.4)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ZeniiError::BudgetExceeded(ref m) if m.contains("daily")));
+    }
+
+    #[tokio::test]
+    async fn recorder_sums_usage_events() {
+        let (_dir, tracker) = tracker(AppConfig::default()).await;
+        let bus: Arc<dyn EventBus> = Arc::new(TokioBroadcastBus::new(16));
+        let handle = tracker.spawn_recorder(bus.clone());
+        for cost in [Some(0.25), None, Some(0.5)] {
+            bus.publish(AppEvent::AgentUsage {
+                session_id: "s1".into(),
+                total_tokens: Some(10),
+                cost_usd: cost,
+            })
+            .unwrap();
+        }
+
+        let mut spent = 0.0;
+        for _ in 0..50 {
+            spent = tracker.status(None).await.unwrap().monthly.spent_usd;
+            if spent >= 0.75 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!((spent - 0.75).abs() < 1e-9);
+        handle.abort();
+    }
+}
@@ -1,4 +1,6 @@
 pub mod approval;
+pub mod audit;
+pub mod budget;
 pub mod permissions;
 pub mod policy;
 
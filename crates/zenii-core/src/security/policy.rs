@@ -1,8 +1,9 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::net::IpAddr;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
 
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -10,6 +11,26 @@ use tracing::warn;
 
 use crate::config::AppConfig;
 
+/// Write-oriented tools, denied outright in ReadOnly mode.
+const WRITE_TOOLS: &[&str] = &[
+    "file_write",
+    "shell",
+    "patch",
+    "channel_send",
+    "scheduler",
+    "config",
+    "memory",
+];
+
+/// Tools that allow their read-only operations in ReadOnly mode and refuse
+/// the writing ones themselves.
+const SELF_GATED_WRITE_TOOLS: &[&str] = &["git", "http_request", "process"];
+
+/// Whether `tool_name` can change state outside the agent's conversation.
+fn can_write(tool_name: &str) -> bool {
+    WRITE_TOOLS.contains(&tool_name) || SELF_GATED_WRITE_TOOLS.contains(&tool_name)
+}
+
 /// The level of autonomy granted to the agent.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -32,10 +53,21 @@ impl AutonomyLevel {
             }
         }
     }
+
+    /// Parse a string into an AutonomyLevel, returning `None` for unrecognized values.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "readonly" | "read_only" | "read-only" => Some(Self::ReadOnly),
+            "full" => Some(Self::Full),
+            "supervised" => Some(Self::Supervised),
+            _ => None,
+        }
+    }
 }
 
 /// Risk classification for a command.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum RiskLevel {
@@ -282,14 +314,46 @@ const INJECTION_PATTERNS: &[&str] = &[
 
 /// Security policy engine that validates commands and paths based on autonomy level.
 pub struct SecurityPolicy {
-    pub autonomy_level: AutonomyLevel,
+    autonomy_level: RwLock<AutonomyLevel>,
     pub workspace_root: Option<PathBuf>,
     pub blocked_dirs: Vec<PathBuf>,
+    /// Hosts the HTTP tool may reach (empty = any public host). Entries also match subdomains.
+    pub http_allowed_hosts: Vec<String>,
+    /// Hosts the HTTP tool may never reach. Checked before the allowlist.
+    pub http_denied_hosts: Vec<String>,
+    /// Per-tool autonomy overrides, consulted before `autonomy_level`.
+    tool_autonomy: RwLock<HashMap<String, AutonomyLevel>>,
     rate_limiter: Mutex<RateLimiter>,
     audit_log: Mutex<VecDeque<AuditEntry>>,
     audit_capacity: usize,
 }
 
+/// Parse `security_tool_autonomy`, skipping (with a warning) unrecognized levels.
+pub fn tool_autonomy_from_config(config: &AppConfig) -> HashMap<String, AutonomyLevel> {
+    config
+        .security_tool_autonomy
+        .iter()
+        .filter_map(|(tool, level)| match AutonomyLevel::parse(level) {
+            Some(level) => Some((tool.clone(), level)),
+            None => {
+                warn!("Ignoring unrecognized autonomy level '{level}' for tool '{tool}'");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Execution limit for a built-in tool: its `security_tool_timeouts` entry if
+/// any, else `security_tool_timeout_secs`. `None` when the limit is 0.
+pub fn tool_timeout(config: &AppConfig, tool_name: &str) -> Option<std::time::Duration> {
+    let secs = config
+        .security_tool_timeouts
+        .get(tool_name)
+        .copied()
+        .unwrap_or(config.security_tool_timeout_secs);
+    (secs > 0).then(|| std::time::Duration::from_secs(secs))
+}
+
 /// Platform-conditional default blocked directories.
 fn default_blocked_dirs() -> Vec<PathBuf> {
     let mut dirs = Vec::new();
@@ -387,9 +451,12 @@ impl SecurityPolicy {
         audit_capacity: usize,
     ) -> Self {
         Self {
-            autonomy_level,
+            autonomy_level: RwLock::new(autonomy_level),
             workspace_root,
             blocked_dirs,
+            http_allowed_hosts: Vec::new(),
+            http_denied_hosts: Vec::new(),
+            tool_autonomy: RwLock::new(HashMap::new()),
             rate_limiter: Mutex::new(RateLimiter::new(rate_limit_max, rate_limit_window_secs)),
             audit_log: Mutex::new(VecDeque::with_capacity(audit_capacity)),
             audit_capacity,
@@ -419,6 +486,59 @@ impl SecurityPolicy {
             config.security_rate_limit_window_secs,
             config.security_audit_log_capacity,
         )
+        .with_http_hosts(
+            config.security_http_allowed_hosts.clone(),
+            config.security_http_denied_hosts.clone(),
+        )
+        .with_tool_autonomy(tool_autonomy_from_config(config))
+    }
+
+    /// Global autonomy level applied to tools without an override.
+    pub fn autonomy_level(&self) -> AutonomyLevel {
+        *self.autonomy_level.read()
+    }
+
+    /// Replace the global autonomy level at runtime.
+    pub fn set_autonomy_level(&self, level: AutonomyLevel) {
+        *self.autonomy_level.write() = level;
+    }
+
+    /// Set the per-tool autonomy overrides.
+    pub fn with_tool_autonomy(self, overrides: HashMap<String, AutonomyLevel>) -> Self {
+        self.set_tool_autonomy(overrides);
+        self
+    }
+
+    /// Replace the per-tool autonomy overrides at runtime.
+    pub fn set_tool_autonomy(&self, overrides: HashMap<String, AutonomyLevel>) {
+        *self.tool_autonomy.write() = overrides;
+    }
+
+    /// Current per-tool autonomy overrides.
+    pub fn tool_autonomy(&self) -> HashMap<String, AutonomyLevel> {
+        self.tool_autonomy.read().clone()
+    }
+
+    /// Autonomy level for `tool_name`: its override if any, else the global
+    /// level. Under a `ReadOnly` global level a tool that can write stays
+    /// `ReadOnly` whatever its override says.
+    pub fn autonomy_for(&self, tool_name: &str) -> AutonomyLevel {
+        let global = self.autonomy_level();
+        if global == AutonomyLevel::ReadOnly && can_write(tool_name) {
+            return AutonomyLevel::ReadOnly;
+        }
+        self.tool_autonomy
+            .read()
+            .get(tool_name)
+            .copied()
+            .unwrap_or(global)
+    }
+
+    /// Set the HTTP host allowlist and denylist used by [`Self::validate_url`].
+    pub fn with_http_hosts(mut self, allowed: Vec<String>, denied: Vec<String>) -> Self {
+        self.http_allowed_hosts = allowed;
+        self.http_denied_hosts = denied;
+        self
     }
 
     /// Classify the risk level of a shell command.
@@ -516,7 +636,7 @@ impl SecurityPolicy {
         // Classify and apply autonomy rules
         let risk = self.classify_command_risk(trimmed);
 
-        match self.autonomy_level {
+        match self.autonomy_for("shell") {
             AutonomyLevel::ReadOnly => match risk {
                 RiskLevel::Low => ValidationResult::Allowed,
                 RiskLevel::Medium | RiskLevel::High => {
@@ -596,29 +716,56 @@ impl SecurityPolicy {
         ValidationResult::Allowed
     }
 
+    /// Validate whether an outbound HTTP request URL is allowed.
+    ///
+    /// Only `http`/`https` are permitted. The denylist wins over the allowlist;
+    /// a non-empty allowlist restricts requests to the listed hosts. IP literals
+    /// in private, loopback or link-local ranges are denied unless explicitly
+    /// allowlisted. Hostnames must still be checked after DNS resolution with
+    /// [`is_private_ip`] to guard against rebinding.
+    pub fn validate_url(&self, url: &reqwest::Url) -> ValidationResult {
+        if !matches!(url.scheme(), "http" | "https") {
+            return ValidationResult::Denied(format!("scheme '{}' not allowed", url.scheme()));
+        }
+        let Some(host) = url.host_str() else {
+            return ValidationResult::Denied("URL has no host".to_string());
+        };
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+
+        if host_in_list(&self.http_denied_hosts, host) {
+            return ValidationResult::Denied(format!("host '{host}' is denied"));
+        }
+        if !self.http_allowed_hosts.is_empty() && !self.is_http_host_allowlisted(host) {
+            return ValidationResult::Denied(format!("host '{host}' is not in the allowlist"));
+        }
+        if let Ok(ip) = host.parse::<IpAddr>()
+            && is_private_ip(ip)
+            && !self.is_http_host_allowlisted(host)
+        {
+            return ValidationResult::Denied(format!("private address '{host}' not allowed"));
+        }
+
+        ValidationResult::Allowed
+    }
+
+    /// Whether `host` is explicitly listed in the HTTP allowlist.
+    pub fn is_http_host_allowlisted(&self, host: &str) -> bool {
+        host_in_list(&self.http_allowed_hosts, host)
+    }
+
     /// Validate whether a tool execution is allowed under the current policy.
     ///
     /// Logs the action to the audit log and returns `ValidationResult::Denied`
-    /// if the autonomy level is `ReadOnly` and the tool performs write operations.
+    /// if the tool's autonomy level ([`Self::autonomy_for`]) is `ReadOnly` and
+    /// the tool performs write operations.
     pub fn validate_tool_execution(
         &self,
         tool_name: &str,
         _args: &serde_json::Value,
     ) -> ValidationResult {
-        // Write-oriented tools that are restricted in ReadOnly mode
-        const WRITE_TOOLS: &[&str] = &[
-            "file_write",
-            "shell",
-            "patch",
-            "channel_send",
-            "scheduler",
-            "config",
-            "memory",
-        ];
-
         let is_write_tool = WRITE_TOOLS.contains(&tool_name);
 
-        let result = match self.autonomy_level {
+        let result = match self.autonomy_for(tool_name) {
             AutonomyLevel::ReadOnly if is_write_tool => ValidationResult::Denied(format!(
                 "tool '{tool_name}' requires write access, denied in read-only mode"
             )),
@@ -658,6 +805,60 @@ impl SecurityPolicy {
     }
 }
 
+/// Match `host` against host patterns. `example.com` (or `*.example.com`)
+/// matches the host itself and any subdomain.
+fn host_in_list(patterns: &[String], host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_lowercase();
+    patterns.iter().any(|p| {
+        let p = p
+            .trim()
+            .trim_start_matches("*.")
+            .trim_end_matches('.')
+            .to_lowercase();
+        !p.is_empty()
+            && (host == p
+                || host
+                    .strip_suffix(p.as_str())
+                    .is_some_and(|rest| rest.ends_with('.')))
+    })
+}
+
+/// Whether an IP address is in a range that must not be reachable from the
+/// HTTP tool by default: loopback, private, link-local, CGNAT, unique-local,
+/// multicast, documentation and other reserved ranges.
+pub fn is_private_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, ..] = v4.octets();
+            v4.is_private()
+                || v4.is_loopback()
+                || v4.is_link_local()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+                || v4.is_multicast()
+                || v4.is_documentation()
+                || a == 0
+                || (a == 100 && (64..128).contains(&b))
+                || (a == 192 && b == 0 && v4.octets()[2] == 0)
+                || (a == 198 && (b == 18 || b == 19))
+                || a >= 240
+        }
+        IpAddr::V6(v6) => {
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return is_private_ip(IpAddr::V4(v4));
+            }
+            let seg0 = v6.segments()[0];
+            v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.is_multicast()
+                || (seg0 & 0xfe00) == 0xfc00
+                || (seg0 & 0xffc0) == 0xfe80
+                || (seg0 & 0xffc0) == 0xfec0
+                || (seg0 == 0x2001 && v6.segments()[1] == 0x0db8)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1113,7 +1314,7 @@ mod tests {
     #[test]
     fn default_policy_is_supervised() {
         let policy = SecurityPolicy::default_policy();
-        assert_eq!(policy.autonomy_level, AutonomyLevel::Supervised);
+        assert_eq!(policy.autonomy_level(), AutonomyLevel::Supervised);
         assert!(policy.workspace_root.is_none());
         assert!(!policy.blocked_dirs.is_empty());
     }
@@ -1187,6 +1388,78 @@ mod tests {
         );
     }
 
+    // WS-4.3b — per-tool overrides win over the global level
+    #[test]
+    fn tool_execution_uses_per_tool_override() {
+        let policy = supervised_policy().with_tool_autonomy(HashMap::from([
+            ("memory".to_string(), AutonomyLevel::Full),
+            ("file_write".to_string(), AutonomyLevel::ReadOnly),
+        ]));
+        let args = serde_json::json!({});
+        assert_eq!(
+            policy.validate_tool_execution("memory", &args),
+            ValidationResult::Allowed
+        );
+        assert!(matches!(
+            policy.validate_tool_execution("file_write", &args),
+            ValidationResult::Denied(_)
+        ));
+        assert_eq!(
+            policy.validate_tool_execution("shell", &args),
+            ValidationResult::NeedsApproval
+        );
+
+        // Overrides can be replaced at runtime
+        policy.set_tool_autonomy(HashMap::from([("shell".to_string(), AutonomyLevel::Full)]));
+        assert_eq!(policy.autonomy_for("shell"), AutonomyLevel::Full);
+        assert_eq!(policy.autonomy_for("memory"), AutonomyLevel::Supervised);
+        assert_eq!(
+            policy.validate_command("cargo build"),
+            ValidationResult::Allowed
+        );
+    }
+
+    // WS-4.3c — overrides never let a read-only policy write
+    #[test]
+    fn readonly_global_ignores_write_overrides() {
+        let policy = readonly_policy().with_tool_autonomy(HashMap::from([
+            ("memory".to_string(), AutonomyLevel::Full),
+            ("shell".to_string(), AutonomyLevel::Full),
+            ("git".to_string(), AutonomyLevel::Full),
+            ("file_read".to_string(), AutonomyLevel::Full),
+        ]));
+        let args = serde_json::json!({});
+        assert!(matches!(
+            policy.validate_tool_execution("memory", &args),
+            ValidationResult::Denied(_)
+        ));
+        assert_eq!(policy.autonomy_for("shell"), AutonomyLevel::ReadOnly);
+        assert_eq!(policy.autonomy_for("git"), AutonomyLevel::ReadOnly);
+        assert_eq!(policy.autonomy_for("file_read"), AutonomyLevel::Full);
+        assert_eq!(
+            policy.validate_tool_execution("file_read", &args),
+            ValidationResult::Allowed
+        );
+    }
+
+    // WS-4.3c — the global level can be replaced at runtime
+    #[test]
+    fn global_autonomy_level_settable_at_runtime() {
+        let policy = supervised_policy();
+        let args = serde_json::json!({});
+        policy.set_autonomy_level(AutonomyLevel::ReadOnly);
+        assert_eq!(policy.autonomy_level(), AutonomyLevel::ReadOnly);
+        assert!(matches!(
+            policy.validate_tool_execution("file_write", &args),
+            ValidationResult::Denied(_)
+        ));
+        policy.set_autonomy_level(AutonomyLevel::Full);
+        assert_eq!(
+            policy.validate_tool_execution("file_write", &args),
+            ValidationResult::Allowed
+        );
+    }
+
     #[test]
     fn tool_execution_logs_to_audit() {
         let policy = full_policy();
@@ -1447,15 +1720,50 @@ mod tests {
         config.security_audit_log_capacity = 500;
 
         let policy = SecurityPolicy::from_config(&config);
-        assert_eq!(policy.autonomy_level, AutonomyLevel::ReadOnly);
+        assert_eq!(policy.autonomy_level(), AutonomyLevel::ReadOnly);
         assert!(!policy.blocked_dirs.is_empty());
     }
 
+    #[test]
+    fn from_config_reads_tool_autonomy() {
+        let mut config = AppConfig::default();
+        config.security_autonomy_level = "supervised".into();
+        config
+            .security_tool_autonomy
+            .insert("shell".into(), "bogus".into());
+
+        let policy = SecurityPolicy::from_config(&config);
+        assert_eq!(policy.autonomy_for("file_read"), AutonomyLevel::Full);
+        assert_eq!(policy.autonomy_for("memory"), AutonomyLevel::Full);
+        assert_eq!(policy.autonomy_for("shell"), AutonomyLevel::Supervised);
+        assert!(!policy.tool_autonomy().contains_key("shell"));
+    }
+
+    #[test]
+    fn tool_timeout_uses_override_then_default() {
+        let mut config = AppConfig::default();
+        config.security_tool_timeout_secs = 60;
+        config
+            .security_tool_timeouts
+            .insert("workflows".into(), 900);
+        config.security_tool_timeouts.insert("git".into(), 0);
+
+        assert_eq!(
+            tool_timeout(&config, "grep"),
+            Some(std::time::Duration::from_secs(60))
+        );
+        assert_eq!(
+            tool_timeout(&config, "workflows"),
+            Some(std::time::Duration::from_secs(900))
+        );
+        assert_eq!(tool_timeout(&config, "git"), None);
+    }
+
     #[test]
     fn from_config_default_is_full() {
         let config = AppConfig::default();
         let policy = SecurityPolicy::from_config(&config);
-        assert_eq!(policy.autonomy_level, AutonomyLevel::Full);
+        assert_eq!(policy.autonomy_level(), AutonomyLevel::Full);
     }
 
     // --- Audit C1: expanded blocked commands ---
@@ -1502,4 +1810,99 @@ mod tests {
             );
         }
     }
+
+    // --- HTTP URL validation ---
+
+    fn url(s: &str) -> reqwest::Url {
+        reqwest::Url::parse(s).unwrap()
+    }
+
+    #[test]
+    fn validate_url_allows_public_hosts_by_default() {
+        let policy = full_policy();
+        assert_eq!(
+            policy.validate_url(&url("https://api.example.com/v1")),
+            ValidationResult::Allowed
+        );
+        assert_eq!(
+            policy.validate_url(&url("http://93.184.216.34/")),
+            ValidationResult::Allowed
+        );
+    }
+
+    #[test]
+    fn validate_url_rejects_non_http_schemes() {
+        let policy = full_policy();
+        assert!(matches!(
+            policy.validate_url(&url("file:///etc/passwd")),
+            ValidationResult::Denied(_)
+        ));
+        assert!(matches!(
+            policy.validate_url(&url("ftp://example.com/")),
+            ValidationResult::Denied(_)
+        ));
+    }
+
+    #[test]
+    fn validate_url_rejects_private_ip_literals() {
+        let policy = full_policy();
+        for u in [
+            "http://127.0.0.1/",
+            "http://10.1.2.3/",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]/",
+            "http://[fd00::1]/",
+        ] {
+            assert!(
+                matches!(policy.validate_url(&url(u)), ValidationResult::Denied(_)),
+                "{u} should be denied"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_url_allowlist_and_denylist() {
+        let policy = full_policy().with_http_hosts(
+            vec!["example.com".into(), "127.0.0.1".into()],
+            vec!["admin.example.com".into()],
+        );
+        assert_eq!(
+            policy.validate_url(&url("https://api.example.com/")),
+            ValidationResult::Allowed
+        );
+        assert_eq!(
+            policy.validate_url(&url("http://127.0.0.1:8080/")),
+            ValidationResult::Allowed
+        );
+        assert!(matches!(
+            policy.validate_url(&url("https://admin.example.com/")),
+            ValidationResult::Denied(_)
+        ));
+        assert!(matches!(
+            policy.validate_url(&url("https://notexample.com/")),
+            ValidationResult::Denied(_)
+        ));
+    }
+
+    #[test]
+    fn is_private_ip_ranges() {
+        for ip in [
+            "127.0.0.1",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fe80::1",
+            "fc00::1",
+            "::ffff:10.0.0.1",
+        ] {
+            assert!(is_private_ip(ip.parse().unwrap()), "{ip} should be private");
+        }
+        for ip in ["8.8.8.8", "93.184.216.34", "2606:4700::1111"] {
+            assert!(!is_private_ip(ip.parse().unwrap()), "{ip} should be public");
+        }
+    }
 }
@@ -38,6 +38,8 @@ pub fn load_skill_from_content(id: &str, content: &str, source: SkillSource) ->
             enabled: true,
             domain: fm.domain,
             surface: fm.surface,
+            requires: fm.requires,
+            unmet_requirements: None,
         },
         None => Skill {
             id: id.to_string(),
@@ -49,6 +51,8 @@ pub fn load_skill_from_content(id: &str, content: &str, source: SkillSource) ->
             enabled: true,
             domain: None,
             surface: None,
+            requires: None,
+            unmet_requirements: None,
         },
     }
 }
@@ -1,7 +1,8 @@
 pub mod defaults;
 pub mod loader;
 pub mod registry;
+pub mod requirements;
 pub mod types;
 
 pub use registry::SkillRegistry;
-pub use types::{Skill, SkillInfo, SkillSource};
+pub use types::{Skill, SkillInfo, SkillRequirements, SkillSource};
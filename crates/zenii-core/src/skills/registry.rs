@@ -8,6 +8,7 @@ use crate::{Result, ZeniiError};
 
 use super::defaults::BUNDLED_SKILLS;
 use super::loader::{load_skill_from_content, load_skill_from_file};
+use super::requirements::RequirementChecker;
 use super::types::{Skill, SkillInfo, SkillSource};
 
 /// Registry for managing skills (bundled + user).
@@ -33,10 +34,12 @@ impl SkillRegistry {
 
     fn load_all(dir: &Path, max_content_size: usize) -> Result<HashMap<String, Skill>> {
         let mut skills = HashMap::new();
+        let mut checker = RequirementChecker::new();
 
         // 1. Load bundled skills
         for (id, content) in BUNDLED_SKILLS {
-            let skill = load_skill_from_content(id, content, SkillSource::Bundled);
+            let mut skill = load_skill_from_content(id, content, SkillSource::Bundled);
+            checker.apply(&mut skill);
             skills.insert(skill.id.clone(), skill);
         }
 
@@ -47,7 +50,8 @@ impl SkillRegistry {
                 let path = entry.path();
                 if path.extension().is_some_and(|ext| ext == "md") {
                     match load_skill_from_file(&path, max_content_size) {
-                        Ok(skill) => {
+                        Ok(mut skill) => {
+                            checker.apply(&mut skill);
                             info!("Loaded user skill: {}", skill.id);
                             skills.insert(skill.id.clone(), skill);
                         }
@@ -101,7 +105,8 @@ impl SkillRegistry {
             )));
         }
 
-        let skill = load_skill_from_content(&id, &content, SkillSource::User);
+        let mut skill = load_skill_from_content(&id, &content, SkillSource::User);
+        RequirementChecker::new().apply(&mut skill);
 
         // Write to disk
         let path = self.dir.join(format!("{id}.md"));
@@ -119,7 +124,8 @@ impl SkillRegistry {
             return Err(ZeniiError::SkillNotFound(format!("skill '{id}' not found")));
         }
 
-        let skill = load_skill_from_content(id, &content, SkillSource::User);
+        let mut skill = load_skill_from_content(id, &content, SkillSource::User);
+        RequirementChecker::new().apply(&mut skill);
 
         // Write to disk while holding the lock to ensure atomicity
         let path = self.dir.join(format!("{id}.md"));
@@ -164,7 +170,8 @@ impl SkillRegistry {
         if skills.contains_key(id) {
             return Ok(()); // skip duplicates silently
         }
-        let skill = load_skill_from_content(id, &content, SkillSource::User);
+        let mut skill = load_skill_from_content(id, &content, SkillSource::User);
+        RequirementChecker::new().apply(&mut skill);
         skills.insert(id.to_string(), skill);
         info!("Registered external skill: {id}");
         Ok(())
@@ -437,4 +444,27 @@ mod tests {
         assert_eq!(list.len(), 4);
         assert!(list.iter().any(|s| s.id == "new-on-disk"));
     }
+
+    #[tokio::test]
+    async fn registry_disables_skill_with_unmet_requirements() {
+        let dir = TempDir::new().unwrap();
+        let content =
+            "---\nname: Needs Tool\nrequires:\n  all_bins: [zenii-no-such-binary]\n---\nBody";
+        std::fs::write(dir.path().join("needs-tool.md"), content).unwrap();
+
+        let registry = SkillRegistry::new(dir.path(), 100_000).unwrap();
+        let skill = registry.get("needs-tool").await.unwrap();
+        assert!(!skill.enabled);
+        assert_eq!(
+            skill.unmet_requirements.as_deref(),
+            Some("zenii-no-such-binary not found on PATH")
+        );
+        assert!(
+            !registry
+                .active_skills()
+                .await
+                .iter()
+                .any(|(name, _)| name == "Needs Tool")
+        );
+    }
 }
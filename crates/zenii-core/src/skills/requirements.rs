@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use semver::Version;
+use tracing::info;
+
+use super::types::{Skill, SkillRequirements};
+use crate::{Result, ZeniiError};
+
+/// Checks skill requirements against the host. PATH and `--version` lookups
+/// are cached for the checker's lifetime, so one registry load spawns each
+/// binary at most once.
+#[derive(Debug, Default)]
+pub struct RequirementChecker {
+    paths: HashMap<String, Option<PathBuf>>,
+    versions: HashMap<String, Option<Version>>,
+}
+
+impl RequirementChecker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disable `skill` when its requirements are not met, recording why.
+    pub fn apply(&mut self, skill: &mut Skill) {
+        let Some(requires) = &skill.requires else {
+            return;
+        };
+        if let Err(e) = self.check(requires) {
+            let reason = unmet_reason(e);
+            info!("Skill '{}' disabled: {reason}", skill.id);
+            skill.enabled = false;
+            skill.unmet_requirements = Some(reason);
+        }
+    }
+
+    /// `Err` carries a readable reason for the first unmet requirement, as
+    /// [`ZeniiError::Skill`], or [`ZeniiError::Validation`] for a malformed
+    /// `min_version`.
+    pub fn check(&mut self, requires: &SkillRequirements) -> Result<()> {
+        let listed =
+            |bin: &&String| requires.all_bins.contains(*bin) || requires.any_bins.contains(*bin);
+        let versioned_only = requires.min_version.keys().filter(|bin| !listed(bin));
+        let mut required: Vec<&String> = requires.all_bins.iter().chain(versioned_only).collect();
+        required.sort();
+        required.dedup();
+        for bin in required {
+            self.check_bin(bin, requires.min_version.get(bin))?;
+        }
+
+        if requires.any_bins.is_empty() {
+            return Ok(());
+        }
+        let mut failures = Vec::new();
+        for bin in &requires.any_bins {
+            match self.check_bin(bin, requires.min_version.get(bin)) {
+                Ok(()) => return Ok(()),
+                Err(e) => failures.push(unmet_reason(e)),
+            }
+        }
+        Err(ZeniiError::Skill(format!(
+            "none of the alternatives is usable: {}",
+            failures.join("; ")
+        )))
+    }
+
+    fn check_bin(&mut self, bin: &str, min_version: Option<&String>) -> Result<()> {
+        let Some(path) = self.locate(bin) else {
+            return Err(ZeniiError::Skill(format!("{bin} not found on PATH")));
+        };
+        let Some(min_version) = min_version else {
+            return Ok(());
+        };
+        let required = parse_version(min_version).ok_or_else(|| {
+            ZeniiError::Validation(format!("invalid min_version '{min_version}' for {bin}"))
+        })?;
+        match self.version(bin, &path) {
+            Some(found) if found >= required => Ok(()),
+            Some(found) => Err(ZeniiError::Skill(format!(
+                "{bin} {found} found, >= {required} required"
+            ))),
+            None => Err(ZeniiError::Skill(format!(
+                "{bin} version could not be determined, >= {required} required"
+            ))),
+        }
+    }
+
+    fn locate(&mut self, bin: &str) -> Option<PathBuf> {
+        self.paths
+            .entry(bin.to_string())
+            .or_insert_with(|| find_on_path(bin))
+            .clone()
+    }
+
+    fn version(&mut self, bin: &str, path: &Path) -> Option<Version> {
+        self.versions
+            .entry(bin.to_string())
+            .or_insert_with(|| query_version(path))
+            .clone()
+    }
+}
+
+/// The reason text of a failed check, without the error kind prefix, as
+/// shown in `unmet_requirements`.
+fn unmet_reason(e: ZeniiError) -> String {
+    match e {
+        ZeniiError::Skill(reason) | ZeniiError::Validation(reason) => reason,
+        other => other.to_string(),
+    }
+}
+
+/// Resolve `bin` like a shell would. Names containing a path separator are
+/// used as-is.
+fn find_on_path(bin: &str) -> Option<PathBuf> {
+    let candidate = Path::new(bin);
+    if candidate.components().count() > 1 {
+        return candidate.is_file().then(|| candidate.to_path_buf());
+    }
+    let extensions: Vec<String> = if cfg!(windows) {
+        std::iter::once(String::new())
+            .chain(
+                std::env::var("PATHEXT")
+                    .unwrap_or_else(|_| ".EXE;.CMD;.BAT".into())
+                    .split(';')
+                    .map(str::to_string),
+            )
+            .collect()
+    } else {
+        vec![String::new()]
+    };
+    std::env::split_paths(&std::env::var_os("PATH")?).find_map(|dir| {
+        extensions
+            .iter()
+            .map(|ext| dir.join(format!("{bin}{ext}")))
+            .find(|path| path.is_file())
+    })
+}
+
+/// Run `path --version` and parse the first version in its output. Some
+/// tools (older Python, Java) print it on stderr.
+fn query_version(path: &Path) -> Option<Version> {
+    let output = Command::new(path)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .output()
+        .ok()?;
+    let text = format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    version_in_output(&text)
+}
+
+/// First dotted version number in `--version` output, e.g. `3.10.12` from
+/// `Python 3.10.12` or `20.1.0` from `v20.1.0`.
+fn version_in_output(text: &str) -> Option<Version> {
+    text.split(|c: char| c.is_whitespace() || c == ',' || c == '(' || c == ')')
+        .map(|word| word.trim_start_matches(['v', 'V']))
+        .filter(|word| {
+            let numeric: String = word
+                .chars()
+                .take_while(|c| c.is_ascii_digit() || *c == '.')
+                .collect();
+            numeric.contains('.')
+        })
+        .find_map(parse_version)
+}
+
+/// Parse a possibly partial version (`3`, `3.10`, `3.10.12-rc1`) into a full
+/// semver version, padding missing components with zero.
+fn parse_version(text: &str) -> Option<Version> {
+    let numeric: String = text
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    let mut parts = numeric
+        .split('.')
+        .filter(|p| !p.is_empty())
+        .map(|p| p.parse::<u64>());
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().transpose().ok()?.unwrap_or(0);
+    let patch = parts.next().transpose().ok()?.unwrap_or(0);
+    Version::parse(&format!("{major}.{minor}.{patch}")).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requires(all: &[&str], any: &[&str], min: &[(&str, &str)]) -> SkillRequirements {
+        SkillRequirements {
+            all_bins: all.iter().map(|s| s.to_string()).collect(),
+            any_bins: any.iter().map(|s| s.to_string()).collect(),
+            min_version: min
+                .iter()
+                .map(|(b, v)| (b.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parse_version_pads_partial_versions() {
+        assert_eq!(parse_version("3.10"), Some(Version::new(3, 10, 0)));
+        assert_eq!(parse_version("3"), Some(Version::new(3, 0, 0)));
+        assert_eq!(parse_version("6.1.1-3ubuntu5"), Some(Version::new(6, 1, 1)));
+        assert_eq!(parse_version("latest"), None);
+    }
+
+    #[test]
+    fn version_in_output_finds_first_dotted_number() {
+        assert_eq!(
+            version_in_output("Python 3.10.12\n"),
+            Some(Version::new(3, 10, 12))
+        );
+        assert_eq!(version_in_output("v20.1.0"), Some(Version::new(20, 1, 0)));
+        assert_eq!(
+            version_in_output("rustc 1.85.0 (4d91de4e4 2025-02-17)"),
+            Some(Version::new(1, 85, 0))
+        );
+        assert_eq!(version_in_output("no version here"), None);
+    }
+
+    #[test]
+    fn missing_binary_is_reported() {
+        let mut checker = RequirementChecker::new();
+        let err = checker
+            .check(&requires(&["zenii-no-such-binary"], &[], &[]))
+            .unwrap_err();
+        assert_eq!(unmet_reason(err), "zenii-no-such-binary not found on PATH");
+    }
+
+    #[test]
+    fn any_bins_needs_one_alternative() {
+        let mut checker = RequirementChecker::new();
+        let err = checker
+            .check(&requires(&[], &["zenii-missing-a", "zenii-missing-b"], &[]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("zenii-missing-a not found"), "{err}");
+        assert!(err.contains("zenii-missing-b not found"), "{err}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn min_version_compares_found_against_required() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let bin = dir.path().join("fake-python");
+        std::fs::write(&bin, "#!/bin/sh\necho 'Python 3.9.7' >&2\n").unwrap();
+        std::fs::set_permissions(&bin, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let bin = bin.to_string_lossy().to_string();
+
+        let mut checker = RequirementChecker::new();
+        let err = checker
+            .check(&requires(&[], &[], &[(&bin, "3.10")]))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ZeniiError::Skill(reason) if reason == format!("{bin} 3.9.7 found, >= 3.10.0 required")
+        ));
+        assert!(checker.check(&requires(&[], &[], &[(&bin, "3.9")])).is_ok());
+        // Second check reused the cached version
+        assert_eq!(checker.versions.len(), 1);
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -34,6 +36,28 @@ pub struct Skill {
     /// Surface filter: "all" or specific surface name (None = everywhere).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub surface: Option<String>,
+    /// Host binaries the skill depends on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires: Option<SkillRequirements>,
+    /// Why the skill was disabled at load, when `requires` is not met.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unmet_requirements: Option<String>,
+}
+
+/// Binaries a skill needs, declared under `requires:` in its frontmatter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
+pub struct SkillRequirements {
+    /// Every one of these must be on PATH.
+    #[serde(default)]
+    pub all_bins: Vec<String>,
+    /// At least one of these must be on PATH.
+    #[serde(default)]
+    pub any_bins: Vec<String>,
+    /// Binary -> lowest accepted version as reported by `--version`, e.g.
+    /// `python3: "3.10"`. Binaries not listed above become required.
+    #[serde(default)]
+    pub min_version: HashMap<String, String>,
 }
 
 /// Summary struct for list endpoints (excludes full content).
@@ -50,6 +74,8 @@ pub struct SkillInfo {
     pub domain: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub surface: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unmet_requirements: Option<String>,
 }
 
 impl From<&Skill> for SkillInfo {
@@ -63,6 +89,7 @@ impl From<&Skill> for SkillInfo {
             enabled: skill.enabled,
             domain: skill.domain.clone(),
             surface: skill.surface.clone(),
+            unmet_requirements: skill.unmet_requirements.clone(),
         }
     }
 }
@@ -79,6 +106,8 @@ pub struct SkillFrontmatter {
     pub domain: Option<String>,
     #[serde(default)]
     pub surface: Option<String>,
+    #[serde(default)]
+    pub requires: Option<SkillRequirements>,
 }
 
 fn default_category() -> String {
@@ -107,6 +136,8 @@ mod tests {
             enabled: true,
             domain: Some("tools".into()),
             surface: Some("all".into()),
+            requires: None,
+            unmet_requirements: None,
         };
         let info = SkillInfo::from(&skill);
         assert_eq!(info.id, "test");
@@ -128,6 +159,8 @@ mod tests {
             enabled: true,
             domain: None,
             surface: None,
+            requires: None,
+            unmet_requirements: None,
         };
         let json = serde_json::to_string(&skill).unwrap();
         let parsed: Skill = serde_json::from_str(&json).unwrap();
@@ -149,6 +182,8 @@ mod tests {
             enabled: true,
             domain: Some("tools".into()),
             surface: Some("channels".into()),
+            requires: None,
+            unmet_requirements: None,
         };
         assert_eq!(skill.domain.as_deref(), Some("tools"));
         assert_eq!(skill.surface.as_deref(), Some("channels"));
@@ -180,4 +215,23 @@ description: Basic skill
         assert!(fm.domain.is_none());
         assert!(fm.surface.is_none());
     }
+
+    #[test]
+    fn skill_frontmatter_requires() {
+        let yaml = r#"
+name: py-skill
+requires:
+  any_bins: [python3, python]
+  min_version:
+    python3: "3.10"
+"#;
+        let fm: SkillFrontmatter = serde_yaml::from_str(yaml).unwrap();
+        let requires = fm.requires.unwrap();
+        assert!(requires.all_bins.is_empty());
+        assert_eq!(requires.any_bins, vec!["python3", "python"]);
+        assert_eq!(
+            requires.min_version.get("python3").map(String::as_str),
+            Some("3.10")
+        );
+    }
 }
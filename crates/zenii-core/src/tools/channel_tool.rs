@@ -5,14 +5,17 @@ use serde_json::json;
 
 use crate::ai::session::SessionManager;
 use crate::channels::contacts;
-use crate::channels::message::ChannelMessage;
+use crate::channels::message::{ChannelAttachment, ChannelMessage};
 use crate::channels::registry::ChannelRegistry;
 use crate::channels::session_map::ChannelSessionMap;
 use crate::db::DbPool;
 use crate::event_bus::{AppEvent, EventBus};
+use crate::security::policy::{SecurityPolicy, ValidationResult};
 use crate::{Result, ZeniiError};
 
+use super::path::resolve_path;
 use super::traits::{Tool, ToolResult};
+use super::workspace;
 
 /// Map channel name to the appropriate metadata key for recipient targeting.
 fn recipient_metadata_key(channel: &str) -> &'static str {
@@ -31,6 +34,7 @@ pub struct ChannelSendTool {
     session_map: Arc<ChannelSessionMap>,
     session_manager: Arc<SessionManager>,
     event_bus: Arc<dyn EventBus>,
+    policy: Option<Arc<SecurityPolicy>>,
 }
 
 impl ChannelSendTool {
@@ -47,8 +51,53 @@ impl ChannelSendTool {
             session_map,
             session_manager,
             event_bus,
+            policy: None,
         }
     }
+
+    /// Allow `send` to attach files, checked against `policy` like the file tools.
+    pub fn with_policy(mut self, policy: Arc<SecurityPolicy>) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Resolve the `attachments` paths of a send, or the reason they are refused.
+    fn attachments(&self, args: &serde_json::Value) -> Result<Vec<ChannelAttachment>> {
+        let Some(paths) = args["attachments"].as_array() else {
+            return Ok(Vec::new());
+        };
+        let Some(policy) = &self.policy else {
+            return Err(ZeniiError::Channel(
+                "attachments are not enabled for channel_send".into(),
+            ));
+        };
+        let mut attachments = Vec::with_capacity(paths.len());
+        for raw in paths {
+            let raw = raw
+                .as_str()
+                .ok_or_else(|| ZeniiError::Validation("attachments must be file paths".into()))?;
+            let path = std::path::PathBuf::from(workspace::confine(&resolve_path(raw))?);
+            match policy.validate_path(&path) {
+                ValidationResult::Allowed => {}
+                ValidationResult::NeedsApproval => {
+                    return Err(ZeniiError::PolicyDenied(format!(
+                        "path needs approval: {}",
+                        path.display()
+                    )));
+                }
+                ValidationResult::Denied(reason) => return Err(ZeniiError::PolicyDenied(reason)),
+            }
+            if !path.is_file() {
+                return Err(ZeniiError::Validation(format!(
+                    "not a file: {}",
+                    path.display()
+                )));
+            }
+            let mime = ChannelAttachment::mime_for_path(&path);
+            attachments.push(ChannelAttachment::from_path(path, mime));
+        }
+        Ok(attachments)
+    }
 }
 
 #[async_trait]
@@ -62,7 +111,7 @@ impl Tool for ChannelSendTool {
     }
 
     fn description(&self) -> &str {
-        "Send messages to connected channels, list channels, check status, or discover known contacts. When sending without a recipient, auto-resolves if only one contact exists. Use 'contacts' action to list known recipients. Actions: send, list, status, contacts."
+        "Send messages (optionally with file attachments) to connected channels, list channels, check status, or discover known contacts. When sending without a recipient, auto-resolves if only one contact exists. Use 'contacts' action to list known recipients. Actions: send, list, status, contacts."
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -85,6 +134,11 @@ impl Tool for ChannelSendTool {
                 "recipient": {
                     "type": "string",
                     "description": "Recipient ID (e.g. chat_id for telegram, channel_id for slack/discord). If omitted for send, auto-resolves when only one contact exists."
+                },
+                "attachments": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Paths of files to send with the message (send only)"
                 }
             },
             "required": ["action"]
@@ -105,6 +159,12 @@ impl Tool for ChannelSendTool {
                     .ok_or_else(|| ZeniiError::Validation("missing 'message' for send".into()))?;
 
                 let mut msg = ChannelMessage::new(channel, message).with_sender("agent");
+                match self.attachments(&args) {
+                    Ok(attachments) => msg.attachments = attachments,
+                    Err(e) => {
+                        return Ok(ToolResult::err(format!("Denied attachment: {e}")));
+                    }
+                }
 
                 // If recipient provided, inject into metadata with channel-specific key.
                 // If not provided, try to auto-resolve from known contacts.
@@ -476,6 +536,49 @@ mod tests {
         assert!(result.output.contains("Message sent"));
     }
 
+    // Attachments need a policy and must be existing files it allows
+    #[tokio::test]
+    async fn send_with_attachments() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let file = dir.path().join("chart.png");
+        std::fs::write(&file, b"png").unwrap();
+        let args = json!({
+            "action": "send",
+            "channel": "telegram",
+            "message": "here you go",
+            "recipient": "12345",
+            "attachments": [file.to_string_lossy()]
+        });
+
+        let tool = setup_with_channel();
+        let result = tool.execute(args.clone()).await.unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("not enabled"));
+
+        let policy = Arc::new(SecurityPolicy::new(
+            crate::security::policy::AutonomyLevel::Full,
+            None,
+            vec![],
+            60,
+            60,
+            30,
+        ));
+        let tool = setup_with_channel().with_policy(policy);
+        let result = tool.execute(args).await.unwrap();
+        assert!(result.success, "{}", result.output);
+
+        let missing = json!({
+            "action": "send",
+            "channel": "telegram",
+            "message": "x",
+            "recipient": "12345",
+            "attachments": [dir.path().join("nope.png").to_string_lossy()]
+        });
+        let result = tool.execute(missing).await.unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("not a file"));
+    }
+
     // Send without recipient errors when no contacts
     #[tokio::test]
     async fn send_no_contacts_error() {
@@ -4,6 +4,7 @@ use crate::{Result, ZeniiError};
 
 use super::path::resolve_path;
 use super::traits::{Tool, ToolResult};
+use super::workspace;
 
 pub struct ContentSearchTool {
     max_results: usize,
@@ -34,6 +35,10 @@ impl Tool for ContentSearchTool {
         "content_search"
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     fn risk_level(&self) -> crate::security::RiskLevel {
         crate::security::RiskLevel::High
     }
@@ -83,7 +88,10 @@ impl Tool for ContentSearchTool {
             .get("directory")
             .and_then(|v| v.as_str())
             .unwrap_or(".");
-        let directory = resolve_path(directory);
+        let directory = match workspace::confine(&resolve_path(directory)) {
+            Ok(path) => path,
+            Err(e) => return Ok(ToolResult::err(e.to_string())),
+        };
 
         let file_pattern = args
             .get("file_pattern")
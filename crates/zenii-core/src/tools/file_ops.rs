@@ -8,6 +8,7 @@ use crate::{Result, ZeniiError};
 
 use super::path::resolve_path;
 use super::traits::{Tool, ToolResult};
+use super::workspace;
 
 // ---------------------------------------------------------------------------
 // FileReadTool
@@ -29,6 +30,10 @@ impl Tool for FileReadTool {
         "file_read"
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     fn risk_level(&self) -> crate::security::RiskLevel {
         crate::security::RiskLevel::High
     }
@@ -53,7 +58,10 @@ impl Tool for FileReadTool {
             .get("path")
             .and_then(|v| v.as_str())
             .ok_or_else(|| ZeniiError::Tool("missing 'path' argument".into()))?;
-        let path = resolve_path(raw_path);
+        let path = match workspace::confine(&resolve_path(raw_path)) {
+            Ok(path) => path,
+            Err(e) => return Ok(ToolResult::err(e.to_string())),
+        };
 
         match self.policy.validate_path(Path::new(&path)) {
             ValidationResult::Allowed => {}
@@ -130,7 +138,10 @@ impl Tool for FileWriteTool {
             .get("path")
             .and_then(|v| v.as_str())
             .ok_or_else(|| ZeniiError::Tool("missing 'path' argument".into()))?;
-        let path = resolve_path(raw_path);
+        let path = match workspace::confine(&resolve_path(raw_path)) {
+            Ok(path) => path,
+            Err(e) => return Ok(ToolResult::err(e.to_string())),
+        };
 
         let content = args
             .get("content")
@@ -184,6 +195,10 @@ impl Tool for FileListTool {
         "file_list"
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     fn risk_level(&self) -> crate::security::RiskLevel {
         crate::security::RiskLevel::High
     }
@@ -208,7 +223,10 @@ impl Tool for FileListTool {
             .get("path")
             .and_then(|v| v.as_str())
             .ok_or_else(|| ZeniiError::Tool("missing 'path' argument".into()))?;
-        let path = resolve_path(raw_path);
+        let path = match workspace::confine(&resolve_path(raw_path)) {
+            Ok(path) => path,
+            Err(e) => return Ok(ToolResult::err(e.to_string())),
+        };
 
         match self.policy.validate_path(Path::new(&path)) {
             ValidationResult::Allowed => {}
@@ -4,6 +4,7 @@ use crate::{Result, ZeniiError};
 
 use super::path::resolve_path;
 use super::traits::{Tool, ToolResult};
+use super::workspace;
 
 pub struct FileSearchTool {
     max_results: usize,
@@ -32,6 +33,10 @@ impl Tool for FileSearchTool {
         "file_search"
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     fn risk_level(&self) -> crate::security::RiskLevel {
         crate::security::RiskLevel::High
     }
@@ -81,7 +86,10 @@ impl Tool for FileSearchTool {
             .get("directory")
             .and_then(|v| v.as_str())
             .unwrap_or(".");
-        let directory = resolve_path(directory);
+        let directory = match workspace::confine(&resolve_path(directory)) {
+            Ok(path) => path,
+            Err(e) => return Ok(ToolResult::err(e.to_string())),
+        };
 
         let case_insensitive = args
             .get("case_insensitive")
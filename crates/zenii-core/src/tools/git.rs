@@ -0,0 +1,673 @@
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use git2::{BranchType, DiffFormat, DiffOptions, IndexAddOption, Repository, StatusOptions};
+
+use crate::security::policy::{AutonomyLevel, SecurityPolicy};
+use crate::{Result, ZeniiError};
+
+use super::traits::{Tool, ToolResult};
+
+/// Diff output beyond this many bytes is truncated.
+const MAX_DIFF_BYTES: usize = 256 * 1024;
+/// Upper bound for `log` `max_count`.
+const MAX_LOG_ENTRIES: usize = 100;
+
+/// Repository operations (`status`, `diff`, `add`, `commit`, `log`, `branch`)
+/// confined to a workspace root, implemented with libgit2 so no `git` binary
+/// is required.
+pub struct GitTool {
+    policy: Arc<SecurityPolicy>,
+    workspace_root: PathBuf,
+}
+
+impl GitTool {
+    pub fn new(policy: Arc<SecurityPolicy>, workspace_root: PathBuf) -> Self {
+        Self {
+            policy,
+            workspace_root,
+        }
+    }
+}
+
+/// Resolve `rel` against `root`, rejecting anything that escapes it.
+fn resolve_in_root(root: &Path, rel: &str) -> Result<PathBuf> {
+    if rel.contains('\0') {
+        return Err(ZeniiError::Validation("null byte in path".into()));
+    }
+    let rel_path = Path::new(rel);
+    if rel_path
+        .components()
+        .any(|c| matches!(c, Component::ParentDir))
+    {
+        return Err(ZeniiError::PolicyDenied(
+            "path traversal not allowed".into(),
+        ));
+    }
+
+    let root = std::fs::canonicalize(root)
+        .map_err(|e| ZeniiError::PolicyDenied(format!("workspace root unavailable: {e}")))?;
+    let joined = if rel_path.is_absolute() {
+        rel_path.to_path_buf()
+    } else {
+        root.join(rel_path)
+    };
+    let effective = std::fs::canonicalize(&joined).unwrap_or(joined);
+    if !effective.starts_with(&root) {
+        return Err(ZeniiError::PolicyDenied(format!(
+            "path is outside workspace root: {}",
+            root.display()
+        )));
+    }
+    Ok(effective)
+}
+
+/// Pathspecs from the `paths` argument, relative to the repository workdir.
+fn pathspecs(root: &Path, workdir: &Path, args: &serde_json::Value) -> Result<Vec<String>> {
+    let Some(paths) = args.get("paths").and_then(|v| v.as_array()) else {
+        return Ok(Vec::new());
+    };
+    paths
+        .iter()
+        .filter_map(|p| p.as_str())
+        .map(|p| {
+            let abs = resolve_in_root(root, p)?;
+            let rel = abs.strip_prefix(workdir).map_err(|_| {
+                ZeniiError::PolicyDenied(format!("path is outside repository: {p}"))
+            })?;
+            Ok(rel.to_string_lossy().into_owned())
+        })
+        .collect()
+}
+
+fn is_write_action(action: &str, args: &serde_json::Value) -> bool {
+    match action {
+        "add" | "commit" => true,
+        "branch" => args.get("name").and_then(|v| v.as_str()).is_some(),
+        _ => false,
+    }
+}
+
+fn git_err(e: git2::Error) -> ZeniiError {
+    ZeniiError::Tool(format!("git error: {}", e.message()))
+}
+
+/// Current branch name, or `None` when HEAD is detached.
+fn current_branch(repo: &Repository) -> Option<String> {
+    match repo.head() {
+        Ok(head) if head.is_branch() => head.shorthand().map(String::from),
+        Ok(_) => None,
+        // Unborn branch: HEAD points at a ref that has no commits yet
+        Err(_) => repo
+            .find_reference("HEAD")
+            .ok()
+            .and_then(|r| r.symbolic_target().map(String::from))
+            .map(|t| t.trim_start_matches("refs/heads/").to_string()),
+    }
+}
+
+/// Status entries as `(code, path)` pairs, using `git status --short` codes.
+fn status_entries(repo: &Repository) -> std::result::Result<Vec<(String, String)>, git2::Error> {
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .include_ignored(false);
+    let statuses = repo.statuses(Some(&mut opts))?;
+
+    Ok(statuses
+        .iter()
+        .filter_map(|entry| {
+            let s = entry.status();
+            let index = if s.is_index_new() {
+                'A'
+            } else if s.is_index_modified() {
+                'M'
+            } else if s.is_index_deleted() {
+                'D'
+            } else if s.is_index_renamed() {
+                'R'
+            } else if s.is_index_typechange() {
+                'T'
+            } else {
+                ' '
+            };
+            let worktree = if s.is_wt_new() {
+                '?'
+            } else if s.is_wt_modified() {
+                'M'
+            } else if s.is_wt_deleted() {
+                'D'
+            } else if s.is_wt_renamed() {
+                'R'
+            } else if s.is_wt_typechange() {
+                'T'
+            } else {
+                ' '
+            };
+            let code = match (index, worktree, s.is_conflicted()) {
+                (_, _, true) => "UU".to_string(),
+                (' ', '?', _) => "??".to_string(),
+                (' ', ' ', _) => return None,
+                (i, w, _) => format!("{i}{w}"),
+            };
+            entry.path().map(|p| (code, p.to_string()))
+        })
+        .collect())
+}
+
+#[async_trait]
+impl Tool for GitTool {
+    fn name(&self) -> &str {
+        "git"
+    }
+
+    fn risk_level(&self) -> crate::security::RiskLevel {
+        crate::security::RiskLevel::High
+    }
+
+    fn description(&self) -> &str {
+        "Git repository operations in the workspace: status, diff, add, commit, log, branch"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["status", "diff", "add", "commit", "log", "branch"],
+                    "description": "Git operation to perform"
+                },
+                "repo": { "type": "string", "description": "Repository directory relative to the workspace root (default: the root)" },
+                "paths": { "type": "array", "items": { "type": "string" }, "description": "Paths to stage (add) or limit the diff to" },
+                "staged": { "type": "boolean", "description": "For diff: compare the index to HEAD instead of the working tree to the index", "default": false },
+                "message": { "type": "string", "description": "Commit message (required for commit)" },
+                "max_count": { "type": "integer", "description": "Number of commits to show for log (default 10, max 100)" },
+                "name": { "type": "string", "description": "For branch: create a branch with this name at HEAD; omit to list branches" }
+            },
+            "required": ["action"]
+        })
+    }
+
+    fn needs_approval(&self, args: &serde_json::Value) -> Option<String> {
+        let action = args.get("action").and_then(|v| v.as_str())?;
+        if self.policy.autonomy_for("git") == AutonomyLevel::Supervised
+            && is_write_action(action, args)
+        {
+            return Some(format!("Git {action} needs approval"));
+        }
+        None
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> Result<ToolResult> {
+        let action = args
+            .get("action")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ZeniiError::Tool("missing 'action' argument".into()))?
+            .to_string();
+
+        if !matches!(
+            action.as_str(),
+            "status" | "diff" | "add" | "commit" | "log" | "branch"
+        ) {
+            return Ok(ToolResult::err(format!("Unknown action: {action}")));
+        }
+
+        if self.policy.autonomy_for("git") == AutonomyLevel::ReadOnly
+            && is_write_action(&action, &args)
+        {
+            return Ok(ToolResult::err(format!(
+                "Denied: git {action} is not allowed in read-only mode"
+            )));
+        }
+
+        let root = self.workspace_root.clone();
+        let repo_dir = match resolve_in_root(
+            &root,
+            args.get("repo").and_then(|v| v.as_str()).unwrap_or(""),
+        ) {
+            Ok(dir) => dir,
+            Err(e) => return Ok(ToolResult::err(e.to_string())),
+        };
+
+        tokio::task::spawn_blocking(move || {
+            let repo = Repository::open(&repo_dir).map_err(git_err)?;
+            let Some(workdir) = repo.workdir().map(Path::to_path_buf) else {
+                return Ok(ToolResult::err("bare repositories are not supported"));
+            };
+            let workdir = std::fs::canonicalize(&workdir).unwrap_or(workdir);
+
+            let (output, data) = match action.as_str() {
+                "status" => {
+                    let entries = status_entries(&repo).map_err(git_err)?;
+                    let text = if entries.is_empty() {
+                        "nothing to commit, working tree clean".to_string()
+                    } else {
+                        entries
+                            .iter()
+                            .map(|(code, path)| format!("{code} {path}"))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    };
+                    let files: Vec<_> = entries
+                        .iter()
+                        .map(|(code, path)| serde_json::json!({"status": code, "path": path}))
+                        .collect();
+                    (text, serde_json::json!({ "files": files }))
+                }
+                "diff" => {
+                    let specs = match pathspecs(&root, &workdir, &args) {
+                        Ok(s) => s,
+                        Err(e) => return Ok(ToolResult::err(e.to_string())),
+                    };
+                    let staged = args
+                        .get("staged")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false);
+                    let mut opts = DiffOptions::new();
+                    for spec in &specs {
+                        opts.pathspec(spec.as_str());
+                    }
+                    let diff = if staged {
+                        let head_tree = repo.head().ok().and_then(|h| h.peel_to_tree().ok());
+                        repo.diff_tree_to_index(head_tree.as_ref(), None, Some(&mut opts))
+                    } else {
+                        repo.diff_index_to_workdir(None, Some(&mut opts))
+                    }
+                    .map_err(git_err)?;
+                    let stats = diff.stats().map_err(git_err)?;
+
+                    let mut patch = String::new();
+                    let mut truncated = false;
+                    diff.print(DiffFormat::Patch, |_, _, line| {
+                        if patch.len() >= MAX_DIFF_BYTES {
+                            truncated = true;
+                            return false;
+                        }
+                        if matches!(line.origin(), '+' | '-' | ' ') {
+                            patch.push(line.origin());
+                        }
+                        patch.push_str(&String::from_utf8_lossy(line.content()));
+                        true
+                    })
+                    // Returning false from the callback aborts printing with an error
+                    .or_else(|e| if truncated { Ok(()) } else { Err(e) })
+                    .map_err(git_err)?;
+                    if truncated {
+                        patch.push_str("\n... [diff truncated]");
+                    }
+                    if patch.is_empty() {
+                        patch = "No changes".to_string();
+                    }
+                    (
+                        patch,
+                        serde_json::json!({
+                            "staged": staged,
+                            "files_changed": stats.files_changed(),
+                            "insertions": stats.insertions(),
+                            "deletions": stats.deletions(),
+                            "truncated": truncated,
+                        }),
+                    )
+                }
+                "add" => {
+                    let specs = match pathspecs(&root, &workdir, &args) {
+                        Ok(s) if !s.is_empty() => s,
+                        Ok(_) => return Ok(ToolResult::err("'paths' is required for add")),
+                        Err(e) => return Ok(ToolResult::err(e.to_string())),
+                    };
+                    let mut index = repo.index().map_err(git_err)?;
+                    index
+                        .add_all(
+                            specs.iter().map(String::as_str),
+                            IndexAddOption::DEFAULT,
+                            None,
+                        )
+                        .map_err(git_err)?;
+                    // add_all only stages existing files; update_all stages removals
+                    index
+                        .update_all(specs.iter().map(String::as_str), None)
+                        .map_err(git_err)?;
+                    index.write().map_err(git_err)?;
+                    (
+                        format!("Staged {}", specs.join(", ")),
+                        serde_json::json!({ "paths": specs }),
+                    )
+                }
+                "commit" => {
+                    let Some(message) = args
+                        .get("message")
+                        .and_then(|v| v.as_str())
+                        .filter(|m| !m.trim().is_empty())
+                    else {
+                        return Ok(ToolResult::err("'message' is required for commit"));
+                    };
+                    let mut index = repo.index().map_err(git_err)?;
+                    let tree_id = index.write_tree().map_err(git_err)?;
+                    let tree = repo.find_tree(tree_id).map_err(git_err)?;
+                    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+                    if parent.as_ref().is_some_and(|p| p.tree_id() == tree_id) {
+                        return Ok(ToolResult::err(
+                            "nothing to commit (stage changes with add)",
+                        ));
+                    }
+                    let signature = repo
+                        .signature()
+                        .or_else(|_| git2::Signature::now("Zenii", "zenii@localhost"))
+                        .map_err(git_err)?;
+                    let parents: Vec<_> = parent.iter().collect();
+                    let oid = repo
+                        .commit(
+                            Some("HEAD"),
+                            &signature,
+                            &signature,
+                            message,
+                            &tree,
+                            &parents,
+                        )
+                        .map_err(git_err)?;
+                    let short = oid.to_string()[..7].to_string();
+                    (
+                        format!(
+                            "Committed {short}: {}",
+                            message.lines().next().unwrap_or("")
+                        ),
+                        serde_json::json!({ "commit": oid.to_string() }),
+                    )
+                }
+                "log" => {
+                    let max_count = args
+                        .get("max_count")
+                        .and_then(|v| v.as_u64())
+                        .map(|n| (n as usize).min(MAX_LOG_ENTRIES))
+                        .unwrap_or(10);
+                    let mut commits = Vec::new();
+                    if repo.head().is_ok() {
+                        let mut walk = repo.revwalk().map_err(git_err)?;
+                        walk.push_head().map_err(git_err)?;
+                        for oid in walk.take(max_count) {
+                            let commit =
+                                repo.find_commit(oid.map_err(git_err)?).map_err(git_err)?;
+                            let time = chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
+                                .map(|t| t.to_rfc3339())
+                                .unwrap_or_default();
+                            commits.push(serde_json::json!({
+                                "id": commit.id().to_string(),
+                                "summary": commit.summary().unwrap_or(""),
+                                "author": commit.author().name().unwrap_or(""),
+                                "time": time,
+                            }));
+                        }
+                    }
+                    let text = if commits.is_empty() {
+                        "No commits yet".to_string()
+                    } else {
+                        commits
+                            .iter()
+                            .map(|c| {
+                                format!(
+                                    "{} {} ({}, {})",
+                                    &c["id"].as_str().unwrap_or("")[..7],
+                                    c["summary"].as_str().unwrap_or(""),
+                                    c["author"].as_str().unwrap_or(""),
+                                    c["time"].as_str().unwrap_or("")
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    };
+                    (text, serde_json::json!({ "commits": commits }))
+                }
+                "branch" => match args.get("name").and_then(|v| v.as_str()) {
+                    Some(name) => {
+                        let head = repo.head().and_then(|h| h.peel_to_commit()).map_err(|_| {
+                            ZeniiError::Tool("cannot branch before the first commit".into())
+                        })?;
+                        repo.branch(name, &head, false).map_err(git_err)?;
+                        (
+                            format!("Created branch {name}"),
+                            serde_json::json!({ "created": name }),
+                        )
+                    }
+                    None => {
+                        let current = current_branch(&repo);
+                        let mut names = Vec::new();
+                        for branch in repo.branches(Some(BranchType::Local)).map_err(git_err)? {
+                            let (branch, _) = branch.map_err(git_err)?;
+                            if let Some(name) = branch.name().map_err(git_err)? {
+                                names.push(name.to_string());
+                            }
+                        }
+                        let text = names
+                            .iter()
+                            .map(|n| {
+                                if current.as_deref() == Some(n.as_str()) {
+                                    format!("* {n}")
+                                } else {
+                                    format!("  {n}")
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        (text, serde_json::json!({ "branches": names }))
+                    }
+                },
+                _ => unreachable!("action validated above"),
+            };
+
+            // Report branch and dirty state after the action has run
+            let branch = current_branch(&repo);
+            let dirty = !status_entries(&repo).map_err(git_err)?.is_empty();
+            let header = format!(
+                "On branch {}{}",
+                branch.as_deref().unwrap_or("(detached HEAD)"),
+                if dirty { " (dirty)" } else { "" }
+            );
+
+            let mut metadata = serde_json::json!({
+                "action": action,
+                "branch": branch,
+                "dirty": dirty,
+            });
+            if let (Some(meta), Some(extra)) = (metadata.as_object_mut(), data.as_object()) {
+                meta.extend(extra.clone());
+            }
+
+            Ok(ToolResult {
+                output: format!("{header}\n{output}"),
+                success: true,
+                metadata: Some(metadata),
+            })
+        })
+        .await
+        .map_err(|e| ZeniiError::Tool(format!("spawn_blocking error: {e}")))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn policy(level: AutonomyLevel) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy::new(level, None, vec![], 60, 60, 100))
+    }
+
+    fn init_repo() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+        dir
+    }
+
+    fn tool(dir: &TempDir, level: AutonomyLevel) -> GitTool {
+        GitTool::new(policy(level), dir.path().to_path_buf())
+    }
+
+    #[tokio::test]
+    async fn status_reports_untracked_and_dirty() {
+        let dir = init_repo();
+        std::fs::write(dir.path().join("a.txt"), "hello\n").unwrap();
+        let result = tool(&dir, AutonomyLevel::Full)
+            .execute(serde_json::json!({"action": "status"}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("?? a.txt"));
+        let meta = result.metadata.unwrap();
+        assert_eq!(meta["dirty"], true);
+        assert!(meta["branch"].is_string());
+    }
+
+    #[tokio::test]
+    async fn add_commit_and_log() {
+        let dir = init_repo();
+        std::fs::write(dir.path().join("a.txt"), "hello\n").unwrap();
+        let git = tool(&dir, AutonomyLevel::Full);
+
+        let add = git
+            .execute(serde_json::json!({"action": "add", "paths": ["a.txt"]}))
+            .await
+            .unwrap();
+        assert!(add.success, "{}", add.output);
+
+        let commit = git
+            .execute(serde_json::json!({"action": "commit", "message": "Initial commit"}))
+            .await
+            .unwrap();
+        assert!(commit.success, "{}", commit.output);
+        assert_eq!(commit.metadata.as_ref().unwrap()["dirty"], false);
+
+        let log = git
+            .execute(serde_json::json!({"action": "log"}))
+            .await
+            .unwrap();
+        assert!(log.output.contains("Initial commit"));
+        assert_eq!(
+            log.metadata.unwrap()["commits"].as_array().unwrap().len(),
+            1
+        );
+
+        let again = git
+            .execute(serde_json::json!({"action": "commit", "message": "Empty"}))
+            .await
+            .unwrap();
+        assert!(!again.success);
+    }
+
+    #[tokio::test]
+    async fn diff_shows_worktree_changes() {
+        let dir = init_repo();
+        let git = tool(&dir, AutonomyLevel::Full);
+        std::fs::write(dir.path().join("a.txt"), "hello\n").unwrap();
+        git.execute(serde_json::json!({"action": "add", "paths": ["a.txt"]}))
+            .await
+            .unwrap();
+        git.execute(serde_json::json!({"action": "commit", "message": "init"}))
+            .await
+            .unwrap();
+
+        std::fs::write(dir.path().join("a.txt"), "hello\nworld\n").unwrap();
+        let diff = git
+            .execute(serde_json::json!({"action": "diff"}))
+            .await
+            .unwrap();
+        assert!(diff.success);
+        assert!(diff.output.contains("+world"));
+        assert_eq!(diff.metadata.unwrap()["insertions"], 1);
+    }
+
+    #[tokio::test]
+    async fn branch_create_and_list() {
+        let dir = init_repo();
+        let git = tool(&dir, AutonomyLevel::Full);
+        std::fs::write(dir.path().join("a.txt"), "hello\n").unwrap();
+        git.execute(serde_json::json!({"action": "add", "paths": ["a.txt"]}))
+            .await
+            .unwrap();
+        git.execute(serde_json::json!({"action": "commit", "message": "init"}))
+            .await
+            .unwrap();
+
+        let created = git
+            .execute(serde_json::json!({"action": "branch", "name": "feature"}))
+            .await
+            .unwrap();
+        assert!(created.success, "{}", created.output);
+
+        let listed = git
+            .execute(serde_json::json!({"action": "branch"}))
+            .await
+            .unwrap();
+        let branches = listed.metadata.unwrap()["branches"].clone();
+        assert!(branches.as_array().unwrap().iter().any(|b| b == "feature"));
+    }
+
+    #[tokio::test]
+    async fn rejects_paths_outside_workspace() {
+        let dir = init_repo();
+        let git = tool(&dir, AutonomyLevel::Full);
+        let result = git
+            .execute(serde_json::json!({"action": "add", "paths": ["../escape.txt"]}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("traversal"));
+
+        let result = git
+            .execute(serde_json::json!({"action": "status", "repo": "/"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("outside workspace"));
+    }
+
+    #[tokio::test]
+    async fn read_only_denies_writes() {
+        let dir = init_repo();
+        let git = tool(&dir, AutonomyLevel::ReadOnly);
+        let result = git
+            .execute(serde_json::json!({"action": "commit", "message": "x"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("read-only"));
+
+        let status = git
+            .execute(serde_json::json!({"action": "status"}))
+            .await
+            .unwrap();
+        assert!(status.success);
+    }
+
+    #[test]
+    fn supervised_commit_needs_approval() {
+        let dir = init_repo();
+        let git = tool(&dir, AutonomyLevel::Supervised);
+        assert!(
+            git.needs_approval(&serde_json::json!({"action": "commit", "message": "x"}))
+                .is_some()
+        );
+        assert!(
+            git.needs_approval(&serde_json::json!({"action": "status"}))
+                .is_none()
+        );
+        assert!(
+            git.needs_approval(&serde_json::json!({"action": "diff"}))
+                .is_none()
+        );
+        assert!(
+            git.needs_approval(&serde_json::json!({"action": "branch"}))
+                .is_none()
+        );
+
+        let full = tool(&dir, AutonomyLevel::Full);
+        assert!(
+            full.needs_approval(&serde_json::json!({"action": "commit", "message": "x"}))
+                .is_none()
+        );
+    }
+}
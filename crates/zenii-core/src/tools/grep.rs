@@ -0,0 +1,372 @@
+use std::io::Read;
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::security::policy::{SecurityPolicy, ValidationResult};
+use crate::{Result, ZeniiError};
+
+use super::path::resolve_path;
+use super::traits::{Tool, ToolResult};
+use super::workspace;
+
+const DEFAULT_MAX_RESULTS: usize = 100;
+/// Upper bound on `max_results` so one call cannot flood the context window.
+const MAX_RESULTS_LIMIT: usize = 1000;
+/// Bytes sniffed for a NUL byte to detect binary files.
+const BINARY_SNIFF_BYTES: usize = 8 * 1024;
+
+#[derive(Debug, Serialize)]
+struct GrepMatch {
+    file: String,
+    line_number: usize,
+    line: String,
+}
+
+/// Line-oriented search returning structured `{file, line_number, line}` matches.
+/// Unlike `content_search`, paths are checked against the security policy.
+pub struct GrepTool {
+    policy: Arc<SecurityPolicy>,
+    max_file_size_bytes: usize,
+    max_depth: usize,
+}
+
+impl GrepTool {
+    pub fn new(policy: Arc<SecurityPolicy>, max_file_size_bytes: usize, max_depth: usize) -> Self {
+        Self {
+            policy,
+            max_file_size_bytes,
+            max_depth,
+        }
+    }
+}
+
+fn is_binary(path: &Path) -> bool {
+    let Ok(file) = std::fs::File::open(path) else {
+        return true;
+    };
+    let mut head = Vec::with_capacity(BINARY_SNIFF_BYTES);
+    if file
+        .take(BINARY_SNIFF_BYTES as u64)
+        .read_to_end(&mut head)
+        .is_err()
+    {
+        return true;
+    }
+    head.contains(&0)
+}
+
+#[async_trait]
+impl Tool for GrepTool {
+    fn name(&self) -> &str {
+        "grep"
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
+    fn risk_level(&self) -> crate::security::RiskLevel {
+        crate::security::RiskLevel::High
+    }
+
+    fn description(&self) -> &str {
+        "Find lines matching a pattern in a file or directory tree, respecting .gitignore. Returns JSON entries with file, line_number and line"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "pattern": {
+                    "type": "string",
+                    "description": "Text to search for (a regex when is_regex is true)"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "File or directory to search (supports: ~/path, Desktop, $HOME/projects, absolute paths)",
+                    "default": "."
+                },
+                "is_regex": {
+                    "type": "boolean",
+                    "description": "Treat pattern as a regular expression instead of literal text",
+                    "default": false
+                },
+                "case_insensitive": {
+                    "type": "boolean",
+                    "description": "Case-insensitive matching",
+                    "default": false
+                },
+                "max_results": {
+                    "type": "integer",
+                    "description": "Maximum number of matching lines to return",
+                    "default": DEFAULT_MAX_RESULTS
+                }
+            },
+            "required": ["pattern"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> Result<ToolResult> {
+        let pattern = args
+            .get("pattern")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ZeniiError::Tool("missing 'pattern' argument".into()))?;
+
+        let raw_path = args.get("path").and_then(|v| v.as_str()).unwrap_or(".");
+        let root = match workspace::confine(&resolve_path(raw_path)) {
+            Ok(path) => path,
+            Err(e) => return Ok(ToolResult::err(e.to_string())),
+        };
+
+        match self.policy.validate_path(Path::new(&root)) {
+            ValidationResult::Allowed => {}
+            ValidationResult::NeedsApproval => {
+                return Ok(ToolResult::err(format!("Path needs approval: {root}")));
+            }
+            ValidationResult::Denied(reason) => {
+                return Ok(ToolResult::err(format!("Denied: {reason}")));
+            }
+        }
+
+        let is_regex = args
+            .get("is_regex")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let case_insensitive = args
+            .get("case_insensitive")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let max_results = args
+            .get("max_results")
+            .and_then(|v| v.as_u64())
+            .map(|n| (n as usize).clamp(1, MAX_RESULTS_LIMIT))
+            .unwrap_or(DEFAULT_MAX_RESULTS);
+
+        let source = if is_regex {
+            pattern.to_string()
+        } else {
+            regex::escape(pattern)
+        };
+        let regex = regex::RegexBuilder::new(&source)
+            .case_insensitive(case_insensitive)
+            .build()
+            .map_err(|e| ZeniiError::Tool(format!("invalid regex pattern: {e}")))?;
+
+        let policy = self.policy.clone();
+        let max_file_size = self.max_file_size_bytes;
+        let max_depth = self.max_depth;
+        // The task-local root is not visible inside spawn_blocking
+        let jail = workspace::current_root();
+
+        let (matches, truncated) = tokio::task::spawn_blocking(move || {
+            let mut matches = Vec::new();
+            let walker = ignore::WalkBuilder::new(&root)
+                .max_depth(Some(max_depth))
+                .build();
+
+            for entry in walker.flatten() {
+                let path = entry.path();
+                if !path.is_file() {
+                    continue;
+                }
+                // A symlink inside the workspace must not expose a file outside it
+                if let Some(jail) = &jail
+                    && workspace::confine_to(jail, path).is_err()
+                {
+                    continue;
+                }
+                // Blocked directories may sit under an allowed root
+                if !matches!(policy.validate_path(path), ValidationResult::Allowed) {
+                    continue;
+                }
+                if let Ok(meta) = path.metadata()
+                    && meta.len() > max_file_size as u64
+                {
+                    continue;
+                }
+                if is_binary(path) {
+                    continue;
+                }
+                let Ok(content) = std::fs::read_to_string(path) else {
+                    continue;
+                };
+
+                for (idx, line) in content.lines().enumerate() {
+                    if !regex.is_match(line) {
+                        continue;
+                    }
+                    if matches.len() >= max_results {
+                        return (matches, true);
+                    }
+                    matches.push(GrepMatch {
+                        file: path.display().to_string(),
+                        line_number: idx + 1,
+                        line: line.to_string(),
+                    });
+                }
+            }
+            (matches, false)
+        })
+        .await
+        .map_err(|e| ZeniiError::Tool(format!("search failed: {e}")))?;
+
+        if matches.is_empty() {
+            return Ok(ToolResult::ok("No matches found"));
+        }
+        let json = serde_json::to_string_pretty(&matches).unwrap_or_else(|_| "[]".to_string());
+        let mut result = ToolResult::ok(json);
+        if truncated {
+            result.metadata = Some(serde_json::json!({ "truncated": true }));
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::policy::AutonomyLevel;
+    use tempfile::TempDir;
+
+    fn tool() -> GrepTool {
+        let policy = SecurityPolicy::new(AutonomyLevel::Full, None, vec![], 60, 60, 100);
+        GrepTool::new(Arc::new(policy), 1024 * 1024, 20)
+    }
+
+    fn entries(result: &ToolResult) -> Vec<serde_json::Value> {
+        serde_json::from_str(&result.output).unwrap()
+    }
+
+    // GR.1 — Literal search returns structured matches
+    #[tokio::test]
+    async fn grep_returns_file_line_entries() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "fn main() {}\nlet x = a.b();\n").unwrap();
+        let result = tool()
+            .execute(serde_json::json!({
+                "pattern": "a.b()",
+                "path": dir.path().to_str().unwrap()
+            }))
+            .await
+            .unwrap();
+        assert!(result.success);
+        let entries = entries(&result);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["line_number"], 2);
+        assert_eq!(entries[0]["line"], "let x = a.b();");
+        assert!(entries[0]["file"].as_str().unwrap().ends_with("a.rs"));
+    }
+
+    // GR.2 — Regex and case-insensitive matching
+    #[tokio::test]
+    async fn grep_regex_case_insensitive() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("t.txt"), "TODO one\ntodo two\ndone\n").unwrap();
+        let result = tool()
+            .execute(serde_json::json!({
+                "pattern": "^todo",
+                "path": dir.path().to_str().unwrap(),
+                "is_regex": true,
+                "case_insensitive": true
+            }))
+            .await
+            .unwrap();
+        assert_eq!(entries(&result).len(), 2);
+    }
+
+    // GR.3 — Caps at max_results and flags truncation
+    #[tokio::test]
+    async fn grep_caps_results() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("many.txt"), "hit\n".repeat(10)).unwrap();
+        let result = tool()
+            .execute(serde_json::json!({
+                "pattern": "hit",
+                "path": dir.path().to_str().unwrap(),
+                "max_results": 3
+            }))
+            .await
+            .unwrap();
+        assert_eq!(entries(&result).len(), 3);
+        assert_eq!(result.metadata.unwrap()["truncated"], true);
+    }
+
+    // GR.4 — Skips binary files and respects .gitignore
+    #[tokio::test]
+    async fn grep_skips_binary_and_ignored() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join(".git")).unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "ignored.txt\n").unwrap();
+        std::fs::write(dir.path().join("ignored.txt"), "needle").unwrap();
+        std::fs::write(dir.path().join("blob.bin"), b"needle\0\x01\x02").unwrap();
+        std::fs::write(dir.path().join("text.txt"), "needle").unwrap();
+        let result = tool()
+            .execute(serde_json::json!({
+                "pattern": "needle",
+                "path": dir.path().to_str().unwrap()
+            }))
+            .await
+            .unwrap();
+        let entries = entries(&result);
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0]["file"].as_str().unwrap().ends_with("text.txt"));
+    }
+
+    // GR.5 — Paths outside the policy workspace are denied
+    #[tokio::test]
+    async fn grep_denies_outside_policy_workspace() {
+        let workspace = TempDir::new().unwrap();
+        let other = TempDir::new().unwrap();
+        let policy = SecurityPolicy::new(
+            AutonomyLevel::Full,
+            Some(workspace.path().to_path_buf()),
+            vec![],
+            60,
+            60,
+            100,
+        );
+        let tool = GrepTool::new(Arc::new(policy), 1024 * 1024, 20);
+        let result = tool
+            .execute(serde_json::json!({
+                "pattern": "x",
+                "path": other.path().to_str().unwrap()
+            }))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("Denied"));
+    }
+
+    // GR.6 — Missing pattern errors
+    #[tokio::test]
+    async fn grep_missing_pattern_errors() {
+        assert!(tool().execute(serde_json::json!({})).await.is_err());
+    }
+
+    // GR.7 — A symlink inside the agent workspace cannot expose an outside file
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn grep_skips_symlink_escaping_workspace() {
+        let dir = TempDir::new().unwrap();
+        let outside = TempDir::new().unwrap();
+        let root = workspace::prepare_root(dir.path()).unwrap();
+        std::fs::write(outside.path().join("secret.txt"), "needle secret").unwrap();
+        std::os::unix::fs::symlink(outside.path().join("secret.txt"), root.join("link.txt"))
+            .unwrap();
+        std::fs::write(root.join("own.txt"), "needle own").unwrap();
+
+        let result = workspace::scope(
+            Some(root),
+            tool().execute(serde_json::json!({ "pattern": "needle" })),
+        )
+        .await
+        .unwrap();
+        let entries = entries(&result);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["line"], "needle own");
+        assert!(!result.output.contains("secret"));
+    }
+}
@@ -0,0 +1,591 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Method, Url, header};
+
+use crate::security::policy::{AutonomyLevel, SecurityPolicy, ValidationResult, is_private_ip};
+use crate::{Result, ZeniiError};
+
+use super::traits::{Tool, ToolResult};
+
+/// Maximum number of redirects followed manually (each hop is re-validated).
+const MAX_REDIRECTS: usize = 5;
+
+/// HTTP request tool for calling REST APIs.
+///
+/// Every URL — including each redirect hop — is checked against the
+/// security policy's host allowlist/denylist, and hostnames are resolved up
+/// front so requests to private, loopback or link-local addresses are refused
+/// unless the host is explicitly allowlisted. The connection is pinned to the
+/// validated addresses to prevent DNS rebinding between check and connect.
+pub struct HttpTool {
+    policy: Arc<SecurityPolicy>,
+    timeout_secs: u64,
+    max_response_bytes: usize,
+}
+
+impl HttpTool {
+    pub fn new(policy: Arc<SecurityPolicy>, timeout_secs: u64, max_response_bytes: usize) -> Self {
+        Self {
+            policy,
+            timeout_secs,
+            max_response_bytes,
+        }
+    }
+
+    /// Validate a URL against the policy and resolve it to addresses that are
+    /// safe to connect to. Denials are `ZeniiError::PolicyDenied`.
+    async fn check_url(&self, url: &Url) -> Result<Vec<SocketAddr>> {
+        if let ValidationResult::Denied(reason) = self.policy.validate_url(url) {
+            return Err(ZeniiError::PolicyDenied(reason));
+        }
+        let host = url
+            .host_str()
+            .unwrap_or_default()
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        let port = url.port_or_known_default().unwrap_or(80);
+        let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|e| ZeniiError::Tool(format!("failed to resolve '{host}': {e}")))?
+            .collect();
+        if addrs.is_empty() {
+            return Err(ZeniiError::Tool(format!(
+                "'{host}' did not resolve to any address"
+            )));
+        }
+        if !self.policy.is_http_host_allowlisted(host)
+            && let Some(addr) = addrs.iter().find(|a| is_private_ip(a.ip()))
+        {
+            return Err(ZeniiError::PolicyDenied(format!(
+                "'{host}' resolves to private address {}",
+                addr.ip()
+            )));
+        }
+        Ok(addrs)
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        url: Url,
+        mut headers: header::HeaderMap,
+        body: Option<String>,
+    ) -> Result<ToolResult> {
+        let mut method = method;
+        let mut url = url;
+        let mut body = body;
+
+        for _ in 0..=MAX_REDIRECTS {
+            let addrs = match self.check_url(&url).await {
+                Ok(addrs) => addrs,
+                Err(ZeniiError::PolicyDenied(reason)) => {
+                    return Ok(ToolResult::err(format!("Denied: {reason}")));
+                }
+                Err(e) => return Ok(ToolResult::err(e.to_string())),
+            };
+
+            let mut builder = reqwest::Client::builder()
+                .redirect(reqwest::redirect::Policy::none())
+                .timeout(Duration::from_secs(self.timeout_secs));
+            if let Some(domain) = url.domain() {
+                builder = builder.resolve_to_addrs(domain, &addrs);
+            }
+            let client = builder
+                .build()
+                .map_err(|e| ZeniiError::Tool(format!("failed to build HTTP client: {e}")))?;
+
+            let mut request = client
+                .request(method.clone(), url.clone())
+                .headers(headers.clone());
+            if let Some(b) = &body {
+                request = request.body(b.clone());
+            }
+            let response = request
+                .send()
+                .await
+                .map_err(|e| ZeniiError::Tool(format!("request failed: {e}")))?;
+
+            let status = response.status();
+            let location = response
+                .headers()
+                .get(header::LOCATION)
+                .and_then(|v| v.to_str().ok());
+            if status.is_redirection()
+                && let Some(location) = location
+            {
+                let next = url
+                    .join(location)
+                    .map_err(|e| ZeniiError::Tool(format!("invalid redirect location: {e}")))?;
+                // Like reqwest's own redirect policy, never carry credentials
+                // to a different scheme, host or port
+                if next.origin() != url.origin() {
+                    for name in [
+                        header::AUTHORIZATION,
+                        header::COOKIE,
+                        header::PROXY_AUTHORIZATION,
+                    ] {
+                        headers.remove(name);
+                    }
+                }
+                url = next;
+                // 303, and 301/302 after a POST, switch to GET without a body
+                if status == reqwest::StatusCode::SEE_OTHER
+                    || (method == Method::POST && matches!(status.as_u16(), 301 | 302))
+                {
+                    method = Method::GET;
+                    body = None;
+                }
+                continue;
+            }
+
+            return self.format_response(response).await;
+        }
+
+        Ok(ToolResult::err(format!(
+            "too many redirects (max {MAX_REDIRECTS})"
+        )))
+    }
+
+    /// Read the body up to the configured cap and render the result.
+    async fn format_response(&self, mut response: reqwest::Response) -> Result<ToolResult> {
+        let status = response.status();
+        let final_url = response.url().to_string();
+        let headers: serde_json::Map<String, serde_json::Value> = response
+            .headers()
+            .iter()
+            .map(|(k, v)| {
+                (
+                    k.as_str().to_string(),
+                    serde_json::Value::String(String::from_utf8_lossy(v.as_bytes()).into_owned()),
+                )
+            })
+            .collect();
+
+        let mut bytes = Vec::new();
+        let mut truncated = false;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| ZeniiError::Tool(format!("failed to read response body: {e}")))?
+        {
+            let remaining = self.max_response_bytes - bytes.len();
+            if chunk.len() > remaining {
+                bytes.extend_from_slice(&chunk[..remaining]);
+                truncated = true;
+                break;
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+        let mut text = String::from_utf8_lossy(&bytes).into_owned();
+        if truncated {
+            text.push_str(&format!(
+                "\n[truncated at {} bytes]",
+                self.max_response_bytes
+            ));
+        }
+
+        let header_lines: String = headers
+            .iter()
+            .map(|(k, v)| format!("{k}: {}\n", v.as_str().unwrap_or_default()))
+            .collect();
+        let output = format!("HTTP {status}\n{header_lines}\n{text}");
+        let metadata = serde_json::json!({
+            "status": status.as_u16(),
+            "url": final_url,
+            "headers": headers,
+            "truncated": truncated,
+        });
+
+        let result = if status.is_client_error() || status.is_server_error() {
+            ToolResult::err(output)
+        } else {
+            ToolResult::ok(output)
+        };
+        Ok(ToolResult {
+            metadata: Some(metadata),
+            ..result
+        })
+    }
+}
+
+fn parse_method(args: &serde_json::Value) -> Result<Method> {
+    let method = args
+        .get("method")
+        .and_then(|v| v.as_str())
+        .unwrap_or("GET")
+        .to_uppercase();
+    match method.as_str() {
+        "GET" | "HEAD" | "POST" | "PUT" | "PATCH" | "DELETE" | "OPTIONS" => {
+            Method::from_bytes(method.as_bytes())
+                .map_err(|e| ZeniiError::Tool(format!("invalid method: {e}")))
+        }
+        other => Err(ZeniiError::Tool(format!("unsupported method '{other}'"))),
+    }
+}
+
+fn is_read_only_method(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+#[async_trait]
+impl Tool for HttpTool {
+    fn name(&self) -> &str {
+        "http_request"
+    }
+
+    fn risk_level(&self) -> crate::security::RiskLevel {
+        crate::security::RiskLevel::Medium
+    }
+
+    fn description(&self) -> &str {
+        "Make an HTTP request to a REST API and return the status, headers and (truncated) body"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "method": {
+                    "type": "string",
+                    "enum": ["GET", "HEAD", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"],
+                    "description": "HTTP method (default: GET)"
+                },
+                "url": { "type": "string", "description": "Absolute http(s) URL" },
+                "headers": {
+                    "type": "object",
+                    "additionalProperties": { "type": "string" },
+                    "description": "Request headers"
+                },
+                "body": {
+                    "description": "Request body; objects and arrays are sent as JSON"
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    fn needs_approval(&self, args: &serde_json::Value) -> Option<String> {
+        let method = parse_method(args).ok()?;
+        if self.policy.autonomy_for("http_request") == AutonomyLevel::Supervised
+            && !is_read_only_method(&method)
+        {
+            let url = args.get("url").and_then(|v| v.as_str()).unwrap_or_default();
+            return Some(format!("HTTP request needs approval: {method} {url}"));
+        }
+        None
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> Result<ToolResult> {
+        let url = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ZeniiError::Tool("missing 'url' argument".into()))?;
+        let url = Url::parse(url).map_err(|e| ZeniiError::Tool(format!("invalid url: {e}")))?;
+        let method = parse_method(&args)?;
+
+        if self.policy.autonomy_for("http_request") == AutonomyLevel::ReadOnly
+            && !is_read_only_method(&method)
+        {
+            return Ok(ToolResult::err(format!(
+                "Denied: {method} requests are not allowed in read-only mode"
+            )));
+        }
+
+        let mut headers = header::HeaderMap::new();
+        if let Some(obj) = args.get("headers").and_then(|v| v.as_object()) {
+            for (k, v) in obj {
+                let value = v
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| v.to_string());
+                let name = header::HeaderName::from_bytes(k.as_bytes())
+                    .map_err(|e| ZeniiError::Tool(format!("invalid header name '{k}': {e}")))?;
+                let value = header::HeaderValue::from_str(&value).map_err(|e| {
+                    ZeniiError::Tool(format!("invalid header value for '{k}': {e}"))
+                })?;
+                headers.insert(name, value);
+            }
+        }
+
+        let body = match args.get("body") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::String(s)) => Some(s.clone()),
+            Some(other) => {
+                headers
+                    .entry(header::CONTENT_TYPE)
+                    .or_insert(header::HeaderValue::from_static("application/json"));
+                Some(other.to_string())
+            }
+        };
+
+        self.policy
+            .log_action(&format!("http_request:{method} {url}"), "allowed");
+
+        tokio::time::timeout(
+            Duration::from_secs(self.timeout_secs),
+            self.send(method, url, headers, body),
+        )
+        .await
+        .map_err(|_| ZeniiError::Tool("request timed out".into()))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn policy(level: AutonomyLevel, allowed: &[&str]) -> Arc<SecurityPolicy> {
+        Arc::new(
+            SecurityPolicy::new(level, None, vec![], 60, 60, 100)
+                .with_http_hosts(allowed.iter().map(|s| s.to_string()).collect(), vec![]),
+        )
+    }
+
+    /// Serve raw HTTP responses chosen by request path; returns the base URL.
+    async fn serve(respond: fn(&str) -> String) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 8192];
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                let req = String::from_utf8_lossy(&buf[..n]).to_string();
+                let path = req.split_whitespace().nth(1).unwrap_or("/").to_string();
+                let _ = stream.write_all(respond(&path).as_bytes()).await;
+            }
+        });
+        format!("http://127.0.0.1:{}", addr.port())
+    }
+
+    /// Serve one fixed response and record every raw request received.
+    async fn serve_recording(reply: String) -> (String, Arc<std::sync::Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = seen.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 8192];
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                log.lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&buf[..n]).to_lowercase());
+                let _ = stream.write_all(reply.as_bytes()).await;
+            }
+        });
+        (format!("http://127.0.0.1:{}", addr.port()), seen)
+    }
+
+    fn response(status: &str, extra_headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n{extra_headers}\r\n{body}",
+            body.len()
+        )
+    }
+
+    fn routes(path: &str) -> String {
+        match path {
+            "/ok" => response("200 OK", "X-Test: yes\r\n", r#"{"hello":"world"}"#),
+            "/big" => response("200 OK", "", &"a".repeat(1000)),
+            "/missing" => response("404 Not Found", "", "nope"),
+            "/redirect" => response("302 Found", "Location: /ok\r\n", ""),
+            "/metadata" => response(
+                "302 Found",
+                "Location: http://169.254.169.254/latest/meta-data\r\n",
+                "",
+            ),
+            "/loop" => response("302 Found", "Location: /loop\r\n", ""),
+            _ => response("500 Internal Server Error", "", ""),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_returns_status_headers_and_body() {
+        let base = serve(routes).await;
+        let tool = HttpTool::new(policy(AutonomyLevel::Full, &["127.0.0.1"]), 5, 1024);
+        let result = tool
+            .execute(serde_json::json!({"url": format!("{base}/ok")}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.starts_with("HTTP 200 OK"));
+        assert!(result.output.contains("x-test: yes"));
+        assert!(result.output.contains(r#"{"hello":"world"}"#));
+        let meta = result.metadata.unwrap();
+        assert_eq!(meta["status"], 200);
+        assert_eq!(meta["headers"]["x-test"], "yes");
+        assert_eq!(meta["truncated"], false);
+    }
+
+    #[tokio::test]
+    async fn body_is_truncated_at_cap() {
+        let base = serve(routes).await;
+        let tool = HttpTool::new(policy(AutonomyLevel::Full, &["127.0.0.1"]), 5, 100);
+        let result = tool
+            .execute(serde_json::json!({"url": format!("{base}/big")}))
+            .await
+            .unwrap();
+        assert!(result.output.contains("[truncated at 100 bytes]"));
+        assert!(!result.output.contains(&"a".repeat(101)));
+        assert_eq!(result.metadata.unwrap()["truncated"], true);
+    }
+
+    #[tokio::test]
+    async fn error_status_is_not_success() {
+        let base = serve(routes).await;
+        let tool = HttpTool::new(policy(AutonomyLevel::Full, &["127.0.0.1"]), 5, 1024);
+        let result = tool
+            .execute(serde_json::json!({"url": format!("{base}/missing")}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert_eq!(result.metadata.unwrap()["status"], 404);
+    }
+
+    #[tokio::test]
+    async fn follows_redirects_on_allowed_hosts() {
+        let base = serve(routes).await;
+        let tool = HttpTool::new(policy(AutonomyLevel::Full, &["127.0.0.1"]), 5, 1024);
+        let result = tool
+            .execute(serde_json::json!({"url": format!("{base}/redirect")}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("hello"));
+    }
+
+    #[tokio::test]
+    async fn cross_origin_redirect_drops_credentials() {
+        let (target, target_seen) = serve_recording(response("200 OK", "", "landed")).await;
+        let (origin, origin_seen) = serve_recording(response(
+            "302 Found",
+            &format!("Location: {target}/landing\r\n"),
+            "",
+        ))
+        .await;
+        let tool = HttpTool::new(policy(AutonomyLevel::Full, &["127.0.0.1"]), 5, 1024);
+        let result = tool
+            .execute(serde_json::json!({
+                "url": format!("{origin}/start"),
+                "headers": {
+                    "Authorization": "Bearer secret",
+                    "Cookie": "session=secret",
+                    "X-Trace": "kept"
+                }
+            }))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("landed"));
+
+        let origin_seen = origin_seen.lock().unwrap();
+        assert!(origin_seen[0].contains("authorization: bearer secret"));
+        let target_seen = target_seen.lock().unwrap();
+        assert_eq!(target_seen.len(), 1);
+        assert!(!target_seen[0].contains("authorization"));
+        assert!(!target_seen[0].contains("cookie"));
+        assert!(target_seen[0].contains("x-trace: kept"));
+    }
+
+    #[tokio::test]
+    async fn redirect_to_private_address_is_refused() {
+        let base = serve(routes).await;
+        let tool = HttpTool::new(policy(AutonomyLevel::Full, &["127.0.0.1"]), 5, 1024);
+        let result = tool
+            .execute(serde_json::json!({"url": format!("{base}/metadata")}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("Denied"));
+    }
+
+    #[tokio::test]
+    async fn redirect_loop_is_capped() {
+        let base = serve(routes).await;
+        let tool = HttpTool::new(policy(AutonomyLevel::Full, &["127.0.0.1"]), 5, 1024);
+        let result = tool
+            .execute(serde_json::json!({"url": format!("{base}/loop")}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("too many redirects"));
+    }
+
+    #[tokio::test]
+    async fn private_address_denied_without_allowlist() {
+        let base = serve(routes).await;
+        let tool = HttpTool::new(policy(AutonomyLevel::Full, &[]), 5, 1024);
+        let result = tool
+            .execute(serde_json::json!({"url": format!("{base}/ok")}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("private address"));
+    }
+
+    #[tokio::test]
+    async fn localhost_name_denied_after_resolution() {
+        let tool = HttpTool::new(policy(AutonomyLevel::Full, &[]), 5, 1024);
+        let result = tool
+            .execute(serde_json::json!({"url": "http://localhost:1/"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("resolves to private address"));
+    }
+
+    #[tokio::test]
+    async fn readonly_denies_mutating_methods() {
+        let tool = HttpTool::new(policy(AutonomyLevel::ReadOnly, &["127.0.0.1"]), 5, 1024);
+        let result = tool
+            .execute(serde_json::json!({"method": "POST", "url": "http://127.0.0.1:1/"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("read-only"));
+    }
+
+    #[tokio::test]
+    async fn invalid_arguments_error() {
+        let tool = HttpTool::new(policy(AutonomyLevel::Full, &[]), 5, 1024);
+        assert!(tool.execute(serde_json::json!({})).await.is_err());
+        assert!(
+            tool.execute(serde_json::json!({"url": "not a url"}))
+                .await
+                .is_err()
+        );
+        assert!(
+            tool.execute(serde_json::json!({"method": "TRACE", "url": "https://example.com"}))
+                .await
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn needs_approval_for_mutating_methods_when_supervised() {
+        let tool = HttpTool::new(policy(AutonomyLevel::Supervised, &[]), 5, 1024);
+        assert!(
+            tool.needs_approval(&serde_json::json!({"url": "https://example.com"}))
+                .is_none()
+        );
+        let msg = tool
+            .needs_approval(
+                &serde_json::json!({"method": "delete", "url": "https://example.com/x"}),
+            )
+            .unwrap();
+        assert!(msg.contains("DELETE https://example.com/x"));
+
+        let full = HttpTool::new(policy(AutonomyLevel::Full, &[]), 5, 1024);
+        assert!(
+            full.needs_approval(
+                &serde_json::json!({"method": "POST", "url": "https://example.com"})
+            )
+            .is_none()
+        );
+    }
+}
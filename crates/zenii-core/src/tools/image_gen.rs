@@ -0,0 +1,218 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::ai::image_gen::ImageGenProvider;
+use crate::{Result, ZeniiError};
+
+use super::traits::{Tool, ToolResult};
+use super::workspace;
+
+/// Generates images via the configured `ImageGenProvider` and saves them to disk.
+///
+/// Inside an agent workspace the images go to its `images/` directory,
+/// otherwise to `output_dir`. Returns the absolute paths of the saved files so
+/// channels and the UI can attach or display them.
+pub struct ImageGenTool {
+    provider: Arc<dyn ImageGenProvider>,
+    output_dir: PathBuf,
+    default_size: String,
+    max_images: u32,
+}
+
+impl ImageGenTool {
+    pub fn new(
+        provider: Arc<dyn ImageGenProvider>,
+        output_dir: PathBuf,
+        default_size: String,
+        max_images: u32,
+    ) -> Self {
+        Self {
+            provider,
+            output_dir,
+            default_size,
+            max_images,
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for ImageGenTool {
+    fn name(&self) -> &str {
+        "image_generate"
+    }
+
+    fn description(&self) -> &str {
+        "Generate images from a text prompt. Returns the file paths of the saved images. \
+         Write a detailed, visual prompt (subject, style, composition, lighting)."
+    }
+
+    fn risk_level(&self) -> crate::security::RiskLevel {
+        crate::security::RiskLevel::Medium
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "prompt": { "type": "string", "description": "Description of the image to generate" },
+                "size": { "type": "string", "description": "Image size as WIDTHxHEIGHT, e.g. 1024x1024", "default": self.default_size },
+                "count": { "type": "integer", "description": "Number of images to generate", "default": 1 }
+            },
+            "required": ["prompt"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> Result<ToolResult> {
+        let prompt = args
+            .get("prompt")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ZeniiError::Tool("missing 'prompt' argument".into()))?;
+        if prompt.trim().is_empty() {
+            return Err(ZeniiError::Tool("prompt must not be empty".into()));
+        }
+
+        let size = args
+            .get("size")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&self.default_size);
+        let count = args
+            .get("count")
+            .and_then(|v| v.as_u64())
+            .map(|n| n as u32)
+            .unwrap_or(1)
+            .clamp(1, self.max_images.max(1));
+
+        info!(model = %self.provider.model(), size = %size, count, "Generating image");
+        let images = self.provider.generate(prompt, size, count).await?;
+
+        let output_dir = workspace::current_root()
+            .map(|root| root.join("images"))
+            .unwrap_or_else(|| self.output_dir.clone());
+        let batch = uuid::Uuid::new_v4().simple().to_string();
+        let paths = tokio::task::spawn_blocking(move || -> Result<Vec<PathBuf>> {
+            std::fs::create_dir_all(&output_dir)?;
+            let mut paths = Vec::with_capacity(images.len());
+            for (i, image) in images.iter().enumerate() {
+                let path = output_dir.join(format!("{}-{i}.{}", &batch[..12], image.extension()));
+                std::fs::write(&path, &image.bytes)?;
+                paths.push(path);
+            }
+            Ok(paths)
+        })
+        .await
+        .map_err(|e| ZeniiError::Tool(format!("spawn_blocking error: {e}")))??;
+
+        let listing: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+        Ok(ToolResult {
+            output: format!(
+                "Generated {} image(s):\n{}",
+                listing.len(),
+                listing.join("\n")
+            ),
+            success: true,
+            metadata: Some(serde_json::json!({
+                "paths": listing,
+                "model": self.provider.model(),
+            })),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::image_gen::OpenAiImageGenProvider;
+    use crate::ai::test_support::{MockReply, openai_mock};
+    use base64::Engine;
+
+    fn tool_for(base_url: &str, dir: &std::path::Path) -> ImageGenTool {
+        let provider = OpenAiImageGenProvider::new("sk-test".into(), "dall-e-3".into())
+            .with_base_url(base_url.into());
+        ImageGenTool::new(Arc::new(provider), dir.to_path_buf(), "1024x1024".into(), 4)
+    }
+
+    /// Mock images endpoint returning `bytes` as base64, with an optional
+    /// `output_format`.
+    async fn images_mock(bytes: &[u8], output_format: Option<&str>) -> String {
+        let mut body = serde_json::json!({
+            "created": 0,
+            "data": [{
+                "b64_json": base64::engine::general_purpose::STANDARD.encode(bytes),
+                "revised_prompt": "a cat"
+            }]
+        });
+        if let Some(format) = output_format {
+            body["output_format"] = serde_json::json!(format);
+        }
+        openai_mock(move |_, _| MockReply::json("200 OK", body.clone())).await
+    }
+
+    fn saved_path(result: &ToolResult) -> PathBuf {
+        let paths = result.metadata.as_ref().unwrap()["paths"].clone();
+        PathBuf::from(paths[0].as_str().unwrap())
+    }
+
+    // IMG.5 — Schema requires prompt
+    #[test]
+    fn schema_requires_prompt() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let tool = tool_for("http://127.0.0.1:1", dir.path());
+        let schema = tool.parameters_schema();
+        assert_eq!(schema["required"][0], "prompt");
+        assert_eq!(tool.name(), "image_generate");
+    }
+
+    // IMG.6 — Missing prompt errors
+    #[tokio::test]
+    async fn missing_prompt_errors() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let tool = tool_for("http://127.0.0.1:1", dir.path());
+        assert!(tool.execute(serde_json::json!({})).await.is_err());
+    }
+
+    // IMG.7 — Tool saves the image returned by a mock server
+    #[tokio::test]
+    async fn saves_returned_image() {
+        let png_bytes: &[u8] = b"\x89PNG\r\n\x1a\nfake-image-data";
+        let url = images_mock(png_bytes, None).await;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let tool = tool_for(&url, dir.path());
+        let result = tool
+            .execute(serde_json::json!({"prompt": "a cat"}))
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let path = saved_path(&result);
+        assert!(path.starts_with(dir.path()));
+        assert_eq!(path.extension().unwrap(), "png");
+        assert_eq!(std::fs::read(&path).unwrap(), png_bytes);
+        assert!(result.output.contains(&path.display().to_string()));
+    }
+
+    // IMG.8 — Inside an agent workspace, images are saved under it with the
+    // extension of the reported format
+    #[tokio::test]
+    async fn saves_under_agent_workspace() {
+        let url = images_mock(b"RIFF-fake-webp", Some("webp")).await;
+        let output = tempfile::TempDir::new().unwrap();
+        let root = tempfile::TempDir::new().unwrap();
+        let tool = tool_for(&url, output.path());
+
+        let result = workspace::scope(
+            Some(root.path().to_path_buf()),
+            tool.execute(serde_json::json!({"prompt": "a cat"})),
+        )
+        .await
+        .unwrap();
+
+        let path = saved_path(&result);
+        assert!(path.starts_with(root.path().join("images")));
+        assert_eq!(path.extension().unwrap(), "webp");
+        assert!(std::fs::read_dir(output.path()).unwrap().next().is_none());
+    }
+}
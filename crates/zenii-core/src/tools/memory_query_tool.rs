@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::json;
+
+use crate::memory::traits::{Memory, MemoryCategory, MemoryQuery};
+use crate::{Result, ZeniiError};
+
+use super::traits::{Tool, ToolResult};
+
+const DEFAULT_LIMIT: u64 = 10;
+const MAX_LIMIT: u64 = 100;
+
+/// Agent tool for filtered memory lookups by category and creation time,
+/// optionally ranked by a search query.
+pub struct MemoryQueryTool {
+    memory: Arc<dyn Memory>,
+}
+
+impl MemoryQueryTool {
+    pub fn new(memory: Arc<dyn Memory>) -> Self {
+        Self { memory }
+    }
+}
+
+/// Parse an `after`/`before` bound: RFC 3339, or a `YYYY-MM-DD` date meaning
+/// midnight UTC.
+fn parse_bound(args: &serde_json::Value, name: &str) -> Result<Option<DateTime<Utc>>> {
+    let Some(value) = args[name].as_str().filter(|v| !v.trim().is_empty()) else {
+        return Ok(None);
+    };
+    let value = value.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(Some(dt.with_timezone(&Utc)));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|d| Some(d.and_time(chrono::NaiveTime::MIN).and_utc()))
+        .map_err(|_| {
+            ZeniiError::Validation(format!(
+                "invalid '{name}' '{value}' (expected RFC 3339 or YYYY-MM-DD)"
+            ))
+        })
+}
+
+#[async_trait]
+impl Tool for MemoryQueryTool {
+    fn name(&self) -> &str {
+        "memory_query"
+    }
+
+    fn description(&self) -> &str {
+        "Find stored memories by category and creation time, e.g. what was saved about a topic last week. With 'query' results are ranked by relevance; without it the most recent matching entries are returned."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "query": {
+                    "type": "string",
+                    "description": "Optional search text to rank results by"
+                },
+                "category": {
+                    "type": "string",
+                    "description": "Only entries in this category (core, daily, conversation or a custom name)"
+                },
+                "after": {
+                    "type": "string",
+                    "description": "Only entries created at or after this time (RFC 3339 or YYYY-MM-DD, UTC)"
+                },
+                "before": {
+                    "type": "string",
+                    "description": "Only entries created before this time (RFC 3339 or YYYY-MM-DD, UTC)"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of results (default: 10, max: 100)"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> Result<ToolResult> {
+        let after = parse_bound(&args, "after")?;
+        let before = parse_bound(&args, "before")?;
+        if let (Some(a), Some(b)) = (after, before)
+            && a >= b
+        {
+            return Err(ZeniiError::Validation(
+                "'after' must be earlier than 'before'".into(),
+            ));
+        }
+        let filter = MemoryQuery {
+            query: args["query"].as_str().map(str::to_string),
+            category: args["category"]
+                .as_str()
+                .filter(|c| !c.trim().is_empty())
+                .map(MemoryCategory::from),
+            after,
+            before,
+            limit: args["limit"]
+                .as_u64()
+                .unwrap_or(DEFAULT_LIMIT)
+                .clamp(1, MAX_LIMIT) as usize,
+        };
+
+        match self.memory.query(&filter).await {
+            Ok(entries) => {
+                let results: Vec<serde_json::Value> = entries
+                    .iter()
+                    .map(|e| {
+                        json!({
+                            "key": e.key,
+                            "content": e.content,
+                            "category": e.category.to_string(),
+                            "score": e.score,
+                            "created_at": e.created_at,
+                        })
+                    })
+                    .collect();
+                Ok(ToolResult::ok(
+                    serde_json::to_string_pretty(&results).unwrap_or_default(),
+                ))
+            }
+            Err(e) => Ok(ToolResult::err(format!("Failed to query memory: {e}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::in_memory_store::InMemoryStore;
+
+    async fn setup() -> MemoryQueryTool {
+        let memory = Arc::new(InMemoryStore::new());
+        memory
+            .store(
+                "billing_plan",
+                "User is on the yearly plan",
+                MemoryCategory::Core,
+            )
+            .await
+            .unwrap();
+        memory
+            .store(
+                "billing_chat",
+                "Asked about a refund",
+                MemoryCategory::Conversation,
+            )
+            .await
+            .unwrap();
+        MemoryQueryTool::new(memory)
+    }
+
+    // MQ.1 — Filters alone return matching entries
+    #[tokio::test]
+    async fn query_by_category_without_text() {
+        let tool = setup().await;
+        let result = tool
+            .execute(json!({ "category": "core", "after": "2000-01-01" }))
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&result.output).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["key"], "billing_plan");
+        assert!(entries[0]["created_at"].is_string());
+    }
+
+    // MQ.2 — A time window with no entries returns an empty list
+    #[tokio::test]
+    async fn query_outside_window_is_empty() {
+        let tool = setup().await;
+        let result = tool
+            .execute(json!({ "query": "billing", "before": "2000-01-01T00:00:00Z" }))
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.output, "[]");
+    }
+
+    // MQ.3 — Malformed or inverted bounds are rejected
+    #[tokio::test]
+    async fn query_rejects_bad_bounds() {
+        let tool = setup().await;
+        assert!(tool.execute(json!({ "after": "last week" })).await.is_err());
+        assert!(
+            tool.execute(json!({ "after": "2026-03-10", "before": "2026-03-01" }))
+                .await
+                .is_err()
+        );
+    }
+}
@@ -3,11 +3,15 @@ pub mod config_tool;
 pub mod content_search;
 pub mod file_ops;
 pub mod file_search;
+pub mod grep;
+pub mod http;
 pub mod learn;
+pub mod memory_query_tool;
 pub mod memory_tool;
 pub mod patch;
 pub mod path;
 pub mod process;
+pub mod profiles;
 pub mod registry;
 pub mod shell;
 pub mod skill_proposal;
@@ -15,11 +19,18 @@ pub mod system_info;
 pub mod traits;
 pub mod web_search;
 pub mod wiki_tool;
+pub mod workspace;
 
 #[cfg(feature = "channels")]
 pub mod channel_tool;
+#[cfg(feature = "git")]
+pub mod git;
+#[cfg(feature = "ai")]
+pub mod image_gen;
 #[cfg(feature = "mcp-client")]
 pub mod mcp_client_tool;
+#[cfg(feature = "ai")]
+pub mod read_tool_output;
 #[cfg(feature = "scheduler")]
 pub mod scheduler_tool;
 #[cfg(feature = "workflows")]
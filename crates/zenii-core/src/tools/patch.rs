@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use crate::{Result, ZeniiError};
 
 use super::traits::{Tool, ToolResult};
+use super::workspace;
 
 pub struct PatchTool;
 
@@ -60,7 +61,10 @@ impl Tool for PatchTool {
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
-        let file_path = file_path.to_string();
+        let file_path = match workspace::confine(file_path) {
+            Ok(path) => path,
+            Err(e) => return Ok(ToolResult::err(e.to_string())),
+        };
         let diff = diff.to_string();
 
         tokio::task::spawn_blocking(move || {
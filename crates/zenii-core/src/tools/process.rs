@@ -94,7 +94,7 @@ impl Tool for ProcessTool {
                 Ok(ToolResult::ok(result))
             }
             "kill" => {
-                if self.policy.autonomy_level != AutonomyLevel::Full {
+                if self.policy.autonomy_for("process") != AutonomyLevel::Full {
                     return Ok(ToolResult::err("Kill requires Full autonomy mode"));
                 }
                 let pid = args
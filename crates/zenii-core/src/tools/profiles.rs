@@ -0,0 +1,115 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::{Result, ZeniiError};
+
+use super::traits::Tool;
+
+/// Built-in profile: every tool that reports [`Tool::is_read_only`].
+pub const READ_ONLY_PROFILE: &str = "read_only";
+
+/// Names usable as allowlist entries that expand to a group of tools:
+/// the built-in [`READ_ONLY_PROFILE`] plus every configured `tool_profiles` key.
+pub fn profile_names(profiles: &HashMap<String, Vec<String>>) -> Vec<String> {
+    let mut names: Vec<String> = profiles.keys().cloned().collect();
+    if !profiles.contains_key(READ_ONLY_PROFILE) {
+        names.push(READ_ONLY_PROFILE.to_string());
+    }
+    names.sort();
+    names
+}
+
+/// Expand an allowlist into the set of tool names it grants. Each entry is a
+/// tool name or a profile name; tool names win when both match. Configured
+/// profiles shadow the built-in `read_only`. Unknown entries are an error.
+pub fn resolve_allowlist(
+    entries: &[String],
+    profiles: &HashMap<String, Vec<String>>,
+    tools: &[Arc<dyn Tool>],
+) -> Result<HashSet<String>> {
+    let mut allowed = HashSet::new();
+    for entry in entries {
+        if tools.iter().any(|t| t.name() == entry) {
+            allowed.insert(entry.clone());
+        } else if let Some(members) = profiles.get(entry) {
+            allowed.extend(members.iter().cloned());
+        } else if entry == READ_ONLY_PROFILE {
+            allowed.extend(
+                tools
+                    .iter()
+                    .filter(|t| t.is_read_only())
+                    .map(|t| t.name().to_string()),
+            );
+        } else {
+            return Err(ZeniiError::Validation(format!(
+                "unknown tool or profile '{entry}' in allowlist"
+            )));
+        }
+    }
+    Ok(allowed)
+}
+
+/// Message returned to the model when it calls a tool outside its toolset.
+pub fn not_available(tool_name: &str) -> String {
+    format!("tool '{tool_name}' is not available in this session")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::policy::{AutonomyLevel, SecurityPolicy};
+    use crate::tools::file_ops::FileWriteTool;
+    use crate::tools::system_info::SystemInfoTool;
+
+    fn tools() -> Vec<Arc<dyn Tool>> {
+        let policy = SecurityPolicy::new(AutonomyLevel::Full, None, vec![], 60, 60, 100);
+        vec![
+            Arc::new(SystemInfoTool::new()),
+            Arc::new(FileWriteTool::new(Arc::new(policy))),
+        ]
+    }
+
+    // TP.1 — Plain tool names pass through
+    #[test]
+    fn resolves_tool_names() {
+        let allowed =
+            resolve_allowlist(&["system_info".into()], &HashMap::new(), &tools()).unwrap();
+        assert_eq!(allowed, HashSet::from(["system_info".to_string()]));
+    }
+
+    // TP.2 — Built-in read_only profile expands to read-only tools only
+    #[test]
+    fn read_only_profile_excludes_writers() {
+        let allowed =
+            resolve_allowlist(&[READ_ONLY_PROFILE.into()], &HashMap::new(), &tools()).unwrap();
+        assert!(allowed.contains("system_info"));
+        assert!(!allowed.contains("file_write"));
+    }
+
+    // TP.3 — Configured profiles expand to their members
+    #[test]
+    fn configured_profile_expands() {
+        let profiles = HashMap::from([(
+            "research".to_string(),
+            vec!["web_search".to_string(), "file_read".to_string()],
+        )]);
+        let allowed = resolve_allowlist(&["research".into()], &profiles, &tools()).unwrap();
+        assert!(allowed.contains("web_search"));
+        assert!(allowed.contains("file_read"));
+        assert_eq!(allowed.len(), 2);
+    }
+
+    // TP.4 — Unknown entries are rejected
+    #[test]
+    fn unknown_entry_errors() {
+        let err = resolve_allowlist(&["nope".into()], &HashMap::new(), &tools()).unwrap_err();
+        assert!(err.to_string().contains("unknown tool or profile 'nope'"));
+    }
+
+    // TP.5 — Profile names include the built-in once
+    #[test]
+    fn profile_names_include_builtin() {
+        let profiles = HashMap::from([("research".to_string(), vec![])]);
+        assert_eq!(profile_names(&profiles), vec!["read_only", "research"]);
+    }
+}
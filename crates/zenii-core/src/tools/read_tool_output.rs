@@ -0,0 +1,186 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::ai::tool_results::{ToolResultStore, truncation_note};
+use crate::{Result, ZeniiError};
+
+use super::traits::{Tool, ToolResult};
+
+/// Pages through the full output of a tool call whose result was truncated.
+///
+/// Handles come from the note the agent appends to truncated results and stay
+/// valid for `tool_result_retention_secs`.
+pub struct ReadToolOutputTool {
+    store: Arc<ToolResultStore>,
+    max_chunk: usize,
+}
+
+impl ReadToolOutputTool {
+    /// `max_chunk` bounds each read in bytes. Keep it under the compression
+    /// ceiling so a chunk is not itself truncated.
+    pub fn new(store: Arc<ToolResultStore>, max_chunk: usize) -> Self {
+        Self {
+            store,
+            max_chunk: max_chunk.max(1),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for ReadToolOutputTool {
+    fn name(&self) -> &str {
+        "read_tool_output"
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
+    fn description(&self) -> &str {
+        "Read more of a truncated tool result. Use the handle and offset from the [truncated, ...] note."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "handle": {
+                    "type": "string",
+                    "description": "Handle from the truncation note"
+                },
+                "offset": {
+                    "type": "integer",
+                    "description": "Byte offset to start reading at (default 0)"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum bytes to return (capped by the server)"
+                }
+            },
+            "required": ["handle"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> Result<ToolResult> {
+        let handle = args
+            .get("handle")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ZeniiError::Tool("missing 'handle' argument".into()))?;
+        let offset = args.get("offset").and_then(|v| v.as_u64()).unwrap_or(0) as usize;
+        let limit = args
+            .get("limit")
+            .and_then(|v| v.as_u64())
+            .map_or(self.max_chunk, |l| (l as usize).min(self.max_chunk));
+
+        let Some(stored) = self.store.get(handle) else {
+            return Ok(ToolResult::err(format!(
+                "no stored output for handle '{handle}' (it may have expired)"
+            )));
+        };
+        let full = &stored.output;
+        if offset >= full.len() {
+            return Ok(ToolResult::err(format!(
+                "offset {offset} is past the end of the output ({} bytes)",
+                full.len()
+            )));
+        }
+
+        let start = floor_char_boundary(full, offset);
+        let end = floor_char_boundary(full, start.saturating_add(limit).min(full.len()));
+        // A limit smaller than one character would return nothing; take the whole char.
+        let end = if end == start {
+            full[start..]
+                .chars()
+                .next()
+                .map_or(start, |c| start + c.len_utf8())
+        } else {
+            end
+        };
+
+        let mut output = full[start..end].to_string();
+        if end < full.len() {
+            output.push('\n');
+            output.push_str(&truncation_note(handle, full.len(), end));
+        }
+        Ok(ToolResult::ok(output))
+    }
+}
+
+fn floor_char_boundary(s: &str, mut idx: usize) -> usize {
+    while !s.is_char_boundary(idx) {
+        idx -= 1;
+    }
+    idx
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn tool_with(output: &str, max_chunk: usize) -> (ReadToolOutputTool, String) {
+        let store = Arc::new(ToolResultStore::new(Duration::from_secs(60)));
+        let id = store.put("shell", output.to_string());
+        (ReadToolOutputTool::new(store, max_chunk), id)
+    }
+
+    // RTO.1 — Output is paged in chunks, each pointing at the next offset
+    #[tokio::test]
+    async fn pages_through_stored_output() {
+        let (tool, id) = tool_with("abcdefghij", 4);
+
+        let first = tool
+            .execute(serde_json::json!({"handle": id}))
+            .await
+            .unwrap();
+        assert!(first.success);
+        assert!(first.output.starts_with("abcd\n"));
+        assert!(first.output.contains("offset=4"), "{}", first.output);
+
+        let last = tool
+            .execute(serde_json::json!({"handle": id, "offset": 8}))
+            .await
+            .unwrap();
+        assert_eq!(last.output, "ij");
+    }
+
+    // RTO.2 — Reads never split a multi-byte character
+    #[tokio::test]
+    async fn chunks_respect_char_boundaries() {
+        let (tool, id) = tool_with("aé€b", 2);
+
+        let result = tool
+            .execute(serde_json::json!({"handle": id, "offset": 2}))
+            .await
+            .unwrap();
+        // Offset 2 is inside "é"; reading starts at its first byte.
+        assert!(result.output.starts_with("é\n"), "{}", result.output);
+
+        let result = tool
+            .execute(serde_json::json!({"handle": id, "offset": 3, "limit": 1}))
+            .await
+            .unwrap();
+        assert!(result.output.starts_with("€\n"), "{}", result.output);
+    }
+
+    // RTO.3 — Unknown handles and out-of-range offsets are reported to the model
+    #[tokio::test]
+    async fn unknown_handle_and_bad_offset_fail_softly() {
+        let (tool, id) = tool_with("abc", 10);
+
+        let result = tool
+            .execute(serde_json::json!({"handle": "nope"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.output.contains("expired"));
+
+        let result = tool
+            .execute(serde_json::json!({"handle": id, "offset": 3}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+}
@@ -33,14 +33,29 @@ impl ToolRegistry {
         }
     }
 
+    /// Insert a tool, replacing any tool already registered under the same name.
+    /// Returns the replaced tool. Callers already holding the old `Arc` keep
+    /// using it, so in-flight calls are unaffected.
+    pub fn replace(&self, tool: Arc<dyn Tool>) -> Option<Arc<dyn Tool>> {
+        self.tools.insert(tool.name().to_string(), tool)
+    }
+
+    /// Remove a tool by name, returning it if it was registered.
+    /// In-flight calls holding the tool's `Arc` run to completion.
+    pub fn unregister(&self, name: &str) -> Option<Arc<dyn Tool>> {
+        self.tools.remove(name).map(|(_, tool)| tool)
+    }
+
     /// Get a tool by name.
     pub fn get(&self, name: &str) -> Option<Arc<dyn Tool>> {
         self.tools.get(name).map(|r| Arc::clone(r.value()))
     }
 
-    /// List all registered tools as ToolInfo.
+    /// List all registered tools as ToolInfo, sorted by name so external
+    /// clients see a stable order.
     pub fn list(&self) -> Vec<ToolInfo> {
-        self.tools
+        let mut tools: Vec<ToolInfo> = self
+            .tools
             .iter()
             .map(|entry| {
                 let tool = entry.value();
@@ -52,7 +67,9 @@ impl ToolRegistry {
                     param_summary: tool.param_summary(),
                 }
             })
-            .collect()
+            .collect();
+        tools.sort_by(|a, b| a.name.cmp(&b.name));
+        tools
     }
 
     /// Get all tools as a Vec for passing to agent builders.
@@ -145,6 +162,17 @@ mod tests {
         assert!(names.contains(&"beta"));
     }
 
+    #[test]
+    fn list_sorted_by_name() {
+        let registry = ToolRegistry::new();
+        for name in ["web_search", "file_read", "shell", "memory_store"] {
+            registry.register(Arc::new(FakeTool::new(name))).unwrap();
+        }
+        let names: Vec<String> = registry.list().into_iter().map(|t| t.name).collect();
+        assert_eq!(names, ["file_read", "memory_store", "shell", "web_search"]);
+        assert_eq!(registry.list()[0].parameters, json!({"type": "object"}));
+    }
+
     #[test]
     fn get_unknown_returns_none() {
         let registry = ToolRegistry::new();
@@ -198,4 +226,24 @@ mod tests {
         assert_eq!(success_count.load(Ordering::SeqCst), 1);
         assert_eq!(registry.len(), 1);
     }
+
+    #[test]
+    fn unregister_keeps_in_flight_handle() {
+        let registry = ToolRegistry::new();
+        registry.register(Arc::new(FakeTool::new("hot"))).unwrap();
+        let in_flight = registry.get("hot").unwrap();
+
+        assert!(registry.unregister("hot").is_some());
+        assert!(registry.get("hot").is_none());
+        assert!(registry.unregister("hot").is_none());
+        assert_eq!(in_flight.name(), "hot");
+    }
+
+    #[test]
+    fn replace_swaps_existing_tool() {
+        let registry = ToolRegistry::new();
+        assert!(registry.replace(Arc::new(FakeTool::new("swap"))).is_none());
+        assert!(registry.replace(Arc::new(FakeTool::new("swap"))).is_some());
+        assert_eq!(registry.len(), 1);
+    }
 }
@@ -31,7 +31,7 @@ impl Tool for SchedulerTool {
     }
 
     fn description(&self) -> &str {
-        "Create, list, update, delete, toggle, or view history of scheduled jobs. Your context shows active jobs — check before creating duplicates. Use cron for complex schedules, interval for periodic, human for one-time events at a specific local datetime (e.g. schedule_type='human', datetime='2026-03-20T00:53'). Human schedules auto-delete after execution. Use update to modify an existing job's name, schedule, or payload."
+        "Create, list, update, delete, toggle, or view history of scheduled jobs. Your context shows active jobs — check before creating duplicates. Use cron for complex schedules, interval for periodic, human for one-time events at a specific local datetime (e.g. schedule_type='human', datetime='2026-03-20T00:53'), once for an exact RFC 3339 instant (e.g. schedule_type='once', at='2026-03-20T09:00:00Z'). Human and once schedules auto-delete after execution. Use update to modify an existing job's name, schedule, or payload."
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -53,13 +53,17 @@ impl Tool for SchedulerTool {
                 },
                 "schedule_type": {
                     "type": "string",
-                    "enum": ["cron", "interval", "human"],
-                    "description": "Schedule type (required for create). Use 'human' for one-time events at a specific local datetime."
+                    "enum": ["cron", "interval", "human", "once"],
+                    "description": "Schedule type (required for create). Use 'human' for one-time events at a specific local datetime, 'once' for an exact RFC 3339 instant."
                 },
                 "cron_expr": {
                     "type": "string",
                     "description": "Cron expression (required if schedule_type=cron)"
                 },
+                "tz": {
+                    "type": "string",
+                    "description": "IANA timezone for the cron expression, e.g. 'America/New_York' (optional, defaults to local time)"
+                },
                 "interval_secs": {
                     "type": "integer",
                     "description": "Interval in seconds (required if schedule_type=interval)"
@@ -68,9 +72,13 @@ impl Tool for SchedulerTool {
                     "type": "string",
                     "description": "Local datetime for human schedule (required if schedule_type=human), e.g. '2026-03-20T00:53' or '2026-03-20 14:30'"
                 },
+                "at": {
+                    "type": "string",
+                    "description": "RFC 3339 instant for once schedule (required if schedule_type=once), e.g. '2026-03-20T09:00:00Z'. A past instant fires immediately"
+                },
                 "payload_type": {
                     "type": "string",
-                    "enum": ["heartbeat", "agent_turn", "notify", "send_via_channel"],
+                    "enum": ["heartbeat", "agent_turn", "notify", "send_via_channel", "channel_notify", "daily_summary"],
                     "description": "What the job does when it fires (required for create)"
                 },
                 "prompt": {
@@ -79,23 +87,36 @@ impl Tool for SchedulerTool {
                 },
                 "message": {
                     "type": "string",
-                    "description": "Message for notify or send_via_channel payload"
+                    "description": "Message for notify, send_via_channel or channel_notify payload"
                 },
                 "channel": {
                     "type": "string",
-                    "description": "Channel name for send_via_channel payload"
+                    "description": "Channel name for send_via_channel or channel_notify payload"
+                },
+                "recipient": {
+                    "type": "string",
+                    "description": "Chat or channel ID for channel_notify payload (sends the message as-is, no agent turn)"
                 },
                 "one_shot": {
                     "type": "boolean",
                     "description": "Set to true for one-time events (e.g. 'remind me today/tonight/tomorrow at X'). The job auto-deletes after its first successful execution. ALWAYS use this for non-recurring reminders."
                 },
+                "priority": {
+                    "type": "integer",
+                    "description": "Run order when several jobs are due at once (higher first, default 0; heartbeats default to -10)"
+                },
                 "active_hours_start": {
                     "type": "integer",
-                    "description": "Start hour (0-23) for active hours window"
+                    "description": "Start hour (0-23) for a single active hours window"
                 },
                 "active_hours_end": {
                     "type": "integer",
-                    "description": "End hour (0-23) for active hours window"
+                    "description": "End hour (0-23) for a single active hours window"
+                },
+                "active_hours": {
+                    "type": "array",
+                    "description": "Several active hours windows, e.g. [{\"start_hour\": 9, \"end_hour\": 17, \"days\": [\"Mon\",\"Tue\",\"Wed\",\"Thu\",\"Fri\"]}, {\"start_hour\": 8, \"end_hour\": 12, \"days\": [\"Sat\"]}]. Optional per window: start_minute, end_minute, tz (IANA). Overrides active_hours_start/end",
+                    "items": {"type": "object"}
                 }
             },
             "required": ["action"]
@@ -166,6 +187,7 @@ impl SchedulerTool {
                 })?;
                 Schedule::Cron {
                     expr: expr.to_string(),
+                    tz: args["tz"].as_str().map(String::from),
                 }
             }
             "interval" => {
@@ -182,15 +204,19 @@ impl SchedulerTool {
                     datetime: datetime.to_string(),
                 }
             }
+            "once" => Schedule::Once {
+                at: parse_once_at(args)?,
+            },
             other => {
                 return Ok(ToolResult::err(format!(
-                    "Unknown schedule_type '{other}'. Valid: cron, interval, human"
+                    "Unknown schedule_type '{other}'. Valid: cron, interval, human, once"
                 )));
             }
         };
 
         let payload = match payload_type {
             "heartbeat" => JobPayload::Heartbeat,
+            "daily_summary" => JobPayload::DailySummary,
             "agent_turn" => {
                 let prompt = args["prompt"].as_str().ok_or_else(|| {
                     ZeniiError::Validation("missing 'prompt' for agent_turn payload".into())
@@ -219,26 +245,33 @@ impl SchedulerTool {
                     message: message.to_string(),
                 }
             }
+            "channel_notify" => {
+                let channel = args["channel"].as_str().ok_or_else(|| {
+                    ZeniiError::Validation("missing 'channel' for channel_notify".into())
+                })?;
+                let recipient = args["recipient"].as_str().ok_or_else(|| {
+                    ZeniiError::Validation("missing 'recipient' for channel_notify".into())
+                })?;
+                let message = args["message"].as_str().ok_or_else(|| {
+                    ZeniiError::Validation("missing 'message' for channel_notify".into())
+                })?;
+                JobPayload::ChannelNotify {
+                    channel: channel.to_string(),
+                    recipient: recipient.to_string(),
+                    message: message.to_string(),
+                }
+            }
             other => {
                 return Ok(ToolResult::err(format!(
-                    "Unknown payload_type '{other}'. Valid: heartbeat, agent_turn, notify, send_via_channel"
+                    "Unknown payload_type '{other}'. Valid: heartbeat, agent_turn, notify, send_via_channel, channel_notify, daily_summary"
                 )));
             }
         };
 
-        let active_hours = match (
-            args["active_hours_start"].as_u64(),
-            args["active_hours_end"].as_u64(),
-        ) {
-            (Some(start), Some(end)) => Some(ActiveHours {
-                start_hour: start as u8,
-                end_hour: end as u8,
-            }),
-            _ => None,
-        };
+        let active_hours = parse_active_hours(args)?;
 
-        let delete_after_run =
-            args["one_shot"].as_bool().unwrap_or(false) || schedule_type == "human";
+        let delete_after_run = args["one_shot"].as_bool().unwrap_or(false)
+            || matches!(schedule_type, "human" | "once");
 
         let job = ScheduledJob {
             id: uuid::Uuid::new_v4().to_string(),
@@ -252,6 +285,7 @@ impl SchedulerTool {
             active_hours,
             delete_after_run,
             timeout_secs: None,
+            priority: args["priority"].as_i64().map(|p| p as i32),
         };
 
         match self.scheduler.add_job(job).await {
@@ -281,6 +315,7 @@ impl SchedulerTool {
                 })?;
                 Schedule::Cron {
                     expr: expr.to_string(),
+                    tz: args["tz"].as_str().map(String::from),
                 }
             }
             "interval" => {
@@ -297,15 +332,19 @@ impl SchedulerTool {
                     datetime: datetime.to_string(),
                 }
             }
+            "once" => Schedule::Once {
+                at: parse_once_at(args)?,
+            },
             other => {
                 return Ok(ToolResult::err(format!(
-                    "Unknown schedule_type '{other}'. Valid: cron, interval, human"
+                    "Unknown schedule_type '{other}'. Valid: cron, interval, human, once"
                 )));
             }
         };
 
         let payload = match payload_type {
             "heartbeat" => JobPayload::Heartbeat,
+            "daily_summary" => JobPayload::DailySummary,
             "agent_turn" => {
                 let prompt = args["prompt"].as_str().ok_or_else(|| {
                     ZeniiError::Validation("missing 'prompt' for agent_turn payload".into())
@@ -334,26 +373,33 @@ impl SchedulerTool {
                     message: message.to_string(),
                 }
             }
+            "channel_notify" => {
+                let channel = args["channel"].as_str().ok_or_else(|| {
+                    ZeniiError::Validation("missing 'channel' for channel_notify".into())
+                })?;
+                let recipient = args["recipient"].as_str().ok_or_else(|| {
+                    ZeniiError::Validation("missing 'recipient' for channel_notify".into())
+                })?;
+                let message = args["message"].as_str().ok_or_else(|| {
+                    ZeniiError::Validation("missing 'message' for channel_notify".into())
+                })?;
+                JobPayload::ChannelNotify {
+                    channel: channel.to_string(),
+                    recipient: recipient.to_string(),
+                    message: message.to_string(),
+                }
+            }
             other => {
                 return Ok(ToolResult::err(format!(
-                    "Unknown payload_type '{other}'. Valid: heartbeat, agent_turn, notify, send_via_channel"
+                    "Unknown payload_type '{other}'. Valid: heartbeat, agent_turn, notify, send_via_channel, channel_notify, daily_summary"
                 )));
             }
         };
 
-        let active_hours = match (
-            args["active_hours_start"].as_u64(),
-            args["active_hours_end"].as_u64(),
-        ) {
-            (Some(start), Some(end)) => Some(ActiveHours {
-                start_hour: start as u8,
-                end_hour: end as u8,
-            }),
-            _ => None,
-        };
+        let active_hours = parse_active_hours(args)?;
 
-        let delete_after_run =
-            args["one_shot"].as_bool().unwrap_or(false) || schedule_type == "human";
+        let delete_after_run = args["one_shot"].as_bool().unwrap_or(false)
+            || matches!(schedule_type, "human" | "once");
 
         let job = ScheduledJob {
             id: job_id.to_string(),
@@ -367,6 +413,7 @@ impl SchedulerTool {
             active_hours,
             delete_after_run,
             timeout_secs: None,
+            priority: args["priority"].as_i64().map(|p| p as i32),
         };
 
         match self.scheduler.update_job(job_id, job).await {
@@ -382,6 +429,40 @@ impl SchedulerTool {
     }
 }
 
+/// Active hours from the `active_hours` list, or the single window given by
+/// `active_hours_start` / `active_hours_end`.
+fn parse_active_hours(args: &serde_json::Value) -> Result<Vec<ActiveHours>> {
+    if let Some(windows) = args.get("active_hours").filter(|v| !v.is_null()) {
+        return crate::scheduler::traits::deserialize_active_hours(windows)
+            .map_err(|e| ZeniiError::Validation(format!("invalid 'active_hours': {e}")));
+    }
+    Ok(
+        match (
+            args["active_hours_start"].as_u64(),
+            args["active_hours_end"].as_u64(),
+        ) {
+            (Some(start), Some(end)) => vec![ActiveHours {
+                start_hour: start as u8,
+                end_hour: end as u8,
+                ..Default::default()
+            }],
+            _ => Vec::new(),
+        },
+    )
+}
+
+/// Parse the RFC 3339 `at` argument of a `once` schedule.
+fn parse_once_at(args: &serde_json::Value) -> Result<chrono::DateTime<chrono::Utc>> {
+    let at = args["at"]
+        .as_str()
+        .ok_or_else(|| ZeniiError::Validation("missing 'at' for once schedule".into()))?;
+    chrono::DateTime::parse_from_rfc3339(at)
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .map_err(|e| {
+            ZeniiError::Validation(format!("invalid 'at' '{at}' (expected RFC 3339): {e}"))
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -640,6 +721,67 @@ mod tests {
         assert!(result.output.contains("not found"));
     }
 
+    // 17.10 — Once schedule takes an RFC 3339 `at` and auto-deletes
+    #[tokio::test]
+    async fn scheduler_tool_create_once() {
+        let (_dir, tool) = setup().await;
+        let result = tool
+            .execute(json!({
+                "action": "create",
+                "name": "launch",
+                "schedule_type": "once",
+                "at": "2030-06-01T09:00:00+02:00",
+                "payload_type": "notify",
+                "message": "go"
+            }))
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let jobs = tool.scheduler.list_jobs().await;
+        assert!(jobs[0].delete_after_run);
+        assert_eq!(
+            jobs[0].next_run.unwrap().to_rfc3339(),
+            "2030-06-01T07:00:00+00:00"
+        );
+
+        let bad = tool
+            .execute(json!({
+                "action": "create",
+                "name": "bad",
+                "schedule_type": "once",
+                "at": "tomorrow",
+                "payload_type": "heartbeat"
+            }))
+            .await;
+        assert!(bad.is_err());
+    }
+
+    // 17.11 — Several active hours windows can be set at once
+    #[tokio::test]
+    async fn scheduler_tool_multiple_active_hours() {
+        let (_dir, tool) = setup().await;
+        let result = tool
+            .execute(json!({
+                "action": "create",
+                "name": "office-hours",
+                "schedule_type": "interval",
+                "interval_secs": 300,
+                "payload_type": "heartbeat",
+                "active_hours": [
+                    {"start_hour": 9, "end_hour": 17, "days": ["Mon", "Tue", "Wed", "Thu", "Fri"]},
+                    {"start_hour": 8, "end_hour": 12, "days": ["Sat"]}
+                ]
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{}", result.output);
+
+        let jobs = tool.scheduler.list_jobs().await;
+        assert_eq!(jobs[0].active_hours.len(), 2);
+        assert_eq!(jobs[0].active_hours[1].days, vec![chrono::Weekday::Sat]);
+    }
+
     // 17.8 — Tool name/description/schema validation
     #[tokio::test]
     async fn scheduler_tool_schema() {
@@ -24,6 +24,10 @@ impl Tool for SystemInfoTool {
         "system_info"
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     fn description(&self) -> &str {
         "Get system information. Date/time, OS, hostname, and architecture are already in your context — only use this for cpu_count, memory, or env queries."
     }
@@ -29,10 +29,14 @@ impl ToolResult {
     }
 }
 
+/// A tool as described to external clients by `GET /tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(utoipa::ToSchema))]
 pub struct ToolInfo {
     pub name: String,
     pub description: String,
+    /// JSON Schema of the tool's arguments.
+    #[cfg_attr(feature = "api-docs", schema(value_type = Object))]
     pub parameters: serde_json::Value,
     pub risk_level: RiskLevel,
     /// Human-readable parameter summary, e.g. `"(query: string, max_results?: number)"`.
@@ -60,6 +64,20 @@ pub trait Tool: Send + Sync {
         None
     }
 
+    /// Whether this tool has no side effects. Read-only calls requested in the
+    /// same model turn may run concurrently; all others run one at a time.
+    /// Default: false.
+    fn is_read_only(&self) -> bool {
+        false
+    }
+
+    /// Whether this tool enforces its own execution limit. Such tools (plugin
+    /// processes, which must be killed on expiry) are exempt from the agent's
+    /// `security_tool_timeout_secs` wrapper. Default: false.
+    fn enforces_timeout(&self) -> bool {
+        false
+    }
+
     /// Return a concise human-readable parameter summary for NL prompt generation,
     /// e.g. `"(query: string, max_results?: number)"`.
     ///
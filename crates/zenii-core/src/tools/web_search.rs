@@ -1,6 +1,8 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
+use serde::Serialize;
 use tracing::{debug, info, warn};
 use websearch::SearchOptions;
 
@@ -9,33 +11,204 @@ use crate::{Result, ZeniiError};
 
 use super::traits::{Tool, ToolResult};
 
-/// Search provider used in the cascade.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum SearchProvider {
-    Tavily,
-    Brave,
-    DuckDuckGo,
+/// A single search result returned to the agent.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchHit {
+    pub title: String,
+    pub url: String,
+    pub snippet: String,
 }
 
-impl std::fmt::Display for SearchProvider {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Tavily => write!(f, "Tavily"),
-            Self::Brave => write!(f, "Brave"),
-            Self::DuckDuckGo => write!(f, "DuckDuckGo"),
-        }
+/// A web search backend.
+#[async_trait]
+pub trait SearchProvider: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn search(
+        &self,
+        query: &str,
+        num_results: usize,
+        timeout_secs: u64,
+    ) -> Result<Vec<SearchHit>>;
+}
+
+/// Run a query through a provider from the `websearch` crate.
+async fn websearch_crate_search(
+    name: &str,
+    provider: Box<dyn websearch::SearchProvider>,
+    query: &str,
+    num_results: usize,
+    timeout_secs: u64,
+) -> Result<Vec<SearchHit>> {
+    let options = SearchOptions {
+        query: query.to_string(),
+        provider,
+        max_results: Some(num_results as u32),
+        timeout: Some(timeout_secs * 1000),
+        ..Default::default()
+    };
+    let results = websearch::web_search(options)
+        .await
+        .map_err(|e| ZeniiError::Tool(format!("{name} search failed: {e}")))?;
+    Ok(results
+        .into_iter()
+        .take(num_results)
+        .map(|r| SearchHit {
+            title: r.title,
+            url: r.url,
+            snippet: r.snippet.unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Tavily search API (`api_key:tavily`).
+pub struct TavilySearch {
+    api_key: String,
+}
+
+#[async_trait]
+impl SearchProvider for TavilySearch {
+    fn name(&self) -> &str {
+        "Tavily"
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        num_results: usize,
+        timeout_secs: u64,
+    ) -> Result<Vec<SearchHit>> {
+        let tavily = websearch::providers::tavily::TavilyProvider::new(&self.api_key)
+            .map_err(|e| ZeniiError::Tool(format!("Tavily provider init failed: {e}")))?;
+        websearch_crate_search(
+            self.name(),
+            Box::new(tavily),
+            query,
+            num_results,
+            timeout_secs,
+        )
+        .await
+    }
+}
+
+/// Brave Search API (`api_key:brave`).
+pub struct BraveSearch {
+    api_key: String,
+}
+
+#[async_trait]
+impl SearchProvider for BraveSearch {
+    fn name(&self) -> &str {
+        "Brave"
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        num_results: usize,
+        timeout_secs: u64,
+    ) -> Result<Vec<SearchHit>> {
+        let brave = websearch::providers::brave::BraveProvider::new(&self.api_key)
+            .map_err(|e| ZeniiError::Tool(format!("Brave provider init failed: {e}")))?;
+        websearch_crate_search(
+            self.name(),
+            Box::new(brave),
+            query,
+            num_results,
+            timeout_secs,
+        )
+        .await
+    }
+}
+
+/// DuckDuckGo HTML search (no key required).
+pub struct DuckDuckGoSearch;
+
+#[async_trait]
+impl SearchProvider for DuckDuckGoSearch {
+    fn name(&self) -> &str {
+        "DuckDuckGo"
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        num_results: usize,
+        timeout_secs: u64,
+    ) -> Result<Vec<SearchHit>> {
+        let ddg = websearch::providers::duckduckgo::DuckDuckGoProvider::new();
+        websearch_crate_search(self.name(), Box::new(ddg), query, num_results, timeout_secs).await
+    }
+}
+
+/// Self-hosted SearXNG instance queried through its JSON API
+/// (`GET {base_url}/search?format=json`). The instance must have the `json`
+/// format enabled in its `settings.yml`.
+pub struct SearxngSearch {
+    base_url: String,
+}
+
+#[async_trait]
+impl SearchProvider for SearxngSearch {
+    fn name(&self) -> &str {
+        "SearXNG"
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        num_results: usize,
+        timeout_secs: u64,
+    ) -> Result<Vec<SearchHit>> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout_secs))
+            .build()
+            .map_err(|e| ZeniiError::Tool(format!("SearXNG client init failed: {e}")))?;
+        let url = reqwest::Url::parse_with_params(
+            &format!("{}/search", self.base_url.trim_end_matches('/')),
+            &[("q", query), ("format", "json")],
+        )
+        .map_err(|e| ZeniiError::Tool(format!("invalid SearXNG URL: {e}")))?;
+        let body: serde_json::Value = client
+            .get(url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| ZeniiError::Tool(format!("SearXNG search failed: {e}")))?
+            .json()
+            .await
+            .map_err(|e| ZeniiError::Tool(format!("SearXNG returned invalid JSON: {e}")))?;
+
+        Ok(body["results"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|r| {
+                Some(SearchHit {
+                    title: r["title"].as_str()?.to_string(),
+                    url: r["url"].as_str()?.to_string(),
+                    snippet: r["content"].as_str().unwrap_or_default().to_string(),
+                })
+            })
+            .take(num_results)
+            .collect())
     }
 }
 
-/// Web search tool with provider cascade: Tavily -> Brave -> DuckDuckGo.
+/// Web search tool backed by a pluggable [`SearchProvider`].
 ///
-/// Uses credential store to resolve API keys at runtime. Falls back to
-/// DuckDuckGo (no key required) when no paid provider keys are available.
-/// Backed by the `websearch` crate for all provider implementations.
+/// With `web_search_provider = "auto"` (the default) providers cascade
+/// Tavily -> Brave -> SearXNG -> DuckDuckGo based on which credentials or URL
+/// are available, and failures fall back to DuckDuckGo. An explicit provider
+/// is used as-is; if its API key or URL is missing the tool reports that
+/// search is not configured.
 pub struct WebSearchTool {
     credentials: Arc<dyn CredentialStore>,
     timeout_secs: u64,
     max_results: usize,
+    provider: String,
+    searxng_url: Option<String>,
 }
 
 impl WebSearchTool {
@@ -48,86 +221,76 @@ impl WebSearchTool {
             credentials,
             timeout_secs,
             max_results,
+            provider: "auto".into(),
+            searxng_url: None,
         }
     }
 
-    /// Select the best available search provider based on stored credentials.
-    async fn select_provider(&self) -> (SearchProvider, Option<String>) {
-        if let Ok(Some(key)) = self.credentials.get("api_key:tavily").await
-            && !key.is_empty()
-        {
-            return (SearchProvider::Tavily, Some(key));
-        }
-        if let Ok(Some(key)) = self.credentials.get("api_key:brave").await
-            && !key.is_empty()
-        {
-            return (SearchProvider::Brave, Some(key));
+    /// Select the provider (`auto`, `tavily`, `brave`, `searxng`, `duckduckgo`
+    /// or `none`) and the SearXNG base URL.
+    pub fn with_provider(mut self, provider: &str, searxng_url: Option<String>) -> Self {
+        self.provider = provider.to_lowercase();
+        self.searxng_url = searxng_url.filter(|u| !u.trim().is_empty());
+        self
+    }
+
+    async fn api_key(&self, name: &str) -> Option<String> {
+        match self.credentials.get(&format!("api_key:{name}")).await {
+            Ok(Some(key)) if !key.is_empty() => Some(key),
+            _ => None,
         }
-        (SearchProvider::DuckDuckGo, None)
     }
 
-    /// Execute search using the `websearch` crate with the selected provider.
-    async fn do_search(
-        &self,
-        query: &str,
-        num_results: usize,
-        provider: SearchProvider,
-        api_key: Option<&str>,
-    ) -> Result<Vec<serde_json::Value>> {
-        let options = match provider {
-            SearchProvider::Tavily => {
-                let key = api_key.unwrap_or("");
-                let tavily = websearch::providers::tavily::TavilyProvider::new(key)
-                    .map_err(|e| ZeniiError::Tool(format!("Tavily provider init failed: {e}")))?;
-                SearchOptions {
-                    query: query.to_string(),
-                    provider: Box::new(tavily),
-                    max_results: Some(num_results as u32),
-                    timeout: Some(self.timeout_secs * 1000),
-                    ..Default::default()
-                }
-            }
-            SearchProvider::Brave => {
-                let key = api_key.unwrap_or("");
-                let brave = websearch::providers::brave::BraveProvider::new(key)
-                    .map_err(|e| ZeniiError::Tool(format!("Brave provider init failed: {e}")))?;
-                SearchOptions {
-                    query: query.to_string(),
-                    provider: Box::new(brave),
-                    max_results: Some(num_results as u32),
-                    timeout: Some(self.timeout_secs * 1000),
-                    ..Default::default()
+    /// Resolve the configured provider. Returns `None` when search is not
+    /// configured (provider disabled, or its API key / URL is missing).
+    async fn select_provider(&self) -> Option<Box<dyn SearchProvider>> {
+        let searxng = || {
+            self.searxng_url
+                .clone()
+                .map(|base_url| Box::new(SearxngSearch { base_url }) as Box<dyn SearchProvider>)
+        };
+        match self.provider.as_str() {
+            "auto" => {
+                if let Some(api_key) = self.api_key("tavily").await {
+                    return Some(Box::new(TavilySearch { api_key }));
                 }
-            }
-            SearchProvider::DuckDuckGo => {
-                let ddg = websearch::providers::duckduckgo::DuckDuckGoProvider::new();
-                SearchOptions {
-                    query: query.to_string(),
-                    provider: Box::new(ddg),
-                    max_results: Some(num_results as u32),
-                    timeout: Some(self.timeout_secs * 1000),
-                    ..Default::default()
+                if let Some(api_key) = self.api_key("brave").await {
+                    return Some(Box::new(BraveSearch { api_key }));
                 }
+                searxng().or_else(|| Some(Box::new(DuckDuckGoSearch)))
             }
-        };
-
-        let results = websearch::web_search(options)
-            .await
-            .map_err(|e| ZeniiError::Tool(format!("{provider} search failed: {e}")))?;
+            "tavily" => self
+                .api_key("tavily")
+                .await
+                .map(|api_key| Box::new(TavilySearch { api_key }) as Box<dyn SearchProvider>),
+            "brave" => self
+                .api_key("brave")
+                .await
+                .map(|api_key| Box::new(BraveSearch { api_key }) as Box<dyn SearchProvider>),
+            "searxng" => searxng(),
+            "duckduckgo" => Some(Box::new(DuckDuckGoSearch)),
+            _ => None,
+        }
+    }
 
-        let json_results: Vec<serde_json::Value> = results
-            .into_iter()
-            .take(num_results)
-            .map(|r| {
-                serde_json::json!({
-                    "title": r.title,
-                    "url": r.url,
-                    "snippet": r.snippet.unwrap_or_default(),
-                })
-            })
-            .collect();
+    fn not_configured(&self) -> ToolResult {
+        let hint = match self.provider.as_str() {
+            "tavily" | "brave" => format!(
+                "no API key stored for '{}' (credential `api_key:{}`)",
+                self.provider, self.provider
+            ),
+            "searxng" => "`web_search_searxng_url` is not set".to_string(),
+            _ => "set `web_search_provider` in config".to_string(),
+        };
+        ToolResult::err(format!("Web search is not configured: {hint}"))
+    }
 
-        Ok(json_results)
+    fn render(results: &[SearchHit]) -> ToolResult {
+        if results.is_empty() {
+            return ToolResult::ok("No search results found.");
+        }
+        let json = serde_json::to_string_pretty(results).unwrap_or_else(|_| "[]".to_string());
+        ToolResult::ok(json)
     }
 }
 
@@ -137,6 +300,10 @@ impl Tool for WebSearchTool {
         "web_search"
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     fn description(&self) -> &str {
         "Search the web and return multiple results (title, URL, snippet) in a single call. \
          IMPORTANT: For time-sensitive queries (news, events, releases, 'today', 'recent', 'latest'), \
@@ -175,65 +342,50 @@ impl Tool for WebSearchTool {
             .and_then(|v| v.as_u64())
             .map(|n| n as usize)
             .unwrap_or(5)
-            .min(self.max_results);
+            .clamp(1, self.max_results.max(1));
 
-        let (provider, api_key) = self.select_provider().await;
-        info!(provider = %provider, query = %query, "Performing web search");
+        let Some(provider) = self.select_provider().await else {
+            return Ok(self.not_configured());
+        };
+        let name = provider.name().to_string();
+        info!(provider = %name, query = %query, "Performing web search");
 
-        let results = self
-            .do_search(query, num_results, provider, api_key.as_deref())
-            .await;
+        let results = provider.search(query, num_results, self.timeout_secs).await;
 
+        // Only the auto cascade falls back to DuckDuckGo; an explicitly chosen
+        // provider reports its own result.
+        let can_fall_back = self.provider == "auto" && name != DuckDuckGoSearch.name();
         match results {
-            Ok(results) if results.is_empty() => {
-                if provider != SearchProvider::DuckDuckGo {
-                    warn!(provider = %provider, "No results, falling back to DuckDuckGo");
-                    match self
-                        .do_search(query, num_results, SearchProvider::DuckDuckGo, None)
-                        .await
-                    {
-                        Ok(fallback) if !fallback.is_empty() => {
-                            let json = serde_json::to_string_pretty(&fallback)
-                                .unwrap_or_else(|_| "[]".to_string());
-                            Ok(ToolResult::ok(json))
-                        }
-                        Ok(_) => Ok(ToolResult::ok("No search results found.")),
-                        Err(e) => {
-                            warn!("DuckDuckGo fallback also failed: {e}");
-                            Ok(ToolResult::ok("No search results found."))
-                        }
+            Ok(results) if results.is_empty() && can_fall_back => {
+                warn!(provider = %name, "No results, falling back to DuckDuckGo");
+                match DuckDuckGoSearch
+                    .search(query, num_results, self.timeout_secs)
+                    .await
+                {
+                    Ok(fallback) => Ok(Self::render(&fallback)),
+                    Err(e) => {
+                        warn!("DuckDuckGo fallback also failed: {e}");
+                        Ok(Self::render(&[]))
                     }
-                } else {
-                    Ok(ToolResult::ok("No search results found."))
                 }
             }
             Ok(results) => {
-                debug!(count = results.len(), provider = %provider, "Search results received");
-                let json =
-                    serde_json::to_string_pretty(&results).unwrap_or_else(|_| "[]".to_string());
-                Ok(ToolResult::ok(json))
+                debug!(count = results.len(), provider = %name, "Search results received");
+                Ok(Self::render(&results))
             }
-            Err(e) => {
-                if provider != SearchProvider::DuckDuckGo {
-                    warn!(provider = %provider, error = %e, "Provider failed, falling back to DuckDuckGo");
-                    match self
-                        .do_search(query, num_results, SearchProvider::DuckDuckGo, None)
-                        .await
-                    {
-                        Ok(fallback) if !fallback.is_empty() => {
-                            let json = serde_json::to_string_pretty(&fallback)
-                                .unwrap_or_else(|_| "[]".to_string());
-                            Ok(ToolResult::ok(json))
-                        }
-                        Ok(_) => Ok(ToolResult::ok("No search results found.")),
-                        Err(fallback_err) => Err(ZeniiError::Tool(format!(
-                            "{provider} failed: {e}; DuckDuckGo fallback also failed: {fallback_err}"
-                        ))),
-                    }
-                } else {
-                    Err(e)
+            Err(e) if can_fall_back => {
+                warn!(provider = %name, error = %e, "Provider failed, falling back to DuckDuckGo");
+                match DuckDuckGoSearch
+                    .search(query, num_results, self.timeout_secs)
+                    .await
+                {
+                    Ok(fallback) => Ok(Self::render(&fallback)),
+                    Err(fallback_err) => Err(ZeniiError::Tool(format!(
+                        "{name} failed: {e}; DuckDuckGo fallback also failed: {fallback_err}"
+                    ))),
                 }
             }
+            Err(e) => Err(e),
         }
     }
 }
@@ -283,9 +435,8 @@ mod tests {
         creds.set("api_key:tavily", "tvly-test123").await.unwrap();
         creds.set("api_key:brave", "brave-test123").await.unwrap();
         let tool = tool_with_creds(creds.clone() as Arc<dyn CredentialStore>);
-        let (provider, key) = tool.select_provider().await;
-        assert_eq!(provider, SearchProvider::Tavily);
-        assert_eq!(key.unwrap(), "tvly-test123");
+        let provider = tool.select_provider().await.unwrap();
+        assert_eq!(provider.name(), "Tavily");
     }
 
     // WS.5
@@ -294,18 +445,16 @@ mod tests {
         let creds = Arc::new(InMemoryCredentialStore::new());
         creds.set("api_key:brave", "brave-test123").await.unwrap();
         let tool = tool_with_creds(creds.clone() as Arc<dyn CredentialStore>);
-        let (provider, key) = tool.select_provider().await;
-        assert_eq!(provider, SearchProvider::Brave);
-        assert_eq!(key.unwrap(), "brave-test123");
+        let provider = tool.select_provider().await.unwrap();
+        assert_eq!(provider.name(), "Brave");
     }
 
     // WS.6
     #[tokio::test]
     async fn cascade_falls_to_duckduckgo() {
         let tool = tool_with_creds(mock_credentials());
-        let (provider, key) = tool.select_provider().await;
-        assert_eq!(provider, SearchProvider::DuckDuckGo);
-        assert!(key.is_none());
+        let provider = tool.select_provider().await.unwrap();
+        assert_eq!(provider.name(), "DuckDuckGo");
     }
 
     // WS.7
@@ -342,6 +491,78 @@ mod tests {
         assert_eq!(tool.max_results, 10);
     }
 
+    // WS.12 — auto cascade prefers SearXNG over DuckDuckGo when a URL is set
+    #[tokio::test]
+    async fn cascade_uses_searxng_when_configured() {
+        let tool = tool_with_creds(mock_credentials())
+            .with_provider("auto", Some("http://searx.local".into()));
+        let provider = tool.select_provider().await.unwrap();
+        assert_eq!(provider.name(), "SearXNG");
+    }
+
+    // WS.13 — explicit provider without credentials is "not configured"
+    #[tokio::test]
+    async fn explicit_provider_without_key_not_configured() {
+        for (provider, needle) in [
+            ("brave", "api_key:brave"),
+            ("tavily", "api_key:tavily"),
+            ("searxng", "web_search_searxng_url"),
+            ("none", "web_search_provider"),
+        ] {
+            let tool = tool_with_creds(mock_credentials()).with_provider(provider, None);
+            let result = tool
+                .execute(serde_json::json!({"query": "rust"}))
+                .await
+                .unwrap();
+            assert!(!result.success, "{provider} should not be configured");
+            assert!(result.output.contains("not configured"));
+            assert!(result.output.contains(needle), "{}", result.output);
+        }
+    }
+
+    // WS.14 — SearXNG JSON results are mapped and capped by num_results
+    #[tokio::test]
+    async fn searxng_returns_results() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = stream.read(&mut buf).await.unwrap();
+            let req = String::from_utf8_lossy(&buf[..n]).to_string();
+            assert!(req.starts_with("GET /search?q=rust&format=json"), "{req}");
+            let body = serde_json::json!({
+                "results": [
+                    { "title": "Rust", "url": "https://rust-lang.org", "content": "A language" },
+                    { "title": "Crates", "url": "https://crates.io" },
+                    { "title": "Docs", "url": "https://docs.rs", "content": "Docs" }
+                ]
+            })
+            .to_string();
+            let resp = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(resp.as_bytes()).await.unwrap();
+        });
+
+        let tool = tool_with_creds(mock_credentials())
+            .with_provider("searxng", Some(format!("http://{addr}/")));
+        let result = tool
+            .execute(serde_json::json!({"query": "rust", "num_results": 2}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        let hits: Vec<serde_json::Value> = serde_json::from_str(&result.output).unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0]["title"], "Rust");
+        assert_eq!(hits[0]["snippet"], "A language");
+        assert_eq!(hits[1]["url"], "https://crates.io");
+        assert_eq!(hits[1]["snippet"], "");
+    }
+
     // WS.10 — Integration test (requires network)
     #[tokio::test]
     #[ignore]
@@ -25,6 +25,10 @@ impl Tool for WikiSearchTool {
         "wiki"
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     fn description(&self) -> &str {
         "Search, retrieve, list, or query pages from the knowledge wiki. Use 'search' to find pages by keyword, 'get' to fetch a specific page by slug, 'list' to browse all available pages with their TLDRs, or 'query' to retrieve the full body of the most relevant pages for a natural-language question (pipe the output into an LLM for synthesis)."
     }
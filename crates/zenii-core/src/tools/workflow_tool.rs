@@ -248,6 +248,7 @@ impl WorkflowTool {
                 name: format!("workflow:{workflow_id}"),
                 schedule: Schedule::Cron {
                     expr: cron_expr.clone(),
+                    tz: None,
                 },
                 session_target: Default::default(),
                 payload: JobPayload::Workflow {
@@ -256,9 +257,10 @@ impl WorkflowTool {
                 enabled: true,
                 error_count: 0,
                 next_run: None,
-                active_hours: None,
+                active_hours: Vec::new(),
                 delete_after_run: false,
                 timeout_secs: None,
+                priority: None,
             };
             match sched.add_job(job).await {
                 Ok(job_id) => {
@@ -0,0 +1,257 @@
+// Per-agent workspace jail for file tools.
+// A sub-agent with a workspace root runs every tool call inside `scope()`; file
+// tools pass their resolved paths through `confine()`, which rejects anything
+// that resolves outside the root, regardless of the global SecurityPolicy.
+
+use std::future::Future;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::security::RiskLevel;
+use crate::{Result, ZeniiError};
+
+use super::traits::{Tool, ToolResult};
+
+tokio::task_local! {
+    static WORKSPACE_ROOT: PathBuf;
+}
+
+/// Run `fut` with `root` as the active workspace root. `None` runs it unconfined.
+pub async fn scope<F: Future>(root: Option<PathBuf>, fut: F) -> F::Output {
+    match root {
+        Some(root) => WORKSPACE_ROOT.scope(root, fut).await,
+        None => fut.await,
+    }
+}
+
+/// The workspace root of the current tool call, if any.
+pub fn current_root() -> Option<PathBuf> {
+    WORKSPACE_ROOT.try_with(|root| root.clone()).ok()
+}
+
+/// Canonicalize a workspace root, creating the directory if it does not exist.
+pub fn prepare_root(root: &Path) -> std::io::Result<PathBuf> {
+    std::fs::create_dir_all(root)?;
+    std::fs::canonicalize(root)
+}
+
+/// Confine a resolved path to the active workspace root.
+///
+/// Without a root the path is returned unchanged. Inside a workspace, relative
+/// paths are taken relative to the root, `..` components are rejected, and the
+/// deepest existing ancestor is canonicalized so symlinks cannot escape.
+pub fn confine(path: &str) -> Result<String> {
+    match current_root() {
+        Some(root) => confine_to(&root, Path::new(path)).map(|p| p.display().to_string()),
+        None => Ok(path.to_string()),
+    }
+}
+
+/// [`confine`] against an explicit root, for callers that leave the task scope
+/// (e.g. `spawn_blocking`) and so cannot read [`current_root`].
+pub(crate) fn confine_to(root: &Path, path: &Path) -> Result<PathBuf> {
+    if path.components().any(|c| matches!(c, Component::ParentDir)) {
+        return Err(ZeniiError::PolicyDenied(
+            "path traversal not allowed".into(),
+        ));
+    }
+    let joined = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        root.join(path)
+    };
+
+    // Canonicalize the longest existing prefix and re-append the rest, so a
+    // symlinked parent of a not-yet-created file is still resolved.
+    let mut existing = joined.as_path();
+    let mut rest = Vec::new();
+    let canonical = loop {
+        match std::fs::canonicalize(existing) {
+            Ok(canon) => break canon,
+            Err(_) => {
+                let Some(name) = existing.file_name() else {
+                    return Err(ZeniiError::PolicyDenied(format!(
+                        "cannot resolve path: {}",
+                        joined.display()
+                    )));
+                };
+                rest.push(name.to_owned());
+                existing = existing.parent().ok_or_else(|| {
+                    ZeniiError::PolicyDenied(format!("cannot resolve path: {}", joined.display()))
+                })?;
+            }
+        }
+    };
+    let effective = rest.into_iter().rev().fold(canonical, |p, c| p.join(c));
+
+    if effective.starts_with(root) {
+        Ok(effective)
+    } else {
+        Err(ZeniiError::PolicyDenied(format!(
+            "path is outside agent workspace: {}",
+            root.display()
+        )))
+    }
+}
+
+/// Wraps a tool so every call runs inside [`scope`] for a fixed workspace root.
+pub struct WorkspaceScopedTool {
+    inner: Arc<dyn Tool>,
+    root: PathBuf,
+}
+
+impl WorkspaceScopedTool {
+    pub fn new(inner: Arc<dyn Tool>, root: PathBuf) -> Self {
+        Self { inner, root }
+    }
+
+    /// Wrap every tool in `tools` with the same workspace root.
+    pub fn wrap_all(tools: Vec<Arc<dyn Tool>>, root: &Path) -> Vec<Arc<dyn Tool>> {
+        tools
+            .into_iter()
+            .map(|t| Arc::new(Self::new(t, root.to_path_buf())) as Arc<dyn Tool>)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Tool for WorkspaceScopedTool {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        self.inner.parameters_schema()
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> Result<ToolResult> {
+        scope(Some(self.root.clone()), self.inner.execute(args)).await
+    }
+
+    fn risk_level(&self) -> RiskLevel {
+        self.inner.risk_level()
+    }
+
+    fn needs_approval(&self, args: &serde_json::Value) -> Option<String> {
+        self.inner.needs_approval(args)
+    }
+
+    fn is_read_only(&self) -> bool {
+        self.inner.is_read_only()
+    }
+
+    fn enforces_timeout(&self) -> bool {
+        self.inner.enforces_timeout()
+    }
+
+    fn param_summary(&self) -> String {
+        self.inner.param_summary()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let root = std::fs::canonicalize(dir.path()).unwrap();
+        (dir, root)
+    }
+
+    #[test]
+    fn confine_without_root_is_passthrough() {
+        assert_eq!(confine("/etc/passwd").unwrap(), "/etc/passwd");
+    }
+
+    #[test]
+    fn relative_paths_resolve_under_root() {
+        let (_dir, root) = root();
+        let p = confine_to(&root, Path::new("notes/todo.md")).unwrap();
+        assert_eq!(p, root.join("notes/todo.md"));
+    }
+
+    #[test]
+    fn rejects_parent_dir_and_outside_paths() {
+        let (_dir, root) = root();
+        assert!(confine_to(&root, Path::new("../other")).is_err());
+        assert!(confine_to(&root, &root.join("a/../../x")).is_err());
+        assert!(confine_to(&root, Path::new("/etc/passwd")).is_err());
+        assert!(confine_to(&root, &root.join("inside.txt")).is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_symlink_escape() {
+        let (_dir, root) = root();
+        let (_other_dir, other) = self::root();
+        std::os::unix::fs::symlink(&other, root.join("link")).unwrap();
+        assert!(confine_to(&root, Path::new("link/secret.txt")).is_err());
+        assert!(confine_to(&root, Path::new("link")).is_err());
+    }
+
+    #[tokio::test]
+    async fn scoped_tool_jails_file_read() {
+        let (_dir, root) = root();
+        std::fs::write(root.join("ok.txt"), "inside").unwrap();
+        let policy = Arc::new(crate::security::policy::SecurityPolicy::default_policy());
+        let tool = WorkspaceScopedTool::new(
+            Arc::new(super::super::file_ops::FileReadTool::new(policy)),
+            root.clone(),
+        );
+
+        let ok = tool
+            .execute(serde_json::json!({"path": root.join("ok.txt").display().to_string()}))
+            .await
+            .unwrap();
+        assert!(ok.success);
+        assert_eq!(ok.output, "inside");
+
+        let (_other_dir, other) = self::root();
+        std::fs::write(other.join("secret.txt"), "other agent").unwrap();
+        let denied = tool
+            .execute(serde_json::json!({"path": other.join("secret.txt").display().to_string()}))
+            .await
+            .unwrap();
+        assert!(!denied.success);
+        assert!(denied.output.contains("outside agent workspace"));
+    }
+
+    struct SelfTimedTool;
+
+    #[async_trait]
+    impl Tool for SelfTimedTool {
+        fn name(&self) -> &str {
+            "self_timed"
+        }
+
+        fn description(&self) -> &str {
+            "Kills its own process on expiry"
+        }
+
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({"type": "object"})
+        }
+
+        async fn execute(&self, _args: serde_json::Value) -> Result<ToolResult> {
+            Ok(ToolResult::ok("done"))
+        }
+
+        fn enforces_timeout(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn scoped_tool_keeps_inner_timeout_handling() {
+        let (_dir, root) = root();
+        let tool = WorkspaceScopedTool::new(Arc::new(SelfTimedTool), root);
+        assert!(tool.enforces_timeout());
+    }
+}
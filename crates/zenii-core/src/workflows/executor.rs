@@ -11,6 +11,9 @@ use crate::{Result, ZeniiError};
 
 use super::definition::*;
 
+/// Error recorded on runs that were interrupted by a daemon shutdown or crash.
+pub const INTERRUPTED_RUN_ERROR: &str = "interrupted: Zenii stopped before the run finished";
+
 pub struct WorkflowExecutor {
     db: DbPool,
     max_steps: usize,
@@ -478,7 +481,7 @@ impl WorkflowExecutor {
         .await
     }
 
-    async fn persist_run_start(
+    pub(crate) async fn persist_run_start(
         &self,
         run_id: &str,
         workflow_id: &str,
@@ -520,6 +523,21 @@ impl WorkflowExecutor {
         .await
     }
 
+    /// Mark runs still `running` in the DB as failed. Called at boot: no run can be
+    /// in flight yet, so any such row was interrupted by a crash or hard kill.
+    pub async fn fail_interrupted_runs(&self) -> Result<usize> {
+        let completed_at = chrono::Utc::now().to_rfc3339();
+        db::with_db(&self.db, move |conn| {
+            let n = conn.execute(
+                "UPDATE workflow_runs SET status = 'failed', error = ?1, completed_at = ?2 \
+                 WHERE status = 'running'",
+                rusqlite::params![INTERRUPTED_RUN_ERROR, completed_at],
+            )?;
+            Ok(n)
+        })
+        .await
+    }
+
     /// Get run history for a workflow.
     pub async fn get_history(&self, workflow_id: &str) -> Result<Vec<WorkflowRun>> {
         let wid = workflow_id.to_string();
@@ -885,4 +903,35 @@ mod tests {
         assert!(details.is_some());
         assert_eq!(details.unwrap().step_results.len(), 1);
     }
+
+    // 5.38 — Runs left `running` by a crash are failed at boot
+    #[tokio::test]
+    async fn fail_interrupted_runs_marks_running_rows() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let pool = crate::db::init_pool(&dir.path().join("test.db")).unwrap();
+        crate::db::with_db(&pool, crate::db::run_migrations)
+            .await
+            .unwrap();
+        let executor = WorkflowExecutor::new(pool, 50, 300, 3);
+        executor
+            .persist_run_start("r1", "test", "Test", "2026-01-01T00:00:00Z")
+            .await
+            .unwrap();
+        executor
+            .persist_run_start("r2", "test", "Test", "2026-01-01T00:00:01Z")
+            .await
+            .unwrap();
+        executor
+            .persist_run_end("r2", "completed", None, "2026-01-01T00:00:02Z")
+            .await
+            .unwrap();
+
+        assert_eq!(executor.fail_interrupted_runs().await.unwrap(), 1);
+        let r1 = executor.get_run("r1").await.unwrap().unwrap();
+        assert_eq!(r1.status, WorkflowRunStatus::Failed);
+        assert_eq!(r1.error.as_deref(), Some(INTERRUPTED_RUN_ERROR));
+        assert!(r1.completed_at.is_some());
+        let r2 = executor.get_run("r2").await.unwrap().unwrap();
+        assert_eq!(r2.status, WorkflowRunStatus::Completed);
+    }
 }
@@ -425,11 +425,13 @@ mod tests {
                         "http://localhost:11434/v1",
                         "llama3",
                         false, // no API key required
+                        &Default::default(),
                         &creds,
                         &tools,
                         &config,
                         None,
                         None,
+                        None,
                     )
                     .await
                     .expect("agent construction should succeed for no-key provider")
@@ -641,11 +643,13 @@ mod tests {
                         "http://localhost:11434/v1",
                         "llama3",
                         false,
+                        &Default::default(),
                         &creds,
                         &tools,
                         &config,
                         None,
                         None,
+                        None,
                     )
                     .await
                     .unwrap()
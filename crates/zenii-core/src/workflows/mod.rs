@@ -146,7 +146,7 @@ impl WorkflowRegistry {
                 let job = ScheduledJob {
                     id: workflow_id.clone(),
                     name,
-                    schedule: Schedule::Cron { expr },
+                    schedule: Schedule::Cron { expr, tz: None },
                     session_target: crate::scheduler::traits::SessionTarget::Isolated,
                     payload: JobPayload::Workflow {
                         workflow_id: workflow_id.clone(),
@@ -154,9 +154,10 @@ impl WorkflowRegistry {
                     enabled: true,
                     error_count: 0,
                     next_run: None,
-                    active_hours: None,
+                    active_hours: Vec::new(),
                     delete_after_run: false,
                     timeout_secs: None,
+                    priority: None,
                 };
                 // Try update first; if the job doesn't exist yet, add it
                 if scheduler
@@ -69,7 +69,8 @@ async fn main() -> ExitCode {
     #[cfg(feature = "channels")]
     state.wire_channels();
     state.wire_notifications();
-    let gateway = GatewayServer::new(state);
+    state.watch_config();
+    let gateway = GatewayServer::new(Arc::clone(&state));
 
     // Graceful shutdown on SIGTERM/SIGINT
     let shutdown = async {
@@ -104,6 +105,12 @@ async fn main() -> ExitCode {
         return ExitCode::FAILURE;
     }
 
+    // Let in-flight workflow runs finish (or record them as failed) before exit
+    let drain_secs = state.config.load().gateway_shutdown_drain_secs;
+    state
+        .drain(std::time::Duration::from_secs(drain_secs))
+        .await;
+
     ExitCode::SUCCESS
 }
 
@@ -111,6 +111,7 @@ pub fn boot_gateway(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Erro
                 #[cfg(feature = "channels")]
                 state.wire_channels();
                 state.wire_notifications();
+                state.watch_config();
                 let gateway = zenii_core::gateway::GatewayServer::new(state);
 
                 info!("Starting embedded gateway on {host}:{port}");
@@ -50,6 +50,7 @@ pub const ONBOARD_CHANNELS: &[ChannelDef] = &[
             ("bot_token", "Bot Token", true),
             ("app_token", "App Token", true),
             ("allowed_channel_ids", "Allowed Channel IDs", false),
+            ("signing_secret", "Signing Secret", false),
         ],
     },
     ChannelDef {
@@ -99,6 +99,28 @@ describe("WebSocket chat streaming", () => {
     expect(ws.closed).toBe(true);
   });
 
+  it("sends a stream_id matching the connection", async () => {
+    const conn = await createChatStream("hello", "sess-1", callbacks);
+
+    const ws = MockWebSocket.instances[0];
+    ws.onopen!();
+
+    expect(JSON.parse(ws.sentMessages[0]).stream_id).toBe(conn.streamId);
+  });
+
+  it("finishes with the partial text on cancelled message", () => {
+    createChatStream("hello", "sess-1", callbacks);
+
+    const ws = MockWebSocket.instances[0];
+    ws.onmessage!({
+      data: JSON.stringify({ type: "cancelled", content: "Partial" }),
+    });
+
+    expect(callbacks.onToken).toHaveBeenCalledWith("Partial");
+    expect(callbacks.onDone).toHaveBeenCalled();
+    expect(ws.closed).toBe(true);
+  });
+
   it("calls onError and closes on error message", () => {
     createChatStream("hello", "sess-1", callbacks);
 
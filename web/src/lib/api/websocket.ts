@@ -6,10 +6,23 @@ export interface WsTextMessage {
   content: string;
 }
 
+/** Incremental assistant text; the following `text` message carries the full answer. */
+export interface WsTokenMessage {
+  type: "token";
+  delta: string;
+}
+
 export interface WsDoneMessage {
   type: "done";
 }
 
+/** Terminal message of a cancelled stream; `content` is the text generated so far. */
+export interface WsCancelledMessage {
+  type: "cancelled";
+  stream_id?: string;
+  content: string;
+}
+
 export interface WsErrorMessage {
   type: "error";
   error: string;
@@ -31,6 +44,8 @@ export interface WsToolResultMessage {
   output: string;
   success: boolean;
   duration_ms: number;
+  /** Present when `output` was truncated; fetch via GET /tools/results/{id}. */
+  full_output_id?: string;
 }
 
 export interface WsDelegationStartedMessage {
@@ -93,7 +108,9 @@ export interface WsWarningMessage {
 
 export type WsMessage =
   | WsTextMessage
+  | WsTokenMessage
   | WsDoneMessage
+  | WsCancelledMessage
   | WsErrorMessage
   | WsToolCallMessage
   | WsToolResultMessage
@@ -114,6 +131,7 @@ export interface ChatStreamCallbacks {
     output: string,
     success: boolean,
     durationMs: number,
+    fullOutputId?: string,
   ) => void;
   onDelegationStarted?: (
     delegationId: string,
@@ -157,6 +175,8 @@ export interface ChatStreamCallbacks {
 
 /** Minimal interface for an active chat connection (browser WS or Tauri WS). */
 export interface ChatConnection {
+  /** Id the stream can be cancelled by (`POST /chat/streams/{id}/cancel`). */
+  readonly streamId: string;
   send(data: string): void;
   close(): void;
   readonly isOpen: boolean;
@@ -171,6 +191,9 @@ function dispatchMessage(
     case "text":
       callbacks.onToken(msg.content);
       return false;
+    case "token":
+      // Rendered from the final `text` message
+      return false;
     case "tool_call":
       callbacks.onToolCall?.(msg.call_id, msg.tool_name, msg.args);
       return false;
@@ -181,6 +204,7 @@ function dispatchMessage(
         msg.output,
         msg.success,
         msg.duration_ms,
+        msg.full_output_id,
       );
       return false;
     case "delegation_started":
@@ -233,6 +257,10 @@ function dispatchMessage(
     case "done":
       callbacks.onDone();
       return true;
+    case "cancelled":
+      if (msg.content) callbacks.onToken(msg.content);
+      callbacks.onDone();
+      return true;
     case "error":
       callbacks.onError(msg.error, msg.hint);
       return true;
@@ -256,6 +284,7 @@ function buildChatUrl(): string {
 function buildPromptPayload(
   prompt: string,
   sessionId: string | undefined,
+  streamId: string,
   model?: string,
   delegation?: boolean,
 ): string {
@@ -264,6 +293,7 @@ function buildPromptPayload(
     session_id: sessionId,
     model: model || undefined,
     delegation: delegation || undefined,
+    stream_id: streamId,
   });
 }
 
@@ -278,11 +308,12 @@ function createChatStreamBrowser(
   const url = buildChatUrl();
   console.log(`[WS] Connecting to ${url.replace(/token=[^&]+/, "token=***")}`);
   const ws = new WebSocket(url);
+  const streamId = crypto.randomUUID();
   let intentionalClose = false;
 
   ws.onopen = () => {
     console.log(`[WS] Connected, sending prompt`);
-    ws.send(buildPromptPayload(prompt, sessionId, model, delegation));
+    ws.send(buildPromptPayload(prompt, sessionId, streamId, model, delegation));
   };
 
   ws.onmessage = (event) => {
@@ -320,6 +351,7 @@ function createChatStreamBrowser(
   };
 
   return {
+    streamId,
     send: (data: string) => ws.send(data),
     close: () => {
       intentionalClose = true;
@@ -351,6 +383,7 @@ async function createChatStreamTauri(
     10000,
     "WS chat connect",
   );
+  const streamId = crypto.randomUUID();
   let intentionalClose = false;
   let open = true;
 
@@ -380,9 +413,10 @@ async function createChatStreamTauri(
 
   // Connection is already open after connect() resolves — send prompt immediately
   console.log(`[WS/Tauri] Connected, sending prompt`);
-  await ws.send(buildPromptPayload(prompt, sessionId, model, delegation));
+  await ws.send(buildPromptPayload(prompt, sessionId, streamId, model, delegation));
 
   return {
+    streamId,
     send: (data: string) => {
       ws.send(data);
     },
@@ -442,3 +476,14 @@ export function sendApprovalResponse(
     );
   }
 }
+
+/**
+ * Ask the server to stop the stream. It replies with a `cancelled` message
+ * carrying the partial answer, which ends the stream like `done`.
+ * Returns false if the connection is already closed.
+ */
+export function cancelChatStream(conn: ChatConnection): boolean {
+  if (!conn.isOpen) return false;
+  conn.send(JSON.stringify({ type: "cancel" }));
+  return true;
+}
@@ -7,7 +7,7 @@
 // ---------------------------------------------------------------------------
 // Always-registered:
 //   system_info, web_search, file_read, file_write, file_list, file_search,
-//   content_search, shell, process, patch, learn, propose_skill_change,
+//   content_search, grep, shell, process, patch, learn, propose_skill_change,
 //   memory, agent_notes, config, wiki
 // Feature-gated:
 //   channel_send  (feature: channels)
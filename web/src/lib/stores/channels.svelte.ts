@@ -62,6 +62,11 @@ function getBuiltinChannels(): ChannelDef[] {
           label: m.channel_slack_channel_ids_label(),
           placeholder: m.channel_slack_channel_ids_placeholder(),
         },
+        {
+          key: "signing_secret",
+          label: m.channel_slack_signing_secret_label(),
+          placeholder: m.channel_slack_signing_secret_placeholder(),
+        },
       ],
     },
     {
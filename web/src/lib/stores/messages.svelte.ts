@@ -31,6 +31,7 @@ export interface DelegationAgentRecord {
   tokens_used: number;
   duration_ms: number;
   error?: string;
+  workspace_changes?: { added: number; modified: number; deleted: number };
 }
 
 export interface DelegationRecord {
@@ -48,6 +49,8 @@ export interface ActiveToolCall {
   output?: string;
   success?: boolean;
   durationMs?: number;
+  /** Set when `output` is truncated and the full text can still be fetched. */
+  fullOutputId?: string;
 }
 
 function createMessagesStore() {
@@ -154,6 +157,7 @@ function createMessagesStore() {
       output: string,
       success: boolean,
       durationMs: number,
+      fullOutputId?: string,
     ) {
       activeToolCalls = activeToolCalls.map((tc) =>
         tc.callId === callId
@@ -162,6 +166,7 @@ function createMessagesStore() {
               output,
               success,
               durationMs,
+              fullOutputId,
               state: (success
                 ? "output-available"
                 : "output-error") as ToolUIPartState,
@@ -170,6 +175,27 @@ function createMessagesStore() {
       );
     },
 
+    async loadFullToolOutput(callId: string) {
+      const tc = activeToolCalls.find((t) => t.callId === callId);
+      if (!tc?.fullOutputId) return;
+      try {
+        const result = await apiGet<{ output: string }>(
+          `/tools/results/${encodeURIComponent(tc.fullOutputId)}`,
+        );
+        activeToolCalls = activeToolCalls.map((t) =>
+          t.callId === callId
+            ? { ...t, output: result.output, fullOutputId: undefined }
+            : t,
+        );
+      } catch (e) {
+        // Expired or unknown: keep the truncated output, drop the stale id
+        activeToolCalls = activeToolCalls.map((t) =>
+          t.callId === callId ? { ...t, fullOutputId: undefined } : t,
+        );
+        console.warn("Failed to load full tool output:", e);
+      }
+    },
+
     async finishStream(
       sessionId: string,
       fallbackDelegation?: DelegationRecord,
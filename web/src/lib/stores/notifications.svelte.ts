@@ -1,4 +1,5 @@
 import { toast } from "svelte-sonner";
+import { goto } from "$app/navigation";
 import { inboxStore } from "./inbox.svelte";
 import { configStore } from "./config.svelte";
 import { sessionsStore } from "./sessions.svelte";
@@ -188,6 +189,44 @@ class NotificationStore {
         if (this.channelAgentActivity?.sessionId === data.session_id) {
           this.channelAgentActivity = null;
         }
+      } else if (data.type === "agent_error") {
+        if (this.channelAgentActivity?.sessionId === data.session_id) {
+          this.channelAgentActivity = null;
+        }
+        this.lastActivityAt = Date.now();
+        const sessionId: string | undefined = data.session_id;
+        toast.error(m.notification_agent_error({ message: data.message }), {
+          action: sessionId
+            ? {
+                label: m.notification_agent_error_open(),
+                onClick: () => goto(`/chat/${sessionId}`),
+              }
+            : undefined,
+        });
+      } else if (data.type === "model_failover") {
+        toast.warning(
+          m.notification_model_failover({
+            failed: data.failed_model,
+            next: data.next_model,
+          }),
+        );
+      } else if (data.type === "context_truncated") {
+        toast.warning(
+          m.notification_context_truncated({
+            count: String(data.dropped_messages),
+            model: data.model,
+          }),
+        );
+      } else if (data.type === "tool_failure_loop") {
+        const message = data.aborted
+          ? m.notification_tool_failure_aborted
+          : m.notification_tool_failure_loop;
+        toast.warning(
+          message({
+            tool: data.tool_name,
+            count: String(data.failures),
+          }),
+        );
       } else if (data.type === "channel_message") {
         inboxStore.handleRealtimeMessage({
           channel: data.channel,
@@ -8,6 +8,9 @@ export interface ModelInfo {
   context_limit: number | null;
   is_custom: boolean;
   is_active: boolean;
+  input_cost_per_mtok: number | null;
+  output_cost_per_mtok: number | null;
+  input_modalities: ("text" | "image" | "audio")[];
 }
 
 export interface ProviderWithKeyStatus {
@@ -15,6 +18,7 @@ export interface ProviderWithKeyStatus {
   name: string;
   base_url: string;
   requires_api_key: boolean;
+  supports_embeddings: boolean;
   is_active: boolean;
   is_user_defined: boolean;
   models: ModelInfo[];
@@ -31,6 +35,16 @@ export interface DefaultModel {
   model_id: string;
 }
 
+export interface ProviderHealth {
+  success: boolean;
+  message: string;
+  latency_ms?: number;
+  checked_at?: string;
+  last_error?: string | null;
+  last_error_at?: string | null;
+  cached?: boolean;
+}
+
 const SELECTED_MODEL_KEY = "zenii:selectedModel";
 
 function createProvidersStore() {
@@ -184,10 +198,9 @@ function createProvidersStore() {
       await this.load();
     },
 
-    async testConnection(
-      providerId: string,
-    ): Promise<{ success: boolean; message: string; latency_ms?: number }> {
-      return apiPost(`/providers/${providerId}/test`, {});
+    async testConnection(providerId: string, force = false): Promise<ProviderHealth> {
+      const query = force ? "?force=true" : "";
+      return apiPost(`/providers/${providerId}/test${query}`, {});
     },
   };
 }
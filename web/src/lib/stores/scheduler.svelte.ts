@@ -5,25 +5,42 @@ export interface ScheduledJob {
   name: string;
   schedule:
     | { type: "interval"; secs: number }
-    | { type: "cron"; expr: string }
-    | { type: "human"; datetime: string };
+    | { type: "cron"; expr: string; tz?: string }
+    | { type: "human"; datetime: string }
+    | { type: "once"; at: string };
   session_target: "main" | "isolated";
   payload:
     | { type: "heartbeat" }
     | { type: "agent_turn"; prompt: string }
     | { type: "notify"; message: string }
-    | { type: "send_via_channel"; channel: string; message: string };
+    | { type: "send_via_channel"; channel: string; message: string }
+    | {
+        type: "channel_notify";
+        channel: string;
+        recipient: string;
+        message: string;
+      }
+    | { type: "daily_summary" };
   enabled: boolean;
   error_count: number;
   next_run: string | null;
-  active_hours: { start_hour: number; end_hour: number } | null;
+  active_hours: ActiveHours[];
   delete_after_run: boolean;
 }
 
+export interface ActiveHours {
+  start_hour: number;
+  start_minute?: number;
+  end_hour: number;
+  end_minute?: number;
+  days?: string[];
+  tz?: string | null;
+}
+
 export interface JobExecution {
   id: string;
   job_id: string;
-  status: "success" | "failed" | "stuck" | "skipped";
+  status: "success" | "failed" | "stuck" | "skipped" | "cancelled";
   started_at: string;
   completed_at: string | null;
   error: string | null;
@@ -90,7 +107,7 @@ function createSchedulerStore() {
         schedule: job.schedule,
         session_target: job.session_target ?? "main",
         payload: job.payload,
-        active_hours: job.active_hours ?? null,
+        active_hours: job.active_hours ?? [],
         delete_after_run: job.delete_after_run ?? false,
       });
       await this.load();
@@ -104,7 +121,7 @@ function createSchedulerStore() {
         schedule: job.schedule,
         session_target: job.session_target ?? "main",
         payload: job.payload,
-        active_hours: job.active_hours ?? null,
+        active_hours: job.active_hours ?? [],
         delete_after_run: job.delete_after_run ?? false,
       });
       await this.load();